@@ -54,6 +54,8 @@ static UTF8_VIEW_TYPE: ArrowDataType = ArrowDataType::Utf8View;
 const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
 const MAX_EXP_BLOCK_SIZE: usize = 16 * 1024 * 1024;
 
+const GC_MINIMUM_SAVINGS: usize = 16 * 1024; // At least 16 KiB.
+
 pub trait ViewType: Sealed + 'static + PartialEq + AsRef<Self> {
     const IS_UTF8: bool;
     const DATA_TYPE: ArrowDataType;
@@ -501,9 +503,67 @@ impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
         !std::ptr::eq(self.views.as_ptr(), self.views.storage_ptr())
     }
 
-    pub fn maybe_gc(self) -> Self {
-        const GC_MINIMUM_SAVINGS: usize = 16 * 1024; // At least 16 KiB.
+    /// Estimate the number of bytes in the data buffers that are not referenced by any view.
+    ///
+    /// This is an estimate as views may overlap or reference the same bytes.
+    pub fn estimated_buffer_waste(&self) -> usize {
+        let referenced: usize = self
+            .views
+            .iter()
+            .filter(|v| v.length > View::MAX_INLINE_SIZE)
+            .map(|v| v.length as usize)
+            .sum();
+        self.total_buffer_len.saturating_sub(referenced)
+    }
+
+    /// Estimate the number of bytes in the data buffers referenced by the views, extrapolated
+    /// from a fixed number of evenly spaced views.
+    fn sampled_buffer_retained_len(&self) -> f64 {
+        const SAMPLE_SIZE: usize = 1024;
+
+        let referenced_len = |v: &View| {
+            if v.length > View::MAX_INLINE_SIZE {
+                v.length as usize
+            } else {
+                0
+            }
+        };
+        let views = self.views.as_slice();
+        if views.len() <= SAMPLE_SIZE {
+            return views.iter().map(referenced_len).sum::<usize>() as f64;
+        }
+
+        let step = views.len() / SAMPLE_SIZE;
+        let sampled: usize = views
+            .iter()
+            .step_by(step)
+            .take(SAMPLE_SIZE)
+            .map(referenced_len)
+            .sum();
+        sampled as f64 * (views.len() as f64 / SAMPLE_SIZE as f64)
+    }
+
+    /// Garbage collect if the bytes referenced by the views make up less than `threshold`
+    /// (a fraction between 0 and 1) of the data buffers.
+    ///
+    /// The referenced bytes are estimated from a sample of the views, so this is cheap to
+    /// call on large arrays.
+    ///
+    /// Unlike [`Self::maybe_gc`] this also collects if the buffers are shared, so that the
+    /// result no longer keeps the original buffers alive.
+    pub fn gc_if_retained_below(self, threshold: f64) -> Self {
+        if self.total_buffer_len <= GC_MINIMUM_SAVINGS {
+            return self;
+        }
 
+        if self.sampled_buffer_retained_len() < threshold * self.total_buffer_len as f64 {
+            self.gc()
+        } else {
+            self
+        }
+    }
+
+    pub fn maybe_gc(self) -> Self {
         if self.total_buffer_len <= GC_MINIMUM_SAVINGS {
             return self;
         }
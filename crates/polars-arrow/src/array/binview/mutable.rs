@@ -179,12 +179,12 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
     /// - The array must not have validity.
     pub(crate) unsafe fn push_view_unchecked(&mut self, v: View, buffers: &[Buffer<u8>]) {
         let len = v.length;
-        self.total_bytes_len += len as usize;
         if len <= 12 {
+            self.total_bytes_len += len as usize;
             debug_assert!(self.views.capacity() > self.views.len());
             self.views.push_unchecked(v)
         } else {
-            self.total_buffer_len += len as usize;
+            // `push_value_ignore_validity` accounts for the bytes.
             let data = buffers.get_unchecked(v.buffer_idx as usize);
             let offset = v.offset as usize;
             let bytes = data.get_unchecked(offset..offset + len as usize);
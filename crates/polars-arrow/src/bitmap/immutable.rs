@@ -193,6 +193,12 @@ impl Bitmap {
         )
     }
 
+    /// The number of bytes allocated for the storage backing this [`Bitmap`], regardless of
+    /// slicing.
+    pub fn allocated_bytes(&self) -> usize {
+        self.storage.capacity_in_bytes()
+    }
+
     /// Returns the number of set bits on this [`Bitmap`].
     ///
     /// See `unset_bits` for details.
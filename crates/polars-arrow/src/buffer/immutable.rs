@@ -268,6 +268,12 @@ impl<T> Buffer<T> {
     pub fn storage_refcount(&self) -> u64 {
         self.storage.refcount()
     }
+
+    /// The number of bytes allocated for the storage backing this buffer, regardless of
+    /// slicing.
+    pub fn allocated_bytes(&self) -> usize {
+        self.storage.capacity_in_bytes()
+    }
 }
 
 impl<T: Clone> Buffer<T> {
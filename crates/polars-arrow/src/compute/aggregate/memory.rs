@@ -129,3 +129,121 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+fn allocated_validity_size(validity: Option<&Bitmap>) -> usize {
+    validity.map(Bitmap::allocated_bytes).unwrap_or(0)
+}
+
+macro_rules! dyn_binary_allocated {
+    ($array:expr, $ty:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        array.values().allocated_bytes()
+            + array.offsets().buffer().allocated_bytes()
+            + allocated_validity_size(array.validity())
+    }};
+}
+
+fn binview_allocated_size<T: ViewType + ?Sized>(array: &BinaryViewArrayGeneric<T>) -> usize {
+    array.views().allocated_bytes()
+        + array
+            .data_buffers()
+            .iter()
+            .map(|b| b.allocated_bytes())
+            .sum::<usize>()
+        + allocated_validity_size(array.validity())
+}
+
+/// Returns the number of bytes allocated for the buffers of the array, including nested arrays.
+///
+/// Unlike [`estimated_bytes_size`], this counts the full capacity of every buffer, also of the
+/// parts that are sliced away or were reserved but never filled. Buffers shared between arrays
+/// are counted for each array.
+pub fn allocated_bytes_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.dtype().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.values().allocated_bytes() + allocated_validity_size(array.validity())
+        },
+        Primitive(PrimitiveType::DaysMs) => {
+            let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+            array.values().allocated_bytes() + allocated_validity_size(array.validity())
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+
+            array.values().allocated_bytes() + allocated_validity_size(array.validity())
+        }),
+        Binary => dyn_binary_allocated!(array, BinaryArray<i32>),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.values().allocated_bytes() + allocated_validity_size(array.validity())
+        },
+        LargeBinary => dyn_binary_allocated!(array, BinaryArray<i64>),
+        Utf8 => dyn_binary_allocated!(array, Utf8Array<i32>),
+        LargeUtf8 => dyn_binary_allocated!(array, Utf8Array<i64>),
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + array.offsets().buffer().allocated_bytes()
+                + allocated_validity_size(array.validity())
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + allocated_validity_size(array.validity())
+        },
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            allocated_bytes_size(array.values().as_ref())
+                + array.offsets().buffer().allocated_bytes()
+                + allocated_validity_size(array.validity())
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| allocated_bytes_size(x.as_ref()))
+                .sum::<usize>()
+                + allocated_validity_size(array.validity())
+        },
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = array.types().allocated_bytes();
+            let offsets = array
+                .offsets()
+                .as_ref()
+                .map(|x| x.allocated_bytes())
+                .unwrap_or_default();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|x| allocated_bytes_size(x.as_ref()))
+                .sum::<usize>();
+            types + offsets + fields
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            allocated_bytes_size(array.keys()) + allocated_bytes_size(array.values().as_ref())
+        }),
+        Utf8View => binview_allocated_size::<str>(array.as_any().downcast_ref().unwrap()),
+        BinaryView => binview_allocated_size::<[u8]>(array.as_any().downcast_ref().unwrap()),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            array.offsets().buffer().allocated_bytes()
+                + allocated_bytes_size(array.field().as_ref())
+                + allocated_validity_size(array.validity())
+        },
+    }
+}
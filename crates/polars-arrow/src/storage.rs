@@ -240,6 +240,19 @@ impl<T> SharedStorage<T> {
         self.inner().ref_count.load(Ordering::Acquire) == 1
     }
 
+    /// The number of bytes allocated for this storage, which can exceed its length if it was
+    /// created from a [`Vec`] with spare capacity.
+    pub fn capacity_in_bytes(&self) -> usize {
+        let inner = self.inner();
+        match &inner.backing {
+            BackingStorage::Vec {
+                original_capacity,
+                vtable,
+            } => original_capacity * vtable.size,
+            _ => inner.length_in_bytes,
+        }
+    }
+
     /// Gets the reference count of this storage.
     ///
     /// Because this function takes a shared reference this should not be used
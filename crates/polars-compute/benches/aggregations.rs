@@ -0,0 +1,57 @@
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use polars_compute::float_sum::{sum_arr_as_f32, sum_arr_as_f64};
+use polars_compute::min_max::MinMaxKernel;
+use polars_compute::sum::wrapping_sum_arr;
+use rand::prelude::*;
+
+const LEN: usize = 1 << 20;
+const NULL_DENSITIES: [f64; 3] = [0.0, 0.01, 0.5];
+
+fn make_array<T: NativeType>(null_density: f64, f: impl Fn(&mut StdRng) -> T) -> PrimitiveArray<T> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let values: Vec<T> = (0..LEN).map(|_| f(&mut rng)).collect();
+    let validity = (null_density > 0.0).then(|| {
+        (0..LEN)
+            .map(|_| !rng.gen_bool(null_density))
+            .collect::<Bitmap>()
+    });
+    PrimitiveArray::from_vec(values).with_validity(validity)
+}
+
+fn bench_aggregations(c: &mut Criterion) {
+    for null_density in NULL_DENSITIES {
+        let f32_arr = make_array(null_density, |rng| rng.r#gen::<f32>());
+        let f64_arr = make_array(null_density, |rng| rng.r#gen::<f64>());
+        let i64_arr = make_array(null_density, |rng| rng.gen_range(-1000i64..1000));
+
+        let mut group = c.benchmark_group("sum");
+        group.bench_function(BenchmarkId::new("f32", null_density), |b| {
+            b.iter(|| sum_arr_as_f32(black_box(&f32_arr)))
+        });
+        group.bench_function(BenchmarkId::new("f64", null_density), |b| {
+            b.iter(|| sum_arr_as_f64(black_box(&f64_arr)))
+        });
+        group.bench_function(BenchmarkId::new("i64", null_density), |b| {
+            b.iter(|| wrapping_sum_arr(black_box(&i64_arr)))
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("min_max");
+        group.bench_function(BenchmarkId::new("f32", null_density), |b| {
+            b.iter(|| black_box(&f32_arr).min_max_ignore_nan_kernel())
+        });
+        group.bench_function(BenchmarkId::new("f64", null_density), |b| {
+            b.iter(|| black_box(&f64_arr).min_max_ignore_nan_kernel())
+        });
+        group.bench_function(BenchmarkId::new("i64", null_density), |b| {
+            b.iter(|| black_box(&i64_arr).min_max_ignore_nan_kernel())
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_aggregations);
+criterion_main!(benches);
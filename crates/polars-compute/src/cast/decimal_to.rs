@@ -1,9 +1,11 @@
 use arrow::array::*;
 use arrow::datatypes::ArrowDataType;
 use arrow::types::NativeType;
-use num_traits::{AsPrimitive, Float, NumCast};
+use num_traits::{AsPrimitive, Bounded, Float, NumCast};
 use polars_error::PolarsResult;
 
+use super::CastOptionsImpl;
+
 #[inline]
 fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
     from: &PrimitiveArray<i128>,
@@ -127,12 +129,46 @@ where
     PrimitiveArray::from_trusted_len_iter(values)
 }
 
-pub(super) fn decimal_to_integer_dyn<T>(from: &dyn Array) -> PolarsResult<Box<dyn Array>>
+/// Returns a [`PrimitiveArray<T>`] with the cast values. Values that are out of range are clamped
+/// to the bounds of `T`.
+pub fn decimal_to_integer_saturating<T>(from: &PrimitiveArray<i128>) -> PrimitiveArray<T>
 where
-    T: NativeType + NumCast,
+    T: NativeType + NumCast + Bounded,
+{
+    let (_, from_scale) = if let ArrowDataType::Decimal(p, s) = from.dtype().to_logical_type() {
+        (*p, *s)
+    } else {
+        panic!("internal error: i128 is always a decimal")
+    };
+
+    let factor = 10_i128.pow(from_scale as u32);
+    let values = from.iter().map(|x| {
+        x.map(|x| {
+            let x = *x / factor;
+            T::from(x).unwrap_or(if x > 0 {
+                T::max_value()
+            } else {
+                T::min_value()
+            })
+        })
+    });
+
+    PrimitiveArray::from_trusted_len_iter(values)
+}
+
+pub(super) fn decimal_to_integer_dyn<T>(
+    from: &dyn Array,
+    options: CastOptionsImpl,
+) -> PolarsResult<Box<dyn Array>>
+where
+    T: NativeType + NumCast + Bounded,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_integer::<T>(from)))
+    if options.saturating {
+        Ok(Box::new(decimal_to_integer_saturating::<T>(from)))
+    } else {
+        Ok(Box::new(decimal_to_integer::<T>(from)))
+    }
 }
 
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the decimal.
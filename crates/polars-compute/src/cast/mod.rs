@@ -42,13 +42,17 @@ pub struct CastOptionsImpl {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether an overflowing numeric cast should be clamped to the bounds of the target type.
+    /// Ignored if `wrapped` is set.
+    pub saturating: bool,
 }
 
 impl CastOptionsImpl {
     pub fn unchecked() -> Self {
         Self {
             wrapped: true,
-            partial: false,
+            ..Default::default()
         }
     }
 }
@@ -699,7 +703,7 @@ pub fn cast(
         (UInt8, Int128) => primitive_to_primitive_dyn::<u8, i128>(array, to_type, options),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
-        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
+        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s, options),
 
         (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type, options),
         (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type, as_options),
@@ -712,7 +716,7 @@ pub fn cast(
         (UInt16, Int128) => primitive_to_primitive_dyn::<u16, i128>(array, to_type, options),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
-        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
+        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s, options),
 
         (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type, options),
         (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type, options),
@@ -725,7 +729,7 @@ pub fn cast(
         (UInt32, Int128) => primitive_to_primitive_dyn::<u32, i128>(array, to_type, options),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
-        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
+        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s, options),
 
         (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type, options),
         (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type, options),
@@ -738,7 +742,7 @@ pub fn cast(
         (UInt64, Int128) => primitive_to_primitive_dyn::<u64, i128>(array, to_type, options),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
-        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
+        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s, options),
 
         (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type, options),
         (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type, options),
@@ -751,7 +755,7 @@ pub fn cast(
         (Int8, Int128) => primitive_to_primitive_dyn::<i8, i128>(array, to_type, as_options),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
-        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
+        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s, options),
 
         (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type, options),
         (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type, options),
@@ -764,7 +768,7 @@ pub fn cast(
         (Int16, Int128) => primitive_to_primitive_dyn::<i16, i128>(array, to_type, as_options),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
-        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
+        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s, options),
 
         (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type, options),
         (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type, options),
@@ -777,7 +781,7 @@ pub fn cast(
         (Int32, Int128) => primitive_to_primitive_dyn::<i32, i128>(array, to_type, as_options),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
-        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
+        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s, options),
 
         (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type, options),
         (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type, options),
@@ -790,7 +794,7 @@ pub fn cast(
         (Int64, Int128) => primitive_to_primitive_dyn::<i64, i128>(array, to_type, options),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
-        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
+        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s, options),
 
         #[cfg(feature = "dtype-i128")]
         (Int128, UInt8) => primitive_to_primitive_dyn::<i128, u8>(array, to_type, options),
@@ -813,7 +817,7 @@ pub fn cast(
         #[cfg(feature = "dtype-i128")]
         (Int128, Float64) => primitive_to_primitive_dyn::<i128, f64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
-        (Int128, Decimal(p, s)) => integer_to_decimal_dyn::<i128>(array, *p, *s),
+        (Int128, Decimal(p, s)) => integer_to_decimal_dyn::<i128>(array, *p, *s, options),
 
         (Float16, Float32) => {
             let from = array.as_any().downcast_ref().unwrap();
@@ -844,15 +848,15 @@ pub fn cast(
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
         (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
 
-        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array),
-        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array),
-        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array),
-        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array),
-        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array),
-        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array),
-        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array),
-        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
-        (Decimal(_, _), Int128) => decimal_to_integer_dyn::<i128>(array),
+        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array, options),
+        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array, options),
+        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array, options),
+        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array, options),
+        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array, options),
+        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array, options),
+        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array, options),
+        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array, options),
+        (Decimal(_, _), Int128) => decimal_to_integer_dyn::<i128>(array, options),
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
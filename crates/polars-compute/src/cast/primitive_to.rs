@@ -197,12 +197,20 @@ pub(super) fn primitive_to_primitive_dyn<I, O>(
     options: CastOptionsImpl,
 ) -> PolarsResult<Box<dyn Array>>
 where
-    I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O>,
-    O: NativeType + num_traits::NumCast,
+    I: NativeType
+        + num_traits::NumCast
+        + num_traits::AsPrimitive<O>
+        + PartialOrd
+        + num_traits::Zero,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
 {
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
         Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
+    } else if options.saturating {
+        Ok(Box::new(primitive_to_primitive_saturating::<I, O>(
+            from, to_type,
+        )))
     } else {
         Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
     }
@@ -223,6 +231,33 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion,
+/// clamping values that are out of range to the bounds of `O`. NaN becomes null.
+pub fn primitive_to_primitive_saturating<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &ArrowDataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType + num_traits::NumCast + PartialOrd + num_traits::Zero,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
+{
+    let iter = from.iter().map(|v| {
+        v.and_then(|x| {
+            num_traits::cast::cast::<I, O>(*x).or_else(|| {
+                if *x > I::zero() {
+                    Some(O::max_value())
+                } else if *x < I::zero() {
+                    Some(O::min_value())
+                } else {
+                    // NaN
+                    None
+                }
+            })
+        })
+    });
+    PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
+}
+
 /// Returns a [`PrimitiveArray<i128>`] with the cast values. Values are `None` on overflow
 pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
     from: &PrimitiveArray<T>,
@@ -252,16 +287,46 @@ pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
         .to(ArrowDataType::Decimal(to_precision, to_scale))
 }
 
+/// Returns a [`PrimitiveArray<i128>`] with the cast values. Values that do not fit the precision
+/// are clamped to the largest or smallest decimal of that precision.
+pub fn integer_to_decimal_saturating<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i128> {
+    let multiplier = 10_i128.pow(to_scale as u32);
+    let max_for_precision = 10_i128.saturating_pow(to_precision as u32) - 1;
+
+    let values = from.iter().map(|x| {
+        x.map(|x| {
+            let x: i128 = x.as_();
+            x.checked_mul(multiplier)
+                .unwrap_or(if x > 0 { i128::MAX } else { i128::MIN })
+                .clamp(-max_for_precision, max_for_precision)
+        })
+    });
+
+    PrimitiveArray::<i128>::from_trusted_len_iter(values)
+        .to(ArrowDataType::Decimal(to_precision, to_scale))
+}
+
 pub(super) fn integer_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptionsImpl,
 ) -> PolarsResult<Box<dyn Array>>
 where
     T: NativeType + AsPrimitive<i128>,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    if options.saturating {
+        Ok(Box::new(integer_to_decimal_saturating::<T>(
+            from, precision, scale,
+        )))
+    } else {
+        Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    }
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the cast values. Values are `None` on overflow
@@ -45,6 +45,7 @@ impl_cast_custom!(i64);
 impl_cast_custom!(f32);
 impl_cast_custom!(f64);
 
+#[inline(always)]
 fn vector_horizontal_sum<V, T>(mut v: V) -> T
 where
     V: IndexMut<usize, Output = T>,
@@ -80,6 +81,7 @@ where
     Simd<T, STRIPE>: SimdCastGeneric<STRIPE>,
     Simd<F, STRIPE>: std::iter::Sum,
 {
+    #[inline(always)]
     fn sum_block_vectorized(&self) -> F {
         let vsum = self
             .chunks_exact(STRIPE)
@@ -88,6 +90,7 @@ where
         vector_horizontal_sum(vsum)
     }
 
+    #[inline(always)]
     fn sum_block_vectorized_with_mask(&self, mask: BitMask<'_>) -> F {
         let zero = Simd::default();
         let vsum = self
@@ -153,17 +156,84 @@ where
     }
 }
 
-/// Invariant: f.len() % PAIRWISE_RECURSION_LIMIT == 0 and f.len() > 0.
-unsafe fn pairwise_sum<F, T>(f: &[T]) -> F
+type BlockSumFn<T, F> = unsafe fn(&[T; PAIRWISE_RECURSION_LIMIT]) -> F;
+type BlockSumWithMaskFn<T, F> = unsafe fn(&[T; PAIRWISE_RECURSION_LIMIT], BitMask<'_>) -> F;
+
+fn block_sum<F, T>(block: &[T; PAIRWISE_RECURSION_LIMIT]) -> F
+where
+    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    block.sum_block_vectorized()
+}
+
+fn block_sum_with_mask<F, T>(block: &[T; PAIRWISE_RECURSION_LIMIT], mask: BitMask<'_>) -> F
+where
+    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    block.sum_block_vectorized_with_mask(mask)
+}
+
+// The block kernels are the same code compiled with AVX-512 enabled. Lane-wise
+// additions are performed in the same order, so the result is bit-for-bit
+// identical to the baseline kernel.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[target_feature(enable = "avx512f")]
+unsafe fn block_sum_avx512<F, T>(block: &[T; PAIRWISE_RECURSION_LIMIT]) -> F
 where
     [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    block.sum_block_vectorized()
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[target_feature(enable = "avx512f")]
+unsafe fn block_sum_with_mask_avx512<F, T>(
+    block: &[T; PAIRWISE_RECURSION_LIMIT],
+    mask: BitMask<'_>,
+) -> F
+where
+    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    block.sum_block_vectorized_with_mask(mask)
+}
+
+/// Selects the fastest block kernel supported by the running CPU.
+fn select_block_sum<F, T>() -> BlockSumFn<T, F>
+where
+    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+    if polars_utils::cpuid::is_avx512_enabled() {
+        return block_sum_avx512::<F, T>;
+    }
+
+    block_sum::<F, T>
+}
+
+/// Selects the fastest masked block kernel supported by the running CPU.
+fn select_block_sum_with_mask<F, T>() -> BlockSumWithMaskFn<T, F>
+where
+    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
+{
+    #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+    if polars_utils::cpuid::is_avx512_enabled() {
+        return block_sum_with_mask_avx512::<F, T>;
+    }
+
+    block_sum_with_mask::<F, T>
+}
+
+/// Invariant: f.len() % PAIRWISE_RECURSION_LIMIT == 0 and f.len() > 0.
+/// Also, `block_f` must be supported by the running CPU.
+unsafe fn pairwise_sum<F, T>(f: &[T], block_f: BlockSumFn<T, F>) -> F
+where
     F: Add<Output = F>,
 {
     debug_assert!(!f.is_empty() && f.len() % PAIRWISE_RECURSION_LIMIT == 0);
 
     let block: Option<&[T; PAIRWISE_RECURSION_LIMIT]> = f.try_into().ok();
     if let Some(block) = block {
-        return block.sum_block_vectorized();
+        return unsafe { block_f(block) };
     }
 
     // SAFETY: we maintain the invariant. `try_into` array of len PAIRWISE_RECURSION_LIMIT
@@ -174,15 +244,18 @@ where
         let blocks = f.len() / PAIRWISE_RECURSION_LIMIT;
         let left_len = (blocks / 2) * PAIRWISE_RECURSION_LIMIT;
         let (left, right) = (f.get_unchecked(..left_len), f.get_unchecked(left_len..));
-        pairwise_sum(left) + pairwise_sum(right)
+        pairwise_sum(left, block_f) + pairwise_sum(right, block_f)
     }
 }
 
 /// Invariant: f.len() % PAIRWISE_RECURSION_LIMIT == 0 and f.len() > 0.
-/// Also, f.len() == mask.len().
-unsafe fn pairwise_sum_with_mask<F, T>(f: &[T], mask: BitMask<'_>) -> F
+/// Also, f.len() == mask.len() and `block_f` must be supported by the running CPU.
+unsafe fn pairwise_sum_with_mask<F, T>(
+    f: &[T],
+    mask: BitMask<'_>,
+    block_f: BlockSumWithMaskFn<T, F>,
+) -> F
 where
-    [T; PAIRWISE_RECURSION_LIMIT]: SumBlock<F>,
     F: Add<Output = F>,
 {
     debug_assert!(!f.is_empty() && f.len() % PAIRWISE_RECURSION_LIMIT == 0);
@@ -190,7 +263,7 @@ where
 
     let block: Option<&[T; PAIRWISE_RECURSION_LIMIT]> = f.try_into().ok();
     if let Some(block) = block {
-        return block.sum_block_vectorized_with_mask(mask);
+        return unsafe { block_f(block, mask) };
     }
 
     // SAFETY: see pairwise_sum.
@@ -199,7 +272,8 @@ where
         let left_len = (blocks / 2) * PAIRWISE_RECURSION_LIMIT;
         let (left, right) = (f.get_unchecked(..left_len), f.get_unchecked(left_len..));
         let (left_mask, right_mask) = mask.split_at_unchecked(left_len);
-        pairwise_sum_with_mask(left, left_mask) + pairwise_sum_with_mask(right, right_mask)
+        pairwise_sum_with_mask(left, left_mask, block_f)
+            + pairwise_sum_with_mask(right, right_mask, block_f)
     }
 }
 
@@ -218,7 +292,7 @@ where
         let remainder = f.len() % PAIRWISE_RECURSION_LIMIT;
         let (rest, main) = f.split_at(remainder);
         let mainsum = if f.len() > remainder {
-            unsafe { pairwise_sum(main) }
+            unsafe { pairwise_sum(main, select_block_sum()) }
         } else {
             F::zero()
         };
@@ -235,7 +309,8 @@ where
         let (rest, main) = f.split_at(remainder);
         let (rest_mask, main_mask) = mask.split_at(remainder);
         let mainsum = if f.len() > remainder {
-            unsafe { pairwise_sum_with_mask(main, main_mask) }
+            let block_f = select_block_sum_with_mask();
+            unsafe { pairwise_sum_with_mask(main, main_mask, block_f) }
         } else {
             F::zero()
         };
@@ -275,3 +350,38 @@ where
         FloatSum::sum(arr.values())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use arrow::bitmap::Bitmap;
+
+    use super::*;
+
+    fn values(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| ((i * 7919) % 1013) as f64 / 3.0 - 150.0 + 1e10 * (i % 3) as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_dispatched_sum_is_bit_identical() {
+        let vals = values(128 * 37);
+        for null_every in [0, 100, 2] {
+            let validity: Bitmap = (0..vals.len())
+                .map(|i| null_every == 0 || i % null_every != 0)
+                .collect();
+            let mask = BitMask::from_bitmap(&validity);
+
+            let baseline: f64 = unsafe { pairwise_sum(&vals, block_sum::<f64, f64>) };
+            let dispatched: f64 = unsafe { pairwise_sum(&vals, select_block_sum()) };
+            assert_eq!(baseline.to_bits(), dispatched.to_bits());
+
+            let baseline: f64 = unsafe {
+                pairwise_sum_with_mask(&vals, mask.clone(), block_sum_with_mask::<f64, f64>)
+            };
+            let dispatched: f64 =
+                unsafe { pairwise_sum_with_mask(&vals, mask, select_block_sum_with_mask()) };
+            assert_eq!(baseline.to_bits(), dispatched.to_bits());
+        }
+    }
+}
@@ -5,6 +5,8 @@ use arrow::array::PrimitiveArray;
 use arrow::bitmap::Bitmap;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::types::NativeType;
+#[cfg(target_arch = "x86_64")]
+use polars_utils::cpuid::is_avx512_enabled;
 use polars_utils::min_max::MinMax;
 
 use super::MinMaxKernel;
@@ -19,7 +21,49 @@ fn scalar_reduce_max_propagate_nan<T: MinMax + Copy, const N: usize>(arr: &[T; N
     it.reduce(MinMax::max_propagate_nan).unwrap()
 }
 
+// The kernels below are written once against portable SIMD and compiled twice:
+// once for the baseline target and once with AVX-512 enabled, picking the variant
+// at runtime. Both variants perform the exact same lane-wise operations in the
+// same order, so their results are bit-for-bit identical.
+
 fn fold_agg_kernel<const N: usize, T, F>(
+    arr: &[T],
+    validity: Option<&Bitmap>,
+    scalar_identity: T,
+    simd_f: F,
+) -> Option<Simd<T, N>>
+where
+    T: SimdElement + NativeType,
+    F: FnMut(Simd<T, N>, Simd<T, N>) -> Simd<T, N>,
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[cfg(target_arch = "x86_64")]
+    if is_avx512_enabled() {
+        // SAFETY: we checked that the CPU supports AVX-512.
+        return unsafe { fold_agg_kernel_avx512(arr, validity, scalar_identity, simd_f) };
+    }
+
+    fold_agg_kernel_impl(arr, validity, scalar_identity, simd_f)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn fold_agg_kernel_avx512<const N: usize, T, F>(
+    arr: &[T],
+    validity: Option<&Bitmap>,
+    scalar_identity: T,
+    simd_f: F,
+) -> Option<Simd<T, N>>
+where
+    T: SimdElement + NativeType,
+    F: FnMut(Simd<T, N>, Simd<T, N>) -> Simd<T, N>,
+    LaneCount<N>: SupportedLaneCount,
+{
+    fold_agg_kernel_impl(arr, validity, scalar_identity, simd_f)
+}
+
+#[inline(always)]
+fn fold_agg_kernel_impl<const N: usize, T, F>(
     arr: &[T],
     validity: Option<&Bitmap>,
     scalar_identity: T,
@@ -73,6 +117,65 @@ where
 }
 
 fn fold_agg_min_max_kernel<const N: usize, T, F>(
+    arr: &[T],
+    validity: Option<&Bitmap>,
+    min_scalar_identity: T,
+    max_scalar_identity: T,
+    simd_f: F,
+) -> Option<(Simd<T, N>, Simd<T, N>)>
+where
+    T: SimdElement + NativeType,
+    F: FnMut((Simd<T, N>, Simd<T, N>), (Simd<T, N>, Simd<T, N>)) -> (Simd<T, N>, Simd<T, N>),
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[cfg(target_arch = "x86_64")]
+    if is_avx512_enabled() {
+        // SAFETY: we checked that the CPU supports AVX-512.
+        return unsafe {
+            fold_agg_min_max_kernel_avx512(
+                arr,
+                validity,
+                min_scalar_identity,
+                max_scalar_identity,
+                simd_f,
+            )
+        };
+    }
+
+    fold_agg_min_max_kernel_impl(
+        arr,
+        validity,
+        min_scalar_identity,
+        max_scalar_identity,
+        simd_f,
+    )
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn fold_agg_min_max_kernel_avx512<const N: usize, T, F>(
+    arr: &[T],
+    validity: Option<&Bitmap>,
+    min_scalar_identity: T,
+    max_scalar_identity: T,
+    simd_f: F,
+) -> Option<(Simd<T, N>, Simd<T, N>)>
+where
+    T: SimdElement + NativeType,
+    F: FnMut((Simd<T, N>, Simd<T, N>), (Simd<T, N>, Simd<T, N>)) -> (Simd<T, N>, Simd<T, N>),
+    LaneCount<N>: SupportedLaneCount,
+{
+    fold_agg_min_max_kernel_impl(
+        arr,
+        validity,
+        min_scalar_identity,
+        max_scalar_identity,
+        simd_f,
+    )
+}
+
+#[inline(always)]
+fn fold_agg_min_max_kernel_impl<const N: usize, T, F>(
     arr: &[T],
     validity: Option<&Bitmap>,
     min_scalar_identity: T,
@@ -380,3 +483,40 @@ macro_rules! impl_min_max_kernel_float {
 
 impl_min_max_kernel_float!(f32, 16);
 impl_min_max_kernel_float!(f64, 8);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dispatched_min_max_is_bit_identical() {
+        let mut vals: Vec<f64> = (0..1000)
+            .map(|i| ((i * 7919) % 1013) as f64 - 500.0)
+            .collect();
+        vals[517] = f64::NAN;
+        for null_every in [0, 100, 2] {
+            let validity: Bitmap = (0..vals.len())
+                .map(|i| null_every == 0 || i % null_every != 0)
+                .collect();
+            let validity = Some(&validity);
+            let f = |a: Simd<f64, 8>, b: Simd<f64, 8>| (a.simd_lt(b) | a.simd_ne(a)).select(a, b);
+
+            let baseline = fold_agg_kernel_impl(&vals, validity, f64::INFINITY, f).unwrap();
+            let dispatched = fold_agg_kernel(&vals, validity, f64::INFINITY, f).unwrap();
+            assert_eq!(
+                baseline.to_array().map(f64::to_bits),
+                dispatched.to_array().map(f64::to_bits)
+            );
+
+            let ints: Vec<i64> = vals.iter().map(|v| (*v as i64) << 50).collect();
+            let f = |(cmin, cmax): (Simd<i64, 8>, Simd<i64, 8>), (min, max)| {
+                (cmin.simd_min(min), cmax.simd_max(max))
+            };
+            let baseline =
+                fold_agg_min_max_kernel_impl(&ints, validity, i64::MAX, i64::MIN, f).unwrap();
+            let dispatched =
+                fold_agg_min_max_kernel(&ints, validity, i64::MAX, i64::MIN, f).unwrap();
+            assert_eq!(baseline, dispatched);
+        }
+    }
+}
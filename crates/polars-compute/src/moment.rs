@@ -242,6 +242,60 @@ impl PearsonState {
         self.mean_y = new_mean_y;
     }
 
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    fn clear_zero_weight_nan(&mut self) {
+        // Clear NaNs due to division by zero.
+        if self.weight == 0.0 {
+            *self = Self::default();
+        }
+    }
+
+    pub fn insert_one(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: 1.0, mean_x: x, mean_y: y, ..Default::default() })
+        let new_weight = self.weight + 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x + delta_mean_x / new_weight;
+        let new_mean_y = self.mean_y + delta_mean_y / new_weight;
+        self.dp_xx += (x - new_mean_x) * delta_mean_x;
+        self.dp_xy += (x - new_mean_x) * delta_mean_y;
+        self.dp_yy += (y - new_mean_y) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y = new_mean_y;
+        self.clear_zero_weight_nan();
+    }
+
+    pub fn remove_one(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: -1.0, mean_x: x, mean_y: y, ..Default::default() })
+        let new_weight = self.weight - 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x - delta_mean_x / new_weight;
+        let new_mean_y = self.mean_y - delta_mean_y / new_weight;
+        self.dp_xx -= (x - new_mean_x) * delta_mean_x;
+        self.dp_xy -= (x - new_mean_x) * delta_mean_y;
+        self.dp_yy -= (y - new_mean_y) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y = new_mean_y;
+        self.clear_zero_weight_nan();
+    }
+
+    /// The covariance of the pairs, see [`CovState::finalize`].
+    pub fn finalize_cov(&self, ddof: u8) -> Option<f64> {
+        if self.weight <= ddof as f64 {
+            None
+        } else {
+            Some(self.dp_xy / (self.weight - ddof as f64))
+        }
+    }
+
     pub fn finalize(&self) -> f64 {
         let denom_sq = self.dp_xx * self.dp_yy;
         if denom_sq > 0.0 {
@@ -0,0 +1,235 @@
+//! Rolling covariance and correlation between two columns.
+//!
+//! The co-moments of the pairs in a window are maintained with Welford-style updates when the
+//! window moves forward, instead of differencing rolling sums of products, which loses
+//! precision on long windows.
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
+
+use super::{det_offsets, det_offsets_center};
+use crate::moment::PearsonState;
+
+/// Computes the covariance, or the Pearson correlation if `is_corr` is set, of `x` and `y` for
+/// every window `[start, end)` in `windows`.
+///
+/// Pairs where either value is null are skipped, windows with fewer than `min_periods` valid
+/// pairs are null. Windows with a NaN or infinite value are NaN. The windows are processed
+/// incrementally as long as both bounds are non-decreasing.
+pub fn rolling_cov_corr<I>(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    windows: I,
+    min_periods: usize,
+    ddof: u8,
+    is_corr: bool,
+) -> PrimitiveArray<f64>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    assert_eq!(x.len(), y.len());
+    let validity = match (x.validity(), y.validity()) {
+        (Some(x), Some(y)) => Some(x & y),
+        (x, y) => x.or(y).cloned(),
+    };
+    let validity: Option<&Bitmap> = validity.as_ref();
+    let (x, y) = (x.values().as_slice(), y.values().as_slice());
+    let min_periods = min_periods.max(1) as f64;
+
+    let is_valid = |i: usize| validity.is_none_or(|v| v.get_bit(i));
+    let is_finite = |i: usize| x[i].is_finite() && y[i].is_finite();
+
+    let mut state = PearsonState::default();
+    // Removing a non-finite pair can't undo its insertion, so we count the non-finite pairs in
+    // the window and rebuild the state once the last of them has left.
+    let mut non_finite = 0usize;
+    let mut poisoned = false;
+    let (mut last_start, mut last_end) = (0, 0);
+    windows
+        .into_iter()
+        .map(|(start, end)| {
+            assert!(start <= end && end <= x.len());
+            if start < last_start || end < last_end || start >= last_end {
+                state = PearsonState::default();
+                (non_finite, poisoned) = (0, false);
+                (last_start, last_end) = (start, start);
+            }
+            for i in last_start..start {
+                if is_valid(i) {
+                    if is_finite(i) {
+                        state.remove_one(x[i], y[i]);
+                    } else {
+                        non_finite -= 1;
+                    }
+                }
+            }
+            for i in last_end..end {
+                if is_valid(i) {
+                    if !is_finite(i) {
+                        non_finite += 1;
+                        poisoned = true;
+                    }
+                    state.insert_one(x[i], y[i]);
+                }
+            }
+            (last_start, last_end) = (start, end);
+            if poisoned && non_finite == 0 {
+                state = PearsonState::default();
+                for i in (start..end).filter(|&i| is_valid(i)) {
+                    state.insert_one(x[i], y[i]);
+                }
+                poisoned = false;
+            }
+
+            if state.weight() < min_periods {
+                None
+            } else if non_finite > 0 {
+                // Like the two-pass formulation, any NaN or infinity in the window gives NaN.
+                (is_corr || state.weight() > ddof as f64).then_some(f64::NAN)
+            } else if is_corr {
+                Some(state.finalize())
+            } else {
+                state.finalize_cov(ddof)
+            }
+        })
+        .collect()
+}
+
+/// [`rolling_cov_corr`] over windows of a fixed number of rows.
+pub fn rolling_cov_corr_fixed(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    ddof: u8,
+    is_corr: bool,
+) -> PrimitiveArray<f64> {
+    let len = x.len();
+    let det_offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let windows = (0..len).map(|i| det_offsets_fn(i, window_size, len));
+    rolling_cov_corr(x, y, windows, min_periods, ddof, is_corr)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Two-pass covariance and correlation of the valid pairs.
+    fn brute_force(x: &[Option<f64>], y: &[Option<f64>], ddof: u8) -> (Option<f64>, f64) {
+        let pairs = x
+            .iter()
+            .zip(y)
+            .filter_map(|(x, y)| Some(((*x)?, (*y)?)))
+            .collect::<Vec<_>>();
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+        let dp = |f: &dyn Fn(&(f64, f64)) -> f64| pairs.iter().map(f).sum::<f64>();
+        let dp_xy = dp(&|p| (p.0 - mean_x) * (p.1 - mean_y));
+        let dp_xx = dp(&|p| (p.0 - mean_x) * (p.0 - mean_x));
+        let dp_yy = dp(&|p| (p.1 - mean_y) * (p.1 - mean_y));
+        let cov = (n > ddof as f64).then(|| dp_xy / (n - ddof as f64));
+        (cov, dp_xy / (dp_xx * dp_yy).sqrt())
+    }
+
+    fn collect_opt(arr: &PrimitiveArray<f64>) -> Vec<Option<f64>> {
+        arr.iter().map(|v| v.copied()).collect()
+    }
+
+    fn assert_close(a: Option<f64>, b: Option<f64>) {
+        match (a, b) {
+            (Some(a), Some(b)) if a.is_nan() || b.is_nan() => {
+                assert!(a.is_nan() && b.is_nan(), "{a} != {b}")
+            },
+            (Some(a), Some(b)) => assert!((a - b).abs() <= 1e-8 * b.abs().max(1.0), "{a} != {b}"),
+            (a, b) => assert_eq!(a, b),
+        }
+    }
+
+    #[test]
+    fn test_rolling_cov_corr_brute_force() {
+        // Large offset to expose cancellation in the naive sum-of-products formulation.
+        let x = (0..200)
+            .map(|i| (i % 13 != 0).then(|| 1e6 + ((i * 7) % 11) as f64))
+            .collect::<Vec<_>>();
+        let y = (0..200)
+            .map(|i| (i % 17 != 0).then(|| 1e6 + ((i * 5) % 23) as f64 * 0.5))
+            .collect::<Vec<_>>();
+        check_brute_force(&x, &y);
+
+        // Non-finite values make their windows NaN, windows after them must be exact again.
+        let mut x = x;
+        let mut y = y;
+        for (i, v) in [(5, f64::NAN), (60, f64::INFINITY), (61, f64::NEG_INFINITY)] {
+            x[i] = Some(v);
+        }
+        for (i, v) in [(7, f64::INFINITY), (120, f64::NAN), (190, f64::NAN)] {
+            y[i] = Some(v);
+        }
+        check_brute_force(&x, &y);
+    }
+
+    fn check_brute_force(x: &[Option<f64>], y: &[Option<f64>]) {
+        let x_arr = PrimitiveArray::from(x.to_vec());
+        let y_arr = PrimitiveArray::from(y.to_vec());
+
+        for (window_size, center) in [(1, false), (5, false), (30, true), (250, false)] {
+            let cov = rolling_cov_corr_fixed(&x_arr, &y_arr, window_size, 2, center, 1, false);
+            let corr = rolling_cov_corr_fixed(&x_arr, &y_arr, window_size, 2, center, 1, true);
+            let (cov, corr) = (collect_opt(&cov), collect_opt(&corr));
+            for i in 0..x.len() {
+                let (start, end) = if center {
+                    det_offsets_center(i, window_size, x.len())
+                } else {
+                    det_offsets(i, window_size, x.len())
+                };
+                let valid = (start..end)
+                    .filter(|&j| x[j].is_some() && y[j].is_some())
+                    .count();
+                let non_finite = (start..end).any(|j| match (x[j], y[j]) {
+                    (Some(x), Some(y)) => !x.is_finite() || !y.is_finite(),
+                    _ => false,
+                });
+                let (expected_cov, expected_corr) = brute_force(&x[start..end], &y[start..end], 1);
+                if valid < 2 {
+                    assert_eq!(cov[i], None);
+                    assert_eq!(corr[i], None);
+                } else {
+                    assert_close(cov[i], expected_cov);
+                    if non_finite || !expected_corr.is_nan() {
+                        assert_close(corr[i], Some(expected_corr));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_cov_corr_non_monotonic_windows() {
+        let x = PrimitiveArray::from_vec(vec![1.0, 2.0, 4.0, 8.0, 3.0]);
+        let y = PrimitiveArray::from_vec(vec![2.0, 1.0, 5.0, 7.0, 0.0]);
+        let windows = [(0, 3), (2, 5), (0, 2), (1, 1)];
+        let out = collect_opt(&rolling_cov_corr(&x, &y, windows, 1, 0, false));
+        for (i, (start, end)) in windows.into_iter().enumerate() {
+            let xs = x.values()[start..end]
+                .iter()
+                .map(|v| Some(*v))
+                .collect::<Vec<_>>();
+            let ys = y.values()[start..end]
+                .iter()
+                .map(|v| Some(*v))
+                .collect::<Vec<_>>();
+            let expected = if start == end {
+                None
+            } else {
+                brute_force(&xs, &ys, 0).0
+            };
+            assert_close(out[i], expected);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cov;
 mod min_max;
 pub mod moment;
 pub mod no_nulls;
@@ -6,6 +6,8 @@ use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::bitmask::BitMask;
 use arrow::types::NativeType;
 use num_traits::Zero;
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+use polars_utils::cpuid::is_avx512_enabled;
 
 macro_rules! wrapping_impl {
     ($trait_name:ident, $method:ident, $t:ty) => {
@@ -74,45 +76,95 @@ where
     }
 }
 
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn wrapping_sum_simd<T>(vals: &[T]) -> T
+where
+    T: NativeType + WrappingAdd + Zero,
+{
+    vals.iter()
+        .copied()
+        .fold(T::zero(), |a, b| a.wrapping_add(&b))
+}
+
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn wrapping_sum_with_mask_simd<T>(vals: &[T], mask: &BitMask) -> T
+where
+    T: NativeType + WrappingAdd + Zero + crate::SimdPrimitive,
+{
+    assert!(vals.len() == mask.len());
+    let remainder = vals.len() % STRIPE;
+    let (rest, main) = vals.split_at(remainder);
+    let (rest_mask, main_mask) = mask.split_at(remainder);
+    let zero: Simd<T, STRIPE> = Simd::default();
+
+    let vsum = main
+        .chunks_exact(STRIPE)
+        .enumerate()
+        .map(|(i, a)| {
+            let m: Mask<_, STRIPE> = main_mask.get_simd(i * STRIPE);
+            m.select(Simd::from_slice(a), zero)
+        })
+        .fold(zero, |a, b| {
+            let a = a.to_array();
+            let b = b.to_array();
+            Simd::from_array(std::array::from_fn(|i| a[i].wrapping_add(&b[i])))
+        });
+
+    let mainsum = vsum
+        .to_array()
+        .into_iter()
+        .fold(T::zero(), |a, b| a.wrapping_add(&b));
+
+    // TODO: faster remainder.
+    let restsum = wrapping_sum_with_mask_scalar(rest, &rest_mask);
+    mainsum.wrapping_add(&restsum)
+}
+
+// Same kernels as above compiled with AVX-512 enabled. The additions happen in the
+// same order, so results are bit-for-bit identical to the baseline kernels.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[target_feature(enable = "avx512f")]
+unsafe fn wrapping_sum_avx512<T>(vals: &[T]) -> T
+where
+    T: NativeType + WrappingAdd + Zero,
+{
+    wrapping_sum_simd(vals)
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[target_feature(enable = "avx512f")]
+unsafe fn wrapping_sum_with_mask_avx512<T>(vals: &[T], mask: &BitMask) -> T
+where
+    T: NativeType + WrappingAdd + Zero + crate::SimdPrimitive,
+{
+    wrapping_sum_with_mask_simd(vals, mask)
+}
+
 #[cfg(feature = "simd")]
 impl<T> WrappingSum for T
 where
     T: NativeType + WrappingAdd + Zero + crate::SimdPrimitive,
 {
     fn wrapping_sum(vals: &[Self]) -> Self {
-        vals.iter()
-            .copied()
-            .fold(T::zero(), |a, b| a.wrapping_add(&b))
+        #[cfg(target_arch = "x86_64")]
+        if is_avx512_enabled() {
+            // SAFETY: we checked that the CPU supports AVX-512.
+            return unsafe { wrapping_sum_avx512(vals) };
+        }
+
+        wrapping_sum_simd(vals)
     }
 
     fn wrapping_sum_with_validity(vals: &[Self], mask: &BitMask) -> Self {
-        assert!(vals.len() == mask.len());
-        let remainder = vals.len() % STRIPE;
-        let (rest, main) = vals.split_at(remainder);
-        let (rest_mask, main_mask) = mask.split_at(remainder);
-        let zero: Simd<T, STRIPE> = Simd::default();
-
-        let vsum = main
-            .chunks_exact(STRIPE)
-            .enumerate()
-            .map(|(i, a)| {
-                let m: Mask<_, STRIPE> = main_mask.get_simd(i * STRIPE);
-                m.select(Simd::from_slice(a), zero)
-            })
-            .fold(zero, |a, b| {
-                let a = a.to_array();
-                let b = b.to_array();
-                Simd::from_array(std::array::from_fn(|i| a[i].wrapping_add(&b[i])))
-            });
-
-        let mainsum = vsum
-            .to_array()
-            .into_iter()
-            .fold(T::zero(), |a, b| a.wrapping_add(&b));
-
-        // TODO: faster remainder.
-        let restsum = wrapping_sum_with_mask_scalar(rest, &rest_mask);
-        mainsum.wrapping_add(&restsum)
+        #[cfg(target_arch = "x86_64")]
+        if is_avx512_enabled() {
+            // SAFETY: we checked that the CPU supports AVX-512.
+            return unsafe { wrapping_sum_with_mask_avx512(vals, mask) };
+        }
+
+        wrapping_sum_with_mask_simd(vals, mask)
     }
 }
 
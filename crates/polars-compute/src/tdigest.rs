@@ -0,0 +1,411 @@
+//! # t-digest
+//!
+//! A mergeable sketch for approximate quantiles as described in
+//! "Computing Extremely Accurate Quantiles Using t-Digests", Ted Dunning and Otmar Ertl,
+//! arXiv:1902.04023.
+//!
+//! Values are summarized in weighted centroids. Centroids near the tails are kept small, so the
+//! relative error on extreme quantiles is much smaller than on the median. The number of
+//! centroids is bounded by the `compression` parameter.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+//!     digest.extend((0..=100).map(|v| v as f64));
+//!
+//!     let median = digest.quantile(0.5).unwrap();
+//!     assert!((median - 50.0).abs() < 1.0);
+//! ```
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+/// Upper bound on the compression, the buffer and centroids are allocated proportional to it.
+pub const MAX_COMPRESSION: f64 = 100_000.0;
+
+/// Version of the serialized format, bump this when the layout in [`TDigest::to_bytes`] changes.
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: usize = 1 + 3 * size_of::<f64>() + size_of::<u64>();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Merged centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values that are not yet merged into the centroids.
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    /// Creates a new, empty t-digest. A larger `compression` gives more accurate quantiles at the
+    /// cost of more memory.
+    pub fn new(compression: f64) -> Self {
+        assert!(
+            compression > 0.0 && compression <= MAX_COMPRESSION,
+            "t-digest compression must be in (0, {MAX_COMPRESSION}]"
+        );
+        Self {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Total number of values added to this t-digest.
+    pub fn count(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum::<f64>() + self.buffer.len() as f64
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize).saturating_mul(8).max(64)
+    }
+
+    /// Adds a value to the t-digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.buffer.push(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress(&[]);
+        }
+    }
+
+    /// Merge the other [`TDigest`] into this one.
+    ///
+    /// An empty t-digest takes over the compression of `other`.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.compression = other.compression;
+        }
+        let mut extra = other.centroids.clone();
+        extra.extend(
+            other
+                .buffer
+                .iter()
+                .map(|&mean| Centroid { mean, weight: 1.0 }),
+        );
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.compress(&extra);
+    }
+
+    /// Merges the buffered values and `extra` centroids into the centroids.
+    fn compress(&mut self, extra: &[Centroid]) {
+        if self.buffer.is_empty() && extra.is_empty() {
+            return;
+        }
+        for &v in &self.buffer {
+            self.min = self.min.min(v);
+            self.max = self.max.max(v);
+        }
+
+        let mut all = std::mem::take(&mut self.centroids);
+        all.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        all.extend_from_slice(extra);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+        let mut out = Vec::with_capacity(self.compression as usize);
+        let mut iter = all.into_iter();
+        let mut current = iter.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = total * self.k_to_q(self.q_to_k(0.0) + 1.0);
+
+        for next in iter {
+            if weight_so_far + current.weight + next.weight <= weight_limit {
+                let weight = current.weight + next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                weight_limit = total * self.k_to_q(self.q_to_k(weight_so_far / total) + 1.0);
+                out.push(current);
+                current = next;
+            }
+        }
+        out.push(current);
+        self.centroids = out;
+    }
+
+    /// The k1 scale function, which maps a quantile to an index in `[-δ/4, δ/4]`.
+    fn q_to_k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * std::f64::consts::PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_to_q(&self, k: f64) -> f64 {
+        if k >= self.compression / 4.0 {
+            return 1.0;
+        }
+        ((k * 2.0 * std::f64::consts::PI / self.compression).sin() + 1.0) / 2.0
+    }
+
+    /// Estimate the value at quantile `q`, which must be in `[0, 1]`.
+    ///
+    /// Returns `None` if no values were added.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.compress(&[]);
+        let centroids = &self.centroids;
+        let (first, last) = (centroids.first()?, centroids.last()?);
+        if q <= 0.0 {
+            return Some(self.min);
+        }
+        if q >= 1.0 {
+            return Some(self.max);
+        }
+        if centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let target = q * total;
+
+        // Interpolate between the extremes and the outer centroids.
+        if target < first.weight / 2.0 {
+            let frac = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * frac);
+        }
+        if target > total - last.weight / 2.0 {
+            let frac = (total - target) / (last.weight / 2.0);
+            return Some(self.max - (self.max - last.mean) * frac);
+        }
+
+        let mut cum_weight = first.weight / 2.0;
+        for w in centroids.windows(2) {
+            let (left, right) = (w[0], w[1]);
+            let step = (left.weight + right.weight) / 2.0;
+            if cum_weight + step >= target {
+                let frac = (target - cum_weight) / step;
+                return Some(left.mean + (right.mean - left.mean) * frac);
+            }
+            cum_weight += step;
+        }
+        Some(last.mean)
+    }
+
+    /// Serialize this t-digest, the result can be restored with [`TDigest::from_bytes`].
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        self.compress(&[]);
+        let mut out = Vec::with_capacity(HEADER_LEN + self.centroids.len() * 2 * size_of::<f64>());
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(&self.compression.to_le_bytes());
+        out.extend_from_slice(&self.min.to_le_bytes());
+        out.extend_from_slice(&self.max.to_le_bytes());
+        out.extend_from_slice(&(self.centroids.len() as u64).to_le_bytes());
+        for c in &self.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() >= HEADER_LEN,
+            ComputeError: "invalid t-digest: expected at least {} bytes, got {}", HEADER_LEN, bytes.len()
+        );
+        if bytes[0] != FORMAT_VERSION {
+            polars_bail!(ComputeError: "unsupported t-digest format version {}", bytes[0]);
+        }
+        let read_f64 = |offset: usize| {
+            f64::from_le_bytes(bytes[offset..offset + size_of::<f64>()].try_into().unwrap())
+        };
+        let compression = read_f64(1);
+        let min = read_f64(9);
+        let max = read_f64(17);
+        let len = u64::from_le_bytes(bytes[25..HEADER_LEN].try_into().unwrap());
+        let expected_len = usize::try_from(len)
+            .ok()
+            .and_then(|len| len.checked_mul(2 * size_of::<f64>()))
+            .and_then(|n| n.checked_add(HEADER_LEN));
+        polars_ensure!(
+            expected_len == Some(bytes.len()),
+            ComputeError: "invalid t-digest: {} centroids do not match a length of {} bytes", len, bytes.len()
+        );
+        polars_ensure!(
+            compression > 0.0 && compression <= MAX_COMPRESSION,
+            ComputeError: "invalid t-digest: compression {} is not in (0, {}]", compression, MAX_COMPRESSION
+        );
+        let len = len as usize;
+
+        let centroids: Vec<Centroid> = (0..len)
+            .map(|i| {
+                let offset = HEADER_LEN + i * 2 * size_of::<f64>();
+                Centroid {
+                    mean: read_f64(offset),
+                    weight: read_f64(offset + size_of::<f64>()),
+                }
+            })
+            .collect();
+        // Every centroid summarizes at least one value and lies within `[min, max]`, in order.
+        let valid = centroids
+            .iter()
+            .all(|c| c.weight.is_finite() && c.weight >= 1.0 && c.mean >= min && c.mean <= max)
+            && centroids.is_sorted_by(|a, b| a.mean <= b.mean);
+        polars_ensure!(valid, ComputeError: "invalid t-digest: malformed centroids");
+        Ok(Self {
+            compression,
+            centroids,
+            buffer: vec![],
+            min,
+            max,
+        })
+    }
+}
+
+impl Extend<f64> for TDigest {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for v in iter {
+            self.add(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::prelude::*;
+
+    use super::*;
+
+    /// Exponentially distributed values, which are heavily skewed to the right.
+    fn skewed_values(n: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(0);
+        (0..n)
+            .map(|_| -(1.0 - rng.r#gen::<f64>()).ln() * 10.0)
+            .collect()
+    }
+
+    /// Assert that the rank of `estimate` in the sorted values is within `max_err` of `q`.
+    fn assert_rank_error(sorted: &[f64], q: f64, estimate: f64, max_err: f64) {
+        let rank = sorted.partition_point(|v| *v < estimate) as f64 / sorted.len() as f64;
+        assert!(
+            (rank - q).abs() <= max_err,
+            "quantile {q}: estimate {estimate} has rank {rank}"
+        );
+    }
+
+    #[test]
+    fn test_tdigest_accuracy() {
+        let values = skewed_values(100_000);
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        digest.extend(values.iter().copied());
+        assert_eq!(digest.count(), values.len() as f64);
+
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let estimate = digest.quantile(q).unwrap();
+            // The error bound tightens towards the tails.
+            let max_err = 0.01 * (q * (1.0 - q)).sqrt().max(0.1);
+            assert_rank_error(&sorted, q, estimate, max_err);
+        }
+        assert_eq!(digest.quantile(0.0), Some(sorted[0]));
+        assert_eq!(digest.quantile(1.0), Some(sorted[sorted.len() - 1]));
+        assert!(digest.centroids.len() <= DEFAULT_COMPRESSION as usize);
+    }
+
+    #[test]
+    fn test_tdigest_merge_and_serialize() {
+        let values = skewed_values(50_000);
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut merged = TDigest::default();
+        for chunk in values.chunks(3_000) {
+            let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+            digest.extend(chunk.iter().copied());
+            let restored = TDigest::from_bytes(&digest.to_bytes()).unwrap();
+            merged.merge(&restored);
+        }
+        assert_eq!(merged.count(), values.len() as f64);
+
+        for q in [0.01, 0.1, 0.5, 0.9, 0.99] {
+            let estimate = merged.quantile(q).unwrap();
+            assert_rank_error(&sorted, q, estimate, 0.01);
+        }
+    }
+
+    #[test]
+    fn test_tdigest_edge_cases() {
+        let mut digest = TDigest::default();
+        assert_eq!(digest.quantile(0.5), None);
+        digest.add(f64::NAN);
+        assert!(digest.is_empty());
+        digest.add(3.0);
+        assert_eq!(digest.quantile(0.1), Some(3.0));
+
+        assert!(TDigest::from_bytes(&[1, 2, 3]).is_err());
+
+        // A centroid count whose byte size overflows is rejected instead of wrapping around.
+        let mut bytes = TDigest::default().to_bytes();
+        bytes[25..HEADER_LEN].copy_from_slice(&(1u64 << 60).to_le_bytes());
+        assert!(TDigest::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_tdigest_from_bytes_validation() {
+        let mut digest = TDigest::default();
+        digest.extend([1.0, 2.0, 3.0]);
+        let bytes = digest.to_bytes();
+        let centroid_weight = HEADER_LEN + size_of::<f64>();
+
+        // A huge compression would abort on allocating the buffer.
+        for compression in [1e300, f64::INFINITY, f64::NAN, 0.0, -1.0] {
+            let mut bytes = bytes.clone();
+            bytes[1..9].copy_from_slice(&compression.to_le_bytes());
+            assert!(TDigest::from_bytes(&bytes).is_err());
+        }
+
+        for weight in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let mut bytes = bytes.clone();
+            bytes[centroid_weight..centroid_weight + 8].copy_from_slice(&weight.to_le_bytes());
+            assert!(TDigest::from_bytes(&bytes).is_err());
+        }
+
+        // Means outside of `[min, max]`.
+        let mut out_of_range = bytes.clone();
+        out_of_range[HEADER_LEN..HEADER_LEN + 8].copy_from_slice(&10.0f64.to_le_bytes());
+        assert!(TDigest::from_bytes(&out_of_range).is_err());
+
+        // A truncated centroid.
+        assert!(TDigest::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut restored = TDigest::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.count(), 3.0);
+        assert_eq!(restored.quantile(0.0), Some(1.0));
+    }
+}
@@ -23,6 +23,8 @@ pub enum CastOptions {
     NonStrict,
     /// Allows wrapping overflow
     Overflowing,
+    /// Overflow is clamped to the bounds of the target type, NaN becomes null
+    Saturating,
 }
 
 impl CastOptions {
@@ -33,12 +35,9 @@ impl CastOptions {
 
 impl From<CastOptions> for CastOptionsImpl {
     fn from(value: CastOptions) -> Self {
-        let wrapped = match value {
-            CastOptions::Strict | CastOptions::NonStrict => false,
-            CastOptions::Overflowing => true,
-        };
         CastOptionsImpl {
-            wrapped,
+            wrapped: matches!(value, CastOptions::Overflowing),
+            saturating: matches!(value, CastOptions::Saturating),
             partial: false,
         }
     }
@@ -759,4 +758,60 @@ mod test {
             .unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    fn test_cast_overflow() -> PolarsResult<()> {
+        let ca = Int32Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(-200), Some(1), Some(300)],
+        );
+        let cast = |options| -> PolarsResult<Vec<Option<i8>>> {
+            Ok(ca
+                .cast_with_options(&DataType::Int8, options)?
+                .i8()?
+                .into_iter()
+                .collect())
+        };
+        assert_eq!(cast(CastOptions::NonStrict)?, [None, Some(1), None]);
+        assert_eq!(
+            cast(CastOptions::Overflowing)?,
+            [Some(56), Some(1), Some(44)]
+        );
+        assert_eq!(
+            cast(CastOptions::Saturating)?,
+            [Some(-128), Some(1), Some(127)]
+        );
+
+        let ca = Float64Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.5],
+        );
+        let out = ca.cast_with_options(&DataType::UInt8, CastOptions::Saturating)?;
+        assert_eq!(
+            out.u8()?.into_iter().collect::<Vec<_>>(),
+            [None, Some(255), Some(0), Some(1)]
+        );
+
+        #[cfg(feature = "dtype-decimal")]
+        {
+            let ca = Int32Chunked::new(PlSmallStr::from_static("a"), &[-2000, 1, 3000]);
+            let out = ca.cast_with_options(
+                &DataType::Decimal(Some(3), Some(0)),
+                CastOptions::Saturating,
+            )?;
+            assert_eq!(
+                out.cast(&DataType::Int32)?
+                    .i32()?
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                [Some(-999), Some(1), Some(999)]
+            );
+            let out = out.cast_with_options(&DataType::Int8, CastOptions::Saturating)?;
+            assert_eq!(
+                out.i8()?.into_iter().collect::<Vec<_>>(),
+                [Some(-128), Some(1), Some(127)]
+            );
+        }
+        Ok(())
+    }
 }
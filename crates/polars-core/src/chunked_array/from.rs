@@ -237,6 +237,41 @@ where
         ChunkedArray::new_with_compute_len(field, chunks)
     }
 
+    /// Get the chunk at `chunk_idx` as Arrow array.
+    ///
+    /// This returns the physical representation of the chunk and doesn't copy any data.
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx` is out of bounds.
+    pub fn to_arrow(&self, chunk_idx: usize) -> ArrayRef {
+        self.chunks[chunk_idx].clone()
+    }
+
+    /// Create a new unnamed [`ChunkedArray`] from Arrow arrays without copying them.
+    ///
+    /// Errors if an array is not the physical Arrow representation of `T`. Use
+    /// [`ChunkedArray::with_name`] to name the result.
+    pub fn from_arrow(arrays: Vec<ArrayRef>) -> PolarsResult<Self> {
+        let static_dtype = T::get_static_dtype();
+        let expected = static_dtype
+            .is_primitive()
+            .then(|| static_dtype.to_arrow(CompatLevel::newest()));
+
+        for arr in &arrays {
+            let matches = arr.as_any().is::<T::Array>()
+                && expected
+                    .as_ref()
+                    .is_none_or(|expected| arr.dtype() == expected);
+            polars_ensure!(
+                matches,
+                SchemaMismatch: "cannot create a {} ChunkedArray from an Arrow array of type {:?}",
+                static_dtype, arr.dtype()
+            );
+        }
+        // SAFETY: we checked that the Arrow arrays match `T`.
+        Ok(unsafe { Self::from_chunks(PlSmallStr::EMPTY, arrays) })
+    }
+
     pub fn full_null_like(ca: &Self, length: usize) -> Self {
         let chunks = std::iter::once(T::Array::full_null(
             length,
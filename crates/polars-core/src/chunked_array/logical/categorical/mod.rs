@@ -36,8 +36,8 @@ pub struct CategoricalChunked {
 
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
-        let name = self.physical().name();
-        Field::new(name.clone(), self.dtype().clone())
+        let field = self.physical().ref_field();
+        Field::new(field.name().clone(), self.dtype().clone()).with_metadata(field.metadata.clone())
     }
 
     pub fn is_empty(&self) -> bool {
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_unchecked;
+use arrow::datatypes::Metadata;
 use polars_compute::filter::filter_with_bitmap;
 
 use crate::prelude::*;
@@ -52,6 +53,8 @@ mod trusted_len;
 use std::slice::Iter;
 
 use arrow::legacy::prelude::*;
+#[cfg(feature = "random")]
+pub use random::StratifiedSpec;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::StructChunked;
 
@@ -477,7 +480,9 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     }
 
     pub(crate) unsafe fn set_dtype(&mut self, dtype: DataType) {
-        self.field = Arc::new(Field::new(self.name().clone(), dtype))
+        self.field = Arc::new(
+            Field::new(self.name().clone(), dtype).with_metadata(self.field.metadata.clone()),
+        )
     }
 
     /// Name of the [`ChunkedArray`].
@@ -492,7 +497,19 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
     /// Rename this [`ChunkedArray`].
     pub fn rename(&mut self, name: PlSmallStr) {
-        self.field = Arc::new(Field::new(name, self.field.dtype().clone()));
+        self.field = Arc::new(
+            Field::new(name, self.field.dtype().clone()).with_metadata(self.field.metadata.clone()),
+        );
+    }
+
+    /// Get the user metadata of this [`ChunkedArray`].
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.field.metadata.as_ref()
+    }
+
+    /// Set the user metadata of this [`ChunkedArray`].
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.field = Arc::new(self.field.as_ref().clone().with_metadata(metadata));
     }
 
     /// Return this [`ChunkedArray`] with a new name.
@@ -1026,6 +1043,21 @@ pub(crate) mod test {
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
 
+    #[test]
+    fn test_arrow_roundtrip() {
+        let mut a = get_chunked_array();
+        a.append(&get_chunked_array()).unwrap();
+        let arrays = (0..a.chunks().len())
+            .map(|i| a.to_arrow(i))
+            .collect::<Vec<_>>();
+        let b = Int32Chunked::from_arrow(arrays.clone()).unwrap();
+        assert_eq!(Vec::from(&a), Vec::from(&b));
+        assert_eq!(b.chunks().len(), 2);
+
+        assert!(Int64Chunked::from_arrow(arrays.clone()).is_err());
+        assert!(StringChunked::from_arrow(arrays).is_err());
+    }
+
     #[test]
     #[ignore]
     fn test_shrink_to_fit() {
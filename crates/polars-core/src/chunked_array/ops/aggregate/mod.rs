@@ -3,7 +3,7 @@ mod quantile;
 mod var;
 
 use arrow::types::NativeType;
-use num_traits::{Float, One, ToPrimitive, Zero};
+use num_traits::{CheckedMul, Float, One, ToPrimitive, Zero};
 use polars_compute::float_sum;
 use polars_compute::min_max::MinMaxKernel;
 use polars_compute::rolling::QuantileMethod;
@@ -283,6 +283,36 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedMul,
+{
+    /// Get the product of the non-null values as a [`Scalar`], or an error if it overflows.
+    pub fn checked_prod_reduce(&self) -> PolarsResult<Scalar> {
+        let mut prod = T::Native::one();
+        let mut overflowed = false;
+
+        for arr in self.downcast_iter() {
+            for v in arr.into_iter().flatten() {
+                if v.is_zero() {
+                    // The product is zero, even if an intermediate result overflowed.
+                    return Ok(Scalar::new(T::get_static_dtype(), T::Native::zero().into()));
+                }
+                match prod.checked_mul(v) {
+                    Some(p) => prod = p,
+                    None => overflowed = true,
+                }
+            }
+        }
+        polars_ensure!(
+            !overflowed,
+            ComputeError: "`product` of column '{}' overflows {}", self.name(), T::get_static_dtype()
+        );
+        Ok(Scalar::new(T::get_static_dtype(), prod.into()))
+    }
+}
+
 impl<T> VarAggSeries for ChunkedArray<T>
 where
     T: PolarsIntegerType,
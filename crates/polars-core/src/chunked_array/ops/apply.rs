@@ -591,3 +591,15 @@ impl StringChunked {
         out
     }
 }
+
+impl BinaryChunked {
+    /// # Safety
+    /// Update the views. All invariants of the views apply.
+    pub unsafe fn apply_views<F: FnMut(View, &[u8]) -> View + Copy>(&self, update_view: F) -> Self {
+        let mut out = self.clone();
+        for arr in out.downcast_iter_mut() {
+            *arr = arr.apply_views(update_view);
+        }
+        out
+    }
+}
@@ -196,6 +196,35 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         }
     }
 
+    /// Split the chunks of this ChunkedArray so that no chunk exceeds `chunk_size` rows. The
+    /// chunks are sliced, the underlying data is not copied.
+    ///
+    /// This is the complement of [`ChunkedArray::rechunk`], smaller chunks are left as is.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    pub fn rechunk_to_chunk_size(&self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+        if self.chunks.iter().all(|arr| arr.len() <= chunk_size) {
+            return self.clone();
+        }
+
+        let mut chunks = Vec::with_capacity(self.len() / chunk_size + self.chunks.len());
+        for arr in &self.chunks {
+            let mut offset = 0;
+            while arr.len() - offset > chunk_size {
+                chunks.push(arr.sliced(offset, chunk_size));
+                offset += chunk_size;
+            }
+            chunks.push(arr.sliced(offset, arr.len() - offset));
+        }
+
+        let mut ca = unsafe { self.copy_with_chunks(chunks) };
+        use StatisticsFlags as F;
+        ca.retain_flags_from(self, F::IS_SORTED_ANY | F::CAN_FAST_EXPLODE_LIST);
+        ca
+    }
+
     pub fn rechunk_validity(&self) -> Option<Bitmap> {
         if self.chunks.len() == 1 {
             return self.chunks[0].validity().cloned();
@@ -360,9 +389,22 @@ impl<T: PolarsObject> ObjectChunked<T> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
     use crate::prelude::*;
 
+    #[test]
+    fn test_rechunk_to_chunk_size() {
+        let mut ca = Int32Chunked::from_slice("a".into(), &[1, 2, 3, 4, 5]);
+        ca.append(&Int32Chunked::from_slice("a".into(), &[6]))
+            .unwrap();
+
+        let out = ca.rechunk_to_chunk_size(2);
+        let lengths = out.chunks().iter().map(|arr| arr.len()).collect::<Vec<_>>();
+        assert_eq!(lengths, [2, 2, 1, 1]);
+        assert_eq!(Vec::from(&out), Vec::from(&ca));
+
+        assert_eq!(ca.rechunk_to_chunk_size(10).chunks().len(), 2);
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_categorical_map_after_rechunk() {
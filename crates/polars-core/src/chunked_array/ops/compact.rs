@@ -0,0 +1,81 @@
+use crate::config::get_string_gc_threshold;
+use crate::prelude::*;
+
+impl BinaryChunked {
+    /// Rewrite the values that are still referenced into fresh data buffers, dropping
+    /// the original buffers.
+    pub fn compact(&self) -> Self {
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| arr.clone().gc().boxed())
+            .collect();
+        self.with_compacted_chunks(chunks)
+    }
+
+    /// Estimate the number of bytes held by the data buffers that are no longer referenced.
+    pub fn estimated_buffer_waste(&self) -> usize {
+        self.downcast_iter()
+            .map(|arr| arr.estimated_buffer_waste())
+            .sum()
+    }
+
+    /// Compact the chunks whose referenced bytes fall below the configured threshold.
+    pub(crate) fn maybe_compact(self) -> Self {
+        let threshold = get_string_gc_threshold();
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| arr.clone().gc_if_retained_below(threshold).boxed())
+            .collect();
+        self.with_compacted_chunks(chunks)
+    }
+
+    fn with_compacted_chunks(&self, chunks: Vec<ArrayRef>) -> Self {
+        // SAFETY: garbage collection does not change the dtype of the chunks.
+        let mut out = unsafe { self.copy_with_chunks(chunks) };
+        out.set_flags(self.get_flags());
+        out
+    }
+}
+
+impl StringChunked {
+    /// Rewrite the strings that are still referenced into fresh data buffers, dropping
+    /// the original buffers.
+    pub fn compact(&self) -> Self {
+        unsafe { self.as_binary().compact().to_string_unchecked() }
+    }
+
+    /// Estimate the number of bytes held by the data buffers that are no longer referenced.
+    pub fn estimated_buffer_waste(&self) -> usize {
+        self.as_binary().estimated_buffer_waste()
+    }
+
+    pub(crate) fn maybe_compact(self) -> Self {
+        unsafe { self.as_binary().maybe_compact().to_string_unchecked() }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_string_compact() {
+        let values: Vec<String> = (0..10_000)
+            .map(|i| format!("a long string value {i}"))
+            .collect();
+        let ca = StringChunked::from_iter_values("a".into(), values.iter().map(|s| s.as_str()));
+        assert_eq!(ca.estimated_buffer_waste(), 0);
+
+        let mask: BooleanChunked = (0..10_000).map(|i| i % 100 == 0).collect();
+        let filtered = ca.filter(&mask).unwrap();
+        assert_eq!(filtered.len(), 100);
+        // The filter compacts by default, as only 1% of the bytes are retained.
+        assert_eq!(filtered.estimated_buffer_waste(), 0);
+
+        let sliced = ca.slice(0, 10);
+        assert!(sliced.estimated_buffer_waste() > 0);
+        let compacted = sliced.compact();
+        assert_eq!(compacted.estimated_buffer_waste(), 0);
+        assert!(compacted.iter().eq(sliced.iter()));
+    }
+}
@@ -88,6 +88,76 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Append `values` to this array, with `validity` marking which of them are valid.
+    ///
+    /// The values are written into the last chunk if its memory is not shared, otherwise they
+    /// are added as a new chunk. This never copies the existing data.
+    pub fn extend_from_slice(
+        &mut self,
+        values: &[T::Native],
+        validity: Option<&[bool]>,
+    ) -> PolarsResult<()> {
+        if let Some(validity) = validity {
+            polars_ensure!(
+                validity.len() == values.len(),
+                ShapeMismatch: "validity length {} does not match values length {}",
+                validity.len(), values.len()
+            );
+        }
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        // Pop the last chunk, so that we may hold the only reference to it.
+        let last = self.chunks.pop().map(|arr| {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<T::Native>>()
+                .unwrap()
+                .clone()
+        });
+        let mutable = match last {
+            Some(arr) if !arr.values().is_sliced() => match arr.into_mut() {
+                Either::Left(immutable) => {
+                    self.chunks.push(Box::new(immutable));
+                    None
+                },
+                Either::Right(mutable) => Some(mutable),
+            },
+            Some(arr) => {
+                self.chunks.push(Box::new(arr));
+                None
+            },
+            None => None,
+        };
+
+        let arr = match mutable {
+            Some(mut mutable) => {
+                match validity {
+                    None => mutable.extend_from_slice(values),
+                    Some(validity) => {
+                        mutable.reserve(values.len());
+                        for (v, &is_valid) in values.iter().zip(validity) {
+                            mutable.push(is_valid.then_some(*v));
+                        }
+                    },
+                }
+                PrimitiveArray::from(mutable)
+            },
+            None => PrimitiveArray::from_vec(values.to_vec())
+                .with_validity(validity.map(|v| v.iter().copied().collect())),
+        };
+        self.chunks.push(Box::new(arr));
+
+        self.compute_len();
+        self.set_sorted_flag(IsSorted::Not);
+        Ok(())
+    }
+}
+
 #[doc(hidden)]
 impl StringChunked {
     pub fn extend(&mut self, other: &Self) -> PolarsResult<()> {
@@ -216,6 +286,32 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[allow(clippy::redundant_clone)]
+    fn test_extend_from_slice() -> PolarsResult<()> {
+        let mut values = Vec::with_capacity(32);
+        values.extend_from_slice(&[1, 2, 3]);
+        let mut ca = Int32Chunked::from_vec(PlSmallStr::from_static("a"), values);
+        let location = ca.cont_slice().unwrap().as_ptr() as usize;
+
+        ca.extend_from_slice(&[4, 5], None)?;
+        assert_eq!(ca.chunks().len(), 1);
+        assert_eq!(ca.cont_slice().unwrap().as_ptr() as usize, location);
+
+        // A shared chunk is left alone, the values go in a new chunk.
+        let _temp = ca.chunks.clone();
+        ca.extend_from_slice(&[6, 7], Some(&[true, false]))?;
+        assert_eq!(ca.chunks().len(), 2);
+        assert_eq!(ca.null_count(), 1);
+        assert_eq!(
+            Vec::from(&ca),
+            [Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), None]
+        );
+
+        assert!(ca.extend_from_slice(&[8], Some(&[])).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_extend_string() -> PolarsResult<()> {
         let mut ca = StringChunked::new(PlSmallStr::from_static("a"), &["a", "b", "c"]);
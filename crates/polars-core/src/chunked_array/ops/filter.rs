@@ -78,7 +78,7 @@ impl ChunkFilter<BinaryType> for BinaryChunked {
             };
         }
         check_filter_len!(self, filter);
-        Ok(unsafe {
+        let out = unsafe {
             arity::binary_unchecked_same_type(
                 self,
                 filter,
@@ -86,7 +86,8 @@ impl ChunkFilter<BinaryType> for BinaryChunked {
                 true,
                 true,
             )
-        })
+        };
+        Ok(out.maybe_compact())
     }
 }
 
@@ -247,7 +247,8 @@ impl ChunkTakeUnchecked<IdxCa> for BinaryChunked {
             }
         });
 
-        let mut out = ChunkedArray::from_chunks(ca.name().clone(), chunks.collect());
+        let mut out =
+            BinaryChunked::from_chunks(ca.name().clone(), chunks.collect()).maybe_compact();
         let sorted_flag = _update_gather_sorted_flag(ca.is_sorted_flag(), indices.is_sorted_flag());
         out.set_sorted_flag(sorted_flag);
         out
@@ -283,7 +284,8 @@ impl ChunkTakeUnchecked<IdxCa> for StringChunked {
             }
         });
 
-        let mut out = ChunkedArray::from_chunks(ca.name().clone(), chunks.collect());
+        let mut out =
+            StringChunked::from_chunks(ca.name().clone(), chunks.collect()).maybe_compact();
         let sorted_flag = _update_gather_sorted_flag(ca.is_sorted_flag(), indices.is_sorted_flag());
         out.set_sorted_flag(sorted_flag);
         out
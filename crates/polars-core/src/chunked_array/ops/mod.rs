@@ -16,6 +16,7 @@ mod bits;
 #[cfg(feature = "bitwise")]
 mod bitwise_reduce;
 pub(crate) mod chunkops;
+mod compact;
 pub(crate) mod compare_inner;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
@@ -251,12 +252,16 @@ pub trait ChunkAgg<T> {
 
     fn _sum_as_f64(&self) -> f64;
 
+    /// Returns the minimum value in the array, according to the natural order.
+    /// Returns `None` if the array is empty or only contains null values.
+    /// `NaN` values are skipped like nulls, unless all non-null values are `NaN`.
     fn min(&self) -> Option<T> {
         None
     }
 
     /// Returns the maximum value in the array, according to the natural order.
     /// Returns `None` if the array is empty or only contains null values.
+    /// `NaN` values are skipped like nulls, unless all non-null values are `NaN`.
     fn max(&self) -> Option<T> {
         None
     }
@@ -12,10 +12,57 @@ use crate::POOL;
 use crate::prelude::*;
 use crate::utils::_split_offsets;
 
-pub fn encode_rows_vertical_par_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked> {
-    let n_threads = POOL.current_num_threads();
+/// The `(offset, len)` splits to encode the rows of `by` in parallel.
+///
+/// If the columns share their chunk boundaries, the splits follow the chunks so that no column
+/// has to be rechunked to encode a split.
+fn row_encoding_splits(by: &[Column], n_threads: usize) -> Vec<(usize, usize)> {
     let len = by[0].len();
-    let splits = _split_offsets(len, n_threads);
+    let mut chunk_lengths = by
+        .iter()
+        .filter_map(|c| c.as_series())
+        .map(|s| s.chunk_lengths());
+    if let Some(first) = chunk_lengths.next() {
+        let first = first.collect::<Vec<_>>();
+        if len > 0
+            && first.len() > 1
+            && chunk_lengths.all(|lengths| lengths.eq(first.iter().copied()))
+        {
+            let mut offset = 0;
+            return first
+                .into_iter()
+                .filter(|&len| len > 0)
+                .map(|len| {
+                    offset += len;
+                    (offset - len, len)
+                })
+                .collect();
+        }
+    }
+    _split_offsets(len, n_threads)
+}
+
+/// Encode the rows of `by` split by chunk, if the columns share their chunk boundaries.
+pub fn encode_rows_vertical_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked> {
+    let chunks = row_encoding_splits(by, 1)
+        .into_iter()
+        .map(|(offset, len)| {
+            let sliced = by
+                .iter()
+                .map(|s| s.slice(offset as i64, len))
+                .collect::<Vec<_>>();
+            Ok(_get_rows_encoded_unordered(&sliced)?.into_array())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(BinaryOffsetChunked::from_chunk_iter(
+        PlSmallStr::EMPTY,
+        chunks,
+    ))
+}
+
+pub fn encode_rows_vertical_par_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked> {
+    let splits = row_encoding_splits(by, POOL.current_num_threads());
 
     let chunks = splits.into_par_iter().map(|(offset, len)| {
         let sliced = by
@@ -37,9 +84,7 @@ pub fn encode_rows_vertical_par_unordered(by: &[Column]) -> PolarsResult<BinaryO
 pub fn encode_rows_vertical_par_unordered_broadcast_nulls(
     by: &[Column],
 ) -> PolarsResult<BinaryOffsetChunked> {
-    let n_threads = POOL.current_num_threads();
-    let len = by[0].len();
-    let splits = _split_offsets(len, n_threads);
+    let splits = row_encoding_splits(by, POOL.current_num_threads());
 
     let chunks = splits.into_par_iter().map(|(offset, len)| {
         let sliced = by
@@ -1,10 +1,12 @@
 use num_traits::{Float, NumCast};
 use polars_error::to_compute_err;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
 use rand::seq::index::IndexVec;
 use rand_distr::{Normal, Standard, StandardNormal, Uniform};
 
+use crate::hashing::columns_to_hashes;
 use crate::prelude::DataType::Float64;
 use crate::prelude::*;
 use crate::random::get_global_random_u64;
@@ -239,6 +241,92 @@ impl DataFrame {
     }
 }
 
+/// How many rows [`DataFrame::sample_stratified`] takes from every group.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StratifiedSpec {
+    /// Take `n` rows per group. Without replacement this is capped at the group size.
+    N(usize),
+    /// Take a fraction of the rows of every group.
+    Fraction(f64),
+}
+
+impl StratifiedSpec {
+    fn group_sample_size(&self, len: usize, with_replacement: bool) -> usize {
+        match self {
+            StratifiedSpec::N(n) if with_replacement => *n,
+            StratifiedSpec::N(n) => (*n).min(len),
+            StratifiedSpec::Fraction(frac) => (len as f64 * frac) as usize,
+        }
+    }
+}
+
+impl DataFrame {
+    /// Sample the rows of every group formed by the columns `by`.
+    ///
+    /// The seed of every group is derived from `seed` and the hash of its key, so the rows
+    /// that are taken from a group don't depend on the order in which the groups are found.
+    /// The groups are returned in order of first appearance.
+    pub fn sample_stratified(
+        &self,
+        by: &[&str],
+        spec: StratifiedSpec,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        if let StratifiedSpec::Fraction(frac) = spec {
+            polars_ensure!(
+                frac >= 0.0 && (with_replacement || frac <= 1.0),
+                ShapeMismatch:
+                "cannot take a larger sample than the total population when `with_replacement=false`"
+            );
+        }
+        let gb = self.group_by_stable(by.iter().copied())?;
+        let groups = gb.get_groups();
+
+        // Hash the key of every group with a fixed seed.
+        let firsts = groups.iter().map(|g| g.first()).collect::<Vec<_>>();
+        // SAFETY: the group indices are within bounds.
+        let keys = unsafe {
+            self.select(by.iter().copied())?
+                .take_slice_unchecked(&firsts)
+        };
+        let mut hashes = vec![];
+        if !firsts.is_empty() {
+            columns_to_hashes(
+                keys.get_columns(),
+                Some(PlSeedableRandomStateQuality::seed_from_u64(0)),
+                &mut hashes,
+            )?;
+        }
+
+        let seed = seed.unwrap_or_else(get_global_random_u64);
+        let mut idx = Vec::with_capacity(match spec {
+            StratifiedSpec::N(n) => n.saturating_mul(groups.len()).min(self.height()),
+            StratifiedSpec::Fraction(_) => 0,
+        });
+        for (g, hash) in groups.iter().zip(hashes) {
+            let len = g.len();
+            let n = spec.group_sample_size(len, with_replacement);
+            let group_idx = match with_replacement {
+                true => create_rand_index_with_replacement(n, len, Some(seed ^ hash)),
+                false => create_rand_index_no_replacement(n, len, Some(seed ^ hash), shuffle),
+            };
+            let group_idx = group_idx.into_no_null_iter();
+            match g {
+                GroupsIndicator::Idx((_, all)) => {
+                    idx.extend(group_idx.map(|i| all[i as usize]));
+                },
+                GroupsIndicator::Slice([first, _]) => {
+                    idx.extend(group_idx.map(|i| first + i));
+                },
+            }
+        }
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_slice_unchecked(&idx) })
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -390,4 +478,52 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_stratified() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b", "c"],
+            "v" => [0, 1, 2, 3, 4, 5]
+        ]?;
+
+        let out = df.sample_stratified(&["g"], StratifiedSpec::N(2), false, false, Some(0))?;
+        let g = out.column("g")?.str()?;
+        assert_eq!(
+            g.into_no_null_iter().collect::<Vec<_>>(),
+            ["a", "a", "b", "b", "c"]
+        );
+
+        let out = df.sample_stratified(&["g"], StratifiedSpec::N(3), true, false, Some(0))?;
+        assert_eq!(out.height(), 9);
+
+        // The sample of a group does not depend on the order of the groups.
+        let reordered = df![
+            "g" => ["b", "c", "b", "a", "a", "a"],
+            "v" => [3, 5, 4, 0, 1, 2]
+        ]?;
+        let a =
+            df.sample_stratified(&["g"], StratifiedSpec::Fraction(0.7), false, true, Some(7))?;
+        let b = reordered.sample_stratified(
+            &["g"],
+            StratifiedSpec::Fraction(0.7),
+            false,
+            true,
+            Some(7),
+        )?;
+        let a = a.sort(
+            ["g"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )?;
+        let b = b.sort(
+            ["g"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )?;
+        assert!(a.equals(&b));
+
+        assert!(
+            df.sample_stratified(&["g"], StratifiedSpec::Fraction(1.5), false, false, None)
+                .is_err()
+        );
+        Ok(())
+    }
 }
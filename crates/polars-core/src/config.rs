@@ -1,3 +1,7 @@
+use std::sync::LazyLock;
+
+use polars_error::polars_warn;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -63,6 +67,31 @@ pub fn get_rg_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(get_file_prefetch_size(), 128))
 }
 
+/// Fraction of the string data buffers that must still be referenced after a filter or
+/// gather, below which the surviving strings are compacted into fresh buffers.
+///
+/// Read once from `POLARS_STRING_GC_THRESHOLD`; an invalid value falls back to the default.
+pub fn get_string_gc_threshold() -> f64 {
+    const DEFAULT: f64 = 0.25;
+    static THRESHOLD: LazyLock<f64> = LazyLock::new(|| {
+        let Ok(value) = std::env::var("POLARS_STRING_GC_THRESHOLD") else {
+            return DEFAULT;
+        };
+        match value.parse::<f64>() {
+            Ok(threshold) if (0.0..=1.0).contains(&threshold) => threshold,
+            _ => {
+                polars_warn!(
+                    "invalid POLARS_STRING_GC_THRESHOLD {:?}, expected a fraction between 0 and 1; using {}",
+                    value,
+                    DEFAULT
+                );
+                DEFAULT
+            },
+        }
+    });
+    *THRESHOLD
+}
+
 pub fn force_async() -> bool {
     std::env::var("POLARS_FORCE_ASYNC")
         .map(|value| value == "1")
@@ -1075,6 +1075,28 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    /// Broadcast this value to a [`Series`] of length `len`.
+    pub fn into_series(self, name: PlSmallStr, len: usize) -> PolarsResult<Series> {
+        let s = match self {
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::Categorical(idx, rev_map, _) | AnyValue::Enum(idx, rev_map, _) => {
+                let is_enum = matches!(self, AnyValue::Enum(..));
+                categorical_series(name, idx, Arc::new(rev_map.clone()), is_enum)
+            },
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::CategoricalOwned(idx, ref rev_map, _)
+            | AnyValue::EnumOwned(idx, ref rev_map, _) => {
+                let is_enum = matches!(self, AnyValue::EnumOwned(..));
+                categorical_series(name, idx, rev_map.clone(), is_enum)
+            },
+            av => {
+                let dtype = av.dtype();
+                Series::from_any_values_and_dtype(name, &[av], &dtype, true)?
+            },
+        };
+        Ok(s.new_from_index(0, len))
+    }
+
     /// Try to coerce to an AnyValue with static lifetime.
     /// This can be done if it does not borrow any values.
     #[inline]
@@ -1372,10 +1394,34 @@ impl AnyValue<'_> {
                 is_equal
             },
 
+            // Mixed numeric types are equal if they compare as equal, see `PartialOrd`.
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l_v, l_s), r) if r.to_i128().is_some() => {
+                Decimal(*l_v, *l_s).eq_missing(&Decimal(r.to_i128().unwrap(), 0), null_equal)
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (l, Decimal(r_v, r_s)) if l.to_i128().is_some() => {
+                Decimal(l.to_i128().unwrap(), 0).eq_missing(&Decimal(*r_v, *r_s), null_equal)
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l_v, l_s), r) if r.to_f64().is_some() => {
+                decimal_to_f64(*l_v, *l_s).to_total_ord() == r.to_f64().unwrap().to_total_ord()
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (l, Decimal(r_v, r_s)) if l.to_f64().is_some() => {
+                l.to_f64().unwrap().to_total_ord() == decimal_to_f64(*r_v, *r_s).to_total_ord()
+            },
+
             (l, r) if l.to_i128().is_some() && r.to_i128().is_some() => l.to_i128() == r.to_i128(),
             (l, r) if l.to_f64().is_some() && r.to_f64().is_some() => {
                 l.to_f64().unwrap().to_total_ord() == r.to_f64().unwrap().to_total_ord()
             },
+            (l, r) if l.to_i128().is_some() && r.to_f64().is_some() => {
+                cmp_int_float(l.to_i128().unwrap(), r.to_f64().unwrap()).is_eq()
+            },
+            (l, r) if l.to_f64().is_some() && r.to_i128().is_some() => {
+                cmp_int_float(r.to_i128().unwrap(), l.to_f64().unwrap()).is_eq()
+            },
 
             (_, _) => {
                 unimplemented!(
@@ -1394,7 +1440,7 @@ impl PartialEq for AnyValue<'_> {
 }
 
 impl PartialOrd for AnyValue<'_> {
-    /// Only implemented for the same types and physical types!
+    /// Only implemented for the same types and physical types, and between numeric types!
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         use AnyValue::*;
         match (self, &other) {
@@ -1511,7 +1557,8 @@ impl PartialOrd for AnyValue<'_> {
                     })() {
                         lhs.partial_cmp(r_v)
                     } else {
-                        Some(Ordering::Greater)
+                        // The scaled lhs is out of range, so it is beyond any rhs in its direction.
+                        Some(l_v.signum().cmp(&0))
                     }
                 } else {
                     // l_v <=> r_v * 10**(l_s - r_s)
@@ -1521,10 +1568,39 @@ impl PartialOrd for AnyValue<'_> {
                     })() {
                         l_v.partial_cmp(&rhs)
                     } else {
-                        Some(Ordering::Less)
+                        Some(0.cmp(&r_v.signum()))
                     }
                 }
             },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l_v, l_s), r) if r.to_i128().is_some() => {
+                Decimal(*l_v, *l_s).partial_cmp(&Decimal(r.to_i128().unwrap(), 0))
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (l, Decimal(r_v, r_s)) if l.to_i128().is_some() => {
+                Decimal(l.to_i128().unwrap(), 0).partial_cmp(&Decimal(*r_v, *r_s))
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l_v, l_s), r) if r.to_f64().is_some() => {
+                Some(decimal_to_f64(*l_v, *l_s).tot_cmp(&r.to_f64().unwrap()))
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (l, Decimal(r_v, r_s)) if l.to_f64().is_some() => {
+                Some(l.to_f64().unwrap().tot_cmp(&decimal_to_f64(*r_v, *r_s)))
+            },
+
+            (l, r) if l.to_i128().is_some() && r.to_i128().is_some() => {
+                l.to_i128().partial_cmp(&r.to_i128())
+            },
+            (l, r) if l.to_f64().is_some() && r.to_f64().is_some() => {
+                Some(l.to_f64().unwrap().tot_cmp(&r.to_f64().unwrap()))
+            },
+            (l, r) if l.to_i128().is_some() && r.to_f64().is_some() => {
+                Some(cmp_int_float(l.to_i128().unwrap(), r.to_f64().unwrap()))
+            },
+            (l, r) if l.to_f64().is_some() && r.to_i128().is_some() => {
+                Some(cmp_int_float(r.to_i128().unwrap(), l.to_f64().unwrap()).reverse())
+            },
 
             (_, _) => {
                 unimplemented!(
@@ -1535,6 +1611,212 @@ impl PartialOrd for AnyValue<'_> {
     }
 }
 
+/// Compare an integer with a float exactly, where NaN is greater than any integer.
+fn cmp_int_float(int: i128, float: f64) -> Ordering {
+    // 2^127, the smallest float that is out of range of an i128.
+    const I128_BOUND: f64 = 170141183460469231731687303715884105728.0;
+    if float.is_nan() || float >= I128_BOUND {
+        return Ordering::Less;
+    }
+    if float < -I128_BOUND {
+        return Ordering::Greater;
+    }
+    let trunc = float.trunc();
+    // Any remaining fraction breaks the tie, in the direction of its sign.
+    int.cmp(&(trunc as i128))
+        .then_with(|| 0f64.tot_cmp(&(float - trunc)))
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn decimal_to_f64(v: i128, scale: usize) -> f64 {
+    v as f64 / 10f64.powi(scale as i32)
+}
+
+/// Groups of values that are coerced to a common type by [`AnyValue::total_cmp`], in the order
+/// in which the groups sort relative to each other.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TotalCmpClass {
+    Null,
+    Boolean,
+    Numeric,
+    String,
+    Binary,
+    #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+    Temporal,
+    #[cfg(feature = "dtype-duration")]
+    Duration,
+    #[cfg(feature = "dtype-time")]
+    Time,
+    Sequence,
+    #[cfg(feature = "dtype-struct")]
+    Struct,
+    #[cfg(feature = "object")]
+    Object,
+}
+
+impl AnyValue<'_> {
+    /// Compare two values, coercing them to a common type first.
+    ///
+    /// Unlike [`PartialOrd`], which only orders values of the same type, this is a total order
+    /// over all values, in the spirit of [`f64::total_cmp`]. Values are coerced as follows:
+    ///
+    /// * `Null` is less than any other value.
+    /// * Integers and decimals are compared exactly, regardless of their width, signedness or
+    ///   scale. Any comparison involving a float is done on `f64`, where NaN is equal to NaN and
+    ///   greater than any other number.
+    /// * Categorical and enum values are compared by their string value, also with strings.
+    /// * Dates and datetimes are compared as points in time, regardless of their time unit.
+    ///   Time zones are ignored, as datetimes are stored as UTC timestamps.
+    /// * Durations are compared regardless of their time unit.
+    /// * Lists, arrays and structs are compared lexicographically by their elements.
+    /// * Objects are all equal to each other.
+    ///
+    /// Values that can't be coerced to a common type are ordered by type: booleans, numbers,
+    /// strings, binary, dates and datetimes, durations, times, lists and arrays, structs, objects.
+    pub fn total_cmp(&self, other: &AnyValue) -> Ordering {
+        use AnyValue::*;
+
+        let class = self.total_cmp_class();
+        let other_class = other.total_cmp_class();
+        if class != other_class {
+            return class.cmp(&other_class);
+        }
+
+        match class {
+            TotalCmpClass::Null => Ordering::Equal,
+            TotalCmpClass::Boolean => self.extract_bool().cmp(&other.extract_bool()),
+            TotalCmpClass::Numeric => {
+                if let (Some(l), Some(r)) = (self.to_i128(), other.to_i128()) {
+                    return l.cmp(&r);
+                }
+                #[cfg(feature = "dtype-decimal")]
+                {
+                    let to_decimal = |av: &AnyValue| match av {
+                        Decimal(v, scale) => Some((*v, *scale)),
+                        av => Some((av.to_i128()?, 0)),
+                    };
+                    if let (Some((l, l_s)), Some((r, r_s))) = (to_decimal(self), to_decimal(other))
+                    {
+                        return Decimal(l, l_s).partial_cmp(&Decimal(r, r_s)).unwrap();
+                    }
+                }
+                let l = self.extract::<f64>().unwrap();
+                let r = other.extract::<f64>().unwrap();
+                l.tot_cmp(&r)
+            },
+            TotalCmpClass::String => self.get_str().cmp(&other.get_str()),
+            TotalCmpClass::Binary => self.extract_bytes().cmp(&other.extract_bytes()),
+            #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+            TotalCmpClass::Temporal => self.temporal_ns().cmp(&other.temporal_ns()),
+            #[cfg(feature = "dtype-duration")]
+            TotalCmpClass::Duration => self.temporal_ns().cmp(&other.temporal_ns()),
+            #[cfg(feature = "dtype-time")]
+            TotalCmpClass::Time => self.temporal_ns().cmp(&other.temporal_ns()),
+            TotalCmpClass::Sequence => {
+                let series = |av: &AnyValue| match av {
+                    List(s) => s.clone(),
+                    #[cfg(feature = "dtype-array")]
+                    Array(s, _) => s.clone(),
+                    _ => unreachable!(),
+                };
+                let (l, r) = (series(self), series(other));
+                let n = l.len().min(r.len());
+                (0..n)
+                    .map(|i| l.get(i).unwrap().total_cmp(&r.get(i).unwrap()))
+                    .find(|o| o.is_ne())
+                    .unwrap_or_else(|| l.len().cmp(&r.len()))
+            },
+            #[cfg(feature = "dtype-struct")]
+            TotalCmpClass::Struct => {
+                let (l, r) = (self.struct_values(), other.struct_values());
+                l.iter()
+                    .zip(r.iter())
+                    .map(|(l, r)| l.total_cmp(r))
+                    .find(|o| o.is_ne())
+                    .unwrap_or_else(|| l.len().cmp(&r.len()))
+            },
+            #[cfg(feature = "object")]
+            TotalCmpClass::Object => Ordering::Equal,
+        }
+    }
+
+    fn total_cmp_class(&self) -> TotalCmpClass {
+        use AnyValue::*;
+        match self {
+            Null => TotalCmpClass::Null,
+            Boolean(_) => TotalCmpClass::Boolean,
+            UInt8(_) | UInt16(_) | UInt32(_) | UInt64(_) | Int8(_) | Int16(_) | Int32(_)
+            | Int64(_) | Int128(_) | Float32(_) | Float64(_) => TotalCmpClass::Numeric,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(..) => TotalCmpClass::Numeric,
+            String(_) | StringOwned(_) => TotalCmpClass::String,
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(..) | CategoricalOwned(..) | Enum(..) | EnumOwned(..) => {
+                TotalCmpClass::String
+            },
+            Binary(_) | BinaryOwned(_) => TotalCmpClass::Binary,
+            #[cfg(feature = "dtype-date")]
+            Date(_) => TotalCmpClass::Temporal,
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(..) | DatetimeOwned(..) => TotalCmpClass::Temporal,
+            #[cfg(feature = "dtype-duration")]
+            Duration(..) => TotalCmpClass::Duration,
+            #[cfg(feature = "dtype-time")]
+            Time(_) => TotalCmpClass::Time,
+            List(_) => TotalCmpClass::Sequence,
+            #[cfg(feature = "dtype-array")]
+            Array(..) => TotalCmpClass::Sequence,
+            #[cfg(feature = "dtype-struct")]
+            Struct(..) | StructOwned(_) => TotalCmpClass::Struct,
+            #[cfg(feature = "object")]
+            Object(_) | ObjectOwned(_) => TotalCmpClass::Object,
+        }
+    }
+
+    /// Nanoseconds since the epoch (dates, datetimes), since midnight (times) or of a duration.
+    #[cfg(any(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration",
+        feature = "dtype-time"
+    ))]
+    fn temporal_ns(&self) -> Option<i128> {
+        #[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+        let scale = |v: i64, tu: &TimeUnit| {
+            (v as i128)
+                * match tu {
+                    TimeUnit::Nanoseconds => 1,
+                    TimeUnit::Microseconds => 1_000,
+                    TimeUnit::Milliseconds => 1_000_000,
+                }
+        };
+        match self {
+            #[cfg(feature = "dtype-date")]
+            AnyValue::Date(v) => Some((*v as i128) * 86_400_000_000_000),
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::Datetime(v, tu, _) | AnyValue::DatetimeOwned(v, tu, _) => Some(scale(*v, tu)),
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, tu) => Some(scale(*v, tu)),
+            #[cfg(feature = "dtype-time")]
+            AnyValue::Time(v) => Some(*v as i128),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn struct_values(&self) -> Vec<AnyValue<'static>> {
+        match self {
+            AnyValue::Struct(idx, arr, fields) => struct_to_avs_static(*idx, arr, fields),
+            AnyValue::StructOwned(payload) => payload
+                .0
+                .iter()
+                .map(|av| av.clone().into_static())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
 impl TotalEq for AnyValue<'_> {
     #[inline]
     fn tot_eq(&self, other: &Self) -> bool {
@@ -1769,9 +2051,28 @@ impl From<bool> for AnyValue<'static> {
     }
 }
 
+#[cfg(feature = "dtype-categorical")]
+fn categorical_series(
+    name: PlSmallStr,
+    idx: u32,
+    rev_map: Arc<RevMapping>,
+    is_enum: bool,
+) -> Series {
+    let cats = UInt32Chunked::from_slice(name, &[idx]);
+    // SAFETY: the index was taken from a value with this rev map.
+    unsafe {
+        CategoricalChunked::from_cats_and_rev_map_unchecked(
+            cats,
+            rev_map,
+            is_enum,
+            Default::default(),
+        )
+    }
+    .into_series()
+}
+
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
     use super::*;
 
     #[test]
@@ -1864,4 +2165,157 @@ mod test {
             assert_eq!(dt_p, dt);
         }
     }
+
+    #[test]
+    fn test_into_series() -> PolarsResult<()> {
+        let s = AnyValue::Int32(3).into_series("a".into(), 3)?;
+        assert_eq!(s, Series::new("a".into(), [3, 3, 3]));
+
+        let s = AnyValue::Null.into_series("a".into(), 2)?;
+        assert_eq!(s.dtype(), &DataType::Null);
+        assert_eq!(s.null_count(), 2);
+
+        let inner = Series::new("".into(), [1i64, 2]);
+        let s = AnyValue::List(inner.clone()).into_series("a".into(), 2)?;
+        assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.get(1)?, AnyValue::List(inner));
+
+        assert_eq!(AnyValue::Int32(1).into_series("a".into(), 0)?.len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_into_series_struct() -> PolarsResult<()> {
+        let fields = vec![
+            Field::new("x".into(), DataType::Int32),
+            Field::new("y".into(), DataType::String),
+        ];
+        let av = AnyValue::StructOwned(Box::new((
+            vec![AnyValue::Int32(1), AnyValue::StringOwned("foo".into())],
+            fields.clone(),
+        )));
+        let s = av.clone().into_series("a".into(), 3)?;
+        assert_eq!(s.dtype(), &DataType::Struct(fields));
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.get(2)?.into_static(), av);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_into_series_categorical() -> PolarsResult<()> {
+        let cats = Series::new("".into(), ["a", "b"])
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let av = cats.get(1)?;
+        let s = av.into_series("a".into(), 2)?;
+        assert!(matches!(s.dtype(), DataType::Categorical(Some(_), _)));
+        assert_eq!(
+            s.cast(&DataType::String)?,
+            Series::new("a".into(), ["b", "b"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_cmp() {
+        use AnyValue::*;
+        use Ordering::*;
+
+        assert_eq!(Int32(5).partial_cmp(&Int64(10)), Some(Less));
+        assert_eq!(Int32(5).total_cmp(&Int64(10)), Less);
+        assert_eq!(UInt64(u64::MAX).total_cmp(&Int64(-1)), Greater);
+        assert_eq!(Int8(2).total_cmp(&Float32(2.0)), Equal);
+        assert_eq!(
+            Float64(f64::NAN).total_cmp(&Float64(f64::INFINITY)),
+            Greater
+        );
+        assert_eq!(Float64(f64::NAN).total_cmp(&Float32(f32::NAN)), Equal);
+        assert_eq!(Null.total_cmp(&Int32(i32::MIN)), Less);
+        assert_eq!(Boolean(true).total_cmp(&Int32(0)), Less);
+        assert_eq!(Int32(0).total_cmp(&String("a")), Less);
+        assert_eq!(String("b").total_cmp(&StringOwned("a".into())), Greater);
+
+        let l = List(Series::new("".into(), [1, 2]));
+        let r = List(Series::new("".into(), [1i64, 2, 3]));
+        assert_eq!(l.total_cmp(&r), Less);
+    }
+
+    #[test]
+    fn test_partial_cmp_mixed_numeric() {
+        use AnyValue::*;
+        use Ordering::*;
+
+        assert_eq!(Int32(2).partial_cmp(&Float64(2.5)), Some(Less));
+        assert_eq!(Float32(2.5).partial_cmp(&Int64(2)), Some(Greater));
+        assert_eq!(Int64(-3).partial_cmp(&Float64(-3.0)), Some(Equal));
+        assert_eq!(Int64(-3).partial_cmp(&Float64(-2.5)), Some(Less));
+        assert_eq!(UInt8(0).partial_cmp(&Float64(f64::NAN)), Some(Less));
+        assert_eq!(
+            Float64(f64::NEG_INFINITY).partial_cmp(&Int128(i128::MIN)),
+            Some(Less)
+        );
+        // Exact, 2^53 + 1 is not representable as a float.
+        assert_eq!(
+            Int64((1 << 53) + 1).partial_cmp(&Float64((1u64 << 53) as f64)),
+            Some(Greater)
+        );
+
+        // Equality agrees with the ordering.
+        assert_eq!(Int64(-3), Float64(-3.0));
+        assert_eq!(Float32(2.0), UInt8(2));
+        assert_ne!(Int32(2), Float64(2.5));
+        assert_ne!(Int64((1 << 53) + 1), Float64((1u64 << 53) as f64));
+        assert!(!UInt8(0).eq_missing(&Float64(f64::NAN), true));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_partial_cmp_decimal() {
+        use AnyValue::*;
+        use Ordering::*;
+
+        assert_eq!(Decimal(150, 2).partial_cmp(&Int32(1)), Some(Greater));
+        assert_eq!(UInt8(1).partial_cmp(&Decimal(100, 2)), Some(Equal));
+        assert_eq!(Int64(-2).partial_cmp(&Decimal(-150, 2)), Some(Less));
+        assert_eq!(Decimal(150, 2).partial_cmp(&Float64(1.6)), Some(Less));
+        assert_eq!(Float32(1.5).partial_cmp(&Decimal(150, 2)), Some(Equal));
+        assert_eq!(Decimal(-10, 0).partial_cmp(&Decimal(1, 38)), Some(Less));
+        assert_eq!(Decimal(1, 38).partial_cmp(&Decimal(-10, 0)), Some(Greater));
+
+        assert_eq!(UInt8(1), Decimal(100, 2));
+        assert_eq!(Decimal(-200, 2), Int64(-2));
+        assert_ne!(Decimal(150, 2), Int32(1));
+        assert_eq!(Float32(1.5), Decimal(150, 2));
+        assert_ne!(Decimal(150, 2), Float64(1.6));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-decimal",
+        feature = "dtype-datetime",
+        feature = "dtype-date"
+    ))]
+    fn test_total_cmp_coercion() {
+        use AnyValue::*;
+        use Ordering::*;
+
+        assert_eq!(Decimal(150, 2).total_cmp(&Int32(1)), Greater);
+        assert_eq!(Decimal(100, 2).total_cmp(&UInt8(1)), Equal);
+        assert_eq!(Decimal(150, 2).total_cmp(&Float64(1.6)), Less);
+        // Rescaling overflows, the sign decides the order.
+        assert_eq!(Decimal(-10, 0).total_cmp(&Decimal(1, 38)), Less);
+        assert_eq!(Decimal(10, 0).total_cmp(&Decimal(-1, 38)), Greater);
+        assert_eq!(Decimal(1, 38).total_cmp(&Decimal(-10, 0)), Greater);
+        assert_eq!(Decimal(-1, 38).total_cmp(&Decimal(10, 0)), Less);
+
+        let ms = Datetime(1_000, TimeUnit::Milliseconds, None);
+        let us = Datetime(999_999, TimeUnit::Microseconds, None);
+        assert_eq!(ms.total_cmp(&us), Greater);
+        assert_eq!(
+            Date(1).total_cmp(&Datetime(86_400_000, TimeUnit::Milliseconds, None)),
+            Equal
+        );
+    }
 }
@@ -36,6 +36,17 @@ pub trait MetaDataExt: IntoMetadata {
         let metadata = self.into_metadata_ref();
         metadata.get(PL_KEY).map(|s| s.as_str()) == Some(MAINTAIN_PL_TYPE)
     }
+
+    /// The metadata that is not used by Polars to restore a [`DataType`].
+    fn user_metadata(&self) -> Option<Arc<Metadata>> {
+        let metadata = self.into_metadata_ref();
+        let md: Metadata = metadata
+            .iter()
+            .filter(|(k, _)| ![DTYPE_ENUM_VALUES, DTYPE_CATEGORICAL, PL_KEY].contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        (!md.is_empty()).then(|| Arc::new(md))
+    }
 }
 
 impl MetaDataExt for Metadata {}
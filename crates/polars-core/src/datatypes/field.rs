@@ -5,7 +5,11 @@ use super::*;
 pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
 
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A `Field` can carry user metadata, such as units or descriptions, that is read from and
+/// written to Arrow based formats. The metadata is not taken into account when comparing or
+/// hashing fields.
+#[derive(Clone, Debug)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-lazy"),
     derive(Serialize, Deserialize)
@@ -14,6 +18,24 @@ pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
 pub struct Field {
     pub name: PlSmallStr,
     pub dtype: DataType,
+    #[cfg_attr(any(feature = "serde", feature = "serde-lazy"), serde(skip))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    pub metadata: Option<Arc<Metadata>>,
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
+}
+
+impl Eq for Field {}
+
+impl Hash for Field {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.dtype.hash(state);
+    }
 }
 
 impl From<Field> for (PlSmallStr, DataType) {
@@ -37,7 +59,11 @@ impl Field {
     /// ```
     #[inline]
     pub fn new(name: PlSmallStr, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: None,
+        }
     }
 
     /// Returns a reference to the `Field` name.
@@ -106,6 +132,29 @@ impl Field {
         self
     }
 
+    /// Returns the user metadata of the `Field`, if any.
+    #[inline]
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns this `Field` with the given user metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use arrow::datatypes::Metadata;
+    /// let md = Metadata::from([("unit".into(), "m/s".into())]);
+    /// let f = Field::new("Speed".into(), DataType::Float64).with_metadata(Some(Arc::new(md)));
+    ///
+    /// assert_eq!(f.metadata().unwrap().get("unit").unwrap().as_str(), "m/s");
+    /// ```
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Self {
+        self.metadata = metadata.filter(|md| !md.is_empty());
+        self
+    }
+
     /// Converts the `Field` to an `arrow::datatypes::Field`.
     ///
     /// # Example
@@ -118,7 +167,16 @@ impl Field {
     /// assert_eq!(f.to_arrow(CompatLevel::newest()), af);
     /// ```
     pub fn to_arrow(&self, compat_level: CompatLevel) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.clone(), compat_level)
+        let mut field = self.dtype.to_arrow_field(self.name.clone(), compat_level);
+        if let Some(md) = &self.metadata {
+            // The metadata Polars needs to restore the dtype takes precedence.
+            let mut merged = md.as_ref().clone();
+            if let Some(dtype_md) = &field.metadata {
+                merged.extend(dtype_md.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+            field.metadata = Some(Arc::new(merged));
+        }
+        field
     }
 }
 
@@ -264,5 +322,6 @@ impl DataType {
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
         Field::new(f.name.clone(), DataType::from_arrow_field(f))
+            .with_metadata(f.metadata.as_deref().and_then(|md| md.user_metadata()))
     }
 }
@@ -94,6 +94,91 @@ pub fn set_trim_decimal_zeros(trim: Option<bool>) {
     arrow::compute::decimal::set_trim_decimal_zeros(trim)
 }
 
+/// Formats the non-null values of a dtype, see [`TableFormatOptions::with_dtype_formatter`].
+pub type DtypeFormatter = Arc<dyn Fn(&AnyValue) -> String + Send + Sync>;
+
+/// Options to render a [`DataFrame`] as a table.
+///
+/// These complement the `POLARS_FMT_*` environment variables, which still control the table
+/// style and the row, column and default string length limits.
+#[derive(Clone, Default)]
+pub struct TableFormatOptions {
+    max_width: PlHashMap<DataType, usize>,
+    float_precision: PlHashMap<PlSmallStr, usize>,
+    formatters: PlHashMap<DataType, DtypeFormatter>,
+}
+
+impl TableFormatOptions {
+    /// Truncate the cells of columns of `dtype` to `width` characters, instead of the
+    /// `POLARS_FMT_STR_LEN` limit.
+    pub fn with_max_width(mut self, dtype: DataType, width: usize) -> Self {
+        self.max_width.insert(dtype, width);
+        self
+    }
+
+    /// Show the floats of `column` with `precision` decimals, overriding [`get_float_precision`].
+    pub fn with_float_precision(mut self, column: impl Into<PlSmallStr>, precision: usize) -> Self {
+        self.float_precision.insert(column.into(), precision);
+        self
+    }
+
+    /// Format the non-null values of columns of `dtype` with `f`, e.g. to render binary values
+    /// as hex. The dtype must match exactly, including the time zone of a datetime.
+    pub fn with_dtype_formatter(
+        mut self,
+        dtype: DataType,
+        f: impl Fn(&AnyValue) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.formatters.insert(dtype, Arc::new(f));
+        self
+    }
+
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn max_width(&self, dtype: &DataType) -> usize {
+        self.max_width
+            .get(dtype)
+            .copied()
+            .unwrap_or_else(get_str_len_limit)
+    }
+
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn str_value<'a>(&self, c: &'a crate::prelude::Column, index: usize) -> Cow<'a, str> {
+        if let Some(f) = self.formatters.get(c.dtype()) {
+            let av = c.get(index).unwrap();
+            if !av.is_null() {
+                return Cow::Owned(f(&av));
+            }
+        } else if let Some(precision) = self.float_precision.get(c.name()) {
+            let v = match c.get(index).unwrap() {
+                AnyValue::Float32(v) => Some(v as f64),
+                AnyValue::Float64(v) => Some(v),
+                _ => None,
+            };
+            if let Some(v) = v {
+                return Cow::Owned(FloatWithPrecision(v, *precision).to_string());
+            }
+        }
+        c.str_value(index).unwrap()
+    }
+}
+
+static TABLE_FORMAT_OPTIONS: RwLock<Option<Arc<TableFormatOptions>>> = RwLock::new(None);
+
+/// Returns the options the [`Display`] implementation of [`DataFrame`] uses.
+pub fn get_table_format_options() -> Arc<TableFormatOptions> {
+    TABLE_FORMAT_OPTIONS
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Sets the options the [`Display`] implementation of [`DataFrame`] uses, or resets them if
+/// `None`.
+pub fn set_table_format_options(options: Option<TableFormatOptions>) {
+    *TABLE_FORMAT_OPTIONS.write().unwrap() = options.map(Arc::new);
+}
+
 /// Parses an environment variable value.
 fn parse_env_var<T: FromStr>(name: &str) -> Option<T> {
     std::env::var(name).ok().and_then(|v| v.parse().ok())
@@ -453,7 +538,6 @@ impl Debug for DataFrame {
         Display::fmt(self, f)
     }
 }
-#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn make_str_val(v: &str, truncate: usize, ellipsis: &String) -> String {
     let v_trunc = &v[..v
         .char_indices()
@@ -520,7 +604,7 @@ fn prepare_row(
     row: Vec<Cow<'_, str>>,
     n_first: usize,
     n_last: usize,
-    str_truncate: usize,
+    str_truncate: &[usize],
     max_elem_lengths: &mut [usize],
     ellipsis: &String,
     padding: usize,
@@ -530,7 +614,7 @@ fn prepare_row(
     let mut row_strings = Vec::with_capacity(n_elems);
 
     for (idx, v) in row[0..n_first].iter().enumerate() {
-        let elem_str = make_str_val(v, str_truncate, ellipsis);
+        let elem_str = make_str_val(v, str_truncate[idx], ellipsis);
         let elem_len = estimate_string_width(elem_str.as_str()) + padding;
         if max_elem_lengths[idx] < elem_len {
             max_elem_lengths[idx] = elem_len;
@@ -542,8 +626,9 @@ fn prepare_row(
         max_elem_lengths[n_first] = ellipsis.chars().count() + padding;
     }
     let elem_offset = n_first + reduce_columns as usize;
-    for (idx, v) in row[row.len() - n_last..].iter().enumerate() {
-        let elem_str = make_str_val(v, str_truncate, ellipsis);
+    let row_offset = row.len() - n_last;
+    for (idx, v) in row[row_offset..].iter().enumerate() {
+        let elem_str = make_str_val(v, str_truncate[row_offset + idx], ellipsis);
         let elem_len = estimate_string_width(elem_str.as_str()) + padding;
         let elem_idx = elem_offset + idx;
         if max_elem_lengths[elem_idx] < elem_len {
@@ -569,250 +654,267 @@ fn fmt_df_shape((shape0, shape1): &(usize, usize)) -> String {
     )
 }
 
-impl Display for DataFrame {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
-        {
-            let height = self.height();
-            assert!(
-                self.columns.iter().all(|s| s.len() == height),
-                "The column lengths in the DataFrame are not equal."
-            );
-
-            let table_style = std::env::var(FMT_TABLE_FORMATTING).unwrap_or("DEFAULT".to_string());
-            let is_utf8 = !table_style.starts_with("ASCII");
-            let preset = match table_style.as_str() {
-                "ASCII_FULL" => ASCII_FULL,
-                "ASCII_FULL_CONDENSED" => ASCII_FULL_CONDENSED,
-                "ASCII_NO_BORDERS" => ASCII_NO_BORDERS,
-                "ASCII_BORDERS_ONLY" => ASCII_BORDERS_ONLY,
-                "ASCII_BORDERS_ONLY_CONDENSED" => ASCII_BORDERS_ONLY_CONDENSED,
-                "ASCII_HORIZONTAL_ONLY" => ASCII_HORIZONTAL_ONLY,
-                "ASCII_MARKDOWN" | "MARKDOWN" => ASCII_MARKDOWN,
-                "UTF8_FULL" => UTF8_FULL,
-                "UTF8_FULL_CONDENSED" => UTF8_FULL_CONDENSED,
-                "UTF8_NO_BORDERS" => UTF8_NO_BORDERS,
-                "UTF8_BORDERS_ONLY" => UTF8_BORDERS_ONLY,
-                "UTF8_HORIZONTAL_ONLY" => UTF8_HORIZONTAL_ONLY,
-                "NOTHING" => NOTHING,
-                _ => UTF8_FULL_CONDENSED,
-            };
-            let ellipsis = get_ellipsis().to_string();
-            let ellipsis_len = ellipsis.chars().count();
-            let max_n_cols = get_col_limit();
-            let max_n_rows = get_row_limit();
-            let str_truncate = get_str_len_limit();
-            let padding = 2; // eg: one char either side of the value
-
-            let (n_first, n_last) = if self.width() > max_n_cols {
-                (max_n_cols.div_ceil(2), max_n_cols / 2)
-            } else {
-                (self.width(), 0)
-            };
-            let reduce_columns = n_first + n_last < self.width();
-            let n_tbl_cols = n_first + n_last + reduce_columns as usize;
-            let mut names = Vec::with_capacity(n_tbl_cols);
-            let mut name_lengths = Vec::with_capacity(n_tbl_cols);
-
-            let fields = self.fields();
-            for field in fields[0..n_first].iter() {
-                let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
-                names.push(s);
-                name_lengths.push(l);
-            }
-            if reduce_columns {
-                names.push(ellipsis.clone());
-                name_lengths.push(ellipsis_len);
-            }
-            for field in fields[self.width() - n_last..].iter() {
-                let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
-                names.push(s);
-                name_lengths.push(l);
-            }
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn fmt_table(df: &DataFrame, f: &mut Formatter<'_>, options: &TableFormatOptions) -> fmt::Result {
+    let height = df.height();
+    assert!(
+        df.columns.iter().all(|s| s.len() == height),
+        "The column lengths in the DataFrame are not equal."
+    );
+
+    let table_style = std::env::var(FMT_TABLE_FORMATTING).unwrap_or("DEFAULT".to_string());
+    let is_utf8 = !table_style.starts_with("ASCII");
+    let preset = match table_style.as_str() {
+        "ASCII_FULL" => ASCII_FULL,
+        "ASCII_FULL_CONDENSED" => ASCII_FULL_CONDENSED,
+        "ASCII_NO_BORDERS" => ASCII_NO_BORDERS,
+        "ASCII_BORDERS_ONLY" => ASCII_BORDERS_ONLY,
+        "ASCII_BORDERS_ONLY_CONDENSED" => ASCII_BORDERS_ONLY_CONDENSED,
+        "ASCII_HORIZONTAL_ONLY" => ASCII_HORIZONTAL_ONLY,
+        "ASCII_MARKDOWN" | "MARKDOWN" => ASCII_MARKDOWN,
+        "UTF8_FULL" => UTF8_FULL,
+        "UTF8_FULL_CONDENSED" => UTF8_FULL_CONDENSED,
+        "UTF8_NO_BORDERS" => UTF8_NO_BORDERS,
+        "UTF8_BORDERS_ONLY" => UTF8_BORDERS_ONLY,
+        "UTF8_HORIZONTAL_ONLY" => UTF8_HORIZONTAL_ONLY,
+        "NOTHING" => NOTHING,
+        _ => UTF8_FULL_CONDENSED,
+    };
+    let ellipsis = get_ellipsis().to_string();
+    let ellipsis_len = ellipsis.chars().count();
+    let max_n_cols = get_col_limit();
+    let max_n_rows = get_row_limit();
+    let str_truncate = get_str_len_limit();
+    let padding = 2; // eg: one char either side of the value
+
+    let (n_first, n_last) = if df.width() > max_n_cols {
+        (max_n_cols.div_ceil(2), max_n_cols / 2)
+    } else {
+        (df.width(), 0)
+    };
+    let reduce_columns = n_first + n_last < df.width();
+    let n_tbl_cols = n_first + n_last + reduce_columns as usize;
+    let mut names = Vec::with_capacity(n_tbl_cols);
+    let mut name_lengths = Vec::with_capacity(n_tbl_cols);
+    let mut tbl_truncate = Vec::with_capacity(n_tbl_cols);
+
+    let fields = df.fields();
+    let col_truncate = fields
+        .iter()
+        .map(|field| options.max_width(field.dtype()))
+        .collect::<Vec<_>>();
+    for (field, truncate) in fields[0..n_first].iter().zip(&col_truncate) {
+        let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
+        names.push(s);
+        name_lengths.push(l);
+        tbl_truncate.push(*truncate);
+    }
+    if reduce_columns {
+        names.push(ellipsis.clone());
+        name_lengths.push(ellipsis_len);
+        tbl_truncate.push(str_truncate);
+    }
+    for (field, truncate) in fields[df.width() - n_last..]
+        .iter()
+        .zip(&col_truncate[df.width() - n_last..])
+    {
+        let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
+        names.push(s);
+        name_lengths.push(l);
+        tbl_truncate.push(*truncate);
+    }
 
-            let mut table = Table::new();
-            table
-                .load_preset(preset)
-                .set_content_arrangement(ContentArrangement::Dynamic);
+    let mut table = Table::new();
+    table
+        .load_preset(preset)
+        .set_content_arrangement(ContentArrangement::Dynamic);
 
-            if is_utf8 && env_is_true(FMT_TABLE_ROUNDED_CORNERS) {
-                table.apply_modifier(UTF8_ROUND_CORNERS);
-            }
-            let mut constraints = Vec::with_capacity(n_tbl_cols);
-            let mut max_elem_lengths: Vec<usize> = vec![0; n_tbl_cols];
-
-            if max_n_rows > 0 {
-                if height > max_n_rows {
-                    // Truncate the table if we have more rows than the
-                    // configured maximum number of rows
-                    let mut rows = Vec::with_capacity(std::cmp::max(max_n_rows, 2));
-                    let half = max_n_rows / 2;
-                    let rest = max_n_rows % 2;
-
-                    for i in 0..(half + rest) {
-                        let row = self
-                            .get_columns()
-                            .iter()
-                            .map(|c| c.str_value(i).unwrap())
-                            .collect();
-
-                        let row_strings = prepare_row(
-                            row,
-                            n_first,
-                            n_last,
-                            str_truncate,
-                            &mut max_elem_lengths,
-                            &ellipsis,
-                            padding,
-                        );
-                        rows.push(row_strings);
-                    }
-                    let dots = vec![ellipsis.clone(); rows[0].len()];
-                    rows.push(dots);
-
-                    for i in (height - half)..height {
-                        let row = self
-                            .get_columns()
-                            .iter()
-                            .map(|c| c.str_value(i).unwrap())
-                            .collect();
-
-                        let row_strings = prepare_row(
-                            row,
-                            n_first,
-                            n_last,
-                            str_truncate,
-                            &mut max_elem_lengths,
-                            &ellipsis,
-                            padding,
-                        );
-                        rows.push(row_strings);
-                    }
-                    table.add_rows(rows);
-                } else {
-                    for i in 0..height {
-                        if self.width() > 0 {
-                            let row = self
-                                .materialized_column_iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
-
-                            let row_strings = prepare_row(
-                                row,
-                                n_first,
-                                n_last,
-                                str_truncate,
-                                &mut max_elem_lengths,
-                                &ellipsis,
-                                padding,
-                            );
-                            table.add_row(row_strings);
-                        } else {
-                            break;
-                        }
-                    }
-                }
-            } else if height > 0 {
-                let dots: Vec<String> = vec![ellipsis.clone(); self.columns.len()];
-                table.add_row(dots);
+    if is_utf8 && env_is_true(FMT_TABLE_ROUNDED_CORNERS) {
+        table.apply_modifier(UTF8_ROUND_CORNERS);
+    }
+    let mut constraints = Vec::with_capacity(n_tbl_cols);
+    let mut max_elem_lengths: Vec<usize> = vec![0; n_tbl_cols];
+
+    if max_n_rows > 0 {
+        if height > max_n_rows {
+            // Truncate the table if we have more rows than the
+            // configured maximum number of rows
+            let mut rows = Vec::with_capacity(std::cmp::max(max_n_rows, 2));
+            let half = max_n_rows / 2;
+            let rest = max_n_rows % 2;
+
+            for i in 0..(half + rest) {
+                let row = df
+                    .get_columns()
+                    .iter()
+                    .map(|c| options.str_value(c, i))
+                    .collect();
+
+                let row_strings = prepare_row(
+                    row,
+                    n_first,
+                    n_last,
+                    &col_truncate,
+                    &mut max_elem_lengths,
+                    &ellipsis,
+                    padding,
+                );
+                rows.push(row_strings);
             }
-            let tbl_fallback_width = 100;
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    let n = s
-                        .parse::<i64>()
-                        .expect("could not parse table width argument");
-                    let w = if n < 0 {
-                        u16::MAX
-                    } else {
-                        u16::try_from(n).expect("table width argument does not fit in u16")
-                    };
-                    Some(w)
-                })
-                .unwrap_or(None);
-
-            // column width constraints
-            let col_width_exact =
-                |w: usize| ColumnConstraint::Absolute(comfy_table::Width::Fixed(w as u16));
-            let col_width_bounds = |l: usize, u: usize| ColumnConstraint::Boundaries {
-                lower: Width::Fixed(l as u16),
-                upper: Width::Fixed(u as u16),
-            };
-            let min_col_width = std::cmp::max(5, 3 + padding);
-            for (idx, elem_len) in max_elem_lengths.iter().enumerate() {
-                let mx = std::cmp::min(
-                    str_truncate + ellipsis_len + padding,
-                    std::cmp::max(name_lengths[idx], *elem_len),
+            let dots = vec![ellipsis.clone(); rows[0].len()];
+            rows.push(dots);
+
+            for i in (height - half)..height {
+                let row = df
+                    .get_columns()
+                    .iter()
+                    .map(|c| options.str_value(c, i))
+                    .collect();
+
+                let row_strings = prepare_row(
+                    row,
+                    n_first,
+                    n_last,
+                    &col_truncate,
+                    &mut max_elem_lengths,
+                    &ellipsis,
+                    padding,
                 );
-                if (mx <= min_col_width) && !(max_n_rows > 0 && height > max_n_rows) {
-                    // col width is less than min width + table is not truncated
-                    constraints.push(col_width_exact(mx));
-                } else if mx <= min_col_width {
-                    // col width is less than min width + table is truncated (w/ ellipsis)
-                    constraints.push(col_width_bounds(mx, min_col_width));
+                rows.push(row_strings);
+            }
+            table.add_rows(rows);
+        } else {
+            for i in 0..height {
+                if df.width() > 0 {
+                    let row = df
+                        .get_columns()
+                        .iter()
+                        .map(|c| options.str_value(c, i))
+                        .collect();
+
+                    let row_strings = prepare_row(
+                        row,
+                        n_first,
+                        n_last,
+                        &col_truncate,
+                        &mut max_elem_lengths,
+                        &ellipsis,
+                        padding,
+                    );
+                    table.add_row(row_strings);
                 } else {
-                    constraints.push(col_width_bounds(min_col_width, mx));
+                    break;
                 }
             }
+        }
+    } else if height > 0 {
+        let dots: Vec<String> = vec![ellipsis.clone(); df.columns.len()];
+        table.add_row(dots);
+    }
+    let tbl_fallback_width = 100;
+    let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
+        .map(|s| {
+            let n = s
+                .parse::<i64>()
+                .expect("could not parse table width argument");
+            let w = if n < 0 {
+                u16::MAX
+            } else {
+                u16::try_from(n).expect("table width argument does not fit in u16")
+            };
+            Some(w)
+        })
+        .unwrap_or(None);
+
+    // column width constraints
+    let col_width_exact =
+        |w: usize| ColumnConstraint::Absolute(comfy_table::Width::Fixed(w as u16));
+    let col_width_bounds = |l: usize, u: usize| ColumnConstraint::Boundaries {
+        lower: Width::Fixed(l as u16),
+        upper: Width::Fixed(u as u16),
+    };
+    let min_col_width = std::cmp::max(5, 3 + padding);
+    for (idx, elem_len) in max_elem_lengths.iter().enumerate() {
+        let mx = std::cmp::min(
+            tbl_truncate[idx].saturating_add(ellipsis_len + padding),
+            std::cmp::max(name_lengths[idx], *elem_len),
+        );
+        if (mx <= min_col_width) && !(max_n_rows > 0 && height > max_n_rows) {
+            // col width is less than min width + table is not truncated
+            constraints.push(col_width_exact(mx));
+        } else if mx <= min_col_width {
+            // col width is less than min width + table is truncated (w/ ellipsis)
+            constraints.push(col_width_bounds(mx, min_col_width));
+        } else {
+            constraints.push(col_width_bounds(min_col_width, mx));
+        }
+    }
 
-            // insert a header row, unless both column names and dtypes are hidden
-            if !(env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES)
-                && env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES))
-            {
-                table.set_header(names).set_constraints(constraints);
-            }
+    // insert a header row, unless both column names and dtypes are hidden
+    if !(env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES) && env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES))
+    {
+        table.set_header(names).set_constraints(constraints);
+    }
 
-            // if tbl_width is explicitly set, use it
-            if let Some(w) = tbl_width {
-                table.set_width(w);
-            } else {
-                // if no tbl_width (it's not tty && width not explicitly set), apply
-                // a default value; this is needed to support non-tty applications
-                #[cfg(feature = "fmt")]
-                if table.width().is_none() && !table.is_tty() {
-                    table.set_width(tbl_fallback_width);
-                }
-                #[cfg(feature = "fmt_no_tty")]
-                if table.width().is_none() {
-                    table.set_width(tbl_fallback_width);
-                }
-            }
+    // if tbl_width is explicitly set, use it
+    if let Some(w) = tbl_width {
+        table.set_width(w);
+    } else {
+        // if no tbl_width (it's not tty && width not explicitly set), apply
+        // a default value; this is needed to support non-tty applications
+        #[cfg(feature = "fmt")]
+        if table.width().is_none() && !table.is_tty() {
+            table.set_width(tbl_fallback_width);
+        }
+        #[cfg(feature = "fmt_no_tty")]
+        if table.width().is_none() {
+            table.set_width(tbl_fallback_width);
+        }
+    }
 
-            // set alignment of cells, if defined
-            if std::env::var(FMT_TABLE_CELL_ALIGNMENT).is_ok()
-                | std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT).is_ok()
-            {
-                let str_preset = std::env::var(FMT_TABLE_CELL_ALIGNMENT)
-                    .unwrap_or_else(|_| "DEFAULT".to_string());
-                let num_preset = std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT)
-                    .unwrap_or_else(|_| str_preset.to_string());
-                for (column_index, column) in table.column_iter_mut().enumerate() {
-                    let dtype = fields[column_index].dtype();
-                    let mut preset = str_preset.as_str();
-                    if dtype.is_primitive_numeric() || dtype.is_decimal() {
-                        preset = num_preset.as_str();
-                    }
-                    match preset {
-                        "RIGHT" => column.set_cell_alignment(CellAlignment::Right),
-                        "LEFT" => column.set_cell_alignment(CellAlignment::Left),
-                        "CENTER" => column.set_cell_alignment(CellAlignment::Center),
-                        _ => {},
-                    }
-                }
+    // set alignment of cells, if defined
+    if std::env::var(FMT_TABLE_CELL_ALIGNMENT).is_ok()
+        | std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT).is_ok()
+    {
+        let str_preset =
+            std::env::var(FMT_TABLE_CELL_ALIGNMENT).unwrap_or_else(|_| "DEFAULT".to_string());
+        let num_preset = std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT)
+            .unwrap_or_else(|_| str_preset.to_string());
+        for (column_index, column) in table.column_iter_mut().enumerate() {
+            let dtype = fields[column_index].dtype();
+            let mut preset = str_preset.as_str();
+            if dtype.is_primitive_numeric() || dtype.is_decimal() {
+                preset = num_preset.as_str();
             }
-
-            // establish 'shape' information (above/below/hidden)
-            if env_is_true(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION) {
-                write!(f, "{table}")?;
-            } else {
-                let shape_str = fmt_df_shape(&self.shape());
-                if env_is_true(FMT_TABLE_DATAFRAME_SHAPE_BELOW) {
-                    write!(f, "{table}\nshape: {shape_str}")?;
-                } else {
-                    write!(f, "shape: {shape_str}\n{table}")?;
-                }
+            match preset {
+                "RIGHT" => column.set_cell_alignment(CellAlignment::Right),
+                "LEFT" => column.set_cell_alignment(CellAlignment::Left),
+                "CENTER" => column.set_cell_alignment(CellAlignment::Center),
+                _ => {},
             }
         }
+    }
+
+    // establish 'shape' information (above/below/hidden)
+    if env_is_true(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION) {
+        write!(f, "{table}")?;
+    } else {
+        let shape_str = fmt_df_shape(&df.shape());
+        if env_is_true(FMT_TABLE_DATAFRAME_SHAPE_BELOW) {
+            write!(f, "{table}\nshape: {shape_str}")?;
+        } else {
+            write!(f, "shape: {shape_str}\n{table}")?;
+        }
+    }
+    Ok(())
+}
+
+impl Display for DataFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+        {
+            fmt_table(self, f, &get_table_format_options())?;
+        }
         #[cfg(not(any(feature = "fmt", feature = "fmt_no_tty")))]
         {
             write!(
@@ -825,6 +927,84 @@ impl Display for DataFrame {
     }
 }
 
+/// Displays a [`DataFrame`] with explicit [`TableFormatOptions`], see [`DataFrame::display_with`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+pub struct DataFrameDisplay<'a> {
+    df: &'a DataFrame,
+    options: &'a TableFormatOptions,
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Display for DataFrameDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_table(self.df, f, self.options)
+    }
+}
+
+/// Options for [`DataFrame::glimpse`].
+#[derive(Clone, Debug)]
+pub struct GlimpseOptions {
+    /// The maximum number of values shown per column.
+    pub max_items_per_column: usize,
+    /// Longer column names are truncated.
+    pub max_colname_length: usize,
+}
+
+impl Default for GlimpseOptions {
+    fn default() -> Self {
+        Self {
+            max_items_per_column: 10,
+            max_colname_length: 50,
+        }
+    }
+}
+
+impl DataFrame {
+    /// Display this [`DataFrame`] as a table with the given options instead of the ones set
+    /// with [`set_table_format_options`].
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    pub fn display_with<'a>(&'a self, options: &'a TableFormatOptions) -> DataFrameDisplay<'a> {
+        DataFrameDisplay { df: self, options }
+    }
+
+    /// A dense preview of the [`DataFrame`] with one line per column, showing its name, dtype
+    /// and first values.
+    ///
+    /// ```text
+    /// Rows: 3
+    /// Columns: 2
+    /// $ a <i32> 1, 2, 3
+    /// $ b <str> "x", "y", null
+    /// ```
+    pub fn glimpse(&self, options: GlimpseOptions) -> String {
+        let ellipsis = get_ellipsis().to_string();
+        let str_truncate = get_str_len_limit();
+        let names = self
+            .get_column_names()
+            .into_iter()
+            .map(|name| make_str_val(name, options.max_colname_length, &ellipsis))
+            .collect::<Vec<_>>();
+        let dtypes = self
+            .dtypes()
+            .iter()
+            .map(|dtype| format!("<{dtype}>"))
+            .collect::<Vec<_>>();
+        let name_width = names.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+        let dtype_width = dtypes.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+
+        let mut out = format!("Rows: {}\nColumns: {}\n", self.height(), self.width());
+        for ((c, name), dtype) in self.get_columns().iter().zip(&names).zip(&dtypes) {
+            let n = options.max_items_per_column.min(c.len());
+            let values = (0..n)
+                .map(|i| make_str_val(&c.get(i).unwrap().to_string(), str_truncate, &ellipsis))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(out, "$ {name:<name_width$} {dtype:<dtype_width$} {values}").unwrap();
+        }
+        out
+    }
+}
+
 fn fmt_int_string_custom(num: &str, group_size: u8, group_separator: &str) -> String {
     if group_size == 0 || num.len() <= 1 {
         num.to_string()
@@ -899,9 +1079,27 @@ fn fmt_integer<T: Num + NumCast + Display>(
 const SCIENTIFIC_BOUND: f64 = 999999.0;
 
 fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
-    let v: f64 = NumCast::from(v).unwrap();
+    fmt_float_with_precision(f, width, v, get_float_precision())
+}
 
-    let float_precision = get_float_precision();
+/// Displays a float with a fixed precision, see [`TableFormatOptions::with_float_precision`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+struct FloatWithPrecision(f64, usize);
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Display for FloatWithPrecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_float_with_precision(f, 0, self.0, Some(self.1))
+    }
+}
+
+fn fmt_float_with_precision<T: Num + NumCast>(
+    f: &mut Formatter<'_>,
+    width: usize,
+    v: T,
+    float_precision: Option<usize>,
+) -> fmt::Result {
+    let v: f64 = NumCast::from(v).unwrap();
 
     if let Some(precision) = float_precision {
         if format!("{v:.precision$}").len() > 19 {
@@ -1311,6 +1509,7 @@ fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result {
 ))]
 #[allow(unsafe_op_in_unsafe_fn)]
 mod test {
+    use super::{GlimpseOptions, TableFormatOptions};
     use crate::prelude::*;
 
     #[test]
@@ -1517,4 +1716,42 @@ ChunkedArray: 'name' [str]
             format!("{ca:?}")
         );
     }
+
+    #[test]
+    fn test_glimpse() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1i32, 2, 3],
+            "long_name" => [Some("x"), Some("y"), None],
+        ]?;
+        let options = GlimpseOptions {
+            max_items_per_column: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            df.glimpse(options),
+            "Rows: 3\nColumns: 2\n$ a         <i32> 1, 2\n$ long_name <str> \"x\", \"y\"\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn test_table_format_options() -> PolarsResult<()> {
+        let df = df![
+            "f" => [1.23456f64, 2.0],
+            "b" => [b"ab".as_slice(), b"\xff".as_slice()],
+        ]?;
+        let options = TableFormatOptions::default()
+            .with_float_precision("f", 2)
+            .with_dtype_formatter(DataType::Binary, |av| match av {
+                AnyValue::Binary(v) => v.iter().map(|b| format!("{b:02x}")).collect(),
+                _ => unreachable!(),
+            });
+        let out = df.display_with(&options).to_string();
+        assert!(out.contains("1.23 "));
+        assert!(out.contains("2.00 "));
+        assert!(out.contains("6162"));
+        assert!(out.contains("ff"));
+        Ok(())
+    }
 }
@@ -35,6 +35,31 @@ impl From<RecordBatch> for DataFrame {
 }
 
 impl DataFrame {
+    /// Convert this [`DataFrame`] into Arrow [`RecordBatch`]es, one per chunk.
+    ///
+    /// Columns whose chunks are not aligned are rechunked first.
+    pub fn to_record_batches(&self) -> Vec<RecordBatch> {
+        let mut df = self.clone();
+        df.align_chunks_par();
+        df.iter_chunks(CompatLevel::newest(), false).collect()
+    }
+
+    /// Create a [`DataFrame`] from Arrow [`RecordBatch`]es, keeping one chunk per batch.
+    ///
+    /// All batches must share the same schema.
+    pub fn from_record_batches(batches: Vec<RecordBatch>) -> PolarsResult<DataFrame> {
+        let mut batches = batches.into_iter();
+        let Some(first) = batches.next() else {
+            return Ok(DataFrame::empty());
+        };
+
+        let mut df = DataFrame::from(first);
+        for rb in batches {
+            df.append_record_batch(rb)?;
+        }
+        Ok(df)
+    }
+
     pub fn split_chunks(&mut self) -> impl Iterator<Item = DataFrame> + '_ {
         self.align_chunks_par();
 
@@ -96,6 +96,32 @@ impl Column {
         Ok(col)
     }
 
+    /// Create a `UInt64` row index column, independent of the [`IdxSize`] of this build.
+    pub fn new_row_index_u64(name: PlSmallStr, offset: u64, length: usize) -> PolarsResult<Column> {
+        let length = length as u64;
+
+        // The last index may be `u64::MAX`.
+        let values = match length.checked_sub(1) {
+            None => vec![],
+            Some(last) => {
+                let Some(last) = offset.checked_add(last) else {
+                    polars_bail!(
+                        ComputeError:
+                        "row index with offset {} overflows on dataframe with height {}",
+                        offset, length
+                    )
+                };
+                (offset..=last).collect()
+            },
+        };
+
+        let mut ca = UInt64Chunked::from_vec(name, values);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        let col = ca.into_series().into();
+
+        Ok(col)
+    }
+
     // # Materialize
     /// Get a reference to a [`Series`] for this [`Column`]
     ///
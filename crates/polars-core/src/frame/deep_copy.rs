@@ -0,0 +1,132 @@
+use arrow::array::*;
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::datatypes::PhysicalType;
+use arrow::offset::OffsetsBuffer;
+use arrow::with_match_primitive_type_full;
+
+use crate::prelude::*;
+
+fn copy_buffer<T: Clone>(buffer: &Buffer<T>) -> Buffer<T> {
+    buffer.as_slice().to_vec().into()
+}
+
+fn copy_bitmap(bitmap: &Bitmap) -> Bitmap {
+    // The clone keeps the storage shared, so `make_mut` copies it.
+    bitmap.clone().make_mut().into()
+}
+
+fn copy_validity(validity: Option<&Bitmap>) -> Option<Bitmap> {
+    validity.map(copy_bitmap)
+}
+
+fn copy_offsets(offsets: &OffsetsBuffer<i64>) -> OffsetsBuffer<i64> {
+    // SAFETY: the copy has the same, valid, offsets.
+    unsafe { OffsetsBuffer::new_unchecked(copy_buffer(offsets.buffer())) }
+}
+
+fn copy_binview(arr: &BinaryViewArray) -> BinaryViewArray {
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    for value in arr.values_iter() {
+        out.push_value(value);
+    }
+    let out: BinaryViewArray = out.into();
+    out.with_validity(copy_validity(arr.validity()))
+}
+
+/// Copy the buffers of `arr` into fresh allocations.
+pub(super) fn deep_copy_array(arr: &dyn Array) -> PolarsResult<ArrayRef> {
+    use PhysicalType::*;
+    let any = arr.as_any();
+    let dtype = arr.dtype().clone();
+    let validity = copy_validity(arr.validity());
+
+    let out = match arr.dtype().to_physical_type() {
+        Null => arr.to_boxed(),
+        Boolean => {
+            let arr = any.downcast_ref::<BooleanArray>().unwrap();
+            BooleanArray::new(dtype, copy_bitmap(arr.values()), validity).boxed()
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let arr = any.downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            PrimitiveArray::new(dtype, copy_buffer(arr.values()), validity).boxed()
+        }),
+        LargeBinary => {
+            let arr = any.downcast_ref::<BinaryArray<i64>>().unwrap();
+            let offsets = copy_offsets(arr.offsets());
+            BinaryArray::new(dtype, offsets, copy_buffer(arr.values()), validity).boxed()
+        },
+        LargeUtf8 => {
+            let arr = any.downcast_ref::<Utf8Array<i64>>().unwrap();
+            let offsets = copy_offsets(arr.offsets());
+            // SAFETY: the values are valid UTF-8 as they are copied from a `Utf8Array`.
+            unsafe { Utf8Array::new_unchecked(dtype, offsets, copy_buffer(arr.values()), validity) }
+                .boxed()
+        },
+        BinaryView => copy_binview(any.downcast_ref::<BinaryViewArray>().unwrap()).boxed(),
+        Utf8View => {
+            let arr = any.downcast_ref::<Utf8ViewArray>().unwrap();
+            // SAFETY: the values are valid UTF-8 as they are copied from a `Utf8ViewArray`.
+            unsafe { copy_binview(&arr.to_binview()).to_utf8view_unchecked() }.boxed()
+        },
+        LargeList => {
+            let arr = any.downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = copy_offsets(arr.offsets());
+            ListArray::new(
+                dtype,
+                offsets,
+                deep_copy_array(arr.values().as_ref())?,
+                validity,
+            )
+            .boxed()
+        },
+        FixedSizeList => {
+            let arr = any.downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = deep_copy_array(arr.values().as_ref())?;
+            FixedSizeListArray::new(dtype, arr.len(), values, validity).boxed()
+        },
+        Struct => {
+            let arr = any.downcast_ref::<StructArray>().unwrap();
+            let values = arr
+                .values()
+                .iter()
+                .map(|field| deep_copy_array(field.as_ref()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructArray::new(dtype, arr.len(), values, validity).boxed()
+        },
+        physical => polars_bail!(
+            InvalidOperation: "cannot copy arrays of physical type {:?}", physical
+        ),
+    };
+    Ok(out)
+}
+
+/// Copy the chunks of `s` into fresh allocations.
+pub(super) fn deep_copy_series(s: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        !s.dtype().is_object(),
+        InvalidOperation: "cannot copy column '{}' of dtype object", s.name()
+    );
+    let chunks = s
+        .chunks()
+        .iter()
+        .map(|arr| deep_copy_array(arr.as_ref()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // SAFETY: the chunks are copies of chunks of the same dtype.
+    let mut out =
+        unsafe { Series::from_chunks_and_dtype_unchecked(s.name().clone(), chunks, s.dtype()) };
+    out.set_flags(s.get_flags());
+    Ok(out)
+}
+
+/// Copy the nested values of `scalar` into fresh allocations.
+///
+/// Other values are owned by the scalar itself, so only a list, array or struct value can share
+/// buffers with another scalar.
+pub(super) fn deep_copy_scalar(scalar: &Scalar) -> PolarsResult<Scalar> {
+    if !scalar.dtype().is_nested() || scalar.is_null() {
+        return Ok(scalar.clone());
+    }
+    let s = deep_copy_series(&scalar.clone().into_series(PlSmallStr::EMPTY))?;
+    Ok(Scalar::new(s.dtype().clone(), s.get(0)?.into_static()))
+}
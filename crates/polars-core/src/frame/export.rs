@@ -0,0 +1,87 @@
+use arrow::array::StructArray;
+use arrow::ffi::{ArrowArrayStream, export_iterator};
+
+use crate::prelude::*;
+
+/// Iterates over the record batches of a [`DataFrame`] as Arrow struct arrays.
+struct StructArrayIter {
+    columns: Vec<Series>,
+    dtype: ArrowDataType,
+    idx: usize,
+    n_chunks: usize,
+}
+
+impl Iterator for StructArrayIter {
+    type Item = PolarsResult<ArrayRef>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.n_chunks {
+            return None;
+        }
+        let batch_cols = self
+            .columns
+            .iter()
+            .map(|s| s.to_arrow(self.idx, CompatLevel::newest()))
+            .collect::<Vec<_>>();
+        self.idx += 1;
+
+        let array = StructArray::new(self.dtype.clone(), batch_cols[0].len(), batch_cols, None);
+        Some(Ok(array.boxed()))
+    }
+}
+
+impl DataFrame {
+    /// Export this [`DataFrame`] through the Arrow
+    /// [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+    ///
+    /// Every record batch is exported as a struct array whose fields are the columns. The
+    /// buffers are shared with the stream; only misaligned chunks are rechunked first.
+    pub fn as_arrow_stream(&self) -> ArrowArrayStream {
+        let mut df = self.clone();
+        df.align_chunks_par();
+
+        let schema = df.schema().to_arrow(CompatLevel::newest());
+        let dtype = ArrowDataType::Struct(schema.into_iter_values().collect());
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+        let iter = StructArrayIter {
+            n_chunks: df.first_col_n_chunks(),
+            columns: df
+                .take_columns()
+                .into_iter()
+                .map(|c| c.take_materialized_series())
+                .collect(),
+            dtype,
+            idx: 0,
+        };
+        export_iterator(Box::new(iter), field)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::ffi::ArrowArrayStreamReader;
+
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_as_arrow_stream() -> PolarsResult<()> {
+        let mut df = df!["a" => [1, 2], "b" => ["x", "y"]]?;
+        df.vstack_mut(&df!["a" => [3], "b" => [None::<&str>]]?)?;
+
+        let stream = Box::new(df.as_arrow_stream());
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        let mut batches = Vec::new();
+        while let Some(array) = unsafe { reader.next() } {
+            let s = Series::from_arrow(PlSmallStr::EMPTY, array?)?;
+            batches.push(s.struct_()?.clone().unnest());
+        }
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].height(), 2);
+        let mut out = batches[0].clone();
+        out.vstack_mut(&batches[1])?;
+        assert!(out.equals_missing(&df));
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use polars_utils::total_ord::TotalOrd;
+
+use crate::chunked_array::ops::arity::binary_elementwise;
+use crate::prelude::*;
+use crate::utils::try_get_supertype;
+
+/// Built-in accumulators for [`DataFrame::fold_with`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FoldOp {
+    /// Sum the columns, nulls propagate.
+    Sum,
+    /// Take the minimum of the columns, ignoring nulls.
+    Min,
+    /// Take the maximum of the columns, ignoring nulls.
+    Max,
+    /// Logical or of boolean columns.
+    Any,
+    /// Logical and of boolean columns.
+    All,
+}
+
+fn min_max_binary<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    min: bool,
+) -> ChunkedArray<T> {
+    binary_elementwise(
+        left,
+        right,
+        |l: Option<T::Native>, r: Option<T::Native>| match (l, r) {
+            (Some(l), Some(r)) => Some(if l.tot_lt(&r) == min { l } else { r }),
+            (l, r) => l.or(r),
+        },
+    )
+}
+
+impl DataFrame {
+    /// Reduce the columns of this [`DataFrame`] into a single [`Series`] with `f`, starting from
+    /// the first column.
+    ///
+    /// Returns `None` if the [`DataFrame`] has no columns.
+    pub fn fold<F>(&self, f: F) -> PolarsResult<Option<Series>>
+    where
+        F: Fn(&Series, &Series) -> PolarsResult<Series>,
+    {
+        let Some((first, rest)) = self.columns.split_first() else {
+            return Ok(None);
+        };
+        let init = first.as_materialized_series().clone();
+        self.fold_columns(init, rest, false, f).map(Some)
+    }
+
+    /// Reduce the columns of this [`DataFrame`] into `init` with `f`.
+    ///
+    /// If `cast_to_supertype` is set, the accumulator and the columns are cast to their common
+    /// supertype before being passed to `f`. Errors if `f` returns a [`Series`] with a length
+    /// other than the height of the [`DataFrame`].
+    pub fn try_fold_with_init<F>(
+        &self,
+        init: Series,
+        cast_to_supertype: bool,
+        f: F,
+    ) -> PolarsResult<Series>
+    where
+        F: Fn(&Series, &Series) -> PolarsResult<Series>,
+    {
+        self.fold_columns(init, &self.columns, cast_to_supertype, f)
+    }
+
+    fn fold_columns<F>(
+        &self,
+        init: Series,
+        columns: &[Column],
+        cast_to_supertype: bool,
+        f: F,
+    ) -> PolarsResult<Series>
+    where
+        F: Fn(&Series, &Series) -> PolarsResult<Series>,
+    {
+        polars_ensure!(
+            init.len() == self.height(),
+            ShapeMismatch: "fold initial value has length {}, expected {}",
+            init.len(), self.height()
+        );
+
+        let supertype = cast_to_supertype
+            .then(|| {
+                columns.iter().try_fold(init.dtype().clone(), |st, c| {
+                    try_get_supertype(&st, c.dtype())
+                })
+            })
+            .transpose()?;
+
+        let mut acc = match &supertype {
+            Some(dtype) => init.cast(dtype)?,
+            None => init,
+        };
+        for c in columns {
+            let s = c.as_materialized_series();
+            acc = match &supertype {
+                Some(dtype) => f(&acc.cast(dtype)?, &s.cast(dtype)?)?,
+                None => f(&acc, s)?,
+            };
+            polars_ensure!(
+                acc.len() == self.height(),
+                ShapeMismatch: "fold function returned length {}, expected {}",
+                acc.len(), self.height()
+            );
+        }
+        Ok(acc)
+    }
+
+    /// Reduce the columns of this [`DataFrame`] into a single [`Series`] with a built-in
+    /// accumulator. The columns are cast to their supertype first.
+    ///
+    /// Returns `None` if the [`DataFrame`] has no columns.
+    pub fn fold_with(&self, op: FoldOp) -> PolarsResult<Option<Series>> {
+        let Some(supertype) = self.get_supertype().transpose()? else {
+            return Ok(None);
+        };
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.as_materialized_series().cast(&supertype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let (first, rest) = columns.split_first().unwrap();
+
+        let out = match op {
+            FoldOp::Sum => rest.iter().try_fold(first.clone(), |acc, s| &acc + s)?,
+            FoldOp::Min | FoldOp::Max => {
+                let physical = supertype.to_physical();
+                polars_ensure!(
+                    physical.is_primitive_numeric(),
+                    InvalidOperation: "{:?} fold is not supported for dtype {}", op, supertype
+                );
+                let min = op == FoldOp::Min;
+                let out = with_match_physical_numeric_polars_type!(physical, |$T| {
+                    let mut acc: ChunkedArray<$T> =
+                        first.to_physical_repr().as_ref().as_ref().as_ref().clone();
+                    for s in rest {
+                        let s = s.to_physical_repr();
+                        acc = min_max_binary(&acc, s.as_ref().as_ref().as_ref(), min);
+                    }
+                    acc.into_series()
+                });
+                // SAFETY: the physical values were computed from columns of this dtype.
+                unsafe { out.from_physical_unchecked(&supertype)? }
+            },
+            FoldOp::Any | FoldOp::All => {
+                polars_ensure!(
+                    supertype == DataType::Boolean,
+                    InvalidOperation: "{:?} fold is not supported for dtype {}", op, supertype
+                );
+                let mut acc = first.bool()?.clone();
+                for s in rest {
+                    acc = if op == FoldOp::Any {
+                        &acc | s.bool()?
+                    } else {
+                        &acc & s.bool()?
+                    };
+                }
+                acc.into_series()
+            },
+        };
+        Ok(Some(out))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_fold() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 5, 3],
+            "b" => [Some(4i64), None, Some(2)],
+        )?;
+
+        let out = df.fold(|acc, s| acc + s)?.unwrap();
+        assert_eq!(Vec::from(out.i64()?), [Some(5), None, Some(5)]);
+
+        let init = Series::new("acc".into(), [0.5f64, 0.5, 0.5]);
+        let out = df.try_fold_with_init(init.clone(), true, |acc, s| {
+            assert_eq!(acc.dtype(), &DataType::Float64);
+            assert_eq!(s.dtype(), &DataType::Float64);
+            acc + s
+        })?;
+        assert_eq!(Vec::from(out.f64()?), [Some(5.5), None, Some(5.5)]);
+
+        let err = df.try_fold_with_init(init, false, |acc, _| Ok(acc.slice(0, 1)));
+        assert!(err.is_err());
+
+        assert!(DataFrame::empty().fold(|acc, s| acc + s)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_with() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 5, 3],
+            "b" => [Some(4i64), None, Some(2)],
+        )?;
+        let out = df.fold_with(FoldOp::Sum)?.unwrap();
+        assert_eq!(Vec::from(out.i64()?), [Some(5), None, Some(5)]);
+        let out = df.fold_with(FoldOp::Min)?.unwrap();
+        assert_eq!(Vec::from(out.i64()?), [Some(1), Some(5), Some(2)]);
+        let out = df.fold_with(FoldOp::Max)?.unwrap();
+        assert_eq!(Vec::from(out.i64()?), [Some(4), Some(5), Some(3)]);
+        assert!(df.fold_with(FoldOp::Any).is_err());
+
+        let df = df!(
+            "a" => [true, false, false],
+            "b" => [true, true, false],
+        )?;
+        let out = df.fold_with(FoldOp::Any)?.unwrap();
+        assert_eq!(
+            Vec::from(out.bool()?),
+            [Some(true), Some(true), Some(false)]
+        );
+        let out = df.fold_with(FoldOp::All)?.unwrap();
+        assert_eq!(
+            Vec::from(out.bool()?),
+            [Some(true), Some(false), Some(false)]
+        );
+        Ok(())
+    }
+}
@@ -8,8 +8,7 @@ use super::*;
 use crate::chunked_array::cast::CastOptions;
 use crate::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
 use crate::config::verbose;
-use crate::series::BitRepr;
-use crate::utils::Container;
+use crate::series::{BitRepr, IsSorted};
 use crate::utils::flatten::flatten_par;
 
 /// Used to create the tuples for a group_by operation.
@@ -57,6 +56,57 @@ where
     }
 }
 
+/// Append the group slices of a sorted chunk that starts at row `offset` to `out`.
+///
+/// If `continues_group` is set, the first group of the chunk has the same key as the last
+/// group in `out` and the two are merged, so that groups can span chunk boundaries.
+fn extend_sorted_groups(
+    out: &mut GroupsSlice,
+    groups: GroupsSlice,
+    offset: IdxSize,
+    continues_group: bool,
+) {
+    let mut groups = groups.into_iter().map(|[first, len]| [first + offset, len]);
+    if continues_group {
+        if let (Some(last), Some([_, len])) = (out.last_mut(), groups.next()) {
+            last[1] += len;
+        }
+    }
+    out.extend(groups);
+}
+
+/// Create the groups of sorted binary keys without nulls, chunk by chunk.
+fn create_groups_from_sorted_varsize<T>(ca: &ChunkedArray<T>) -> GroupsSlice
+where
+    T: PolarsDataType,
+    for<'a> T::Array: StaticArray<ValueT<'a> = &'a [u8]>,
+{
+    let mut out = Vec::with_capacity(ca.len() / 30);
+    let mut chunk_groups = Vec::new();
+    let mut offset = 0;
+    let mut prev_last: Option<&[u8]> = None;
+    for arr in ca.downcast_iter().filter(|arr| !arr.is_empty()) {
+        partition_to_groups_amortized_varsize(
+            arr.values_iter(),
+            arr.len() as _,
+            0,
+            false,
+            0,
+            &mut chunk_groups,
+        );
+        let continues_group = prev_last == Some(arr.value(0));
+        extend_sorted_groups(
+            &mut out,
+            std::mem::take(&mut chunk_groups),
+            offset,
+            continues_group,
+        );
+        offset += arr.len() as IdxSize;
+        prev_last = Some(arr.value(arr.len() - 1));
+    }
+    out
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -66,7 +116,46 @@ where
         if verbose() {
             eprintln!("group_by keys are sorted; running sorted key fast path");
         }
-        let arr = self.downcast_iter().next().unwrap();
+        if self.chunks().len() == 1 {
+            let arr = self.downcast_iter().next().unwrap();
+            return self.create_groups_from_sorted_arr(arr, multithreaded);
+        }
+
+        // Create the groups per chunk and stitch them together at the chunk boundaries,
+        // this prevents a rechunk of the keys.
+        let arrs = self
+            .downcast_iter()
+            .filter(|arr| !arr.is_empty())
+            .collect::<Vec<_>>();
+        let chunk_groups = if multithreaded && POOL.current_num_threads() > 1 {
+            POOL.install(|| {
+                arrs.par_iter()
+                    .map(|arr| self.create_groups_from_sorted_arr(arr, false))
+                    .collect::<Vec<_>>()
+            })
+        } else {
+            arrs.iter()
+                .map(|arr| self.create_groups_from_sorted_arr(arr, false))
+                .collect::<Vec<_>>()
+        };
+
+        let mut out = Vec::with_capacity(chunk_groups.iter().map(|g| g.len()).sum());
+        let mut offset = 0;
+        let mut prev_last: Option<Option<T::Native>> = None;
+        for (arr, groups) in arrs.iter().zip(chunk_groups) {
+            let continues_group = prev_last.is_some_and(|last| last.tot_eq(&arr.get(0)));
+            extend_sorted_groups(&mut out, groups, offset, continues_group);
+            offset += arr.len() as IdxSize;
+            prev_last = Some(arr.get(arr.len() - 1));
+        }
+        out
+    }
+
+    fn create_groups_from_sorted_arr(
+        &self,
+        arr: &PrimitiveArray<T::Native>,
+        multithreaded: bool,
+    ) -> GroupsSlice {
         if arr.is_empty() {
             return GroupsSlice::default();
         }
@@ -146,7 +235,7 @@ where
         if self.is_sorted_ascending_flag() || self.is_sorted_descending_flag() {
             // don't have to pass `sorted` arg, GroupSlice is always sorted.
             return Ok(GroupsType::Slice {
-                groups: self.rechunk().create_groups_from_sorted(multithreaded),
+                groups: self.create_groups_from_sorted(multithreaded),
                 rolling: false,
             });
         }
@@ -278,13 +367,12 @@ impl IntoGroupsType for BinaryChunked {
         mut multithreaded: bool,
         sorted: bool,
     ) -> PolarsResult<GroupsType> {
-        if self.is_sorted_any() && !self.has_nulls() && self.n_chunks() == 1 {
-            let arr = self.downcast_get(0).unwrap();
-            let values = arr.values_iter();
-            let mut out = Vec::with_capacity(values.len() / 30);
-            partition_to_groups_amortized_varsize(values, arr.len() as _, 0, false, 0, &mut out);
+        if self.is_sorted_any() && !self.has_nulls() {
+            if verbose() {
+                eprintln!("group_by keys are sorted; running sorted key fast path");
+            }
             return Ok(GroupsType::Slice {
-                groups: out,
+                groups: create_groups_from_sorted_varsize(self),
                 rolling: false,
             });
         }
@@ -312,13 +400,9 @@ impl IntoGroupsType for BinaryOffsetChunked {
         mut multithreaded: bool,
         sorted: bool,
     ) -> PolarsResult<GroupsType> {
-        if self.is_sorted_any() && !self.has_nulls() && self.n_chunks() == 1 {
-            let arr = self.downcast_get(0).unwrap();
-            let values = arr.values_iter();
-            let mut out = Vec::with_capacity(values.len() / 30);
-            partition_to_groups_amortized_varsize(values, arr.len() as _, 0, false, 0, &mut out);
+        if self.is_sorted_any() && !self.has_nulls() {
             return Ok(GroupsType::Slice {
-                groups: out,
+                groups: create_groups_from_sorted_varsize(self),
                 rolling: false,
             });
         }
@@ -386,3 +470,67 @@ where
         Ok(group_by(self.into_iter(), sorted))
     }
 }
+
+/// Whether the groups of `s` are built by a sorted fast path when it has a sorted flag.
+fn has_sorted_groups_path(s: &Series) -> bool {
+    if s.is_sorted_flag() == IsSorted::Not || s.dtype().is_categorical() || s.dtype().is_enum() {
+        return false;
+    }
+    match s.dtype().to_physical() {
+        dt if dt.is_primitive_numeric() => true,
+        DataType::String | DataType::Binary => !s.has_nulls(),
+        _ => false,
+    }
+}
+
+/// Create the groups of multiple keys that all have a sorted flag, without hashing.
+///
+/// The rows with an equal value of a sorted key are contiguous, and so are the rows in the
+/// intersection of such ranges. So a new group starts wherever any of the keys starts a new
+/// group, and the groups are ordered by their first row. Returns `None` if not all keys are
+/// sorted.
+pub(crate) fn group_tuples_sorted_keys(
+    by: &[Column],
+    multithreaded: bool,
+) -> PolarsResult<Option<GroupsType>> {
+    if by.len() < 2
+        || !by
+            .iter()
+            .all(|c| has_sorted_groups_path(c.as_materialized_series()))
+    {
+        return Ok(None);
+    }
+    if verbose() {
+        eprintln!("group_by keys are sorted; running sorted multi-key fast path");
+    }
+
+    let height = by[0].len();
+    let mut is_start = vec![false; height];
+    for c in by {
+        let GroupsType::Slice { groups, .. } = c
+            .as_materialized_series()
+            .group_tuples(multithreaded, false)?
+        else {
+            return Ok(None);
+        };
+        for [first, _] in groups {
+            is_start[first as usize] = true;
+        }
+    }
+
+    let mut groups = Vec::new();
+    let mut first = 0;
+    for (i, is_start) in is_start.into_iter().enumerate().skip(1) {
+        if is_start {
+            groups.push([first as IdxSize, (i - first) as IdxSize]);
+            first = i;
+        }
+    }
+    if height > 0 {
+        groups.push([first as IdxSize, (height - first) as IdxSize]);
+    }
+    Ok(Some(GroupsType::Slice {
+        groups,
+        rolling: false,
+    }))
+}
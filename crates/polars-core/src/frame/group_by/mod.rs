@@ -23,7 +23,7 @@ pub use into_groups::*;
 pub use position::*;
 
 use crate::chunked_array::ops::row_encode::{
-    encode_rows_unordered, encode_rows_vertical_par_unordered,
+    encode_rows_vertical_par_unordered, encode_rows_vertical_unordered,
 };
 
 impl DataFrame {
@@ -91,11 +91,13 @@ impl DataFrame {
                     groups,
                     rolling: false,
                 })
+            } else if let Some(groups) = group_tuples_sorted_keys(&by, multithreaded)? {
+                Ok(groups)
             } else {
                 let rows = if multithreaded {
                     encode_rows_vertical_par_unordered(&by)
                 } else {
-                    encode_rows_unordered(&by)
+                    encode_rows_vertical_unordered(&by)
                 }?
                 .into_series();
                 rows.group_tuples(multithreaded, sorted)
@@ -1207,4 +1209,83 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_sorted_keys_over_chunks() -> PolarsResult<()> {
+        use crate::series::IsSorted;
+
+        let mut ints = Int32Chunked::new("a".into(), &[None, None]);
+        ints.append(&Int32Chunked::new("a".into(), &[None, Some(1), Some(1)]))?;
+        ints.append(&Int32Chunked::new("a".into(), &[Some(1), Some(2)]))?;
+        ints.append(&Int32Chunked::new("a".into(), &[Some(2), Some(2), Some(3)]))?;
+        ints.set_sorted_flag(IsSorted::Ascending);
+        assert_eq!(ints.chunks().len(), 4);
+
+        let groups = ints.group_tuples(false, false)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 3], [3, 3], [6, 3], [9, 1]]);
+        assert_eq!(groups, ints.group_tuples(true, false)?);
+
+        let mut strs = StringChunked::new("b".into(), &["a", "a"]);
+        strs.append(&StringChunked::new("b".into(), &["a", "b"]))?;
+        strs.append(&StringChunked::new("b".into(), &["c"]))?;
+        strs.set_sorted_flag(IsSorted::Ascending);
+
+        let groups = strs.group_tuples(false, false)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 3], [3, 1], [4, 1]]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_multiple_keys_over_chunks() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1, 1, 2],
+            "b" => ["x", "y", "x"],
+        ]?;
+        df.vstack_mut(&df![
+            "a" => [2, 1, 3],
+            "b" => ["x", "x", "y"],
+        ]?)?;
+        assert_eq!(df.first_col_n_chunks(), 2);
+
+        for multithreaded in [false, true] {
+            let keys = df.get_columns().to_vec();
+            let groups = df
+                .group_by_with_series(keys, multithreaded, true)?
+                .take_groups();
+            let mut firsts = groups
+                .iter()
+                .map(|g| (g.first(), g.len()))
+                .collect::<Vec<_>>();
+            firsts.sort();
+            assert_eq!(firsts, [(0, 2), (1, 1), (2, 2), (5, 1)]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_sorted_multiple_keys() -> PolarsResult<()> {
+        use crate::series::IsSorted;
+
+        let mut a = Int32Chunked::new("a".into(), &[1, 1, 1, 2, 2, 3]);
+        a.set_sorted_flag(IsSorted::Ascending);
+        let mut b = StringChunked::new("b".into(), &["z", "z", "y", "y", "y", "x"]);
+        b.set_sorted_flag(IsSorted::Descending);
+        let df = DataFrame::new(vec![a.into_column(), b.into_column()])?;
+
+        let groups = df.group_by(["a", "b"])?.take_groups();
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 1], [3, 2], [5, 1]]);
+
+        // Without the flags the keys are hashed.
+        let df = df![
+            "a" => [1, 1, 1, 2, 2, 3],
+            "b" => ["z", "z", "y", "y", "y", "x"],
+        ]?;
+        let hashed = df.group_by_stable(["a", "b"])?.take_groups();
+        assert!(matches!(&*hashed, GroupsType::Idx(_)));
+        assert_eq!(hashed.len(), groups.len());
+        Ok(())
+    }
 }
@@ -12,6 +12,7 @@ use rayon::prelude::*;
 use crate::chunked_array::flags::StatisticsFlags;
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
+use crate::error::PolarsResultExt;
 use crate::prelude::*;
 #[cfg(feature = "row_hash")]
 use crate::utils::split_df;
@@ -24,7 +25,11 @@ pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
 pub mod column;
+mod deep_copy;
 pub mod explode;
+mod export;
+mod fold;
+pub use fold::FoldOp;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
@@ -32,6 +37,7 @@ pub(crate) mod horizontal;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+pub mod unnest;
 mod upstream_traits;
 mod validation;
 
@@ -486,6 +492,20 @@ impl DataFrame {
         DataFrame::new(columns)
     }
 
+    /// Add a row index column of type `UInt64` in front of the `DataFrame`, regardless of the
+    /// [`IdxSize`] of this build.
+    ///
+    /// Errors if the index would overflow `u64::MAX`.
+    pub fn with_row_index_u64(&self, name: PlSmallStr, offset: Option<u64>) -> PolarsResult<Self> {
+        let mut columns = Vec::with_capacity(self.columns.len() + 1);
+        let offset = offset.unwrap_or(0);
+
+        let col = Column::new_row_index_u64(name, offset, self.height())?;
+        columns.push(col);
+        columns.extend_from_slice(&self.columns);
+        DataFrame::new(columns)
+    }
+
     /// Add a row index column in place.
     ///
     /// # Safety
@@ -556,6 +576,28 @@ impl DataFrame {
         }
     }
 
+    /// Clone the DataFrame into fresh allocations.
+    ///
+    /// [`DataFrame::clone`] shares the underlying buffers with `self`, whereas the result of this
+    /// doesn't share any memory with `self`. Scalar columns stay scalar, their nested values are
+    /// copied as well. Columns of dtype object can't be copied.
+    pub fn try_clone_owned(&self) -> PolarsResult<DataFrame> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| match c {
+                Column::Scalar(sc) => Ok(Column::new_scalar(
+                    sc.name().clone(),
+                    deep_copy::deep_copy_scalar(sc.scalar())?,
+                    sc.len(),
+                )),
+                c => deep_copy::deep_copy_series(c.as_materialized_series()).map(Column::from),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // SAFETY: the columns are copies of the columns of `self`.
+        Ok(unsafe { DataFrame::new_no_checks(self.height(), columns) })
+    }
+
     /// Shrink the capacity of this DataFrame to fit its length.
     pub fn shrink_to_fit(&mut self) {
         // Don't parallelize this. Memory overhead
@@ -582,6 +624,22 @@ impl DataFrame {
         self
     }
 
+    /// Returns a copy with all columns rechunked to a single chunk, rechunking the columns in
+    /// parallel. This may lead to more peak memory consumption than [`DataFrame::rechunk_mut`].
+    ///
+    /// The columns are only rechunked in parallel if more than one of them has multiple chunks
+    /// and the thread pool has more than one thread.
+    pub fn rechunk_parallel(&self) -> DataFrame {
+        let mut df = self.clone();
+        let n_chunked = self.columns.iter().filter(|c| c.n_chunks() > 1).count();
+        if n_chunked > 1 && POOL.current_num_threads() > 1 {
+            df.as_single_chunk_par();
+        } else {
+            df.rechunk_mut();
+        }
+        df
+    }
+
     /// Rechunks all columns to only have a single chunk.
     pub fn rechunk_mut(&mut self) {
         // SAFETY: We never adjust the length or names of the columns.
@@ -1165,9 +1223,8 @@ impl DataFrame {
             .zip(other.columns.iter())
             .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
                 ensure_can_extend(&*left, right)?;
-                left.append(right).map_err(|e| {
-                    e.context(format!("failed to vstack column '{}'", right.name()).into())
-                })?;
+                left.append(right)
+                    .with_context(|| format!("failed to vstack column '{}'", right.name()))?;
                 Ok(())
             })?;
         self.height += other.height;
@@ -1193,9 +1250,8 @@ impl DataFrame {
             .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
                 ensure_can_extend(&*left, &right)?;
                 let right_name = right.name().clone();
-                left.append_owned(right).map_err(|e| {
-                    e.context(format!("failed to vstack column '{right_name}'").into())
-                })?;
+                left.append_owned(right)
+                    .with_context(|| format!("failed to vstack column '{right_name}'"))?;
                 Ok(())
             })?;
         self.height += other.height;
@@ -1214,9 +1270,7 @@ impl DataFrame {
             .zip(other.columns.iter())
             .for_each(|(left, right)| {
                 left.append(right)
-                    .map_err(|e| {
-                        e.context(format!("failed to vstack column '{}'", right.name()).into())
-                    })
+                    .with_context(|| format!("failed to vstack column '{}'", right.name()))
                     .expect("should not fail");
             });
         self.height += other.height;
@@ -1265,9 +1319,8 @@ impl DataFrame {
             .zip(other.columns.iter())
             .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
                 ensure_can_extend(&*left, right)?;
-                left.extend(right).map_err(|e| {
-                    e.context(format!("failed to extend column '{}'", right.name()).into())
-                })?;
+                left.extend(right)
+                    .with_context(|| format!("failed to extend column '{}'", right.name()))?;
                 Ok(())
             })?;
         self.height += other.height;
@@ -3311,34 +3364,7 @@ impl DataFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoVec<PlSmallStr>>(&self, cols: I) -> PolarsResult<DataFrame> {
-        let cols = cols.into_vec();
-        self.unnest_impl(cols.into_iter().collect())
-    }
-
-    #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(&self, cols: PlHashSet<PlSmallStr>) -> PolarsResult<DataFrame> {
-        let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
-        let mut count = 0;
-        for s in &self.columns {
-            if cols.contains(s.name()) {
-                let ca = s.struct_()?.clone();
-                new_cols.extend(ca.fields_as_series().into_iter().map(Column::from));
-                count += 1;
-            } else {
-                new_cols.push(s.clone())
-            }
-        }
-        if count != cols.len() {
-            // one or more columns not found
-            // the code below will return an error with the missing name
-            let schema = self.schema();
-            for col in cols {
-                let _ = schema
-                    .get(col.as_str())
-                    .ok_or_else(|| polars_err!(col_not_found = col))?;
-            }
-        }
-        DataFrame::new(new_cols)
+        self.unnest_with_options(cols, &UnnestOptions::default())
     }
 
     pub(crate) fn infer_height(cols: &[Column]) -> usize {
@@ -3475,6 +3501,43 @@ mod test {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_record_batches_roundtrip() {
+        let mut df = df!(
+            "foo" => [1, 2, 3],
+            "bar" => ["a", "b", "c"]
+        )
+        .unwrap();
+        let other = df.clone();
+        df.vstack_mut(&other).unwrap();
+
+        let batches = df.to_record_batches();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].height(), 3);
+
+        let out = DataFrame::from_record_batches(batches).unwrap();
+        assert!(out.equals(&df));
+        assert_eq!(out.first_col_n_chunks(), 2);
+
+        let mut batches = df.to_record_batches();
+        batches.extend(df!("foo" => [1.0]).unwrap().to_record_batches());
+        assert!(DataFrame::from_record_batches(batches).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_rechunk_parallel() {
+        let mut df = create_frame();
+        let other = df.clone();
+        df.vstack_mut(&other).unwrap();
+        assert_eq!(df.first_col_n_chunks(), 2);
+
+        let out = df.rechunk_parallel();
+        assert!(out.get_columns().iter().all(|c| c.n_chunks() == 1));
+        assert!(out.equals(&df));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_select() {
@@ -3618,6 +3681,79 @@ mod test {
         assert_eq!(df.first_col_n_chunks(), 2)
     }
 
+    #[test]
+    fn test_try_clone_owned() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "a longer string that is not inlined", "z"],
+        ]?;
+        let copy = df.try_clone_owned()?;
+        assert!(copy.equals(&df));
+
+        let ptr = |df: &DataFrame| {
+            df.column("a")
+                .unwrap()
+                .i32()
+                .unwrap()
+                .cont_slice()
+                .unwrap()
+                .as_ptr()
+        };
+        assert_ne!(ptr(&df), ptr(&copy));
+
+        // The list value of a scalar column is copied too.
+        let list = Series::new("".into(), [1i32, 2]);
+        let list_ptr = |s: &Series| s.i32().unwrap().cont_slice().unwrap().as_ptr();
+        let scalar = Scalar::new(
+            DataType::List(Box::new(DataType::Int32)),
+            AnyValue::List(list.clone()),
+        );
+        let df = DataFrame::new(vec![Column::new_scalar("l".into(), scalar, 3)])?;
+        let copy = df.try_clone_owned()?;
+        assert!(copy.equals(&df));
+        let Column::Scalar(sc) = copy.column("l")? else {
+            panic!("expected a scalar column")
+        };
+        let AnyValue::List(copied) = sc.scalar().value() else {
+            panic!("expected a list value")
+        };
+        assert!(copied.equals(&list));
+        assert_ne!(list_ptr(copied), list_ptr(&list));
+        Ok(())
+    }
+
+    #[test]
+    fn test_vstack_error_context() -> PolarsResult<()> {
+        let mut df = df!("a" => [1i32, 2])?;
+        let other = df!("a" => ["x", "y"])?;
+
+        let err = df.vstack_mut(&other).unwrap_err();
+        assert!(matches!(&err, PolarsError::SchemaMismatch(_)));
+        assert!(
+            err.to_string()
+                .starts_with("failed to vstack column 'a': type String is incompatible")
+        );
+
+        let err = err.with_context("while appending");
+        assert_eq!(err.contexts().collect::<Vec<_>>(), ["while appending"]);
+
+        // Prepending to the message keeps the context layers.
+        let err = Err::<(), _>(err.with_context("in query"))
+            .context("reading 'x'")
+            .unwrap_err();
+        assert_eq!(
+            err.contexts().collect::<Vec<_>>(),
+            ["in query", "while appending"]
+        );
+        let err = err.remove_context();
+        assert!(matches!(&err, PolarsError::SchemaMismatch(_)));
+        assert!(
+            err.to_string()
+                .starts_with("reading 'x': failed to vstack column 'a': type String")
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_vstack_on_empty_dataframe() {
         let mut df = DataFrame::empty();
@@ -3666,4 +3802,32 @@ mod test {
         .unwrap();
         assert!(out.equals(&expected));
     }
+
+    #[test]
+    fn test_with_row_index_u64() -> PolarsResult<()> {
+        let df = df!("x" => [1, 2, 3])?;
+        let offset = u64::from(u32::MAX);
+        let out = df.with_row_index_u64("idx".into(), Some(offset))?;
+        let idx = out.column("idx")?.u64()?;
+        assert_eq!(
+            Vec::from(idx),
+            [Some(offset), Some(offset + 1), Some(offset + 2)]
+        );
+        assert_eq!(idx.is_sorted_flag(), IsSorted::Ascending);
+
+        assert!(
+            df.with_row_index_u64("idx".into(), Some(u64::MAX - 1))
+                .is_err()
+        );
+
+        // The last index may be `u64::MAX`.
+        let df = df!("x" => [1])?;
+        let out = df.with_row_index_u64("idx".into(), Some(u64::MAX))?;
+        assert_eq!(out.column("idx")?.u64()?.get(0), Some(u64::MAX));
+        let out = df
+            .clear()
+            .with_row_index_u64("idx".into(), Some(u64::MAX))?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
 }
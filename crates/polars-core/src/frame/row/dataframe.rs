@@ -10,6 +10,25 @@ impl DataFrame {
         Ok(Row(values))
     }
 
+    /// Get a row from a [`DataFrame`] whose values can be accessed by column name. Use of this is
+    /// discouraged as it will likely be slow.
+    pub fn get_named_row(&self, idx: usize) -> PolarsResult<NamedRow<'_>> {
+        polars_ensure!(
+            idx < self.height(),
+            OutOfBounds: "row index {} is out of bounds for a DataFrame of height {}", idx, self.height()
+        );
+        let row = self.get_row(idx)?;
+        Ok(NamedRow::new(self.schema().clone(), row))
+    }
+
+    /// Iterate over the rows of a [`DataFrame`].
+    ///
+    /// The rows are borrowed views, values are only read when they are accessed. This is still a
+    /// lot slower than working on the columns directly.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = RowView<'_>> {
+        (0..self.height()).map(|idx| RowView { df: self, idx })
+    }
+
     /// Amortize allocations by reusing a row.
     /// The caller is responsible to make sure that the row has at least the capacity for the number
     /// of columns in the [`DataFrame`]
@@ -47,9 +66,28 @@ impl DataFrame {
     ///
     /// This should only be used when you have row wise data, as this is a lot slower
     /// than creating the [`Series`] in a columnar fashion.
-    pub fn from_rows_iter_and_schema<'a, I>(mut rows: I, schema: &Schema) -> PolarsResult<Self>
+    pub fn from_rows_iter_and_schema<'a, I>(rows: I, schema: &Schema) -> PolarsResult<Self>
     where
         I: Iterator<Item = &'a Row<'a>>,
+    {
+        Self::from_rows_iter_impl(rows, schema)
+    }
+
+    /// Create a new [`DataFrame`] from an iterator over owned rows.
+    ///
+    /// The values are appended to per-column builders while the iterator is consumed, so the rows
+    /// don't have to be collected first.
+    pub fn from_rows_iter<'a, I>(rows: I, schema: &Schema) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        Self::from_rows_iter_impl(rows.into_iter(), schema)
+    }
+
+    fn from_rows_iter_impl<'a, I, R>(mut rows: I, schema: &Schema) -> PolarsResult<Self>
+    where
+        I: Iterator<Item = R>,
+        R: Borrow<Row<'a>>,
     {
         if schema.is_empty() {
             let height = rows.count();
@@ -70,7 +108,7 @@ impl DataFrame {
         let mut expected_len = 0;
         rows.try_for_each::<_, PolarsResult<()>>(|row| {
             expected_len += 1;
-            for (value, buf) in row.0.iter().zip(&mut buffers) {
+            for (value, buf) in row.borrow().0.iter().zip(&mut buffers) {
                 buf.add_fallible(value)?
             }
             Ok(())
@@ -148,3 +186,76 @@ impl DataFrame {
         Self::from_rows_and_schema(rows, &schema)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_named_row() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 2],
+            "b" => [Some("x"), None],
+        )?;
+
+        let row = df.get_named_row(1)?;
+        assert_eq!(row.get_by_name("a"), Some(&AnyValue::Int32(2)));
+        assert_eq!(row.get_by_name("c"), None);
+        assert!(row.try_get_by_name("c").is_err());
+        assert_eq!(row.extract_by_name::<i64>("a")?, Some(2));
+        assert_eq!(row.str_by_name("b")?, None);
+        assert_eq!(df.get_named_row(0)?.str_by_name("b")?, Some("x"));
+        assert_eq!(row.into_row(), df.get_row(1)?);
+
+        assert!(df.get_named_row(2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+        )?;
+
+        let rows = df.rows();
+        assert_eq!(rows.len(), 3);
+        for (i, row) in rows.enumerate() {
+            assert_eq!(row.index(), i);
+            assert_eq!(row.len(), 2);
+            assert_eq!(row.to_row(), df.get_row(i)?);
+        }
+
+        let row = df.rows().nth(2).unwrap();
+        assert_eq!(row.get(0), Some(AnyValue::Int32(3)));
+        assert_eq!(row.get_by_name("b"), Some(AnyValue::String("z")));
+        assert_eq!(row.get(2), None);
+        assert_eq!(row.get_by_name("c"), None);
+
+        let df = df.clear();
+        assert_eq!(df.rows().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_rows_iter() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+        ]);
+        let rows = (0..3).map(|i| {
+            Row::new(vec![
+                AnyValue::Int32(i),
+                AnyValue::StringOwned(format!("x{i}").into()),
+            ])
+        });
+
+        let df = DataFrame::from_rows_iter(rows, &schema)?;
+        let expected = df!(
+            "a" => [0i32, 1, 2],
+            "b" => ["x0", "x1", "x2"],
+        )?;
+        assert!(df.equals(&expected));
+        Ok(())
+    }
+}
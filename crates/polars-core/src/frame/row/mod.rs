@@ -9,6 +9,7 @@ use std::hash::{Hash, Hasher};
 
 use arrow::bitmap::Bitmap;
 pub use av_buffer::*;
+use num_traits::NumCast;
 use polars_utils::format_pl_smallstr;
 #[cfg(feature = "object")]
 use polars_utils::total_ord::TotalHash;
@@ -96,6 +97,113 @@ impl<'a> Row<'a> {
     }
 }
 
+/// A [`Row`] together with the schema of the [`DataFrame`] it was taken from, so that its values
+/// can be accessed by column name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedRow<'a> {
+    schema: SchemaRef,
+    row: Row<'a>,
+}
+
+impl<'a> NamedRow<'a> {
+    /// # Panics
+    /// Panics if the number of values doesn't match the length of the schema.
+    pub fn new(schema: SchemaRef, row: Row<'a>) -> Self {
+        assert_eq!(schema.len(), row.0.len());
+        Self { schema, row }
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn values(&self) -> &[AnyValue<'a>] {
+        &self.row.0
+    }
+
+    pub fn into_row(self) -> Row<'a> {
+        self.row
+    }
+
+    /// Get the value of the column `name`.
+    pub fn get_by_name(&self, name: &str) -> Option<&AnyValue<'a>> {
+        let idx = self.schema.index_of(name)?;
+        Some(&self.row.0[idx])
+    }
+
+    /// Get the value of the column `name`, erroring if the column doesn't exist.
+    pub fn try_get_by_name(&self, name: &str) -> PolarsResult<&AnyValue<'a>> {
+        let idx = self.schema.try_index_of(name)?;
+        Ok(&self.row.0[idx])
+    }
+
+    /// Get the value of the numeric column `name` as `T`.
+    ///
+    /// Returns `Ok(None)` if the value is null or can't be represented as `T`.
+    pub fn extract_by_name<T: NumCast>(&self, name: &str) -> PolarsResult<Option<T>> {
+        Ok(self.try_get_by_name(name)?.extract())
+    }
+
+    /// Get the value of the string column `name`.
+    ///
+    /// Returns `Ok(None)` if the value is null or not a string.
+    pub fn str_by_name(&self, name: &str) -> PolarsResult<Option<&str>> {
+        Ok(self.try_get_by_name(name)?.get_str())
+    }
+}
+
+/// A borrowed view of a single row of a [`DataFrame`], see [`DataFrame::rows`].
+///
+/// Values are read from the columns on access, no [`Row`] is allocated.
+#[derive(Debug, Clone, Copy)]
+pub struct RowView<'a> {
+    df: &'a DataFrame,
+    idx: usize,
+}
+
+impl<'a> RowView<'a> {
+    /// The index of this row in the [`DataFrame`].
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// The number of values in this row.
+    pub fn len(&self) -> usize {
+        self.df.width()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.df.width() == 0
+    }
+
+    /// Get the value of the `i`-th column.
+    pub fn get(&self, i: usize) -> Option<AnyValue<'a>> {
+        let column = self.df.get_columns().get(i)?;
+        // SAFETY: `idx` is smaller than the height of the DataFrame.
+        Some(unsafe { column.get_unchecked(self.idx) })
+    }
+
+    /// Get the value of the column `name`.
+    pub fn get_by_name(&self, name: &str) -> Option<AnyValue<'a>> {
+        self.get(self.df.get_column_index(name)?)
+    }
+
+    /// Iterate over the values of this row.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = AnyValue<'a>> + 'a {
+        let idx = self.idx;
+        self.df
+            .get_columns()
+            .iter()
+            // SAFETY: `idx` is smaller than the height of the DataFrame.
+            .map(move |c| unsafe { c.get_unchecked(idx) })
+    }
+
+    /// Collect the values into an owned [`Row`].
+    pub fn to_row(&self) -> Row<'a> {
+        Row(self.iter().collect())
+    }
+}
+
 type Tracker = PlIndexMap<PlSmallStr, PlHashSet<DataType>>;
 
 pub fn infer_schema(
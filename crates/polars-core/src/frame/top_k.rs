@@ -2,6 +2,33 @@ use super::*;
 use crate::prelude::sort::arg_bottom_k::_arg_bottom_k;
 
 impl DataFrame {
+    /// Return the `k` rows with the largest values in the `by` columns, from largest to smallest.
+    ///
+    /// This is equivalent to sorting in reverse order with the nulls last and taking the first
+    /// `k` rows, but only the selected rows are sorted. See [`DataFrame::bottom_k`].
+    pub fn top_k(
+        &self,
+        k: usize,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<DataFrame> {
+        self.bottom_k(k, by, sort_options.with_order_reversed())
+    }
+
+    /// Return the `k` rows with the smallest values in the `by` columns, from smallest to largest.
+    ///
+    /// The rows are selected with a partition on their encoded sort keys, after which only the
+    /// selected rows are sorted. Nulls are always placed last.
+    pub fn bottom_k(
+        &self,
+        k: usize,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<DataFrame> {
+        let by_column = self.select_columns(by)?;
+        self.sort_impl(by_column, sort_options.with_nulls_last(true), Some((0, k)))
+    }
+
     pub(crate) fn bottom_k_impl(
         &self,
         k: usize,
@@ -30,3 +57,39 @@ impl DataFrame {
         Ok(df)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_top_k() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1), Some(3), None, Some(3), Some(2)],
+            "b" => ["v", "w", "x", "y", "z"],
+        ]?;
+
+        let out = df.top_k(3, ["a", "b"], Default::default())?;
+        let expected = df![
+            "a" => [3, 3, 2],
+            "b" => ["y", "w", "z"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = df.bottom_k(
+            2,
+            ["a", "b"],
+            SortMultipleOptions::default().with_order_descending_multi([false, true]),
+        )?;
+        let expected = df![
+            "a" => [1, 2],
+            "b" => ["v", "z"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = df.bottom_k(10, ["a"], Default::default())?;
+        assert_eq!(out.height(), 5);
+        assert!(out.column("a")?.get(4)?.is_null());
+        Ok(())
+    }
+}
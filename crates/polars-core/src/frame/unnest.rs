@@ -0,0 +1,212 @@
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// What to do when an unnested field has the same name as another output column.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum CollisionPolicy {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Replace the existing column with the field.
+    Overwrite,
+    /// Rename the field to `{struct_name}_{field_name}`.
+    RenameWithPrefix,
+}
+
+/// Where the fields of an unnested struct column are placed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum InsertionPoint {
+    /// At the position of the struct column they replace.
+    #[default]
+    OriginalPosition,
+    /// After all columns that are not unnested.
+    End,
+}
+
+/// Options for `DataFrame::unnest_with_options`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct UnnestOptions {
+    /// Prefix added to the name of every unnested field.
+    pub prefix: Option<PlSmallStr>,
+    pub collision: CollisionPolicy,
+    pub insert_at: InsertionPoint,
+}
+
+/// An input column of an unnest: its name, its value and, if it is unnested, its fields.
+pub type UnnestInput<T> = (PlSmallStr, T, Option<Vec<(PlSmallStr, T)>>);
+
+impl UnnestOptions {
+    /// Whether the output names can differ from the names of the fields and columns in the input.
+    pub fn renames(&self) -> bool {
+        self.prefix.is_some() || self.collision != CollisionPolicy::Error
+    }
+
+    /// Determine the output columns of an unnest.
+    ///
+    /// The input columns are given in order, see [`UnnestInput`].
+    pub fn resolve<T>(&self, columns: Vec<UnnestInput<T>>) -> PolarsResult<Vec<(PlSmallStr, T)>> {
+        let kept = columns
+            .iter()
+            .filter(|(_, _, fields)| fields.is_none())
+            .map(|(name, _, _)| name.clone())
+            .collect::<PlHashSet<_>>();
+        let columns = match self.insert_at {
+            InsertionPoint::OriginalPosition => columns,
+            InsertionPoint::End => {
+                let (kept, unnested): (Vec<_>, Vec<_>) = columns
+                    .into_iter()
+                    .partition(|(_, _, fields)| fields.is_none());
+                kept.into_iter().chain(unnested).collect()
+            },
+        };
+
+        let mut out = Vec::with_capacity(columns.len());
+        let mut positions = PlHashMap::with_capacity(columns.len());
+        // Kept columns that are replaced by a field before they are reached.
+        let mut overwritten = PlHashSet::new();
+
+        for (name, value, fields) in columns {
+            let Some(fields) = fields else {
+                if !overwritten.contains(&name) {
+                    positions.insert(name.clone(), out.len());
+                    out.push(Some((name, value)));
+                }
+                continue;
+            };
+
+            for (field_name, field) in fields {
+                let mut field_name = match &self.prefix {
+                    Some(prefix) => format_pl_smallstr!("{prefix}{field_name}"),
+                    None => field_name,
+                };
+                if positions.contains_key(&field_name) || kept.contains(&field_name) {
+                    match self.collision {
+                        CollisionPolicy::Error => polars_bail!(
+                            Duplicate: "field '{}' of unnested column '{}' collides with an existing column",
+                            field_name, name
+                        ),
+                        CollisionPolicy::Overwrite => match positions.get(&field_name) {
+                            Some(&idx) => out[idx] = None,
+                            None => {
+                                overwritten.insert(field_name.clone());
+                            },
+                        },
+                        CollisionPolicy::RenameWithPrefix => {
+                            field_name = format_pl_smallstr!("{name}_{field_name}");
+                            polars_ensure!(
+                                !positions.contains_key(&field_name) && !kept.contains(&field_name),
+                                Duplicate: "renamed field '{}' of unnested column '{}' collides with an existing column",
+                                field_name, name
+                            );
+                        },
+                    }
+                }
+                positions.insert(field_name.clone(), out.len());
+                out.push(Some((field_name, field)));
+            }
+        }
+
+        Ok(out.into_iter().flatten().collect())
+    }
+}
+
+impl DataFrame {
+    /// Unnest the given `Struct` columns, with control over the naming and placement of the
+    /// resulting columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<I: IntoVec<PlSmallStr>>(
+        &self,
+        cols: I,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
+        let cols = cols.into_vec().into_iter().collect::<PlHashSet<_>>();
+        for col in &cols {
+            self.try_get_column_index(col)?;
+        }
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| {
+                let fields = if cols.contains(c.name()) {
+                    let fields = c.struct_()?.fields_as_series();
+                    Some(
+                        fields
+                            .into_iter()
+                            .map(|s| (s.name().clone(), Column::from(s)))
+                            .collect(),
+                    )
+                } else {
+                    None
+                };
+                Ok((c.name().clone(), c.clone(), fields))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let columns = options
+            .resolve(columns)?
+            .into_iter()
+            .map(|(name, c)| c.with_name(name))
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn unnest_with_options() -> PolarsResult<()> {
+        let meta = df!(
+            "a" => [1, 2],
+            "b" => [3, 4],
+        )?
+        .into_struct("meta".into())
+        .into_column();
+        let df = DataFrame::new(vec![
+            Column::new("a".into(), [5, 6]),
+            meta,
+            Column::new("c".into(), [7, 8]),
+        ])?;
+
+        assert!(df.unnest(["meta"]).is_err());
+
+        let options = UnnestOptions {
+            collision: CollisionPolicy::Overwrite,
+            ..Default::default()
+        };
+        let out = df.unnest_with_options(["meta"], &options)?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(out.column("a")?, &Column::new("a".into(), [1, 2]));
+
+        let options = UnnestOptions {
+            collision: CollisionPolicy::RenameWithPrefix,
+            insert_at: InsertionPoint::End,
+            ..Default::default()
+        };
+        let out = df.unnest_with_options(["meta"], &options)?;
+        assert_eq!(out.get_column_names(), &["a", "c", "meta_a", "b"]);
+
+        let options = UnnestOptions {
+            prefix: Some("m_".into()),
+            ..Default::default()
+        };
+        let out = df.unnest_with_options(["meta"], &options)?;
+        assert_eq!(out.get_column_names(), &["a", "m_a", "m_b", "c"]);
+
+        assert!(df.unnest_with_options(["missing"], &options).is_err());
+        Ok(())
+    }
+}
@@ -32,7 +32,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use datatypes::SchemaExtPl;
 pub use hashing::IdBuildHasher;
-use rayon::{ThreadPool, ThreadPoolBuilder};
+pub use polars_utils::pool::{ComputePool, PolarsPool};
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+use rayon::ThreadPoolBuilder;
 
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::string_cache::*;
@@ -46,9 +48,9 @@ pub static PROCESS_ID: LazyLock<u128> = LazyLock::new(|| {
 
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
-pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
+pub static POOL: LazyLock<PolarsPool> = LazyLock::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
-    ThreadPoolBuilder::new()
+    let pool = ThreadPoolBuilder::new()
         .num_threads(
             std::env::var("POLARS_MAX_THREADS")
                 .map(|s| s.parse::<usize>().expect("integer"))
@@ -60,16 +62,18 @@ pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
         )
         .thread_name(move |i| format!("{thread_name}-{i}"))
         .build()
-        .expect("could not spawn threads")
+        .expect("could not spawn threads");
+    PolarsPool::new(pool)
 });
 
 #[cfg(all(target_os = "emscripten", target_family = "wasm"))] // Use 1 rayon thread on emscripten
-pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
-    ThreadPoolBuilder::new()
+pub static POOL: LazyLock<PolarsPool> = LazyLock::new(|| {
+    let pool = ThreadPoolBuilder::new()
         .num_threads(1)
         .use_current_thread()
         .build()
-        .expect("could not create pool")
+        .expect("could not create pool");
+    PolarsPool::new(pool)
 });
 
 #[cfg(all(not(target_os = "emscripten"), target_family = "wasm"))] // use this on other wasm targets
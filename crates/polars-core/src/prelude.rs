@@ -14,6 +14,8 @@ pub(crate) use polars_utils::total_ord::{TotalEq, TotalOrd};
 
 pub(crate) use crate::chunked_array::ChunkLenIter;
 pub use crate::chunked_array::ChunkedArray;
+#[cfg(feature = "random")]
+pub use crate::chunked_array::StratifiedSpec;
 #[cfg(feature = "dtype-struct")]
 pub use crate::chunked_array::StructChunked;
 pub use crate::chunked_array::arithmetic::ArithmeticChunked;
@@ -49,7 +51,8 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
-pub use crate::frame::{DataFrame, UniqueKeepStrategy};
+pub use crate::frame::unnest::{CollisionPolicy, InsertionPoint, UnnestOptions};
+pub use crate::frame::{DataFrame, FoldOp, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
 pub use crate::scalar::Scalar;
@@ -8,6 +8,7 @@ use crate::utils::try_get_supertype;
 
 pub type SchemaRef = Arc<Schema>;
 pub type Schema = polars_schema::Schema<DataType>;
+pub type SchemaDiff = polars_schema::SchemaDiff<DataType>;
 
 pub trait SchemaExt {
     fn from_arrow_schema(value: &ArrowSchema) -> Self;
@@ -41,8 +42,10 @@ impl SchemaExt for Schema {
     /// This method constructs the `Field` by cloning the name and dtype. For a version that returns references, see
     /// [`get`][Self::get] or [`get_full`][Self::get_full].
     fn get_field(&self, name: &str) -> Option<Field> {
-        self.get_full(name)
-            .map(|(_, name, dtype)| Field::new(name.clone(), dtype.clone()))
+        self.get_full(name).map(|(_, name, dtype)| {
+            Field::new(name.clone(), dtype.clone())
+                .with_metadata(self.field_metadata(name).cloned())
+        })
     }
 
     /// Look up the name in the schema and return an owned [`Field`] by cloning the data.
@@ -54,7 +57,10 @@ impl SchemaExt for Schema {
     fn try_get_field(&self, name: &str) -> PolarsResult<Field> {
         self.get_full(name)
             .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
-            .map(|(_, name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .map(|(_, name, dtype)| {
+                Field::new(name.clone(), dtype.clone())
+                    .with_metadata(self.field_metadata(name).cloned())
+            })
     }
 
     /// Convert self to `ArrowSchema` by cloning the fields.
@@ -72,10 +78,12 @@ impl SchemaExt for Schema {
     /// Iterates the [`Field`]s in this schema, constructing them anew by cloning each `(&name, &dtype)` pair.
     ///
     /// Note that this clones each name and dtype in order to form an owned [`Field`]. For a clone-free version, use
-    /// [`iter`][Self::iter], which returns `(&name, &dtype)`.
+    /// [`iter`][Self::iter], which returns `(&name, &dtype)`. The fields carry the user metadata of the schema.
     fn iter_fields(&self) -> impl ExactSizeIterator<Item = Field> + '_ {
-        self.iter()
-            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+        self.iter().map(|(name, dtype)| {
+            Field::new(name.clone(), dtype.clone())
+                .with_metadata(self.field_metadata(name).cloned())
+        })
     }
 
     /// Take another [`Schema`] and try to find the supertypes between them.
@@ -184,3 +192,59 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_schema_diff() {
+        let old = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::Float32),
+        ]);
+        let new = Schema::from_iter([
+            Field::new("c".into(), DataType::Float64),
+            Field::new("a".into(), DataType::Int64),
+            Field::new("d".into(), DataType::Boolean),
+        ]);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, vec![("d".into(), DataType::Boolean)]);
+        assert_eq!(diff.removed, vec![("b".into(), DataType::String)]);
+        assert_eq!(
+            diff.changed,
+            vec![("c".into(), DataType::Float32, DataType::Float64)]
+        );
+        assert_eq!(diff.to_string(), "- b: str\n+ d: bool\n~ c: f32 -> f64\n");
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_schema_prefix_suffix() {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]);
+
+        let prefixed = schema.with_prefix("left_");
+        assert_eq!(
+            prefixed
+                .iter_names()
+                .map(|n| n.as_str())
+                .collect::<Vec<_>>(),
+            ["left_a", "left_b"]
+        );
+        assert_eq!(prefixed.get("left_b"), Some(&DataType::String));
+
+        let suffixed = schema.with_suffix("_right");
+        assert_eq!(
+            suffixed
+                .iter_names()
+                .map(|n| n.as_str())
+                .collect::<Vec<_>>(),
+            ["a_right", "b_right"]
+        );
+    }
+}
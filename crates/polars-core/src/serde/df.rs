@@ -142,6 +142,18 @@ impl DataFrame {
 
         Ok(df)
     }
+
+    /// Serialize this [`DataFrame`] to bytes in the Arrow IPC stream format.
+    ///
+    /// The bytes can be turned back into a [`DataFrame`] with [`DataFrame::from_bytes`].
+    pub fn to_bytes(&self) -> PolarsResult<Vec<u8>> {
+        self.clone().serialize_to_bytes()
+    }
+
+    /// Deserialize a [`DataFrame`] from bytes created by [`DataFrame::to_bytes`].
+    pub fn from_bytes(mut bytes: &[u8]) -> PolarsResult<Self> {
+        Self::deserialize_from_reader(&mut bytes)
+    }
 }
 
 impl Serialize for DataFrame {
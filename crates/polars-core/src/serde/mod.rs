@@ -77,6 +77,19 @@ mod test {
         assert!(df.equals_missing(&out));
     }
 
+    #[test]
+    fn test_df_bytes_roundtrip() {
+        let mut df = sample_dataframe();
+        df.columns[0].set_sorted_flag(IsSorted::Ascending);
+        let bytes = df.to_bytes().unwrap();
+        let out = DataFrame::from_bytes(&bytes).unwrap();
+        assert!(df.equals_missing(&out));
+        assert_eq!(df.schema(), out.schema());
+        assert_eq!(out.get_columns()[0].is_sorted_flag(), IsSorted::Ascending);
+
+        assert!(DataFrame::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned_json() {
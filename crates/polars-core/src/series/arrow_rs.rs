@@ -0,0 +1,262 @@
+//! Conversion between [`Series`] and arrays of the [arrow](https://crates.io/crates/arrow) crate.
+//!
+//! Arrays are handed over through the Arrow C Data Interface, so the data buffers are shared
+//! instead of copied.
+use arrow::ffi;
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi, to_ffi};
+use polars_error::to_compute_err;
+
+use crate::prelude::*;
+
+impl Series {
+    /// Create a new [`Series`] from an array of the `arrow` crate.
+    ///
+    /// The data buffers are reused where the Arrow type matches the in-memory representation of
+    /// Polars, see [`Series::from_arrow_zero_copy`] for the types that need a conversion.
+    /// Dictionary arrays become categoricals, and timestamps keep their time unit and time zone.
+    pub fn from_arrow_rs(name: PlSmallStr, array: &dyn arrow_array::Array) -> PolarsResult<Series> {
+        let (array, schema) = to_ffi(&array.to_data()).map_err(to_compute_err)?;
+
+        // SAFETY: both crates define these as the `#[repr(C)]` structs of the C Data Interface,
+        // and the structs were just exported by arrow-rs.
+        let (field, array) = unsafe {
+            let array = std::mem::transmute::<FFI_ArrowArray, ffi::ArrowArray>(array);
+            let schema = std::mem::transmute::<FFI_ArrowSchema, ffi::ArrowSchema>(schema);
+            let field = ffi::import_field_from_c(&schema)?;
+            let array = ffi::import_array_from_c(array, field.dtype().clone())?;
+            (field, array)
+        };
+
+        let field = ArrowField { name, ..field };
+        Series::try_from((&field, array))
+    }
+
+    /// Convert this [`Series`] into an array of the `arrow` crate.
+    ///
+    /// The [`Series`] is rechunked first; after that the data buffers are shared with the
+    /// returned array.
+    pub fn to_arrow_rs(&self) -> PolarsResult<arrow_array::ArrayRef> {
+        polars_ensure!(
+            !self.dtype().is_object(),
+            InvalidOperation: "cannot convert a Series of dtype {} to an arrow-rs array", self.dtype()
+        );
+
+        // Polars exports null arrays with a (null) validity buffer for older C++ consumers,
+        // which arrow-rs rejects.
+        if self.dtype().is_null() {
+            return Ok(Arc::new(arrow_array::NullArray::new(self.len())));
+        }
+
+        let s = self.rechunk();
+        let field = s.field().to_arrow(CompatLevel::newest());
+        let schema = ffi::export_field_to_c(&field);
+        let array = ffi::export_array_to_c(s.to_arrow(0, CompatLevel::newest()));
+
+        // SAFETY: both crates define these as the `#[repr(C)]` structs of the C Data Interface,
+        // and the structs were just exported by Polars.
+        let data = unsafe {
+            let array = std::mem::transmute::<ffi::ArrowArray, FFI_ArrowArray>(array);
+            let schema = std::mem::transmute::<ffi::ArrowSchema, FFI_ArrowSchema>(schema);
+            from_ffi(array, &schema).map_err(to_compute_err)?
+        };
+        Ok(arrow_array::make_array(data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+
+    use crate::prelude::*;
+
+    fn round_trip(s: &Series) -> Series {
+        let array = s.to_arrow_rs().unwrap();
+        assert_eq!(array.len(), s.len());
+        Series::from_arrow_rs(s.name().clone(), array.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_arrow_rs_round_trip() {
+        let list = Series::new(
+            "list".into(),
+            [
+                Series::new("".into(), [1i64, 2]),
+                Series::new("".into(), [3i64]),
+                Series::new_empty("".into(), &DataType::Int64),
+            ],
+        );
+
+        for s in [
+            Series::new("i8".into(), [Some(1i8), None, Some(-3)]),
+            Series::new("i16".into(), [Some(1i16), None, Some(-3)]),
+            Series::new("i32".into(), [Some(1i32), None, Some(-3)]),
+            Series::new("i64".into(), [Some(1i64), None, Some(-3)]),
+            Series::new("u8".into(), [Some(1u8), None, Some(3)]),
+            Series::new("u16".into(), [Some(1u16), None, Some(3)]),
+            Series::new("u32".into(), [Some(1u32), None, Some(3)]),
+            Series::new("u64".into(), [Some(1u64), None, Some(3)]),
+            Series::new("f32".into(), [Some(1.5f32), None, Some(-2.25)]),
+            Series::new("f64".into(), [Some(1.5f64), None, Some(-0.0)]),
+            Series::new("bool".into(), [Some(true), None, Some(false)]),
+            Series::new(
+                "str".into(),
+                [Some("a"), None, Some("a longer string than 12 bytes")],
+            ),
+            Series::new(
+                "bin".into(),
+                [Some(b"a".as_slice()), None, Some(b"bc".as_slice())],
+            ),
+            Series::new_null("null".into(), 3),
+            list,
+        ] {
+            let out = round_trip(&s);
+            assert_eq!(out.dtype(), s.dtype());
+            assert_eq!(out.name(), s.name());
+            assert!(out.equals_missing(&s), "{s:?} != {out:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_arrow_rs_round_trip_temporal() {
+        let date = Int32Chunked::new("date".into(), [Some(0), None, Some(19000)])
+            .into_date()
+            .into_series();
+        let datetime = Int64Chunked::new("datetime".into(), [Some(0), None, Some(1_000_000)])
+            .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+            .into_series();
+
+        for s in [date, datetime] {
+            let out = round_trip(&s);
+            assert_eq!(out.dtype(), s.dtype());
+            assert!(out.equals_missing(&s));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+    fn test_arrow_rs_round_trip_time_duration() {
+        let time = Int64Chunked::new("time".into(), [Some(0), None, Some(3_600_000_000_000)])
+            .into_time()
+            .into_series();
+        let duration = Int64Chunked::new("duration".into(), [Some(-5), None, Some(1_000)])
+            .into_duration(TimeUnit::Milliseconds)
+            .into_series();
+
+        for s in [time, duration] {
+            let out = round_trip(&s);
+            assert_eq!(out.dtype(), s.dtype());
+            assert!(out.equals_missing(&s));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_arrow_rs_round_trip_decimal() {
+        let s = Int128Chunked::new("dec".into(), [Some(12345i128), None, Some(-1)])
+            .into_decimal_unchecked(Some(10), 2)
+            .into_series();
+
+        let out = round_trip(&s);
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(10), Some(2)));
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_arrow_rs_round_trip_array() {
+        let s = Series::new(
+            "arr".into(),
+            [
+                Series::new("".into(), [1i32, 2]),
+                Series::new("".into(), [3i32, 4]),
+            ],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Int32), 2))
+        .unwrap();
+
+        let out = round_trip(&s);
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_arrow_rs_round_trip_struct_of_lists() {
+        let a = Series::new("a".into(), [1i32, 2]);
+        let l = Series::new(
+            "l".into(),
+            [
+                Series::new("".into(), ["x", "y"]),
+                Series::new("".into(), ["z"]),
+            ],
+        );
+        let s = StructChunked::from_series("s".into(), 2, [a, l].iter())
+            .unwrap()
+            .into_series();
+
+        let out = round_trip(&s);
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_arrow_rs_dictionary_to_categorical() {
+        use arrow_array::DictionaryArray;
+        use arrow_array::types::Int32Type;
+
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let s = Series::from_arrow_rs("cat".into(), &dict).unwrap();
+        assert!(matches!(s.dtype(), DataType::Categorical(_, _)));
+
+        let out = s.cast(&DataType::String).unwrap();
+        let expected = Series::new("cat".into(), ["a", "b", "a"]);
+        assert!(out.equals_missing(&expected));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_arrow_rs_categorical_to_dictionary() {
+        let s = Series::new("cat".into(), [Some("a"), None, Some("b"), Some("a")])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+
+        let array = s.to_arrow_rs().unwrap();
+        let dict = array
+            .as_any()
+            .downcast_ref::<arrow_array::DictionaryArray<arrow_array::types::UInt32Type>>()
+            .unwrap();
+        assert_eq!(dict.len(), 4);
+        assert_eq!(dict.null_count(), 1);
+
+        let out = Series::from_arrow_rs("cat".into(), dict).unwrap();
+        assert!(matches!(out.dtype(), DataType::Categorical(_, _)));
+        assert!(
+            out.cast(&DataType::String)
+                .unwrap()
+                .equals_missing(&s.cast(&DataType::String).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_arrow_rs_zero_copy() {
+        let s = Series::new("a".into(), [1i64, 2, 3]);
+        let ptr = s.i64().unwrap().downcast_get(0).unwrap().values().as_ptr();
+
+        let array = s.to_arrow_rs().unwrap();
+        let array = array
+            .as_any()
+            .downcast_ref::<arrow_array::Int64Array>()
+            .unwrap();
+        assert_eq!(array.values().as_ptr(), ptr);
+
+        let array = arrow_array::Int64Array::from(vec![4, 5, 6]);
+        let ptr = array.values().as_ptr();
+        let s = Series::from_arrow_rs("b".into(), &array).unwrap();
+        assert_eq!(
+            s.i64().unwrap().downcast_get(0).unwrap().values().as_ptr(),
+            ptr
+        );
+    }
+}
@@ -37,6 +37,35 @@ impl Series {
         Ok(series)
     }
 
+    /// Reconstitute a [`Series`] from the parts returned by [`Series::into_raw_parts`],
+    /// without copying the chunks.
+    ///
+    /// Every chunk must have the Arrow type of the physical representation of `dtype`, otherwise
+    /// an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The physical values must be valid for `dtype`. Only the Arrow type is checked, so e.g. the
+    /// ids of a `Categorical` or `Enum`, also when nested, must be in bounds of its mapping.
+    pub unsafe fn from_raw_parts(
+        name: PlSmallStr,
+        dtype: DataType,
+        chunks: Vec<ArrayRef>,
+    ) -> PolarsResult<Self> {
+        let arrow_dtype = dtype.to_physical().try_to_arrow(CompatLevel::newest())?;
+        for chunk in &chunks {
+            polars_ensure!(
+                chunk.dtype() == &arrow_dtype,
+                InvalidOperation: "cannot create a series of type '{dtype}' of arrow chunk with type '{:?}'",
+                chunk.dtype()
+            );
+        }
+
+        // SAFETY: We check that the datatype matches, the caller ensures the values are valid.
+        let series = unsafe { Self::from_chunks_and_dtype_unchecked(name, chunks, &dtype) };
+        Ok(series)
+    }
+
     /// Takes chunks and a polars datatype and constructs the Series
     /// This is faster than creating from chunks and an arrow datatype because there is no
     /// casting involved
@@ -163,6 +192,8 @@ impl Series {
 
     /// Create a new Series without checking if the inner dtype of the chunks is correct
     ///
+    /// The user metadata in `md` is kept on the Series.
+    ///
     /// # Safety
     /// The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
     pub unsafe fn _try_from_arrow_unchecked_with_md(
@@ -170,6 +201,21 @@ impl Series {
         chunks: Vec<ArrayRef>,
         dtype: &ArrowDataType,
         md: Option<&Metadata>,
+    ) -> PolarsResult<Self> {
+        let s = Self::try_from_arrow_unchecked_impl(name, chunks, dtype, md)?;
+        Ok(match md.and_then(|md| md.user_metadata()) {
+            Some(user_md) => s.with_metadata(Some(user_md)),
+            None => s,
+        })
+    }
+
+    /// # Safety
+    /// The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
+    unsafe fn try_from_arrow_unchecked_impl(
+        name: PlSmallStr,
+        chunks: Vec<ArrayRef>,
+        dtype: &ArrowDataType,
+        md: Option<&Metadata>,
     ) -> PolarsResult<Self> {
         match dtype {
             ArrowDataType::Utf8View => Ok(StringChunked::from_chunks(name, chunks).into_series()),
@@ -674,6 +720,36 @@ unsafe fn to_physical_and_dtype(
     }
 }
 
+/// Whether arrays of this Arrow type can be converted to a [`Series`] without copying any of
+/// their data buffers.
+pub(crate) fn is_zero_copy_arrow_dtype(dtype: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    match dtype {
+        D::Null
+        | D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::Int128
+        | D::UInt8
+        | D::UInt16
+        | D::UInt32
+        | D::UInt64
+        | D::Float32
+        | D::Float64
+        | D::Utf8View
+        | D::BinaryView
+        | D::Date32
+        | D::Decimal(_, _) => true,
+        D::Timestamp(tu, _) | D::Duration(tu) => *tu != ArrowTimeUnit::Second,
+        D::Time64(tu) => *tu == ArrowTimeUnit::Nanosecond,
+        D::LargeList(field) | D::FixedSizeList(field, _) => is_zero_copy_arrow_dtype(field.dtype()),
+        D::Struct(fields) => fields.iter().all(|f| is_zero_copy_arrow_dtype(f.dtype())),
+        _ => false,
+    }
+}
+
 fn check_types(chunks: &[ArrayRef]) -> PolarsResult<ArrowDataType> {
     let mut chunks_iter = chunks.iter();
     let dtype: ArrowDataType = chunks_iter
@@ -1,6 +1,8 @@
 use std::any::Any;
 use std::borrow::Cow;
 
+use arrow::datatypes::Metadata;
+
 use self::compare_inner::{TotalEqInner, TotalOrdInner};
 use self::sort::arg_sort_row_fmt;
 use super::{IsSorted, StatisticsFlags, private};
@@ -81,6 +83,14 @@ impl SeriesTrait for SeriesWrap<ArrayChunked> {
         self.0.rename(name);
     }
 
+    fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.0.metadata()
+    }
+
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         self.0.chunk_lengths()
     }
@@ -123,6 +123,14 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         self.0.rename(name);
     }
 
+    fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.0.metadata()
+    }
+
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         self.0.chunk_lengths()
     }
@@ -226,6 +226,14 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn metadata(&self) -> Option<&Arc<Metadata>> {
+                self.0.metadata()
+            }
+
+            fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+                self.0.set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkLenIter {
                 self.0.chunk_lengths()
             }
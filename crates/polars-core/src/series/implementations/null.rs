@@ -169,6 +169,15 @@ impl SeriesTrait for NullChunked {
         self.name = name
     }
 
+    fn metadata(&self) -> Option<&Arc<Metadata>> {
+        None
+    }
+
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        // A `NullChunked` has no field to hold the metadata.
+        let _ = metadata;
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
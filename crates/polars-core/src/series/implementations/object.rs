@@ -95,6 +95,14 @@ where
         ObjectChunked::rename(&mut self.0, name)
     }
 
+    fn metadata(&self) -> Option<&Arc<Metadata>> {
+        ObjectChunked::metadata(&self.0)
+    }
+
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        ObjectChunked::set_metadata(&mut self.0, metadata)
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter {
         ObjectChunked::chunk_lengths(&self.0)
     }
@@ -18,6 +18,8 @@ macro_rules! invalid_operation_panic {
 pub mod amortized_iter;
 mod any_value;
 pub mod arithmetic;
+#[cfg(feature = "compat-arrow-rs")]
+mod arrow_rs;
 pub mod builder;
 mod comparison;
 mod from;
@@ -31,7 +33,8 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{allocated_bytes_size, estimated_bytes_size};
+use arrow::datatypes::Metadata;
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -229,6 +232,16 @@ impl Series {
         chunks
     }
 
+    /// Decompose this [`Series`] into its name, dtype and Arrow chunks without copying.
+    ///
+    /// The chunks hold the physical representation of the dtype. Use
+    /// [`Series::from_raw_parts`] to reconstitute the [`Series`].
+    pub fn into_raw_parts(self) -> (PlSmallStr, DataType, Vec<ArrayRef>) {
+        let name = self.name().clone();
+        let dtype = self.dtype().clone();
+        (name, dtype, self.into_chunks())
+    }
+
     // TODO! this probably can now be removed, now we don't have special case for structs.
     pub fn select_chunk(&self, i: usize) -> Self {
         let mut new = self.clear();
@@ -289,6 +302,19 @@ impl Series {
         self
     }
 
+    /// Get the user metadata of this Series, e.g. units or a description.
+    ///
+    /// This is read from and written to the field metadata of Arrow based formats.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.0.metadata().cloned()
+    }
+
+    /// Return this Series with the given user metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
     pub fn from_arrow_chunks(name: PlSmallStr, arrays: Vec<ArrayRef>) -> PolarsResult<Series> {
         Self::try_from((name, arrays))
     }
@@ -297,6 +323,30 @@ impl Series {
         Self::try_from((name, array))
     }
 
+    /// Create a new [`Series`] from an Arrow array, guaranteeing that its data buffers are
+    /// reused and not copied.
+    ///
+    /// A copy is inevitable when the Arrow type doesn't match the in-memory representation
+    /// of Polars:
+    /// * `Utf8`, `LargeUtf8`, `Binary`, `LargeBinary` and `FixedSizeBinary` are converted
+    ///   to string/binary views.
+    /// * `List` (`i32` offsets) and `Map` are converted to lists with `i64` offsets.
+    /// * `Dictionary` is converted to a categorical with its own string mapping.
+    /// * `Float16`, `Date64`, `Time32`, non-nanosecond `Time64`, and `Timestamp`/`Duration`
+    ///   in seconds are converted to a different physical representation.
+    ///
+    /// Those types, also when nested, return an error here and should go through
+    /// [`Series::from_arrow`] instead. The validity of a `Struct` array is still propagated
+    /// to its fields, which allocates new validity bitmaps but no new data buffers.
+    pub fn from_arrow_zero_copy(name: PlSmallStr, array: ArrayRef) -> PolarsResult<Series> {
+        polars_ensure!(
+            from::is_zero_copy_arrow_dtype(array.dtype()),
+            InvalidOperation: "cannot create a Series from an Arrow array of type {:?} without copying",
+            array.dtype()
+        );
+        Self::try_from((name, array))
+    }
+
     /// Shrink the capacity of this array to fit its length.
     pub fn shrink_to_fit(&mut self) {
         self._get_inner_mut().shrink_to_fit()
@@ -349,6 +399,29 @@ impl Series {
         Ok(self)
     }
 
+    /// Append `values` to this series, with `validity` marking which of them are valid.
+    ///
+    /// The values are written into the last chunk if its memory is not shared, otherwise they
+    /// are added as a new chunk. See [`ChunkedArray::extend_from_slice`].
+    pub fn extend_from_slice<T>(
+        &mut self,
+        values: &[T],
+        validity: Option<&[bool]>,
+    ) -> PolarsResult<()>
+    where
+        T: NumericNative,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        let dtype = T::PolarsType::get_static_dtype();
+        polars_ensure!(
+            self.dtype() == &dtype,
+            SchemaMismatch: "cannot extend series of dtype {} with values of dtype {}",
+            self.dtype(), dtype
+        );
+        let ca: &mut ChunkedArray<T::PolarsType> = self._get_inner_mut().as_mut();
+        ca.extend_from_slice(values, validity)
+    }
+
     /// Sort the series with specific options.
     ///
     /// # Example
@@ -427,6 +500,7 @@ impl Series {
                             new_fields.push(Field {
                                 name: field.name.clone(),
                                 dtype,
+                                metadata: field.metadata.clone(),
                             });
                             break new_fields;
                         }
@@ -437,6 +511,7 @@ impl Series {
                         Field {
                             name: field.name.clone(),
                             dtype,
+                            metadata: field.metadata,
                         }
                     }));
 
@@ -457,10 +532,20 @@ impl Series {
             Some(ref dtype) => dtype,
         };
 
+        let metadata = slf.0.metadata().cloned();
+        let keep_metadata = |s: Series| match &metadata {
+            Some(md) => s.with_metadata(Some(md.clone())),
+            None => s,
+        };
+
         // Always allow casting all nulls to other all nulls.
         let len = slf.len();
         if slf.null_count() == len {
-            return Ok(Series::full_null(slf.name().clone(), len, dtype));
+            return Ok(keep_metadata(Series::full_null(
+                slf.name().clone(),
+                len,
+                dtype,
+            )));
         }
 
         let new_options = match options {
@@ -473,7 +558,7 @@ impl Series {
         if options.is_strict() {
             handle_casting_failures(slf.as_ref(), &out)?;
         }
-        Ok(out)
+        Ok(keep_metadata(out))
     }
 
     /// Cast from physical to logical types without any checks on the validity of the cast.
@@ -786,8 +871,9 @@ impl Series {
 
     /// Get the product of an array.
     ///
-    /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
-    /// first cast to `Int64` to prevent overflow issues.
+    /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16, Int32, UInt32}` the `Series` is
+    /// first cast to `Int64` to prevent overflow issues. An integer product that still overflows
+    /// raises an error.
     pub fn product(&self) -> PolarsResult<Scalar> {
         #[cfg(feature = "product")]
         {
@@ -798,10 +884,10 @@ impl Series {
                     let s = self.cast(&Int64).unwrap();
                     s.product()
                 },
-                Int64 => Ok(self.i64().unwrap().prod_reduce()),
-                UInt64 => Ok(self.u64().unwrap().prod_reduce()),
+                Int64 => self.i64().unwrap().checked_prod_reduce(),
+                UInt64 => self.u64().unwrap().checked_prod_reduce(),
                 #[cfg(feature = "dtype-i128")]
-                Int128 => Ok(self.i128().unwrap().prod_reduce()),
+                Int128 => self.i128().unwrap().checked_prod_reduce(),
                 Float32 => Ok(self.f32().unwrap().prod_reduce()),
                 Float64 => Ok(self.f64().unwrap().prod_reduce()),
                 dt => {
@@ -1021,6 +1107,36 @@ impl Series {
         size
     }
 
+    /// Returns the number of bytes allocated for the buffers of the `Series`.
+    ///
+    /// # Implementation
+    /// Unlike [`Series::estimated_size`], this sums the full capacity of the data, validity and
+    /// offset buffers of every chunk, including nested arrays. A sliced `Series` therefore reports
+    /// the size of the whole allocation. Buffers shared between chunks are counted once per chunk.
+    pub fn memory_size(&self) -> usize {
+        let mut size = 0;
+        match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rv), _) | DataType::Enum(Some(rv), _) => match &**rv {
+                RevMapping::Local(arr, _) => size += allocated_bytes_size(arr),
+                RevMapping::Global(map, arr, _) => {
+                    size += map.capacity() * size_of::<u32>() * 2 + allocated_bytes_size(arr);
+                },
+            },
+            #[cfg(feature = "object")]
+            DataType::Object(_) => return self.estimated_size(),
+            _ => {},
+        }
+
+        size += self
+            .chunks()
+            .iter()
+            .map(|arr| allocated_bytes_size(&**arr))
+            .sum::<usize>();
+
+        size
+    }
+
     /// Packs every element into a list.
     pub fn as_list(&self) -> ListChunked {
         let s = self.rechunk();
@@ -1102,6 +1218,110 @@ mod test {
     use crate::prelude::*;
     use crate::series::*;
 
+    #[test]
+    fn from_arrow_zero_copy() {
+        let arr = PrimitiveArray::from_vec(vec![1i64, 2, 3]);
+        let ptr = arr.values().as_ptr();
+        let s = Series::from_arrow_zero_copy("a".into(), arr.boxed()).unwrap();
+        assert_eq!(
+            s.i64().unwrap().downcast_get(0).unwrap().values().as_ptr(),
+            ptr
+        );
+
+        let arr = Utf8Array::<i64>::from_slice(["a", "b"]);
+        assert!(Series::from_arrow_zero_copy("a".into(), arr.clone().boxed()).is_err());
+        assert!(Series::from_arrow("a".into(), arr.boxed()).is_ok());
+    }
+
+    #[test]
+    fn raw_parts_roundtrip() {
+        let mut s = Series::new("a".into(), &[1i64, 2]);
+        s.append(&Series::new("a".into(), &[3i64])).unwrap();
+        let ptr = s.chunks()[0]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .values()
+            .as_ptr();
+
+        let (name, dtype, chunks) = s.clone().into_raw_parts();
+        assert_eq!(chunks.len(), 2);
+        let out = unsafe { Series::from_raw_parts(name.clone(), dtype, chunks.clone()) }.unwrap();
+        assert!(out.equals(&s));
+        assert_eq!(
+            out.i64()
+                .unwrap()
+                .downcast_get(0)
+                .unwrap()
+                .values()
+                .as_ptr(),
+            ptr
+        );
+
+        assert!(unsafe { Series::from_raw_parts(name, DataType::Int32, chunks) }.is_err());
+    }
+
+    #[test]
+    fn raw_parts_reference_layout() {
+        // The Arrow types of the chunks are part of the raw parts format.
+        let list: ListChunked = [Some(Series::new("".into(), [1i32, 2]))]
+            .into_iter()
+            .collect();
+        #[allow(unused_mut)]
+        let mut cases = vec![
+            (Series::new("a".into(), [1i64]), ArrowDataType::Int64),
+            (Series::new("a".into(), [true]), ArrowDataType::Boolean),
+            (Series::new("a".into(), ["x"]), ArrowDataType::Utf8View),
+            (
+                Series::new("a".into(), [b"x".as_slice()]),
+                ArrowDataType::BinaryView,
+            ),
+            (
+                list.into_series(),
+                ListArray::<i64>::default_datatype(ArrowDataType::Int32),
+            ),
+        ];
+        #[cfg(feature = "dtype-date")]
+        cases.push((
+            Series::new("a".into(), [1i32])
+                .cast(&DataType::Date)
+                .unwrap(),
+            ArrowDataType::Int32,
+        ));
+        #[cfg(feature = "dtype-datetime")]
+        cases.push((
+            Series::new("a".into(), [1i64])
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .unwrap(),
+            ArrowDataType::Int64,
+        ));
+
+        for (s, arrow_dtype) in cases {
+            let (name, dtype, chunks) = s.clone().into_raw_parts();
+            assert_eq!(&name, s.name());
+            assert_eq!(&dtype, s.dtype());
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks[0].dtype(), &arrow_dtype, "{dtype}");
+            let out = unsafe { Series::from_raw_parts(name, dtype, chunks) }.unwrap();
+            assert_eq!(out.dtype(), s.dtype());
+            assert!(out.equals_missing(&s));
+        }
+    }
+
+    #[test]
+    fn memory_size() {
+        let mut values = Vec::with_capacity(100);
+        values.extend([1i64, 2, 3]);
+        let arr = PrimitiveArray::from_vec(values);
+        let s = Series::from_arrow("a".into(), arr.boxed()).unwrap();
+        assert_eq!(s.memory_size(), 100 * size_of::<i64>());
+        assert!(s.memory_size() > s.estimated_size());
+        assert_eq!(s.slice(1, 1).memory_size(), s.memory_size());
+
+        let s = Series::new("a".into(), &[Some("foo"), None]);
+        assert!(s.memory_size() >= s.estimated_size());
+    }
+
     #[test]
     fn cast() {
         let ar = UInt32Chunked::new("a".into(), &[1, 2]);
@@ -1169,6 +1389,18 @@ mod test {
         assert!(s1.append(&s2).is_err())
     }
 
+    #[test]
+    fn series_extend_from_slice() {
+        let mut s = Series::new("a".into(), &[1i64, 2]);
+        s.extend_from_slice(&[3i64, 4], Some(&[true, false]))
+            .unwrap();
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.null_count(), 1);
+
+        // wrong type
+        assert!(s.extend_from_slice(&[3i32], None).is_err())
+    }
+
     #[test]
     #[cfg(feature = "dtype-decimal")]
     fn series_append_decimal() {
@@ -1214,4 +1446,20 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    #[cfg(feature = "product")]
+    fn product_checks_overflow() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(3i32), None, Some(-4)]);
+        assert_eq!(s.product()?.value(), &AnyValue::Int64(-12));
+
+        let s = Series::new("a".into(), &[i64::MAX, 2]);
+        assert!(s.product().is_err());
+        let s = Series::new("a".into(), &[i64::MAX, 2, 0]);
+        assert_eq!(s.product()?.value(), &AnyValue::Int64(0));
+
+        let s = Series::new("a".into(), &[1.5f64, 4.0]);
+        assert_eq!(s.product()?.value(), &AnyValue::Float64(6.0));
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ use std::any::Any;
 use std::borrow::Cow;
 
 use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::datatypes::Metadata;
 use polars_compute::rolling::QuantileMethod;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -211,6 +212,12 @@ pub trait SeriesTrait:
     /// Rename the Series.
     fn rename(&mut self, name: PlSmallStr);
 
+    /// Get the user metadata of the Series.
+    fn metadata(&self) -> Option<&Arc<Metadata>>;
+
+    /// Set the user metadata of the Series.
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>);
+
     /// Get the lengths of the underlying chunks
     fn chunk_lengths(&self) -> ChunkLenIter;
 
@@ -501,11 +508,15 @@ pub trait SeriesTrait:
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
         polars_bail!(opq = sum, self._dtype());
     }
-    /// Get the max of the Series as a new Series of length 1.
+    /// Get the max of the Series as a new Scalar.
+    ///
+    /// `NaN` values are skipped like nulls, unless all non-null values are `NaN`.
     fn max_reduce(&self) -> PolarsResult<Scalar> {
         polars_bail!(opq = max, self._dtype());
     }
-    /// Get the min of the Series as a new Series of length 1.
+    /// Get the min of the Series as a new Scalar.
+    ///
+    /// `NaN` values are skipped like nulls, unless all non-null values are `NaN`.
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         polars_bail!(opq = min, self._dtype());
     }
@@ -61,6 +61,34 @@ impl DataFrame {
         Ok(())
     }
 
+    /// Check if [`DataFrame`]s have the same columns with the same dtypes, in the same order.
+    pub fn schema_eq_strict(&self, other: &DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            self.width() == other.width(),
+            SchemaMismatch: "column count mismatch: left-hand = {}, right-hand = {}",
+            self.width(), other.width()
+        );
+        for (idx, (lhs, rhs)) in self.iter().zip(other.iter()).enumerate() {
+            if lhs.name() != rhs.name() {
+                polars_ensure!(
+                    other.get_column_index(lhs.name()).is_none(),
+                    SchemaMismatch: "column order mismatch at position {}: left-hand = '{}', right-hand = '{}'",
+                    idx, lhs.name(), rhs.name()
+                );
+                polars_bail!(
+                    SchemaMismatch: "column name mismatch: left-hand = '{}', right-hand = '{}'",
+                    lhs.name(), rhs.name()
+                );
+            }
+            polars_ensure!(
+                lhs.dtype() == rhs.dtype(),
+                SchemaMismatch: "column datatype mismatch for '{}': left-hand = '{}', right-hand = '{}'",
+                lhs.name(), lhs.dtype(), rhs.dtype()
+            );
+        }
+        Ok(())
+    }
+
     /// Check if [`DataFrame`]s are equal. Note that `None == None` evaluates to `false`
     ///
     /// # Example
@@ -170,6 +198,23 @@ mod test {
         assert!(df1.equals(&df1))
     }
 
+    #[test]
+    fn test_df_schema_eq_strict() {
+        let df1 = df!("a" => [1], "b" => ["x"]).unwrap();
+        let df2 = df!("b" => ["x"], "a" => [1]).unwrap();
+        let df3 = df!("a" => [1], "c" => ["x"]).unwrap();
+        let df4 = df!("a" => [1], "b" => [1]).unwrap();
+
+        assert!(df1.schema_eq_strict(&df1).is_ok());
+        let err = df1.schema_eq_strict(&df2).unwrap_err();
+        assert!(err.to_string().contains("column order mismatch"));
+        let err = df1.schema_eq_strict(&df3).unwrap_err();
+        assert!(err.to_string().contains("column name mismatch"));
+        let err = df1.schema_eq_strict(&df4).unwrap_err();
+        assert!(err.to_string().contains("column datatype mismatch"));
+        assert!(df1.schema_eq_strict(&df1.select(["a"]).unwrap()).is_err());
+    }
+
     #[test]
     fn assert_df_eq_passes() {
         let df = df!("a" => [1], "b" => [2]).unwrap();
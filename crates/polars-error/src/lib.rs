@@ -98,6 +98,8 @@ pub enum PolarsError {
     SQLSyntax(ErrString),
     StringCacheMismatch(ErrString),
     StructFieldNotFound(ErrString),
+    /// The operation didn't finish in time, e.g. a query that exceeds `LazyFrame::with_timeout`.
+    Timeout(ErrString),
     Context {
         error: Box<PolarsError>,
         msg: ErrString,
@@ -108,7 +110,17 @@ pub enum PolarsError {
     },
 }
 
-impl Error for PolarsError {}
+impl Error for PolarsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PolarsError::IO { error, .. } => Some(&**error),
+            PolarsError::Context { error, .. } => error.source(),
+            #[cfg(feature = "python")]
+            PolarsError::Python { error } => Some(&error.0),
+            _ => None,
+        }
+    }
+}
 
 impl Display for PolarsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -133,6 +145,7 @@ impl Display for PolarsError {
             ShapeMismatch(msg) => write!(f, "lengths don't match: {msg}"),
             StringCacheMismatch(msg) => write!(f, "string caches don't match: {msg}"),
             StructFieldNotFound(msg) => write!(f, "field not found: {msg}"),
+            Timeout(msg) => write!(f, "timeout: {msg}"),
             Context { error, msg } => write!(f, "{error}: {msg}"),
             #[cfg(feature = "python")]
             Python { error } => write!(f, "python: {error}"),
@@ -261,9 +274,13 @@ impl PolarsError {
             ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
             StringCacheMismatch(msg) => StringCacheMismatch(func(msg).into()),
             StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
+            Timeout(msg) => Timeout(func(msg).into()),
             SQLInterface(msg) => SQLInterface(func(msg).into()),
             SQLSyntax(msg) => SQLSyntax(func(msg).into()),
-            Context { error, .. } => error.wrap_msg(func),
+            Context { error, msg } => Context {
+                error: Box::new(error.wrap_msg(func)),
+                msg: msg.clone(),
+            },
             #[cfg(feature = "python")]
             Python { error } => pyo3::Python::with_gil(|py| {
                 use pyo3::types::{PyAnyMethods, PyStringMethods};
@@ -315,6 +332,25 @@ impl PolarsError {
         }
     }
 
+    /// Annotates the error with `context`, e.g. `"while reading column 'foo'"`.
+    ///
+    /// The contexts form a chain around the original error, see [`PolarsError::contexts`].
+    pub fn with_context(self, context: impl Display) -> Self {
+        self.context(context.to_string().into())
+    }
+
+    /// The context messages of the error, from the outermost to the innermost.
+    pub fn contexts(&self) -> impl Iterator<Item = &str> {
+        let mut current = self;
+        std::iter::from_fn(move || match current {
+            Self::Context { error, msg } => {
+                current = error;
+                Some(&**msg)
+            },
+            _ => None,
+        })
+    }
+
     pub fn remove_context(mut self) -> Self {
         while let Self::Context { error, .. } = self {
             self = *error;
@@ -323,6 +359,33 @@ impl PolarsError {
     }
 }
 
+/// Adds context to the error of a [`PolarsResult`].
+///
+/// Unlike [`PolarsError::with_context`], the context is prepended to the message of the error
+/// itself, so the error keeps its variant, e.g. `"failed to vstack column 'a': type String is
+/// incompatible with expected type Int32"`. Context layers the error already has are kept.
+pub trait PolarsResultExt<T> {
+    /// Prepends `msg` to the message of the error.
+    fn context(self, msg: &str) -> PolarsResult<T>;
+
+    /// Prepends the message returned by `f` to the message of the error. `f` is only called on
+    /// error.
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> PolarsResult<T>;
+}
+
+impl<T> PolarsResultExt<T> for PolarsResult<T> {
+    fn context(self, msg: &str) -> PolarsResult<T> {
+        self.with_context(|| msg)
+    }
+
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> PolarsResult<T> {
+        self.map_err(|e| {
+            let context = f();
+            e.wrap_msg(|msg| format!("{context}: {msg}"))
+        })
+    }
+}
+
 pub fn map_err<E: Error>(error: E) -> PolarsError {
     PolarsError::ComputeError(format!("{error}").into())
 }
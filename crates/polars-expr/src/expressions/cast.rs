@@ -11,12 +11,19 @@ pub struct CastExpr {
     pub(crate) dtype: DataType,
     pub(crate) expr: Expr,
     pub(crate) options: CastOptions,
+    pub(crate) drop_metadata: bool,
     pub(crate) inlined_eval: OnceLock<Option<Column>>,
 }
 
 impl CastExpr {
     fn finish(&self, input: &Column) -> PolarsResult<Column> {
-        input.cast_with_options(&self.dtype, self.options)
+        let out = input.cast_with_options(&self.dtype, self.options)?;
+        Ok(match out {
+            Column::Series(s) if self.drop_metadata && s.metadata().is_some() => {
+                s.take().with_metadata(None).into_column()
+            },
+            out => out,
+        })
     }
 }
 
@@ -90,6 +97,9 @@ impl PhysicalExpr for CastExpr {
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
         self.input.to_field(input_schema).map(|mut fld| {
             fld.coerce(self.dtype.clone());
+            if self.drop_metadata {
+                fld.metadata = None;
+            }
             fld
         })
     }
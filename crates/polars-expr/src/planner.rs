@@ -407,6 +407,7 @@ fn create_physical_expr_inner(
             expr,
             dtype,
             options,
+            drop_metadata,
         } => {
             let phys_expr = create_physical_expr_inner(*expr, ctxt, expr_arena, schema, state)?;
             Ok(Arc::new(CastExpr {
@@ -414,6 +415,7 @@ fn create_physical_expr_inner(
                 dtype: dtype.clone(),
                 expr: node_to_expr(expression, expr_arena),
                 options: *options,
+                drop_metadata: *drop_metadata,
                 inlined_eval: Default::default(),
             }))
         },
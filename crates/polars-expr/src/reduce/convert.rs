@@ -8,7 +8,13 @@ use crate::reduce::first_last::{new_first_reduction, new_last_reduction};
 use crate::reduce::len::LenReduce;
 use crate::reduce::mean::new_mean_reduction;
 use crate::reduce::min_max::{new_max_reduction, new_min_reduction};
+#[cfg(feature = "moment")]
+use crate::reduce::moment::{new_kurtosis_reduction, new_skew_reduction};
 use crate::reduce::sum::new_sum_reduction;
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::tdigest::new_tdigest_reduction;
+#[cfg(feature = "unique_counts")]
+use crate::reduce::unique_counts::new_unique_counts_reduction;
 use crate::reduce::var_std::new_var_std_reduction;
 
 /// Converts a node into a reduction + its associated selector expression.
@@ -72,7 +78,53 @@ pub fn into_reduction(
                 (out, expr)
             }
         },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::TDigest(function),
+            ..
+        } => (new_tdigest_reduction(*function)?, input[0].node()),
+        #[cfg(feature = "unique_counts")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::UniqueWithCounts,
+            ..
+        } => (
+            new_unique_counts_reduction(get_dt(input[0].node())?),
+            input[0].node(),
+        ),
+        #[cfg(feature = "moment")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Skew(bias),
+            ..
+        } => (
+            new_skew_reduction(get_dt(input[0].node())?, *bias)?,
+            input[0].node(),
+        ),
+        #[cfg(feature = "moment")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Kurtosis(fisher, bias),
+            ..
+        } => (
+            new_kurtosis_reduction(get_dt(input[0].node())?, *fisher, *bias)?,
+            input[0].node(),
+        ),
         _ => unreachable!(),
     };
     Ok(out)
 }
+
+/// Whether the function is an aggregation that is computed by a [`GroupedReduction`].
+pub fn is_reduction_function(function: &FunctionExpr) -> bool {
+    match function {
+        #[cfg(feature = "approx_quantile")]
+        FunctionExpr::TDigest(_) => true,
+        #[cfg(feature = "unique_counts")]
+        FunctionExpr::UniqueWithCounts => true,
+        #[cfg(feature = "moment")]
+        FunctionExpr::Skew(_) | FunctionExpr::Kurtosis(_, _) => true,
+        _ => false,
+    }
+}
@@ -5,7 +5,13 @@ mod first_last;
 mod len;
 mod mean;
 mod min_max;
+#[cfg(feature = "moment")]
+mod moment;
 mod sum;
+#[cfg(feature = "approx_quantile")]
+mod tdigest;
+#[cfg(feature = "unique_counts")]
+mod unique_counts;
 mod var_std;
 
 use std::any::Any;
@@ -14,7 +20,7 @@ use std::marker::PhantomData;
 
 use arrow::array::{Array, PrimitiveArray, StaticArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
-pub use convert::into_reduction;
+pub use convert::{into_reduction, is_reduction_function};
 pub use min_max::{new_max_reduction, new_min_reduction};
 use polars_core::prelude::*;
 
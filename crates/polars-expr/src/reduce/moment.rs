@@ -0,0 +1,185 @@
+use std::marker::PhantomData;
+
+use arrow::types::NativeType;
+use num_traits::AsPrimitive;
+use polars_compute::moment::{KurtosisState, SkewState};
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+pub fn new_skew_reduction(dtype: DataType, bias: bool) -> PolarsResult<Box<dyn GroupedReduction>> {
+    new_moment_reduction(dtype, SkewMoment { bias })
+}
+
+pub fn new_kurtosis_reduction(
+    dtype: DataType,
+    fisher: bool,
+    bias: bool,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    new_moment_reduction(dtype, KurtosisMoment { fisher, bias })
+}
+
+fn new_moment_reduction<M: Moment>(
+    dtype: DataType,
+    moment: M,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use DataType::*;
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        _ if dtype.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(VGR::new(dtype, MomentReducer::<$T, M> {
+                    moment,
+                    needs_cast: false,
+                    _phantom: PhantomData,
+                }))
+            })
+        },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => Box::new(VGR::new(
+            dtype,
+            MomentReducer::<Float64Type, M> {
+                moment,
+                needs_cast: true,
+                _phantom: PhantomData,
+            },
+        )),
+        Boolean => Box::new(VGR::new(
+            dtype,
+            MomentReducer::<Float64Type, M> {
+                moment,
+                needs_cast: true,
+                _phantom: PhantomData,
+            },
+        )),
+        _ => polars_bail!(op = M::NAME, dtype),
+    })
+}
+
+/// A higher moment that is computed from a mergeable state of the central moments.
+trait Moment: Copy + Send + Sync + 'static {
+    type State: Default + Clone + Send + Sync + 'static;
+    const NAME: &'static str;
+
+    fn insert_one(state: &mut Self::State, x: f64);
+    fn combine(state: &mut Self::State, other: &Self::State);
+    fn from_arr<T: NativeType + AsPrimitive<f64>>(arr: &PrimitiveArray<T>) -> Self::State;
+    fn finalize(&self, state: &Self::State) -> Option<f64>;
+}
+
+#[derive(Clone, Copy)]
+struct SkewMoment {
+    bias: bool,
+}
+
+impl Moment for SkewMoment {
+    type State = SkewState;
+    const NAME: &'static str = "`skew`";
+
+    fn insert_one(state: &mut SkewState, x: f64) {
+        state.insert_one(x);
+    }
+
+    fn combine(state: &mut SkewState, other: &SkewState) {
+        state.combine(other);
+    }
+
+    fn from_arr<T: NativeType + AsPrimitive<f64>>(arr: &PrimitiveArray<T>) -> SkewState {
+        polars_compute::moment::skew(arr)
+    }
+
+    fn finalize(&self, state: &SkewState) -> Option<f64> {
+        state.finalize(self.bias)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct KurtosisMoment {
+    fisher: bool,
+    bias: bool,
+}
+
+impl Moment for KurtosisMoment {
+    type State = KurtosisState;
+    const NAME: &'static str = "`kurtosis`";
+
+    fn insert_one(state: &mut KurtosisState, x: f64) {
+        state.insert_one(x);
+    }
+
+    fn combine(state: &mut KurtosisState, other: &KurtosisState) {
+        state.combine(other);
+    }
+
+    fn from_arr<T: NativeType + AsPrimitive<f64>>(arr: &PrimitiveArray<T>) -> KurtosisState {
+        polars_compute::moment::kurtosis(arr)
+    }
+
+    fn finalize(&self, state: &KurtosisState) -> Option<f64> {
+        state.finalize(self.fisher, self.bias)
+    }
+}
+
+struct MomentReducer<T, M> {
+    moment: M,
+    needs_cast: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, M: Moment> Clone for MomentReducer<T, M> {
+    fn clone(&self) -> Self {
+        Self {
+            moment: self.moment,
+            needs_cast: self.needs_cast,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: PolarsNumericType, M: Moment> Reducer for MomentReducer<T, M> {
+    type Dtype = T;
+    type Value = M::State;
+
+    fn init(&self) -> Self::Value {
+        M::State::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        if self.needs_cast {
+            Cow::Owned(s.cast(&DataType::Float64).unwrap())
+        } else {
+            Cow::Borrowed(s)
+        }
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        M::combine(a, b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(x) = b {
+            M::insert_one(a, x.as_());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            M::combine(v, &M::from_arr(arr))
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .iter()
+            .map(|s| self.moment.finalize(s))
+            .collect_ca(PlSmallStr::EMPTY);
+        Ok(ca.into_series())
+    }
+}
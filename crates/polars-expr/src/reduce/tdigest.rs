@@ -0,0 +1,132 @@
+use polars_compute::tdigest::TDigest;
+use polars_plan::dsl::{TDigestFunction, update_tdigest};
+
+use super::*;
+
+pub fn new_tdigest_reduction(function: TDigestFunction) -> PolarsResult<Box<dyn GroupedReduction>> {
+    Ok(Box::new(TDigestReduce {
+        empty: function.new_digest()?,
+        function,
+        digests: Vec::new(),
+        evictions: Vec::new(),
+    }))
+}
+
+/// Maintains a t-digest per group, see [`TDigestFunction`].
+pub struct TDigestReduce {
+    function: TDigestFunction,
+    empty: TDigest,
+    digests: Vec<TDigest>,
+    evictions: Vec<TDigest>,
+}
+
+impl GroupedReduction for TDigestReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            function: self.function,
+            empty: self.empty.clone(),
+            digests: Vec::new(),
+            evictions: Vec::new(),
+        })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.digests.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.digests.resize(num_groups as usize, self.empty.clone());
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        update_tdigest(&mut self.digests[group_idx as usize], values)
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(subset.len() == group_idxs.len());
+        let values = values.as_materialized_series();
+        if let Ok(ca) = values.binary() {
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let digest = self.evict_and_get(g);
+                if let Some(bytes) = ca.get_unchecked(*i as usize) {
+                    digest.merge(&TDigest::from_bytes(bytes)?);
+                }
+            }
+        } else {
+            let values = values.cast(&DataType::Float64)?;
+            let ca = values.f64().unwrap();
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let digest = self.evict_and_get(g);
+                if let Some(v) = ca.get_unchecked(*i as usize) {
+                    digest.add(v);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        for (i, g) in subset.iter().zip(group_idxs) {
+            self.digests
+                .get_unchecked_mut(*g as usize)
+                .merge(other.digests.get_unchecked(*i as usize));
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            function: self.function,
+            empty: self.empty.clone(),
+            digests: core::mem::take(&mut self.evictions),
+            evictions: Vec::new(),
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let dtype = self.function.output_dtype();
+        let values = self
+            .digests
+            .drain(..)
+            .map(|mut digest| self.function.finish(&mut digest))
+            .collect::<Vec<_>>();
+        Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &dtype, true)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl TDigestReduce {
+    /// Returns the digest of group `g`, evicting its current digest first if requested.
+    ///
+    /// # Safety
+    /// The group index is in-bounds.
+    unsafe fn evict_and_get(&mut self, g: &EvictIdx) -> &mut TDigest {
+        let digest = self.digests.get_unchecked_mut(g.idx());
+        if g.should_evict() {
+            self.evictions
+                .push(core::mem::replace(digest, self.empty.clone()));
+        }
+        digest
+    }
+}
@@ -0,0 +1,213 @@
+use arrow::array::{BinaryArray, ListArray};
+use arrow::offset::OffsetsBuffer;
+use polars_core::chunked_array::ops::row_encode::{
+    _get_rows_encoded_unordered, get_row_encoding_context,
+};
+use polars_row::RowEncodingOptions;
+
+use super::*;
+
+pub fn new_unique_counts_reduction(in_dtype: DataType) -> Box<dyn GroupedReduction> {
+    Box::new(UniqueCountsReduce {
+        key_dtype: key_dtype(&in_dtype),
+        in_dtype,
+        counts: Vec::new(),
+        evictions: Vec::new(),
+    })
+}
+
+/// The dtype the values are hashed as.
+fn key_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        // Local categoricals of different morsels don't share their physical values.
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) => DataType::String,
+        dt => dt.clone(),
+    }
+}
+
+/// The count of a unique value and the position of its first appearance, as `(seq_id, row)`.
+///
+/// Morsels can arrive and be combined in any order, so the output is ordered on the position
+/// rather than on the insertion order.
+#[derive(Clone, Copy)]
+struct KeyCount {
+    count: IdxSize,
+    first: (u64, IdxSize),
+}
+
+/// The counts of the unique (row-encoded) values per group.
+type UniqueCounts = PlHashMap<Box<[u8]>, KeyCount>;
+
+/// Computes `unique_with_counts` per group, see [`FunctionExpr::UniqueWithCounts`].
+///
+/// [`FunctionExpr::UniqueWithCounts`]: polars_plan::dsl::FunctionExpr::UniqueWithCounts
+pub struct UniqueCountsReduce {
+    in_dtype: DataType,
+    key_dtype: DataType,
+    counts: Vec<UniqueCounts>,
+    evictions: Vec<UniqueCounts>,
+}
+
+impl UniqueCountsReduce {
+    fn encode(&self, values: &Column) -> PolarsResult<BinaryArray<i64>> {
+        let values = values.cast(&self.key_dtype)?;
+        Ok(_get_rows_encoded_unordered(std::slice::from_ref(&values))?.into_array())
+    }
+
+    /// Returns the counts of group `g`, evicting its current counts first if requested.
+    ///
+    /// # Safety
+    /// The group index is in-bounds.
+    unsafe fn evict_and_get(&mut self, g: &EvictIdx) -> &mut UniqueCounts {
+        let counts = self.counts.get_unchecked_mut(g.idx());
+        if g.should_evict() {
+            self.evictions.push(core::mem::take(counts));
+        }
+        counts
+    }
+}
+
+fn insert(counts: &mut UniqueCounts, key: &[u8], new: KeyCount) {
+    match counts.get_mut(key) {
+        Some(kc) => {
+            kc.count += new.count;
+            kc.first = kc.first.min(new.first);
+        },
+        None => {
+            counts.insert(key.into(), new);
+        },
+    }
+}
+
+fn first_at(seq_id: u64, row: IdxSize) -> KeyCount {
+    KeyCount {
+        count: 1,
+        first: (seq_id, row),
+    }
+}
+
+impl GroupedReduction for UniqueCountsReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        new_unique_counts_reduction(self.in_dtype.clone())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.counts.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.counts
+            .resize_with(num_groups as usize, Default::default);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        let keys = self.encode(values)?;
+        let counts = &mut self.counts[group_idx as usize];
+        for (row, key) in keys.values_iter().enumerate() {
+            insert(counts, key, first_at(seq_id, row as IdxSize));
+        }
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(subset.len() == group_idxs.len());
+        let keys = self.encode(values)?;
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let key = keys.value_unchecked(*i as usize);
+            insert(self.evict_and_get(g), key, first_at(seq_id, *i));
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let counts = self.counts.get_unchecked_mut(*g as usize);
+            for (key, kc) in other.counts.get_unchecked(*i as usize) {
+                insert(counts, key, *kc);
+            }
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            key_dtype: self.key_dtype.clone(),
+            counts: core::mem::take(&mut self.evictions),
+            evictions: Vec::new(),
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let groups = core::mem::take(&mut self.counts);
+        let mut offsets = Vec::with_capacity(groups.len() + 1);
+        offsets.push(0i64);
+        let mut keys = Vec::new();
+        let mut counts = Vec::new();
+        let mut scratch = Vec::new();
+        for group in &groups {
+            scratch.extend(group.iter());
+            scratch.sort_unstable_by_key(|(_, kc)| kc.first);
+            for (key, kc) in scratch.drain(..) {
+                keys.push(&key[..]);
+                counts.push(kc.count);
+            }
+            offsets.push(keys.len() as i64);
+        }
+
+        let arrow_dtype = self.key_dtype.to_physical().to_arrow(CompatLevel::newest());
+        let ctxt = get_row_encoding_context(&self.key_dtype, false);
+        let mut decoded = unsafe {
+            polars_row::decode::decode_rows(
+                &mut keys,
+                &[RowEncodingOptions::new_unsorted()],
+                &[ctxt],
+                &[arrow_dtype],
+            )
+        };
+        let values = Series::try_from((PlSmallStr::from_static("value"), decoded.pop().unwrap()))?;
+        let values =
+            unsafe { values.from_physical_unchecked(&self.key_dtype)? }.cast(&self.in_dtype)?;
+        let counts = IdxCa::from_vec(PlSmallStr::from_static("count"), counts).into_series();
+        let values =
+            StructChunked::from_series(PlSmallStr::EMPTY, values.len(), [values, counts].iter())?
+                .rechunk()
+                .into_owned()
+                .into_series();
+
+        let dtype = DataType::List(Box::new(values.dtype().clone()));
+        let inner = values.chunks()[0].clone();
+        let list = ListArray::<i64>::new(
+            ListArray::<i64>::default_datatype(inner.dtype().clone()),
+            unsafe { OffsetsBuffer::new_unchecked(offsets.into()) },
+            inner,
+            None,
+        );
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![list.boxed()], &dtype)
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, OnceLock, RwLock};
 use std::time::Duration;
 
@@ -7,8 +7,9 @@ use bitflags::bitflags;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
+use polars_utils::cancel::CancelToken;
 
-use super::NodeTimer;
+use super::{NodeTimer, ProfilingInfo};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 
@@ -114,7 +115,7 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
-    stop: Arc<AtomicBool>,
+    stop: CancelToken,
 }
 
 impl ExecutionState {
@@ -131,7 +132,7 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
-            stop: Arc::new(AtomicBool::new(false)),
+            stop: CancelToken::new(),
         }
     }
 
@@ -143,7 +144,7 @@ impl ExecutionState {
         self.node_timer.is_some()
     }
 
-    pub fn finish_timer(self) -> PolarsResult<DataFrame> {
+    pub fn finish_timer(self) -> PolarsResult<ProfilingInfo> {
         self.node_timer.unwrap().finish()
     }
 
@@ -155,6 +156,7 @@ impl ExecutionState {
                 Duration::from_nanos(start),
                 Duration::from_nanos(end),
                 name.to_string(),
+                (None, None),
             );
         }
     }
@@ -162,15 +164,31 @@ impl ExecutionState {
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
+        polars_ensure!(!self.stop.is_cancelled(), ComputeError: "query interrupted");
+        polars_ensure!(!self.stop.is_expired(), Timeout: "query did not finish before its deadline");
         Ok(())
     }
 
-    pub fn cancel_token(&self) -> Arc<AtomicBool> {
+    pub fn cancel_token(&self) -> CancelToken {
         self.stop.clone()
     }
 
-    pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+    /// Share the cancellation token with another query, cancelling either one
+    /// stops both.
+    pub fn set_cancel_token(&mut self, token: CancelToken) {
+        self.stop = token;
+    }
+
+    /// Time `func` if the nodes are timed. `rows_in` is the number of rows the node consumes.
+    pub fn record<F>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+        rows_in: usize,
+    ) -> PolarsResult<DataFrame>
+    where
+        F: FnOnce() -> PolarsResult<DataFrame>,
+    {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
@@ -178,7 +196,8 @@ impl ExecutionState {
                 let out = func();
                 let end = std::time::Instant::now();
 
-                timer.store(start, end, name.as_ref().to_string());
+                let rows_out = out.as_ref().ok().map(|df| df.height());
+                timer.store(start, end, name.as_ref().to_string(), rows_in, rows_out);
                 out
             },
         }
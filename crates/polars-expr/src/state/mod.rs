@@ -2,4 +2,5 @@ mod execution_state;
 mod node_timer;
 
 pub use execution_state::*;
+pub use node_timer::ProfilingInfo;
 use node_timer::*;
@@ -9,40 +9,93 @@ type EndInstant = Instant;
 
 type Nodes = Vec<String>;
 type Ticks = Vec<(Duration, Duration)>;
+/// Number of input and output rows of a node, unknown for nodes timed by another engine.
+type Rows = Vec<(Option<u64>, Option<u64>)>;
+
+/// Profiling information of an executed query.
+#[derive(Clone, Debug)]
+pub struct ProfilingInfo {
+    /// The `start` and `end` of every node and of the optimization in microseconds since the
+    /// start of the query.
+    pub timings: DataFrame,
+    /// The `node_name`, `duration_ns`, `rows_in` and `rows_out` of every executed node.
+    pub nodes: DataFrame,
+}
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
-    data: Arc<Mutex<(Nodes, Ticks)>>,
+    data: Arc<Mutex<(Nodes, Ticks, Rows)>>,
 }
 
 impl NodeTimer {
     pub(super) fn new(query_start: Instant) -> Self {
         Self {
             query_start,
-            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            data: Arc::new(Mutex::new((
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+            ))),
         }
     }
 
-    pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
+    pub(super) fn store(
+        &self,
+        start: StartInstant,
+        end: EndInstant,
+        name: String,
+        rows_in: usize,
+        rows_out: Option<usize>,
+    ) {
         self.store_duration(
             start.duration_since(self.query_start),
             end.duration_since(self.query_start),
             name,
+            (Some(rows_in as u64), rows_out.map(|n| n as u64)),
         )
     }
 
-    pub(super) fn store_duration(&self, start: Duration, end: Duration, name: String) {
+    pub(super) fn store_duration(
+        &self,
+        start: Duration,
+        end: Duration,
+        name: String,
+        rows: (Option<u64>, Option<u64>),
+    ) {
         let mut data = self.data.lock().unwrap();
         let nodes = &mut data.0;
         nodes.push(name);
         let ticks = &mut data.1;
-        ticks.push((start, end))
+        ticks.push((start, end));
+        data.2.push(rows);
     }
 
-    pub(super) fn finish(self) -> PolarsResult<DataFrame> {
+    pub(super) fn finish(self) -> PolarsResult<ProfilingInfo> {
         let mut data = self.data.lock().unwrap();
         let mut nodes = std::mem::take(&mut data.0);
+        let rows = std::mem::take(&mut data.2);
+
+        let duration_ns = data
+            .1
+            .iter()
+            .map(|(start, end)| end.saturating_sub(*start).as_nanos() as u64)
+            .collect::<Vec<_>>();
+        let start_ns = data
+            .1
+            .iter()
+            .map(|(start, _)| start.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        let nodes_df = df!(
+            "node_name" => &nodes,
+            "duration_ns" => duration_ns,
+            "rows_in" => rows.iter().map(|r| r.0).collect::<Vec<_>>(),
+            "rows_out" => rows.iter().map(|r| r.1).collect::<Vec<_>>(),
+            "start" => start_ns,
+        )?
+        .sort(vec!["start"], SortMultipleOptions::default())?
+        .drop("start")?;
+
         nodes.push("optimization".to_string());
 
         let mut ticks = std::mem::take(&mut data.1);
@@ -68,6 +121,9 @@ impl NodeTimer {
         let height = nodes_s.len();
         let columns = vec![nodes_s, start.into_column(), end.into_column()];
         let df = unsafe { DataFrame::new_no_checks(height, columns) };
-        df.sort(vec!["start"], SortMultipleOptions::default())
+        Ok(ProfilingInfo {
+            timings: df.sort(vec!["start"], SortMultipleOptions::default())?,
+            nodes: nodes_df,
+        })
     }
 }
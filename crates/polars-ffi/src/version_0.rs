@@ -59,11 +59,10 @@ pub fn export_column(c: &Column) -> SeriesExport {
 }
 
 pub fn export_series(s: &Series) -> SeriesExport {
-    let field = ArrowField::new(
-        s.name().clone(),
-        s.dtype().to_arrow(CompatLevel::newest()),
-        true,
-    );
+    // The field carries the metadata that restores categoricals and enums on import.
+    let field = s
+        .dtype()
+        .to_arrow_field(s.name().clone(), CompatLevel::newest());
     let schema = Box::new(ffi::export_field_to_c(&field));
 
     let mut arrays = (0..s.chunks().len())
@@ -100,7 +99,7 @@ pub unsafe fn import_series(e: SeriesExport) -> PolarsResult<Series> {
         })
         .collect::<PolarsResult<Vec<_>>>()?;
 
-    Series::try_from((field.name.clone(), chunks))
+    Series::try_from((&field, chunks))
 }
 
 /// # Safety
@@ -172,4 +171,42 @@ mod test {
             assert_eq!(import_series(e).unwrap(), s);
         };
     }
+
+    fn roundtrip(s: &Series) -> Series {
+        unsafe { import_series(export_series(s)).unwrap() }
+    }
+
+    #[test]
+    fn test_ffi_nested() -> PolarsResult<()> {
+        let list = Series::new(
+            "list".into(),
+            [
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [3, 4]),
+            ],
+        );
+        let array = list.cast(&DataType::Array(Box::new(DataType::Int32), 2))?;
+        let fields = [Series::new("x".into(), [1, 2]), list.clone()];
+        let st = StructChunked::from_series("st".into(), 2, fields.iter())?.into_series();
+
+        for s in [list, array, st] {
+            let out = roundtrip(&s);
+            assert_eq!(out.dtype(), s.dtype());
+            assert!(out.equals_missing(&s));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ffi_categorical() -> PolarsResult<()> {
+        let s = Series::new("c".into(), [Some("x"), None, Some("y"), Some("x")])
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Lexical))?;
+        let out = roundtrip(&s);
+        assert!(matches!(out.dtype(), DataType::Categorical(_, _)));
+        assert!(
+            out.cast(&DataType::String)?
+                .equals_missing(&s.cast(&DataType::String)?)
+        );
+        Ok(())
+    }
 }
@@ -338,10 +338,7 @@ impl<'a> CoreReader<'a> {
                     &projection
                         .iter()
                         .map(|&i| self.schema.get_at_index(i).unwrap())
-                        .map(|(name, dtype)| Field {
-                            name: name.clone(),
-                            dtype: dtype.clone(),
-                        })
+                        .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
                         .collect::<Schema>(),
                 )
             };
@@ -373,17 +370,16 @@ impl<'a> CoreReader<'a> {
         // We have to do this after parsing as there can be comments.
         let total_line_count = &AtomicUsize::new(0);
 
-        #[cfg(not(target_family = "wasm"))]
-        let pool;
         #[cfg(not(target_family = "wasm"))]
         let pool = if n_threads == POOL.current_num_threads() {
-            &POOL
+            POOL.current_pool()
         } else {
-            pool = rayon::ThreadPoolBuilder::new()
-                .num_threads(n_threads)
-                .build()
-                .map_err(|_| polars_err!(ComputeError: "could not spawn threads"))?;
-            &pool
+            Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(n_threads)
+                    .build()
+                    .map_err(|_| polars_err!(ComputeError: "could not spawn threads"))?,
+            )
         };
         #[cfg(target_family = "wasm")]
         let pool = &POOL;
@@ -1,8 +1,13 @@
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ValueFormat;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,6 +47,9 @@ pub struct SerializeOptions {
     /// and [`DataType::Float32`](polars_core::datatypes::DataType::Float32).
     pub float_scientific: Option<bool>,
     pub float_precision: Option<usize>,
+    /// Per-column overrides of the formats above, keyed by column name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_formats: Arc<BTreeMap<PlSmallStr, ValueFormat>>,
     /// Used as separator.
     pub separator: u8,
     /// Quoting character.
@@ -62,6 +70,7 @@ impl Default for SerializeOptions {
             datetime_format: None,
             float_scientific: None,
             float_precision: None,
+            column_formats: Default::default(),
             separator: b',',
             quote_char: b'"',
             null: String::new(),
@@ -10,6 +10,7 @@ use polars_error::polars_ensure;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::ValueFormat;
 use crate::csv::write::SerializeOptions;
 
 pub(crate) fn write<W: Write>(
@@ -44,7 +45,17 @@ pub(crate) fn write<W: Write>(
         ComputeError: "quote char results in invalid utf-8",
     );
 
-    let (datetime_formats, time_zones): (Vec<&str>, Vec<Option<Tz>>) = df
+    for (name, format) in options.column_formats.iter() {
+        let column = df.column(name)?;
+        format.validate(name, column.dtype())?;
+    }
+    let column_formats: Vec<Option<&ValueFormat>> = df
+        .get_columns()
+        .iter()
+        .map(|column| options.column_formats.get(column.name()))
+        .collect();
+
+    let (mut datetime_formats, time_zones): (Vec<&str>, Vec<Option<Tz>>) = df
         .get_columns()
         .iter()
         .map(|column| match column.dtype() {
@@ -111,6 +122,11 @@ pub(crate) fn write<W: Write>(
             _ => ("", None),
         })
         .unzip();
+    for (datetime_format, format) in datetime_formats.iter_mut().zip(&column_formats) {
+        if let Some(ValueFormat::Temporal(format)) = format {
+            *datetime_format = format;
+        }
+    }
 
     let len = df.height();
     let total_rows_per_pool_iter = n_threads * chunk_size;
@@ -150,6 +166,7 @@ pub(crate) fn write<W: Write>(
                             &*col.as_materialized_series().chunks()[0],
                             options,
                             col.dtype(),
+                            column_formats[i],
                             datetime_formats[i],
                             time_zones[i],
                         )
@@ -42,6 +42,7 @@ use memchr::{memchr_iter, memchr3};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 
+use crate::ValueFormat;
 use crate::csv::write::{QuoteStyle, SerializeOptions};
 
 const TOO_MANY_MSG: &str = "too many items requested from CSV serializer";
@@ -283,7 +284,7 @@ type ChronoFormatIter<'a, 'b> = std::slice::Iter<'a, chrono::format::Item<'b>>;
 
 #[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
 fn date_and_time_serializer<'a, Underlying: NativeType, T: std::fmt::Display>(
-    format_str: &'a Option<String>,
+    format_str: Option<&'a str>,
     description: &str,
     array: &'a dyn Array,
     sample_value: T,
@@ -511,9 +512,23 @@ pub(super) fn serializer_for<'a>(
     array: &'a dyn Array,
     options: &'a SerializeOptions,
     dtype: &'a DataType,
+    format: Option<&'a ValueFormat>,
     _datetime_format: &'a str,
     _time_zone: Option<Tz>,
 ) -> PolarsResult<Box<dyn Serializer<'a> + Send + 'a>> {
+    let (float_precision, float_scientific) = match format {
+        Some(ValueFormat::Float {
+            precision,
+            scientific,
+        }) => (*precision, *scientific),
+        _ => (options.float_precision, options.float_scientific),
+    };
+    #[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+    let temporal_format = match format {
+        Some(ValueFormat::Temporal(format)) => Some(format.as_str()),
+        _ => None,
+    };
+
     macro_rules! quote_if_always {
         ($make_serializer:path, $($arg:tt)*) => {{
             let serializer = $make_serializer(array.as_any().downcast_ref().unwrap(), $($arg)*);
@@ -536,27 +551,27 @@ pub(super) fn serializer_for<'a>(
         DataType::Int64 => quote_if_always!(integer_serializer::<i64>),
         DataType::UInt64 => quote_if_always!(integer_serializer::<u64>),
         DataType::Int128 => quote_if_always!(integer_serializer::<i128>),
-        DataType::Float32 => match options.float_precision {
-            Some(precision) => match options.float_scientific {
+        DataType::Float32 => match float_precision {
+            Some(precision) => match float_scientific {
                 Some(true) => {
                     quote_if_always!(float_serializer_with_precision_scientific::<f32>, precision)
                 },
                 _ => quote_if_always!(float_serializer_with_precision_positional::<f32>, precision),
             },
-            None => match options.float_scientific {
+            None => match float_scientific {
                 Some(true) => quote_if_always!(float_serializer_no_precision_scientific::<f32>),
                 Some(false) => quote_if_always!(float_serializer_no_precision_positional::<f32>),
                 None => quote_if_always!(float_serializer_no_precision_autoformat::<f32>),
             },
         },
-        DataType::Float64 => match options.float_precision {
-            Some(precision) => match options.float_scientific {
+        DataType::Float64 => match float_precision {
+            Some(precision) => match float_scientific {
                 Some(true) => {
                     quote_if_always!(float_serializer_with_precision_scientific::<f64>, precision)
                 },
                 _ => quote_if_always!(float_serializer_with_precision_positional::<f64>, precision),
             },
-            None => match options.float_scientific {
+            None => match float_scientific {
                 Some(true) => quote_if_always!(float_serializer_no_precision_scientific::<f64>),
                 Some(false) => quote_if_always!(float_serializer_no_precision_positional::<f64>),
                 None => quote_if_always!(float_serializer_no_precision_autoformat::<f64>),
@@ -574,7 +589,7 @@ pub(super) fn serializer_for<'a>(
         },
         #[cfg(feature = "dtype-date")]
         DataType::Date => date_and_time_serializer(
-            &options.date_format,
+            temporal_format.or(options.date_format.as_deref()),
             "NaiveDate",
             array,
             chrono::NaiveDate::MAX,
@@ -584,7 +599,7 @@ pub(super) fn serializer_for<'a>(
         )?,
         #[cfg(feature = "dtype-time")]
         DataType::Time => date_and_time_serializer(
-            &options.time_format,
+            temporal_format.or(options.time_format.as_deref()),
             "NaiveTime",
             array,
             chrono::NaiveTime::MIN,
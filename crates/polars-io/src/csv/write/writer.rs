@@ -1,13 +1,17 @@
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_core::schema::Schema;
 use polars_error::PolarsResult;
+use polars_utils::pl_str::PlSmallStr;
 
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
+use crate::ValueFormat;
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -135,6 +139,15 @@ where
         self
     }
 
+    /// Override the float and temporal formats of individual columns.
+    pub fn with_column_formats(
+        mut self,
+        formats: impl Into<Arc<BTreeMap<PlSmallStr, ValueFormat>>>,
+    ) -> Self {
+        self.options.column_formats = formats.into();
+        self
+    }
+
     /// Set the single byte character used for quoting.
     pub fn with_quote_char(mut self, char: u8) -> Self {
         self.options.quote_char = char;
@@ -32,13 +32,16 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.equals(&df_read));
 //! ```
-use std::io::{Read, Seek};
+use std::fs::File;
+use std::hash::BuildHasher;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 
 use arrow::datatypes::{ArrowSchemaRef, Metadata};
 use arrow::io::ipc::read::{self, get_row_count};
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
+use polars_utils::aliases::PlFixedStateQuality;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -49,10 +52,91 @@ use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct IpcScanOptions;
+pub struct IpcScanOptions {
+    /// Take a shared advisory lock on the files while they are memory-mapped. Files that can't
+    /// be locked are read into memory instead. Only supported on Unix.
+    ///
+    /// Without the lock, a file truncated while it is read can crash the process with a SIGBUS.
+    /// Modifications are detected on a best-effort basis in both cases, see
+    /// [`IpcFileFingerprint`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lock_file: bool,
+}
+
+/// Length and footer hash of an IPC file.
+///
+/// Scans record this for every local file when the query is planned, and compare it against
+/// the file before every read from its memory map. The footer holds the offsets of all record
+/// batches, so a rewrite of the file almost always changes it.
+///
+/// This doesn't make reading a modified file safe. A truncation between the check and the read
+/// still results in a SIGBUS, and a rewrite that keeps the length and footer goes unnoticed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpcFileFingerprint {
+    pub len: u64,
+    pub footer_hash: u64,
+}
+
+impl IpcFileFingerprint {
+    /// The file ends with the footer, its length as an `i32` and the `ARROW1` magic.
+    const TRAILER_LEN: u64 = 10;
+
+    /// Fingerprint of the file with the contents `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let len = bytes.len() as u64;
+        let start = Self::footer_start(len, &bytes[bytes.len().saturating_sub(10)..]);
+        Self {
+            len,
+            footer_hash: PlFixedStateQuality::default().hash_one(&bytes[start as usize..]),
+        }
+    }
+
+    /// Fingerprint of `file`, only reading its footer.
+    pub fn from_file(mut file: &File) -> PolarsResult<Self> {
+        let len = file.metadata()?.len();
+
+        let mut trailer = [0u8; Self::TRAILER_LEN as usize];
+        let trailer = &mut trailer[..len.min(Self::TRAILER_LEN) as usize];
+        file.seek(SeekFrom::Start(len - trailer.len() as u64))?;
+        file.read_exact(trailer)?;
+
+        let start = Self::footer_start(len, trailer);
+        let mut footer = Vec::with_capacity((len - start) as usize);
+        file.seek(SeekFrom::Start(start))?;
+        file.take(len - start).read_to_end(&mut footer)?;
+
+        Ok(Self {
+            len,
+            footer_hash: PlFixedStateQuality::default().hash_one(footer.as_slice()),
+        })
+    }
+
+    /// Raise an error if `self` differs from the fingerprint `expected`.
+    pub fn ensure_eq(&self, expected: &Self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.len == expected.len,
+            ComputeError: "IPC file was modified during the query: its length changed from {} to {} bytes",
+            expected.len, self.len
+        );
+        polars_ensure!(
+            self.footer_hash == expected.footer_hash,
+            ComputeError: "IPC file was modified during the query: its footer changed"
+        );
+        Ok(())
+    }
+
+    fn footer_start(len: u64, trailer: &[u8]) -> u64 {
+        let footer_len = if trailer.len() as u64 == Self::TRAILER_LEN {
+            i32::from_le_bytes(trailer[..4].try_into().unwrap()).max(0) as u64
+        } else {
+            0
+        };
+        len.saturating_sub(footer_len + Self::TRAILER_LEN)
+    }
+}
 
 /// Read Arrows IPC format into a DataFrame
 ///
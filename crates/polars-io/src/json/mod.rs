@@ -64,6 +64,7 @@
 //!
 pub(crate) mod infer;
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
@@ -72,18 +73,79 @@ use arrow::legacy::conversion::chunk_to_struct;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_error::{PolarsResult, polars_bail};
-use polars_json::json::write::FallibleStreamingIterator;
+use polars_json::json::write::{
+    FallibleStreamingIterator, SerializeOptions as JsonSerializeOptions,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+use crate::ValueFormat;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
+/// Options for writing NDJSON.
+///
+/// Per-column formats are set on the [`JsonWriter`] or the [`BatchedWriter`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct JsonWriterOptions {}
+pub struct JsonWriterOptions {
+    /// Number of decimals floats are written with.
+    pub float_precision: Option<usize>,
+    /// Write floats in scientific (`true`) or positional (`false`) notation. By default the
+    /// shortest representation that parses back to the same float is written.
+    pub float_scientific: Option<bool>,
+}
+
+impl JsonWriterOptions {
+    /// Get the options for the serializer of `polars-json`, for the columns in `schema`.
+    ///
+    /// `column_formats` override the float options and the format of temporal columns, keyed
+    /// by column name.
+    fn serialize_options(
+        &self,
+        column_formats: &BTreeMap<PlSmallStr, ValueFormat>,
+        schema: &Schema,
+    ) -> PolarsResult<(
+        JsonSerializeOptions,
+        PlHashMap<PlSmallStr, JsonSerializeOptions>,
+    )> {
+        let options = JsonSerializeOptions {
+            float_precision: self.float_precision,
+            float_scientific: self.float_scientific,
+            temporal_format: None,
+        };
+
+        let field_options = column_formats
+            .iter()
+            .map(|(name, format)| {
+                let dtype = schema.try_get(name)?;
+                format.validate(name, dtype)?;
+
+                let field_options = match format {
+                    ValueFormat::Float {
+                        precision,
+                        scientific,
+                    } => JsonSerializeOptions {
+                        float_precision: *precision,
+                        float_scientific: *scientific,
+                        temporal_format: None,
+                    },
+                    ValueFormat::Temporal(format) => JsonSerializeOptions {
+                        temporal_format: Some(format.clone()),
+                        ..Default::default()
+                    },
+                };
+                field_options.validate()?;
+                Ok((name.clone(), field_options))
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok((options, field_options))
+    }
+}
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
 /// or `JsonLines` (each row output on a separate line).
@@ -118,6 +180,8 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    options: JsonWriterOptions,
+    column_formats: BTreeMap<PlSmallStr, ValueFormat>,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -125,6 +189,24 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the number of decimals floats are written with.
+    pub fn with_float_precision(mut self, precision: Option<usize>) -> Self {
+        self.options.float_precision = precision;
+        self
+    }
+
+    /// Set the forced scientific notation for floats.
+    pub fn with_float_scientific(mut self, scientific: Option<bool>) -> Self {
+        self.options.float_scientific = scientific;
+        self
+    }
+
+    /// Override the float and temporal formats of individual columns.
+    pub fn with_column_formats(mut self, formats: BTreeMap<PlSmallStr, ValueFormat>) -> Self {
+        self.column_formats = formats;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -137,6 +219,8 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            options: JsonWriterOptions::default(),
+            column_formats: BTreeMap::new(),
         }
     }
 
@@ -150,19 +234,26 @@ where
                 Ok(s.field().to_arrow(CompatLevel::newest()))
             })
             .collect::<PolarsResult<Vec<_>>>()?;
+        let (options, field_options) = self
+            .options
+            .serialize_options(&self.column_formats, df.schema())?;
         let batches = df
             .iter_chunks(CompatLevel::newest(), false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
         match self.json_format {
             JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+                    .with_options(options)
+                    .with_field_options(field_options);
                 let writer =
                     polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
+                let serializer = polars_json::json::write::Serializer::new(batches, vec![])
+                    .with_options(options)
+                    .with_field_options(field_options);
                 polars_json::json::write::write(&mut self.buffer, serializer)?;
             },
         }
@@ -173,6 +264,8 @@ where
 
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    options: JsonWriterOptions,
+    column_formats: BTreeMap<PlSmallStr, ValueFormat>,
 }
 
 impl<W> BatchedWriter<W>
@@ -180,7 +273,23 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            options: JsonWriterOptions::default(),
+            column_formats: BTreeMap::new(),
+        }
+    }
+
+    /// Set the options used to write the values.
+    pub fn with_options(mut self, options: JsonWriterOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Override the float and temporal formats of individual columns.
+    pub fn with_column_formats(mut self, formats: BTreeMap<PlSmallStr, ValueFormat>) -> Self {
+        self.column_formats = formats;
+        self
     }
     /// Write a batch to the json writer.
     ///
@@ -195,10 +304,15 @@ where
                 Ok(s.field().to_arrow(CompatLevel::newest()))
             })
             .collect::<PolarsResult<Vec<_>>>()?;
+        let (options, field_options) = self
+            .options
+            .serialize_options(&self.column_formats, df.schema())?;
         let chunks = df.iter_chunks(CompatLevel::newest(), false);
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+            .with_options(options)
+            .with_field_options(field_options);
         while let Some(block) = serializer.next()? {
             self.writer.write_all(block)?;
         }
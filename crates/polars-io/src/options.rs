@@ -1,4 +1,6 @@
+use polars_core::prelude::DataType;
 use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -51,3 +53,41 @@ impl Default for HiveOptions {
         Self::new_enabled()
     }
 }
+
+/// Format override for the values of a single column, used by the text-based writers.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ValueFormat {
+    /// Used for [`DataType::Float32`](polars_core::datatypes::DataType::Float32) and
+    /// [`DataType::Float64`](polars_core::datatypes::DataType::Float64) columns.
+    ///
+    /// Replaces the writer-wide float options for this column.
+    Float {
+        precision: Option<usize>,
+        scientific: Option<bool>,
+    },
+    /// A `chrono` format string used for
+    /// [`DataType::Date`](polars_core::datatypes::DataType::Date),
+    /// [`DataType::Time`](polars_core::datatypes::DataType::Time) and
+    /// [`DataType::Datetime`](polars_core::datatypes::DataType::Datetime) columns.
+    Temporal(String),
+}
+
+impl ValueFormat {
+    /// Check that this format can be applied to a column of `dtype`.
+    pub fn validate(&self, name: &str, dtype: &DataType) -> PolarsResult<()> {
+        let is_valid = match self {
+            Self::Float { .. } => dtype.is_float(),
+            Self::Temporal(_) => matches!(
+                dtype,
+                DataType::Date | DataType::Time | DataType::Datetime(_, _)
+            ),
+        };
+        polars_ensure!(
+            is_valid,
+            InvalidOperation: "cannot apply {:?} to column '{}' of dtype {}", self, name, dtype
+        );
+        Ok(())
+    }
+}
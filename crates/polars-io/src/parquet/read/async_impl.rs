@@ -3,6 +3,7 @@
 use arrow::datatypes::ArrowSchemaRef;
 use object_store::path::Path as ObjectPath;
 use polars_core::prelude::*;
+use polars_parquet::read::FileDecryptionProperties;
 use polars_parquet::write::FileMetadata;
 
 use crate::cloud::{
@@ -16,6 +17,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl ParquetObjectStore {
@@ -33,9 +35,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
         })
     }
 
+    /// Set the keys to read a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -53,7 +62,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -97,6 +106,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -111,22 +121,24 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    let footer_byte_length: usize = {
+    let (footer_byte_length, encrypted_footer): (usize, bool) = {
         let reader = &mut footer_header_bytes.as_ref();
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        let encrypted_footer = magic == polars_parquet::parquet::PARQUET_ENCRYPTED_MAGIC;
+        if !encrypted_footer && magic != polars_parquet::parquet::PARQUET_MAGIC {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
             .into());
         }
-        footer_byte_size.try_into().map_err(|_| {
+        let footer_byte_length = footer_byte_size.try_into().map_err(|_| {
             polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "negative footer byte length".to_string(),
             )
-        })?
+        })?;
+        (footer_byte_length, encrypted_footer)
     };
 
     let footer_bytes = store
@@ -142,11 +154,15 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    Ok(
+        polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+            footer_bytes.as_ref(),
+            encrypted_footer,
+            // TODO: Describe why this makes sense. Taken from the previous
+            // implementation which said "a highly nested but sparse struct could
+            // result in many allocations".
+            footer_bytes.as_ref().len() * 2 + 1024,
+            decryption,
+        )?,
+    )
 }
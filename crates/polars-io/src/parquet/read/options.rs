@@ -1,4 +1,5 @@
 use polars_core::schema::SchemaRef;
+use polars_parquet::read::FileDecryptionProperties;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,13 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Keys to read files written with Parquet modular encryption.
+    ///
+    /// Not serialized, so the keys never end up in a serialized plan. A deserialized plan
+    /// can't read encrypted files.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    pub encryption: Option<FileDecryptionProperties>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -54,10 +54,11 @@ fn should_copy_sortedness(dtype: &DataType) -> bool {
     // @NOTE: For now, we are a bit conservative with this.
     use DataType as D;
 
-    matches!(
-        dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
-    )
+    dtype.is_temporal()
+        || matches!(
+            dtype,
+            D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
+        )
 }
 
 pub fn try_set_sorted_flag(
@@ -239,7 +240,10 @@ fn rg_to_dfs_optionally_par_over_columns(
                 store,
             )?;
 
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+            // The sorting columns refer to leaves, so this only applies to non-nested fields.
+            if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+            }
             Ok(series.into_column())
         };
 
@@ -380,7 +384,9 @@ fn rg_to_dfs_par_over_rg(
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                            try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+                        }
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -27,6 +27,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -163,13 +164,22 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Set the keys to read a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -191,6 +201,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
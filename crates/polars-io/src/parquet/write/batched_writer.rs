@@ -4,15 +4,16 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_columns, schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
-use super::{KeyValueMetadata, ParquetMetadataContext};
+use super::{KeyValueMetadata, ParquetMetadataContext, ParquetSortingColumn};
 
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
@@ -26,6 +27,10 @@ pub struct BatchedWriter<W: Write> {
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
+    /// Sorting columns declared by the user, these are written for every row group.
+    pub(super) sorting_columns: Vec<SortingColumn>,
+    /// The declared sorting columns by name, to check the data against.
+    pub(super) declared_sorting_columns: Vec<ParquetSortingColumn>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -43,27 +48,81 @@ impl<W: Write> BatchedWriter<W> {
             options,
             parallel,
             key_value_metadata,
+            sorting_columns: Vec::new(),
+            declared_sorting_columns: Vec::new(),
         }
     }
 
+    /// Declare the columns that every row group written by this writer is sorted by.
+    pub fn with_sorting_columns(
+        mut self,
+        sorting_columns: &[ParquetSortingColumn],
+    ) -> PolarsResult<Self> {
+        let writer = self.writer.get_mut().unwrap();
+        self.sorting_columns = resolve_sorting_columns(writer.parquet_schema(), sorting_columns)?;
+        self.declared_sorting_columns = sorting_columns.to_vec();
+        Ok(self)
+    }
+
+    /// The declared sorting columns, extended with the columns of `df` that carry a sorted flag.
+    fn sorting_columns_for(&self, df: &DataFrame) -> Vec<SortingColumn> {
+        let mut sorting_columns = self.sorting_columns.clone();
+        for column in df.get_columns() {
+            // Only record the flag for types for which Parquet uses the same order as we do.
+            if !(column.dtype().is_integer() || column.dtype().is_temporal()) {
+                continue;
+            }
+            let descending = match column.is_sorted_flag() {
+                IsSorted::Ascending => false,
+                IsSorted::Descending => true,
+                IsSorted::Not => continue,
+            };
+            let Some(column_idx) = leaf_index(&self.parquet_schema, column.name()) else {
+                continue;
+            };
+            if sorting_columns.iter().any(|s| s.column_idx == column_idx) {
+                continue;
+            }
+            // A sorted column has all of its nulls at one end.
+            let nulls_first = column.null_count() > 0 && column.get(0).is_ok_and(|v| v.is_null());
+            sorting_columns.push(SortingColumn {
+                column_idx,
+                descending,
+                nulls_first,
+            });
+        }
+        sorting_columns
+    }
+
+    /// Checks that the columns of `df` are sorted as declared.
+    fn check_sorting_columns(&self, df: &DataFrame) -> PolarsResult<()> {
+        for sorting_column in &self.declared_sorting_columns {
+            ensure_sorted_as_declared(df.column(&sorting_column.name)?, sorting_column)?;
+        }
+        Ok(())
+    }
+
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
     ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+        let check = self.check_sorting_columns(df).err().map(Err);
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
-        rb_iter.filter_map(move |batch| match batch.len() {
-            0 => None,
-            _ => {
-                let row_group = create_eager_serializer(
-                    batch,
-                    self.parquet_schema.fields(),
-                    self.column_options.as_ref(),
-                    self.options,
-                );
+        check
+            .into_iter()
+            .chain(rb_iter.filter_map(move |batch| match batch.len() {
+                0 => None,
+                _ => {
+                    let row_group = create_eager_serializer(
+                        batch,
+                        self.parquet_schema.fields(),
+                        self.column_options.as_ref(),
+                        self.options,
+                    );
 
-                Some(row_group)
-            },
-        })
+                    Some(row_group)
+                },
+            }))
     }
 
     /// Write a batch to the parquet writer.
@@ -71,6 +130,7 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.check_sorting_columns(df)?;
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
@@ -78,10 +138,11 @@ impl<W: Write> BatchedWriter<W> {
             self.options,
             self.parallel,
         );
+        let sorting_columns = self.sorting_columns_for(df);
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            writer.write_with_sorting_columns(group?, Some(sorting_columns.clone()))?;
         }
         Ok(())
     }
@@ -98,7 +159,7 @@ impl<W: Write> BatchedWriter<W> {
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_sorting_columns(rg, Some(self.sorting_columns.clone()))?;
         Ok(())
     }
 
@@ -113,7 +174,7 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write_with_sorting_columns(group, Some(self.sorting_columns.clone()))?;
         }
         Ok(())
     }
@@ -143,6 +204,99 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// Index of the leaf column of a top-level, non-nested field.
+fn leaf_index(parquet_schema: &SchemaDescriptor, name: &str) -> Option<i32> {
+    parquet_schema
+        .columns()
+        .iter()
+        .position(|c| c.path_in_schema.len() == 1 && c.path_in_schema[0].as_str() == name)
+        .map(|idx| idx as i32)
+}
+
+pub(super) fn resolve_sorting_columns(
+    parquet_schema: &SchemaDescriptor,
+    sorting_columns: &[ParquetSortingColumn],
+) -> PolarsResult<Vec<SortingColumn>> {
+    sorting_columns
+        .iter()
+        .map(|c| {
+            let Some(column_idx) = leaf_index(parquet_schema, &c.name) else {
+                polars_bail!(
+                    ColumnNotFound: "sorting column '{}' is not a non-nested column of the written schema",
+                    c.name
+                );
+            };
+            Ok(SortingColumn {
+                column_idx,
+                descending: c.descending,
+                nulls_first: !c.nulls_last,
+            })
+        })
+        .collect()
+}
+
+/// Checks that `column` is sorted in the order declared by `sorting_column`.
+///
+/// The declared sorting columns are written to the metadata of every row group and readers trust
+/// them, so data that isn't sorted as declared is rejected.
+pub fn ensure_sorted_as_declared(
+    column: &Column,
+    sorting_column: &ParquetSortingColumn,
+) -> PolarsResult<()> {
+    let null_count = column.null_count();
+    let is_sorted = match column {
+        // A scalar column holds a single value, only its null position matters.
+        Column::Scalar(_) => true,
+        _ => {
+            let s = column.as_materialized_series();
+            let len = s.len();
+            let (nulls, values) = if sorting_column.nulls_last {
+                (
+                    s.slice((len - null_count) as i64, null_count),
+                    s.slice(0, len - null_count),
+                )
+            } else {
+                (
+                    s.slice(0, null_count),
+                    s.slice(null_count as i64, len - null_count),
+                )
+            };
+            nulls.null_count() == null_count
+                && values_are_sorted(&values, sorting_column.descending)?
+        },
+    };
+    polars_ensure!(
+        is_sorted,
+        InvalidOperation: "column '{}' is declared as a sorting column, but it is not sorted {} with nulls {}",
+        sorting_column.name,
+        if sorting_column.descending { "descending" } else { "ascending" },
+        if sorting_column.nulls_last { "last" } else { "first" },
+    );
+    Ok(())
+}
+
+/// Whether the non-null `values` are sorted, using the sorted flag if it is set.
+///
+/// Otherwise each value is compared with the next one, in the total order that is also used to
+/// sort.
+fn values_are_sorted(values: &Series, descending: bool) -> PolarsResult<bool> {
+    match (values.is_sorted_flag(), descending) {
+        (IsSorted::Ascending, false) | (IsSorted::Descending, true) => return Ok(true),
+        _ => {},
+    }
+    if values.len() < 2 {
+        return Ok(true);
+    }
+    let n = values.len() - 1;
+    let (current, next) = (values.slice(0, n), values.slice(1, n));
+    let out_of_order = if descending {
+        current.lt(&next)?
+    } else {
+        current.gt(&next)?
+    };
+    Ok(!out_of_order.any())
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
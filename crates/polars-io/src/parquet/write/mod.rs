@@ -5,11 +5,11 @@ mod key_value_metadata;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
+pub use batched_writer::{BatchedWriter, ensure_sorted_as_declared};
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
     BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
-    ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
+    ParquetFieldOverwrites, ParquetSortingColumn, ParquetWriteOptions, ZstdLevel,
 };
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
 pub use writer::{ParquetWriter, get_column_write_options};
@@ -26,6 +26,21 @@ pub struct ParquetWriteOptions {
 
     /// Per-field overwrites for writing properties.
     pub field_overwrites: Vec<ParquetFieldOverwrites>,
+    /// Columns that every row group is sorted by, written as `sorting_columns` metadata.
+    ///
+    /// Columns that carry a sorted flag are recorded as well.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sorting_columns: Vec<ParquetSortingColumn>,
+}
+
+/// A column the written data is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetSortingColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    pub nulls_last: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -10,11 +10,14 @@ use polars_parquet::write::{
     Version, WriteOptions, to_parquet_schema,
 };
 
-use super::batched_writer::BatchedWriter;
+use super::batched_writer::{BatchedWriter, resolve_sorting_columns};
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use super::{
+    KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetSortingColumn,
+    ParquetWriteOptions,
+};
 use crate::prelude::ChildFieldOverwrites;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{df_to_arrow_schema_checked, schema_to_arrow_checked};
 
 impl ParquetWriteOptions {
     pub fn to_writer<F>(&self, f: F) -> ParquetWriter<F>
@@ -27,6 +30,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_sorting_columns(self.sorting_columns.clone())
     }
 }
 
@@ -47,6 +51,8 @@ pub struct ParquetWriter<W> {
     field_overwrites: Vec<ParquetFieldOverwrites>,
     /// Custom file-level key value metadata
     key_value_metadata: Option<KeyValueMetadata>,
+    /// Columns that every row group is sorted by
+    sorting_columns: Vec<ParquetSortingColumn>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
 }
@@ -69,6 +75,7 @@ where
             parallel: true,
             field_overwrites: Vec::new(),
             key_value_metadata: None,
+            sorting_columns: Vec::new(),
             context_info: None,
         }
     }
@@ -113,6 +120,13 @@ where
         self
     }
 
+    /// Declare the columns that every row group is sorted by. These are written as the
+    /// `sorting_columns` of each row group, together with any column that carries a sorted flag.
+    pub fn with_sorting_columns(mut self, sorting_columns: Vec<ParquetSortingColumn>) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
     /// Set context information for the writer
     pub fn with_context_info(mut self, context_info: Option<PlHashMap<String, String>>) -> Self {
         self.context_info = context_info;
@@ -121,6 +135,10 @@ where
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        self.batched_arrow(schema)
+    }
+
+    fn batched_arrow(self, schema: ArrowSchema) -> PolarsResult<BatchedWriter<W>> {
         let column_options = get_column_write_options(&schema, &self.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
@@ -131,6 +149,8 @@ where
             &column_options,
         )?);
 
+        let sorting_columns = resolve_sorting_columns(&parquet_schema, &self.sorting_columns)?;
+
         Ok(BatchedWriter {
             writer,
             parquet_schema,
@@ -138,6 +158,8 @@ where
             options,
             parallel: self.parallel,
             key_value_metadata: self.key_value_metadata,
+            sorting_columns,
+            declared_sorting_columns: self.sorting_columns,
         })
     }
 
@@ -154,7 +176,8 @@ where
     /// Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
         let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
-        let mut batched = self.batched(chunked_df.schema())?;
+        let schema = df_to_arrow_schema_checked(&chunked_df, CompatLevel::newest(), "parquet")?;
+        let mut batched = self.batched_arrow(schema)?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
@@ -178,7 +178,48 @@ pub fn expand_paths(
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
 ) -> PolarsResult<Arc<[PathBuf]>> {
-    expand_paths_hive(paths, glob, cloud_options, false).map(|x| x.0)
+    expand_paths_hive(paths, glob, cloud_options, false, None).map(|x| x.0)
+}
+
+/// Filter on the leading hive partition directories of a directory scan.
+///
+/// When given to [`expand_paths_hive`], the sub-directories of the first `num_levels` directory
+/// levels are only listed if the filter accepts their name, e.g. `month=01`. The filter is called
+/// with the directory level, starting at 0, and the name of the directory.
+#[derive(Clone)]
+pub struct HiveListingFilter {
+    num_levels: usize,
+    #[allow(clippy::type_complexity)]
+    accepts: Arc<dyn Fn(usize, &str) -> bool + Send + Sync>,
+}
+
+impl HiveListingFilter {
+    pub fn new(
+        num_levels: usize,
+        accepts: impl Fn(usize, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            num_levels,
+            accepts: Arc::new(accepts),
+        }
+    }
+
+    pub fn num_levels(&self) -> usize {
+        self.num_levels
+    }
+
+    /// Whether the directory `name` at directory `level` should be listed.
+    pub fn accepts(&self, level: usize, name: &str) -> bool {
+        level >= self.num_levels || (self.accepts)(level, name)
+    }
+}
+
+impl std::fmt::Debug for HiveListingFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HiveListingFilter")
+            .field("num_levels", &self.num_levels)
+            .finish_non_exhaustive()
+    }
 }
 
 struct HiveIdxTracker<'a> {
@@ -214,16 +255,22 @@ impl HiveIdxTracker<'_> {
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
+///
+/// If a `listing_filter` is given, only the partition directories of directory inputs that it
+/// accepts are listed. It is not applied to glob patterns and Hugging Face paths.
 pub fn expand_paths_hive(
     paths: &[PathBuf],
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
     check_directory_level: bool,
+    listing_filter: Option<&HiveListingFilter>,
 ) -> PolarsResult<(Arc<[PathBuf]>, usize)> {
     let Some(first_path) = paths.first() else {
         return Ok((vec![].into(), 0));
     };
 
+    let listing_filter = listing_filter.filter(|filter| filter.num_levels() > 0);
+
     let is_cloud = is_cloud_url(first_path);
 
     /// Wrapper around `Vec<PathBuf>` that also tracks file extensions, so that
@@ -403,6 +450,64 @@ pub fn expand_paths_hive(
                 })
             };
 
+            // Descend the leading partition levels of the directory `path` one level at a time,
+            // only keeping the directories accepted by `filter`. Returns the index to start
+            // parsing hive partitions from and the matching directories, with a trailing slash.
+            let list_partition_dirs = |path: &str,
+                                       filter: &HiveListingFilter|
+             -> PolarsResult<(usize, Vec<String>)> {
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let (cloud_location, store) =
+                        crate::cloud::build_object_store(path, cloud_options, false).await?;
+                    let mut prefixes = vec![object_path_from_str(&cloud_location.prefix)?];
+
+                    for level in 0..filter.num_levels() {
+                        let mut next_prefixes = vec![];
+
+                        for prefix in &prefixes {
+                            let listed = store
+                                .try_exec_rebuild_on_err(|store| {
+                                    let st = store.clone();
+
+                                    async move { Ok(st.list_with_delimiter(Some(prefix)).await?) }
+                                })
+                                .await?;
+
+                            next_prefixes.extend(listed.common_prefixes.into_iter().filter(
+                                |dir| {
+                                    dir.filename()
+                                        .is_some_and(|name| filter.accepts(level, name))
+                                },
+                            ));
+                        }
+
+                        prefixes = next_prefixes;
+                    }
+
+                    let hive_start_idx = format_path(
+                        &cloud_location.scheme,
+                        &cloud_location.bucket,
+                        &cloud_location.prefix,
+                    )
+                    .len();
+                    let dirs = prefixes
+                        .iter()
+                        .map(|prefix| {
+                            format!(
+                                "{}/",
+                                format_path(
+                                    &cloud_location.scheme,
+                                    &cloud_location.bucket,
+                                    prefix.as_ref()
+                                )
+                            )
+                        })
+                        .collect();
+
+                    PolarsResult::Ok((hive_start_idx, dirs))
+                })
+            };
+
             for (path_idx, path) in paths.iter().enumerate() {
                 if path.to_str().unwrap().starts_with("http") {
                     out_paths.push(path.clone());
@@ -414,6 +519,21 @@ pub fn expand_paths_hive(
 
                 let path = if glob && glob_start_idx.is_some() {
                     path.clone()
+                } else if let Some(filter) = listing_filter.filter(|_| {
+                    // Same convention as in `expand_path_cloud`, cloud directories must end with
+                    // a trailing slash.
+                    path.to_str().unwrap().ends_with("/") || (!is_cloud && path.is_dir())
+                }) {
+                    let (hive_start_idx, partition_dirs) =
+                        list_partition_dirs(path.to_str().unwrap(), filter)?;
+
+                    for dir in partition_dirs {
+                        let (_, paths) = expand_path_cloud(&dir, cloud_options)?;
+                        out_paths.extend_from_slice(&paths);
+                    }
+
+                    hive_idx_tracker.update(hive_start_idx, path_idx)?;
+                    continue;
                 } else {
                     let (expand_start_idx, paths) =
                         expand_path_cloud(path.to_str().unwrap(), cloud_options)?;
@@ -449,9 +569,9 @@ pub fn expand_paths_hive(
 
                 hive_idx_tracker.update(i, path_idx)?;
 
-                stack.push_back(path.clone());
+                stack.push_back((path.clone(), 0));
 
-                while let Some(dir) = stack.pop_front() {
+                while let Some((dir, level)) = stack.pop_front() {
                     let mut paths = std::fs::read_dir(dir)
                         .map_err(PolarsError::from)?
                         .map(|x| x.map(|x| x.path()))
@@ -461,7 +581,14 @@ pub fn expand_paths_hive(
 
                     for path in paths {
                         if path.is_dir() {
-                            stack.push_back(path);
+                            let accepted = listing_filter.is_none_or(|filter| {
+                                path.file_name()
+                                    .and_then(|name| name.to_str())
+                                    .is_none_or(|name| filter.accepts(level, name))
+                            });
+                            if accepted {
+                                stack.push_back((path, level + 1));
+                            }
                         } else if path.metadata()?.len() > 0 {
                             out_paths.push(path);
                         }
@@ -1,3 +1,5 @@
+#[cfg(feature = "csv")]
+pub use crate::ValueFormat;
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
@@ -149,3 +149,20 @@ pub fn schema_to_arrow_checked(
         })
         .collect::<PolarsResult<ArrowSchema>>()
 }
+
+/// Like [`schema_to_arrow_checked`], but also carries the user metadata of the columns of `df`
+/// over to the Arrow fields.
+pub fn df_to_arrow_schema_checked(
+    df: &DataFrame,
+    compat_level: CompatLevel,
+    file_name: &str,
+) -> PolarsResult<ArrowSchema> {
+    let mut schema = schema_to_arrow_checked(df.schema(), compat_level, file_name)?;
+    for (column, arrow_field) in df.get_columns().iter().zip(schema.iter_values_mut()) {
+        let field = column.field();
+        if field.metadata().is_some() {
+            *arrow_field = field.to_arrow(compat_level);
+        }
+    }
+    Ok(schema)
+}
@@ -10,8 +10,11 @@ use arrow::io::iterator::StreamingIterator;
 use arrow::record_batch::RecordBatchT;
 pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
-pub(crate) use serialize::new_serializer;
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
+pub use serialize::SerializeOptions;
 use serialize::serialize;
+pub(crate) use serialize::{DEFAULT_SERIALIZE_OPTIONS, new_record_serializer, new_serializer};
 pub use utf8::serialize_to_utf8;
 
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
@@ -25,6 +28,8 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: SerializeOptions,
+    field_options: PlHashMap<PlSmallStr, SerializeOptions>,
 }
 
 impl<A, I> Serializer<A, I>
@@ -34,7 +39,29 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: SerializeOptions::default(),
+            field_options: PlHashMap::default(),
+        }
+    }
+
+    /// Set the options used to write the values.
+    pub fn with_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the options of individual fields of the serialized struct arrays, by field name.
+    ///
+    /// These take precedence over the options set with [`Serializer::with_options`].
+    pub fn with_field_options(
+        mut self,
+        field_options: PlHashMap<PlSmallStr, SerializeOptions>,
+    ) -> Self {
+        self.field_options = field_options;
+        self
     }
 }
 
@@ -51,7 +78,16 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| {
+                    serialize(
+                        array.as_ref(),
+                        &mut self.buffer,
+                        &self.options,
+                        &self.field_options,
+                    )
+                })
+            })
             .transpose()?;
         Ok(())
     }
@@ -88,7 +124,7 @@ impl<'a> RecordSerializer<'a> {
         let iterators = chunk
             .arrays()
             .iter()
-            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX))
+            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX, &DEFAULT_SERIALIZE_OPTIONS))
             .collect();
 
         Self {
@@ -1,3 +1,4 @@
+use std::fmt::{Display, LowerExp};
 use std::io::Write;
 
 use arrow::array::*;
@@ -15,7 +16,11 @@ use arrow::temporal_conversions::{
     timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
+use chrono::format::{Item, StrftimeItems};
 use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use polars_error::{PolarsResult, polars_err};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
 use streaming_iterator::StreamingIterator;
 
 use super::utf8;
@@ -32,6 +37,81 @@ fn write_float<I: ryu::Float>(f: &mut Vec<u8>, val: I) {
     f.extend_from_slice(value.as_bytes())
 }
 
+/// Options that control how values are written to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerializeOptions {
+    /// Number of decimals floats are written with.
+    pub float_precision: Option<usize>,
+    /// Write floats in scientific (`true`) or positional (`false`) notation. By default the
+    /// shortest representation that parses back to the same float is written.
+    pub float_scientific: Option<bool>,
+    /// A `chrono` format string used for dates, times and datetimes.
+    pub temporal_format: Option<String>,
+}
+
+impl SerializeOptions {
+    /// Check that the temporal format is a valid `chrono` format string.
+    pub fn validate(&self) -> PolarsResult<()> {
+        if let Some(format) = &self.temporal_format {
+            StrftimeItems::new(format).parse().map_err(
+                |_| polars_err!(ComputeError: "invalid temporal format '{format}' for JSON"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) static DEFAULT_SERIALIZE_OPTIONS: SerializeOptions = SerializeOptions {
+    float_precision: None,
+    float_scientific: None,
+    temporal_format: None,
+};
+
+/// Writes a finite float. The output always has a fractional part or an exponent, so that whole
+/// values such as `1.0` are read back as floats rather than integers.
+fn write_formatted_float<I: ryu::Float + LowerExp + Display>(
+    buf: &mut Vec<u8>,
+    val: I,
+    precision: Option<usize>,
+    scientific: Option<bool>,
+) {
+    let start = buf.len();
+    match (precision, scientific) {
+        (None, None) => write_float(buf, val),
+        (Some(precision), Some(true)) => write!(buf, "{val:.precision$e}").unwrap(),
+        (Some(precision), _) => write!(buf, "{val:.precision$}").unwrap(),
+        (None, Some(true)) => write!(buf, "{val:e}").unwrap(),
+        (None, Some(false)) => write!(buf, "{val}").unwrap(),
+    }
+    if !buf[start..].iter().any(|b| matches!(b, b'.' | b'e' | b'E')) {
+        buf.extend_from_slice(b".0")
+    }
+}
+
+/// Parses the temporal format of `options`, if any.
+///
+/// An invalid format makes every value fail to format, which is written as `null`. Use
+/// [`SerializeOptions::validate`] to catch this before writing.
+fn temporal_format_items(options: &SerializeOptions) -> Option<Vec<Item<'_>>> {
+    options.temporal_format.as_deref().map(|format| {
+        StrftimeItems::new(format)
+            .parse()
+            .unwrap_or_else(|_| vec![Item::Error])
+    })
+}
+
+/// Writes `value` as a JSON string, using `scratch` to format it.
+fn write_display_str(buf: &mut Vec<u8>, scratch: &mut String, value: impl Display) {
+    use std::fmt::Write;
+
+    scratch.clear();
+    if write!(scratch, "{value}").is_ok() {
+        utf8::write_str(buf, scratch).unwrap();
+    } else {
+        buf.extend_from_slice(b"null")
+    }
+}
+
 fn materialize_serializer<'a, I, F, T>(
     f: F,
     iterator: I,
@@ -95,16 +175,19 @@ fn float_serializer<'a, T>(
     array: &'a PrimitiveArray<T>,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
-    T: num_traits::Float + NativeType + ryu::Float,
+    T: num_traits::Float + NativeType + ryu::Float + LowerExp + Display,
 {
-    let f = |x: Option<&T>, buf: &mut Vec<u8>| {
+    let precision = options.float_precision;
+    let scientific = options.float_scientific;
+    let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             if T::is_nan(*x) || T::is_infinite(*x) {
                 buf.extend(b"null")
             } else {
-                write_float(buf, *x)
+                write_formatted_float(buf, *x, precision, scientific)
             }
         } else {
             buf.extend(b"null")
@@ -184,6 +267,8 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
+    field_options: Option<&'a PlHashMap<PlSmallStr, SerializeOptions>>,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -195,8 +280,13 @@ fn struct_serializer<'a>(
     let mut serializers = array
         .values()
         .iter()
-        .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .zip(array.fields())
+        .map(|(arr, field)| {
+            let options = field_options
+                .and_then(|field_options| field_options.get(&field.name))
+                .unwrap_or(options);
+            new_serializer(arr.as_ref(), offset, take, options)
+        })
         .collect::<Vec<_>>();
 
     Box::new(BufStreamingIterator::new(
@@ -228,6 +318,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -238,7 +329,7 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer = new_serializer(array.values().as_ref(), start, end - start, options);
 
     let mut prev_offset = start;
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
@@ -275,8 +366,9 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
+    let mut serializer = new_serializer(array.values().as_ref(), offset, take, options);
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
@@ -306,15 +398,23 @@ fn date_serializer<'a, T, F>(
     convert: F,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
     T: NativeType,
     F: Fn(T) -> NaiveDate + 'static + Send + Sync,
 {
+    let items = temporal_format_items(options);
+    let mut scratch = String::new();
     let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let nd = convert(*x);
-            write!(buf, "\"{nd}\"").unwrap();
+            match &items {
+                Some(items) => {
+                    write_display_str(buf, &mut scratch, nd.format_with_items(items.iter()))
+                },
+                None => write!(buf, "\"{nd}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -350,15 +450,23 @@ fn time_serializer<'a, T, F>(
     convert: F,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
     T: NativeType,
     F: Fn(T) -> NaiveTime + 'static + Send + Sync,
 {
+    let items = temporal_format_items(options);
+    let mut scratch = String::new();
     let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let time = convert(*x);
-            write!(buf, "\"{time}\"").unwrap();
+            match &items {
+                Some(items) => {
+                    write_display_str(buf, &mut scratch, time.format_with_items(items.iter()))
+                },
+                None => write!(buf, "\"{time}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -372,14 +480,22 @@ fn timestamp_serializer<'a, F>(
     convert: F,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
     F: Fn(i64) -> NaiveDateTime + 'static + Send + Sync,
 {
+    let items = temporal_format_items(options);
+    let mut scratch = String::new();
     let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let ndt = convert(*x);
-            write!(buf, "\"{ndt}\"").unwrap();
+            match &items {
+                Some(items) => {
+                    write_display_str(buf, &mut scratch, ndt.format_with_items(items.iter()))
+                },
+                None => write!(buf, "\"{ndt}\"").unwrap(),
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -393,13 +509,21 @@ fn timestamp_tz_serializer<'a>(
     tz: &str,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let items = temporal_format_items(options);
+    let mut scratch = String::new();
     match parse_offset(tz) {
         Ok(parsed_tz) => {
             let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                 if let Some(x) = x {
-                    let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                    write!(buf, "\"{dt_str}\"").unwrap();
+                    let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                    match &items {
+                        Some(items) => {
+                            write_display_str(buf, &mut scratch, dt.format_with_items(items.iter()))
+                        },
+                        None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                    }
                 } else {
                     buf.extend_from_slice(b"null")
                 }
@@ -412,8 +536,15 @@ fn timestamp_tz_serializer<'a>(
             Ok(parsed_tz) => {
                 let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
                     if let Some(x) = x {
-                        let dt_str = timestamp_to_datetime(*x, time_unit, &parsed_tz).to_rfc3339();
-                        write!(buf, "\"{dt_str}\"").unwrap();
+                        let dt = timestamp_to_datetime(*x, time_unit, &parsed_tz);
+                        match &items {
+                            Some(items) => write_display_str(
+                                buf,
+                                &mut scratch,
+                                dt.format_with_items(items.iter()),
+                            ),
+                            None => write!(buf, "\"{}\"", dt.to_rfc3339()).unwrap(),
+                        }
                     } else {
                         buf.extend_from_slice(b"null")
                     }
@@ -436,6 +567,7 @@ pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.dtype().to_logical_type() {
         ArrowDataType::Boolean => {
@@ -465,12 +597,18 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::UInt64 => {
             primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Float32 => {
-            float_serializer::<f32>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::Float64 => {
-            float_serializer::<f64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Float32 => float_serializer::<f32>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::Float64 => float_serializer::<f64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         #[cfg(feature = "dtype-decimal")]
         ArrowDataType::Decimal(_, scale) => {
             decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale, offset, take)
@@ -481,15 +619,25 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+            None,
+        ),
+        ArrowDataType::FixedSizeList(_, _) => fixed_size_list_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::LargeList(_) => list_serializer::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt32, ArrowDataType::Utf8View) => {
                 let array = array
@@ -508,6 +656,7 @@ pub(crate) fn new_serializer<'a>(
             date32_to_date,
             offset,
             take,
+            options,
         ),
         ArrowDataType::Timestamp(tu, None) => {
             let convert = match tu {
@@ -521,6 +670,7 @@ pub(crate) fn new_serializer<'a>(
                 convert,
                 offset,
                 take,
+                options,
             )
         },
         ArrowDataType::Timestamp(time_unit, Some(tz)) => timestamp_tz_serializer(
@@ -529,6 +679,7 @@ pub(crate) fn new_serializer<'a>(
             tz,
             offset,
             take,
+            options,
         ),
         ArrowDataType::Duration(tu) => {
             let convert = match tu {
@@ -554,6 +705,7 @@ pub(crate) fn new_serializer<'a>(
                 convert,
                 offset,
                 take,
+                options,
             )
         },
         ArrowDataType::Null => null_serializer(array.len(), offset, take),
@@ -561,6 +713,21 @@ pub(crate) fn new_serializer<'a>(
     }
 }
 
+/// Like [`new_serializer`], but if `array` is a struct array its fields can be given their own
+/// options, by field name.
+pub(crate) fn new_record_serializer<'a>(
+    array: &'a dyn Array,
+    options: &'a SerializeOptions,
+    field_options: &'a PlHashMap<PlSmallStr, SerializeOptions>,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    match array.as_any().downcast_ref::<StructArray>() {
+        Some(array) if !field_options.is_empty() => {
+            struct_serializer(array, 0, usize::MAX, options, Some(field_options))
+        },
+        _ => new_serializer(array, 0, usize::MAX, options),
+    }
+}
+
 fn serialize_item<'a>(
     buffer: &mut Vec<u8>,
     record: impl Iterator<Item = (&'a str, &'a [u8])>,
@@ -586,8 +753,13 @@ fn serialize_item<'a>(
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
-pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+pub(crate) fn serialize(
+    array: &dyn Array,
+    buffer: &mut Vec<u8>,
+    options: &SerializeOptions,
+    field_options: &PlHashMap<PlSmallStr, SerializeOptions>,
+) {
+    let mut serializer = new_record_serializer(array, options, field_options);
 
     (0..array.len()).for_each(|i| {
         if i != 0 {
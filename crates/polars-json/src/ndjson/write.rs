@@ -4,11 +4,18 @@ use std::io::Write;
 use arrow::array::Array;
 pub use fallible_streaming_iterator::FallibleStreamingIterator;
 use polars_error::{PolarsError, PolarsResult};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
 
-use super::super::json::write::new_serializer;
+use super::super::json::write::{SerializeOptions, new_record_serializer};
 
-fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+fn serialize(
+    array: &dyn Array,
+    buffer: &mut Vec<u8>,
+    options: &SerializeOptions,
+    field_options: &PlHashMap<PlSmallStr, SerializeOptions>,
+) {
+    let mut serializer = new_record_serializer(array, options, field_options);
     (0..array.len()).for_each(|_| {
         buffer.extend_from_slice(serializer.next().unwrap());
         buffer.push(b'\n');
@@ -27,6 +34,8 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: SerializeOptions,
+    field_options: PlHashMap<PlSmallStr, SerializeOptions>,
 }
 
 impl<A, I> Serializer<A, I>
@@ -36,7 +45,29 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: SerializeOptions::default(),
+            field_options: PlHashMap::default(),
+        }
+    }
+
+    /// Set the options used to write the values.
+    pub fn with_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the options of individual fields of the serialized struct arrays, by field name.
+    ///
+    /// These take precedence over the options set with [`Serializer::with_options`].
+    pub fn with_field_options(
+        mut self,
+        field_options: PlHashMap<PlSmallStr, SerializeOptions>,
+    ) -> Self {
+        self.field_options = field_options;
+        self
     }
 }
 
@@ -53,7 +84,16 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| {
+                    serialize(
+                        array.as_ref(),
+                        &mut self.buffer,
+                        &self.options,
+                        &self.field_options,
+                    )
+                })
+            })
             .transpose()?;
         Ok(())
     }
@@ -16,13 +16,21 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
 ) -> PolarsResult<LazyFrame> {
     let mut inputs = inputs.as_ref().to_vec();
 
+    // The source index column is added during conversion to IR, where the input schemas are known.
+    polars_ensure!(
+        !(args.include_source_index && args.diagonal),
+        InvalidOperation: "'include_source_index' is not supported for diagonal concatenation"
+    );
+
+    let compute_pool = LazyFrame::compute_pool_of(&inputs);
     let lf = std::mem::take(
         inputs
             .get_mut(0)
             .ok_or_else(|| polars_err!(NoData: "empty container given"))?,
     );
 
-    let opt_state = lf.opt_state;
+    let mut opt_state = lf.opt_state;
+    opt_state.compute_pool = compute_pool;
     let cached_arenas = lf.cached_arena.clone();
 
     let mut lps = Vec::with_capacity(inputs.len());
@@ -53,10 +61,14 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     inputs: L,
     args: UnionArgs,
 ) -> PolarsResult<LazyFrame> {
+    polars_ensure!(
+        !args.include_source_index,
+        InvalidOperation: "'include_source_index' is not supported for horizontal concatenation"
+    );
     let lfs = inputs.as_ref();
-    let (opt_state, cached_arena) = lfs
+    let (mut opt_state, cached_arena) = lfs
         .first()
-        .map(|lf| (lf.opt_state, lf.cached_arena.clone()))
+        .map(|lf| (lf.opt_state.clone(), lf.cached_arena.clone()))
         .ok_or_else(
             || polars_err!(NoData: "Require at least one LazyFrame for horizontal concatenation"),
         )?;
@@ -68,6 +80,7 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
         inputs: lfs.iter().map(|lf| lf.logical_plan.clone()).collect(),
         options,
     };
+    opt_state.compute_pool = LazyFrame::compute_pool_of(lfs);
     Ok(LazyFrame::from_inner(lp, opt_state, cached_arena))
 }
 
@@ -132,4 +145,55 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat_lf_source_index() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1, 2],
+        ]?;
+        let b = df![
+            "a" => [3],
+        ]?;
+
+        let args = UnionArgs {
+            include_source_index: true,
+            ..Default::default()
+        };
+        let out = concat(&[a.clone().lazy(), b.clone().lazy()], args)?.collect()?;
+
+        let expected = df![
+            "a" => [1, 2, 3],
+            "source_index" => [0u32, 0, 1],
+        ]?;
+        assert!(out.equals(&expected));
+
+        // An existing column is not overwritten.
+        let existing = df![
+            "source_index" => [7u32],
+        ]?;
+        let err = concat(&[existing.clone().lazy(), existing.lazy()], args)?
+            .collect()
+            .unwrap_err();
+        assert!(matches!(err.remove_context(), PolarsError::Duplicate(_)));
+
+        // Without maintaining order the inputs may be interleaved.
+        #[cfg(feature = "new_streaming")]
+        {
+            let unordered = UnionArgs {
+                maintain_order: false,
+                ..args
+            };
+            let out = concat(&[a.clone().lazy(), b.clone().lazy()], unordered)?
+                .with_new_streaming(true)
+                .sort(["a"], Default::default())
+                .collect()?;
+            assert!(out.equals(&expected));
+        }
+
+        assert!(concat_lf_horizontal(&[a.clone().lazy(), b.clone().lazy()], args).is_err());
+        #[cfg(feature = "diagonal_concat")]
+        assert!(concat_lf_diagonal(&[a.lazy(), b.lazy()], args).is_err());
+
+        Ok(())
+    }
 }
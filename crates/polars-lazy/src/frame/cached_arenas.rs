@@ -39,9 +39,33 @@ impl LazyFrame {
     /// Get a handle to the schema — a map from column names to data types — of the current
     /// `LazyFrame` computation.
     ///
+    /// The schema also holds the user metadata, such as units or descriptions, that is known at
+    /// plan time. See [`field_metadata`] for which operations keep the metadata of a column.
+    ///
     /// Returns an `Err` if the logical plan has already encountered an error (i.e., if
     /// `self.collect()` would fail), `Ok` otherwise.
     pub fn collect_schema(&mut self) -> PolarsResult<SchemaRef> {
+        let mut schema = self.collect_plan_schema()?;
+        let DslPlan::IR {
+            node: Some(node), ..
+        } = self.logical_plan
+        else {
+            unreachable!("collect_plan_schema caches the converted plan")
+        };
+        let cached_arenas = self.cached_arena.lock().unwrap();
+        let arenas = cached_arenas.as_ref().unwrap();
+        let metadata = field_metadata(node, &arenas.lp_arena, &arenas.expr_arena);
+
+        if !metadata.is_empty() {
+            let schema = Arc::make_mut(&mut schema);
+            for (name, md) in metadata {
+                schema.set_field_metadata(&name, Some(md));
+            }
+        }
+        Ok(schema)
+    }
+
+    fn collect_plan_schema(&mut self) -> PolarsResult<SchemaRef> {
         let mut cached_arenas = self.cached_arena.lock().unwrap();
 
         match &mut *cached_arenas {
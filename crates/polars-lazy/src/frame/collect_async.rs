@@ -0,0 +1,123 @@
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::mpsc::RecvTimeoutError;
+use std::task::{Context, Poll, Waker};
+
+use polars_utils::cancel::CancelToken;
+
+use super::*;
+
+impl LazyFrame {
+    /// Execute the query in the background and return a future that resolves to the result,
+    /// without blocking the calling thread. The work of the query runs on the polars thread pool.
+    ///
+    /// Dropping the future cancels the query: the streaming engine stops at the next morsel
+    /// boundary and the in-memory engine before the next node of the plan.
+    pub fn collect_async(self) -> CollectFuture {
+        self.collect_async_with_engine(Engine::InMemory)
+    }
+
+    /// Like [`LazyFrame::collect_async`], using a specified `engine`.
+    pub fn collect_async_with_engine(self, engine: Engine) -> CollectFuture {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+        let token = CancelToken::new();
+
+        let query_shared = shared.clone();
+        let query_token = token.clone();
+        spawn_query(move || {
+            let result = self.collect_with_engine_cancellable(engine, query_token);
+
+            let mut shared = query_shared.lock().unwrap();
+            shared.result = Some(result);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        });
+
+        CollectFuture { shared, token }
+    }
+}
+
+/// Run a query that blocks until it is done.
+///
+/// The streaming engine must not block a thread of the pool it executes on, so it runs on the
+/// blocking threads of the async runtime instead. These are reused between queries.
+fn spawn_query(f: impl FnOnce() + Send + 'static) {
+    #[cfg(feature = "new_streaming")]
+    {
+        _ = polars_io::pl_async::get_runtime().spawn_blocking(f);
+    }
+    #[cfg(not(feature = "new_streaming"))]
+    POOL.spawn_fifo(f);
+}
+
+/// Run the query `f` until the deadline of `cancel_token`.
+///
+/// Once the deadline passes, the token is cancelled and this returns a "timeout" error right
+/// away, without waiting for an operator of the query that is still running.
+pub(super) fn run_until_deadline<T: Send + 'static>(
+    cancel_token: CancelToken,
+    f: impl FnOnce() -> PolarsResult<T> + Send + 'static,
+) -> PolarsResult<T> {
+    let Some(deadline) = cancel_token.deadline() else {
+        return f();
+    };
+
+    let (tx, rx) = std::sync::mpsc::sync_channel(1);
+    spawn_query(move || {
+        _ = tx.send(std::panic::catch_unwind(AssertUnwindSafe(f)));
+    });
+
+    match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        Ok(Ok(result)) => result,
+        Ok(Err(panic)) => std::panic::resume_unwind(panic),
+        Err(RecvTimeoutError::Timeout) => {
+            cancel_token.cancel();
+            polars_bail!(Timeout: "query did not finish before its deadline")
+        },
+        Err(RecvTimeoutError::Disconnected) => unreachable!(),
+    }
+}
+
+struct Shared {
+    result: Option<PolarsResult<DataFrame>>,
+    waker: Option<Waker>,
+}
+
+/// Future resolving to the result of a query, see [`LazyFrame::collect_async`].
+pub struct CollectFuture {
+    shared: Arc<Mutex<Shared>>,
+    token: CancelToken,
+}
+
+impl CollectFuture {
+    /// Cancel the query at earliest convenience, the future then resolves to an error.
+    pub fn cancel(&self) {
+        self.token.cancel()
+    }
+}
+
+impl Future for CollectFuture {
+    type Output = PolarsResult<DataFrame>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+impl Drop for CollectFuture {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
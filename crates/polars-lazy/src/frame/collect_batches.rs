@@ -0,0 +1,75 @@
+use std::sync::mpsc::{Receiver, sync_channel};
+
+use polars_utils::cancel::CancelToken;
+
+use super::*;
+
+impl LazyFrame {
+    /// Run the query on the streaming engine and iterate over the result in
+    /// [`DataFrame`]s of `batch_rows` rows. The last batch may be smaller.
+    ///
+    /// The query runs at most a single batch ahead of the consumer. Dropping the
+    /// iterator cancels the query without waiting for it to finish, the query thread
+    /// exits on its own once it observes the cancellation.
+    pub fn collect_batches(self, batch_rows: usize) -> PolarsResult<CollectBatches> {
+        self.collect_batches_impl(true, batch_rows)
+    }
+
+    /// Like [`LazyFrame::collect_batches`], but the rows may be returned in any order, which
+    /// lets the engine emit batches as soon as they are ready.
+    pub fn collect_batches_unordered(self, batch_rows: usize) -> PolarsResult<CollectBatches> {
+        self.collect_batches_impl(false, batch_rows)
+    }
+
+    fn collect_batches_impl(
+        self,
+        maintain_order: bool,
+        batch_rows: usize,
+    ) -> PolarsResult<CollectBatches> {
+        let chunk_size = NonZeroUsize::new(batch_rows);
+        polars_ensure!(
+            chunk_size.is_some(),
+            InvalidOperation: "'batch_rows' must be greater than zero"
+        );
+
+        let (tx, rx) = sync_channel::<PolarsResult<DataFrame>>(1);
+        let batch_tx = tx.clone();
+        let lf = self.sink_batches(
+            Arc::new(move |df| Ok(batch_tx.send(Ok(df)).is_err())),
+            maintain_order,
+            chunk_size,
+        )?;
+
+        let token = CancelToken::new();
+        let query_token = token.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = lf.collect_with_engine_cancellable(Engine::Streaming, query_token) {
+                _ = tx.send(Err(err));
+            }
+        });
+
+        Ok(CollectBatches { rx, token })
+    }
+}
+
+/// Iterator over the batches of a query, see [`LazyFrame::collect_batches`].
+pub struct CollectBatches {
+    rx: Receiver<PolarsResult<DataFrame>>,
+    token: CancelToken,
+}
+
+impl Iterator for CollectBatches {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rx.recv().ok()
+    }
+}
+
+impl Drop for CollectBatches {
+    fn drop(&mut self) {
+        // Don't join the query thread, it stops at the next morsel boundary. The
+        // channel is closed as well, so a sink blocked on sending a batch stops too.
+        self.token.cancel();
+    }
+}
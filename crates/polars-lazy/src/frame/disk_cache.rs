@@ -0,0 +1,342 @@
+use std::any::Any;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fs4::fs_std::FileExt;
+use polars_io::is_cloud_url;
+use polars_io::prelude::{ParquetReader, ParquetWriter, SerReader};
+use polars_plan::plans::ArenaLpIter;
+
+use super::*;
+
+const MANIFEST: &str = "manifest";
+const MANIFEST_LOCK: &str = "manifest.lock";
+
+/// Options for [`LazyFrame::cache_to_disk_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct DiskCacheOptions {
+    /// Name of the cache entry. Defaults to a hash of the plan.
+    pub key: Option<String>,
+    /// Evict the least recently used entries once the cached files take more than this many
+    /// bytes.
+    pub max_size: Option<u64>,
+}
+
+impl LazyFrame {
+    /// Cache the result of this query as a Parquet file in the directory `dir`.
+    ///
+    /// See [`LazyFrame::cache_to_disk_with_options`].
+    pub fn cache_to_disk(self, dir: impl Into<PathBuf>, key: Option<String>) -> PolarsResult<Self> {
+        self.cache_to_disk_with_options(
+            dir,
+            DiskCacheOptions {
+                key,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Cache the result of this query as a Parquet file in the directory `dir`.
+    ///
+    /// The cache is checked when the returned [`LazyFrame`] is executed. The cached file is only
+    /// used if neither the plan nor any of the files it scans (by modification time and size)
+    /// changed since it was written, otherwise the query is executed and the cache is
+    /// overwritten. In-memory data in the plan, such as DataFrames and literal Series, is part of
+    /// the plan. Plans that can't be serialized, e.g. because they contain Rust closures, can't be
+    /// cached.
+    ///
+    /// The entries of a directory are tracked in a manifest which is locked while it is read or
+    /// written, so multiple processes can share a cache directory.
+    pub fn cache_to_disk_with_options(
+        mut self,
+        dir: impl Into<PathBuf>,
+        options: DiskCacheOptions,
+    ) -> PolarsResult<Self> {
+        if let Some(key) = &options.key {
+            polars_ensure!(
+                !key.is_empty() && !key.contains(['\t', '\n']),
+                InvalidOperation: "invalid cache key {:?}", key
+            );
+        }
+        let schema = self.collect_schema()?;
+        let scan = DiskCacheScan {
+            lf: self,
+            dir: dir.into(),
+            options,
+        };
+
+        Self::anonymous_scan(
+            Arc::new(scan),
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: "DISK CACHE",
+                ..Default::default()
+            },
+        )
+    }
+}
+
+struct DiskCacheScan {
+    lf: LazyFrame,
+    dir: PathBuf,
+    options: DiskCacheOptions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ManifestEntry {
+    key: String,
+    fingerprint: String,
+    size: u64,
+    last_used: u64,
+}
+
+impl ManifestEntry {
+    fn file_name(&self) -> String {
+        format!("{}.parquet", hash_hex(self.key.as_bytes()))
+    }
+}
+
+/// Renumber the `Cache` nodes of `plan` in the order they are visited.
+///
+/// Cache ids are unique per `LazyFrame::cache` call, so they would otherwise make every
+/// construction of the same query hash differently.
+fn normalized_cache_ids(plan: &DslPlan) -> DslPlan {
+    let mut plan = plan.clone();
+    let mut ids = PlHashMap::new();
+    let mut stack = vec![&mut plan];
+    while let Some(node) = stack.pop() {
+        if let DslPlan::Cache { id, .. } = node {
+            let next_id = ids.len();
+            *id = *ids.entry(*id).or_insert(next_id);
+        }
+        node.inputs_mut(&mut stack);
+    }
+    plan
+}
+
+fn hash_hex(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex()[..32].to_string()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+fn modified_ms(metadata: &std::fs::Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+fn read_manifest(path: &Path) -> PolarsResult<Vec<ManifestEntry>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut entries = vec![];
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let mut fields = line.split('\t');
+        let (Some(key), Some(fingerprint), Some(size), Some(last_used), None) = (
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+        ) else {
+            polars_bail!(ComputeError: "corrupt disk cache manifest at {}", path.display());
+        };
+        let (Ok(size), Ok(last_used)) = (size.parse(), last_used.parse()) else {
+            polars_bail!(ComputeError: "corrupt disk cache manifest at {}", path.display());
+        };
+        entries.push(ManifestEntry {
+            key: key.to_string(),
+            fingerprint: fingerprint.to_string(),
+            size,
+            last_used,
+        });
+    }
+    Ok(entries)
+}
+
+fn write_manifest(path: &Path, entries: &[ManifestEntry]) -> PolarsResult<()> {
+    // Write to a temporary file first so that a crash never leaves a partial manifest behind.
+    let tmp_path = path.with_extension("tmp");
+    let mut file = File::create(&tmp_path)?;
+    for e in entries {
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            e.key, e.fingerprint, e.size, e.last_used
+        )?;
+    }
+    file.sync_all()?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+/// Exclusive lock on the manifest of a cache directory, released on drop.
+struct ManifestLock(File);
+
+impl ManifestLock {
+    fn acquire(dir: &Path) -> PolarsResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(dir.join(MANIFEST_LOCK))?;
+        file.lock_exclusive()?;
+        Ok(Self(file))
+    }
+}
+
+impl Drop for ManifestLock {
+    fn drop(&mut self) {
+        _ = FileExt::unlock(&self.0);
+    }
+}
+
+impl DiskCacheScan {
+    /// Returns the key of the entry and the fingerprint of the plan and its sources.
+    fn key_and_fingerprint(&self) -> PolarsResult<(String, String)> {
+        // The serialized plan includes in-memory data such as DataFrames and literal Series, so
+        // plans that only differ in that data get different keys.
+        let mut serialized_plan = vec![];
+        normalized_cache_ids(&self.lf.logical_plan)
+            .serialize_versioned(&mut serialized_plan)
+            .map_err(|e| {
+                polars_err!(
+                    InvalidOperation: "'cache_to_disk' requires a serializable plan: {}", e
+                )
+            })?;
+
+        let plan = self.lf.clone().to_alp()?;
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&serialized_plan);
+        for (_, lp) in (&plan.lp_arena).iter(plan.lp_top) {
+            let IR::Scan { sources, .. } = lp else {
+                continue;
+            };
+            for source in sources.iter() {
+                match source {
+                    ScanSourceRef::Path(path) => {
+                        polars_ensure!(
+                            !is_cloud_url(path),
+                            InvalidOperation: "'cache_to_disk' does not support cloud sources, got {}",
+                            path.display()
+                        );
+                        let metadata = std::fs::metadata(path)?;
+                        hasher.update(path.as_os_str().as_encoded_bytes());
+                        hasher.update(&modified_ms(&metadata).to_le_bytes());
+                        hasher.update(&metadata.len().to_le_bytes());
+                    },
+                    ScanSourceRef::File(file) => {
+                        let metadata = file.metadata()?;
+                        hasher.update(&modified_ms(&metadata).to_le_bytes());
+                        hasher.update(&metadata.len().to_le_bytes());
+                    },
+                    ScanSourceRef::Buffer(buffer) => {
+                        hasher.update(buffer);
+                    },
+                }
+            }
+        }
+        let fingerprint = hasher.finalize().to_hex()[..32].to_string();
+
+        let key = match &self.options.key {
+            Some(key) => key.clone(),
+            None => hash_hex(&serialized_plan),
+        };
+        Ok((key, fingerprint))
+    }
+
+    fn try_load(&self, key: &str, fingerprint: &str) -> PolarsResult<Option<DataFrame>> {
+        let _lock = ManifestLock::acquire(&self.dir)?;
+        let manifest_path = self.dir.join(MANIFEST);
+        let mut entries = read_manifest(&manifest_path)?;
+
+        let Some(entry) = entries
+            .iter_mut()
+            .find(|e| e.key == key && e.fingerprint == fingerprint)
+        else {
+            return Ok(None);
+        };
+        let Ok(file) = File::open(self.dir.join(entry.file_name())) else {
+            return Ok(None);
+        };
+        let df = ParquetReader::new(file).finish()?;
+
+        entry.last_used = now_ms();
+        write_manifest(&manifest_path, &entries)?;
+        Ok(Some(df))
+    }
+
+    fn store(&self, key: String, fingerprint: String, df: &mut DataFrame) -> PolarsResult<()> {
+        let entry = ManifestEntry {
+            key,
+            fingerprint,
+            size: 0,
+            last_used: now_ms(),
+        };
+        let file_name = entry.file_name();
+
+        // Write outside of the lock, other processes only see the file after the rename.
+        let tmp_path = self
+            .dir
+            .join(format!("{file_name}.{}.tmp", std::process::id()));
+        let size = ParquetWriter::new(File::create(&tmp_path)?).finish(df)?;
+
+        let _lock = ManifestLock::acquire(&self.dir)?;
+        let manifest_path = self.dir.join(MANIFEST);
+        let mut entries = read_manifest(&manifest_path)?;
+        std::fs::rename(&tmp_path, self.dir.join(&file_name))?;
+
+        entries.retain(|e| e.key != entry.key);
+        let key = entry.key.clone();
+        entries.push(ManifestEntry { size, ..entry });
+
+        if let Some(max_size) = self.options.max_size {
+            // Evict the least recently used entries first, but never the one we just wrote.
+            entries.sort_by_key(|e| e.last_used);
+            let mut total: u64 = entries.iter().map(|e| e.size).sum();
+            entries.retain(|e| {
+                if total <= max_size || e.key == key {
+                    return true;
+                }
+                total -= e.size;
+                _ = std::fs::remove_file(self.dir.join(e.file_name()));
+                false
+            });
+        }
+
+        write_manifest(&manifest_path, &entries)
+    }
+}
+
+impl AnonymousScan for DiskCacheScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        std::fs::create_dir_all(&self.dir)?;
+        let (key, fingerprint) = self.key_and_fingerprint()?;
+
+        if let Some(df) = self.try_load(&key, &fingerprint)? {
+            return Ok(df);
+        }
+
+        let mut df = self.lf.clone().collect()?;
+        self.store(key, fingerprint, &mut df)?;
+        Ok(df)
+    }
+}
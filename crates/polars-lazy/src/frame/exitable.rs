@@ -1,25 +1,27 @@
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{Receiver, channel};
 
 use polars_core::POOL;
+use polars_utils::cancel::CancelToken;
 
 use super::*;
 
 impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
-
-        let (tx, rx) = channel();
-        let token = state.cancel_token();
-        POOL.spawn_fifo(move || {
-            let result = physical_plan.execute(&mut state);
-            tx.send(result).unwrap();
-        });
-
-        Ok(InProcessQuery {
-            rx: Arc::new(Mutex::new(rx)),
-            token,
+        self.enter_compute_pool(|lf| {
+            let (mut state, mut physical_plan, _) = lf.prepare_collect(false, None)?;
+
+            let (tx, rx) = channel();
+            let token = state.cancel_token();
+            POOL.spawn_fifo(move || {
+                let result = physical_plan.execute(&mut state);
+                tx.send(result).unwrap();
+            });
+
+            Ok(InProcessQuery {
+                rx: Arc::new(Mutex::new(rx)),
+                token,
+            })
         })
     }
 }
@@ -27,13 +29,13 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
-    token: Arc<AtomicBool>,
+    token: CancelToken,
 }
 
 impl InProcessQuery {
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
-        self.token.store(true, Ordering::Relaxed)
+        self.token.cancel()
     }
 
     /// Fetch the result.
@@ -54,6 +56,6 @@ impl InProcessQuery {
 
 impl Drop for InProcessQuery {
     fn drop(&mut self) {
-        self.token.store(true, Ordering::Relaxed);
+        self.token.cancel();
     }
 }
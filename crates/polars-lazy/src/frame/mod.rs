@@ -3,18 +3,34 @@
 mod python;
 
 mod cached_arenas;
+#[cfg(not(target_arch = "wasm32"))]
+mod collect_async;
+#[cfg(not(target_arch = "wasm32"))]
+mod collect_batches;
+#[cfg(feature = "disk_cache")]
+mod disk_cache;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 pub use anonymous_scan::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use collect_async::CollectFuture;
+#[cfg(not(target_arch = "wasm32"))]
+use collect_async::run_until_deadline;
+#[cfg(not(target_arch = "wasm32"))]
+pub use collect_batches::CollectBatches;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "disk_cache")]
+pub use disk_cache::DiskCacheOptions;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
@@ -25,19 +41,22 @@ pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
-use polars_core::POOL;
 #[cfg(all(feature = "new_streaming", feature = "dtype-categorical"))]
 use polars_core::StringCacheHolder;
 use polars_core::error::feature_gated;
 use polars_core::prelude::*;
+use polars_core::{ComputePool, POOL};
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::RowIndex;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
-use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_ops::frame::{ColumnFillNullStrategy, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
+#[cfg(feature = "cross_join")]
+use polars_plan::constants::POLARS_EXTERNAL_PREFIX;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
+use polars_utils::cancel::CancelToken;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
@@ -46,6 +65,26 @@ use crate::frame::cached_arenas::CachedArena;
 use crate::physical_plan::streaming::insert_streaming_nodes;
 use crate::prelude::*;
 
+/// Without threads to run the query on, the deadline is only checked by the query itself.
+#[cfg(target_arch = "wasm32")]
+fn run_until_deadline<T>(
+    _cancel_token: CancelToken,
+    f: impl FnOnce() -> PolarsResult<T>,
+) -> PolarsResult<T> {
+    f()
+}
+
+/// The keys of a rolling or dynamic group-by, with multiple keys combined into a single struct
+/// column if `keys_as_struct` is set.
+#[cfg(feature = "dynamic_group_by")]
+fn dynamic_group_keys(keys: &[Expr], keys_as_struct: bool) -> Vec<Expr> {
+    if keys_as_struct && keys.len() > 1 {
+        vec![as_struct(keys.to_vec())]
+    } else {
+        keys.to_vec()
+    }
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -68,6 +107,19 @@ impl IntoLazy for LazyFrame {
     }
 }
 
+/// The optimizations a query runs with and the settings of the engine that runs it.
+///
+/// Builder methods pass this on to the [`LazyFrame`] they return, so it applies to whichever
+/// frame ends up being collected.
+#[derive(Clone, Default)]
+pub(crate) struct OptState {
+    pub(crate) flags: OptFlags,
+    /// See [`LazyFrame::with_compute_pool`].
+    pub(crate) compute_pool: Option<ComputePool>,
+    /// See [`LazyFrame::with_timeout`].
+    pub(crate) timeout: Option<Duration>,
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 ///
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
@@ -76,7 +128,7 @@ impl IntoLazy for LazyFrame {
 #[must_use]
 pub struct LazyFrame {
     pub logical_plan: DslPlan,
-    pub(crate) opt_state: OptFlags,
+    pub(crate) opt_state: OptState,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
 }
 
@@ -84,7 +136,7 @@ impl From<DslPlan> for LazyFrame {
     fn from(plan: DslPlan) -> Self {
         Self {
             logical_plan: plan,
-            opt_state: OptFlags::default(),
+            opt_state: OptState::default(),
             cached_arena: Default::default(),
         }
     }
@@ -93,7 +145,7 @@ impl From<DslPlan> for LazyFrame {
 impl LazyFrame {
     pub(crate) fn from_inner(
         logical_plan: DslPlan,
-        opt_state: OptFlags,
+        opt_state: OptState,
         cached_arena: Arc<Mutex<Option<CachedArena>>>,
     ) -> Self {
         Self {
@@ -107,11 +159,11 @@ impl LazyFrame {
         DslBuilder::from(self.logical_plan)
     }
 
-    fn get_opt_state(&self) -> OptFlags {
-        self.opt_state
+    fn get_opt_state(&self) -> OptState {
+        self.opt_state.clone()
     }
 
-    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptFlags) -> Self {
+    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptState) -> Self {
         LazyFrame {
             logical_plan,
             opt_state,
@@ -121,12 +173,12 @@ impl LazyFrame {
 
     /// Get current optimizations.
     pub fn get_current_optimizations(&self) -> OptFlags {
-        self.opt_state
+        self.opt_state.flags
     }
 
     /// Set allowed optimizations.
     pub fn with_optimizations(mut self, opt_state: OptFlags) -> Self {
-        self.opt_state = opt_state;
+        self.opt_state.flags = opt_state;
         self
     }
 
@@ -137,95 +189,167 @@ impl LazyFrame {
 
     /// Toggle projection pushdown optimization.
     pub fn with_projection_pushdown(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::PROJECTION_PUSHDOWN, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::PROJECTION_PUSHDOWN, toggle);
         self
     }
 
     /// Toggle cluster with columns optimization.
     pub fn with_cluster_with_columns(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::CLUSTER_WITH_COLUMNS, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::CLUSTER_WITH_COLUMNS, toggle);
         self
     }
 
     /// Toggle collapse joins optimization.
     pub fn with_collapse_joins(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::COLLAPSE_JOINS, toggle);
+        self.opt_state.flags.set(OptFlags::COLLAPSE_JOINS, toggle);
         self
     }
 
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::CHECK_ORDER_OBSERVE, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::CHECK_ORDER_OBSERVE, toggle);
         self
     }
 
     /// Toggle predicate pushdown optimization.
     pub fn with_predicate_pushdown(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::PREDICATE_PUSHDOWN, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::PREDICATE_PUSHDOWN, toggle);
         self
     }
 
     /// Toggle type coercion optimization.
     pub fn with_type_coercion(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::TYPE_COERCION, toggle);
+        self.opt_state.flags.set(OptFlags::TYPE_COERCION, toggle);
         self
     }
 
     /// Toggle type check optimization.
     pub fn with_type_check(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::TYPE_CHECK, toggle);
+        self.opt_state.flags.set(OptFlags::TYPE_CHECK, toggle);
         self
     }
 
     /// Toggle expression simplification optimization on or off.
     pub fn with_simplify_expr(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::SIMPLIFY_EXPR, toggle);
+        self.opt_state.flags.set(OptFlags::SIMPLIFY_EXPR, toggle);
         self
     }
 
     /// Toggle common subplan elimination optimization on or off
     #[cfg(feature = "cse")]
     pub fn with_comm_subplan_elim(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::COMM_SUBPLAN_ELIM, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::COMM_SUBPLAN_ELIM, toggle);
         self
     }
 
     /// Toggle common subexpression elimination optimization on or off
     #[cfg(feature = "cse")]
     pub fn with_comm_subexpr_elim(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::COMM_SUBEXPR_ELIM, toggle);
+        self.opt_state
+            .flags
+            .set(OptFlags::COMM_SUBEXPR_ELIM, toggle);
         self
     }
 
     /// Toggle slice pushdown optimization.
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::SLICE_PUSHDOWN, toggle);
+        self.opt_state.flags.set(OptFlags::SLICE_PUSHDOWN, toggle);
         self
     }
 
     /// Run nodes that are capably of doing so on the streaming engine.
     #[cfg(feature = "streaming")]
     pub fn with_streaming(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::STREAMING, toggle);
+        self.opt_state.flags.set(OptFlags::STREAMING, toggle);
         self
     }
 
     #[cfg(feature = "new_streaming")]
     pub fn with_new_streaming(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::NEW_STREAMING, toggle);
+        self.opt_state.flags.set(OptFlags::NEW_STREAMING, toggle);
+        self
+    }
+
+    /// Run this query on a dedicated pool of `n_threads` threads instead of on the global pool.
+    ///
+    /// The parallel work of the query, including that of user functions using the polars thread
+    /// pool, is bounded to this pool, and the streaming engine runs `n_threads` pipelines. The
+    /// pool comes in addition to the global pool, which is sized by `POLARS_MAX_THREADS`; work
+    /// the streaming engine hands to the in-memory engine still runs on the global pool.
+    ///
+    /// The pool is kept by the queries built on top of this [`LazyFrame`]. Joins and
+    /// concatenations run on the pool of their first input that has one.
+    pub fn with_compute_pool(mut self, n_threads: usize) -> PolarsResult<Self> {
+        polars_ensure!(n_threads > 0, InvalidOperation: "'n_threads' must be greater than zero");
+        self.opt_state.compute_pool = Some(ComputePool::new(n_threads)?);
+        Ok(self)
+    }
+
+    /// The compute pool of the first of `inputs` that has one, for a query combining them.
+    pub(crate) fn compute_pool_of<'a>(
+        inputs: impl IntoIterator<Item = &'a LazyFrame>,
+    ) -> Option<ComputePool> {
+        inputs
+            .into_iter()
+            .find_map(|lf| lf.opt_state.compute_pool.clone())
+    }
+
+    /// Run `op` on the compute pool of this query if it has one, see
+    /// [`LazyFrame::with_compute_pool`].
+    pub(crate) fn enter_compute_pool<R>(mut self, op: impl FnOnce(Self) -> R) -> R {
+        match self.opt_state.compute_pool.take() {
+            Some(pool) => pool.enter(|| op(self)),
+            None => op(self),
+        }
+    }
+
+    /// Stop the query with a "timeout" error if it runs longer than `duration`.
+    ///
+    /// Once the deadline passes, collecting returns the error without waiting for an operator
+    /// that is still running. The query itself stops before the next node of the plan in the
+    /// in-memory engine and at the next morsel boundary in the streaming engine. Like the
+    /// compute pool, the timeout is kept by the queries built on top of this [`LazyFrame`].
+    pub fn with_timeout(mut self, duration: Duration) -> Self {
+        self.opt_state.timeout = Some(duration);
         self
     }
 
+    /// Run `op` with `cancel_token` carrying the deadline of this query if it has a timeout, see
+    /// [`LazyFrame::with_timeout`].
+    fn run_with_timeout<R: Send + 'static>(
+        mut self,
+        cancel_token: CancelToken,
+        op: impl FnOnce(Self, CancelToken) -> PolarsResult<R> + Send + 'static,
+    ) -> PolarsResult<R> {
+        match self.opt_state.timeout.take() {
+            Some(timeout) => {
+                let cancel_token = cancel_token.with_deadline(Instant::now() + timeout);
+                run_until_deadline(cancel_token.clone(), move || op(self, cancel_token))
+            },
+            None => op(self, cancel_token),
+        }
+    }
+
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub fn with_row_estimate(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::ROW_ESTIMATE, toggle);
+        self.opt_state.flags.set(OptFlags::ROW_ESTIMATE, toggle);
         self
     }
 
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
-        self.opt_state.set(OptFlags::EAGER, toggle);
+        self.opt_state.flags.set(OptFlags::EAGER, toggle);
         self
     }
 
@@ -277,6 +401,32 @@ impl LazyFrame {
         }
     }
 
+    /// Validate the query and return the schema of its output without executing it.
+    ///
+    /// This resolves and optimizes the logical plan, so any error that would be raised before
+    /// execution starts (unknown columns, invalid types, failing optimizations) is returned here.
+    pub fn dry_run(&self) -> PolarsResult<Schema> {
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = self.clone().to_alp_optimized()?;
+        let schema = lp_arena.get(lp_top).schema(&lp_arena).into_owned();
+        Ok(Arc::unwrap_or_clone(schema))
+    }
+
+    /// Serialize the logical plan of this [`LazyFrame`] into a JSON string.
+    ///
+    /// See [`DslPlan::to_json`]. The plan is optimized by the process that collects it.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> PolarsResult<String> {
+        self.logical_plan.to_json()
+    }
+
+    /// Deserialize a [`LazyFrame`] from a JSON string created by [`LazyFrame::to_json`].
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> PolarsResult<LazyFrame> {
+        DslPlan::from_json(json).map(LazyFrame::from)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -510,6 +660,42 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Fill the nulls of the columns in `strategies`, each with its own strategy.
+    ///
+    /// Columns that are not in `strategies` are left untouched. Every column is filled based on
+    /// the values of the input, so a column that is filled can still be used as the `by` column of
+    /// [`ColumnFillNullStrategy::InterpolateBy`]. The names are taken literally, wildcards and
+    /// regex patterns are rejected. Unknown column names raise an error when the query is
+    /// resolved.
+    pub fn fill_null_with(
+        self,
+        strategies: PlIndexMap<PlSmallStr, ColumnFillNullStrategy>,
+    ) -> PolarsResult<LazyFrame> {
+        fn column(name: PlSmallStr) -> PolarsResult<Expr> {
+            polars_ensure!(
+                name != "*" && !(name.starts_with('^') && name.ends_with('$')),
+                InvalidOperation: "fill_null_with expects column names, got pattern '{}'", name
+            );
+            Ok(Expr::Column(name))
+        }
+
+        let exprs = strategies
+            .into_iter()
+            .map(|(name, strategy)| {
+                Ok(match strategy {
+                    ColumnFillNullStrategy::Strategy(strategy) => {
+                        column(name)?.fill_null_with_strategy(strategy)
+                    },
+                    #[cfg(feature = "interpolate_by")]
+                    ColumnFillNullStrategy::InterpolateBy(by) => {
+                        column(name)?.interpolate_by(column(by)?)
+                    },
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(self.with_columns(exprs))
+    }
+
     /// Fill NaN values in the DataFrame with an expression.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
         let opt_state = self.get_opt_state();
@@ -592,7 +778,7 @@ impl LazyFrame {
             self.logical_plan,
             &mut expr_arena,
             &mut lp_arena,
-            &mut self.opt_state,
+            &mut self.opt_state.flags,
         )?;
         let plan = IRPlan::new(node, lp_arena, expr_arena);
         Ok(plan)
@@ -606,9 +792,9 @@ impl LazyFrame {
         enable_fmt: bool,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
-        let mut opt_state = self.opt_state;
-        let streaming = self.opt_state.contains(OptFlags::STREAMING);
-        let new_streaming = self.opt_state.contains(OptFlags::NEW_STREAMING);
+        let mut opt_state = self.opt_state.flags;
+        let streaming = opt_state.contains(OptFlags::STREAMING);
+        let new_streaming = opt_state.contains(OptFlags::NEW_STREAMING);
         #[cfg(feature = "cse")]
         if streaming && !new_streaming {
             opt_state &= !OptFlags::COMM_SUBPLAN_ELIM;
@@ -678,6 +864,10 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
+        let deadline = self
+            .opt_state
+            .timeout
+            .map(|timeout| Instant::now() + timeout);
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
 
         let mut scratch = vec![];
@@ -698,7 +888,9 @@ impl LazyFrame {
             !matches!(
                 lp_arena.get(lp_top),
                 IR::Sink {
-                    payload: SinkTypeIR::File { .. } | SinkTypeIR::Partition { .. },
+                    payload: SinkTypeIR::File { .. }
+                        | SinkTypeIR::Partition { .. }
+                        | SinkTypeIR::Callback { .. },
                     ..
                 }
             )
@@ -712,7 +904,10 @@ impl LazyFrame {
             BUILD_STREAMING_EXECUTOR,
         )?;
 
-        let state = ExecutionState::new();
+        let mut state = ExecutionState::new();
+        if let Some(deadline) = deadline {
+            state.set_cancel_token(CancelToken::new().with_deadline(deadline));
+        }
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -726,9 +921,11 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
-        let (mut state, mut physical_plan, _) =
-            self.prepare_collect_post_opt(false, None, post_opt)?;
-        physical_plan.execute(&mut state)
+        self.enter_compute_pool(|lf| {
+            let (mut state, mut physical_plan, _) =
+                lf.prepare_collect_post_opt(false, None, post_opt)?;
+            physical_plan.execute(&mut state)
+        })
     }
 
     #[allow(unused_mut)]
@@ -744,7 +941,27 @@ impl LazyFrame {
     /// `engine`.
     ///
     /// The query is optimized prior to execution.
-    pub fn collect_with_engine(mut self, mut engine: Engine) -> PolarsResult<DataFrame> {
+    pub fn collect_with_engine(self, engine: Engine) -> PolarsResult<DataFrame> {
+        self.collect_with_engine_cancellable(engine, CancelToken::new())
+    }
+
+    /// [`LazyFrame::collect_with_engine`], stopping the query with a "query interrupted"
+    /// error once `cancel_token` is set.
+    pub(crate) fn collect_with_engine_cancellable(
+        mut self,
+        mut engine: Engine,
+        cancel_token: CancelToken,
+    ) -> PolarsResult<DataFrame> {
+        if self.opt_state.timeout.is_some() {
+            return self.run_with_timeout(cancel_token, move |lf, cancel_token| {
+                lf.collect_with_engine_cancellable(engine, cancel_token)
+            });
+        }
+        if self.opt_state.compute_pool.is_some() {
+            return self
+                .enter_compute_pool(|lf| lf.collect_with_engine_cancellable(engine, cancel_token));
+        }
+
         let payload = if let DslPlan::Sink { payload, .. } = &self.logical_plan {
             payload.clone()
         } else {
@@ -759,7 +976,9 @@ impl LazyFrame {
         if engine == Engine::Auto {
             engine = match payload {
                 #[cfg(feature = "new_streaming")]
-                SinkType::File { .. } | SinkType::Partition { .. } => Engine::Streaming,
+                SinkType::File { .. } | SinkType::Partition { .. } | SinkType::Callback { .. } => {
+                    Engine::Streaming
+                },
                 _ => Engine::InMemory,
             };
         }
@@ -789,10 +1008,11 @@ impl LazyFrame {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
                 #[cfg(feature = "dtype-categorical")]
                 let string_cache_hold = StringCacheHolder::hold();
-                let result = polars_stream::run_query(
+                let result = polars_stream::run_query_cancellable(
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    cancel_token,
                 );
                 #[cfg(feature = "dtype-categorical")]
                 drop(string_cache_hold);
@@ -813,16 +1033,18 @@ impl LazyFrame {
                     BUILD_STREAMING_EXECUTOR,
                 )?;
                 let mut state = ExecutionState::new();
+                state.set_cancel_token(cancel_token);
                 physical_plan.execute(&mut state)
             },
             Engine::OldStreaming => {
-                self.opt_state |= OptFlags::STREAMING;
+                self.opt_state.flags |= OptFlags::STREAMING;
                 let (mut state, mut physical_plan, is_streaming) =
                     self.prepare_collect(true, None)?;
                 polars_ensure!(
                     is_streaming,
                     ComputeError: format!("cannot run the whole query in a streaming order")
                 );
+                state.set_cancel_token(cancel_token);
                 physical_plan.execute(&mut state)
             },
         }
@@ -831,7 +1053,10 @@ impl LazyFrame {
     pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
         let sink_multiple = LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
-            opt_state,
+            opt_state: OptState {
+                flags: opt_state,
+                ..Default::default()
+            },
             cached_arena: Default::default(),
         };
         sink_multiple.explain(true)
@@ -839,10 +1064,24 @@ impl LazyFrame {
 
     pub fn collect_all_with_engine(
         plans: Vec<DslPlan>,
+        engine: Engine,
+        opt_state: OptFlags,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        let lfs = plans.into_iter().map(LazyFrame::from).collect();
+        Self::collect_all_frames_with_engine(lfs, engine, opt_state)
+    }
+
+    /// Execute the queries of `lfs` together, so that work common to them is only done once.
+    ///
+    /// Unlike [`LazyFrame::collect_all_with_engine`], this keeps the options of the frames: the
+    /// queries run on the compute pool of the first of `lfs` that has one, and stop at the
+    /// earliest of their timeouts.
+    pub fn collect_all_frames_with_engine(
+        lfs: Vec<LazyFrame>,
         mut engine: Engine,
         opt_state: OptFlags,
     ) -> PolarsResult<Vec<DataFrame>> {
-        if plans.is_empty() {
+        if lfs.is_empty() {
             return Ok(Vec::new());
         }
 
@@ -855,11 +1094,29 @@ impl LazyFrame {
             engine = Engine::InMemory;
         }
 
-        let mut sink_multiple = LazyFrame {
+        let compute_pool = Self::compute_pool_of(&lfs);
+        let timeout = lfs.iter().filter_map(|lf| lf.opt_state.timeout).min();
+        let plans = lfs.into_iter().map(|lf| lf.logical_plan).collect();
+        let sink_multiple = LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
-            opt_state,
+            opt_state: OptState {
+                flags: opt_state,
+                compute_pool,
+                timeout,
+            },
             cached_arena: Default::default(),
         };
+        sink_multiple.run_with_timeout(CancelToken::new(), move |lf, cancel_token| {
+            lf.enter_compute_pool(|lf| lf.collect_sink_multiple(engine, cancel_token))
+        })
+    }
+
+    fn collect_sink_multiple(
+        self,
+        engine: Engine,
+        cancel_token: CancelToken,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        let mut sink_multiple = self;
 
         #[cfg(feature = "new_streaming")]
         {
@@ -888,10 +1145,11 @@ impl LazyFrame {
             feature_gated!("new_streaming", {
                 #[cfg(feature = "dtype-categorical")]
                 let string_cache_hold = StringCacheHolder::hold();
-                let result = polars_stream::run_query(
+                let result = polars_stream::run_query_cancellable(
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    cancel_token,
                 );
                 #[cfg(feature = "dtype-categorical")]
                 drop(string_cache_hold);
@@ -919,6 +1177,7 @@ impl LazyFrame {
                 // this might then lead to a rayon SO. So we take a multitude of the threads to keep work stealing
                 // within bounds
                 let mut state = ExecutionState::new();
+                state.set_cancel_token(cancel_token);
                 if let Some(mut cache_prefiller) = multiplan.cache_prefiller {
                     cache_prefiller.execute(&mut state)?;
                 }
@@ -970,9 +1229,26 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute all the lazy operations and write the result into `target`, replacing its
+    /// columns.
+    ///
+    /// The column allocation of `target` is reused, which avoids reallocating when the same
+    /// query is collected repeatedly in a loop.
+    pub fn collect_into(self, target: &mut DataFrame) -> PolarsResult<()> {
+        let out = self.collect()?;
+        let height = out.height();
+        target.clear_columns();
+        // SAFETY: the columns come from a valid DataFrame of `height` rows.
+        unsafe {
+            target.column_extend_unchecked(out.take_columns());
+            target.set_height(height);
+        }
+        Ok(())
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
-    pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
+    pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, ProfilingInfo)>
     where
         P: FnOnce(
             Node,
@@ -981,23 +1257,22 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
-        let query_start = std::time::Instant::now();
-        let (mut state, mut physical_plan, _) =
-            self.prepare_collect_post_opt(false, Some(query_start), post_opt)?;
-        state.time_nodes(query_start);
-        let out = physical_plan.execute(&mut state)?;
-        let timer_df = state.finish_timer()?;
-        Ok((out, timer_df))
+        self.enter_compute_pool(|lf| {
+            let query_start = std::time::Instant::now();
+            let (mut state, mut physical_plan, _) =
+                lf.prepare_collect_post_opt(false, Some(query_start), post_opt)?;
+            state.time_nodes(query_start);
+            let out = physical_plan.execute(&mut state)?;
+            let info = state.finish_timer()?;
+            Ok((out, info))
+        })
     }
 
     /// Profile a LazyFrame.
     ///
-    /// This will run the query and return a tuple
-    /// containing the materialized DataFrame and a DataFrame that contains profiling information
-    /// of each node that is executed.
-    ///
-    /// The units of the timings are microseconds.
-    pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
+    /// This will run the query and return a tuple containing the materialized DataFrame and
+    /// the [`ProfilingInfo`] of each node that is executed.
+    pub fn profile(self) -> PolarsResult<(DataFrame, ProfilingInfo)> {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
 
@@ -1189,6 +1464,23 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream the query result into `function` in batches of `chunk_size` rows.
+    ///
+    /// The function is called from a single thread at a time and may block, which
+    /// holds back the query. Returning `true` from the function stops the query.
+    pub fn sink_batches(
+        self,
+        function: Arc<dyn Fn(DataFrame) -> PolarsResult<bool> + Send + Sync>,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Callback(CallbackSinkType {
+            function: SinkBatchCallback::Rust(SpecialEq::new(function)),
+            maintain_order,
+            chunk_size,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -1200,8 +1492,8 @@ impl LazyFrame {
             // Try to run using the new streaming engine, falling back
             // if it fails in a todo!() error if auto_new_streaming is set.
             let mut new_stream_lazy = self.clone();
-            new_stream_lazy.opt_state |= OptFlags::NEW_STREAMING;
-            new_stream_lazy.opt_state &= !OptFlags::STREAMING;
+            new_stream_lazy.opt_state.flags |= OptFlags::NEW_STREAMING;
+            new_stream_lazy.opt_state.flags &= !OptFlags::STREAMING;
             let mut alp_plan = match new_stream_lazy.to_alp_optimized() {
                 Ok(v) => v,
                 Err(e) => return Some(Err(e)),
@@ -1435,7 +1727,7 @@ impl LazyFrame {
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
-            keys: group_by.as_ref().to_vec(),
+            keys: dynamic_group_keys(group_by.as_ref(), options.keys_as_struct),
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
@@ -1480,7 +1772,7 @@ impl LazyFrame {
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
-            keys: group_by.as_ref().to_vec(),
+            keys: dynamic_group_keys(group_by.as_ref(), options.keys_as_struct),
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
@@ -1556,6 +1848,42 @@ impl LazyFrame {
         )
     }
 
+    /// Register `other` as the external frame `name`, whose columns can be referenced with
+    /// [`external`] in the following operations.
+    ///
+    /// `other` must have a single row, e.g. because it is an aggregation, so that its values can
+    /// be broadcast. It is collected here to check that, so this raises an error while the query
+    /// is built rather than when it is collected. The external columns are not part of the
+    /// output and are not selected by wildcards or selectors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn share(sales: LazyFrame, all_sales: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///     Ok(sales
+    ///         .with_external("totals", all_sales.select([col("amount").sum()]))?
+    ///         .with_column((col("amount") / external("totals", "amount")).alias("share")))
+    /// }
+    /// ```
+    #[cfg(feature = "cross_join")]
+    pub fn with_external(self, name: &str, other: LazyFrame) -> PolarsResult<LazyFrame> {
+        let mut other = other.collect()?;
+        polars_ensure!(
+            other.height() == 1,
+            ShapeMismatch: "external frame '{}' must have a single row, got {}", name, other.height()
+        );
+        let names = other
+            .get_column_names()
+            .into_iter()
+            .map(|column| format!("{POLARS_EXTERNAL_PREFIX}{name}.{column}"))
+            .collect::<Vec<_>>();
+        other.set_column_names(names)?;
+        Ok(self.cross_join(other.lazy(), None))
+    }
+
     /// Left outer join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -2193,6 +2521,7 @@ impl LazyFrame {
                     name,
                     offset: offset.unwrap_or(0),
                 });
+                unified_scan_args.row_index_u64_offset = None;
 
                 DslPlan::Scan {
                     sources,
@@ -2207,6 +2536,61 @@ impl LazyFrame {
         }
     }
 
+    /// Add a new `UInt64` column at index 0 that counts the rows, regardless of the [`IdxSize`]
+    /// of this build.
+    ///
+    /// Unlike [`LazyFrame::with_row_index`], this can number more than `u32::MAX` rows, e.g.
+    /// when assigning global row ids to a scan over many files. Overflowing `u64::MAX` raises an
+    /// error.
+    ///
+    /// If [`IdxSize`] is 64 bits wide, this is the same as [`LazyFrame::with_row_index`]. Otherwise
+    /// a file scan still adds the row index itself, numbering the rows across all of its files.
+    ///
+    /// # Warning
+    /// This can have a negative effect on query performance. This may for instance block
+    /// predicate pushdown optimization if the input is not a scan.
+    pub fn with_row_index_u64<S>(self, name: S, offset: u64) -> LazyFrame
+    where
+        S: Into<PlSmallStr>,
+    {
+        let name = name.into();
+        if IDX_DTYPE == DataType::UInt64 {
+            if let Ok(offset) = IdxSize::try_from(offset) {
+                return self.with_row_index(name, Some(offset));
+            }
+        }
+
+        match &self.logical_plan {
+            v @ DslPlan::Scan { scan_type, .. }
+                if !matches!(&**scan_type, FileScan::Anonymous { .. }) =>
+            {
+                let DslPlan::Scan {
+                    sources,
+                    mut unified_scan_args,
+                    scan_type,
+                    file_info,
+                    cached_ir: _,
+                } = v.clone()
+                else {
+                    unreachable!()
+                };
+
+                unified_scan_args.row_index = Some(RowIndex { name, offset: 0 });
+                unified_scan_args.row_index_u64_offset = Some(offset);
+
+                DslPlan::Scan {
+                    sources,
+                    unified_scan_args,
+                    scan_type,
+                    file_info,
+                    cached_ir: Default::default(),
+                }
+                .into()
+            },
+            _ => self.map_private(DslFunction::RowIndexU64 { name, offset }),
+        }
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -2225,11 +2609,52 @@ impl LazyFrame {
             .iter()
             .map(|ie| ie.clone().into())
             .collect::<Vec<_>>();
-        self.map_private(DslFunction::Unnest(cols))
+        self.map_private(DslFunction::Unnest {
+            columns: cols,
+            options: Default::default(),
+        })
     }
 
+    /// Unnest the given `Struct` columns, with control over the naming and placement of the
+    /// resulting columns.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<E, IE>(self, cols: E, options: UnnestOptions) -> Self
+    where
+        E: AsRef<[IE]>,
+        IE: Into<Selector> + Clone,
+    {
+        let cols = cols
+            .as_ref()
+            .iter()
+            .map(|ie| ie.clone().into())
+            .collect::<Vec<_>>();
+        self.map_private(DslFunction::Unnest {
+            columns: cols,
+            options,
+        })
+    }
+
+    /// Merge this `LazyFrame` with `other`, both sorted in ascending order by the column `key`
+    /// with the nulls first.
     #[cfg(feature = "merge_sorted")]
     pub fn merge_sorted<S>(self, other: LazyFrame, key: S) -> PolarsResult<LazyFrame>
+    where
+        S: Into<PlSmallStr>,
+    {
+        self.merge_sorted_with_nulls_last(other, key, false)
+    }
+
+    /// Merge this `LazyFrame` with `other`, both sorted in ascending order by the column `key`.
+    ///
+    /// `nulls_last` states where the nulls of both keys are placed. Keys with their nulls on the
+    /// other end are an error.
+    #[cfg(feature = "merge_sorted")]
+    pub fn merge_sorted_with_nulls_last<S>(
+        self,
+        other: LazyFrame,
+        key: S,
+        nulls_last: bool,
+    ) -> PolarsResult<LazyFrame>
     where
         S: Into<PlSmallStr>,
     {
@@ -2239,6 +2664,7 @@ impl LazyFrame {
             input_left: Arc::new(self.logical_plan),
             input_right: Arc::new(other.logical_plan),
             key,
+            nulls_last,
         };
         Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
     }
@@ -2248,7 +2674,7 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct LazyGroupBy {
     pub logical_plan: DslPlan,
-    opt_state: OptFlags,
+    opt_state: OptState,
     keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
@@ -2351,6 +2777,38 @@ impl LazyGroupBy {
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
+        self.apply_impl(Arc::new(f), schema)
+    }
+
+    /// Apply a function over the groups as a new DataFrame, running the groups in parallel.
+    ///
+    /// `schema` is the schema of the final result, the output of every call to `f` is validated
+    /// against it. If `keep_keys` is set, the group keys are attached in front of the output of
+    /// `f` when it doesn't contain them itself. The keys must then be columns of the input.
+    ///
+    /// **It is not recommended that you use this as materializing the DataFrame is very
+    /// expensive.**
+    pub fn map_groups<F>(self, schema: SchemaRef, keep_keys: bool, f: F) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        let keys = if keep_keys {
+            self.keys
+                .iter()
+                .filter_map(|expr| expr_output_name(expr).ok())
+                .collect()
+        } else {
+            vec![]
+        };
+        let udf = MapGroupsUdf {
+            f,
+            schema: schema.clone(),
+            keys,
+        };
+        self.apply_impl(Arc::new(udf), schema)
+    }
+
+    fn apply_impl(self, f: Arc<dyn DataFrameUdf>, schema: SchemaRef) -> LazyFrame {
         #[cfg(feature = "dynamic_group_by")]
         let options = GroupbyOptions {
             dynamic: self.dynamic_options,
@@ -2365,7 +2823,7 @@ impl LazyGroupBy {
             input: Arc::new(self.logical_plan),
             keys: self.keys,
             aggs: vec![],
-            apply: Some((Arc::new(f), schema)),
+            apply: Some((f, schema)),
             maintain_order: self.maintain_order,
             options: Arc::new(options),
         };
@@ -2373,6 +2831,49 @@ impl LazyGroupBy {
     }
 }
 
+/// The function of [`LazyGroupBy::map_groups`].
+struct MapGroupsUdf<F> {
+    f: F,
+    schema: SchemaRef,
+    /// Names of the keys to attach to the output.
+    keys: Vec<PlSmallStr>,
+}
+
+impl<F> DataFrameUdf for MapGroupsUdf<F>
+where
+    F: Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+{
+    fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let mut out = (self.f)(df.clone())?;
+
+        let missing_keys = self
+            .keys
+            .iter()
+            .filter(|name| out.get_column_index(name).is_none())
+            .collect::<Vec<_>>();
+        if !missing_keys.is_empty() {
+            let height = out.height();
+            let mut columns = Vec::with_capacity(missing_keys.len() + out.width());
+            for name in missing_keys {
+                columns.push(df.column(name)?.new_from_index(0, height));
+            }
+            columns.extend(out.take_columns());
+            out = DataFrame::new(columns)?;
+        }
+
+        polars_ensure!(
+            out.schema() == &self.schema,
+            SchemaMismatch: "'map_groups' function returned schema {:?}, expected {:?}",
+            out.schema(), self.schema
+        );
+        Ok(out)
+    }
+
+    fn allows_parallel(&self) -> bool {
+        true
+    }
+}
+
 #[must_use]
 pub struct JoinBuilder {
     lf: LazyFrame,
@@ -2387,6 +2888,7 @@ pub struct JoinBuilder {
     nulls_equal: bool,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
+    row_hints: Option<(usize, usize)>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2404,6 +2906,7 @@ impl JoinBuilder {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            row_hints: None,
         }
     }
 
@@ -2490,10 +2993,20 @@ impl JoinBuilder {
         self
     }
 
+    /// Hint the number of rows of the left and right table.
+    ///
+    /// The optimizer uses these to put the smaller table on the build side of an inner join when
+    /// the sizes can't be estimated from the inputs.
+    pub fn row_hints(mut self, left: usize, right: usize) -> Self {
+        self.row_hints = Some((left, right));
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let mut opt_state = self.lf.get_opt_state();
         let other = self.other.expect("'with' not set in join builder");
+        opt_state.compute_pool = LazyFrame::compute_pool_of([&self.lf, &other]);
 
         let args = JoinArgs {
             how: self.how,
@@ -2504,6 +3017,7 @@ impl JoinBuilder {
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
         };
+        let (rows_left, rows_right) = self.row_hints.unwrap_or((usize::MAX, usize::MAX));
 
         let lp = self
             .lf
@@ -2516,6 +3030,8 @@ impl JoinBuilder {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
                     args,
+                    rows_left: (None, rows_left),
+                    rows_right: (None, rows_right),
                     ..Default::default()
                 }
                 .into(),
@@ -2526,8 +3042,9 @@ impl JoinBuilder {
 
     // Finish with join predicates
     pub fn join_where(self, predicates: Vec<Expr>) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let mut opt_state = self.lf.get_opt_state();
         let other = self.other.expect("with not set");
+        opt_state.compute_pool = LazyFrame::compute_pool_of([&self.lf, &other]);
 
         // Decompose `And` conjunctions into their component expressions
         fn decompose_and(predicate: Expr, expanded_predicates: &mut Vec<Expr>) {
@@ -2677,13 +3194,13 @@ mod streaming_dispatch {
     }
 
     impl Executor for StreamingQueryExecutor {
-        fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        fn execute(&mut self, cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
             // Must not block rayon thread on pending new-streaming future.
             assert!(POOL.current_thread_index().is_none());
 
             let mut df = { self.executor.try_lock().unwrap().take() }
                 .expect("unhandled: execute() more than once")
-                .execute()
+                .execute_cancellable(cache.cancel_token())
                 .map(|x| x.unwrap_single())?;
 
             if self.rechunk {
@@ -235,7 +235,11 @@ pub(crate) fn insert_streaming_nodes(
                 && matches!(
                     &unified_scan_args.pre_slice,
                     None | Some(polars_utils::slice_enum::Slice::Positive { .. })
-                ) =>
+                )
+                // The old streaming sources don't add the file metadata columns or a `UInt64` row
+                // index.
+                && unified_scan_args.file_metadata_columns.is_empty()
+                && unified_scan_args.row_index_u64_offset.is_none() =>
             {
                 if state.streamable {
                     state.sources.push(root);
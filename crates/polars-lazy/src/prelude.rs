@@ -1,4 +1,5 @@
 pub(crate) use polars_expr::prelude::*;
+pub use polars_expr::state::ProfilingInfo;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -7,7 +8,7 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_ops::prelude::{ColumnFillNullStrategy, JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
@@ -51,10 +51,12 @@ impl LazyFrame {
                 glob: false,
                 projection: None,
                 row_index: None,
+                row_index_u64_offset: None,
                 pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths: None,
+                file_metadata_columns: Default::default(),
             },
         )?
         .build()
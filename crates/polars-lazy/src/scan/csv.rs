@@ -23,6 +23,7 @@ pub struct LazyCsvReader {
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
+    file_metadata_columns: FileMetadataColumns,
 }
 
 #[cfg(feature = "csv")]
@@ -48,6 +49,7 @@ impl LazyCsvReader {
             read_options: Default::default(),
             cloud_options: Default::default(),
             include_file_paths: None,
+            file_metadata_columns: Default::default(),
         }
     }
 
@@ -317,6 +319,15 @@ impl LazyCsvReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Attach metadata of the source files as columns, see [`FileMetadataColumns`].
+    pub fn with_file_metadata_columns(
+        mut self,
+        file_metadata_columns: FileMetadataColumns,
+    ) -> Self {
+        self.file_metadata_columns = file_metadata_columns;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
@@ -338,10 +349,12 @@ impl LazyFileListReader for LazyCsvReader {
                 glob: self.glob,
                 projection: None,
                 row_index,
+                row_index_u64_offset: None,
                 pre_slice,
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths: self.include_file_paths,
+                file_metadata_columns: self.file_metadata_columns,
             },
         )?
         .build()
@@ -17,6 +17,10 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    pub file_metadata_columns: FileMetadataColumns,
+    /// Hold a shared advisory lock on the files for the duration of the scan, see
+    /// [`IpcScanOptions::lock_file`].
+    pub lock_file: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -29,6 +33,8 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            file_metadata_columns: Default::default(),
+            lock_file: false,
         }
     }
 }
@@ -52,7 +58,9 @@ impl LazyFileListReader for LazyIpcReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let args = self.args;
 
-        let options = IpcScanOptions {};
+        let options = IpcScanOptions {
+            lock_file: args.lock_file,
+        };
         let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
 
         let cloud_options = args.cloud_options;
@@ -61,6 +69,7 @@ impl LazyFileListReader for LazyIpcReader {
         let cache = args.cache;
         let row_index = args.row_index;
         let include_file_paths = args.include_file_paths;
+        let file_metadata_columns = args.file_metadata_columns;
 
         let lf: LazyFrame = DslBuilder::scan_ipc(
             self.sources,
@@ -74,10 +83,12 @@ impl LazyFileListReader for LazyIpcReader {
                 glob: true,
                 projection: None,
                 row_index,
+                row_index_u64_offset: None,
                 pre_slice,
                 cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths,
+                file_metadata_columns,
             },
         )?
         .build()
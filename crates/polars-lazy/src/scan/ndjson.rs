@@ -5,7 +5,9 @@ use std::sync::Arc;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::{HiveOptions, RowIndex};
-use polars_plan::dsl::{CastColumnsPolicy, DslPlan, FileScan, MissingColumnsPolicy, ScanSources};
+use polars_plan::dsl::{
+    CastColumnsPolicy, DslPlan, FileMetadataColumns, FileScan, MissingColumnsPolicy, ScanSources,
+};
 use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
 use polars_utils::slice_enum::Slice;
 
@@ -25,6 +27,7 @@ pub struct LazyJsonLineReader {
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
+    pub(crate) file_metadata_columns: FileMetadataColumns,
     pub(crate) cloud_options: Option<CloudOptions>,
 }
 
@@ -46,6 +49,7 @@ impl LazyJsonLineReader {
             ignore_errors: false,
             n_rows: None,
             include_file_paths: None,
+            file_metadata_columns: Default::default(),
             cloud_options: None,
         }
     }
@@ -119,6 +123,15 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Attach metadata of the source files as columns, see [`FileMetadataColumns`].
+    pub fn with_file_metadata_columns(
+        mut self,
+        file_metadata_columns: FileMetadataColumns,
+    ) -> Self {
+        self.file_metadata_columns = file_metadata_columns;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -132,10 +145,12 @@ impl LazyFileListReader for LazyJsonLineReader {
             glob: true,
             projection: None,
             row_index: self.row_index,
+            row_index_u64_offset: None,
             pre_slice: self.n_rows.map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
             missing_columns_policy: MissingColumnsPolicy::Raise,
             include_file_paths: self.include_file_paths,
+            file_metadata_columns: self.file_metadata_columns,
         };
 
         let options = NDJsonReadOptions {
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::read::{FileDecryptionProperties, ParallelStrategy};
 use polars_io::prelude::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::slice_enum::Slice;
@@ -24,7 +24,12 @@ pub struct ScanArgsParquet {
     /// Expand path given via globbing rules.
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
+    pub file_metadata_columns: FileMetadataColumns,
     pub allow_missing_columns: bool,
+    /// How to cast the columns of each file to `schema`.
+    pub cast_columns_policy: CastColumnsPolicy,
+    /// Keys to read files written with Parquet modular encryption.
+    pub encryption: Option<FileDecryptionProperties>,
 }
 
 impl Default for ScanArgsParquet {
@@ -42,7 +47,10 @@ impl Default for ScanArgsParquet {
             cache: true,
             glob: true,
             include_file_paths: None,
+            file_metadata_columns: Default::default(),
             allow_missing_columns: false,
+            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            encryption: None,
         }
     }
 }
@@ -70,6 +78,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            encryption: self.args.encryption,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -82,17 +91,19 @@ impl LazyFileListReader for LazyParquetReader {
             projection: None,
             // Note: We call `with_row_index()` on the LazyFrame below
             row_index: None,
+            row_index_u64_offset: None,
             pre_slice: self
                 .args
                 .n_rows
                 .map(|len| Slice::Positive { offset: 0, len }),
-            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            cast_columns_policy: self.args.cast_columns_policy,
             missing_columns_policy: if self.args.allow_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
                 MissingColumnsPolicy::Raise
             },
             include_file_paths: self.args.include_file_paths,
+            file_metadata_columns: self.args.file_metadata_columns,
         };
 
         let mut lf: LazyFrame =
@@ -610,3 +610,260 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+#[cfg(feature = "unique_counts")]
+fn test_agg_unique_with_counts() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 1, 2, 2],
+        "v" => [Some(3), Some(1), Some(3), Some(3), None, Some(5)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("v").unique_with_counts().alias("uc"),
+            col("v").unique_stable().alias("u"),
+            col("v").unique_counts().alias("c"),
+        ])
+        .collect()?;
+
+    let uc = out.column("uc")?.explode(false)?;
+    let uc = uc.struct_()?;
+    let values = uc.field_by_name("value")?;
+    let counts = uc.field_by_name("count")?;
+    assert_eq!(
+        Vec::from(values.i32()?),
+        &[Some(3), Some(1), Some(3), None, Some(5)]
+    );
+    assert_eq!(
+        Vec::from(counts.idx()?),
+        &[Some(2), Some(1), Some(1), Some(1), Some(1)]
+    );
+
+    // The unique values and counts of a group are in the same order.
+    let u = out.column("u")?.explode(false)?;
+    let c = out.column("c")?.explode(false)?;
+    assert!(values.equals_missing(u.as_materialized_series()));
+    assert!(counts.equals_missing(c.as_materialized_series()));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "approx_quantile")]
+fn test_agg_approx_quantile() -> PolarsResult<()> {
+    // Skewed values: most values are small, the tail is long.
+    let values = (0..10_000)
+        .map(|i| (i as f64 / 1000.0).powi(2))
+        .collect::<Vec<_>>();
+    let groups = (0..10_000).map(|i| i % 3).collect::<Vec<i32>>();
+    let df = df![
+        "g" => groups,
+        "v" => values,
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("v").approx_quantile(0.9, 100.0).alias("approx"),
+            col("v")
+                .quantile(lit(0.9), QuantileMethod::Linear)
+                .alias("exact"),
+            col("v").approx_quantile_state(100.0).alias("state"),
+        ])
+        .collect()?;
+    let approx = out.column("approx")?.f64()?;
+    let exact = out.column("exact")?.f64()?;
+    for (a, e) in approx.into_no_null_iter().zip(exact.into_no_null_iter()) {
+        assert!((a - e).abs() / e < 0.05, "approx {a}, exact {e}");
+    }
+
+    // Merging the per-group digests gives the quantile of the whole column.
+    let merged = out
+        .lazy()
+        .select([
+            col("state").merge_tdigest().approx_quantile(0.5, 100.0),
+            col("state").approx_quantile(0.5, 100.0).alias("direct"),
+        ])
+        .collect()?;
+    let exact = df.column("v")?.as_materialized_series().median().unwrap();
+    for name in ["state", "direct"] {
+        let v = merged.column(name)?.f64()?.get(0).unwrap();
+        assert!(
+            (v - exact).abs() / exact < 0.05,
+            "approx {v}, exact {exact}"
+        );
+    }
+
+    // The streaming engine builds the digests with a grouped reduction, its
+    // partial digests must merge to the same accuracy.
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by([col("g")])
+            .agg([
+                col("v").approx_quantile(0.9, 100.0).alias("approx"),
+                col("v")
+                    .quantile(lit(0.9), QuantileMethod::Linear)
+                    .alias("exact"),
+                col("v").approx_quantile_state(100.0).alias("state"),
+            ])
+            .sort(["g"], Default::default())
+            .with_new_streaming(true)
+            .collect()?;
+        assert_eq!(out.height(), 3);
+        let approx = out.column("approx")?.f64()?;
+        let exact = out.column("exact")?.f64()?;
+        for (a, e) in approx.into_no_null_iter().zip(exact.into_no_null_iter()) {
+            assert!((a - e).abs() / e < 0.05, "approx {a}, exact {e}");
+        }
+
+        let merged = out
+            .lazy()
+            .select([col("state").approx_quantile(0.5, 100.0)])
+            .collect()?;
+        let exact = df.column("v")?.as_materialized_series().median().unwrap();
+        let v = merged.column("state")?.f64()?.get(0).unwrap();
+        assert!(
+            (v - exact).abs() / exact < 0.05,
+            "approx {v}, exact {exact}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_empty_group_policy() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "x" => [Some(1i64), Some(2), None, None],
+        "p" => [Some(true), Some(true), None, None],
+    ]?;
+    let aggs = |policy: EmptyGroupPolicy| {
+        [
+            col("x").sum_with(policy).alias("sum"),
+            col("x").product_with(policy).alias("product"),
+            col("p").any_with(true, policy).alias("any"),
+            col("p").all_with(true, policy).alias("all"),
+        ]
+    };
+
+    for (policy, expected) in [
+        (
+            EmptyGroupPolicy::Identity,
+            df![
+                "g" => ["a", "b"],
+                "sum" => [3i64, 0],
+                "product" => [2i64, 1],
+                "any" => [true, false],
+                "all" => [true, true],
+            ]?,
+        ),
+        (
+            EmptyGroupPolicy::Null,
+            df![
+                "g" => ["a", "b"],
+                "sum" => [Some(3i64), None],
+                "product" => [Some(2i64), None],
+                "any" => [Some(true), None],
+                "all" => [Some(true), None],
+            ]?,
+        ),
+    ] {
+        let q = df
+            .clone()
+            .lazy()
+            .group_by([col("g")])
+            .agg(aggs(policy))
+            .sort(["g"], Default::default());
+
+        assert_eq!(q.clone().collect()?, expected, "{policy:?}");
+        #[cfg(feature = "new_streaming")]
+        assert_eq!(
+            q.with_new_streaming(true).collect()?,
+            expected,
+            "{policy:?}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "abs")]
+fn test_max_min_abs() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "x" => [Some(-3), Some(2), Some(1), None],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x").max_abs().alias("max_abs"),
+            col("x").min_abs().alias("min_abs"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("max_abs")?.get(0)?, AnyValue::Int32(-3));
+    assert_eq!(out.column("min_abs")?.get(0)?, AnyValue::Int32(1));
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("x").max_abs()])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("x")?.i32()?), [Some(-3), Some(1)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sample_per_group() -> PolarsResult<()> {
+    // Two groups with the same values.
+    let df = df![
+        "g" => std::iter::repeat_n("a", 100).chain(std::iter::repeat_n("b", 100)).collect::<Vec<_>>(),
+        "v" => (0..100).chain(0..100).collect::<Vec<i32>>(),
+    ]?;
+    let sample = |df: DataFrame| {
+        df.lazy()
+            .group_by_stable([col("g")])
+            .agg([
+                col("v").sample_per_group([col("g")], StratifiedSpec::N(10), false, true, Some(7)),
+                col("v")
+                    .sample_per_group([col("g")], StratifiedSpec::N(1000), false, false, Some(7))
+                    .len()
+                    .alias("len"),
+            ])
+            .collect()
+    };
+
+    let out = sample(df.clone())?;
+    let v = out.column("v")?.list()?;
+    let a = v.get_as_series(0).unwrap();
+    let b = v.get_as_series(1).unwrap();
+    assert_eq!(a.len(), 10);
+    // Every group has its own seed, so equal groups are not sampled at the same positions.
+    assert!(!a.equals(&b));
+    // The samples are reproducible with a fixed seed.
+    assert!(out.equals(&sample(df.clone())?));
+    // Without replacement the sample is capped at the group size.
+    assert_eq!(Vec::from(out.column("len")?.idx()?), [Some(100), Some(100)]);
+
+    // The seed is derived from the key, so the order of the groups doesn't matter.
+    let swapped = df.slice(100, 100).vstack(&df.slice(0, 100))?;
+    let out_swapped = sample(swapped)?;
+    let v_swapped = out_swapped.column("v")?.list()?;
+    assert!(v_swapped.get_as_series(1).unwrap().equals(&a));
+    assert!(v_swapped.get_as_series(0).unwrap().equals(&b));
+
+    // The groups are sampled like `DataFrame::sample_stratified` does.
+    let stratified = df.sample_stratified(&["g"], StratifiedSpec::N(10), false, true, Some(7))?;
+    let stratified = stratified.column("v")?.as_materialized_series();
+    assert!(stratified.slice(0, 10).equals(&a));
+    assert!(stratified.slice(10, 10).equals(&b));
+    Ok(())
+}
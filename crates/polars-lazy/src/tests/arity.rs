@@ -81,3 +81,48 @@ fn test_lazy_ternary() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_scatter_by_mask() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "x" => [1, 2, 3, 4],
+        "y" => [10, 20, 30, 40],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").scatter_by_mask(col("x").gt(lit(2)), col("y") * lit(2))])
+        .collect()?;
+    assert_eq!(out, df!["x" => [1, 2, 60, 80]]?);
+
+    // The mask and the values are computed per group.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x")
+            .scatter_by_mask(col("x").eq(col("x").max()), col("y").sum())
+            .over([col("g")])])
+        .collect()?;
+    assert_eq!(out, df!["x" => [1, 20, 3, 40]]?);
+
+    // Columns selected in other ways are filtered too, and `len` counts the masked rows.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").scatter_by_mask(
+            col("x").gt(lit(2)),
+            nth(2) + cols(["x"]) + len().cast(DataType::Int32),
+        )])
+        .collect()?;
+    assert_eq!(out, df!["x" => [1, 2, 35, 46]]?);
+
+    // The number of values has to match the number of `true` values in the mask.
+    let values = lit(Series::new("v".into(), [1, 2, 3]));
+    let q = df
+        .lazy()
+        .select([col("x").scatter_by_mask(col("x").gt(lit(2)), values)]);
+    assert!(matches!(q.collect(), Err(PolarsError::ShapeMismatch(_))));
+    Ok(())
+}
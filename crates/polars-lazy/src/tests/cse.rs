@@ -355,3 +355,54 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_expr_hash_cons() -> PolarsResult<()> {
+    use polars_plan::plans::expr_ir::ExprHashCons;
+
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+    let select_exprs = |exprs: &[Expr]| -> PolarsResult<_> {
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            expr_arena,
+        } = df.clone().lazy().select(exprs).to_alp()?;
+        let IR::Select { expr, .. } = lp_arena.get(lp_top) else {
+            unreachable!()
+        };
+        Ok((expr.to_vec(), expr_arena))
+    };
+
+    let (exprs, arena) = select_exprs(&[
+        (col("a") + col("b")).alias("x"),
+        ((col("a") + col("b")) * lit(2)).alias("y"),
+        (col("b") + col("a")).alias("z"),
+    ])?;
+    let mut hash_cons = ExprHashCons::default();
+    let ids = exprs
+        .iter()
+        .map(|e| hash_cons.add(e, &arena))
+        .collect::<Vec<_>>();
+    let AExpr::BinaryExpr { left, .. } = arena.get(exprs[1].node()) else {
+        unreachable!()
+    };
+    assert_eq!(hash_cons.id(*left), Some(ids[0]));
+    assert_eq!(hash_cons.count(ids[0]), 2);
+    assert_ne!(ids[0], ids[2]);
+    assert!(hash_cons.has_duplicate_subexprs(&arena));
+
+    // Repeated columns and literals alone are not worth eliminating.
+    let (exprs, arena) = select_exprs(&[
+        (col("a") + lit(1)).alias("x"),
+        (col("a") * lit(1)).alias("y"),
+    ])?;
+    let mut hash_cons = ExprHashCons::default();
+    for e in &exprs {
+        hash_cons.add(e, &arena);
+    }
+    assert!(!hash_cons.has_duplicate_subexprs(&arena));
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 use polars_utils::slice_enum::Slice;
@@ -421,9 +421,12 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cache: true,
             rechunk: false,
             row_index: None,
+            row_index_u64_offset: None,
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            file_metadata_columns: Default::default(),
+            lock_file: false,
         },
     )?
     .collect()?;
@@ -435,6 +438,52 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(target_family = "unix")]
+fn test_ipc_lock_file() -> PolarsResult<()> {
+    init_files();
+    let expected = LazyFrame::scan_ipc(FOODS_IPC, Default::default())?.collect()?;
+    let args = ScanArgsIpc {
+        lock_file: true,
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_ipc(FOODS_IPC, args)?.collect()?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_ipc_file_fingerprint() -> PolarsResult<()> {
+    use polars_io::ipc::IpcFileFingerprint;
+
+    let path = std::env::temp_dir().join("polars-test-ipc-file-fingerprint.ipc");
+    let write = |values: &[i32]| -> PolarsResult<()> {
+        let mut df = df!("a" => values)?;
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)
+    };
+
+    write(&[1, 2, 3])?;
+    let fingerprint = IpcFileFingerprint::from_file(&std::fs::File::open(&path)?)?;
+    assert_eq!(
+        fingerprint,
+        IpcFileFingerprint::from_bytes(&std::fs::read(&path)?)
+    );
+
+    // The fingerprints are taken for every query, so rewriting the file between queries on the
+    // same plan is fine.
+    let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+    assert_eq!(lf.clone().collect()?.height(), 3);
+    write(&[1, 2, 3, 4])?;
+    assert_ne!(
+        IpcFileFingerprint::from_file(&std::fs::File::open(&path)?)?,
+        fingerprint
+    );
+    assert_eq!(lf.collect()?.height(), 4);
+
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -763,3 +812,514 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+fn test_scan_file_metadata_columns() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-file-metadata-columns");
+    for part in [1, 2] {
+        let part_dir = dir.join(format!("part={part}"));
+        std::fs::create_dir_all(&part_dir)?;
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => ["x"; 6])?;
+        ParquetWriter::new(std::fs::File::create(part_dir.join("data.parquet"))?)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+    }
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            enabled: Some(true),
+            ..Default::default()
+        },
+        file_metadata_columns: FileMetadataColumns {
+            path: Some("path".into()),
+            size: Some("size".into()),
+            mtime: Some("mtime".into()),
+            row_group: Some("row_group".into()),
+        },
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(dir.join("**/*.parquet"), args)?;
+
+    let out = lf
+        .clone()
+        .filter(col("a").gt(lit(1)))
+        .select([col("part"), col("a"), col("row_group"), col("path")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["part", "a", "row_group", "path"]);
+    assert_eq!(
+        out.column("row_group")?.idx()?.to_vec(),
+        [0, 1, 1, 2, 2, 0, 1, 1, 2, 2].map(Some)
+    );
+    assert!(matches!(
+        out.column("path")?.dtype(),
+        DataType::Categorical(_, _)
+    ));
+    let path = out.column("path")?.cast(&DataType::String)?;
+    assert!(path.str()?.get(0).unwrap().ends_with("part=1/data.parquet"));
+    assert!(path.str()?.get(9).unwrap().ends_with("part=2/data.parquet"));
+
+    let out = lf.select([col("size"), col("mtime")]).collect()?;
+    let size = std::fs::metadata(dir.join("part=1/data.parquet"))?.len();
+    assert_eq!(out.column("size")?.u64()?.get(0), Some(size));
+    assert_eq!(out.column("mtime")?.null_count(), 0);
+
+    let lf = LazyCsvReader::new(FOODS_CSV)
+        .with_file_metadata_columns(FileMetadataColumns {
+            row_group: Some("row_group".into()),
+            ..Default::default()
+        })
+        .finish()?;
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("row_group")?.null_count(), 27);
+
+    // The old streaming engine doesn't add the columns, so the scan runs on the in-memory engine.
+    #[cfg(feature = "streaming")]
+    assert_eq!(lf.with_streaming(true).collect()?, out);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_hive_listing_filter() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-hive-listing-filter");
+    let _ = std::fs::remove_dir_all(&dir);
+    for year in [2023, 2024, 2025] {
+        for month in [1, 2, 3] {
+            // Partitions written by other tools can use another form of the same value.
+            let part_dir = if year == 2025 {
+                dir.join(format!("year={year}/month={month:02}"))
+            } else {
+                dir.join(format!("year={year}/month={month}"))
+            };
+            std::fs::create_dir_all(&part_dir)?;
+            let mut df = df!("a" => [year * 100 + month])?;
+            ParquetWriter::new(std::fs::File::create(part_dir.join("data.parquet"))?)
+                .finish(&mut df)?;
+        }
+    }
+    // Fails the scan if it is listed.
+    let corrupt = dir.join("year=2023/month=1/data.parquet");
+    std::fs::write(&corrupt, b"not a parquet file")?;
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            schema: Some(Arc::new(Schema::from_iter([
+                Field::new("year".into(), DataType::Int32),
+                Field::new("month".into(), DataType::Int32),
+            ]))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(&dir, args)?;
+
+    let out = lf
+        .clone()
+        .filter(
+            col("year")
+                .eq(lit(2024))
+                .and(col("month").is_in(lit(Series::new("".into(), [1, 3])).implode(), false)),
+        )
+        .collect()?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(202401), Some(202403)]
+    );
+
+    let out = lf
+        .clone()
+        .filter(
+            col("year")
+                .gt_eq(lit(2024))
+                .and(col("year").lt(lit(2026)))
+                .and(col("month").eq(lit(2))),
+        )
+        .collect()?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(202402), Some(202502)]
+    );
+
+    // The directories of every key are filtered, not only the leading one.
+    let out = lf
+        .clone()
+        .filter(col("month").eq(lit(2)))
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(202302), Some(202402), Some(202502)]
+    );
+    assert!(
+        lf.clone()
+            .filter(col("month").eq(lit(1)))
+            .collect()
+            .is_err()
+    );
+
+    std::fs::remove_file(&corrupt)?;
+    let out = lf.filter(col("year").eq(lit(2030))).collect()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(out.get_column_names(), &["a", "year", "month"]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "disk_cache")]
+fn test_cache_to_disk() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-cache-to-disk");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let source = dir.join("source.parquet");
+    let cache_dir = dir.join("cache");
+
+    let write_source = |values: &[i32]| -> PolarsResult<()> {
+        let mut df = df!("a" => values)?;
+        ParquetWriter::new(std::fs::File::create(&source)?).finish(&mut df)?;
+        Ok(())
+    };
+    let query = |options: DiskCacheOptions| -> PolarsResult<DataFrame> {
+        LazyFrame::scan_parquet(&source, Default::default())?
+            .select([col("a") * lit(2)])
+            .cache_to_disk_with_options(&cache_dir, options)?
+            .collect()
+    };
+    let cached_files = || -> PolarsResult<Vec<std::path::PathBuf>> {
+        Ok(std::fs::read_dir(&cache_dir)?
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "parquet"))
+            .collect())
+    };
+    let options = DiskCacheOptions {
+        key: Some("doubled".into()),
+        ..Default::default()
+    };
+
+    write_source(&[1, 2, 3])?;
+    let out = query(options.clone())?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(2), Some(4), Some(6)]
+    );
+    let files = cached_files()?;
+    assert_eq!(files.len(), 1);
+    let modified = std::fs::metadata(&files[0])?.modified()?;
+
+    // Unchanged source: the cached file is read and not rewritten.
+    let out = query(options.clone())?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(2), Some(4), Some(6)]
+    );
+    assert_eq!(std::fs::metadata(&files[0])?.modified()?, modified);
+
+    // Changed source: the entry is invalidated.
+    write_source(&[1, 2, 3, 4])?;
+    let out = query(options)?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(2), Some(4), Some(6), Some(8)]
+    );
+
+    // Exceeding the max size evicts the older entry.
+    let out = query(DiskCacheOptions {
+        key: Some("other".into()),
+        max_size: Some(1),
+    })?;
+    assert_eq!(out.height(), 4);
+    assert_eq!(cached_files()?.len(), 1);
+
+    // Plans that only differ in their in-memory data don't share an entry.
+    let in_memory = |values: &[i32]| -> PolarsResult<DataFrame> {
+        df!("a" => values)?
+            .lazy()
+            .cache_to_disk(&cache_dir, None)?
+            .collect()
+    };
+    assert_eq!(
+        in_memory(&[1, 2])?.column("a")?.i32()?.to_vec(),
+        [Some(1), Some(2)]
+    );
+    assert_eq!(
+        in_memory(&[3, 4])?.column("a")?.i32()?.to_vec(),
+        [Some(3), Some(4)]
+    );
+
+    // Every `cache` call gets a new id, but rebuilding the same plan still hits the entry.
+    let with_cache = || -> PolarsResult<DataFrame> {
+        LazyFrame::scan_parquet(&source, Default::default())?
+            .cache()
+            .cache_to_disk(&cache_dir, None)?
+            .collect()
+    };
+    assert_eq!(with_cache()?.height(), 4);
+    let n_files = cached_files()?.len();
+    assert_eq!(with_cache()?.height(), 4);
+    assert_eq!(cached_files()?.len(), n_files);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "new_streaming",
+    feature = "csv",
+    feature = "ipc",
+    feature = "json",
+    feature = "parquet"
+))]
+fn test_sink_rotating_files() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-sink-rotating-files");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let df = df!("a" => (0..10).collect::<Vec<i32>>())?;
+
+    for ext in ["csv", "ndjson", "ipc", "parquet"] {
+        let target = SinkTarget::Path(Arc::new(dir.join(format!("out.{ext}"))));
+        let sink_options = SinkOptions {
+            max_rows_per_file: Some(4),
+            file_name_template: Some(format!("out_{{:03}}.{ext}").into()),
+            ..Default::default()
+        };
+        let lf = df.clone().lazy();
+        let lf = match ext {
+            "csv" => lf.sink_csv(target, Default::default(), None, sink_options)?,
+            "ndjson" => lf.sink_json(target, Default::default(), None, sink_options)?,
+            "ipc" => lf.sink_ipc(target, Default::default(), None, sink_options)?,
+            _ => lf.sink_parquet(target, Default::default(), None, sink_options)?,
+        };
+        lf.collect_with_engine(Engine::Streaming)?;
+
+        // Every file is complete on its own and the temporary files are gone.
+        let mut values = Vec::new();
+        for (file_idx, height) in [4, 4, 2].into_iter().enumerate() {
+            let path = dir.join(format!("out_{file_idx:03}.{ext}"));
+            let out = match ext {
+                "csv" => LazyCsvReader::new(&path).finish()?,
+                "ndjson" => LazyJsonLineReader::new(&path).finish()?,
+                "ipc" => LazyFrame::scan_ipc(&path, Default::default())?,
+                _ => LazyFrame::scan_parquet(&path, Default::default())?,
+            }
+            .select([col("a").cast(DataType::Int32)])
+            .collect()?;
+            assert_eq!(out.height(), height);
+            values.extend(out.column("a")?.i32()?.into_no_null_iter());
+        }
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+        assert!(!dir.join(format!("out_003.{ext}")).exists());
+    }
+
+    let names = std::fs::read_dir(&dir)?
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names.len(), 12);
+    assert!(names.iter().all(|name| !name.ends_with(".tmp")));
+
+    // An empty result still writes a file with the CSV header.
+    let target = SinkTarget::Path(Arc::new(dir.join("empty.csv")));
+    let sink_options = SinkOptions {
+        max_rows_per_file: Some(4),
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .filter(col("a").lt(lit(0)))
+        .sink_csv(target, Default::default(), None, sink_options)?
+        .collect_with_engine(Engine::Streaming)?;
+    let out = LazyCsvReader::new(dir.join("empty_00000.csv"))
+        .finish()?
+        .collect()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(out.get_column_names(), &["a"]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_hive_partition_columns_in_some_files() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-hive-partition-columns-in-some-files");
+    let _ = std::fs::remove_dir_all(&dir);
+    let write = |part: &str, mut df: DataFrame| -> PolarsResult<()> {
+        let part_dir = dir.join(part);
+        std::fs::create_dir_all(&part_dir)?;
+        ParquetWriter::new(std::fs::File::create(part_dir.join("data.parquet"))?)
+            .finish(&mut df)?;
+        Ok(())
+    };
+    // Only some files contain the partition columns, with values that differ from the paths.
+    write("flag=True/b=1", df!("a" => [1i64])?)?;
+    write("flag=True/b=02", df!("a" => [2i64], "b" => [20i64])?)?;
+    write("flag=false/b=3", df!("a" => [3i64], "flag" => [true])?)?;
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            schema: Some(Arc::new(Schema::from_iter([
+                Field::new("flag".into(), DataType::Boolean),
+                Field::new("b".into(), DataType::Int64),
+            ]))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(&dir, args)?;
+    let columns = [col("a"), col("flag"), col("b")];
+
+    // The values from the paths take precedence over the columns in the files.
+    let out = lf
+        .clone()
+        .select(columns.clone())
+        .sort(["a"], Default::default())
+        .collect()?;
+    let expected = df!(
+        "a" => [1i64, 2, 3],
+        "flag" => [true, true, false],
+        "b" => [1i64, 2, 3],
+    )?;
+    assert!(out.equals(&expected), "{out:?}");
+
+    let out = lf
+        .filter(col("flag").eq(lit(true)).and(col("b").eq(lit(2i64))))
+        .select(columns)
+        .collect()?;
+    assert!(out.equals(&expected.slice(1, 1)), "{out:?}");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_field_metadata() -> PolarsResult<()> {
+    use polars_core::chunked_array::cast::CastOptions;
+
+    let path = std::env::temp_dir().join("polars-test-field-metadata.parquet");
+    let md = Arc::new(std::collections::BTreeMap::from([(
+        PlSmallStr::from_static("unit"),
+        PlSmallStr::from_static("celsius"),
+    )]));
+    let temp = Series::new("temp".into(), [22.1, 19.9]).with_metadata(Some(md.clone()));
+    let mut df = DataFrame::new(vec![Column::new("station".into(), [1, 2]), temp.into()])?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let mut lf = LazyFrame::scan_parquet(&path, Default::default())?
+        .filter(col("station").gt(lit(1)))
+        .select([
+            col("station"),
+            col("temp").cast(DataType::Float32).alias("temperature"),
+            col("temp")
+                .cast_dropping_metadata(DataType::Float32, CastOptions::NonStrict)
+                .alias("plain"),
+            (col("temp") * lit(2.0)).alias("doubled"),
+        ]);
+    let schema = lf.collect_schema()?;
+    let metadata = schema
+        .iter_names()
+        .map(|name| (name.as_str(), schema.field_metadata(name).cloned()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        metadata,
+        [
+            ("station", None),
+            ("temperature", Some(md.clone())),
+            ("plain", None),
+            ("doubled", None)
+        ]
+    );
+
+    let out = lf.collect()?;
+    assert_eq!(out.column("temperature")?.field().metadata, Some(md));
+    assert_eq!(out.column("plain")?.field().metadata, None);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "asof_join"))]
+fn test_scan_parquet_sorting_columns_asof_join() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let write = |dir: &std::path::Path, parts: [[i64; 3]; 2]| -> PolarsResult<()> {
+        std::fs::create_dir_all(dir)?;
+        for (i, part) in parts.into_iter().enumerate() {
+            let mut ts = Series::new("ts".into(), part);
+            ts.set_sorted_flag(IsSorted::Ascending);
+            let mut df = DataFrame::new(vec![ts.into()])?;
+            ParquetWriter::new(std::fs::File::create(dir.join(format!("{i}.parquet")))?)
+                .finish(&mut df)?;
+        }
+        Ok(())
+    };
+    let scan = |dir: &std::path::Path| {
+        LazyFrame::scan_parquet(dir.join("*.parquet"), ScanArgsParquet::default())
+    };
+
+    // The files are read in path order and continue each other, so the scanned column is
+    // flagged as sorted.
+    let dir = std::env::temp_dir().join("polars-test-scan-sorting-columns");
+    write(&dir, [[1, 2, 3], [4, 5, 6]])?;
+    let out = scan(&dir)?.collect()?;
+    assert_eq!(out.column("ts")?.is_sorted_flag(), IsSorted::Ascending);
+
+    // The flag reaches the asof join, which relies on it for the sortedness check.
+    let right = df!("ts" => [2i64, 5], "v" => ["a", "b"])?;
+    let out = scan(&dir)?
+        .join_builder()
+        .with(right.lazy())
+        .on([col("ts")])
+        .how(JoinType::AsOf(AsOfOptions {
+            check_sortedness: true,
+            ..Default::default()
+        }))
+        .finish()
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.str()?),
+        [None, Some("a"), Some("a"), Some("a"), Some("b"), Some("b")]
+    );
+
+    // Every file is sorted on its own, but in path order they are not.
+    let dir = std::env::temp_dir().join("polars-test-scan-sorting-columns-unordered");
+    write(&dir, [[4, 5, 6], [1, 2, 3]])?;
+    let out = scan(&dir)?.collect()?;
+    assert_eq!(out.column("ts")?.is_sorted_flag(), IsSorted::Not);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_integer_downcast() -> PolarsResult<()> {
+    use polars_core::chunked_array::cast::CastOptions;
+
+    let path = std::env::temp_dir().join("polars-test-scan-integer-downcast.parquet");
+    let mut df = df!("a" => [-200i64, 1, 300])?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let scan = |integer_downcast| {
+        let args = ScanArgsParquet {
+            schema: Some(Arc::new(Schema::from_iter([Field::new(
+                "a".into(),
+                DataType::Int8,
+            )]))),
+            cast_columns_policy: CastColumnsPolicy {
+                integer_downcast,
+                ..CastColumnsPolicy::ERROR_ON_MISMATCH
+            },
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args)?.collect()
+    };
+
+    assert!(scan(None).is_err());
+    assert!(scan(Some(CastOptions::Strict)).is_err());
+    let out = scan(Some(CastOptions::Saturating))?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i8()?),
+        [Some(-128), Some(1), Some(127)]
+    );
+    let out = scan(Some(CastOptions::NonStrict))?;
+    assert_eq!(Vec::from(out.column("a")?.i8()?), [None, Some(1), None]);
+    Ok(())
+}
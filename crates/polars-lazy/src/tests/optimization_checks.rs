@@ -652,3 +652,208 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_constant_folding() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1i32, 2, 3],
+    ]?;
+    let q = df.lazy().select([
+        lit(1).is_null().alias("a"),
+        col("x")
+            .cast(DataType::Float64)
+            .cast(DataType::Float64)
+            .alias("b"),
+        (lit(2) + lit(3)).alias("c"),
+    ]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let IR::Select { expr, .. } = lp_arena.get(lp) else {
+        unreachable!()
+    };
+    assert!(matches!(expr_arena.get(expr[0].node()), AExpr::Literal(_)));
+    let AExpr::Cast { expr: input, .. } = expr_arena.get(expr[1].node()) else {
+        unreachable!()
+    };
+    assert!(matches!(expr_arena.get(*input), AExpr::Column(_)));
+    assert!(matches!(expr_arena.get(expr[2].node()), AExpr::Literal(_)));
+
+    let out = q.collect()?;
+    assert_eq!(
+        out,
+        df![
+            "a" => [false, false, false],
+            "b" => [1.0, 2.0, 3.0],
+            "c" => [5i32, 5, 5],
+        ]?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dead_column_elimination() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1i32, 2, 3],
+        "y" => [1i32, 1, 1],
+    ]?;
+    // The first `y` is overwritten before it is used.
+    let q = df
+        .lazy()
+        .with_column((col("x") * lit(2)).alias("y"))
+        .with_column((col("x") + lit(1)).alias("z"))
+        .with_column(lit(0).alias("y"));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let n_exprs: usize = (&lp_arena)
+        .iter(lp)
+        .map(|(_, lp)| match lp {
+            IR::HStack { exprs, .. } => exprs.len(),
+            _ => 0,
+        })
+        .sum();
+    assert_eq!(n_exprs, 2);
+
+    let out = q.collect()?;
+    assert_eq!(
+        out,
+        df![
+            "x" => [1i32, 2, 3],
+            "y" => [0i32, 0, 0],
+            "z" => [2i32, 3, 4],
+        ]?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder_build_side() -> PolarsResult<()> {
+    let small = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"],
+    ]?;
+    let large = df![
+        "a" => [1i32, 2, 3, 4, 5],
+        "c" => [1.0f64, 2.0, 3.0, 4.0, 5.0],
+    ]?;
+    let q = small.clone().lazy().join(
+        large.clone().lazy(),
+        [col("a")],
+        [col("a")],
+        JoinType::Inner.into(),
+    );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let IR::SimpleProjection { input, .. } = lp_arena.get(lp) else {
+        unreachable!()
+    };
+    let IR::Join { input_right, .. } = lp_arena.get(*input) else {
+        unreachable!()
+    };
+    // The smaller frame is on the build side.
+    assert!(matches!(
+        lp_arena.get(*input_right),
+        IR::DataFrameScan { df, .. } if df.height() == 2
+    ));
+
+    let out = q.collect()?.sort(["a"], Default::default())?;
+    let expected = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"],
+        "c" => [1.0f64, 2.0],
+    ]?;
+    assert_eq!(out, expected);
+
+    // The smaller frame already is on the build side.
+    let q = large.clone().lazy().join(
+        small.clone().lazy(),
+        [col("a")],
+        [col("a")],
+        JoinType::Inner.into(),
+    );
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    assert!(matches!(lp_arena.get(lp), IR::Join { .. }));
+
+    // Hints take precedence over the estimates.
+    let q = small
+        .lazy()
+        .join_builder()
+        .with(large.lazy())
+        .on([col("a")])
+        .how(JoinType::Inner)
+        .row_hints(100, 1)
+        .finish();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    assert!(matches!(lp_arena.get(lp), IR::Join { .. }));
+    let out = q.collect()?.sort(["a"], Default::default())?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+fn test_filter_merge() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2, 3, 4],
+        "b" => [4i32, 3, 2, 1],
+    ]?;
+    let q = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .filter(col("b").gt(lit(1)));
+    let count_filters = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena)
+            .iter(lp)
+            .filter(|(_, lp)| matches!(lp, IR::Filter { .. }))
+            .count()
+    };
+    assert_eq!(count_filters(q.clone()), 1);
+    // Without predicate pushdown the filters are merged by the filter merge rule.
+    assert_eq!(count_filters(q.clone().with_predicate_pushdown(false)), 1);
+
+    let out = q.collect()?;
+    assert_eq!(
+        out,
+        df![
+            "a" => [2i32, 3],
+            "b" => [3i32, 2],
+        ]?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_filter_merge_fallible() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2, 3],
+        "s" => ["1", "x", "3"],
+    ]?;
+    // The strict cast only fails on the row that the first filter removes, so the filters are not
+    // merged.
+    let q = df
+        .lazy()
+        .filter(col("a").neq(lit(2)))
+        .filter(col("s").strict_cast(DataType::Int32).gt(lit(1)))
+        .with_predicate_pushdown(false);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let n_filters = (&lp_arena)
+        .iter(lp)
+        .filter(|(_, lp)| matches!(lp, IR::Filter { .. }))
+        .count();
+    assert_eq!(n_filters, 2);
+
+    let out = q.collect()?;
+    assert_eq!(
+        out,
+        df![
+            "a" => [3i32],
+            "s" => ["3"],
+        ]?
+    );
+    Ok(())
+}
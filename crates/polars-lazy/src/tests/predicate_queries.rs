@@ -314,3 +314,38 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_filter_conjuncts_over_chunks() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1i32, 2, 3, 4],
+        "b" => [Some(10i32), None, Some(30), Some(40)],
+    ]?;
+    // The second chunk is rejected by the first conjunct entirely.
+    df.vstack_mut(&df![
+        "a" => [-1i32, -2],
+        "b" => [50i32, 60],
+    ]?)?;
+    df.vstack_mut(&df![
+        "a" => [5i32, 6],
+        "b" => [Some(70i32), None],
+    ]?)?;
+    assert_eq!(df.first_col_n_chunks(), 3);
+
+    let out = df
+        .lazy()
+        .filter(
+            col("a")
+                .gt(lit(0))
+                .and(col("b").is_not_null())
+                .and(col("b").gt(lit(20))),
+        )
+        .collect()?;
+
+    let expected = df![
+        "a" => [3i32, 4, 5],
+        "b" => [30i32, 40, 70],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -639,6 +639,115 @@ fn test_lazy_fill_null() {
     assert_eq!(out.get_column_names(), vec!["a", "b"])
 }
 
+#[test]
+#[cfg(feature = "interpolate_by")]
+fn test_lazy_fill_null_with() -> PolarsResult<()> {
+    use polars_ops::prelude::DataFrameOps;
+
+    let df = df! {
+        "t" => [0i64, 1, 3, 4],
+        "a" => [Some(1.0), None, Some(4.0), None],
+        "b" => [None, Some(2), None, Some(4)],
+        "c" => [None, Some(1), None, None],
+    }?;
+
+    let mut strategies = PlIndexMap::new();
+    strategies.insert(
+        "a".into(),
+        ColumnFillNullStrategy::InterpolateBy("t".into()),
+    );
+    strategies.insert("b".into(), FillNullStrategy::Backward(None).into());
+    let lf = df.clone().lazy().fill_null_with(strategies.clone())?;
+    // The expressions follow the insertion order of `strategies`.
+    let DslPlan::HStack { exprs, .. } = &lf.logical_plan else {
+        unreachable!()
+    };
+    let names = exprs
+        .iter()
+        .map(polars_plan::utils::expr_output_name)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(names, ["a", "b"]);
+    let out = lf.collect()?;
+    let expected = df! {
+        "t" => [0i64, 1, 3, 4],
+        "a" => [Some(1.0), Some(2.0), Some(4.0), None],
+        "b" => [Some(2), Some(2), Some(4), Some(4)],
+        "c" => [None, Some(1), None, None],
+    }?;
+    assert!(out.equals_missing(&expected));
+    assert!(out.equals_missing(&df.fill_null_with(&strategies)?));
+
+    // Patterns are not expanded.
+    for pattern in ["*", "^.*$"] {
+        let mut strategies = PlIndexMap::new();
+        strategies.insert(pattern.into(), FillNullStrategy::Zero.into());
+        assert!(df.clone().lazy().fill_null_with(strategies).is_err());
+    }
+
+    strategies.insert("d".into(), FillNullStrategy::Zero.into());
+    assert!(df.lazy().fill_null_with(strategies)?.collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "round_series")]
+fn test_winsorize() -> PolarsResult<()> {
+    let df = df!["a" => [100, 1, 2, 3, 4, 5, 6, 7, 8, -50]]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").winsorize(0.1, 0.9)])
+        .collect()?;
+    assert!(out.equals(&df!["a" => [8, 1, 2, 3, 4, 5, 6, 7, 8, 1]]?));
+    assert!(
+        df.lazy()
+            .select([col("a").winsorize(0.9, 0.1)])
+            .collect()
+            .is_err()
+    );
+
+    // Nulls are ignored for the quantiles and stay null.
+    let df = df!["b" => [Some(1.5), None, Some(-10.0), Some(2.5), Some(3.0)]]?;
+    let out = df
+        .lazy()
+        .select([col("b").winsorize(0.25, 1.0)])
+        .collect()?;
+    let expected = df!["b" => [Some(1.5), None, Some(1.5), Some(2.5), Some(3.0)]]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_close")]
+fn test_is_close() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(0.1 + 0.2), Some(1.0), None, Some(f64::NAN)],
+        "b" => [Some(0.3), Some(1.5), Some(1.0), Some(f64::NAN)],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").is_close(col("b"), 1e-9, 0.0, false).alias("rel"),
+            col("a").is_close(col("b"), 1e-9, 0.0, true).alias("nans"),
+            col("a").is_close(lit(1), 0.0, 0.5, false).alias("lit"),
+        ])
+        .collect()?;
+    let expected = df![
+        "rel" => [Some(true), Some(false), None, Some(false)],
+        "nans" => [Some(true), Some(false), None, Some(true)],
+        "lit" => [Some(false), Some(true), None, Some(false)],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df
+        .lazy()
+        .filter(col("a").is_close(col("b"), 0.0, 0.6, false))
+        .collect()?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_double_projection() {
     let df = df! {
@@ -737,6 +846,135 @@ fn test_lazy_group_by_apply() {
         .unwrap();
 }
 
+#[test]
+fn test_lazy_group_by_map_groups() -> PolarsResult<()> {
+    let df = fruits_cars();
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("fruits".into(), DataType::String),
+        Field::new("A".into(), DataType::Int32),
+    ]));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .map_groups(schema.clone(), true, |df| {
+            Ok(df.select(["A"])?.head(Some(1)))
+        })
+        .collect()?;
+    let expected = df! {
+        "fruits" => ["banana", "apple"],
+        "A" => [1, 3],
+    }?;
+    assert!(out.equals(&expected));
+
+    // The slice applies to the result, not to the groups.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .map_groups(schema.clone(), true, |df| df.select(["fruits", "A"]))
+        .slice(1, 2)
+        .collect()?;
+    let expected = df! {
+        "fruits" => ["banana", "banana"],
+        "A" => [2, 5],
+    }?;
+    assert!(out.equals(&expected));
+
+    // The output is validated against the declared schema.
+    let out = df
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .map_groups(schema, false, |df| df.select(["A"]))
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_lazy_dry_run() -> PolarsResult<()> {
+    let lf = fruits_cars()
+        .lazy()
+        .group_by([col("fruits")])
+        .agg([col("A").sum().alias("sum")]);
+    let schema = lf.dry_run()?;
+    assert_eq!(
+        schema,
+        Schema::from_iter([
+            Field::new("fruits".into(), DataType::String),
+            Field::new("sum".into(), DataType::Int32),
+        ])
+    );
+
+    assert!(fruits_cars().lazy().select([col("foo")]).dry_run().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_lazy_json_roundtrip() -> PolarsResult<()> {
+    let lf = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .group_by_stable([col("fruits")])
+        .agg([col("B").sum()]);
+    let json = lf.to_json()?;
+    let restored = LazyFrame::from_json(&json)?;
+    assert!(restored.collect()?.equals(&lf.collect()?));
+
+    assert!(LazyFrame::from_json("{").is_err());
+
+    // The DSL version is checked like in the binary format.
+    let (major, minor) = polars_plan::dsl::DSL_VERSION;
+    let version = format!("\"dsl_version\":[{major},{minor}]");
+    assert!(json.contains(&version));
+    let other_major = json.replace(&version, &format!("\"dsl_version\":[{},0]", major + 1));
+    let err = LazyFrame::from_json(&other_major).unwrap_err();
+    assert!(err.to_string().contains("different major version"));
+    let unknown_field = json.replace(&version, &format!("{version},\"foo\":1"));
+    let err = LazyFrame::from_json(&unknown_field).unwrap_err();
+    assert!(err.to_string().contains("unknown fields"));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_collect_into() -> PolarsResult<()> {
+    let mut target = DataFrame::empty();
+    for n in [2, 4] {
+        fruits_cars()
+            .lazy()
+            .select([col("A"), col("B")])
+            .limit(n)
+            .collect_into(&mut target)?;
+        assert_eq!(target.shape(), (n as usize, 2));
+        assert_eq!(target.get_column_names(), &["A", "B"]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_lazy_profile() -> PolarsResult<()> {
+    let (out, info) = fruits_cars().lazy().select([col("A").sum()]).profile()?;
+    assert_eq!(out.height(), 1);
+
+    let nodes = &info.nodes;
+    assert_eq!(
+        nodes.get_column_names(),
+        &["node_name", "duration_ns", "rows_in", "rows_out"]
+    );
+    let rows_in = nodes.column("rows_in")?.u64()?;
+    let rows_out = nodes.column("rows_out")?.u64()?;
+    assert!(
+        rows_in
+            .into_iter()
+            .zip(rows_out)
+            .any(|rows| rows == (Some(5), Some(1)))
+    );
+    assert!(info.timings.height() > nodes.height());
+    Ok(())
+}
+
 #[test]
 fn test_lazy_shift_and_fill() {
     let df = df! {
@@ -1206,6 +1444,111 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "cross_join")]
+#[test]
+fn test_with_external() -> PolarsResult<()> {
+    let sales = df![
+        "region" => ["north", "south", "north"],
+        "amount" => [10.0, 30.0, 60.0]
+    ]?;
+    let totals = sales.clone().lazy().select([col("amount").sum()]);
+
+    let mut lf = sales
+        .clone()
+        .lazy()
+        .with_external("totals", totals.clone())?
+        .with_column((col("amount") / external("totals", "amount")).alias("share"));
+    let schema = lf.collect_schema()?;
+    assert_eq!(
+        schema.iter_names().collect::<Vec<_>>(),
+        ["region", "amount", "share"]
+    );
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("share")?.f64()?),
+        [Some(0.1), Some(0.3), Some(0.6)]
+    );
+
+    // Wildcards, selectors and `last` don't see the external columns.
+    let out = sales
+        .clone()
+        .lazy()
+        .with_external("totals", totals.clone())?
+        .select([all().name().suffix("_x"), nth(-1).alias("last")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["region_x", "amount_x", "last"]);
+
+    // The reference is a scalar per group.
+    let out = sales
+        .clone()
+        .lazy()
+        .with_external("totals", totals.clone())?
+        .group_by_stable([col("region")])
+        .agg([(col("amount").sum() / external("totals", "amount")).alias("share")])
+        .collect()?;
+    assert_eq!(out.column("share")?.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(out.column("share")?.f64()?),
+        [Some(0.7), Some(0.3)]
+    );
+
+    // `collect_all` drops the external columns from every output.
+    let lf = sales.clone().lazy().with_external("totals", totals)?;
+    let out = LazyFrame::collect_all_frames_with_engine(
+        vec![lf.clone(), lf],
+        Engine::InMemory,
+        OptFlags::default(),
+    )?;
+    for df in out {
+        assert_eq!(df.get_column_names(), ["region", "amount"]);
+    }
+
+    // The external frame must have a single row, which is checked while building the query.
+    let out = sales.clone().lazy().with_external("all", sales.lazy());
+    assert!(matches!(out, Err(PolarsError::ShapeMismatch(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_merge_sorted_nulls_last() -> PolarsResult<()> {
+    // One side is all null and the other has no nulls, so the placement is only known from
+    // `nulls_last`.
+    let left = df!["a" => [None::<i32>, None]]?;
+    let right = df!["a" => [1, 2]]?;
+
+    let streaming = cfg!(feature = "new_streaming").then_some(Engine::Streaming);
+    for engine in [Engine::InMemory].into_iter().chain(streaming) {
+        let out = left
+            .clone()
+            .lazy()
+            .merge_sorted_with_nulls_last(right.clone().lazy(), "a", true)?
+            .collect_with_engine(engine)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            [Some(1), Some(2), None, None]
+        );
+
+        let out = left
+            .clone()
+            .lazy()
+            .merge_sorted(right.clone().lazy(), "a")?
+            .collect_with_engine(engine)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            [None, None, Some(1), Some(2)]
+        );
+    }
+
+    // Nulls on the other end than declared are an error.
+    let out = df!["a" => [Some(1), None]]?
+        .lazy()
+        .merge_sorted(right.lazy(), "a")?
+        .collect_with_engine(Engine::InMemory);
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1628,6 +1971,88 @@ pub fn test_select_by_dtypes() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_selectors() -> PolarsResult<()> {
+    let df = df![
+        "bools" => [true, false, true],
+        "ints" => [1, 2, 3],
+        "strings" => ["a", "b", "c"],
+        "floats" => [1.0, 2.0, 3.0f32]
+    ]?;
+    let names = |mut lf: LazyFrame| -> PolarsResult<Vec<String>> {
+        Ok(lf
+            .collect_schema()?
+            .iter_names()
+            .map(|n| n.to_string())
+            .collect())
+    };
+
+    let lf = df.lazy();
+    assert_eq!(
+        names(lf.clone().select([sel::numeric().as_expr()]))?,
+        ["ints", "floats"]
+    );
+    assert_eq!(
+        names(lf.clone().select([sel::numeric().complement().as_expr()]))?,
+        ["bools", "strings"]
+    );
+    assert_eq!(
+        names(
+            lf.clone()
+                .select([sel::last(2).union(sel::first(1)).as_expr()])
+        )?,
+        ["bools", "strings", "floats"]
+    );
+    assert_eq!(
+        names(lf.clone().drop([sel::by_dtype([DataType::Boolean])]))?,
+        ["ints", "strings", "floats"]
+    );
+    #[cfg(feature = "regex")]
+    assert_eq!(
+        names(
+            lf.clone().select([sel::starts_with("s")
+                .union(sel::ends_with("ts"))
+                .difference(sel::integer())
+                .as_expr()])
+        )?,
+        ["strings", "floats"]
+    );
+
+    #[cfg(feature = "dtype-decimal")]
+    {
+        let decimals = lf.clone().with_column(
+            col("ints")
+                .cast(DataType::Decimal(Some(10), Some(2)))
+                .alias("d"),
+        );
+        assert_eq!(
+            names(decimals.select([sel::numeric().as_expr()]))?,
+            ["ints", "floats", "d"]
+        );
+    }
+    assert_eq!(
+        names(lf.clone().select([sel::first(5).as_expr()]))?,
+        ["bools", "ints", "strings", "floats"]
+    );
+    assert!(
+        lf.clone()
+            .select([sel::first(5).strict().as_expr()])
+            .collect_schema()
+            .is_err()
+    );
+
+    // Matching nothing is only an error for strict selectors.
+    let nothing = sel::by_dtype([DataType::Date]);
+    assert!(names(lf.clone().select([nothing.clone().as_expr()]))?.is_empty());
+    assert!(
+        lf.select([nothing.strict().as_expr()])
+            .collect_schema()
+            .is_err()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_binary_expr() -> PolarsResult<()> {
     // test panic in schema names
@@ -1708,6 +2133,35 @@ fn test_single_ranked_group() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "rank")]
+fn test_rank_by() -> PolarsResult<()> {
+    let df = df![
+        "group" => ["a", "b", "a", "b", "a"],
+        "value" => [3, 1, 1, 2, 2],
+    ]?;
+    let options = RankOptions {
+        method: RankMethod::Ordinal,
+        descending: false,
+    };
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("value").rank_by([col("group")], options, None)])
+        .collect()?;
+    let expected = df
+        .lazy()
+        .select([col("value").rank(options, None).over([col("group")])])
+        .collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(
+        Vec::from(out.column("value")?.idx()?),
+        &[Some(3), Some(1), Some(1), Some(2), Some(2)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "diff")]
 fn empty_df() -> PolarsResult<()> {
@@ -1978,3 +2432,705 @@ fn test_over_with_options_empty_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn test_collect_async() -> PolarsResult<()> {
+    use std::future::Future;
+    use std::sync::Mutex;
+    use std::sync::mpsc::channel;
+    use std::task::{Context, Poll, Waker};
+
+    fn block_on(mut fut: CollectFuture) -> PolarsResult<DataFrame> {
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = std::pin::Pin::new(&mut fut).poll(&mut cx) {
+                return out;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let out = block_on(df.clone().lazy().select([col("a").sum()]).collect_async())?;
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(6));
+
+    // Cancel while the first input of the union runs, the second input must not run.
+    let (started_tx, started_rx) = channel();
+    let (resume_tx, resume_rx) = channel::<()>();
+    let resume_rx = Mutex::new(resume_rx);
+    let blocking = df.clone().lazy().map(
+        move |df| {
+            started_tx.send(()).unwrap();
+            _ = resume_rx.lock().unwrap().recv();
+            Ok(df)
+        },
+        Default::default(),
+        None,
+        None,
+    );
+    let lf = concat(
+        [blocking, df.lazy()],
+        UnionArgs {
+            parallel: false,
+            ..Default::default()
+        },
+    )?;
+
+    let fut = lf.collect_async();
+    started_rx.recv().unwrap();
+    fut.cancel();
+    resume_tx.send(()).unwrap();
+    let err = block_on(fut).unwrap_err();
+    assert!(err.to_string().contains("query interrupted"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn test_with_compute_pool() -> PolarsResult<()> {
+    use std::sync::Barrier;
+
+    use polars_core::POOL;
+
+    // Both queries are in flight at the same time, and each sees only its own pool.
+    let barrier = Arc::new(Barrier::new(2));
+    let run = |n_threads: usize| {
+        let barrier = barrier.clone();
+        std::thread::spawn(move || {
+            df!["a" => [1, 2, 3]]?
+                .lazy()
+                .map(
+                    move |_| {
+                        barrier.wait();
+                        let n = POOL.install(rayon::current_num_threads);
+                        df!["n" => [n as u32]]
+                    },
+                    Default::default(),
+                    None,
+                    None,
+                )
+                .with_compute_pool(n_threads)?
+                .collect()
+        })
+    };
+
+    let small = run(2);
+    let large = run(3);
+    let small = small.join().unwrap()?;
+    let large = large.join().unwrap()?;
+    assert_eq!(small.column("n")?.u32()?.get(0), Some(2));
+    assert_eq!(large.column("n")?.u32()?.get(0), Some(3));
+
+    // Joins and concatenations run on the pool of their inputs.
+    let n_threads = POOL.current_num_threads() as u32 + 1;
+    let pool_size = || {
+        df!["n" => [0u32]].unwrap().lazy().map(
+            |_| df!["n" => [POOL.current_num_threads() as u32]],
+            Default::default(),
+            None,
+            None,
+        )
+    };
+    let other = df!["n" => [n_threads]]?.lazy();
+
+    let out = pool_size()
+        .with_compute_pool(n_threads as usize)?
+        .join(
+            other.clone(),
+            [col("n")],
+            [col("n")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .collect()?;
+    assert_eq!(out.height(), 1);
+
+    let out = concat(
+        [other, pool_size().with_compute_pool(n_threads as usize)?],
+        UnionArgs::default(),
+    )?
+    .collect()?;
+    assert_eq!(out.column("n")?.u32()?.get(1), Some(n_threads));
+
+    // Builder methods keep the pool, and every way of collecting runs on it.
+    let lf = pool_size()
+        .with_compute_pool(n_threads as usize)?
+        .with_column(col("n") + lit(0u32))
+        .select([col("n")]);
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("n")?.u32()?.get(0), Some(n_threads));
+    let (out, _) = lf.clone().profile()?;
+    assert_eq!(out.column("n")?.u32()?.get(0), Some(n_threads));
+    let out =
+        LazyFrame::collect_all_frames_with_engine(vec![lf], Engine::InMemory, OptFlags::default())?;
+    assert_eq!(out[0].column("n")?.u32()?.get(0), Some(n_threads));
+
+    Ok(())
+}
+
+#[test]
+fn test_with_timeout() -> PolarsResult<()> {
+    use std::time::{Duration, Instant};
+
+    let slow = |sleep: Duration| {
+        df!["a" => [1, 2, 3]].unwrap().lazy().map(
+            move |df| {
+                std::thread::sleep(sleep);
+                Ok(df)
+            },
+            Default::default(),
+            None,
+            None,
+        )
+    };
+
+    // The timeout is kept by builder methods.
+    let err = slow(Duration::from_millis(200))
+        .with_timeout(Duration::from_millis(20))
+        .select([col("a")])
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Timeout(_)));
+
+    // A running operator doesn't hold back the error.
+    let start = Instant::now();
+    let err = slow(Duration::from_secs(5))
+        .with_timeout(Duration::from_millis(20))
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Timeout(_)));
+    assert!(start.elapsed() < Duration::from_secs(4));
+
+    let out = slow(Duration::from_millis(200))
+        .with_timeout(Duration::from_secs(60))
+        .collect()?;
+    assert_eq!(out.height(), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_name_prefix_fields_recursive() -> PolarsResult<()> {
+    let lf = df!["a" => [1, 2], "b" => [3, 4]]?
+        .lazy()
+        .select([as_struct(vec![col("a"), as_struct(vec![col("b")]).alias("inner")]).alias("s")]);
+
+    let inner = DataType::Struct(vec![Field::new("b".into(), DataType::Int32)]);
+    let out = lf
+        .clone()
+        .select([col("s").name().prefix_fields("x_")])
+        .collect_schema()?;
+    assert_eq!(
+        out.get("s"),
+        Some(&DataType::Struct(vec![
+            Field::new("x_a".into(), DataType::Int32),
+            Field::new("x_inner".into(), inner),
+        ]))
+    );
+
+    // The renamed nested fields resolve at plan time.
+    let out = lf
+        .clone()
+        .select([col("s").name().suffix_fields_recursive("_y")])
+        .unnest(["s"])
+        .select([col("a_y"), col("inner_y").struct_().field_by_name("b_y")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["a_y", "b_y"]);
+    assert_eq!(Vec::from(out.column("b_y")?.i32()?), [Some(3), Some(4)]);
+
+    let mapper: FieldsNameMapper = Arc::new(|name: &str| name.to_uppercase().into());
+    let out = lf
+        .select([col("s").name().map_fields_recursive(mapper)])
+        .unnest(["s"])
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["A", "INNER"]);
+    assert_eq!(
+        out.column("INNER")?.dtype(),
+        &DataType::Struct(vec![Field::new("B".into(), DataType::Int32)])
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_with_options() -> PolarsResult<()> {
+    let lf = df!["a" => [1, 2], "b" => [3, 4], "c" => [5, 6]]?
+        .lazy()
+        .select([
+            col("a"),
+            as_struct(vec![col("c").alias("a"), col("b")]).alias("meta"),
+            col("c"),
+        ]);
+
+    let options = UnnestOptions {
+        collision: CollisionPolicy::RenameWithPrefix,
+        insert_at: InsertionPoint::End,
+        ..Default::default()
+    };
+    let out = lf.clone().unnest_with_options(["meta"], options);
+    let schema = out.clone().collect_schema()?;
+    assert_eq!(
+        schema.iter_names().cloned().collect::<Vec<_>>(),
+        ["a", "c", "meta_a", "b"]
+    );
+    // Only select the renamed field, so the colliding column must not be pruned.
+    let df = out.select([col("meta_a")]).collect()?;
+    assert_eq!(Vec::from(df.column("meta_a")?.i32()?), [Some(5), Some(6)]);
+
+    let options = UnnestOptions {
+        collision: CollisionPolicy::Overwrite,
+        ..Default::default()
+    };
+    let df = lf
+        .clone()
+        .unnest_with_options(["meta"], options)
+        .filter(col("a").gt(lit(5)))
+        .collect()?;
+    assert_eq!(df.get_column_names(), ["a", "b", "c"]);
+    assert_eq!(Vec::from(df.column("a")?.i32()?), [Some(6)]);
+
+    assert!(lf.unnest(["meta"]).collect().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_expr_plugin() -> PolarsResult<()> {
+    register_expr_plugin(
+        "test_times_two",
+        ExprPluginVTable::new(|fields| Ok(fields[0].clone()), |inputs| Ok(&inputs[0] * 2)),
+    );
+    register_expr_plugin(
+        "test_max_minus_min",
+        ExprPluginVTable::new(
+            |fields| Ok(fields[0].clone()),
+            |inputs| {
+                let s = &inputs[0];
+                let out = (&s.max_reduce()?.into_series(s.name().clone())
+                    - &s.min_reduce()?.into_series(s.name().clone()))?;
+                Ok(out)
+            },
+        ),
+    );
+    let elementwise = PluginCallOptions {
+        elementwise: true,
+        ..Default::default()
+    };
+    let scalar = PluginCallOptions {
+        returns_scalar: true,
+        ..Default::default()
+    };
+
+    let df = df![
+        "g" => ["a", "b", "a", "b"],
+        "v" => [1, 5, 4, 2],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            Expr::plugin_call("test_times_two", vec![col("v")], elementwise)?,
+            Expr::plugin_call("test_max_minus_min", vec![col("v")], scalar)?
+                .over([col("g")])
+                .alias("range"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        [Some(2), Some(10), Some(8), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("range")?.i32()?),
+        [Some(3), Some(3), Some(3), Some(3)]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([Expr::plugin_call(
+            "test_max_minus_min",
+            vec![col("v")],
+            scalar,
+        )?])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i32()?), [Some(3), Some(3)]);
+
+    let missing = df
+        .lazy()
+        .select([Expr::plugin_call(
+            "test_missing",
+            vec![col("v")],
+            elementwise,
+        )?])
+        .collect();
+    assert!(missing.is_err());
+
+    // Conflicting options are rejected when the expression is built.
+    let conflicting = PluginCallOptions {
+        elementwise: true,
+        returns_scalar: true,
+    };
+    assert!(Expr::plugin_call("test_times_two", vec![col("v")], conflicting).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rle")]
+fn test_rle_over_and_decode() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b", "b"],
+        "v" => [1, 1, 1, 2, 1, 1],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").rle_id().over([col("g")]).alias("id")])
+        .collect()?;
+    // Every window numbers its own runs, starting at zero.
+    assert_eq!(
+        Vec::from(out.column("id")?.idx()?),
+        [0, 0, 0, 1, 0, 0].map(Some)
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").rle_with(DataType::UInt64).alias("rle")])
+        .collect()?;
+    let lengths = out.column("rle")?.struct_()?.field_by_name("len")?;
+    assert_eq!(Vec::from(lengths.u64()?), [Some(3), Some(1), Some(2)]);
+
+    let out = df.lazy().select([col("v").rle().rle_decode()]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        [1, 1, 1, 2, 1, 1].map(Some)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binary_encoding")]
+fn test_binary_slice_reinterpret() -> PolarsResult<()> {
+    // Big-endian sensor frames: a 2-byte header, an i16 temperature and a f32 pressure.
+    let frames: [&[u8]; 3] = [
+        &[0xAB, 0x01, 0xFF, 0x38, 0x44, 0x7D, 0x40, 0x00],
+        &[0xAB, 0x01, 0x00, 0xFA, 0x44, 0x7A, 0x00, 0x00],
+        &[0xAB, 0x01, 0x00],
+    ];
+    let df = df!["frame" => frames]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("frame")
+                .binary()
+                .slice(lit(2), lit(2))
+                .binary()
+                .reinterpret(DataType::Int16, false, false)
+                .alias("temperature"),
+            col("frame")
+                .binary()
+                .slice(lit(-4), lit(NULL))
+                .binary()
+                .reinterpret(DataType::Float32, false, false)
+                .alias("pressure"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("temperature")?.i16()?),
+        [Some(-200), Some(250), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("pressure")?.f32()?),
+        [Some(1013.0), Some(1000.0), None]
+    );
+
+    let strict = df
+        .lazy()
+        .select([col("frame")
+            .binary()
+            .reinterpret(DataType::Int64, false, true)])
+        .collect();
+    assert!(strict.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-array", feature = "array_to_struct"))]
+fn test_array_to_struct_and_slice() -> PolarsResult<()> {
+    use polars_ops::chunked_array::array::arr_names_struct_name_gen;
+
+    let pos = Series::new(
+        "pos".into(),
+        &[
+            Series::new("".into(), [1.0, 2.0, 3.0]),
+            Series::new("".into(), [4.0, 5.0, 6.0]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+    let names = arr_names_struct_name_gen(vec!["x".into(), "y".into(), "z".into()]);
+    let mut lf = DataFrame::new(vec![pos.into()])?.lazy().select([
+        col("pos").arr().to_struct(Some(names))?.alias("xyz"),
+        col("pos").arr().slice(lit(-2), lit(NULL)).alias("yz"),
+        col("pos")
+            .arr()
+            .slice(lit(0), lit(2).cast(DataType::Int64))
+            .alias("xy"),
+    ]);
+
+    let schema = lf.collect_schema()?;
+    let DataType::Struct(fields) = schema.get("xyz").unwrap() else {
+        panic!()
+    };
+    let names = fields.iter().map(|f| f.name().as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["x", "y", "z"]);
+    assert_eq!(
+        schema.get("yz"),
+        Some(&DataType::Array(Box::new(DataType::Float64), 2))
+    );
+    assert_eq!(
+        schema.get("xy"),
+        Some(&DataType::List(Box::new(DataType::Float64)))
+    );
+
+    let out = lf.collect()?;
+    let xyz = out.column("xyz")?.struct_()?.clone().unnest();
+    assert_eq!(Vec::from(xyz.column("z")?.f64()?), [Some(3.0), Some(6.0)]);
+    let yz = out.column("yz")?.array()?.get_inner();
+    assert_eq!(
+        Vec::from(yz.f64()?),
+        [Some(2.0), Some(3.0), Some(5.0), Some(6.0)]
+    );
+    let xy = out.column("xy")?.list()?.get_inner();
+    assert_eq!(
+        Vec::from(xy.f64()?),
+        [Some(1.0), Some(2.0), Some(4.0), Some(5.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+fn test_rolling_map_frame() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1.0, 2.0, 3.0, 4.0],
+        "y" => [10.0, 20.0, 30.0, 40.0],
+    ]?;
+
+    // The sum of x * y over the last 2 rows.
+    let f = Arc::new(|w: &DataFrame| -> PolarsResult<AnyValue<'static>> {
+        let x = w.column("x")?.as_materialized_series();
+        let y = w.column("y")?.as_materialized_series();
+        Ok(AnyValue::Float64((x * y)?.sum::<f64>()?))
+    });
+    let out = df
+        .lazy()
+        .select([as_struct(vec![col("x"), col("y")])
+            .rolling_map_frame(f, 2, 2, DataType::Float64)
+            .alias("out")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("out")?.f64()?),
+        &[None, Some(50.0), Some(130.0), Some(250.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cov")]
+fn test_autocorr() -> PolarsResult<()> {
+    use polars_ops::series::SeriesAutocorr;
+
+    let df = df![
+        "group" => ["a", "a", "a", "a", "b", "b", "b"],
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 1.0, 5.0],
+    ]?;
+
+    let out = df.clone().lazy().select([col("x").autocorr(1)]).collect()?;
+    let expected = df.column("x")?.as_materialized_series().autocorr(1)?;
+    assert_eq!(out.column("x")?.f64()?.get(0), Some(expected));
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("group")])
+        .agg([col("x").autocorr(1)])
+        .collect()?;
+    let values = out.column("x")?.f64()?;
+    // corr([2, 3, 4], [1, 2, 3]) and corr([1, 5], [5, 1])
+    assert!((values.get(0).unwrap() - 1.0).abs() < 1e-12);
+    assert!((values.get(1).unwrap() + 1.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "session_id")]
+fn test_session_id() -> PolarsResult<()> {
+    use polars_time::Duration;
+
+    let df = df![
+        "user" => ["a", "b", "a", "b", "a", "a"],
+        "t" => [0i64, 3, 5, 30, 40, 42],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("t")
+            .session_id(Duration::parse("10i"), false)
+            .over([col("user")])])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("t")?.u32()?),
+        &[Some(0), Some(0), Some(0), Some(1), Some(1), Some(1)]
+    );
+
+    let df = df![
+        "t" => [40i64, 0, 5],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("t").session_id(Duration::parse("10i"), false)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_cum_sum_reset() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1i64, 2, 3, 4, 5],
+        "reset" => [false, false, true, false, true],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("x").cum_sum_reset(col("reset"))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i64()?),
+        &[Some(1), Some(3), Some(3), Some(7), Some(5)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "finance")]
+fn test_garman_klass_volatility() -> PolarsResult<()> {
+    let df = df![
+        "open" => [10.0, 11.0, 12.0],
+        "high" => [11.0, 12.0, 13.0],
+        "low" => [9.0, 10.0, 11.0],
+        "close" => [10.5, 11.5, 12.5],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([garman_klass_volatility(
+            col("open"),
+            col("high"),
+            col("low"),
+            col("close"),
+            2,
+        )])
+        .collect()?;
+    let expected = polars_ops::series::garman_klass_volatility(
+        df.column("open")?.as_materialized_series(),
+        df.column("high")?.as_materialized_series(),
+        df.column("low")?.as_materialized_series(),
+        df.column("close")?.as_materialized_series(),
+        2,
+    )?;
+    assert_eq!(out.column("open")?.as_materialized_series(), &expected);
+    assert_eq!(out.column("open")?.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_cum_trapz() -> PolarsResult<()> {
+    let df = df![
+        "x" => [0i64, 2, 3, 5],
+        "y" => [1.0, 2.0, 3.0, 4.0],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("y").cum_trapz(None).alias("unit"),
+            col("y").cum_trapz(Some(col("x"))).alias("by_x"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("unit")?.f64()?),
+        &[Some(0.0), Some(1.5), Some(4.0), Some(7.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("by_x")?.f64()?),
+        &[Some(0.0), Some(3.0), Some(5.5), Some(12.5)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_arg_sort_by_limit() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "x" => [2, 1, 2, 5, 4],
+        "y" => [1, 7, 0, 3, 3],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([arg_sort_by(
+            [col("x"), col("y")],
+            SortMultipleOptions::default()
+                .with_order_descending_multi([false, true])
+                .with_maintain_order(true),
+        )])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.idx()?),
+        &[Some(1), Some(0), Some(2), Some(4), Some(3)]
+    );
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([arg_sort_by(
+            [col("x"), col("y")],
+            SortMultipleOptions {
+                limit: Some(1),
+                ..SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true)
+            },
+        )
+        .alias("idx")])
+        .collect()?;
+    let idx = out.column("idx")?.explode(false)?;
+    assert_eq!(Vec::from(idx.idx()?), &[Some(0), Some(0)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "signal")]
+fn test_savgol_filter() -> PolarsResult<()> {
+    let df = df![
+        "a" => [0.0, 0.0, 0.0, 35.0, 0.0, 0.0, 0.0],
+    ]?;
+
+    let out = df.lazy().select([col("a").savgol_filter(5, 2)]).collect()?;
+    let out = out.column("a")?.f64()?;
+    assert_eq!(out.len(), 7);
+    assert!((out.get(3).unwrap() - 17.0).abs() < 1e-9);
+    // The edge is evaluated on the fit of the first window: [9, 13, 12, 6, -5] / 35.
+    assert!((out.get(1).unwrap() - 6.0).abs() < 1e-9);
+    Ok(())
+}
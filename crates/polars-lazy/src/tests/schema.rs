@@ -40,3 +40,93 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_match_to_schema() -> PolarsResult<()> {
+    let df = df![
+        "b" => [1i32, 300],
+        "a" => [1.5f64, 2.5],
+        "extra" => [true, false],
+    ]?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Float64),
+        Field::new("b".into(), DataType::UInt8),
+        Field::new("c".into(), DataType::String),
+    ]));
+    let per_column = |lossy_cast| MatchToSchemaPerColumn {
+        missing_columns: MissingColumnsPolicyOrExpr::Insert,
+        missing_struct_fields: MissingColumnsPolicy::Raise,
+        extra_struct_fields: ExtraColumnsPolicy::Raise,
+        integer_cast: UpcastOrForbid::Forbid,
+        float_cast: UpcastOrForbid::Forbid,
+        lossy_cast,
+    };
+
+    // Casting Int32 to UInt8 is not an upcast.
+    let out = df
+        .clone()
+        .lazy()
+        .match_to_schema(
+            schema.clone(),
+            vec![per_column(false); 3].into(),
+            ExtraColumnsPolicy::Ignore,
+        )
+        .collect();
+    assert!(out.is_err());
+
+    let q = df.lazy().match_to_schema(
+        schema.clone(),
+        vec![per_column(true); 3].into(),
+        ExtraColumnsPolicy::Ignore,
+    );
+    assert_eq!(q.clone().collect_schema()?, schema);
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("b")?.u8()?), [Some(1), None]);
+    assert_eq!(out.column("c")?.null_count(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_match_to_schema_lossy_struct_fields() -> PolarsResult<()> {
+    let lf = df![
+        "a" => [1i32, 300],
+        "b" => [true, false],
+    ]?
+    .lazy()
+    .select([as_struct(vec![col("a"), col("b")]).alias("s")]);
+    // The target drops field "b" and narrows "a".
+    let schema = Arc::new(Schema::from_iter([Field::new(
+        "s".into(),
+        DataType::Struct(vec![Field::new("a".into(), DataType::UInt8)]),
+    )]));
+    let per_column = |extra_struct_fields| MatchToSchemaPerColumn {
+        missing_columns: MissingColumnsPolicyOrExpr::Raise,
+        missing_struct_fields: MissingColumnsPolicy::Raise,
+        extra_struct_fields,
+        integer_cast: UpcastOrForbid::Forbid,
+        float_cast: UpcastOrForbid::Forbid,
+        lossy_cast: true,
+    };
+
+    // A lossy cast doesn't bypass the struct field policy.
+    let out = lf
+        .clone()
+        .match_to_schema(
+            schema.clone(),
+            vec![per_column(ExtraColumnsPolicy::Raise)].into(),
+            ExtraColumnsPolicy::Raise,
+        )
+        .collect();
+    assert!(out.is_err());
+
+    let out = lf
+        .match_to_schema(
+            schema.clone(),
+            vec![per_column(ExtraColumnsPolicy::Ignore)].into(),
+            ExtraColumnsPolicy::Raise,
+        )
+        .collect()?;
+    assert_eq!(out.schema().as_ref(), schema.as_ref());
+    Ok(())
+}
@@ -417,3 +417,135 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_collect_batches() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+
+    let batches = df
+        .clone()
+        .lazy()
+        .with_column(col("a") * lit(2))
+        .collect_batches(300)?
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let heights: Vec<_> = batches.iter().map(|df| df.height()).collect();
+    assert_eq!(heights, [300, 300, 300, 100]);
+    let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    assert_eq!(
+        out,
+        df.clone().lazy().with_column(col("a") * lit(2)).collect()?
+    );
+
+    // Without maintaining the order, all rows are still returned once.
+    let batches = df
+        .clone()
+        .lazy()
+        .collect_batches_unordered(300)?
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    let out = out.sort(["a"], Default::default())?;
+    assert_eq!(out, df);
+
+    // Dropping the iterator early stops the query.
+    let mut batches = get_parquet_file().collect_batches(5)?;
+    assert_eq!(batches.next().unwrap()?.height(), 5);
+    drop(batches);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_with_row_index_u64() -> PolarsResult<()> {
+    let offset = u64::from(u32::MAX) - 5;
+    let q = get_csv_glob().with_row_index_u64("idx", offset);
+    assert_streaming_with_default(q.clone(), false);
+
+    let out = q.with_new_streaming(true).collect()?;
+    let idx = out.column("idx")?.u64()?;
+    assert_eq!(idx.get(0), Some(offset));
+    assert_eq!(
+        idx.get(out.height() - 1),
+        Some(offset + out.height() as u64 - 1)
+    );
+
+    let q = get_csv_glob().with_row_index_u64("idx", u64::MAX - 5);
+    assert!(q.clone().with_new_streaming(true).collect().is_err());
+    assert!(q.with_new_streaming(false).collect().is_err());
+
+    // The last index may be `u64::MAX`.
+    let q = df!("x" => [1])?.lazy().with_row_index_u64("idx", u64::MAX);
+    for new_streaming in [true, false] {
+        let out = q.clone().with_new_streaming(new_streaming).collect()?;
+        assert_eq!(out.column("idx")?.u64()?.get(0), Some(u64::MAX));
+    }
+
+    // The scan adds the row index across all of its files, so predicates are still pushed
+    // into the scan.
+    let q = get_csv_glob()
+        .with_row_index_u64("idx", offset)
+        .filter(col("calories").gt(lit(100)));
+    assert!(row_index_at_scan(q.clone()));
+    assert!(predicate_at_scan(q.clone()));
+
+    let expected = get_csv_glob()
+        .with_row_index("idx", None)
+        .filter(col("calories").gt(lit(100)))
+        .collect()?;
+    let expected = expected.column("idx")?.cast(&DataType::UInt64)?;
+    for new_streaming in [true, false] {
+        let out = q.clone().with_new_streaming(new_streaming).collect()?;
+        let idx = out.column("idx")?.u64()?;
+        assert_eq!(idx.len(), expected.len());
+        for (a, b) in idx.into_iter().zip(expected.u64()?) {
+            assert_eq!(a, b.map(|b| b + offset));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "unique_counts"))]
+fn test_streaming_group_by_unique_with_counts() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .group_by([col("category")])
+        .agg([col("calories").unique_with_counts()])
+        .sort(["category"], Default::default());
+    assert_streaming_with_default(q, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "moment"))]
+fn test_streaming_group_by_skew_kurtosis() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .group_by([col("category")])
+        .agg([
+            col("calories").skew(false).alias("skew"),
+            col("calories").kurtosis(true, false).alias("kurtosis"),
+            col("fats_g").kurtosis(false, true).alias("pearson"),
+        ])
+        .sort(["category"], Default::default());
+    let out = q.clone().with_new_streaming(true).collect()?;
+    let expected = q.with_new_streaming(false).collect()?;
+
+    assert_eq!(out.column("category")?, expected.column("category")?);
+    for name in ["skew", "kurtosis", "pearson"] {
+        let out = out.column(name)?.f64()?;
+        let expected = expected.column(name)?.f64()?;
+        assert_eq!(out.null_count(), expected.null_count());
+        for (a, b) in out.into_iter().zip(expected) {
+            if let (Some(a), Some(b)) = (a, b) {
+                assert!((a - b).abs() < 1e-9, "{name}: {a} != {b}");
+            }
+        }
+    }
+
+    // Unsupported dtypes raise an error instead of panicking.
+    let q = get_csv_glob()
+        .group_by([col("category")])
+        .agg([col("category").skew(false)]);
+    assert!(q.with_new_streaming(true).collect().is_err());
+    Ok(())
+}
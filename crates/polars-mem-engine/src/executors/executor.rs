@@ -36,9 +36,11 @@ impl Executor for SinkExecutor {
             Cow::Borrowed("")
         };
 
+        let rows_in = df.height();
         state.clone().record(
             || (self.f)(df, state).map(|df| df.unwrap_or_else(DataFrame::empty)),
             profile_name,
+            rows_in,
         )
     }
 }
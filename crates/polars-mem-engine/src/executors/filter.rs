@@ -4,6 +4,9 @@ use super::*;
 
 pub struct FilterExec {
     pub(crate) predicate: Arc<dyn PhysicalExpr>,
+    /// The conjuncts of `predicate` if it can be evaluated conjunct-by-conjunct, i.e. it is
+    /// an `&`-chain of more than one term where none of the terms can raise.
+    conjuncts: Option<Vec<Arc<dyn PhysicalExpr>>>,
     pub(crate) input: Box<dyn Executor>,
     // if the predicate contains a window function
     has_window: bool,
@@ -27,12 +30,70 @@ impl FilterExec {
     ) -> Self {
         Self {
             predicate,
+            conjuncts: None,
             input,
             has_window,
             streamable,
         }
     }
 
+    /// Evaluate the predicate as separate conjuncts. Conjuncts are evaluated left-to-right and
+    /// later conjuncts are skipped as soon as the accumulated mask doesn't select any row.
+    pub fn with_conjuncts(mut self, conjuncts: Vec<Arc<dyn PhysicalExpr>>) -> Self {
+        debug_assert!(conjuncts.len() > 1);
+        self.conjuncts = Some(conjuncts);
+        self
+    }
+
+    fn evaluate_conjuncts(
+        conjuncts: &[Arc<dyn PhysicalExpr>],
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<BooleanChunked> {
+        let mut acc: Option<BooleanChunked> = None;
+        for predicate in conjuncts {
+            let c = predicate.evaluate(df, state)?;
+            let mask = column_to_mask(&c)?;
+            let mask = match acc {
+                None => mask.clone(),
+                Some(acc) => &acc & mask,
+            };
+            // Nulls are filtered out, so nothing can be selected anymore.
+            if !mask.any() {
+                return Ok(mask);
+            }
+            acc = Some(mask);
+        }
+        Ok(acc.unwrap())
+    }
+
+    /// Evaluate all conjuncts on `df` and reorder them so that the most selective ones are
+    /// evaluated first on the remaining chunks. Returns the mask of `df`.
+    fn sample_conjuncts(
+        conjuncts: &mut Vec<Arc<dyn PhysicalExpr>>,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<BooleanChunked> {
+        let mut acc: Option<BooleanChunked> = None;
+        let mut selectivity = Vec::with_capacity(conjuncts.len());
+        for predicate in conjuncts.iter() {
+            let c = predicate.evaluate(df, state)?;
+            let mask = column_to_mask(&c)?;
+            let n_selected = mask.sum().unwrap_or(0) as f64;
+            selectivity.push(n_selected / mask.len().max(1) as f64);
+            acc = Some(match acc {
+                None => mask.clone(),
+                Some(acc) => &acc & mask,
+            });
+        }
+
+        let mut order = (0..conjuncts.len()).collect::<Vec<_>>();
+        order.sort_by(|&l, &r| selectivity[l].total_cmp(&selectivity[r]));
+        *conjuncts = order.into_iter().map(|i| conjuncts[i].clone()).collect();
+
+        Ok(acc.unwrap())
+    }
+
     fn execute_hor(
         &mut self,
         df: DataFrame,
@@ -41,30 +102,57 @@ impl FilterExec {
         if self.has_window {
             state.insert_has_window_function_flag()
         }
-        let c = self.predicate.evaluate(&df, state)?;
+        let mask = match &self.conjuncts {
+            Some(conjuncts) => Self::evaluate_conjuncts(conjuncts, &df, state)?,
+            None => {
+                let c = self.predicate.evaluate(&df, state)?;
+                column_to_mask(&c)?.clone()
+            },
+        };
         if self.has_window {
             state.clear_window_expr_cache()
         }
 
         // @scalar-opt
         // @partition-opt
-        df.filter(column_to_mask(&c)?)
+        df.filter(&mask)
     }
 
     fn execute_chunks(
         &mut self,
-        chunks: Vec<DataFrame>,
+        mut chunks: Vec<DataFrame>,
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
+        // Use the first chunk as sample for the selectivity of the conjuncts.
+        let mut first = None;
+        if let Some(conjuncts) = &mut self.conjuncts {
+            if chunks.len() > 1 {
+                let df = chunks.remove(0);
+                let mask = Self::sample_conjuncts(conjuncts, &df, state)?;
+                first = Some(df.filter(&mask)?);
+            }
+        }
+
+        let predicate = &self.predicate;
+        let conjuncts = self.conjuncts.as_deref();
         let iter = chunks.into_par_iter().map(|df| {
-            let c = self.predicate.evaluate(&df, state)?;
+            let mask = match conjuncts {
+                Some(conjuncts) => Self::evaluate_conjuncts(conjuncts, &df, state)?,
+                None => {
+                    let c = predicate.evaluate(&df, state)?;
+                    column_to_mask(&c)?.clone()
+                },
+            };
 
             // @scalar-opt
             // @partition-opt
-            df.filter(column_to_mask(&c)?)
+            df.filter(&mask)
         });
-        let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
-        Ok(accumulate_dataframes_vertical_unchecked(df))
+        let mut dfs = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
+        if let Some(first) = first {
+            dfs.insert(0, first);
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
     }
 
     fn execute_impl(
@@ -107,6 +195,7 @@ impl Executor for FilterExec {
             Cow::Borrowed("")
         };
 
+        let rows_in = df.height();
         state.clone().record(
             || {
                 let df = self.execute_impl(df, state);
@@ -116,6 +205,7 @@ impl Executor for FilterExec {
                 df
             },
             profile_name,
+            rows_in,
         )
     }
 }
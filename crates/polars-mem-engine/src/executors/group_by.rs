@@ -67,7 +67,18 @@ pub(super) fn group_by_helper(
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
 
     if let Some(f) = apply {
-        return gb.sliced(slice).apply(move |df| f.call_udf(df));
+        // The function can return any number of rows per group, so the slice applies to the
+        // concatenated result and not to the groups.
+        #[allow(deprecated)]
+        let out = if f.allows_parallel() {
+            POOL.install(|| gb.par_apply(|df| f.call_udf(df)))
+        } else {
+            gb.apply(move |df| f.call_udf(df))
+        }?;
+        return Ok(match slice {
+            Some((offset, len)) => out.slice(offset, len),
+            None => out,
+        });
     }
 
     let mut groups = gb.get_groups();
@@ -140,7 +151,8 @@ impl Executor for GroupByExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            let rows_in = df.height();
+            new_state.record(|| self.execute_impl(state, df), profile_name, rows_in)
         } else {
             self.execute_impl(state, df)
         }
@@ -373,7 +373,12 @@ impl Executor for PartitionGroupByExec {
         };
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, original_df), profile_name)
+            let rows_in = original_df.height();
+            new_state.record(
+                || self.execute_impl(state, original_df),
+                profile_name,
+                rows_in,
+            )
         } else {
             self.execute_impl(state, original_df)
         }
@@ -79,6 +79,20 @@ impl GroupByRollingExec {
             None
         };
 
+        // Every row is its own window, so the id is the position of the row within its group.
+        let mut window_id = self.options.include_window_id.then(|| {
+            let window_id = match &group_by {
+                Some(groups) => groups.iter().flat_map(|[_, len]| 0..*len as u64).collect(),
+                None => (0..df.height() as u64).collect(),
+            };
+            let mut window_id =
+                UInt64Chunked::from_vec(PlSmallStr::from_static("_window_id"), window_id);
+            if group_by.is_none() {
+                window_id.set_sorted_flag(polars_core::series::IsSorted::Ascending);
+            }
+            window_id.into_column()
+        });
+
         let (mut time_key, groups) = df.rolling(group_by, &self.options)?;
 
         if let Some(f) = &self.apply {
@@ -101,15 +115,16 @@ impl GroupByRollingExec {
             groups = sliced_groups.as_ref().unwrap();
 
             time_key = time_key.slice(offset, len);
-            for k in &mut keys {
+            for k in keys.iter_mut().chain(window_id.as_mut()) {
                 *k = k.slice(offset, len);
             }
         }
 
         let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
 
-        let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
+        let mut columns = Vec::with_capacity(agg_columns.len() + 2 + keys.len());
         columns.extend_from_slice(&keys);
+        columns.extend(window_id);
         columns.push(time_key);
         columns.extend(agg_columns);
 
@@ -147,7 +162,8 @@ impl Executor for GroupByRollingExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            let rows_in = df.height();
+            new_state.record(|| self.execute_impl(state, df), profile_name, rows_in)
         } else {
             self.execute_impl(state, df)
         }
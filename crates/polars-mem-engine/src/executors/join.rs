@@ -86,6 +86,7 @@ impl Executor for JoinExec {
             Cow::Borrowed("")
         };
 
+        let rows_in = df_left.height() + df_right.height();
         state.record(|| {
 
             let left_on_series = self
@@ -155,6 +156,6 @@ impl Executor for JoinExec {
             };
             df
 
-        }, profile_name)
+        }, profile_name, rows_in)
     }
 }
@@ -6,6 +6,7 @@ pub(crate) struct MergeSorted {
     pub(crate) input_left: Box<dyn Executor>,
     pub(crate) input_right: Box<dyn Executor>,
     pub(crate) key: PlSmallStr,
+    pub(crate) nulls_last: bool,
 }
 
 impl Executor for MergeSorted {
@@ -39,9 +40,12 @@ impl Executor for MergeSorted {
                     lhs.as_materialized_series(),
                     rhs.as_materialized_series(),
                     true,
+                    false,
+                    self.nulls_last,
                 )
             },
             profile_name,
+            left.height() + right.height(),
         )
     }
 }
@@ -95,7 +95,8 @@ impl Executor for ProjectionExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            let rows_in = df.height();
+            new_state.record(|| self.execute_impl(state, df), profile_name, rows_in)
         } else {
             self.execute_impl(state, df)
         }
@@ -26,7 +26,12 @@ impl Executor for ProjectionSimple {
         let df = self.input.execute(state)?;
 
         if state.has_node_timer() {
-            state.record(|| self.execute_impl(df, columns.as_slice()), profile_name)
+            let rows_in = df.height();
+            state.record(
+                || self.execute_impl(df, columns.as_slice()),
+                profile_name,
+                rows_in,
+            )
         } else {
             self.execute_impl(df, columns.as_slice())
         }
@@ -69,6 +69,7 @@ impl Executor for AnonymousScanExec {
                     self.function.scan(args)
                 },
                 "anonymous_scan".into(),
+                0,
             ),
             (false, Some(predicate)) => state.record(
                 || {
@@ -85,8 +86,9 @@ impl Executor for AnonymousScanExec {
                     Ok(df)
                 },
                 "anonymous_scan".into(),
+                0,
             ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
+            _ => state.record(|| self.function.scan(args), "anonymous_scan".into(), 0),
         }
     }
 }
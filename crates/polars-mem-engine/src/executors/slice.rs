@@ -19,6 +19,7 @@ impl Executor for SliceExec {
         state.record(
             || Ok(df.slice(self.offset, self.len as usize)),
             "slice".into(),
+            df.height(),
         )
     }
 }
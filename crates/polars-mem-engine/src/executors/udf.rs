@@ -21,6 +21,7 @@ impl Executor for UdfExec {
         } else {
             Cow::Borrowed("")
         };
-        state.record(|| self.function.evaluate(df), profile_name)
+        let rows_in = df.height();
+        state.record(|| self.function.evaluate(df), profile_name, rows_in)
     }
 }
@@ -43,6 +43,7 @@ impl Executor for UnionExec {
             let mut dfs = Vec::with_capacity(inputs.len());
 
             for (idx, mut input) in inputs.into_iter().enumerate() {
+                state.should_stop()?;
                 let mut state = state.split();
                 state.branch_idx += idx;
 
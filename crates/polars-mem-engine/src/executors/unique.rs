@@ -21,6 +21,7 @@ impl Executor for UniqueExec {
             .as_ref()
             .map(|v| v.iter().cloned().collect::<Vec<_>>());
         let keep = self.options.keep_strategy;
+        let rows_in = df.height();
 
         state.record(
             || {
@@ -36,6 +37,7 @@ impl Executor for UniqueExec {
                 )
             },
             Cow::Borrowed("unique()"),
+            rows_in,
         )
     }
 }
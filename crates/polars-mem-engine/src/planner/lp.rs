@@ -52,6 +52,37 @@ fn partitionable_gb(
     }
 }
 
+/// Whether evaluating a filter conjunct may raise. Such conjuncts have to see every row,
+/// so they keep the filter from skipping conjuncts on chunks that are already filtered out.
+fn conjunct_can_raise(node: Node, expr_arena: &Arena<AExpr>, input_schema: &Schema) -> bool {
+    has_aexpr(node, expr_arena, |ae| match ae {
+        // Integer division and modulo raise on a zero divisor.
+        AExpr::BinaryExpr {
+            op: Operator::Divide | Operator::FloorDivide | Operator::Modulus,
+            ..
+        } => ae
+            .to_dtype(input_schema, Context::Default, expr_arena)
+            .ok()
+            .is_none_or(|dtype| dtype.is_integer()),
+        AExpr::Column(_) | AExpr::Literal(_) | AExpr::BinaryExpr { .. } => false,
+        AExpr::Cast { options, .. } => options.is_strict(),
+        AExpr::Function {
+            function: FunctionExpr::Boolean(function),
+            ..
+        } => !matches!(
+            function,
+            BooleanFunction::IsNull
+                | BooleanFunction::IsNotNull
+                | BooleanFunction::IsNan
+                | BooleanFunction::IsNotNan
+                | BooleanFunction::IsFinite
+                | BooleanFunction::IsInfinite
+                | BooleanFunction::Not
+        ),
+        _ => true,
+    })
+}
+
 #[derive(Clone)]
 struct ConversionState {
     has_cache_child: bool,
@@ -258,6 +289,11 @@ fn create_physical_plan_impl(
                     sink_options,
                     cloud_options,
                 }) => {
+                    polars_ensure!(
+                        !sink_options.is_rotating(),
+                        InvalidOperation: "file rotation is not yet supported in standard engine."
+                    );
+
                     let name: &'static str = match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(_) => "parquet",
@@ -329,17 +365,22 @@ fn create_physical_plan_impl(
                                         .with_float_precision(
                                             options.serialize_options.float_precision,
                                         )
+                                        .with_column_formats(
+                                            options.serialize_options.column_formats.clone(),
+                                        )
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
 
                                     JsonWriter::new(BufWriter::new(writer))
                                         .with_json_format(JsonFormat::JsonLines)
+                                        .with_float_precision(options.float_precision)
+                                        .with_float_scientific(options.float_scientific)
                                         .finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
@@ -359,6 +400,29 @@ fn create_physical_plan_impl(
                         "partition sinks not yet supported in standard engine."
                     )
                 },
+                SinkTypeIR::Callback(CallbackSinkType {
+                    function,
+                    maintain_order: _,
+                    chunk_size,
+                }) => {
+                    let function = function.clone();
+                    let chunk_size = chunk_size.map_or(usize::MAX, |n| n.get());
+                    Ok(Box::new(SinkExecutor {
+                        input,
+                        name: "callback".to_string(),
+                        f: Box::new(move |df, _state| {
+                            let mut offset = 0;
+                            while offset < df.height() {
+                                let batch = df.slice(offset as i64, chunk_size);
+                                offset += batch.height();
+                                if function.call(batch)? {
+                                    break;
+                                }
+                            }
+                            Ok(None)
+                        }),
+                    }))
+                },
             }
         },
         SinkMultiple { .. } => {
@@ -415,19 +479,39 @@ fn create_physical_plan_impl(
             }
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
-            let predicate = create_physical_expr(
+            let phys_predicate = create_physical_expr(
                 &predicate,
                 Context::Default,
                 expr_arena,
                 &input_schema,
                 &mut state,
             )?;
-            Ok(Box::new(executors::FilterExec::new(
-                predicate,
-                input,
-                state.has_windows,
-                streamable,
-            )))
+            let mut exec =
+                executors::FilterExec::new(phys_predicate, input, state.has_windows, streamable);
+
+            if streamable && !state.has_windows {
+                let minterms = MintermIter::new(predicate.node(), expr_arena).collect::<Vec<_>>();
+                if minterms.len() > 1
+                    && !minterms
+                        .iter()
+                        .any(|node| conjunct_can_raise(*node, expr_arena, &input_schema))
+                {
+                    let conjuncts = minterms
+                        .into_iter()
+                        .map(|node| {
+                            create_physical_expr(
+                                &ExprIR::from_node(node, expr_arena),
+                                Context::Default,
+                                expr_arena,
+                                &input_schema,
+                                &mut ExpressionConversionState::new(true),
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    exec = exec.with_conjuncts(conjuncts);
+                }
+            }
+            Ok(Box::new(exec))
         },
         #[allow(unused_variables)]
         Scan {
@@ -874,6 +958,7 @@ fn create_physical_plan_impl(
             input_left,
             input_right,
             key,
+            nulls_last,
         } => {
             let (input_left, input_right) = state.with_new_branch(|new_state| {
                 (
@@ -888,6 +973,7 @@ fn create_physical_plan_impl(
                 input_left,
                 input_right,
                 key,
+                nulls_last,
             };
             Ok(Box::new(exec))
         },
@@ -1,3 +1,6 @@
+use arrow::array::FixedSizeListArray;
+use polars_core::utils::slice_offsets;
+
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
@@ -138,6 +141,42 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// Slice every sub-array to the elements `offset..offset + length`.
+    ///
+    /// A negative `offset` counts from the end of the sub-array and a `length` of `None` takes
+    /// all remaining elements. The bounds are the same for every row, so the output is an array
+    /// of the sliced width.
+    fn array_slice(&self, offset: i64, length: Option<usize>) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array().rechunk();
+        let width = ca.width();
+        let (start, new_width) = slice_offsets(offset, length.unwrap_or(width), width);
+        let dtype = DataType::Array(Box::new(ca.inner_dtype().clone()), new_width);
+
+        let idx = IdxCa::from_vec(
+            PlSmallStr::EMPTY,
+            (0..ca.len())
+                .flat_map(|row| {
+                    let row_start = (row * width + start) as IdxSize;
+                    row_start..row_start + new_width as IdxSize
+                })
+                .collect(),
+        );
+        // SAFETY: The indices are in-bounds as `start + new_width <= width`.
+        let values = unsafe { ca.get_inner().take_unchecked(&idx) };
+        let values = values.to_physical_repr().rechunk();
+
+        let arr = FixedSizeListArray::new(
+            dtype.to_physical().to_arrow(CompatLevel::newest()),
+            ca.len(),
+            values.chunks()[0].clone(),
+            ca.rechunk_validity(),
+        );
+        // SAFETY: The chunk has the physical arrow dtype of `dtype`.
+        Ok(unsafe {
+            ArrayChunked::from_chunks_and_dtype(ca.name().clone(), vec![arr.boxed()], dtype)
+        })
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -11,6 +11,22 @@ pub fn arr_default_struct_name_gen(idx: usize) -> PlSmallStr {
     format_pl_smallstr!("field_{idx}")
 }
 
+/// Name the fields by replacing `{}` in `template` with the field index, e.g. `"field_{}"`.
+pub fn arr_template_struct_name_gen(template: &str) -> ArrToStructNameGenerator {
+    let template = template.to_string();
+    Arc::new(move |idx| PlSmallStr::from_string(template.replace("{}", &idx.to_string())))
+}
+
+/// Name the fields after `names`, falling back to the default names if the array is wider.
+pub fn arr_names_struct_name_gen(names: Vec<PlSmallStr>) -> ArrToStructNameGenerator {
+    Arc::new(move |idx| {
+        names
+            .get(idx)
+            .cloned()
+            .unwrap_or_else(|| arr_default_struct_name_gen(idx))
+    })
+}
+
 pub trait ToStruct: AsArray {
     fn to_struct(
         &self,
@@ -1,5 +1,6 @@
 mod cast_binary_to_numerical;
 mod namespace;
+mod slice;
 
 pub use namespace::*;
 use polars_core::prelude::*;
@@ -11,7 +11,7 @@ use polars_compute::size::binary_size_bytes;
 use polars_core::prelude::arity::{broadcast_binary_elementwise_values, unary_elementwise_values};
 
 use super::cast_binary_to_numerical::cast_binview_to_primitive_dyn;
-use super::*;
+use super::{slice, *};
 
 pub trait BinaryNameSpaceImpl: AsBinary {
     /// Check if binary contains given literal
@@ -92,6 +92,19 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         })
     }
 
+    /// Slice the binary values by byte offset and length.
+    ///
+    /// A negative `offset` counts from the end of the value and a null `length` slices until the
+    /// end. With a single offset and length the values are not copied.
+    fn bin_slice(&self, offset: &Column, length: &Column) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        let offset = offset.cast(&DataType::Int64)?;
+        // We strict cast, otherwise negative value will be treated as a valid length.
+        let length = length.strict_cast(&DataType::UInt64)?;
+
+        slice::slice(ca, offset.i64()?, length.u64()?)
+    }
+
     /// Get the size of the binary values in bytes.
     fn size_bytes(&self) -> UInt32Chunked {
         let ca = self.as_binary();
@@ -183,6 +196,39 @@ pub trait BinaryNameSpaceImpl: AsBinary {
             ),
         }
     }
+
+    /// Reinterpret the bytes of every value as a number of the fixed-width numeric `dtype`.
+    ///
+    /// Values whose size doesn't match the width of `dtype` become null, or raise an error if
+    /// `strict` is set.
+    #[cfg(feature = "binary_encoding")]
+    fn reinterpret(
+        &self,
+        dtype: &DataType,
+        is_little_endian: bool,
+        strict: bool,
+    ) -> PolarsResult<Series> {
+        let ca = self.as_binary();
+        let out = self.from_buffer(dtype, is_little_endian)?;
+        if strict && out.null_count() != ca.null_count() {
+            let arrow::datatypes::PhysicalType::Primitive(ty) =
+                dtype.to_arrow(CompatLevel::newest()).to_physical_type()
+            else {
+                unreachable!()
+            };
+            let width = with_match_primitive_type!(ty, |$T| size_of::<$T>());
+            let (idx, size) = ca
+                .iter()
+                .enumerate()
+                .find_map(|(i, v)| v.filter(|v| v.len() != width).map(|v| (i, v.len())))
+                .unwrap();
+            polars_bail!(
+                InvalidOperation: "cannot reinterpret a value of {} bytes at index {} as {} of {} bytes",
+                size, idx, dtype, width
+            );
+        }
+        Ok(out)
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
@@ -0,0 +1,103 @@
+use arrow::array::View;
+use polars_core::prelude::arity::ternary_elementwise;
+use polars_core::prelude::*;
+
+/// The byte range of `val[offset..][..length]` for a value of `len` bytes. A negative `offset`
+/// counts from the end, if it points before the start of the value the length is reduced.
+fn slice_offsets(len: usize, offset: i64, length: u64) -> (usize, usize) {
+    let (start, length) = if offset >= 0 {
+        ((offset as u64).min(len as u64) as usize, length)
+    } else {
+        let back = offset.unsigned_abs();
+        if back <= len as u64 {
+            (len - back as usize, length)
+        } else {
+            (0, length.saturating_sub(back - len as u64))
+        }
+    };
+    let end = start + length.min((len - start) as u64) as usize;
+    (start, end)
+}
+
+fn slice_ternary(
+    opt_val: Option<&[u8]>,
+    opt_offset: Option<i64>,
+    opt_length: Option<u64>,
+) -> Option<&[u8]> {
+    let val = opt_val?;
+    let (start, end) = slice_offsets(val.len(), opt_offset?, opt_length.unwrap_or(u64::MAX));
+    Some(&val[start..end])
+}
+
+fn update_view(mut view: View, start: usize, end: usize, val: &[u8]) -> View {
+    let subval = &val[start..end];
+    if subval.len() <= 12 {
+        View::new_inline(subval)
+    } else {
+        view.offset += start as u32;
+        view.length = subval.len() as u32;
+        view.prefix = u32::from_le_bytes(subval[0..4].try_into().unwrap());
+        view
+    }
+}
+
+fn broadcast<T: PolarsDataType>(ca: &ChunkedArray<T>, len: usize) -> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkExpandAtIndex<T>,
+{
+    if ca.len() == len {
+        ca.clone()
+    } else {
+        ca.new_from_index(0, len)
+    }
+}
+
+pub(super) fn slice(
+    ca: &BinaryChunked,
+    offset: &Int64Chunked,
+    length: &UInt64Chunked,
+) -> PolarsResult<BinaryChunked> {
+    if offset.len() == 1 && length.len() == 1 {
+        let Some(offset) = offset.get(0) else {
+            return Ok(BinaryChunked::full_null(ca.name().clone(), ca.len()));
+        };
+        let length = length.get(0).unwrap_or(u64::MAX);
+
+        // SAFETY: the views are narrowed to a range within their own value.
+        return Ok(unsafe {
+            ca.apply_views(|view, val| {
+                let (start, end) = slice_offsets(val.len(), offset, length);
+                update_view(view, start, end, val)
+            })
+        });
+    }
+
+    let len = ca.len().max(offset.len()).max(length.len());
+    polars_ensure!(
+        [ca.len(), offset.len(), length.len()].iter().all(|n| *n == 1 || *n == len),
+        ShapeMismatch: "bin.slice got a column of length {}, offsets of length {} and lengths of length {}",
+        ca.len(), offset.len(), length.len()
+    );
+    Ok(ternary_elementwise(
+        &broadcast(ca, len),
+        &broadcast(offset, len),
+        &broadcast(length, len),
+        slice_ternary,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slice_offsets() {
+        assert_eq!(slice_offsets(4, 1, 2), (1, 3));
+        assert_eq!(slice_offsets(4, 3, 10), (3, 4));
+        assert_eq!(slice_offsets(4, 5, 1), (4, 4));
+        assert_eq!(slice_offsets(4, -2, u64::MAX), (2, 4));
+        // Starts two bytes before the value.
+        assert_eq!(slice_offsets(4, -6, 3), (0, 1));
+        assert_eq!(slice_offsets(4, i64::MIN, u64::MAX), (0, 4));
+    }
+}
@@ -47,59 +47,128 @@ impl<'a> MaterializeValues<Option<&'a [u8]>> for MutablePlBinary {
     }
 }
 
-fn set_operation<K, I, J, R>(
-    set: &mut PlIndexSet<K>,
-    set2: &mut PlIndexSet<K>,
-    a: I,
-    b: J,
+/// The distinct values of one list in the order of first occurrence.
+///
+/// If nulls are not equal to each other they are counted instead of being added to the set.
+struct ListSet<K> {
+    set: PlIndexSet<K>,
+    null_count: usize,
+    nulls_equal: bool,
+}
+
+impl<K: Eq + Hash + Copy> ListSet<K> {
+    fn new(nulls_equal: bool) -> Self {
+        Self {
+            set: Default::default(),
+            null_count: 0,
+            nulls_equal,
+        }
+    }
+
+    fn fill<I: IntoIterator<Item = K>>(&mut self, values: I, null: K) {
+        self.set.clear();
+        self.null_count = 0;
+        for v in values {
+            if !self.nulls_equal && v == null {
+                self.null_count += 1;
+            } else {
+                self.set.insert(v);
+            }
+        }
+    }
+
+    fn only_in<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = K> + 'a {
+        self.set.iter().filter(|v| !other.set.contains(*v)).copied()
+    }
+}
+
+fn set_operation<K, R>(
+    a: &ListSet<K>,
+    b: &ListSet<K>,
     out: &mut R,
     set_op: SetOperation,
-    broadcast_rhs: bool,
+    maintain_order: bool,
+    null: K,
 ) -> usize
 where
     K: Eq + Hash + Copy,
-    I: IntoIterator<Item = K>,
-    J: IntoIterator<Item = K>,
     R: MaterializeValues<K>,
 {
-    set.clear();
-    let a = a.into_iter();
-    let b = b.into_iter();
+    // Nulls that are not equal to each other are never matched, so they are appended after the
+    // other values.
+    let nulls = |n: usize| std::iter::repeat_n(null, n);
 
     match set_op {
         SetOperation::Intersection => {
-            set.extend(a);
-            // If broadcast `set2` should already be filled.
-            if !broadcast_rhs {
-                set2.clear();
-                set2.extend(b);
-            }
-            out.extend_buf(set.intersection(set2).copied())
-        },
-        SetOperation::Union => {
-            set.extend(a);
-            set.extend(b);
-            out.extend_buf(set.drain(..))
-        },
-        SetOperation::Difference => {
-            set.extend(a);
-            for v in b {
-                set.swap_remove(&v);
-            }
-            out.extend_buf(set.drain(..))
-        },
-        SetOperation::SymmetricDifference => {
-            // If broadcast `set2` should already be filled.
-            if !broadcast_rhs {
-                set2.clear();
-                set2.extend(b);
-            }
-            // We could speed this up, but implementing ourselves, but we need to have a cloneable
-            // iterator as we need 2 passes
-            set.extend(a);
-            out.extend_buf(set.symmetric_difference(set2).copied())
+            // Iterate over the smallest set, unless we need the order of the left operand.
+            let (probe, build) = if maintain_order || a.set.len() <= b.set.len() {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            out.extend_buf(probe.set.iter().filter(|v| build.set.contains(*v)).copied())
         },
+        SetOperation::Union => out.extend_buf(
+            a.set
+                .iter()
+                .copied()
+                .chain(b.only_in(a))
+                .chain(nulls(a.null_count + b.null_count)),
+        ),
+        SetOperation::Difference => out.extend_buf(a.only_in(b).chain(nulls(a.null_count))),
+        SetOperation::SymmetricDifference => out.extend_buf(
+            a.only_in(b)
+                .chain(b.only_in(a))
+                .chain(nulls(a.null_count + b.null_count)),
+        ),
+    }
+}
+
+/// Applies the set operation row by row and returns the offsets of the output values.
+///
+/// A side with a single row is broadcast, its set is only built once.
+#[allow(clippy::too_many_arguments)]
+fn set_operation_rows<K, R>(
+    offsets_a: &[i64],
+    offsets_b: &[i64],
+    set_op: SetOperation,
+    options: ListSetOptions,
+    null: K,
+    out: &mut R,
+    mut fill_a: impl FnMut(&mut ListSet<K>, usize, usize),
+    mut fill_b: impl FnMut(&mut ListSet<K>, usize, usize),
+) -> Vec<i64>
+where
+    K: Eq + Hash + Copy,
+    R: MaterializeValues<K>,
+{
+    let broadcast_lhs = offsets_a.len() == 2;
+    let broadcast_rhs = offsets_b.len() == 2;
+    let mut set_a = ListSet::new(options.nulls_equal);
+    let mut set_b = ListSet::new(options.nulls_equal);
+    if broadcast_lhs {
+        fill_a(&mut set_a, offsets_a[0] as usize, offsets_a[1] as usize);
+    }
+    if broadcast_rhs {
+        fill_b(&mut set_b, offsets_b[0] as usize, offsets_b[1] as usize);
+    }
+
+    let n_rows = std::cmp::max(offsets_a.len(), offsets_b.len()) - 1;
+    let mut offsets = Vec::with_capacity(n_rows + 1);
+    offsets.push(0i64);
+    for i in 0..n_rows {
+        // The branches are the same every loop.
+        // We rely on branch prediction here.
+        if !broadcast_lhs {
+            fill_a(&mut set_a, offsets_a[i] as usize, offsets_a[i + 1] as usize);
+        }
+        if !broadcast_rhs {
+            fill_b(&mut set_b, offsets_b[i] as usize, offsets_b[i + 1] as usize);
+        }
+        let offset = set_operation(&set_a, &set_b, out, set_op, options.maintain_order, null);
+        offsets.push(offset as i64);
     }
+    offsets
 }
 
 fn copied_wrapper_opt<T: Copy + TotalEq + TotalHash>(
@@ -130,127 +199,74 @@ impl Display for SetOperation {
     }
 }
 
+/// Options for the list set operations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ListSetOptions {
+    /// Keep the values in the order of their first occurrence, values of the left operand
+    /// first. Otherwise the order of the output is unspecified.
+    pub maintain_order: bool,
+    /// Whether a null is an element equal to any other null. If `false`, nulls never match
+    /// and every null of the operands that ends up in the output is kept.
+    pub nulls_equal: bool,
+}
+
+impl Default for ListSetOptions {
+    fn default() -> Self {
+        Self {
+            maintain_order: true,
+            nulls_equal: true,
+        }
+    }
+}
+
 fn primitive<T>(
     a: &PrimitiveArray<T>,
     b: &PrimitiveArray<T>,
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    options: ListSetOptions,
     validity: Option<Bitmap>,
 ) -> PolarsResult<ListArray<i64>>
 where
     T: NativeType + TotalHash + TotalEq + Copy + ToTotalOrd,
     <Option<T> as ToTotalOrd>::TotalOrdItem: Hash + Eq + Copy,
 {
-    let broadcast_lhs = offsets_a.len() == 2;
-    let broadcast_rhs = offsets_b.len() == 2;
-
-    let mut set = Default::default();
-    let mut set2: PlIndexSet<<Option<T> as ToTotalOrd>::TotalOrdItem> = Default::default();
-
+    let null = None::<T>.to_total_ord();
     let mut values_out = MutablePrimitiveArray::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
         *offsets_b.last().unwrap(),
     ) as usize);
-    let mut offsets = Vec::with_capacity(std::cmp::max(offsets_a.len(), offsets_b.len()));
-    offsets.push(0i64);
-
-    let offsets_slice = if offsets_a.len() > offsets_b.len() {
-        offsets_a
-    } else {
-        offsets_b
-    };
-    let first_a = offsets_a[0];
-    let second_a = offsets_a[1];
-    let first_b = offsets_b[0];
-    let second_b = offsets_b[1];
-    if broadcast_rhs {
-        set2.extend(
-            b.into_iter()
-                .skip(first_b as usize)
-                .take(second_b as usize - first_b as usize)
-                .map(copied_wrapper_opt),
-        );
-    }
-    for i in 1..offsets_slice.len() {
-        // If we go OOB we take the first element as we are then broadcasting.
-        let start_a = *offsets_a.get(i - 1).unwrap_or(&first_a) as usize;
-        let end_a = *offsets_a.get(i).unwrap_or(&second_a) as usize;
 
-        let start_b = *offsets_b.get(i - 1).unwrap_or(&first_b) as usize;
-        let end_b = *offsets_b.get(i).unwrap_or(&second_b) as usize;
-
-        // The branches are the same every loop.
-        // We rely on branch prediction here.
-        let offset = if broadcast_rhs {
-            // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
-            let a_iter = a
-                .into_iter()
-                .skip(start_a)
-                .take(end_a - start_a)
-                .map(copied_wrapper_opt);
-            let b_iter = b
-                .into_iter()
-                .skip(first_b as usize)
-                .take(second_b as usize - first_b as usize)
-                .map(copied_wrapper_opt);
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                true,
+    // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
+    let offsets = set_operation_rows(
+        offsets_a,
+        offsets_b,
+        set_op,
+        options,
+        null,
+        &mut values_out,
+        |set, start, end| {
+            set.fill(
+                a.into_iter()
+                    .skip(start)
+                    .take(end - start)
+                    .map(copied_wrapper_opt),
+                null,
             )
-        } else if broadcast_lhs {
-            let a_iter = a
-                .into_iter()
-                .skip(first_a as usize)
-                .take(second_a as usize - first_a as usize)
-                .map(copied_wrapper_opt);
-
-            let b_iter = b
-                .into_iter()
-                .skip(start_b)
-                .take(end_b - start_b)
-                .map(copied_wrapper_opt);
-
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                false,
-            )
-        } else {
-            // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
-            let a_iter = a
-                .into_iter()
-                .skip(start_a)
-                .take(end_a - start_a)
-                .map(copied_wrapper_opt);
-
-            let b_iter = b
-                .into_iter()
-                .skip(start_b)
-                .take(end_b - start_b)
-                .map(copied_wrapper_opt);
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                false,
+        },
+        |set, start, end| {
+            set.fill(
+                b.into_iter()
+                    .skip(start)
+                    .take(end - start)
+                    .map(copied_wrapper_opt),
+                null,
             )
-        };
-
-        offsets.push(offset as i64);
-    }
+        },
+    );
     let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
     let dtype = ListArray::<i64>::default_datatype(values_out.dtype().clone());
 
@@ -258,96 +274,33 @@ where
     Ok(ListArray::new(dtype, offsets, values.boxed(), validity))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn binary(
     a: &BinaryViewArray,
     b: &BinaryViewArray,
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    options: ListSetOptions,
     validity: Option<Bitmap>,
     as_utf8: bool,
 ) -> PolarsResult<ListArray<i64>> {
-    let broadcast_lhs = offsets_a.len() == 2;
-    let broadcast_rhs = offsets_b.len() == 2;
-    let mut set = Default::default();
-    let mut set2: PlIndexSet<Option<&[u8]>> = Default::default();
-
     let mut values_out = MutablePlBinary::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
         *offsets_b.last().unwrap(),
     ) as usize);
-    let mut offsets = Vec::with_capacity(std::cmp::max(offsets_a.len(), offsets_b.len()));
-    offsets.push(0i64);
-
-    if broadcast_rhs {
-        set2.extend(b);
-    }
-    let offsets_slice = if offsets_a.len() > offsets_b.len() {
-        offsets_a
-    } else {
-        offsets_b
-    };
-    let first_a = offsets_a[0];
-    let second_a = offsets_a[1];
-    let first_b = offsets_b[0];
-    let second_b = offsets_b[1];
-    for i in 1..offsets_slice.len() {
-        // If we go OOB we take the first element as we are then broadcasting.
-        let start_a = *offsets_a.get(i - 1).unwrap_or(&first_a) as usize;
-        let end_a = *offsets_a.get(i).unwrap_or(&second_a) as usize;
-
-        let start_b = *offsets_b.get(i - 1).unwrap_or(&first_b) as usize;
-        let end_b = *offsets_b.get(i).unwrap_or(&second_b) as usize;
 
-        // The branches are the same every loop.
-        // We rely on branch prediction here.
-        let offset = if broadcast_rhs {
-            // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
-            let a_iter = a.into_iter().skip(start_a).take(end_a - start_a);
-            let b_iter = b
-                .into_iter()
-                .skip(first_b as usize)
-                .take(second_b as usize - first_b as usize);
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                true,
-            )
-        } else if broadcast_lhs {
-            let a_iter = a
-                .into_iter()
-                .skip(first_a as usize)
-                .take(second_a as usize - first_a as usize);
-            let b_iter = b.into_iter().skip(start_b).take(end_b - start_b);
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                false,
-            )
-        } else {
-            // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
-            let a_iter = a.into_iter().skip(start_a).take(end_a - start_a);
-            let b_iter = b.into_iter().skip(start_b).take(end_b - start_b);
-            set_operation(
-                &mut set,
-                &mut set2,
-                a_iter,
-                b_iter,
-                &mut values_out,
-                set_op,
-                false,
-            )
-        };
-        offsets.push(offset as i64);
-    }
+    // going via skip iterator instead of slice doesn't heap alloc nor trigger a bitcount
+    let offsets = set_operation_rows(
+        offsets_a,
+        offsets_b,
+        set_op,
+        options,
+        None,
+        &mut values_out,
+        |set, start, end| set.fill(a.into_iter().skip(start).take(end - start), None),
+        |set, start, end| set.fill(b.into_iter().skip(start).take(end - start), None),
+    );
     let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
     let values = values_out.freeze();
 
@@ -365,6 +318,7 @@ fn array_set_operation(
     a: &ListArray<i64>,
     b: &ListArray<i64>,
     set_op: SetOperation,
+    options: ListSetOptions,
 ) -> PolarsResult<ListArray<i64>> {
     let offsets_a = a.offsets().as_slice();
     let offsets_b = b.offsets().as_slice();
@@ -389,12 +343,14 @@ fn array_set_operation(
                 .unwrap()
                 .to_binview();
 
-            binary(&a, &b, offsets_a, offsets_b, set_op, validity, true)
+            binary(
+                &a, &b, offsets_a, offsets_b, set_op, options, validity, true,
+            )
         },
         ArrowDataType::BinaryView => {
             let a = values_a.as_any().downcast_ref::<BinaryViewArray>().unwrap();
             let b = values_b.as_any().downcast_ref::<BinaryViewArray>().unwrap();
-            binary(a, b, offsets_a, offsets_b, set_op, validity, false)
+            binary(a, b, offsets_a, offsets_b, set_op, options, validity, false)
         },
         ArrowDataType::Boolean => {
             polars_bail!(InvalidOperation: "boolean type not yet supported in list 'set' operations")
@@ -404,7 +360,7 @@ fn array_set_operation(
                 let a = values_a.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
                 let b = values_b.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
 
-                primitive(&a, &b, offsets_a, offsets_b, set_op, validity)
+                primitive(&a, &b, offsets_a, offsets_b, set_op, options, validity)
             })
         },
     }
@@ -414,6 +370,7 @@ pub fn list_set_operation(
     a: &ListChunked,
     b: &ListChunked,
     set_op: SetOperation,
+    options: ListSetOptions,
 ) -> PolarsResult<ListChunked> {
     polars_ensure!(a.len() == b.len() || b.len() == 1 || a.len() == 1, ShapeMismatch: "column lengths don't match");
     polars_ensure!(a.dtype() == b.dtype(), InvalidOperation: "cannot do 'set' operation on dtypes: {} and {}", a.dtype(), b.dtype());
@@ -441,9 +398,109 @@ pub fn list_set_operation(
         arity::try_binary_unchecked_same_type(
             &a,
             &b,
-            |a, b| array_set_operation(a, b, set_op).map(|arr| arr.boxed()),
+            |a, b| array_set_operation(a, b, set_op, options).map(|arr| arr.boxed()),
             false,
             false,
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(rows: &[&[Option<i32>]]) -> ListChunked {
+        rows.iter()
+            .map(|row| Some(Series::new(PlSmallStr::EMPTY, *row)))
+            .collect()
+    }
+
+    #[test]
+    fn test_list_set_operation_nulls() -> PolarsResult<()> {
+        let a = list(&[&[Some(3), Some(1), None, Some(3), Some(2)], &[Some(5)]]);
+        // Broadcast against every row of `a`.
+        let b = list(&[&[Some(2), None, Some(5)]]);
+        let nulls_unequal = ListSetOptions {
+            nulls_equal: false,
+            ..Default::default()
+        };
+
+        // The expected first row with nulls equal and with nulls unequal.
+        type Case<'a> = (SetOperation, &'a [Option<i32>], &'a [Option<i32>]);
+        let cases: [Case; 4] = [
+            (
+                SetOperation::Union,
+                &[Some(3), Some(1), None, Some(2), Some(5)],
+                &[Some(3), Some(1), Some(2), Some(5), None, None],
+            ),
+            (SetOperation::Intersection, &[None, Some(2)], &[Some(2)]),
+            (
+                SetOperation::Difference,
+                &[Some(3), Some(1)],
+                &[Some(3), Some(1), None],
+            ),
+            (
+                SetOperation::SymmetricDifference,
+                &[Some(3), Some(1), Some(5)],
+                &[Some(3), Some(1), Some(5), None, None],
+            ),
+        ];
+        for (set_op, nulls_equal, nulls_unequal_expected) in cases {
+            for (options, expected) in [
+                (ListSetOptions::default(), nulls_equal),
+                (nulls_unequal, nulls_unequal_expected),
+            ] {
+                let out = list_set_operation(&a, &b, set_op, options)?;
+                assert_eq!(out.len(), 2);
+                let expected = Series::new(PlSmallStr::EMPTY, expected);
+                assert!(
+                    out.get_as_series(0).unwrap().equals_missing(&expected),
+                    "{set_op} {options:?}"
+                );
+            }
+        }
+
+        let out = list_set_operation(&a, &b, SetOperation::Union, Default::default())?;
+        let expected = Series::new(PlSmallStr::EMPTY, [Some(5), Some(2), None]);
+        assert!(out.get_as_series(1).unwrap().equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_set_operation_unordered() -> PolarsResult<()> {
+        let a = list(&[
+            &[Some(3), Some(1), None, Some(3), Some(2)],
+            &[Some(4), Some(6), Some(5)],
+        ]);
+        let b = list(&[&[Some(2), None, Some(5), Some(1)], &[Some(5)]]);
+        let unordered = ListSetOptions {
+            maintain_order: false,
+            ..Default::default()
+        };
+
+        // Without maintained order only the values in each row are defined.
+        for set_op in [
+            SetOperation::Union,
+            SetOperation::Intersection,
+            SetOperation::Difference,
+            SetOperation::SymmetricDifference,
+        ] {
+            let ordered = list_set_operation(&a, &b, set_op, Default::default())?;
+            let out = list_set_operation(&a, &b, set_op, unordered)?;
+            assert_eq!(out.len(), ordered.len());
+            for i in 0..out.len() {
+                let sorted = |ca: &ListChunked| {
+                    ca.get_as_series(i)
+                        .unwrap()
+                        .sort(SortOptions::default())
+                        .unwrap()
+                };
+                assert!(
+                    sorted(&out).equals_missing(&sorted(&ordered)),
+                    "{set_op} row {i}"
+                );
+            }
+        }
+        Ok(())
+    }
+}
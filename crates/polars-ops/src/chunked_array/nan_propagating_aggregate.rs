@@ -228,3 +228,33 @@ pub unsafe fn group_agg_nan_max_s(s: &Series, groups: &GroupsType) -> Series {
         _ => panic!("expected float"),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::series::SeriesMethods;
+
+    #[test]
+    fn test_nan_min_max() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1.0), Some(f64::NAN), None, Some(-2.0)]);
+
+        // `max` and `min` skip NaN, the `nan_` variants propagate it.
+        assert_eq!(s.max_reduce()?.value(), &AnyValue::Float64(1.0));
+        assert_eq!(s.min_reduce()?.value(), &AnyValue::Float64(-2.0));
+        assert!(nan_max_s(&s, "a".into()).f64()?.get(0).unwrap().is_nan());
+        assert!(nan_min_s(&s, "a".into()).f64()?.get(0).unwrap().is_nan());
+
+        // `max_ignore_nan` and `min_ignore_nan` skip NaN entirely.
+        assert_eq!(s.max_ignore_nan()?, AnyValue::Float64(1.0));
+        assert_eq!(s.min_ignore_nan()?, AnyValue::Float64(-2.0));
+
+        let s = Series::new("a".into(), [Some(f64::NAN), None]);
+        assert!(s.max_reduce()?.value().extract::<f64>().unwrap().is_nan());
+        assert_eq!(s.max_ignore_nan()?, AnyValue::Null);
+        assert_eq!(s.min_ignore_nan()?, AnyValue::Null);
+
+        let s = Series::new("a".into(), [Some(3i32), None, Some(1)]);
+        assert_eq!(s.max_ignore_nan()?, AnyValue::Int32(3));
+        Ok(())
+    }
+}
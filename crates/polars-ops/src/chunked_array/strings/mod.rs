@@ -24,6 +24,8 @@ mod split;
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_to_integer")]
+mod to_integer;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
 
@@ -46,6 +48,8 @@ pub use split::*;
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "string_to_integer")]
+pub use to_integer::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+/// Options for [`to_integer_with`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ToIntegerOptions {
+    /// The base of the values, or `None` to detect it per value from a `0x`, `0o` or `0b` prefix,
+    /// and to parse values without a prefix as decimal.
+    pub base: Option<u32>,
+    /// Raise on values that can't be parsed instead of returning null.
+    pub strict: bool,
+    /// Allow single `_` separators between digits, e.g. `1_000_000`.
+    pub allow_underscores: bool,
+    /// Also return the reason why a value couldn't be parsed.
+    pub with_errors: bool,
+}
+
+impl ToIntegerOptions {
+    pub fn with_base(mut self, base: Option<u32>) -> Self {
+        self.base = base;
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_allow_underscores(mut self, allow_underscores: bool) -> Self {
+        self.allow_underscores = allow_underscores;
+        self
+    }
+
+    pub fn with_errors(mut self, with_errors: bool) -> Self {
+        self.with_errors = with_errors;
+        self
+    }
+}
+
+/// Parse a signed integer, with an optional `+` or `-` sign in every base.
+fn parse_integer(s: &str, options: &ToIntegerOptions) -> Result<i64, Cow<'static, str>> {
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (base, digits) = match options.base {
+        Some(base) => (base, rest),
+        None => match rest.get(..2) {
+            Some("0x" | "0X") => (16, &rest[2..]),
+            Some("0o" | "0O") => (8, &rest[2..]),
+            Some("0b" | "0B") => (2, &rest[2..]),
+            _ => (10, rest),
+        },
+    };
+    // `from_str_radix` accepts a sign of its own.
+    if digits.starts_with(['+', '-']) {
+        return Err("invalid digit found in string".into());
+    }
+    let digits = if options.allow_underscores && digits.contains('_') {
+        if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+            return Err("invalid placement of '_' separator".into());
+        }
+        Cow::Owned(digits.replace('_', ""))
+    } else {
+        Cow::Borrowed(digits)
+    };
+
+    let magnitude = u64::from_str_radix(&digits, base).map_err(|e| e.to_string())?;
+    if negative {
+        // `i64::MIN` has no positive counterpart.
+        (magnitude <= i64::MIN.unsigned_abs())
+            .then(|| (magnitude as i64).wrapping_neg())
+            .ok_or_else(|| "number too small to fit in target type".into())
+    } else {
+        i64::try_from(magnitude).map_err(|_| "number too large to fit in target type".into())
+    }
+}
+
+/// Parse strings into integers according to `options`.
+///
+/// Returns the parsed values and, per value, the reason why it couldn't be parsed. Null values
+/// have no error.
+pub fn to_integer_with(
+    ca: &StringChunked,
+    options: &ToIntegerOptions,
+) -> PolarsResult<(Int64Chunked, StringChunked)> {
+    if let Some(base) = options.base {
+        polars_ensure!(
+            (2..=36).contains(&base),
+            ComputeError: "`to_integer` called with invalid base '{base}'"
+        );
+    }
+
+    let mut values = Vec::with_capacity(ca.len());
+    let mut errors = Vec::with_capacity(ca.len());
+    for opt_s in ca.iter() {
+        match opt_s.map(|s| parse_integer(s, options)) {
+            Some(Ok(v)) => {
+                values.push(Some(v));
+                errors.push(None);
+            },
+            Some(Err(e)) => {
+                values.push(None);
+                errors.push(Some(e));
+            },
+            None => {
+                values.push(None);
+                errors.push(None);
+            },
+        }
+    }
+
+    if options.strict {
+        if let Some((s, e)) = ca
+            .iter()
+            .zip(&errors)
+            .find_map(|(s, e)| Some((s?, e.as_ref()?)))
+        {
+            let n_failures = errors.iter().filter(|e| e.is_some()).count();
+            polars_bail!(
+                ComputeError:
+                "strict integer parsing failed for {} value(s); error message for the first \
+                failed value '{}': '{}' (consider non-strict parsing)",
+                n_failures, s, e
+            );
+        }
+    }
+
+    let values = Int64Chunked::from_iter_options(ca.name().clone(), values.into_iter());
+    let errors = StringChunked::from_iter_options(
+        PlSmallStr::from_static("error"),
+        errors.iter().map(|e| e.as_deref()),
+    );
+    Ok((values, errors))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_integer() {
+        let detect = ToIntegerOptions::default();
+        assert_eq!(parse_integer("0x1A", &detect), Ok(26));
+        assert_eq!(parse_integer("-0b101", &detect), Ok(-5));
+        assert_eq!(parse_integer("+0o17", &detect), Ok(15));
+        assert_eq!(parse_integer("-42", &detect), Ok(-42));
+        assert_eq!(parse_integer("-0x8000000000000000", &detect), Ok(i64::MIN));
+        assert!(parse_integer("0x-1", &detect).is_err());
+        assert!(parse_integer("1_000", &detect).is_err());
+
+        let underscores = detect.with_allow_underscores(true);
+        assert_eq!(parse_integer("1_000_000", &underscores), Ok(1_000_000));
+        assert_eq!(parse_integer("0xff_ff", &underscores), Ok(0xffff));
+        assert!(parse_integer("1__0", &underscores).is_err());
+        assert!(parse_integer("_1", &underscores).is_err());
+
+        let hex = detect.with_base(Some(16));
+        assert_eq!(parse_integer("-1a", &hex), Ok(-26));
+    }
+
+    #[test]
+    fn test_to_integer_with() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), [Some("0x1A"), Some("12"), Some("zz"), None]);
+        let (values, errors) = to_integer_with(&ca, &ToIntegerOptions::default())?;
+        assert_eq!(Vec::from(&values), [Some(26), Some(12), None, None]);
+        assert_eq!(errors.null_count(), 3);
+
+        let strict = ToIntegerOptions::default().with_strict(true);
+        assert!(to_integer_with(&ca, &strict).is_err());
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ use polars_core::series::IsSorted;
 use polars_core::{POOL, downcast_as_macro_arg_physical};
 use polars_utils::total_ord::TotalOrd;
 
+use crate::prelude::SeriesSealed;
+
 fn first_n_valid_mask(num_valid: usize, out_len: usize) -> Option<Bitmap> {
     if num_valid < out_len {
         let mut bm = BitmapBuilder::with_capacity(out_len);
@@ -164,7 +166,10 @@ pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
     }
 
     let (k, src) = extract_target_and_k(s)?;
+    top_k_impl(k, src, descending)
+}
 
+fn top_k_impl(k: usize, src: &Column, descending: bool) -> PolarsResult<Column> {
     if src.is_empty() {
         return Ok(src.clone());
     }
@@ -221,6 +226,27 @@ pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
     }
 }
 
+pub trait SeriesTopK: SeriesSealed {
+    /// Returns the `k` largest values, in no particular order.
+    ///
+    /// The values are selected with a partition instead of a full sort, so this takes `O(n)`
+    /// time on average. Nulls are only returned if there are fewer than `k` other values.
+    fn top_k(&self, k: usize) -> PolarsResult<Series> {
+        let s = self.as_series();
+        top_k_impl(k, &s.clone().into_column(), false).map(Column::take_materialized_series)
+    }
+
+    /// Returns the `k` smallest values, in no particular order.
+    ///
+    /// See [`SeriesTopK::top_k`].
+    fn bottom_k(&self, k: usize) -> PolarsResult<Series> {
+        let s = self.as_series();
+        top_k_impl(k, &s.clone().into_column(), true).map(Column::take_materialized_series)
+    }
+}
+
+impl SeriesTopK for Series {}
+
 pub fn top_k_by(s: &[Column], descending: Vec<bool>) -> PolarsResult<Column> {
     /// Return (k, src, by)
     fn extract_parameters(s: &[Column]) -> PolarsResult<(usize, &Column, &[Column])> {
@@ -288,3 +314,22 @@ fn top_k_by_impl(
     };
     Ok(result.into())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_series_top_k() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(3), None, Some(9), Some(1), Some(5)]);
+
+        let sorted = |s: Series| s.sort(SortOptions::default().with_nulls_last(true));
+        assert_eq!(sorted(s.top_k(2)?)?, Series::new("a".into(), [5, 9]));
+        assert_eq!(sorted(s.bottom_k(3)?)?, Series::new("a".into(), [1, 3, 5]));
+        assert!(sorted(s.top_k(5)?)?.equals_missing(&Series::new(
+            "a".into(),
+            [Some(1), Some(3), Some(5), Some(9), None]
+        )));
+        Ok(())
+    }
+}
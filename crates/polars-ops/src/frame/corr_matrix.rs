@@ -0,0 +1,319 @@
+use arrow::bitmap::Bitmap;
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "rank")]
+use crate::series::{RankMethod, RankOptions, SeriesRank};
+
+/// Correlation coefficient computed by [`DataFrameOps::corr_matrix`].
+///
+/// [`DataFrameOps::corr_matrix`]: crate::frame::DataFrameOps::corr_matrix
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CorrMethod {
+    #[default]
+    Pearson,
+    /// Pearson correlation of the ranks. Every column is ranked once over its own non-null
+    /// values.
+    #[cfg(feature = "rank")]
+    Spearman,
+}
+
+/// Which rows take part in a correlation or covariance matrix when there are nulls.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MatrixNulls {
+    /// Every pair of columns uses the rows where both are non-null.
+    #[default]
+    Pairwise,
+    /// All pairs use the rows where none of the selected columns are null.
+    Listwise,
+}
+
+/// Running co-moments of a pair of columns.
+#[derive(Copy, Clone, Default)]
+struct CoMoment {
+    n: f64,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl CoMoment {
+    #[inline]
+    fn add(&mut self, x: f64, y: f64) {
+        self.n += 1.0;
+        let dx = x - self.mean_x;
+        self.mean_x += dx / self.n;
+        let dy = y - self.mean_y;
+        self.mean_y += dy / self.n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c_xy += dx * (y - self.mean_y);
+    }
+
+    fn combine(&mut self, other: &Self) {
+        if other.n == 0.0 {
+            return;
+        }
+        let n = self.n + other.n;
+        let dx = other.mean_x - self.mean_x;
+        let dy = other.mean_y - self.mean_y;
+        let factor = self.n * other.n / n;
+        self.m2_x += other.m2_x + dx * dx * factor;
+        self.m2_y += other.m2_y + dy * dy * factor;
+        self.c_xy += other.c_xy + dx * dy * factor;
+        self.mean_x += dx * other.n / n;
+        self.mean_y += dy * other.n / n;
+        self.n = n;
+    }
+
+    fn cov(&self, ddof: u8) -> Option<f64> {
+        let denom = self.n - ddof as f64;
+        (denom > 0.0).then(|| self.c_xy / denom)
+    }
+
+    fn pearson(&self) -> Option<f64> {
+        (self.n > 0.0).then(|| self.c_xy / (self.m2_x * self.m2_y).sqrt())
+    }
+}
+
+fn select_columns(df: &DataFrame, columns: Option<&[&str]>) -> PolarsResult<DataFrame> {
+    let df = match columns {
+        Some(columns) => df.select(columns.iter().copied())?,
+        None => df.clone(),
+    };
+    for c in df.get_columns() {
+        polars_ensure!(
+            c.dtype().is_primitive_numeric(),
+            InvalidOperation: "correlation matrix requires numeric columns, got column '{}' of dtype {}",
+            c.name(), c.dtype()
+        );
+    }
+    Ok(df)
+}
+
+/// Accumulate the co-moments of all pairs of columns `(i, j)` with `i <= j` in one pass over
+/// the rows, in parallel over blocks of rows.
+fn co_moments(columns: &[(&[f64], Option<&Bitmap>)], height: usize) -> Vec<CoMoment> {
+    let k = columns.len();
+    let n_pairs = k * (k + 1) / 2;
+    let offsets = _split_offsets(height, POOL.current_num_threads());
+
+    POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut acc = vec![CoMoment::default(); n_pairs];
+                let is_valid = |c: usize, row: usize| {
+                    columns[c]
+                        .1
+                        .is_none_or(|validity| unsafe { validity.get_bit_unchecked(row) })
+                };
+                for row in offset..offset + len {
+                    let mut pair = 0;
+                    for i in 0..k {
+                        if !is_valid(i, row) {
+                            pair += k - i;
+                            continue;
+                        }
+                        let x = columns[i].0[row];
+                        for (j, (values, _)) in columns.iter().enumerate().skip(i) {
+                            if is_valid(j, row) {
+                                acc[pair].add(x, values[row]);
+                            }
+                            pair += 1;
+                        }
+                    }
+                }
+                acc
+            })
+            .reduce_with(|mut a, b| {
+                for (a, b) in a.iter_mut().zip(&b) {
+                    a.combine(b);
+                }
+                a
+            })
+            .unwrap_or_else(|| vec![CoMoment::default(); n_pairs])
+    })
+}
+
+fn matrix(
+    df: &DataFrame,
+    nulls: MatrixNulls,
+    finish: impl Fn(&CoMoment) -> Option<f64>,
+) -> PolarsResult<DataFrame> {
+    let df = match nulls {
+        MatrixNulls::Pairwise => df.clone(),
+        MatrixNulls::Listwise => df.drop_nulls::<String>(None)?,
+    };
+    let casted = df
+        .get_columns()
+        .iter()
+        .map(|c| {
+            let c = c.cast(&DataType::Float64)?;
+            Ok(c.f64()?.rechunk().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let arrays = casted
+        .iter()
+        .map(|ca| {
+            let arr = ca.downcast_as_array();
+            (arr.values().as_slice(), arr.validity())
+        })
+        .collect::<Vec<_>>();
+
+    let k = arrays.len();
+    let acc = co_moments(&arrays, df.height());
+    // Index of the pair (i, j) with i <= j in the upper triangle, row by row.
+    let pair_idx = |i: usize, j: usize| {
+        let (i, j) = (i.min(j), i.max(j));
+        i * k - (i * i.saturating_sub(1)) / 2 + (j - i)
+    };
+
+    let mut columns = Vec::with_capacity(k + 1);
+    columns.push(Column::new("column".into(), df.get_column_names_str()));
+    for (j, name) in df.get_column_names_owned().into_iter().enumerate() {
+        let values: Float64Chunked = (0..k).map(|i| finish(&acc[pair_idx(i, j)])).collect();
+        columns.push(values.with_name(name).into_column());
+    }
+    DataFrame::new(columns)
+}
+
+pub(super) fn cov_matrix(
+    df: &DataFrame,
+    columns: Option<&[&str]>,
+    ddof: u8,
+    nulls: MatrixNulls,
+) -> PolarsResult<DataFrame> {
+    let df = select_columns(df, columns)?;
+    matrix(&df, nulls, |m| m.cov(ddof))
+}
+
+pub(super) fn corr_matrix(
+    df: &DataFrame,
+    columns: Option<&[&str]>,
+    method: CorrMethod,
+    nulls: MatrixNulls,
+) -> PolarsResult<DataFrame> {
+    let df = select_columns(df, columns)?;
+    match method {
+        CorrMethod::Pearson => matrix(&df, nulls, CoMoment::pearson),
+        #[cfg(feature = "rank")]
+        CorrMethod::Spearman => {
+            let df = match nulls {
+                MatrixNulls::Pairwise => df,
+                MatrixNulls::Listwise => df.drop_nulls::<String>(None)?,
+            };
+            let options = RankOptions {
+                method: RankMethod::Average,
+                ..Default::default()
+            };
+            let ranked = df
+                .get_columns()
+                .iter()
+                .map(|c| Column::from(c.as_materialized_series().rank(options, None)))
+                .collect::<Vec<_>>();
+            // SAFETY: ranking preserves the height and names of the columns.
+            let ranked = unsafe { DataFrame::new_no_checks(df.height(), ranked) };
+            matrix(&ranked, MatrixNulls::Pairwise, CoMoment::pearson)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+    use crate::chunked_array::cov::{cov, pearson_corr};
+
+    fn assert_close(a: Option<f64>, b: Option<f64>) {
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert!((a - b).abs() < 1e-10, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_corr_matrix() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "b" => [2.0, 1.0, 4.0, 3.0, 7.0],
+            "c" => [9.0, 7.0, 4.0, 5.0, 1.0],
+        ]?;
+        let corr = corr_matrix(&df, None, CorrMethod::Pearson, MatrixNulls::Pairwise)?;
+        let cov_df = cov_matrix(&df, Some(&["a", "c"]), 1, MatrixNulls::Pairwise)?;
+        assert_eq!(corr.shape(), (3, 4));
+        assert_eq!(cov_df.shape(), (2, 3));
+
+        let names = ["a", "b", "c"];
+        for (i, x) in names.iter().enumerate() {
+            for y in names {
+                let (x_ca, y_ca) = (df.column(x)?.f64()?, df.column(y)?.f64()?);
+                let out = corr.column(y)?.f64()?.get(i);
+                assert_close(out, pearson_corr(x_ca, y_ca));
+            }
+        }
+        let a = df.column("a")?.f64()?;
+        let c = df.column("c")?.f64()?;
+        assert_close(cov_df.column("c")?.f64()?.get(0), cov(a, c, 1));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rank")]
+    fn test_corr_matrix_spearman() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "b" => [2.0, 1.0, 4.0, 3.0, 7.0],
+            "c" => [9.0, 7.0, 4.0, 5.0, 1.0],
+            "d" => [1.0, 8.0, 27.0, 64.0, 125.0],
+            "e" => [1, 1, 2, 2, 3],
+        ]?;
+        let corr = corr_matrix(&df, None, CorrMethod::Spearman, MatrixNulls::Pairwise)?;
+        let a = |name: &str| corr.column(name).unwrap().f64().unwrap().get(0);
+
+        // 1 - 6 * sum(d^2) / (n * (n^2 - 1)) without ties.
+        assert_close(a("b"), Some(0.8));
+        assert_close(a("c"), Some(-0.9));
+        // Any monotonic relation has a correlation of 1.
+        assert_close(a("d"), Some(1.0));
+        // Ties get the average of their ranks.
+        let ranks_e = Float64Chunked::new("e".into(), [1.5, 1.5, 3.5, 3.5, 5.0]);
+        let ranks_a = df.column("a")?.f64()?;
+        assert_close(a("e"), pearson_corr(ranks_a, &ranks_e));
+
+        // Listwise, the ranks are computed over the complete rows only.
+        let df = df![
+            "a" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0)],
+            "b" => [Some(3.0), None, Some(1.0), Some(2.0)],
+        ]?;
+        let corr = corr_matrix(&df, None, CorrMethod::Spearman, MatrixNulls::Listwise)?;
+        assert_close(corr.column("b")?.f64()?.get(0), Some(-0.5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_corr_matrix_nulls() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0)],
+            "b" => [Some(1.0), None, Some(2.0), Some(5.0)],
+        ]?;
+        let pairwise = cov_matrix(&df, None, 1, MatrixNulls::Pairwise)?;
+        let listwise = cov_matrix(&df, None, 1, MatrixNulls::Listwise)?;
+
+        // The variance of `a` only excludes the null row of `b` when listwise.
+        assert_close(pairwise.column("a")?.f64()?.get(0), Some(5.0 / 3.0));
+        assert_close(listwise.column("a")?.f64()?.get(0), Some(7.0 / 3.0));
+        assert_eq!(
+            pairwise.column("b")?.f64()?.get(0),
+            listwise.column("b")?.f64()?.get(0)
+        );
+        Ok(())
+    }
+}
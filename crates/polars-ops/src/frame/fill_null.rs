@@ -0,0 +1,82 @@
+use polars_core::prelude::*;
+
+/// How the nulls of a single column are filled by [`DataFrameOps::fill_null_with`].
+///
+/// [`DataFrameOps::fill_null_with`]: super::DataFrameOps::fill_null_with
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub enum ColumnFillNullStrategy {
+    /// Fill the nulls with a [`FillNullStrategy`].
+    Strategy(FillNullStrategy),
+    /// Interpolate the nulls linearly against the values of another column, e.g. a time column.
+    #[cfg(feature = "interpolate_by")]
+    InterpolateBy(PlSmallStr),
+}
+
+impl From<FillNullStrategy> for ColumnFillNullStrategy {
+    fn from(strategy: FillNullStrategy) -> Self {
+        Self::Strategy(strategy)
+    }
+}
+
+pub(super) fn fill_null_with(
+    df: &DataFrame,
+    strategies: &PlIndexMap<PlSmallStr, ColumnFillNullStrategy>,
+) -> PolarsResult<DataFrame> {
+    let mut out = df.clone();
+    for (name, strategy) in strategies {
+        // Every column is filled based on the input frame, so a `by` column can be filled too.
+        let column = df.column(name)?;
+        let filled = match strategy {
+            ColumnFillNullStrategy::Strategy(strategy) => column.fill_null(*strategy)?,
+            #[cfg(feature = "interpolate_by")]
+            ColumnFillNullStrategy::InterpolateBy(by) => {
+                use crate::series::SeriesMethods;
+
+                let by = df.column(by)?;
+                let by_is_sorted = by.as_materialized_series().is_sorted(Default::default())?;
+                crate::series::interpolate_by(column, by, by_is_sorted)?
+            },
+        };
+        out.replace(name, filled.take_materialized_series())?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "interpolate_by")]
+    fn test_fill_null_with() -> PolarsResult<()> {
+        let df = df![
+            "t" => [0i64, 1, 3, 4],
+            "a" => [Some(1.0), None, Some(4.0), None],
+            "b" => [None, Some(2), None, Some(4)],
+            "c" => [None, Some(1), None, None],
+        ]?;
+
+        let mut strategies = PlIndexMap::new();
+        strategies.insert("b".into(), FillNullStrategy::Forward(None).into());
+        strategies.insert("c".into(), FillNullStrategy::Zero.into());
+        strategies.insert(
+            "a".into(),
+            ColumnFillNullStrategy::InterpolateBy("t".into()),
+        );
+
+        let out = fill_null_with(&df, &strategies)?;
+        let expected = df![
+            "t" => [0i64, 1, 3, 4],
+            "a" => [Some(1.0), Some(2.0), Some(4.0), None],
+            "b" => [None, Some(2), Some(2), Some(4)],
+            "c" => [0, 1, 0, 0],
+        ]?;
+        assert_eq!(out, expected);
+
+        strategies.insert("d".into(), FillNullStrategy::Zero.into());
+        assert!(fill_null_with(&df, &strategies).is_err());
+        Ok(())
+    }
+}
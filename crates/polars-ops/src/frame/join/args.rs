@@ -36,6 +36,45 @@ impl JoinArgs {
     pub fn should_coalesce(&self) -> bool {
         self.coalesce.coalesce(&self.how)
     }
+
+    /// Returns the left and right names of the join keys listed in [`JoinCoalesce::Columns`].
+    ///
+    /// The join itself keeps all key columns for this policy, the listed keys are coalesced
+    /// afterwards.
+    pub fn coalesced_keys(
+        &self,
+        left_on: &[PlSmallStr],
+        right_on: &[PlSmallStr],
+    ) -> PolarsResult<(Vec<PlSmallStr>, Vec<PlSmallStr>)> {
+        let JoinCoalesce::Columns(columns) = &self.coalesce else {
+            return Ok((vec![], vec![]));
+        };
+        polars_ensure!(
+            matches!(
+                self.how,
+                JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+            ),
+            InvalidOperation: "coalescing specific columns is not supported for {} joins", self.how
+        );
+
+        columns
+            .iter()
+            .map(|name| {
+                let idx = left_on
+                    .iter()
+                    .zip(right_on)
+                    .position(|(l, r)| l == name || r == name)
+                    .ok_or_else(|| {
+                        polars_err!(
+                            InvalidOperation:
+                            "cannot coalesce column '{}': it is not a join key", name
+                        )
+                    })?;
+                Ok((left_on[idx].clone(), right_on[idx].clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+            .map(|keys| keys.into_iter().unzip())
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Default, IntoStaticStr)]
@@ -60,7 +99,7 @@ pub enum JoinType {
     Cross,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum JoinCoalesce {
@@ -68,9 +107,17 @@ pub enum JoinCoalesce {
     JoinSpecific,
     CoalesceColumns,
     KeepColumns,
+    /// Only coalesce the listed join keys, the other keys are kept as left and right columns.
+    ///
+    /// A key can be listed by its left or right name. Only keys that are plain columns on both
+    /// sides can be listed.
+    Columns(Vec<PlSmallStr>),
 }
 
 impl JoinCoalesce {
+    /// Whether the join itself coalesces the keys.
+    ///
+    /// This is `false` for [`JoinCoalesce::Columns`], whose keys are coalesced after the join.
     pub fn coalesce(&self, join_type: &JoinType) -> bool {
         use JoinCoalesce::*;
         use JoinType::*;
@@ -322,7 +369,7 @@ impl JoinValidation {
         !matches!(self, JoinValidation::ManyToMany)
     }
 
-    fn swap(self, swap: bool) -> Self {
+    pub fn swap(self, swap: bool) -> Self {
         use JoinValidation::*;
         if swap {
             match self {
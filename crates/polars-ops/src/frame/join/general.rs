@@ -1,3 +1,4 @@
+use arrow::datatypes::Metadata;
 use polars_utils::format_pl_smallstr;
 
 use super::*;
@@ -7,6 +8,54 @@ pub fn _join_suffix_name(name: &str, suffix: &str) -> PlSmallStr {
     format_pl_smallstr!("{name}{suffix}")
 }
 
+/// Give the output columns of a join the user metadata of the input columns they come from.
+///
+/// The gathers of a join don't keep the metadata. A column that exists in both frames, such as a
+/// coalesced key, gets the metadata of the left frame. The right column it collides with gets its
+/// own metadata under the suffixed name.
+#[doc(hidden)]
+pub fn _propagate_join_metadata(
+    mut out: DataFrame,
+    left: &DataFrame,
+    right: &DataFrame,
+    suffix: &str,
+) -> DataFrame {
+    fn metadata(c: &Column) -> Option<Arc<Metadata>> {
+        c.as_series()?.metadata()
+    }
+
+    let has_metadata = |df: &DataFrame| df.get_columns().iter().any(|c| metadata(c).is_some());
+    if !has_metadata(left) && !has_metadata(right) {
+        return out;
+    }
+
+    let source_metadata = |name: &PlSmallStr| {
+        if let Ok(c) = left.column(name) {
+            return metadata(c);
+        }
+        let right_name = match name.strip_suffix(suffix) {
+            Some(stripped) if left.get_column_index(stripped).is_some() => stripped,
+            _ => name.as_str(),
+        };
+        right.column(right_name).ok().and_then(metadata)
+    };
+
+    // SAFETY: the names and lengths of the columns don't change.
+    for c in unsafe { out.get_columns_mut() } {
+        if metadata(c).is_some() {
+            continue;
+        }
+        if let Some(md) = source_metadata(c.name()) {
+            *c = c
+                .as_materialized_series()
+                .clone()
+                .with_metadata(Some(md))
+                .into_column();
+        }
+    }
+    out
+}
+
 fn get_suffix(suffix: Option<PlSmallStr>) -> PlSmallStr {
     suffix.unwrap_or_else(|| PlSmallStr::from_static("_right"))
 }
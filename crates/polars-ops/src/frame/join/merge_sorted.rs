@@ -1,7 +1,11 @@
+use arrow::array::builder::ShareStrategy;
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
+use polars_core::series::builder::SeriesBuilder;
 use polars_core::with_match_physical_numeric_polars_type;
 
+use crate::series::SeriesMethods;
+
 fn check_and_union_revmaps(
     lhs_revmap: &Option<Arc<RevMapping>>,
     rhs_revmap: &Option<Arc<RevMapping>>,
@@ -27,12 +31,20 @@ fn check_and_union_revmaps(
     }
 }
 
+/// Merge two [`DataFrame`]s that are sorted in ascending order by the keys `left_s` and
+/// `right_s`, with their nulls placed last if `nulls_last` is set and first otherwise.
+///
+/// An error is returned if the nulls of a key are placed on the other end. If `validate_sorted`
+/// is set, an error is also returned if one of the keys is not sorted, instead of silently
+/// producing a frame that is not sorted either.
 pub fn _merge_sorted_dfs(
     left: &DataFrame,
     right: &DataFrame,
     left_s: &Series,
     right_s: &Series,
     check_schema: bool,
+    validate_sorted: bool,
+    nulls_last: bool,
 ) -> PolarsResult<DataFrame> {
     if check_schema {
         left.schema_equal(right)?;
@@ -54,6 +66,35 @@ pub fn _merge_sorted_dfs(
         );
     }
 
+    let null_ordering = if nulls_last {
+        NullOrdering::NullsLast
+    } else {
+        NullOrdering::NullsFirst
+    };
+
+    for (side, s) in [("left", left_s), ("right", right_s)] {
+        polars_ensure!(
+            has_null_ordering(s, null_ordering),
+            InvalidOperation: "cannot merge-sort: the nulls of the {} key column '{}' are not placed {}",
+            side, s.name(), if nulls_last { "last" } else { "first" }
+        );
+    }
+
+    if validate_sorted {
+        let options = SortOptions {
+            nulls_last,
+            ..Default::default()
+        };
+        for (side, s) in [("left", left_s), ("right", right_s)] {
+            polars_ensure!(
+                s.is_sorted(options)?,
+                InvalidOperation: "cannot merge-sort: the {} key column '{}' is not sorted in ascending order; \
+                sort both inputs by the key first",
+                side, s.name()
+            );
+        }
+    }
+
     // If one frame is empty, we can return the other immediately.
     if right_s.is_empty() {
         return Ok(left.clone());
@@ -61,7 +102,7 @@ pub fn _merge_sorted_dfs(
         return Ok(right.clone());
     }
 
-    let merge_indicator = series_to_merge_indicator(left_s, right_s)?;
+    let merge_indicator = series_to_merge_indicator(left_s, right_s, null_ordering)?;
     let new_columns = left
         .get_columns()
         .iter()
@@ -98,7 +139,112 @@ pub fn _merge_sorted_dfs(
     Ok(unsafe { DataFrame::new_no_checks(left.height() + right.height(), new_columns) })
 }
 
+/// Runs of the same side in the merge indicator that are at least this long are copied in bulk
+/// instead of element by element.
+const MIN_BULK_COPY_RUN: usize = 64;
+
+/// Run-length encode `merge_indicator`, returning the `(start, length)` of the runs that are long
+/// enough to be copied in bulk.
+fn bulk_copy_runs(merge_indicator: &[bool]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < merge_indicator.len() {
+        let rest = &merge_indicator[start..];
+        let len = rest
+            .iter()
+            .position(|&a_indicator| a_indicator != rest[0])
+            .unwrap_or(rest.len());
+        if len >= MIN_BULK_COPY_RUN {
+            runs.push((start, len));
+        }
+        start += len;
+    }
+    runs
+}
+
 fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> PolarsResult<Series> {
+    // Checked up front, as the bulk copied runs would otherwise silently carry the outer nulls.
+    #[cfg(feature = "dtype-struct")]
+    if matches!(lhs.dtype(), DataType::Struct(_)) {
+        polars_ensure!(lhs.null_count() + rhs.null_count() == 0, InvalidOperation: "merge sorted with structs with outer nulls not yet supported");
+    }
+
+    let runs = bulk_copy_runs(merge_indicator);
+    if runs.is_empty() {
+        return merge_series_elementwise(lhs, rhs, merge_indicator);
+    }
+
+    let mut builder = SeriesBuilder::new(lhs.dtype().clone());
+    builder.reserve(merge_indicator.len());
+    let mut a_offset = 0;
+    let mut b_offset = 0;
+
+    let mut end_prev_run = 0;
+    for (start, len) in runs {
+        extend_interleaved(
+            &mut builder,
+            lhs,
+            rhs,
+            &merge_indicator[end_prev_run..start],
+            &mut a_offset,
+            &mut b_offset,
+        )?;
+
+        let (s, offset) = if merge_indicator[start] {
+            (lhs, &mut a_offset)
+        } else {
+            (rhs, &mut b_offset)
+        };
+        builder.subslice_extend(s, *offset, len, ShareStrategy::Always);
+        *offset += len;
+        end_prev_run = start + len;
+    }
+    extend_interleaved(
+        &mut builder,
+        lhs,
+        rhs,
+        &merge_indicator[end_prev_run..],
+        &mut a_offset,
+        &mut b_offset,
+    )?;
+
+    Ok(builder.freeze(PlSmallStr::EMPTY))
+}
+
+/// Merge the rows of `lhs` and `rhs` starting at the given offsets element by element, as
+/// directed by `merge_indicator`, and advance the offsets past them.
+fn extend_interleaved(
+    builder: &mut SeriesBuilder,
+    lhs: &Series,
+    rhs: &Series,
+    merge_indicator: &[bool],
+    a_offset: &mut usize,
+    b_offset: &mut usize,
+) -> PolarsResult<()> {
+    if merge_indicator.is_empty() {
+        return Ok(());
+    }
+    let a_len = merge_indicator
+        .iter()
+        .filter(|&&a_indicator| a_indicator)
+        .count();
+    let b_len = merge_indicator.len() - a_len;
+    let merged = merge_series_elementwise(
+        &lhs.slice(*a_offset as i64, a_len),
+        &rhs.slice(*b_offset as i64, b_len),
+        merge_indicator,
+    )?;
+    builder.extend(&merged, ShareStrategy::Always);
+    *a_offset += a_len;
+    *b_offset += b_len;
+    Ok(())
+}
+
+fn merge_series_elementwise(
+    lhs: &Series,
+    rhs: &Series,
+    merge_indicator: &[bool],
+) -> PolarsResult<Series> {
     use DataType::*;
     let out = match lhs.dtype() {
         Boolean => {
@@ -123,7 +269,6 @@ fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> PolarsR
         Struct(_) => {
             let lhs = lhs.struct_().unwrap();
             let rhs = rhs.struct_().unwrap();
-            polars_ensure!(lhs.null_count() + rhs.null_count() == 0, InvalidOperation: "merge sorted with structs with outer nulls not yet supported");
 
             let new_fields = lhs
                 .fields_as_series()
@@ -181,12 +326,36 @@ where
     unsafe { iter.trust_my_length(total_len).collect_trusted() }
 }
 
-fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> PolarsResult<Vec<bool>> {
+/// Where the nulls are placed in a sorted key column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NullOrdering {
+    NullsFirst,
+    NullsLast,
+}
+
+/// Whether all nulls of the key column `s` are at the end given by `null_ordering`.
+fn has_null_ordering(s: &Series, null_ordering: NullOrdering) -> bool {
+    let null_count = s.null_count();
+    if null_count == 0 || null_count == s.len() {
+        return true;
+    }
+    let nulls = match null_ordering {
+        NullOrdering::NullsFirst => s.slice(0, null_count),
+        NullOrdering::NullsLast => s.slice(-(null_count as i64), null_count),
+    };
+    nulls.null_count() == null_count
+}
+
+fn series_to_merge_indicator(
+    lhs: &Series,
+    rhs: &Series,
+    null_ordering: NullOrdering,
+) -> PolarsResult<Vec<bool>> {
     if lhs.dtype().is_categorical() {
         let lhs_ca = lhs.categorical().unwrap();
         if lhs_ca.uses_lexical_ordering() {
             let rhs_ca = rhs.categorical().unwrap();
-            let out = get_merge_indicator(lhs_ca.iter_str(), rhs_ca.iter_str());
+            let out = get_merge_indicator(lhs_ca.iter_str(), rhs_ca.iter_str(), null_ordering);
             return Ok(out);
         }
     }
@@ -198,31 +367,34 @@ fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> PolarsResult<Vec<boo
         DataType::Boolean => {
             let lhs = lhs_s.bool().unwrap();
             let rhs = rhs_s.bool().unwrap();
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), null_ordering)
         },
         DataType::String => {
             let lhs = lhs.str().unwrap().as_binary();
             let rhs = rhs.str().unwrap().as_binary();
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), null_ordering)
         },
         DataType::Binary => {
             let lhs = lhs_s.binary().unwrap();
             let rhs = rhs_s.binary().unwrap();
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), null_ordering)
         },
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => {
-            let options = SortOptions::default();
+            let options = SortOptions {
+                nulls_last: null_ordering == NullOrdering::NullsLast,
+                ..Default::default()
+            };
             let lhs = lhs_s.struct_().unwrap().get_row_encoded(options)?;
             let rhs = rhs_s.struct_().unwrap().get_row_encoded(options)?;
-            get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+            get_merge_indicator(lhs.into_iter(), rhs.into_iter(), null_ordering)
         },
         _ => {
             with_match_physical_numeric_polars_type!(lhs_s.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs_s.as_ref().as_ref().as_ref();
                     let rhs: &ChunkedArray<$T> = rhs_s.as_ref().as_ref().as_ref();
 
-                    get_merge_indicator(lhs.into_iter(), rhs.into_iter())
+                    get_merge_indicator(lhs.into_iter(), rhs.into_iter(), null_ordering)
 
             })
         },
@@ -233,25 +405,40 @@ fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> PolarsResult<Vec<boo
 // get a boolean values, left: true, right: false
 // that indicate from which side we should take a value
 fn get_merge_indicator<T>(
-    mut a_iter: impl ExactSizeIterator<Item = T>,
-    mut b_iter: impl ExactSizeIterator<Item = T>,
+    mut a_iter: impl ExactSizeIterator<Item = Option<T>>,
+    mut b_iter: impl ExactSizeIterator<Item = Option<T>>,
+    null_ordering: NullOrdering,
 ) -> Vec<bool>
 where
-    T: PartialOrd + Default + Copy,
+    T: PartialOrd + Copy,
 {
     const A_INDICATOR: bool = true;
     const B_INDICATOR: bool = false;
 
+    let nulls_first = null_ordering == NullOrdering::NullsFirst;
+    let le = |a: Option<T>, b: Option<T>| match (a, b) {
+        (None, None) => true,
+        (None, Some(_)) => nulls_first,
+        (Some(_), None) => !nulls_first,
+        (Some(a), Some(b)) => a <= b,
+    };
+    let lt = |a: Option<T>, b: Option<T>| match (a, b) {
+        (None, None) => false,
+        (None, Some(_)) => nulls_first,
+        (Some(_), None) => !nulls_first,
+        (Some(a), Some(b)) => a < b,
+    };
+
     let a_len = a_iter.size_hint().0;
     let b_len = b_iter.size_hint().0;
     if a_len == 0 {
-        return vec![true; b_len];
+        return vec![B_INDICATOR; b_len];
     };
     if b_len == 0 {
-        return vec![false; a_len];
+        return vec![A_INDICATOR; a_len];
     }
 
-    let mut current_a = T::default();
+    let mut current_a = None;
     let cap = a_len + b_len;
     let mut out = Vec::with_capacity(cap);
 
@@ -259,7 +446,7 @@ where
 
     for a in &mut a_iter {
         current_a = a;
-        if a <= current_b {
+        if le(a, current_b) {
             out.push(A_INDICATOR);
             continue;
         }
@@ -268,7 +455,7 @@ where
         loop {
             if let Some(b) = b_iter.next() {
                 current_b = b;
-                if b >= a {
+                if le(a, b) {
                     out.push(A_INDICATOR);
                     break;
                 }
@@ -281,7 +468,7 @@ where
             return out;
         }
     }
-    if current_a < current_b {
+    if lt(current_a, current_b) {
         out.push(B_INDICATOR);
     }
     // check if current value already is added
@@ -297,8 +484,12 @@ where
 
 #[test]
 fn test_merge_sorted() {
-    fn get_merge_indicator_sliced<T: PartialOrd + Default + Copy>(a: &[T], b: &[T]) -> Vec<bool> {
-        get_merge_indicator(a.iter().copied(), b.iter().copied())
+    fn get_merge_indicator_sliced<T: PartialOrd + Copy>(a: &[T], b: &[T]) -> Vec<bool> {
+        get_merge_indicator(
+            a.iter().copied().map(Some),
+            b.iter().copied().map(Some),
+            NullOrdering::NullsFirst,
+        )
     }
 
     let a = [1, 2, 4, 6, 9];
@@ -331,3 +522,146 @@ fn test_merge_sorted() {
     let expected = [true, true, true, false, false, false, false];
     assert_eq!(out, expected);
 }
+
+#[test]
+fn test_merge_sorted_nulls() {
+    let a = [None, Some(1), Some(3)];
+    let b = [None, Some(2)];
+    let out = get_merge_indicator(
+        a.iter().copied(),
+        b.iter().copied(),
+        NullOrdering::NullsFirst,
+    );
+    // null, null, 1, 2, 3
+    let expected = [true, false, true, false, true];
+    assert_eq!(out, expected);
+
+    let a = [Some(1), Some(3), None];
+    let b = [Some(2), None];
+    let out = get_merge_indicator(
+        a.iter().copied(),
+        b.iter().copied(),
+        NullOrdering::NullsLast,
+    );
+    // 1, 2, 3, null, null
+    let expected = [true, false, true, true, false];
+    assert_eq!(out, expected);
+
+    let a = [Some(1), Some(3)];
+    let b = [Some(2), None, None];
+    let out = get_merge_indicator(
+        a.iter().copied(),
+        b.iter().copied(),
+        NullOrdering::NullsLast,
+    );
+    // 1, 2, 3, null, null
+    let expected = [true, false, true, false, false];
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_merge_sorted_validate_sorted() -> PolarsResult<()> {
+    use polars_core::df;
+
+    let left = df!["a" => [1, 3, 2]]?;
+    let right = df!["a" => [2, 4]]?;
+    let left_s = left.column("a")?.as_materialized_series();
+    let right_s = right.column("a")?.as_materialized_series();
+    let err = _merge_sorted_dfs(&left, &right, left_s, right_s, true, true, false).unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+
+    let left = df!["a" => [1, 2, 3]]?;
+    let left_s = left.column("a")?.as_materialized_series();
+    let out = _merge_sorted_dfs(&left, &right, left_s, right_s, true, true, false)?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(1), Some(2), Some(2), Some(3), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merge_sorted_null_ordering() -> PolarsResult<()> {
+    use polars_core::df;
+
+    // The placement can't be seen from the data when one side is all null and the other has no
+    // nulls, so it is taken from `nulls_last`.
+    let left = df!["a" => [None::<i32>, None]]?;
+    let right = df!["a" => [1, 2]]?;
+    let left_s = left.column("a")?.as_materialized_series();
+    let right_s = right.column("a")?.as_materialized_series();
+    let out = _merge_sorted_dfs(&left, &right, left_s, right_s, true, true, true)?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(1), Some(2), None, None]
+    );
+    let out = _merge_sorted_dfs(&left, &right, left_s, right_s, true, true, false)?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [None, None, Some(1), Some(2)]
+    );
+
+    // Nulls on the other end are an error, even without validating the order.
+    let right = df!["a" => [Some(1), None]]?;
+    let right_s = right.column("a")?.as_materialized_series();
+    let err = _merge_sorted_dfs(&left, &right, left_s, right_s, true, false, false).unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let out = _merge_sorted_dfs(&left, &right, left_s, right_s, true, false, true)?;
+    assert_eq!(
+        out.column("a")?.i32()?.to_vec(),
+        [Some(1), None, None, None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merge_sorted_bulk_copy() -> PolarsResult<()> {
+    use polars_core::df;
+
+    fn value(key: i32) -> Option<String> {
+        (key % 7 != 0).then(|| key.to_string())
+    }
+    fn frame(keys: Vec<i32>) -> PolarsResult<DataFrame> {
+        let values: Vec<_> = keys.iter().map(|&k| value(k)).collect();
+        let flags: Vec<_> = keys.iter().map(|&k| k % 3 == 0).collect();
+        df!["key" => keys, "value" => values, "flag" => flags]
+    }
+
+    // Long runs at the start and the end, with interleaved rows in between.
+    let left_keys: Vec<i32> = (0..100).chain((100..140).step_by(2)).collect();
+    let right_keys: Vec<i32> = (101..140).step_by(2).chain(200..300).collect();
+    let left = frame(left_keys)?;
+    let right = frame(right_keys)?;
+
+    let left_s = left.column("key")?.as_materialized_series();
+    let right_s = right.column("key")?.as_materialized_series();
+    let merge_indicator = series_to_merge_indicator(left_s, right_s, NullOrdering::NullsFirst)?;
+    assert_eq!(bulk_copy_runs(&merge_indicator), [(0, 101), (139, 101)]);
+
+    let out = _merge_sorted_dfs(&left, &right, left_s, right_s, true, false, false)?;
+    let expected = frame((0..140).chain(200..300).collect())?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_merge_sorted_struct_outer_nulls() -> PolarsResult<()> {
+    use polars_core::df;
+
+    // Long enough for the rows to be copied in bulk.
+    let mut validity = vec![true; 100];
+    validity[50] = false;
+    let left = df!["key" => (0..100).collect::<Vec<i32>>()]?
+        .into_struct("s".into())
+        .with_outer_validity(Some(validity.into_iter().collect()))
+        .into_series();
+    let right = df!["key" => (100..200).collect::<Vec<i32>>()]?
+        .into_struct("s".into())
+        .into_series();
+
+    let merge_indicator = [vec![true; 100], vec![false; 100]].concat();
+    let err = merge_series(&left, &right, &merge_indicator).unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
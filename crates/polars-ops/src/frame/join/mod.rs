@@ -27,7 +27,7 @@ pub use cross_join::CrossJoin;
 use either::Either;
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
-pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
+pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name, _propagate_join_metadata};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "iejoin")]
@@ -121,8 +121,33 @@ pub trait DataFrameJoinOps: IntoDf {
 
     #[doc(hidden)]
     #[allow(clippy::too_many_arguments)]
-    #[allow(unused_mut)]
     fn _join_impl(
+        &self,
+        other: &DataFrame,
+        selected_left: Vec<Series>,
+        selected_right: Vec<Series>,
+        args: JoinArgs,
+        options: Option<JoinTypeOptions>,
+        _check_rechunk: bool,
+        _verbose: bool,
+    ) -> PolarsResult<DataFrame> {
+        let suffix = args.suffix().clone();
+        let out = self._join_impl_without_metadata(
+            other,
+            selected_left,
+            selected_right,
+            args,
+            options,
+            _check_rechunk,
+            _verbose,
+        )?;
+        Ok(_propagate_join_metadata(out, self.to_df(), other, &suffix))
+    }
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(unused_mut)]
+    fn _join_impl_without_metadata(
         &self,
         other: &DataFrame,
         mut selected_left: Vec<Series>,
@@ -134,6 +159,30 @@ pub trait DataFrameJoinOps: IntoDf {
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
 
+        if let JoinCoalesce::Columns(_) = &args.coalesce {
+            let names = |s: &[Series]| s.iter().map(|s| s.name().clone()).collect::<Vec<_>>();
+            let (keys_left, keys_right) =
+                args.coalesced_keys(&names(&selected_left), &names(&selected_right))?;
+            let suffix = args.suffix.clone();
+            args.coalesce = JoinCoalesce::KeepColumns;
+            let out = self._join_impl(
+                other,
+                selected_left,
+                selected_right,
+                args,
+                options,
+                _check_rechunk,
+                _verbose,
+            )?;
+            return Ok(_coalesce_full_join(
+                out,
+                &keys_left,
+                &keys_right,
+                suffix,
+                left_df,
+            ));
+        }
+
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = args.how {
             if let Some(JoinTypeOptions::Cross(cross_options)) = &options {
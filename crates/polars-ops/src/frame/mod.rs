@@ -1,7 +1,16 @@
+#[cfg(feature = "cov")]
+mod corr_matrix;
+mod fill_null;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "rolling_window")]
+mod rolling_map;
+mod value_counts;
 
+#[cfg(feature = "cov")]
+pub use corr_matrix::{CorrMethod, MatrixNulls};
+pub use fill_null::ColumnFillNullStrategy;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::POOL;
@@ -113,4 +122,82 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compute the correlation between every pair of the given numeric `columns`, or of all
+    /// columns if `None`.
+    ///
+    /// Returns a square matrix with a leading `column` label column, computed in a single pass
+    /// over the rows.
+    #[cfg(feature = "cov")]
+    fn corr_matrix(
+        &self,
+        columns: Option<&[&str]>,
+        method: CorrMethod,
+        nulls: MatrixNulls,
+    ) -> PolarsResult<DataFrame> {
+        corr_matrix::corr_matrix(self.to_df(), columns, method, nulls)
+    }
+
+    /// Compute the covariance between every pair of the given numeric `columns`, or of all
+    /// columns if `None`. See [`DataFrameOps::corr_matrix`].
+    #[cfg(feature = "cov")]
+    fn cov_matrix(
+        &self,
+        columns: Option<&[&str]>,
+        ddof: u8,
+        nulls: MatrixNulls,
+    ) -> PolarsResult<DataFrame> {
+        corr_matrix::cov_matrix(self.to_df(), columns, ddof, nulls)
+    }
+
+    /// Fill the nulls of the columns in `strategies`, each with its own strategy.
+    ///
+    /// Columns that are not in `strategies` are left untouched. Every column is filled based on
+    /// the values of `self`, so a column that is filled can still be used as the `by` column of
+    /// [`ColumnFillNullStrategy::InterpolateBy`]. Errors if a column doesn't exist.
+    fn fill_null_with(
+        &self,
+        strategies: &PlIndexMap<PlSmallStr, ColumnFillNullStrategy>,
+    ) -> PolarsResult<DataFrame> {
+        fill_null::fill_null_with(self.to_df(), strategies)
+    }
+
+    /// Count the distinct combinations of the values of the `subset` columns, or of all columns
+    /// if `None`.
+    ///
+    /// Returns the combinations and a `name` column with their counts, or their proportions if
+    /// `normalize` is set. Null values are counted like any other value. If `as_struct` is set,
+    /// the combinations are returned as a single struct column `values`, like the output of the
+    /// `value_counts` expression, and `name` can't be `values`. If `sort` is set, the most
+    /// frequent combinations come first.
+    fn value_counts(
+        &self,
+        subset: Option<&[&str]>,
+        sort: bool,
+        normalize: bool,
+        name: PlSmallStr,
+        as_struct: bool,
+    ) -> PolarsResult<DataFrame> {
+        value_counts::value_counts(self.to_df(), subset, sort, normalize, name, as_struct)
+    }
+
+    /// Apply `f` to every window of `window_size` rows of the frame.
+    ///
+    /// The window of a row consists of the row itself and the rows before it. `f` gets a
+    /// zero-copy slice of the window, so it can combine several columns, e.g. to compute a rolling
+    /// regression. Windows with fewer than `min_periods` rows give a null. The windows are
+    /// computed in parallel and the results are collected into a [`Series`] of `dtype`.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_map<F>(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        f: F,
+        dtype: &DataType,
+    ) -> PolarsResult<Series>
+    where
+        F: Fn(&DataFrame) -> PolarsResult<AnyValue<'static>> + Send + Sync,
+    {
+        rolling_map::rolling_map(self.to_df(), window_size, min_periods, f, dtype)
+    }
 }
@@ -0,0 +1,96 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use rayon::prelude::*;
+
+pub(super) fn rolling_map<F>(
+    df: &DataFrame,
+    window_size: usize,
+    min_periods: usize,
+    f: F,
+    dtype: &DataType,
+) -> PolarsResult<Series>
+where
+    F: Fn(&DataFrame) -> PolarsResult<AnyValue<'static>> + Send + Sync,
+{
+    polars_ensure!(
+        window_size > 0,
+        ComputeError: "`window_size` should be strictly positive"
+    );
+    polars_ensure!(
+        min_periods <= window_size,
+        ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
+        window_size, min_periods
+    );
+
+    // With a single chunk per column, slicing a window is cheap.
+    let mut df = df.clone();
+    df.as_single_chunk_par();
+
+    let height = df.height();
+    let n_threads = POOL.current_num_threads().min(height.max(1));
+    let values = POOL.install(|| {
+        _split_offsets(height, n_threads)
+            .into_par_iter()
+            .map(|(offset, len)| {
+                (offset..offset + len)
+                    .map(|idx| {
+                        let start = (idx + 1).saturating_sub(window_size);
+                        let size = idx + 1 - start;
+                        if size < min_periods.max(1) {
+                            return Ok(AnyValue::Null);
+                        }
+                        f(&df.slice(start as i64, size))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    let values = values.into_iter().flatten().collect::<Vec<_>>();
+    Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, dtype, true)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_rolling_map() -> PolarsResult<()> {
+        let df = df![
+            "x" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "y" => [2.0, 4.0, 7.0, 8.0, 13.0],
+        ]?;
+
+        // The slope of y on x over the last 3 rows.
+        let slope = |w: &DataFrame| -> PolarsResult<AnyValue<'static>> {
+            let x = w.column("x")?.as_materialized_series();
+            let y = w.column("y")?.as_materialized_series();
+            let x_mean = x.mean().unwrap();
+            let y_mean = y.mean().unwrap();
+            let dx = x - x_mean;
+            let cov = (&dx * &(y - y_mean))?.sum::<f64>()?;
+            let var = (&dx * &dx)?.sum::<f64>()?;
+            Ok(AnyValue::Float64(cov / var))
+        };
+        let out = rolling_map(&df, 3, 2, slope, &DataType::Float64)?;
+        let expected = Series::new(
+            PlSmallStr::EMPTY,
+            [None, Some(2.0), Some(2.5), Some(2.0), Some(3.0)],
+        );
+        assert!(out.equals_missing(&expected));
+
+        let height = |w: &DataFrame| -> PolarsResult<AnyValue<'static>> {
+            Ok(AnyValue::UInt32(w.height() as u32))
+        };
+        let out = rolling_map(&df, 2, 0, height, &DataType::UInt32)?;
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[Some(1), Some(2), Some(2), Some(2), Some(2)]
+        );
+
+        assert!(rolling_map(&df, 2, 3, height, &DataType::UInt32).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::*;
+
+pub(super) fn value_counts(
+    df: &DataFrame,
+    subset: Option<&[&str]>,
+    sort: bool,
+    normalize: bool,
+    name: PlSmallStr,
+    as_struct: bool,
+) -> PolarsResult<DataFrame> {
+    let keys = match subset {
+        Some(subset) => df.select(subset.iter().copied())?,
+        None => df.clone(),
+    };
+    polars_ensure!(
+        keys.width() > 0,
+        ComputeError: "'value_counts' requires at least one column"
+    );
+    let values_name = PlSmallStr::from_static("values");
+    if as_struct {
+        polars_ensure!(
+            name != values_name,
+            Duplicate: "using `value_counts` with `as_struct` and `name` '{}' would lead to \
+            duplicate column names; change `name` to fix", name,
+        );
+    } else {
+        polars_ensure!(
+            keys.get_column_index(&name).is_none(),
+            Duplicate: "using `value_counts` on a column named '{}' would lead to duplicate \
+            column names; change `name` to fix", name,
+        );
+    }
+
+    // Nulls are hashed like any other value, so null combinations form their own groups.
+    let groups = keys
+        .group_by_with_series(keys.get_columns().to_vec(), true, sort)?
+        .take_groups();
+    let values = keys
+        .get_columns()
+        .iter()
+        .map(|c| unsafe { c.agg_first(&groups) })
+        .collect::<Vec<_>>();
+    let counts = groups.group_count().with_name(name.clone());
+    let counts = if normalize {
+        let len = keys.height() as f64;
+        let counts: Float64Chunked = unary_elementwise_values(&counts, |count| count as f64 / len);
+        counts.into_column()
+    } else {
+        counts.into_column()
+    };
+
+    let height = counts.len();
+    let mut columns = if as_struct {
+        #[cfg(feature = "dtype-struct")]
+        {
+            let values = unsafe { DataFrame::new_no_checks(height, values) };
+            vec![values.into_struct(values_name).into_column()]
+        }
+        #[cfg(not(feature = "dtype-struct"))]
+        {
+            polars_bail!(InvalidOperation: "'value_counts' with `as_struct` requires the 'dtype-struct' feature")
+        }
+    } else {
+        values
+    };
+    columns.push(counts);
+    let df = unsafe { DataFrame::new_no_checks(height, columns) };
+    if sort {
+        df.sort(
+            [name],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_maintain_order(true)
+                .with_multithreaded(true),
+        )
+    } else {
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_value_counts_multiple_columns() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1), Some(1), None, None, Some(2), Some(1)],
+            "b" => [Some("x"), Some("x"), None, None, Some("y"), Some("y")],
+            "c" => [1, 2, 3, 4, 5, 6],
+        ]?;
+
+        let out = value_counts(&df, Some(&["a", "b"]), true, false, "count".into(), false)?;
+        let expected = df![
+            "a" => [Some(1), None, Some(2), Some(1)],
+            "b" => [Some("x"), None, Some("y"), Some("y")],
+            "count" => [2 as IdxSize, 2, 1, 1],
+        ]?;
+        assert_eq!(out, expected);
+
+        let out = value_counts(&df, Some(&["a", "b"]), true, true, "p".into(), true)?;
+        assert_eq!(out.get_column_names(), ["values", "p"]);
+        assert_eq!(out.column("values")?.dtype().to_string(), "struct[2]");
+        assert_eq!(out.column("p")?.f64()?.get(0), Some(2.0 / 6.0));
+
+        assert!(value_counts(&df, None, false, false, "c".into(), false).is_err());
+        // The struct column is named "values", a key column of that name is a struct field.
+        let df = df!["values" => [1, 1, 2]]?;
+        assert!(value_counts(&df, None, false, false, "values".into(), true).is_err());
+        let out = value_counts(&df, None, true, false, "count".into(), true)?;
+        assert_eq!(out.get_column_names(), ["values", "count"]);
+        Ok(())
+    }
+}
@@ -7,5 +7,5 @@ pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
 #[cfg(feature = "pivot")]
 pub use crate::frame::pivot::UnpivotDF;
-pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+pub use crate::frame::{ColumnFillNullStrategy, DataFrameJoinOps, DataFrameOps};
 pub use crate::series::*;
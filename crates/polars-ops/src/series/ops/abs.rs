@@ -1,5 +1,7 @@
 use polars_core::prelude::*;
 
+use crate::series::ArgAgg;
+
 /// Convert numerical values to their absolute value.
 pub fn abs(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
@@ -35,3 +37,88 @@ pub fn abs(s: &Series) -> PolarsResult<Series> {
     };
     Ok(out)
 }
+
+/// The position of the first valid element with the largest (`max`) or smallest absolute value.
+///
+/// Signed integers are compared by their `unsigned_abs` in their own width, so the absolute
+/// value of their minimum doesn't wrap around.
+fn arg_abs(s: &Series, max: bool) -> PolarsResult<Option<usize>> {
+    fn arg<K: Ord>(keys: impl Iterator<Item = Option<K>>, max: bool) -> Option<usize> {
+        keys.enumerate()
+            .filter_map(|(idx, key)| Some((idx, key?)))
+            .reduce(|a, b| {
+                if (max && b.1 > a.1) || (!max && b.1 < a.1) {
+                    b
+                } else {
+                    a
+                }
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    let phys = s.to_physical_repr();
+    let idx = match phys.dtype() {
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => arg(phys.i8()?.iter().map(|v| v.map(i8::unsigned_abs)), max),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => arg(phys.i16()?.iter().map(|v| v.map(i16::unsigned_abs)), max),
+        DataType::Int32 => arg(phys.i32()?.iter().map(|v| v.map(i32::unsigned_abs)), max),
+        DataType::Int64 => arg(phys.i64()?.iter().map(|v| v.map(i64::unsigned_abs)), max),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => arg(phys.i128()?.iter().map(|v| v.map(i128::unsigned_abs)), max),
+        _ => {
+            let abs = abs(s)?;
+            if max { abs.arg_max() } else { abs.arg_min() }
+        },
+    };
+    Ok(idx)
+}
+
+fn get_or_null(s: &Series, idx: Option<usize>) -> PolarsResult<AnyValue<'static>> {
+    match idx {
+        Some(idx) => Ok(s.get(idx)?.into_static()),
+        None => Ok(AnyValue::Null),
+    }
+}
+
+/// Get the element with the largest absolute value, keeping its sign.
+///
+/// Nulls are ignored, null is returned if there are no valid elements.
+pub fn max_abs(s: &Series) -> PolarsResult<AnyValue<'static>> {
+    get_or_null(s, arg_abs(s, true)?)
+}
+
+/// Get the element with the smallest absolute value, keeping its sign.
+///
+/// Nulls are ignored, null is returned if there are no valid elements.
+pub fn min_abs(s: &Series) -> PolarsResult<AnyValue<'static>> {
+    get_or_null(s, arg_abs(s, false)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_max_min_abs() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(3), None, Some(-7), Some(i32::MIN), Some(-1)],
+        );
+        assert_eq!(max_abs(&s)?, AnyValue::Int32(i32::MIN));
+        assert_eq!(min_abs(&s)?, AnyValue::Int32(-1));
+
+        // These are equal as floats.
+        let s = Series::new("a".into(), [i64::MAX - 1, -i64::MAX]);
+        assert_eq!(max_abs(&s)?, AnyValue::Int64(-i64::MAX));
+        assert_eq!(min_abs(&s)?, AnyValue::Int64(i64::MAX - 1));
+
+        let s = Series::new("a".into(), [2.5f64, -4.0, 0.5]);
+        assert_eq!(max_abs(&s)?, AnyValue::Float64(-4.0));
+        assert_eq!(min_abs(&s)?, AnyValue::Float64(0.5));
+
+        let s = Series::new_null("a".into(), 2).cast(&DataType::Int64)?;
+        assert_eq!(max_abs(&s)?, AnyValue::Null);
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use polars_core::prelude::*;
+
+use super::SeriesSealed;
+use crate::chunked_array::cov::pearson_corr;
+
+fn autocorr_f64(ca: &Float64Chunked, lag: usize) -> f64 {
+    let n = ca.len();
+    if lag >= n {
+        return f64::NAN;
+    }
+    let lead = ca.slice(lag as i64, n - lag);
+    let lagged = ca.slice(0, n - lag);
+    pearson_corr(&lead, &lagged).unwrap_or(f64::NAN)
+}
+
+fn to_f64(s: &Series) -> PolarsResult<Series> {
+    polars_ensure!(s.dtype().is_primitive_numeric(), opq = autocorr, s.dtype());
+    s.cast(&DataType::Float64)
+}
+
+pub trait SeriesAutocorr: SeriesSealed {
+    /// Compute the Pearson autocorrelation at the given `lag`.
+    ///
+    /// This is the correlation of `x[lag:]` with `x[:-lag]`. Pairs with a null are skipped.
+    /// `NaN` is returned if the series has no more than `lag` values.
+    fn autocorr(&self, lag: usize) -> PolarsResult<f64> {
+        let s = to_f64(self.as_series())?;
+        Ok(autocorr_f64(s.f64()?, lag))
+    }
+
+    /// Compute the autocorrelation at every lag from `0` up to and including `max_lag`.
+    ///
+    /// Returns a [`DataFrame`] with a `lag` and an `autocorr` column.
+    fn autocorr_all(&self, max_lag: usize) -> PolarsResult<DataFrame> {
+        let s = to_f64(self.as_series())?;
+        let ca = s.f64()?;
+        let lags = (0..=max_lag as IdxSize).collect::<Vec<_>>();
+        let values = (0..=max_lag)
+            .map(|lag| autocorr_f64(ca, lag))
+            .collect::<Vec<_>>();
+        DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("lag"), lags),
+            Column::new(PlSmallStr::from_static("autocorr"), values),
+        ])
+    }
+}
+
+impl SeriesAutocorr for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_autocorr() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1, 3, 2, 5, 4, 6]);
+        assert!((s.autocorr(0)? - 1.0).abs() < 1e-12);
+
+        // corr([3, 2, 5, 4, 6], [1, 3, 2, 5, 4])
+        assert!((s.autocorr(1)? - 0.3).abs() < 1e-12);
+        assert!(s.autocorr(6)?.is_nan());
+
+        let out = s.autocorr_all(2)?;
+        assert_eq!(out.shape(), (3, 2));
+        let values = out.column("autocorr")?.f64()?;
+        assert_eq!(values.get(1), Some(s.autocorr(1)?));
+        assert_eq!(values.get(2), Some(s.autocorr(2)?));
+
+        // Pairs with a null are skipped: corr([3, 4, 6], [1, 5, 4])
+        let s = Series::new(
+            "a".into(),
+            [Some(1), Some(3), None, Some(5), Some(4), Some(6)],
+        );
+        let expected = 33.0 / (42.0f64 * 78.0).sqrt();
+        assert!((s.autocorr(1)? - expected).abs() < 1e-12);
+
+        let s = Series::new("a".into(), ["a", "b"]);
+        assert!(s.autocorr(1).is_err());
+        Ok(())
+    }
+}
@@ -53,6 +53,24 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
     })
 }
 
+/// Winsorize the values: clip the values below the `lower` quantile and above the `upper`
+/// quantile to those quantiles.
+///
+/// The quantiles are computed with [`QuantileMethod::Nearest`], so the boundaries are values of
+/// `s` and the dtype is preserved. Both quantiles must be in `[0, 1]` with `lower <= upper`.
+pub fn winsorize(s: &Series, lower: f64, upper: f64) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&lower) && (0.0..=1.0).contains(&upper) && lower <= upper,
+        ComputeError: "`winsorize` quantiles must satisfy 0 <= lower <= upper <= 1, got {} and {}",
+        lower, upper
+    );
+    let bound = |quantile| -> PolarsResult<Series> {
+        Ok(s.quantile_reduce(quantile, QuantileMethod::Nearest)?
+            .into_series(s.name().clone()))
+    };
+    clip(s, &bound(lower)?, &bound(upper)?)
+}
+
 /// Set values above the given maximum to the maximum value.
 pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
     polars_ensure!(
@@ -161,6 +161,25 @@ where
     out.with_name(ca.name().clone())
 }
 
+fn cum_sum_reset_numeric<T>(ca: &ChunkedArray<T>, mask: &BooleanChunked) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: FromIterator<Option<T::Native>>,
+{
+    let mut state = T::Native::zero();
+    let out: ChunkedArray<T> = ca
+        .iter()
+        .zip(mask.iter())
+        .map(|(v, reset)| {
+            if reset == Some(true) {
+                state = T::Native::zero();
+            }
+            det_sum(&mut state, v).unwrap()
+        })
+        .collect_trusted();
+    out.with_name(ca.name().clone())
+}
+
 fn cum_prod_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -237,6 +256,52 @@ pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
     Ok(out)
 }
 
+/// Get an array with the cumulative sum computed at every element, where the sum starts over at
+/// every element for which `mask` is `true`.
+///
+/// The same dtypes as [`cum_sum`] are upcast.
+pub fn cum_sum_reset(s: &Series, mask: &Series) -> PolarsResult<Series> {
+    use DataType::*;
+    polars_ensure!(
+        s.len() == mask.len(),
+        ShapeMismatch: "`cum_sum_reset` expected a mask of length {}, got {}", s.len(), mask.len()
+    );
+    let mask = mask.bool()?;
+    let out = match s.dtype() {
+        Boolean => {
+            let s = s.cast(&UInt32)?;
+            cum_sum_reset_numeric(s.u32()?, mask).into_series()
+        },
+        Int8 | UInt8 | Int16 | UInt16 => {
+            let s = s.cast(&Int64)?;
+            cum_sum_reset_numeric(s.i64()?, mask).into_series()
+        },
+        Int32 => cum_sum_reset_numeric(s.i32()?, mask).into_series(),
+        UInt32 => cum_sum_reset_numeric(s.u32()?, mask).into_series(),
+        Int64 => cum_sum_reset_numeric(s.i64()?, mask).into_series(),
+        UInt64 => cum_sum_reset_numeric(s.u64()?, mask).into_series(),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => cum_sum_reset_numeric(s.i128()?, mask).into_series(),
+        Float32 => cum_sum_reset_numeric(s.f32()?, mask).into_series(),
+        Float64 => cum_sum_reset_numeric(s.f64()?, mask).into_series(),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(precision, scale) => {
+            let ca = s.decimal().unwrap().as_ref();
+            cum_sum_reset_numeric(ca, mask)
+                .into_decimal_unchecked(*precision, scale.unwrap())
+                .into_series()
+        },
+        #[cfg(feature = "dtype-duration")]
+        Duration(tu) => {
+            let s = s.to_physical_repr();
+            let ca = s.i64()?;
+            cum_sum_reset_numeric(ca, mask).cast(&Duration(*tu))?
+        },
+        dt => polars_bail!(opq = cum_sum_reset, dt),
+    };
+    Ok(out)
+}
+
 /// Get an array with the cumulative min computed at every element.
 pub fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
     match s.dtype() {
@@ -0,0 +1,97 @@
+use polars_core::prelude::*;
+
+use super::SeriesSealed;
+
+fn to_f64(s: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric(),
+        opq = cumulative_trapz,
+        s.dtype()
+    );
+    s.cast(&DataType::Float64)
+}
+
+fn cumulative_trapz_f64(y: &Float64Chunked, x: Option<&Float64Chunked>) -> Float64Chunked {
+    let mut sum = 0.0;
+    let mut prev: Option<(f64, f64)> = None;
+    let unit_x;
+    let x = match x {
+        Some(x) => x,
+        None => {
+            unit_x =
+                Float64Chunked::from_iter_values(PlSmallStr::EMPTY, (0..y.len()).map(|i| i as f64));
+            &unit_x
+        },
+    };
+    y.iter()
+        .zip(x.iter())
+        .map(|(y, x)| {
+            let (Some(y), Some(x)) = (y, x) else {
+                // The intervals on both sides of a missing point are skipped.
+                prev = None;
+                return None;
+            };
+            if let Some((prev_y, prev_x)) = prev {
+                sum += (y + prev_y) / 2.0 * (x - prev_x);
+            }
+            prev = Some((y, x));
+            Some(sum)
+        })
+        .collect()
+}
+
+pub trait SeriesCumTrapz: SeriesSealed {
+    /// Compute the running integral of this series over `x` with the trapezoidal rule.
+    ///
+    /// Without `x` the points are spaced one unit apart. The first value is `0.0`, and the
+    /// intervals on both sides of a null in `self` or `x` don't contribute to the integral.
+    fn cumulative_trapz(&self, x: Option<&Series>) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let y = to_f64(s)?;
+        let x = x
+            .map(|x| {
+                polars_ensure!(
+                    x.len() == s.len(),
+                    ShapeMismatch: "`cumulative_trapz` expected `x` of length {}, got {}", s.len(), x.len()
+                );
+                to_f64(x)
+            })
+            .transpose()?;
+        let x = x.as_ref().map(|x| x.f64()).transpose()?;
+        Ok(cumulative_trapz_f64(y.f64()?, x)
+            .with_name(s.name().clone())
+            .into_series())
+    }
+}
+
+impl SeriesCumTrapz for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cumulative_trapz() -> PolarsResult<()> {
+        let y = Series::new("y".into(), [1.0, 2.0, 3.0, 4.0]);
+        let out = y.cumulative_trapz(None)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), Some(1.5), Some(4.0), Some(7.5)]
+        );
+
+        let x = Series::new("x".into(), [0i64, 2, 3, 5]);
+        let out = y.cumulative_trapz(Some(&x))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), Some(3.0), Some(5.5), Some(12.5)]
+        );
+
+        let y = Series::new("y".into(), [Some(1.0), None, Some(3.0), Some(4.0)]);
+        let out = y.cumulative_trapz(None)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), None, Some(0.0), Some(3.5)]
+        );
+        Ok(())
+    }
+}
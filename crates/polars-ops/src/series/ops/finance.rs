@@ -0,0 +1,112 @@
+use polars_core::prelude::*;
+
+/// Per-bar Garman-Klass variance: `0.5 * ln(h / l)^2 - (2 * ln(2) - 1) * ln(c / o)^2`.
+fn garman_klass_variance(open: f64, high: f64, low: f64, close: f64) -> f64 {
+    let hl = (high / low).ln();
+    let co = (close / open).ln();
+    0.5 * hl * hl - (2.0 * std::f64::consts::LN_2 - 1.0) * co * co
+}
+
+/// Compute the rolling Garman-Klass volatility estimate from open, high, low and close prices.
+///
+/// Every bar contributes its Garman-Klass variance, and the output is the square root of the mean
+/// variance over the last `window` bars. The first `window - 1` values and any window that
+/// contains a null are null. The volatility is not annualized.
+pub fn garman_klass_volatility(
+    open: &Series,
+    high: &Series,
+    low: &Series,
+    close: &Series,
+    window: usize,
+) -> PolarsResult<Series> {
+    polars_ensure!(window > 0, InvalidOperation: "`window` must be positive");
+    let len = open.len();
+    polars_ensure!(
+        high.len() == len && low.len() == len && close.len() == len,
+        ShapeMismatch: "open, high, low and close must have the same length"
+    );
+
+    let open = open.cast(&DataType::Float64)?;
+    let high = high.cast(&DataType::Float64)?;
+    let low = low.cast(&DataType::Float64)?;
+    let close = close.cast(&DataType::Float64)?;
+    let variances: Vec<Option<f64>> = open
+        .f64()?
+        .iter()
+        .zip(high.f64()?.iter())
+        .zip(low.f64()?.iter())
+        .zip(close.f64()?.iter())
+        .map(|(((o, h), l), c)| Some(garman_klass_variance(o?, h?, l?, c?)))
+        .collect();
+
+    // Only finite variances enter the running sum, as a single infinite value would turn every
+    // later sum into NaN. Windows that contain a non-finite variance are summed directly.
+    let mut sum = 0.0;
+    let mut null_count = 0;
+    let mut non_finite_count = 0;
+    let out: Float64Chunked = (0..len)
+        .map(|i| {
+            match variances[i] {
+                Some(v) if v.is_finite() => sum += v,
+                Some(_) => non_finite_count += 1,
+                None => null_count += 1,
+            }
+            if i >= window {
+                match variances[i - window] {
+                    Some(v) if v.is_finite() => sum -= v,
+                    Some(_) => non_finite_count -= 1,
+                    None => null_count -= 1,
+                }
+            }
+            if i + 1 < window || null_count > 0 {
+                return None;
+            }
+            let window_sum = if non_finite_count > 0 {
+                variances[i + 1 - window..=i].iter().flatten().sum()
+            } else {
+                sum
+            };
+            // Negative means are clamped to zero, NaN and infinity are propagated.
+            let mean = window_sum / window as f64;
+            Some(if mean < 0.0 { 0.0 } else { mean.sqrt() })
+        })
+        .collect();
+    Ok(out.with_name(open.name().clone()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_garman_klass_volatility() -> PolarsResult<()> {
+        let open = Series::new("open".into(), [10.0, 11.0, 12.0]);
+        let high = Series::new("high".into(), [11.0, 12.0, 13.0]);
+        let low = Series::new("low".into(), [9.0, 10.0, 11.0]);
+        let close = Series::new("close".into(), [10.5, 11.5, 12.5]);
+        let out = garman_klass_volatility(&open, &high, &low, &close, 2)?;
+        let out = out.f64()?;
+
+        let v = |o: f64, h: f64, l: f64, c: f64| garman_klass_variance(o, h, l, c);
+        let expected = ((v(10.0, 11.0, 9.0, 10.5) + v(11.0, 12.0, 10.0, 11.5)) / 2.0).sqrt();
+        assert_eq!(out.get(0), None);
+        assert!((out.get(1).unwrap() - expected).abs() < 1e-12);
+        assert!(out.get(2).is_some());
+
+        // A non-finite bar only affects the windows that contain it.
+        let low = Series::new("low".into(), [9.0, 0.0, 11.0, 12.0]);
+        let open = Series::new("open".into(), [10.0, 11.0, 12.0, 13.0]);
+        let high = Series::new("high".into(), [11.0, 12.0, 13.0, 14.0]);
+        let close = Series::new("close".into(), [10.5, 11.5, 12.5, 13.5]);
+        let out = garman_klass_volatility(&open, &high, &low, &close, 2)?;
+        let out = out.f64()?;
+        assert_eq!(out.get(1), Some(f64::INFINITY));
+        assert_eq!(out.get(2), Some(f64::INFINITY));
+        let expected = ((v(12.0, 13.0, 11.0, 12.5) + v(13.0, 14.0, 12.0, 13.5)) / 2.0).sqrt();
+        assert!((out.get(3).unwrap() - expected).abs() < 1e-12);
+
+        let short = Series::new("close".into(), [10.5]);
+        assert!(garman_klass_volatility(&open, &high, &low, &short, 2).is_err());
+        Ok(())
+    }
+}
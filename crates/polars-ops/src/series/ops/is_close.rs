@@ -0,0 +1,223 @@
+use num_traits::{AsPrimitive, Float, PrimInt};
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::{with_match_physical_float_polars_type, with_match_physical_integer_polars_type};
+
+#[inline]
+fn is_close_value<T: Float + AsPrimitive<f64>>(
+    a: T,
+    b: T,
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> bool {
+    // Also handles infinities, which are only close to themselves.
+    if a == b {
+        return true;
+    }
+    if a.is_nan() || b.is_nan() {
+        return nans_equal && a.is_nan() && b.is_nan();
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let (a, b): (f64, f64) = (a.as_(), b.as_());
+    let tol = f64::max(rel_tol * f64::max(a.abs(), b.abs()), abs_tol);
+    (a - b).abs() <= tol
+}
+
+#[inline]
+fn is_close_int<T: PrimInt + AsPrimitive<i128>>(a: T, b: T, rel_tol: f64, abs_tol: f64) -> bool {
+    let (a, b): (i128, i128) = (a.as_(), b.as_());
+    // The difference is exact, so this is also correct above 2^53.
+    let diff = a.abs_diff(b);
+    let tol = f64::max(
+        rel_tol * a.unsigned_abs().max(b.unsigned_abs()) as f64,
+        abs_tol,
+    );
+    // `diff` is whole, so comparing it to the whole part of `tol` is exact. The cast saturates.
+    diff <= tol.floor() as u128
+}
+
+fn is_close_integer<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> BooleanChunked
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt + AsPrimitive<i128>,
+{
+    broadcast_binary_elementwise_values(lhs, rhs, |a: T::Native, b: T::Native| {
+        is_close_int(a, b, rel_tol, abs_tol)
+    })
+}
+
+fn is_close_float<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> BooleanChunked
+where
+    T: PolarsFloatType,
+    T::Native: Float + AsPrimitive<f64>,
+{
+    broadcast_binary_elementwise_values(lhs, rhs, |a: T::Native, b: T::Native| {
+        is_close_value(a, b, rel_tol, abs_tol, nans_equal)
+    })
+}
+
+/// Check elementwise whether the values of `s` and `other` are close to each other.
+///
+/// Two values `a` and `b` are close if `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`, as in
+/// Python's `math.isclose`. Infinities are only close to themselves and NaN values are only close
+/// to each other if `nans_equal` is set. The difference of two integers is computed exactly,
+/// without a cast to floats. Decimals are compared exactly.
+///
+/// Either side may have length 1, in which case it is broadcast. Nulls propagate like in
+/// [`ChunkCompareEq::equal`].
+pub fn is_close(
+    s: &Series,
+    other: &Series,
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        rel_tol >= 0.0 && abs_tol >= 0.0,
+        InvalidOperation: "`rel_tol` and `abs_tol` must be non-negative, got {} and {}",
+        rel_tol, abs_tol
+    );
+    polars_ensure!(
+        s.len() == other.len() || s.len() == 1 || other.len() == 1,
+        length_mismatch = "is_close",
+        s.len(),
+        other.len()
+    );
+
+    let (s, other) = match (s.dtype(), other.dtype()) {
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(_, _), DataType::Decimal(_, _)) => {
+            return s.equal(other);
+        },
+        (l, r) if l.is_float() && l == r => (s.clone(), other.clone()),
+        (l, r) if l.is_integer() && r.is_integer() && try_get_supertype(l, r)?.is_integer() => {
+            let dtype = try_get_supertype(l, r)?;
+            let (s, other) = (s.cast(&dtype)?, other.cast(&dtype)?);
+            let out = with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let lhs: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                let rhs: &ChunkedArray<$T> = other.as_ref().as_ref().as_ref();
+                is_close_integer(lhs, rhs, rel_tol, abs_tol)
+            });
+            return Ok(out.with_name(s.name().clone()));
+        },
+        (l, r) if l.is_primitive_numeric() && r.is_primitive_numeric() => {
+            (s.cast(&DataType::Float64)?, other.cast(&DataType::Float64)?)
+        },
+        (l, r) => polars_bail!(
+            InvalidOperation: "`is_close` operation not supported for dtypes `{}` and `{}`", l, r
+        ),
+    };
+
+    let out = with_match_physical_float_polars_type!(s.dtype(), |$T| {
+        let lhs: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = other.as_ref().as_ref().as_ref();
+        is_close_float(lhs, rhs, rel_tol, abs_tol, nans_equal)
+    });
+    Ok(out.with_name(s.name().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_close() -> PolarsResult<()> {
+        let a = Series::new(
+            "a".into(),
+            [
+                Some(1.0),
+                Some(1.0),
+                None,
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(100.0),
+            ],
+        );
+        let b = Series::new(
+            "b".into(),
+            [
+                Some(1.0 + 1e-10),
+                Some(1.1),
+                Some(1.0),
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(101.0),
+            ],
+        );
+
+        let out = is_close(&a, &b, 1e-9, 0.0, false)?;
+        let expected = [
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+        ];
+        assert_eq!(Vec::from(&out), expected);
+
+        let out = is_close(&a, &b, 0.01, 0.0, true)?;
+        let expected = [
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(true),
+            Some(true),
+        ];
+        assert_eq!(Vec::from(&out), expected);
+
+        // Literals are broadcast and integers are compared as floats.
+        let one = Series::new("one".into(), [1i32]);
+        let out = is_close(&a, &one, 0.0, 0.15, false)?;
+        let expected = [
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+        ];
+        assert_eq!(Vec::from(&out), expected);
+
+        // Integers above 2^53 are not rounded to the same float.
+        let big = 1i64 << 53;
+        let a = Series::new("a".into(), [big, big, i64::MIN, 0]);
+        let b = Series::new("b".into(), [big + 1, big, i64::MAX, 0]);
+        let out = is_close(&a, &b, 0.0, 0.0, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(false), Some(true), Some(false), Some(true)]
+        );
+        let out = is_close(&a, &b, 0.0, 1.0, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(true), Some(true), Some(false), Some(true)]
+        );
+        let unsigned = Series::new("b".into(), [1u32, 0, 0, 1]);
+        let out = is_close(&a, &unsigned, 0.0, 1.0, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(false), Some(false), Some(false), Some(true)]
+        );
+
+        assert!(is_close(&a, &b, 0.0, -1.0, false).is_err());
+        assert!(is_close(&a, &b.slice(0, 2), 0.0, 0.0, false).is_err());
+        Ok(())
+    }
+}
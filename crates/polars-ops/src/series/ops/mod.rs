@@ -1,12 +1,16 @@
 #[cfg(feature = "abs")]
 mod abs;
 mod arg_min_max;
+#[cfg(feature = "cov")]
+mod autocorr;
 mod bitwise;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
+#[cfg(feature = "cum_agg")]
+mod cum_trapz;
 #[cfg(feature = "cutqcut")]
 mod cut;
 #[cfg(feature = "diff")]
@@ -15,6 +19,8 @@ mod diff;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+#[cfg(feature = "finance")]
+mod finance;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
@@ -28,6 +34,8 @@ mod int_range;
 mod interpolation;
 #[cfg(feature = "is_between")]
 mod is_between;
+#[cfg(feature = "is_close")]
+mod is_close;
 #[cfg(feature = "is_first_distinct")]
 mod is_first_distinct;
 #[cfg(feature = "is_in")]
@@ -58,6 +66,10 @@ mod rolling;
 pub mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "session_id")]
+mod session_id;
+#[cfg(feature = "signal")]
+mod signal;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -67,12 +79,16 @@ mod various;
 #[cfg(feature = "abs")]
 pub use abs::*;
 pub use arg_min_max::ArgAgg;
+#[cfg(feature = "cov")]
+pub use autocorr::*;
 pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
+#[cfg(feature = "cum_agg")]
+pub use cum_trapz::*;
 #[cfg(feature = "cutqcut")]
 pub use cut::*;
 #[cfg(feature = "diff")]
@@ -81,6 +97,8 @@ pub use diff::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+#[cfg(feature = "finance")]
+pub use finance::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
@@ -98,6 +116,8 @@ pub use interpolation::interpolate_by::*;
 pub use interpolation::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
+#[cfg(feature = "is_close")]
+pub use is_close::*;
 #[cfg(feature = "is_first_distinct")]
 pub use is_first_distinct::*;
 #[cfg(feature = "is_in")]
@@ -130,6 +150,10 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "session_id")]
+pub use session_id::*;
+#[cfg(feature = "signal")]
+pub use signal::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
@@ -1,5 +1,6 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use arrow::array::BooleanArray;
+use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_validities;
 use polars_core::prelude::*;
 use rand::prelude::*;
@@ -46,7 +47,15 @@ fn get_random_seed() -> u64 {
     rng.next_u64()
 }
 
-unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray, mut flush_ties: F) {
+/// Calls `flush_ties` for every run of tied indices in `idxs`, together with whether the run
+/// starts a new group. Consecutive indices are tied unless `neq` is set for them; `new_group`
+/// marks the group boundaries, which must also be set in `neq`.
+unsafe fn rank_impl<F: FnMut(&mut [IdxSize], bool)>(
+    idxs: &IdxCa,
+    neq: &BooleanArray,
+    new_group: Option<&BooleanArray>,
+    mut flush_ties: F,
+) {
     let mut ties_indices = Vec::with_capacity(128);
     let mut idx_it = idxs.downcast_iter().flat_map(|arr| arr.values_iter());
     let Some(first_idx) = idx_it.next() else {
@@ -54,15 +63,17 @@ unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray,
     };
     ties_indices.push(*first_idx);
 
+    let mut starts_group = true;
     for (eq_idx, idx) in idx_it.enumerate() {
         if neq.value_unchecked(eq_idx) {
-            flush_ties(&mut ties_indices);
-            ties_indices.clear()
+            flush_ties(&mut ties_indices, starts_group);
+            ties_indices.clear();
+            starts_group = new_group.is_some_and(|new_group| new_group.value_unchecked(eq_idx));
         }
 
         ties_indices.push(*idx);
     }
-    flush_ties(&mut ties_indices);
+    flush_ties(&mut ties_indices, starts_group);
 }
 
 fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
@@ -129,74 +140,193 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
             .unwrap();
         let neq = not_consecutive_same.rechunk();
         let neq = neq.downcast_as_array();
+        rank_sorted(s, &sort_idx_ca, neq, None, validity, method, seed)
+    }
+}
 
-        let mut rank = 1;
-        match method {
-            #[cfg(feature = "random")]
-            Random => unsafe {
-                let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    ties.shuffle(&mut rng);
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                        rank += 1;
-                    }
-                });
-                IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
-            },
-            Average => unsafe {
-                let mut out = vec![0.0; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    let first = rank;
-                    rank += ties.len() as IdxSize;
-                    let last = rank - 1;
-                    let avg = 0.5 * (first as f64 + last as f64);
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = avg;
-                    }
-                });
-                Float64Chunked::from_vec_validity(s.name().clone(), out, validity).into_series()
-            },
-            Min => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    for i in ties.iter() {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                    }
-                    rank += ties.len() as IdxSize;
-                });
-                IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
-            },
-            Max => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    rank += ties.len() as IdxSize;
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank - 1;
-                    }
-                });
-                IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
-            },
-            Dense => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                    }
+/// Assign the ranks given the indices that sort `s`, see [`rank_impl`].
+fn rank_sorted(
+    s: &Series,
+    sort_idx_ca: &IdxCa,
+    neq: &BooleanArray,
+    new_group: Option<&BooleanArray>,
+    validity: Option<Bitmap>,
+    method: RankMethod,
+    seed: Option<u64>,
+) -> Series {
+    use RankMethod::*;
+    let mut rank = 1;
+    match method {
+        #[cfg(feature = "random")]
+        Random => unsafe {
+            let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+            let mut out = vec![0 as IdxSize; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                ties.shuffle(&mut rng);
+                for i in ties {
+                    *out.get_unchecked_mut(*i as usize) = rank;
                     rank += 1;
-                });
-                IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
-            },
-            Ordinal => unreachable!(),
-        }
+                }
+            });
+            IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
+        Average => unsafe {
+            let mut out = vec![0.0; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                let first = rank;
+                rank += ties.len() as IdxSize;
+                let last = rank - 1;
+                let avg = 0.5 * (first as f64 + last as f64);
+                for i in ties {
+                    *out.get_unchecked_mut(*i as usize) = avg;
+                }
+            });
+            Float64Chunked::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
+        Min => unsafe {
+            let mut out = vec![0 as IdxSize; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                for i in ties.iter() {
+                    *out.get_unchecked_mut(*i as usize) = rank;
+                }
+                rank += ties.len() as IdxSize;
+            });
+            IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
+        Max => unsafe {
+            let mut out = vec![0 as IdxSize; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                rank += ties.len() as IdxSize;
+                for i in ties {
+                    *out.get_unchecked_mut(*i as usize) = rank - 1;
+                }
+            });
+            IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
+        Dense => unsafe {
+            let mut out = vec![0 as IdxSize; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                for i in ties {
+                    *out.get_unchecked_mut(*i as usize) = rank;
+                }
+                rank += 1;
+            });
+            IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
+        Ordinal => unsafe {
+            let mut out = vec![0 as IdxSize; s.len()];
+            rank_impl(sort_idx_ca, neq, new_group, |ties, starts_group| {
+                if starts_group {
+                    rank = 1;
+                }
+                for i in ties {
+                    *out.get_unchecked_mut(*i as usize) = rank;
+                    rank += 1;
+                }
+            });
+            IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
+        },
     }
 }
 
+/// Rank `s` within the groups formed by the unique combinations of the `by` keys.
+///
+/// Instead of building the groups, the rows are sorted by the keys and then by the values, after
+/// which the ranks are assigned in a single pass that restarts at every group boundary.
+fn rank_by(
+    s: &Series,
+    by: &[Column],
+    method: RankMethod,
+    descending: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    polars_ensure!(!by.is_empty(), ComputeError: "`rank_by` needs at least one key");
+    for key in by {
+        polars_ensure!(
+            key.len() == s.len(),
+            length_mismatch = "rank_by",
+            s.len(),
+            key.len()
+        );
+    }
+    let len = s.len();
+    if len <= 1 {
+        return Ok(rank(s, method, descending, seed));
+    }
+
+    let mut other = by[1..].to_vec();
+    other.push(s.clone().into_column());
+    let sort_options = SortMultipleOptions::default()
+        .with_order_descending_multi(by.iter().map(|_| false).chain([descending]))
+        .with_nulls_last_multi(std::iter::repeat_n(true, by.len() + 1))
+        .with_maintain_order(true);
+    let sort_idx_ca = by[0].arg_sort_multiple(&other, &sort_options)?;
+
+    let mut new_group = BooleanChunked::full(PlSmallStr::EMPTY, false, len - 1);
+    for key in by {
+        let sorted_key = unsafe { key.as_materialized_series().take_unchecked(&sort_idx_ca) };
+        new_group = new_group
+            | sorted_key
+                .slice(1, len - 1)
+                .not_equal_missing(&sorted_key.slice(0, len - 1))?;
+    }
+    let sorted_values = unsafe { s.take_unchecked(&sort_idx_ca) };
+    let neq = sorted_values
+        .slice(1, len - 1)
+        .not_equal_missing(&sorted_values.slice(0, len - 1))?
+        | new_group.clone();
+
+    // Null values are sorted last within their group, so they don't affect the ranks of the
+    // other values and are masked out by the validity.
+    let validity = concatenate_validities(s.chunks());
+    let neq = neq.rechunk();
+    let new_group = new_group.rechunk();
+    Ok(rank_sorted(
+        s,
+        &sort_idx_ca,
+        neq.downcast_as_array(),
+        Some(new_group.downcast_as_array()),
+        validity,
+        method,
+        seed,
+    ))
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// Rank the values within the groups formed by the unique combinations of the `by` keys.
+    fn rank_by(
+        &self,
+        by: &[Column],
+        options: RankOptions,
+        seed: Option<u64>,
+    ) -> PolarsResult<Series> {
+        rank_by(
+            self.as_series(),
+            by,
+            options.method,
+            options.descending,
+            seed,
+        )
+    }
 }
 
 impl SeriesRank for Series {}
@@ -335,6 +465,68 @@ mod test {
         assert_eq!(out.dtype(), &IDX_DTYPE);
     }
 
+    #[test]
+    fn test_rank_by() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(3), Some(1), None, Some(2), Some(1), Some(5), Some(1)],
+        );
+        let g = Column::new("g".into(), &["x", "y", "x", "x", "y", "y", "x"]);
+
+        let rank_g = |method, descending| {
+            let options = RankOptions { method, descending };
+            s.rank_by(std::slice::from_ref(&g), options, None)
+        };
+
+        let out = rank_g(RankMethod::Ordinal, false)?
+            .idx()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(3), Some(1), None, Some(2), Some(2), Some(3), Some(1)]
+        );
+
+        let out = rank_g(RankMethod::Dense, true)?
+            .idx()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1), Some(2), None, Some(2), Some(2), Some(1), Some(3)]
+        );
+
+        let out = rank_g(RankMethod::Average, false)?
+            .f64()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(3.0),
+                Some(1.5),
+                None,
+                Some(2.0),
+                Some(1.5),
+                Some(3.0),
+                Some(1.0)
+            ]
+        );
+
+        // Multiple keys form the groups together.
+        let h = Column::new("h".into(), &[1, 1, 1, 2, 1, 1, 2]);
+        let out = s
+            .rank_by(&[g, h], RankOptions::default(), None)?
+            .idx()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1), Some(1), None, Some(2), Some(1), Some(2), Some(1)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_rank_reverse() -> PolarsResult<()> {
         let s = Series::new("".into(), &[None, Some(1), Some(1), Some(5), None]);
@@ -1,6 +1,25 @@
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
+/// Whether each value differs from the next one, nulls compare equal to each other.
+///
+/// Nested values are compared by their row encoding.
+fn neq_next(s: &Column) -> PolarsResult<BooleanChunked> {
+    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
+    if !s.dtype().is_nested() {
+        return s1
+            .as_materialized_series()
+            .not_equal_missing(s2.as_materialized_series());
+    }
+
+    let rows = _get_rows_encoded_ca_unordered(s.name().clone(), std::slice::from_ref(s))?;
+    let arr = rows.downcast_as_array();
+    let neq =
+        (1..arr.len()).map(|i| unsafe { arr.value_unchecked(i - 1) != arr.value_unchecked(i) });
+    Ok(BooleanChunked::from_iter_values(s.name().clone(), neq))
+}
+
 /// Get the run-Lengths of values.
 pub fn rle_lengths(s: &Column, lengths: &mut Vec<IdxSize>) -> PolarsResult<()> {
     lengths.clear();
@@ -13,10 +32,7 @@ pub fn rle_lengths(s: &Column, lengths: &mut Vec<IdxSize>) -> PolarsResult<()> {
         return Ok(());
     }
 
-    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
-    let s_neq = s1
-        .as_materialized_series()
-        .not_equal_missing(s2.as_materialized_series())?;
+    let s_neq = neq_next(s)?;
     let n_runs = s_neq.sum().unwrap() + 1;
 
     lengths.reserve(n_runs as usize);
@@ -40,7 +56,15 @@ pub fn rle_lengths(s: &Column, lengths: &mut Vec<IdxSize>) -> PolarsResult<()> {
 }
 
 /// Get the lengths of runs of identical values.
-pub fn rle(s: &Column) -> PolarsResult<Column> {
+///
+/// The lengths are cast to `lengths_dtype`, which must be an integer dtype. This errors if a
+/// length doesn't fit in it.
+pub fn rle(s: &Column, lengths_dtype: &DataType) -> PolarsResult<Column> {
+    polars_ensure!(
+        lengths_dtype.is_integer(),
+        InvalidOperation: "rle lengths must have an integer dtype, got {}", lengths_dtype
+    );
+
     let mut lengths = Vec::new();
     rle_lengths(s, &mut lengths)?;
 
@@ -56,10 +80,9 @@ pub fn rle(s: &Column) -> PolarsResult<Column> {
         .take_slice(&idxs)
         .unwrap()
         .with_name(PlSmallStr::from_static("value"));
-    let outvals = vec![
-        Series::from_vec(PlSmallStr::from_static("len"), lengths).into(),
-        vals,
-    ];
+    let lengths =
+        Series::from_vec(PlSmallStr::from_static("len"), lengths).strict_cast(lengths_dtype)?;
+    let outvals = vec![lengths.into(), vals];
     Ok(StructChunked::from_columns(s.name().clone(), idxs.len(), &outvals)?.into_column())
 }
 
@@ -69,10 +92,7 @@ pub fn rle_id(s: &Column) -> PolarsResult<Column> {
         return Ok(Column::new_empty(s.name().clone(), &IDX_DTYPE));
     }
 
-    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
-    let s_neq = s1
-        .as_materialized_series()
-        .not_equal_missing(s2.as_materialized_series())?;
+    let s_neq = neq_next(s)?;
 
     let mut out = Vec::<IdxSize>::with_capacity(s.len());
     let mut last = 0;
@@ -88,3 +108,93 @@ pub fn rle_id(s: &Column) -> PolarsResult<Column> {
         .with_sorted_flag(IsSorted::Ascending)
         .into_column())
 }
+
+/// Decode the output of [`rle`], a struct with the fields `len` and `value`, back into the
+/// column of repeated values.
+pub fn rle_decode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.struct_()?;
+    let fields = ca.fields_as_series();
+    polars_ensure!(
+        fields.len() == 2 && fields[0].name() == "len" && fields[1].name() == "value",
+        InvalidOperation: "rle_decode expects a struct with the fields 'len' and 'value', got {}", s.dtype()
+    );
+    polars_ensure!(
+        fields[0].dtype().is_integer(),
+        InvalidOperation: "rle_decode expects integer lengths, got {}", fields[0].dtype()
+    );
+    polars_ensure!(
+        fields[0].null_count() == 0,
+        InvalidOperation: "rle_decode lengths must not contain nulls"
+    );
+    polars_ensure!(
+        !fields[0].dtype().is_signed_integer() || !fields[0].lt(0)?.any(),
+        InvalidOperation: "rle_decode lengths must not be negative"
+    );
+    let lengths = fields[0].strict_cast(&IDX_DTYPE)?;
+    let lengths = lengths.idx()?;
+
+    let mut idx = Vec::with_capacity(lengths.sum().unwrap_or(0) as usize);
+    for (i, len) in lengths.into_no_null_iter().enumerate() {
+        idx.extend(std::iter::repeat_n(i as IdxSize, len as usize));
+    }
+    let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+    let values = fields[1].take(&idx)?;
+    Ok(values.with_name(s.name().clone()).into_column())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle_nested_roundtrip() -> PolarsResult<()> {
+        let inner = Series::new("".into(), [1, 1, 2]);
+        let s = Series::new(
+            "a".into(),
+            [inner.clone(), inner.clone(), inner.slice(0, 2), inner],
+        )
+        .into_column();
+
+        let out = rle(&s, &DataType::UInt64)?;
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(fields[0].dtype(), &DataType::UInt64);
+        assert_eq!(
+            fields[0].u64()?.into_no_null_iter().collect::<Vec<_>>(),
+            [2, 1, 1]
+        );
+        assert!(rle_decode(&out)?.equals_missing(&s));
+
+        let ids = rle_id(&s)?;
+        assert_eq!(
+            ids.idx()?.into_no_null_iter().collect::<Vec<_>>(),
+            [0, 0, 1, 2]
+        );
+        assert!(rle(&s, &DataType::Float64).is_err());
+
+        let long = Series::new("a".into(), vec![1; 300]).into_column();
+        assert!(rle(&long, &DataType::UInt8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rle_decode_invalid_lengths() -> PolarsResult<()> {
+        let encoded = |lengths: Series| {
+            let values = Series::new("value".into(), [1, 2]);
+            StructChunked::from_series("a".into(), 2, [lengths, values].iter())
+                .unwrap()
+                .into_column()
+        };
+
+        let err = rle_decode(&encoded(Series::new("len".into(), [1i32, -1]))).unwrap_err();
+        assert!(err.to_string().contains("must not be negative"), "{err}");
+        let err = rle_decode(&encoded(Series::new("len".into(), [Some(1i32), None]))).unwrap_err();
+        assert!(err.to_string().contains("must not contain nulls"), "{err}");
+
+        let out = rle_decode(&encoded(Series::new("len".into(), [2i8, 1])))?;
+        assert_eq!(
+            out.i32()?.into_no_null_iter().collect::<Vec<_>>(),
+            [1, 1, 2]
+        );
+        Ok(())
+    }
+}
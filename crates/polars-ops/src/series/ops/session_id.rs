@@ -0,0 +1,115 @@
+use polars_core::prelude::*;
+
+/// Returns `None` if the non-null times are not sorted ascending.
+fn session_id_sorted(times: &Int64Chunked, gap: i64) -> Option<UInt32Chunked> {
+    let mut id = 0u32;
+    let mut prev_time: Option<i64> = None;
+    let mut out = Vec::with_capacity(times.len());
+    for time in times.iter() {
+        let Some(time) = time else {
+            out.push(None);
+            continue;
+        };
+        if let Some(prev_time) = prev_time {
+            if time < prev_time {
+                return None;
+            }
+            if time.saturating_sub(prev_time) > gap {
+                id += 1;
+            }
+        }
+        prev_time = Some(time);
+        out.push(Some(id));
+    }
+    Some(UInt32Chunked::from_iter_options(
+        times.name().clone(),
+        out.into_iter(),
+    ))
+}
+
+/// Sort on behalf of user
+fn session_id_impl(times: &Int64Chunked, gap: i64) -> UInt32Chunked {
+    let sorting_indices = times.arg_sort(Default::default());
+    let sorted_times = unsafe { times.take_unchecked(&sorting_indices) };
+    let sorted_ids = session_id_sorted(&sorted_times, gap).unwrap();
+
+    let mut out = vec![None; times.len()];
+    for (idx, id) in sorting_indices.into_no_null_iter().zip(sorted_ids.iter()) {
+        out[idx as usize] = id;
+    }
+    UInt32Chunked::from_iter_options(times.name().clone(), out.into_iter())
+}
+
+/// Assign a session index to every event at `times`.
+///
+/// A new session starts whenever the time since the previous event is larger than `gap`, so the
+/// indices count up from `0` in time order. `gap` is in nanoseconds for `Date` and `Datetime`
+/// times, and in the unit of the values for integer times. Null times get a null index and don't
+/// interrupt a session.
+///
+/// The events are visited in time order in a single pass. If `times` is not sorted it is sorted
+/// first when `sort` is set, and an error is raised otherwise.
+pub fn session_id(times: &Series, gap: i64, sort: bool) -> PolarsResult<Series> {
+    polars_ensure!(gap >= 0, InvalidOperation: "`gap` cannot be negative");
+    match times.dtype() {
+        DataType::Int64 => {
+            let ca = times.i64().unwrap();
+            let out = match session_id_sorted(ca, gap) {
+                Some(out) => out,
+                None => {
+                    polars_ensure!(
+                        sort,
+                        InvalidOperation: "`session_id` requires sorted times, set `sort` to sort them first"
+                    );
+                    session_id_impl(ca, gap)
+                },
+            };
+            Ok(out.into_series())
+        },
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, _) => {
+            let gap = match time_unit {
+                TimeUnit::Milliseconds => gap / 1_000_000,
+                TimeUnit::Microseconds => gap / 1_000,
+                TimeUnit::Nanoseconds => gap,
+            };
+            session_id(&times.cast(&DataType::Int64)?, gap, sort)
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => session_id(
+            &times.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            gap,
+            sort,
+        ),
+        DataType::UInt64 | DataType::UInt32 | DataType::Int32 => {
+            session_id(&times.cast(&DataType::Int64)?, gap, sort)
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected times to be Date, Datetime, Int64, Int32, UInt64, or UInt32, got {}", dt
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session_id() -> PolarsResult<()> {
+        let times = Series::new("t".into(), [Some(0i64), Some(5), None, Some(20), Some(22)]);
+        let out = session_id(&times, 10, false)?;
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[Some(0), Some(0), None, Some(1), Some(1)]
+        );
+
+        let times = Series::new("t".into(), [20i64, 0, 22, 5, 40]);
+        assert!(session_id(&times, 10, false).is_err());
+        let out = session_id(&times, 10, true)?;
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[Some(1), Some(0), Some(1), Some(0), Some(2)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use polars_core::prelude::*;
+
+use super::SeriesSealed;
+
+/// Solve `a * x = b` for `x` in place with Gaussian elimination, where `a` is an `n x n` matrix
+/// and `b` is an `n x m` matrix, both stored row-major. The solution is left in `b`.
+fn solve_in_place(a: &mut [f64], b: &mut [f64], n: usize, m: usize) {
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i * n + col].abs().total_cmp(&a[j * n + col].abs()))
+            .unwrap();
+        if pivot != col {
+            for k in 0..n {
+                a.swap(col * n + k, pivot * n + k);
+            }
+            for k in 0..m {
+                b.swap(col * m + k, pivot * m + k);
+            }
+        }
+
+        let diag = a[col * n + col];
+        for row in 0..n {
+            if row == col {
+                continue;
+            }
+            let factor = a[row * n + col] / diag;
+            if factor == 0.0 {
+                continue;
+            }
+            for k in col..n {
+                a[row * n + k] -= factor * a[col * n + k];
+            }
+            for k in 0..m {
+                b[row * m + k] -= factor * b[col * m + k];
+            }
+        }
+    }
+    for row in 0..n {
+        let diag = a[row * n + row];
+        for k in 0..m {
+            b[row * m + k] /= diag;
+        }
+    }
+}
+
+/// The largest `window_size` [`SeriesSignal::savgol_filter`] accepts.
+const MAX_SAVGOL_WINDOW_SIZE: usize = 1 << 20;
+
+/// The largest `poly_order` [`SeriesSignal::savgol_filter`] accepts. Higher powers of the window
+/// positions overflow `f64` for large windows.
+const MAX_SAVGOL_POLY_ORDER: usize = 32;
+
+/// The `(poly_order + 1) x window_size` matrix that maps the values in a window to the
+/// coefficients of the least-squares polynomial fit of degree `poly_order`. The window positions
+/// are centered around zero.
+fn savgol_pinv(window_size: usize, poly_order: usize) -> Vec<f64> {
+    let w = window_size;
+    let p = poly_order + 1;
+    let half = (w / 2) as f64;
+    // Vandermonde matrix of the window positions.
+    let vander: Vec<f64> = (0..w)
+        .flat_map(|i| {
+            let x = i as f64 - half;
+            (0..p).map(move |j| x.powi(j as i32))
+        })
+        .collect();
+
+    // Solve (A^T A) X = A^T for X = pinv(A).
+    let mut ata = vec![0.0; p * p];
+    for r in 0..p {
+        for c in 0..p {
+            ata[r * p + c] = (0..w).map(|i| vander[i * p + r] * vander[i * p + c]).sum();
+        }
+    }
+    let mut pinv: Vec<f64> = (0..p)
+        .flat_map(|r| (0..w).map(move |i| (r, i)))
+        .map(|(r, i)| vander[i * p + r])
+        .collect();
+    solve_in_place(&mut ata, &mut pinv, p, w);
+    pinv
+}
+
+/// Evaluate the polynomial with `coefficients` (lowest degree first) at `x`.
+fn eval_poly(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+fn savgol_filter_f64(ca: &Float64Chunked, window_size: usize, poly_order: usize) -> Float64Chunked {
+    let values = ca.rechunk();
+    let values = values.iter().collect::<Vec<_>>();
+    let n = values.len();
+    let w = window_size;
+    let p = poly_order + 1;
+    let half = w / 2;
+    let pinv = savgol_pinv(w, poly_order);
+
+    // The fit evaluated at the center of the window, which is the constant term. This row holds
+    // the usual Savitzky-Golay convolution coefficients.
+    let center = &pinv[..w];
+    let apply = |window: &[Option<f64>]| -> Option<f64> {
+        window
+            .iter()
+            .zip(center)
+            .try_fold(0.0, |acc, (v, c)| v.map(|v| acc + v * c))
+    };
+    // The coefficients of the polynomial fitted to a window, only needed at the edges.
+    let fit = |window: &[Option<f64>]| -> Option<Vec<f64>> {
+        let window = window.iter().copied().collect::<Option<Vec<_>>>()?;
+        Some(
+            (0..p)
+                .map(|r| {
+                    let row = &pinv[r * w..(r + 1) * w];
+                    row.iter().zip(&window).map(|(c, v)| c * v).sum()
+                })
+                .collect(),
+        )
+    };
+    let (first_fit, last_fit) = if half > 0 {
+        (fit(&values[..w]), fit(&values[n - w..]))
+    } else {
+        (None, None)
+    };
+
+    // The points within `half` of either end are evaluated on the polynomial fitted to the first
+    // or last window.
+    let out: Float64Chunked = (0..n)
+        .map(|i| {
+            if i < half {
+                let x = i as f64 - half as f64;
+                first_fit.as_deref().map(|c| eval_poly(c, x))
+            } else if i + half >= n {
+                let x = (w - (n - i)) as f64 - half as f64;
+                last_fit.as_deref().map(|c| eval_poly(c, x))
+            } else {
+                apply(&values[i - half..i + half + 1])
+            }
+        })
+        .collect();
+    out.with_name(ca.name().clone())
+}
+
+pub trait SeriesSignal: SeriesSealed {
+    /// Smooth the series with a Savitzky-Golay filter.
+    ///
+    /// Every value is replaced by the least-squares polynomial of degree `poly_order` fitted to
+    /// the `window_size` values centered on it. The coefficients of that fit are computed once
+    /// and applied as a convolution. The values within `window_size / 2` of either end are
+    /// evaluated on the polynomial fitted to the first or last window. A window that contains a
+    /// null produces a null. If the series is shorter than `window_size`, all values are null.
+    ///
+    /// `window_size` can be at most 2^20 and `poly_order` at most 32.
+    fn savgol_filter(&self, window_size: usize, poly_order: usize) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            opq = savgol_filter,
+            s.dtype()
+        );
+        polars_ensure!(
+            window_size % 2 == 1,
+            InvalidOperation: "`window_size` must be odd, got {}", window_size
+        );
+        polars_ensure!(
+            poly_order < window_size,
+            InvalidOperation: "`poly_order` must be less than `window_size`"
+        );
+        polars_ensure!(
+            window_size <= MAX_SAVGOL_WINDOW_SIZE,
+            InvalidOperation: "`window_size` must be at most {}, got {}",
+            MAX_SAVGOL_WINDOW_SIZE, window_size
+        );
+        polars_ensure!(
+            poly_order <= MAX_SAVGOL_POLY_ORDER,
+            InvalidOperation: "`poly_order` must be at most {}, got {}",
+            MAX_SAVGOL_POLY_ORDER, poly_order
+        );
+        if window_size > s.len() {
+            return Ok(Series::full_null(
+                s.name().clone(),
+                s.len(),
+                &DataType::Float64,
+            ));
+        }
+        let s = s.cast(&DataType::Float64)?;
+        Ok(savgol_filter_f64(s.f64()?, window_size, poly_order).into_series())
+    }
+}
+
+impl SeriesSignal for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_savgol_filter() -> PolarsResult<()> {
+        // A polynomial of at most `poly_order` is reproduced exactly.
+        let s = Series::new(
+            "a".into(),
+            (0..7).map(|x| (x * x) as f64).collect::<Vec<_>>(),
+        );
+        let out = s.savgol_filter(5, 2)?;
+        for (a, b) in out
+            .f64()?
+            .into_no_null_iter()
+            .zip(s.f64()?.into_no_null_iter())
+        {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        // The center coefficients for window 5 and order 2 are [-3, 12, 17, 12, -3] / 35.
+        let s = Series::new("a".into(), [0.0, 0.0, 0.0, 35.0, 0.0, 0.0, 0.0]);
+        let out = s.savgol_filter(5, 2)?;
+        let out = out.f64()?;
+        assert!((out.get(3).unwrap() - 17.0).abs() < 1e-9);
+        assert!((out.get(2).unwrap() - 12.0).abs() < 1e-9);
+
+        assert!(s.savgol_filter(4, 2).is_err());
+        assert!(s.savgol_filter(5, 5).is_err());
+        assert!(s.savgol_filter(MAX_SAVGOL_WINDOW_SIZE + 1, 2).is_err());
+        assert!(s.savgol_filter(41, MAX_SAVGOL_POLY_ORDER + 1).is_err());
+
+        // A large window only needs memory linear in its size.
+        let s = Series::new(
+            "a".into(),
+            (0..100_001).map(|x| x as f64).collect::<Vec<_>>(),
+        );
+        let out = s.savgol_filter(100_001, 1)?;
+        let out = out.f64()?;
+        assert!((out.get(0).unwrap() - 0.0).abs() < 1e-6);
+        assert!((out.get(100_000).unwrap() - 100_000.0).abs() < 1e-6);
+        let s = Series::new("a".into(), [0.0, 0.0, 0.0, 35.0, 0.0, 0.0, 0.0]);
+
+        let out = s.savgol_filter(9, 2)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.null_count(), s.len());
+        Ok(())
+    }
+}
@@ -53,3 +53,19 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
         }
     }
 }
+
+/// Returns the unique values and their counts in the order of first appearance, as a struct with
+/// the fields `value` and `count`.
+///
+/// The values and counts are computed in one hash pass, so they are always aligned.
+pub fn unique_with_counts(s: &Series) -> PolarsResult<Series> {
+    // Sorting the groups puts them in the order of their first index.
+    let groups = s.group_tuples(false, true)?;
+    let values = unsafe { s.agg_first(&groups) }.with_name(PlSmallStr::from_static("value"));
+    let counts = groups
+        .group_count()
+        .with_name(PlSmallStr::from_static("count"))
+        .into_series();
+    StructChunked::from_series(s.name().clone(), values.len(), [values, counts].iter())
+        .map(|ca| ca.into_series())
+}
@@ -71,6 +71,38 @@ pub trait SeriesMethods: SeriesSealed {
         }
     }
 
+    /// Get the maximum value, treating `NaN` values exactly like nulls.
+    ///
+    /// [`SeriesTrait::max_reduce`] already skips `NaN` like `numpy.nanmax`, and like it returns
+    /// `NaN` if all non-null values are `NaN`. This returns null in that case instead. Note that
+    /// the `nan_max` aggregations do the opposite and propagate `NaN`.
+    fn max_ignore_nan(&self) -> PolarsResult<AnyValue<'static>> {
+        let max = self.as_series().max_reduce()?.into_value();
+        Ok(null_if_nan(max))
+    }
+
+    /// Get the minimum value, skipping `NaN` values like nulls, see
+    /// [`SeriesMethods::max_ignore_nan`].
+    fn min_ignore_nan(&self) -> PolarsResult<AnyValue<'static>> {
+        let min = self.as_series().min_reduce()?.into_value();
+        Ok(null_if_nan(min))
+    }
+
+    /// Sum the values, skipping `NaN` values like nulls, similar to `numpy.nansum`.
+    ///
+    /// Unlike [`SeriesTrait::sum_reduce`], this doesn't propagate `NaN`. Zero is returned if there
+    /// are no valid values.
+    fn sum_ignore_nan(&self) -> PolarsResult<AnyValue<'static>> {
+        Ok(drop_nans(self.as_series())?.sum_reduce()?.into_value())
+    }
+
+    /// Get the mean of the values, skipping `NaN` values like nulls, similar to `numpy.nanmean`.
+    ///
+    /// The `NaN` values aren't counted. Null is returned if there are no valid values.
+    fn mean_ignore_nan(&self) -> PolarsResult<AnyValue<'static>> {
+        Ok(drop_nans(self.as_series())?.mean_reduce().into_value())
+    }
+
     fn ensure_sorted_arg(&self, operation: &str) -> PolarsResult<()> {
         polars_ensure!(self.is_sorted(Default::default())?, InvalidOperation: "argument in operation '{}' is not sorted, please sort the 'expr/series/column' first", operation);
         Ok(())
@@ -145,6 +177,23 @@ pub trait SeriesMethods: SeriesSealed {
     }
 }
 
+/// The float reductions only return `NaN` if all non-null values are `NaN`.
+fn null_if_nan(av: AnyValue<'static>) -> AnyValue<'static> {
+    match av {
+        AnyValue::Float32(v) if v.is_nan() => AnyValue::Null,
+        AnyValue::Float64(v) if v.is_nan() => AnyValue::Null,
+        av => av,
+    }
+}
+
+fn drop_nans(s: &Series) -> PolarsResult<Series> {
+    if s.dtype().is_float() {
+        s.filter(&s.is_not_nan()?)
+    } else {
+        Ok(s.clone())
+    }
+}
+
 fn check_cmp<T: NumericNative, Cmp: Fn(&T, &T) -> bool>(
     vals: &[T],
     f: Cmp,
@@ -212,3 +261,24 @@ fn is_sorted_ca_num<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: SortOpt
 }
 
 impl SeriesMethods for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum_mean_ignore_nan() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1.0), Some(f64::NAN), None, Some(5.0)]);
+        assert!(s.sum::<f64>()?.is_nan());
+        assert_eq!(s.sum_ignore_nan()?, AnyValue::Float64(6.0));
+        assert_eq!(s.mean_ignore_nan()?, AnyValue::Float64(3.0));
+
+        let s = Series::new("a".into(), [f64::NAN, f64::NAN]);
+        assert_eq!(s.sum_ignore_nan()?, AnyValue::Float64(0.0));
+        assert_eq!(s.mean_ignore_nan()?, AnyValue::Null);
+
+        let s = Series::new("a".into(), [1, 2]);
+        assert_eq!(s.sum_ignore_nan()?, AnyValue::Int32(3));
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_met
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
     FallibleStreamingIterator,
+    encryption::FileDecryptionProperties,
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
@@ -30,6 +31,7 @@ pub use crate::parquet::{
     read::{
         BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
         get_column_iterator, read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +60,14 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting it if the file is encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -2,6 +2,7 @@ use std::io::Write;
 
 use arrow::datatypes::ArrowSchema;
 use polars_error::{PolarsError, PolarsResult};
+use polars_parquet_format::SortingColumn;
 
 use super::schema::schema_to_metadata_key;
 use super::{ColumnWriteOptions, ThriftFileMetadata, WriteOptions, to_parquet_schema};
@@ -83,6 +84,17 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file and records the leaf columns it is sorted by.
+    pub fn write_with_sorting_columns(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_sorting_columns(row_group, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
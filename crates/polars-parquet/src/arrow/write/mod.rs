@@ -30,6 +30,7 @@ use arrow::datatypes::*;
 use arrow::types::{NativeType, days_ms, i256};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
+pub use polars_parquet_format::SortingColumn;
 use polars_utils::pl_str::PlSmallStr;
 pub use utils::write_def_levels;
 
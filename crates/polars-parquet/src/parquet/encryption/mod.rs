@@ -0,0 +1,538 @@
+//! Decryption of files written with [Parquet modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Encrypted files are made of modules (the footer, column metadata, page headers and pages),
+//! each encrypted with AES-GCM, except for pages of files using `AES_GCM_CTR_V1`, which are
+//! encrypted with AES-CTR. Every module is stored as `length ‖ nonce ‖ ciphertext [‖ tag]`,
+//! where the length is a 4 byte little-endian integer that doesn't count itself.
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm,
+};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+
+use self::crypto::{decrypt_ctr, decrypt_gcm, encrypt_gcm_tag};
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const LENGTH_LEN: usize = 4;
+
+/// A function that returns the key identified by the key metadata stored in the file.
+pub type KeyRetriever = dyn Fn(&[u8]) -> PolarsResult<Vec<u8>> + Send + Sync;
+
+/// Keys used to read files written with Parquet modular encryption.
+///
+/// Keys are looked up in this order: the explicit footer key or column key, then the key
+/// retriever, which receives the key metadata that the writer stored next to the encrypted
+/// module. Column keys are addressed by their dot-separated path in the schema, e.g. `a.b`.
+#[derive(Clone, Default)]
+pub struct FileDecryptionProperties {
+    footer_key: Option<Vec<u8>>,
+    column_keys: BTreeMap<String, Vec<u8>>,
+    aad_prefix: Option<Vec<u8>>,
+    key_retriever: Option<Arc<KeyRetriever>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the key of the footer and of the columns encrypted with the footer key.
+    pub fn with_footer_key(mut self, key: Vec<u8>) -> Self {
+        self.footer_key = Some(key);
+        self
+    }
+
+    /// Set the key of the column at the dot-separated `column_path`.
+    pub fn with_column_key(mut self, column_path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys.insert(column_path.into(), key);
+        self
+    }
+
+    /// Set the AAD prefix, required for files written without storing it.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// Set the function that retrieves keys from their key metadata.
+    pub fn with_key_retriever(
+        mut self,
+        key_retriever: impl Fn(&[u8]) -> PolarsResult<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        self.key_retriever = Some(Arc::new(key_retriever));
+        self
+    }
+
+    fn retrieve_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Option<Arc<[u8]>>> {
+        let (Some(key_retriever), Some(key_metadata)) = (&self.key_retriever, key_metadata) else {
+            return Ok(None);
+        };
+        let key = key_retriever(key_metadata)
+            .map_err(|e| ParquetError::InvalidParameter(format!("failed to retrieve key: {e}")))?;
+        Ok(Some(key.into()))
+    }
+
+    fn key_retriever_addr(&self) -> Option<usize> {
+        self.key_retriever
+            .as_ref()
+            .map(|f| Arc::as_ptr(f) as *const () as usize)
+    }
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the keys.
+        f.debug_struct("FileDecryptionProperties")
+            .field(
+                "footer_key",
+                &self.footer_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("aad_prefix", &self.aad_prefix)
+            .field(
+                "key_retriever",
+                &self
+                    .key_retriever_addr()
+                    .map(|addr| format!("key retriever function at 0x{addr:016x}")),
+            )
+            .finish()
+    }
+}
+
+impl Eq for FileDecryptionProperties {}
+
+impl PartialEq for FileDecryptionProperties {
+    fn eq(&self, other: &Self) -> bool {
+        self.footer_key == other.footer_key
+            && self.column_keys == other.column_keys
+            && self.aad_prefix == other.aad_prefix
+            && self.key_retriever_addr() == other.key_retriever_addr()
+    }
+}
+
+impl Hash for FileDecryptionProperties {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Leave the keys out, hashes of the plan may be logged or persisted.
+        self.footer_key.is_some().hash(state);
+        self.column_keys.keys().for_each(|path| path.hash(state));
+        self.aad_prefix.hash(state);
+        self.key_retriever_addr().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    AesGcmV1,
+    AesGcmCtrV1,
+}
+
+/// The module types, which are part of the additional authenticated data of every module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+/// Decrypts the footer and the column metadata of a file.
+pub(crate) struct FileDecryptor<'a> {
+    properties: &'a FileDecryptionProperties,
+    algorithm: Algorithm,
+    file_aad: Arc<[u8]>,
+    footer_key: Option<Arc<[u8]>>,
+    // Keys returned by the key retriever, by column path.
+    column_keys: PlHashMap<String, Arc<[u8]>>,
+}
+
+impl<'a> FileDecryptor<'a> {
+    pub(crate) fn try_new(
+        properties: &'a FileDecryptionProperties,
+        algorithm: EncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Self> {
+        let (algorithm, aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+            EncryptionAlgorithm::AESGCMV1(v) => (
+                Algorithm::AesGcmV1,
+                v.aad_prefix,
+                v.aad_file_unique,
+                v.supply_aad_prefix,
+            ),
+            EncryptionAlgorithm::AESGCMCTRV1(v) => (
+                Algorithm::AesGcmCtrV1,
+                v.aad_prefix,
+                v.aad_file_unique,
+                v.supply_aad_prefix,
+            ),
+        };
+
+        let aad_prefix = match (aad_prefix, &properties.aad_prefix) {
+            (Some(stored), Some(supplied)) if &stored != supplied => {
+                return Err(ParquetError::InvalidParameter(
+                    "the AAD prefix does not match the one stored in the file".to_string(),
+                ));
+            },
+            (Some(stored), _) => stored,
+            (None, Some(supplied)) => supplied.clone(),
+            (None, None) if supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was written without storing its AAD prefix, which must be supplied"
+                        .to_string(),
+                ));
+            },
+            (None, None) => vec![],
+        };
+        let file_aad = [aad_prefix, aad_file_unique.unwrap_or_default()].concat();
+
+        let footer_key = match &properties.footer_key {
+            Some(key) => Some(key.as_slice().into()),
+            None => properties.retrieve_key(footer_key_metadata)?,
+        };
+
+        Ok(Self {
+            properties,
+            algorithm,
+            file_aad: file_aad.into(),
+            footer_key,
+            column_keys: PlHashMap::new(),
+        })
+    }
+
+    fn footer_key(&self) -> ParquetResult<Arc<[u8]>> {
+        self.footer_key.clone().ok_or_else(|| {
+            ParquetError::InvalidParameter("no decryption key for the footer".to_string())
+        })
+    }
+
+    fn column_key(
+        &mut self,
+        path_in_schema: &[String],
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Arc<[u8]>> {
+        let path = path_in_schema.join(".");
+
+        if let Some(key) = self.properties.column_keys.get(&path) {
+            return Ok(key.as_slice().into());
+        }
+        if let Some(key) = self.column_keys.get(&path) {
+            return Ok(key.clone());
+        }
+
+        let key = self.properties.retrieve_key(key_metadata)?.ok_or_else(|| {
+            ParquetError::InvalidParameter(format!("no decryption key for column '{path}'"))
+        })?;
+        self.column_keys.insert(path, key.clone());
+        Ok(key)
+    }
+
+    /// Decrypts an encrypted footer, which is a single AES-GCM module at the start of `bytes`.
+    pub(crate) fn decrypt_footer(&self, bytes: &[u8]) -> ParquetResult<Vec<u8>> {
+        let module = bytes
+            .get(..LENGTH_LEN)
+            .and_then(|length_bytes| bytes.get(..module_len(length_bytes.try_into().unwrap())))
+            .ok_or_else(|| ParquetError::oos("The encrypted footer is truncated"))?;
+        let aad = [self.file_aad.as_ref(), &[ModuleType::Footer as u8]].concat();
+        decrypt_gcm(&self.footer_key()?, module, &aad)
+    }
+
+    /// Verifies the signature (`nonce ‖ tag`) at the start of `bytes`, which follow a plaintext
+    /// footer.
+    ///
+    /// This is skipped when the footer key is unknown, so that readers with only some of the
+    /// column keys can still read those columns.
+    pub(crate) fn verify_footer_signature(&self, footer: &[u8], bytes: &[u8]) -> ParquetResult<()> {
+        let Some(key) = &self.footer_key else {
+            return Ok(());
+        };
+        let signature = bytes.get(..NONCE_LEN + TAG_LEN).ok_or_else(|| {
+            ParquetError::oos(
+                "The plaintext footer of an encrypted file must be followed by a 28 byte signature",
+            )
+        })?;
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let aad = [self.file_aad.as_ref(), &[ModuleType::Footer as u8]].concat();
+        if encrypt_gcm_tag(key, nonce, footer, &aad)? != tag {
+            return Err(ParquetError::InvalidParameter(
+                "the signature of the plaintext footer does not match the footer key".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Decrypts the column metadata of an encrypted column chunk and returns the decryptor of its
+    /// pages. Column chunks that aren't encrypted are returned unchanged.
+    pub(crate) fn decrypt_column_chunk(
+        &mut self,
+        mut column_chunk: ColumnChunk,
+        row_group_ordinal: i16,
+        column_ordinal: usize,
+    ) -> ParquetResult<(ColumnChunk, Option<Arc<ColumnDecryptor>>)> {
+        let key = match &column_chunk.crypto_metadata {
+            None => return Ok((column_chunk, None)),
+            Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => self.footer_key()?,
+            Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(v)) => {
+                self.column_key(&v.path_in_schema, v.key_metadata.as_deref())?
+            },
+        };
+
+        let decryptor = ColumnDecryptor {
+            algorithm: self.algorithm,
+            key,
+            file_aad: self.file_aad.clone(),
+            row_group_ordinal,
+            column_ordinal: column_ordinal.try_into()?,
+        };
+
+        // Files with a plaintext footer keep a copy of the metadata without statistics in
+        // `meta_data` for legacy readers, the full metadata is always the encrypted one.
+        if let Some(encrypted) = column_chunk.encrypted_column_metadata.take() {
+            let metadata = decrypt_gcm(
+                &decryptor.key,
+                &encrypted,
+                &decryptor.aad(ModuleType::ColumnMetaData, None),
+            )?;
+            let mut prot =
+                TCompactInputProtocol::new(metadata.as_slice(), metadata.len() * 2 + 1024);
+            column_chunk.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+        }
+
+        Ok((column_chunk, Some(Arc::new(decryptor))))
+    }
+}
+
+/// Decrypts the page headers and pages of an encrypted column chunk.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnDecryptor {
+    algorithm: Algorithm,
+    key: Arc<[u8]>,
+    file_aad: Arc<[u8]>,
+    row_group_ordinal: i16,
+    column_ordinal: i16,
+}
+
+impl Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("algorithm", &self.algorithm)
+            .field("row_group_ordinal", &self.row_group_ordinal)
+            .field("column_ordinal", &self.column_ordinal)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnDecryptor {
+    fn aad(&self, module_type: ModuleType, page_ordinal: Option<i16>) -> Vec<u8> {
+        module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )
+    }
+
+    /// Decrypts a page header. The module type is not known before decrypting, so the first
+    /// header of a column chunk is tried as a dictionary page header first.
+    pub(crate) fn decrypt_page_header(
+        &self,
+        module: &[u8],
+        is_first_page: bool,
+        page_ordinal: i16,
+    ) -> ParquetResult<Vec<u8>> {
+        if is_first_page {
+            let aad = self.aad(ModuleType::DictionaryPageHeader, None);
+            if let Ok(header) = decrypt_gcm(&self.key, module, &aad) {
+                return Ok(header);
+            }
+        }
+        let aad = self.aad(ModuleType::DataPageHeader, Some(page_ordinal));
+        decrypt_gcm(&self.key, module, &aad)
+    }
+
+    /// Decrypts a dictionary page or, if `page_ordinal` is set, a data page.
+    pub(crate) fn decrypt_page(
+        &self,
+        module: &[u8],
+        page_ordinal: Option<i16>,
+    ) -> ParquetResult<Vec<u8>> {
+        match self.algorithm {
+            Algorithm::AesGcmV1 => {
+                let aad = match page_ordinal {
+                    None => self.aad(ModuleType::DictionaryPage, None),
+                    Some(page_ordinal) => self.aad(ModuleType::DataPage, Some(page_ordinal)),
+                };
+                decrypt_gcm(&self.key, module, &aad)
+            },
+            Algorithm::AesGcmCtrV1 => decrypt_ctr(&self.key, module),
+        }
+    }
+}
+
+fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group_ordinal: i16,
+    column_ordinal: i16,
+    page_ordinal: Option<i16>,
+) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    aad.extend_from_slice(&row_group_ordinal.to_le_bytes());
+    aad.extend_from_slice(&column_ordinal.to_le_bytes());
+    if let Some(page_ordinal) = page_ordinal {
+        aad.extend_from_slice(&page_ordinal.to_le_bytes());
+    }
+    aad
+}
+
+/// Returns the length of the module that starts with `length_bytes`, including the length itself.
+pub(crate) fn module_len(length_bytes: [u8; 4]) -> usize {
+    LENGTH_LEN + u32::from_le_bytes(length_bytes) as usize
+}
+
+#[cfg(feature = "encryption")]
+mod crypto {
+    use aes_gcm::AesGcm;
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::generic_array::GenericArray;
+    use aes_gcm::aead::{Aead, AeadInPlace, KeyInit, Payload};
+    use aes_gcm::aes::{Aes128, Aes192, Aes256};
+    use ctr::Ctr32BE;
+    use ctr::cipher::{KeyIvInit, StreamCipher};
+
+    use super::{LENGTH_LEN, NONCE_LEN, TAG_LEN, module_len};
+    use crate::parquet::error::{ParquetError, ParquetResult};
+
+    /// Splits a module into its nonce and its ciphertext (followed by the tag for AES-GCM).
+    fn split_module(module: &[u8], min_len: usize) -> ParquetResult<(&[u8], &[u8])> {
+        if module.len() < LENGTH_LEN + min_len
+            || module_len(module[..LENGTH_LEN].try_into().unwrap()) != module.len()
+        {
+            return Err(ParquetError::oos(
+                "The length of an encrypted module does not match its size",
+            ));
+        }
+        Ok(module[LENGTH_LEN..].split_at(NONCE_LEN))
+    }
+
+    fn invalid_key_length() -> ParquetError {
+        ParquetError::InvalidParameter(
+            "encryption keys must be 16, 24 or 32 bytes long".to_string(),
+        )
+    }
+
+    pub(super) fn decrypt_gcm(key: &[u8], module: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        fn decrypt<C: Aead + KeyInit>(
+            key: &[u8],
+            nonce: &[u8],
+            payload: Payload,
+        ) -> Option<Vec<u8>> {
+            let cipher = C::new_from_slice(key).unwrap();
+            cipher
+                .decrypt(GenericArray::from_slice(nonce), payload)
+                .ok()
+        }
+
+        let (nonce, msg) = split_module(module, NONCE_LEN + TAG_LEN)?;
+        let payload = Payload { msg, aad };
+        match key.len() {
+            16 => decrypt::<AesGcm<Aes128, U12>>(key, nonce, payload),
+            24 => decrypt::<AesGcm<Aes192, U12>>(key, nonce, payload),
+            32 => decrypt::<AesGcm<Aes256, U12>>(key, nonce, payload),
+            _ => return Err(invalid_key_length()),
+        }
+        .ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "failed to decrypt the parquet file, the key is wrong or the file is corrupted"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Returns the AES-GCM tag of `msg`, which is how plaintext footers are signed.
+    pub(super) fn encrypt_gcm_tag(
+        key: &[u8],
+        nonce: &[u8],
+        msg: &[u8],
+        aad: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        fn tag<C: AeadInPlace + KeyInit>(
+            key: &[u8],
+            nonce: &[u8],
+            msg: &[u8],
+            aad: &[u8],
+        ) -> Vec<u8> {
+            let cipher = C::new_from_slice(key).unwrap();
+            let mut buffer = msg.to_vec();
+            cipher
+                .encrypt_in_place_detached(GenericArray::from_slice(nonce), aad, &mut buffer)
+                .unwrap()
+                .to_vec()
+        }
+
+        Ok(match key.len() {
+            16 => tag::<AesGcm<Aes128, U12>>(key, nonce, msg, aad),
+            24 => tag::<AesGcm<Aes192, U12>>(key, nonce, msg, aad),
+            32 => tag::<AesGcm<Aes256, U12>>(key, nonce, msg, aad),
+            _ => return Err(invalid_key_length()),
+        })
+    }
+
+    pub(super) fn decrypt_ctr(key: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+        fn decrypt<C: KeyIvInit + StreamCipher>(key: &[u8], iv: &[u8], buffer: &mut [u8]) {
+            C::new_from_slices(key, iv).unwrap().apply_keystream(buffer)
+        }
+
+        let (nonce, ciphertext) = split_module(module, 0)?;
+        // The 4 byte big-endian counter starts at 1, as in AES-GCM.
+        let iv = [nonce, &[0, 0, 0, 1]].concat();
+        let mut buffer = ciphertext.to_vec();
+        match key.len() {
+            16 => decrypt::<Ctr32BE<Aes128>>(key, &iv, &mut buffer),
+            24 => decrypt::<Ctr32BE<Aes192>>(key, &iv, &mut buffer),
+            32 => decrypt::<Ctr32BE<Aes256>>(key, &iv, &mut buffer),
+            _ => return Err(invalid_key_length()),
+        }
+        Ok(buffer)
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+mod crypto {
+    use crate::parquet::error::{Feature, ParquetError, ParquetResult};
+
+    fn not_active() -> ParquetError {
+        ParquetError::FeatureNotActive(Feature::Encryption, "read encrypted files".to_string())
+    }
+
+    pub(super) fn decrypt_gcm(_key: &[u8], _module: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        Err(not_active())
+    }
+
+    pub(super) fn encrypt_gcm_tag(
+        _key: &[u8],
+        _nonce: &[u8],
+        _msg: &[u8],
+        _aad: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        Err(not_active())
+    }
+
+    pub(super) fn decrypt_ctr(_key: &[u8], _module: &[u8]) -> ParquetResult<Vec<u8>> {
+        Err(not_active())
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests;
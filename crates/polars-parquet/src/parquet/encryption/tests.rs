@@ -0,0 +1,460 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::aes::Aes128;
+use aes_gcm::{Aes128Gcm, Nonce};
+use arrow::array::{ArrayRef, Int64Array, Utf8ViewArray};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use arrow::record_batch::RecordBatchT;
+use ctr::Ctr32BE;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use polars_error::{PolarsResult, polars_bail};
+use polars_parquet_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use polars_parquet_format::{
+    AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData, EncryptionWithColumnKey, EncryptionWithFooterKey,
+    FileCryptoMetaData, PageHeader, PageType,
+};
+use polars_utils::mmap::{MemReader, MemSlice};
+
+use super::*;
+use crate::arrow::read::{
+    BasicDecompressor, PageReader, column_iter_to_arrays, infer_schema,
+    read_metadata_with_decryption,
+};
+use crate::arrow::write::{
+    ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions, FileWriter,
+    RowGroupIterator, StatisticsOptions, Version, WriteOptions,
+};
+use crate::parquet::statistics::Statistics;
+use crate::parquet::thrift_format::FileMetaData as TFileMetadata;
+
+const FOOTER_KEY: &[u8] = b"0123456789012345";
+const COLUMN_KEY: &[u8] = b"1234567890123450";
+const AAD_FILE_UNIQUE: &[u8] = b"file unique";
+
+fn write_plaintext_file() -> PolarsResult<(Vec<u8>, Vec<ArrayRef>)> {
+    let a = Int64Array::from_vec((0..1000).collect()).boxed();
+    let b = Utf8ViewArray::from_slice(
+        (0..1000)
+            .map(|i| Some(["foo", "bar", "hamlet"][i % 3]))
+            .collect::<Vec<_>>(),
+    )
+    .boxed();
+    let schema = ArrowSchema::from_iter([
+        Field::new("a".into(), ArrowDataType::Int64, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ]);
+    let column_options: Vec<ColumnWriteOptions> = [Encoding::Plain, Encoding::RleDictionary]
+        .into_iter()
+        .map(|encoding| {
+            FieldWriteOptions::default_with_encoding(encoding).into_default_column_write_options()
+        })
+        .collect();
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        // Several data pages per column chunk, to cover the page ordinals.
+        data_page_size: Some(1024),
+    };
+
+    let batch = RecordBatchT::try_new(1000, Arc::new(schema.clone()), vec![a.clone(), b.clone()]);
+    let row_groups = RowGroupIterator::try_new(
+        [batch].into_iter(),
+        &schema,
+        options,
+        column_options.clone(),
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options, &column_options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None, &column_options)?;
+
+    Ok((writer.into_inner().into_inner(), vec![a, b]))
+}
+
+/// How [`encrypt_file`] encrypts a file.
+struct Encryption {
+    algorithm: Algorithm,
+    plaintext_footer: bool,
+    /// The column encrypted with its own key, the others are encrypted with the footer key.
+    column_with_key: &'static str,
+}
+
+fn gcm_module(key: &[u8], nonce: u8, msg: &[u8], aad: &[u8]) -> Vec<u8> {
+    let nonce = [nonce; NONCE_LEN];
+    let ciphertext = Aes128Gcm::new_from_slice(key)
+        .unwrap()
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg, aad })
+        .unwrap();
+    let len = (NONCE_LEN + ciphertext.len()) as u32;
+    [&len.to_le_bytes()[..], &nonce, &ciphertext].concat()
+}
+
+fn ctr_module(key: &[u8], nonce: u8, msg: &[u8]) -> Vec<u8> {
+    let nonce = [nonce; NONCE_LEN];
+    let iv = [&nonce[..], &[0, 0, 0, 1]].concat();
+    let mut ciphertext = msg.to_vec();
+    Ctr32BE::<Aes128>::new_from_slices(key, &iv)
+        .unwrap()
+        .apply_keystream(&mut ciphertext);
+    let len = (NONCE_LEN + ciphertext.len()) as u32;
+    [&len.to_le_bytes()[..], &nonce, &ciphertext].concat()
+}
+
+macro_rules! to_thrift_bytes {
+    ($value:expr) => {{
+        let mut bytes = vec![];
+        $value
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))
+            .unwrap();
+        bytes
+    }};
+}
+
+/// Rewrites a plaintext file the way a writer using modular encryption would have written it.
+fn encrypt_file(plain: &[u8], encryption: &Encryption) -> Vec<u8> {
+    let end = plain.len() - 8;
+    let footer_len = u32::from_le_bytes(plain[end..end + 4].try_into().unwrap()) as usize;
+    let mut footer = &plain[end - footer_len..end];
+    let mut metadata = TFileMetadata::read_from_in_protocol(&mut TCompactInputProtocol::new(
+        &mut footer,
+        usize::MAX,
+    ))
+    .unwrap();
+
+    let algorithm = match encryption.algorithm {
+        Algorithm::AesGcmV1 => EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: None,
+            aad_file_unique: Some(AAD_FILE_UNIQUE.to_vec()),
+            supply_aad_prefix: None,
+        }),
+        Algorithm::AesGcmCtrV1 => EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+            aad_prefix: None,
+            aad_file_unique: Some(AAD_FILE_UNIQUE.to_vec()),
+            supply_aad_prefix: None,
+        }),
+    };
+    let mut nonce = 0u8;
+    let mut next_nonce = || {
+        nonce += 1;
+        nonce
+    };
+
+    let magic = if encryption.plaintext_footer {
+        b"PAR1"
+    } else {
+        b"PARE"
+    };
+    let mut out = magic.to_vec();
+    for (row_group_ordinal, row_group) in metadata.row_groups.iter_mut().enumerate() {
+        for (column_ordinal, column_chunk) in row_group.columns.iter_mut().enumerate() {
+            let mut meta = column_chunk.meta_data.take().unwrap();
+            let path = meta.path_in_schema.join(".");
+            let has_column_key = path == encryption.column_with_key;
+            let key = if has_column_key {
+                COLUMN_KEY
+            } else {
+                FOOTER_KEY
+            };
+            let aad = |module_type, page_ordinal| {
+                module_aad(
+                    AAD_FILE_UNIQUE,
+                    module_type,
+                    row_group_ordinal as i16,
+                    column_ordinal as i16,
+                    page_ordinal,
+                )
+            };
+
+            let start = meta.dictionary_page_offset.unwrap_or(meta.data_page_offset) as usize;
+            let mut pages = &plain[start..start + meta.total_compressed_size as usize];
+            let new_start = out.len();
+            let mut page_ordinal = 0;
+            while !pages.is_empty() {
+                let mut header = PageHeader::read_from_in_protocol(
+                    &mut TCompactInputProtocol::new(&mut pages, usize::MAX),
+                )
+                .unwrap();
+                let (data, rest) = pages.split_at(header.compressed_page_size as usize);
+                pages = rest;
+
+                let is_dict = header.type_ == PageType::DICTIONARY_PAGE;
+                let (page_type, header_type, ordinal) = if is_dict {
+                    meta.dictionary_page_offset = Some(out.len() as i64);
+                    (
+                        ModuleType::DictionaryPage,
+                        ModuleType::DictionaryPageHeader,
+                        None,
+                    )
+                } else {
+                    if page_ordinal == 0 {
+                        meta.data_page_offset = out.len() as i64;
+                    }
+                    page_ordinal += 1;
+                    (
+                        ModuleType::DataPage,
+                        ModuleType::DataPageHeader,
+                        Some(page_ordinal - 1),
+                    )
+                };
+
+                let data = match encryption.algorithm {
+                    Algorithm::AesGcmV1 => {
+                        gcm_module(key, next_nonce(), data, &aad(page_type, ordinal))
+                    },
+                    Algorithm::AesGcmCtrV1 => ctr_module(key, next_nonce(), data),
+                };
+                header.compressed_page_size = data.len() as i32;
+                let header = to_thrift_bytes!(header);
+                out.extend(gcm_module(
+                    key,
+                    next_nonce(),
+                    &header,
+                    &aad(header_type, ordinal),
+                ));
+                out.extend(data);
+            }
+            meta.total_compressed_size = (out.len() - new_start) as i64;
+            column_chunk.file_offset = new_start as i64;
+            column_chunk.offset_index_offset = None;
+            column_chunk.offset_index_length = None;
+            column_chunk.column_index_offset = None;
+            column_chunk.column_index_length = None;
+
+            if has_column_key {
+                let encrypted = to_thrift_bytes!(meta);
+                column_chunk.encrypted_column_metadata = Some(gcm_module(
+                    key,
+                    next_nonce(),
+                    &encrypted,
+                    &aad(ModuleType::ColumnMetaData, None),
+                ));
+                column_chunk.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                    EncryptionWithColumnKey {
+                        path_in_schema: meta.path_in_schema.clone(),
+                        key_metadata: Some(path.into_bytes()),
+                    },
+                ));
+                // Legacy readers of files with a plaintext footer get the metadata without
+                // statistics.
+                meta.statistics = None;
+                column_chunk.meta_data = encryption.plaintext_footer.then_some(meta);
+            } else {
+                column_chunk.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    EncryptionWithFooterKey {},
+                ));
+                column_chunk.meta_data = Some(meta);
+            }
+        }
+        row_group.file_offset = None;
+        row_group.total_compressed_size = None;
+    }
+
+    let footer_aad = [AAD_FILE_UNIQUE, &[ModuleType::Footer as u8]].concat();
+    let tail = if encryption.plaintext_footer {
+        metadata.encryption_algorithm = Some(algorithm);
+        metadata.footer_signing_key_metadata = Some(b"footer".to_vec());
+        let footer = to_thrift_bytes!(metadata);
+        let module = gcm_module(FOOTER_KEY, next_nonce(), &footer, &footer_aad);
+        let nonce = &module[LENGTH_LEN..LENGTH_LEN + NONCE_LEN];
+        let tag = &module[module.len() - TAG_LEN..];
+        [&footer[..], nonce, tag].concat()
+    } else {
+        let crypto_metadata = FileCryptoMetaData {
+            encryption_algorithm: algorithm,
+            key_metadata: Some(b"footer".to_vec()),
+        };
+        let footer = to_thrift_bytes!(metadata);
+        let module = gcm_module(FOOTER_KEY, next_nonce(), &footer, &footer_aad);
+        [to_thrift_bytes!(crypto_metadata), module].concat()
+    };
+    out.extend(&tail);
+    out.extend((tail.len() as u32).to_le_bytes());
+    out.extend(magic);
+    out
+}
+
+fn read_file(
+    data: &[u8],
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<Vec<ArrayRef>> {
+    let metadata = read_metadata_with_decryption(&mut Cursor::new(data), decryption)?;
+    let schema = infer_schema(&metadata)?;
+    let data = MemSlice::from_vec(data.to_vec());
+
+    let [row_group] = metadata.row_groups.as_slice() else {
+        unreachable!()
+    };
+    schema
+        .iter_values()
+        .map(|field| {
+            let (columns, types) = row_group
+                .columns_under_root_iter(&field.name)
+                .unwrap()
+                .map(|column| {
+                    let range = column.byte_range();
+                    let chunk = data.slice(range.start as usize..range.end as usize);
+                    let pages = PageReader::new(MemReader::new(chunk), column, vec![], usize::MAX);
+                    (
+                        BasicDecompressor::new(pages, vec![]),
+                        &column.descriptor().descriptor.primitive_type,
+                    )
+                })
+                .unzip();
+            Ok(column_iter_to_arrays(columns, types, field.clone(), None)?.0)
+        })
+        .collect()
+}
+
+fn key_retriever(key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+    match key_metadata {
+        b"footer" => Ok(FOOTER_KEY.to_vec()),
+        b"a" => Ok(COLUMN_KEY.to_vec()),
+        _ => polars_bail!(ComputeError: "unknown key"),
+    }
+}
+
+fn round_trip(algorithm: Algorithm, plaintext_footer: bool) -> PolarsResult<()> {
+    let (plain, expected) = write_plaintext_file()?;
+    let data = encrypt_file(
+        &plain,
+        &Encryption {
+            algorithm,
+            plaintext_footer,
+            column_with_key: "a",
+        },
+    );
+
+    let with_keys = FileDecryptionProperties::new()
+        .with_footer_key(FOOTER_KEY.to_vec())
+        .with_column_key("a", COLUMN_KEY.to_vec());
+    let with_key_retriever = FileDecryptionProperties::new().with_key_retriever(key_retriever);
+    for decryption in [with_keys, with_key_retriever] {
+        let arrays = read_file(&data, Some(&decryption))?;
+        assert_eq!(arrays, expected);
+
+        // Statistics are read from the decrypted column metadata.
+        let metadata = read_metadata_with_decryption(&mut Cursor::new(&data), Some(&decryption))?;
+        let Some(Statistics::Int64(statistics)) = metadata.row_groups[0].parquet_columns()[0]
+            .statistics()
+            .transpose()?
+        else {
+            unreachable!()
+        };
+        assert_eq!(statistics.min_value, Some(0));
+        assert_eq!(statistics.max_value, Some(999));
+    }
+    Ok(())
+}
+
+#[test]
+fn read_encrypted_footer_aes_gcm() -> PolarsResult<()> {
+    round_trip(Algorithm::AesGcmV1, false)
+}
+
+#[test]
+fn read_encrypted_footer_aes_gcm_ctr() -> PolarsResult<()> {
+    round_trip(Algorithm::AesGcmCtrV1, false)
+}
+
+#[test]
+fn read_plaintext_footer_aes_gcm() -> PolarsResult<()> {
+    round_trip(Algorithm::AesGcmV1, true)
+}
+
+#[test]
+fn read_plaintext_footer_aes_gcm_ctr() -> PolarsResult<()> {
+    round_trip(Algorithm::AesGcmCtrV1, true)
+}
+
+fn read_error(plaintext_footer: bool, decryption: Option<FileDecryptionProperties>) -> String {
+    let (plain, _) = write_plaintext_file().unwrap();
+    let data = encrypt_file(
+        &plain,
+        &Encryption {
+            algorithm: Algorithm::AesGcmV1,
+            plaintext_footer,
+            column_with_key: "a",
+        },
+    );
+    read_file(&data, decryption.as_ref())
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn read_without_keys() {
+    for plaintext_footer in [false, true] {
+        let error = read_error(plaintext_footer, None);
+        assert!(
+            error.contains("decryption properties are required"),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn read_with_wrong_footer_key() {
+    let decryption = FileDecryptionProperties::new()
+        .with_footer_key(COLUMN_KEY.to_vec())
+        .with_column_key("a", COLUMN_KEY.to_vec());
+
+    let error = read_error(false, Some(decryption.clone()));
+    assert!(error.contains("the key is wrong"), "{error}");
+    let error = read_error(true, Some(decryption));
+    assert!(
+        error.contains("signature of the plaintext footer"),
+        "{error}"
+    );
+}
+
+#[test]
+fn read_without_column_key() {
+    let decryption = FileDecryptionProperties::new().with_footer_key(FOOTER_KEY.to_vec());
+    for plaintext_footer in [false, true] {
+        let error = read_error(plaintext_footer, Some(decryption.clone()));
+        assert!(
+            error.contains("no decryption key for column 'a'"),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn decrypt_modules() {
+    // Generated with the `cryptography` Python package.
+    let key: Vec<u8> = (0..16).collect();
+    let aad = module_aad(b"file", ModuleType::DataPage, 1, 2, Some(3));
+    assert_eq!(aad, b"file\x02\x01\x00\x02\x00\x03\x00");
+
+    let gcm = "360000006465666768696a6b6c6d6e6f6a033cd4ae67ccdaf6513365eaab59cc55e61b8debd9a9339b\
+               01f7eac5a78a90739a80ac8a22ef40d5cf";
+    let ctr =
+        "260000006465666768696a6b6c6d6e6f15e3de74952265884bd29a5b7825328b7f0c2dd7a272cc93f450";
+    let [gcm, ctr] = [gcm, ctr].map(|hex| {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert_eq!(
+        decrypt_gcm(&key, &gcm, &aad).unwrap(),
+        b"parquet modular encryption"
+    );
+    assert_eq!(
+        decrypt_ctr(&key, &ctr).unwrap(),
+        b"parquet modular encryption"
+    );
+
+    // The AAD binds a module to its position in the file.
+    let other_page = module_aad(b"file", ModuleType::DataPage, 1, 2, Some(4));
+    assert!(decrypt_gcm(&key, &gcm, &other_page).is_err());
+    let mut truncated = gcm.clone();
+    truncated.pop();
+    assert!(decrypt_gcm(&key, &truncated, &aad).is_err());
+    assert!(decrypt_gcm(&key[..15], &gcm, &aad).is_err());
+}
@@ -15,6 +15,8 @@ pub enum Feature {
     Lz4,
     /// Zstd compression and decompression
     Zstd,
+    /// Decryption of files written with modular encryption
+    Encryption,
 }
 
 /// Errors generated by this crate
@@ -43,6 +45,14 @@ impl ParquetError {
     pub(crate) fn not_supported<I: Into<String>>(message: I) -> Self {
         Self::FeatureNotSupported(message.into())
     }
+
+    /// The error returned for encrypted files (modular encryption) read without decryption properties.
+    pub fn decryption_required() -> Self {
+        Self::InvalidParameter(
+            "the parquet file is encrypted, decryption properties are required to read it"
+                .to_string(),
+        )
+    }
 }
 
 impl std::error::Error for ParquetError {}
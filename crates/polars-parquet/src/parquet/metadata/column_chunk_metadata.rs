@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -36,6 +39,9 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// Set if the pages of this column chunk are encrypted.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decryption: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde")]
@@ -76,6 +82,7 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            decryption: None,
         }
     }
 
@@ -166,6 +173,11 @@ impl ColumnChunkMetadata {
         &self.metadata().encodings
     }
 
+    /// The decryptor of the pages of this column chunk, if they are encrypted.
+    pub(crate) fn decryption(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryption.as_ref()
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         // this has been validated in [`try_from_thrift`]
@@ -173,10 +185,18 @@ impl ColumnChunkMetadata {
     }
 
     /// Method to convert from Thrift.
+    ///
+    /// `decryption` must be set if the column chunk is encrypted, in which case its metadata must
+    /// already be decrypted.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
         column_chunk: ColumnChunk,
+        decryption: Option<Arc<ColumnDecryptor>>,
     ) -> ParquetResult<Self> {
+        if column_chunk.crypto_metadata.is_some() && decryption.is_none() {
+            return Err(ParquetError::decryption_required());
+        }
+
         // validate metadata
         if let Some(meta) = &column_chunk.meta_data {
             let _: u64 = meta.total_compressed_size.try_into()?;
@@ -194,6 +214,7 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryption,
         })
     }
 
@@ -203,9 +224,7 @@ impl ColumnChunkMetadata {
     }
 }
 
-pub(super) fn column_metadata_byte_range(
-    column_metadata: &ColumnMetaData,
-) -> core::ops::Range<u64> {
+fn column_metadata_byte_range(column_metadata: &ColumnMetaData) -> core::ops::Range<u64> {
     let offset = if let Some(dict_page_offset) = column_metadata.dictionary_page_offset {
         dict_page_offset as u64
     } else {
@@ -7,8 +7,9 @@ use polars_utils::idx_vec::UnitVec;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unitvec;
 
-use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
+use super::column_chunk_metadata::ColumnChunkMetadata;
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -103,9 +104,14 @@ impl RowGroupMetadata {
     }
 
     /// Method to convert from Thrift.
+    ///
+    /// `index` is the position of the row group in the file, used to decrypt the columns of
+    /// encrypted files.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
         rg: RowGroup,
+        index: usize,
+        mut decryptor: Option<&mut FileDecryptor>,
     ) -> ParquetResult<RowGroupMetadata> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!(
@@ -116,16 +122,13 @@ impl RowGroupMetadata {
         }
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
+        let ordinal = match rg.ordinal {
+            Some(ordinal) => ordinal,
+            None => index.try_into()?,
+        };
 
         let mut column_lookup = ColumnLookup::with_capacity(rg.columns.len());
-        let mut full_byte_range = if let Some(first_column_chunk) = rg.columns.first() {
-            let Some(metadata) = &first_column_chunk.meta_data else {
-                return Err(ParquetError::oos("Column chunk requires metadata"));
-            };
-            column_metadata_byte_range(metadata)
-        } else {
-            0..0
-        };
+        let mut full_byte_range: Option<core::ops::Range<u64>> = None;
 
         let sorting_columns = rg.sorting_columns.clone();
 
@@ -135,14 +138,23 @@ impl RowGroupMetadata {
             .zip(schema_descr.columns())
             .enumerate()
             .map(|(i, (column_chunk, descriptor))| {
-                let column =
-                    ColumnChunkMetadata::try_from_thrift(descriptor.clone(), column_chunk)?;
+                let (column_chunk, decryption) = match decryptor.as_deref_mut() {
+                    Some(decryptor) => decryptor.decrypt_column_chunk(column_chunk, ordinal, i)?,
+                    None => (column_chunk, None),
+                };
+                let column = ColumnChunkMetadata::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    decryption,
+                )?;
 
                 column_lookup.add_column(i, &column);
 
                 let byte_range = column.byte_range();
-                full_byte_range = full_byte_range.start.min(byte_range.start)
-                    ..full_byte_range.end.max(byte_range.end);
+                full_byte_range = Some(match full_byte_range.take() {
+                    Some(range) => range.start.min(byte_range.start)..range.end.max(byte_range.end),
+                    None => byte_range,
+                });
 
                 Ok(column)
             })
@@ -154,7 +166,7 @@ impl RowGroupMetadata {
             column_lookup,
             num_rows,
             total_byte_size,
-            full_byte_range,
+            full_byte_range: full_byte_range.unwrap_or(0..0),
             sorting_columns,
         })
     }
@@ -1,11 +1,14 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
-use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
+use super::super::encryption::{FileDecryptionProperties, FileDecryptor};
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -37,6 +40,24 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetada
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_metadata_with_size_and_decryption(reader, file_size, None)
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file, decrypting it if the
+/// file was written with modular encryption.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    read_metadata_with_size_and_decryption(reader, file_size, decryption)
+}
+
+fn read_metadata_with_size_and_decryption<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,7 +76,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let encrypted_footer = buffer[default_end_len - 4..] == PARQUET_ENCRYPTED_MAGIC;
+    if !encrypted_footer && buffer[default_end_len - 4..] != PARQUET_MAGIC {
         return Err(ParquetError::oos("The file must end with PAR1"));
     }
 
@@ -88,7 +110,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata_with_decryption(reader, encrypted_footer, max_size, decryption)
 }
 
 /// Parse loaded metadata bytes
@@ -98,3 +120,52 @@ pub fn deserialize_metadata<R: Read>(reader: R, max_size: usize) -> ParquetResul
 
     FileMetadata::try_from_thrift(metadata)
 }
+
+/// Parse loaded metadata bytes of a file that may be written with modular encryption.
+///
+/// `encrypted_footer` is whether the file ends with [`PARQUET_ENCRYPTED_MAGIC`], in which case
+/// `buffer` starts with the [`FileCryptoMetaData`] followed by the encrypted footer.
+pub fn deserialize_metadata_with_decryption(
+    buffer: &[u8],
+    encrypted_footer: bool,
+    max_size: usize,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let mut reader = buffer;
+
+    if encrypted_footer {
+        let decryption = decryption.ok_or_else(ParquetError::decryption_required)?;
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+        let mut decryptor = FileDecryptor::try_new(
+            decryption,
+            crypto_metadata.encryption_algorithm,
+            crypto_metadata.key_metadata.as_deref(),
+        )?;
+
+        let footer = decryptor.decrypt_footer(reader)?;
+        let mut prot = TCompactInputProtocol::new(footer.as_slice(), max_size);
+        let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+        return FileMetadata::try_from_thrift_with_decryption(metadata, Some(&mut decryptor));
+    }
+
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+    let mut metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+    // Files with a plaintext footer only need decrypting if any of their columns is encrypted.
+    match (metadata.encryption_algorithm.take(), decryption) {
+        (Some(algorithm), Some(decryption)) => {
+            let mut decryptor = FileDecryptor::try_new(
+                decryption,
+                algorithm,
+                metadata.footer_signing_key_metadata.as_deref(),
+            )?;
+            let footer = &buffer[..buffer.len() - reader.len()];
+            decryptor.verify_footer_signature(footer, reader)?;
+
+            FileMetadata::try_from_thrift_with_decryption(metadata, Some(&mut decryptor))
+        },
+        _ => FileMetadata::try_from_thrift(metadata),
+    }
+}
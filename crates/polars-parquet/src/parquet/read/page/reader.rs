@@ -1,5 +1,5 @@
 use std::io::Seek;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_utils::mmap::{MemReader, MemSlice};
@@ -7,6 +7,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnDecryptor, module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +27,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// The decryptor of the pages, if they are encrypted
+    pub decryption: Option<Arc<ColumnDecryptor>>,
 }
 
 impl PageMetaData {
@@ -41,6 +44,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            decryption: None,
         }
     }
 }
@@ -52,6 +56,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            decryption: column.decryption().cloned(),
         }
     }
 }
@@ -81,6 +86,15 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Set if the pages are encrypted.
+    decryption: Option<Arc<ColumnDecryptor>>,
+
+    // Whether no page has been read yet, only the first page can be a dictionary page.
+    is_first_page: bool,
+
+    // The ordinal of the next data page, which is part of the AAD of encrypted pages.
+    page_ordinal: usize,
 }
 
 impl PageReader {
@@ -114,6 +128,9 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryption: reader_meta.decryption,
+            is_first_page: true,
+            page_ordinal: 0,
         }
     }
 
@@ -137,7 +154,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -160,6 +177,8 @@ impl PageReader {
             ));
         }
 
+        let buffer = self.decrypt_page(page_type, buffer)?;
+
         finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
             if let CompressedPage::Dict(d) = p {
                 Some(d)
@@ -170,6 +189,52 @@ impl PageReader {
     }
 }
 
+impl PageReader {
+    fn page_ordinal(&self) -> ParquetResult<i16> {
+        self.page_ordinal.try_into().map_err(|_| {
+            ParquetError::oos("An encrypted column chunk can have at most 32768 data pages")
+        })
+    }
+
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryption) = &self.decryption else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let start = self.reader.position();
+        let length_bytes = self.reader.read_slice(4);
+        let length_bytes = length_bytes.as_ref().try_into()?;
+        let module_len = module_len(length_bytes);
+        if module_len > self.max_page_size {
+            return Err(ParquetError::WouldOverAllocate);
+        }
+        self.reader.seek(std::io::SeekFrom::Start(start as u64))?;
+        let module = self.reader.read_slice(module_len);
+
+        let header =
+            decryption.decrypt_page_header(&module, self.is_first_page, self.page_ordinal()?)?;
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    fn decrypt_page(&mut self, page_type: PageType, data: MemSlice) -> ParquetResult<CowBuffer> {
+        let Some(decryption) = &self.decryption else {
+            return Ok(CowBuffer::Borrowed(data));
+        };
+
+        let data = match page_type {
+            PageType::DictionaryPage => decryption.decrypt_page(&data, None)?,
+            PageType::DataPage | PageType::DataPageV2 => {
+                let data = decryption.decrypt_page(&data, Some(self.page_ordinal()?))?;
+                self.page_ordinal += 1;
+                data
+            },
+        };
+        self.is_first_page = false;
+        Ok(CowBuffer::Owned(data))
+    }
+}
+
 impl PageIterator for PageReader {
     fn swap_buffer(&mut self, scratch: &mut Vec<u8>) {
         std::mem::swap(&mut self.scratch, scratch)
@@ -210,7 +275,7 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
@@ -228,12 +293,14 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
         ));
     }
 
+    let buffer = reader.decrypt_page(page_header.type_.try_into()?, buffer)?;
+
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
 
 pub(super) fn finish_page(
     page_header: ParquetPageHeader,
-    data: MemSlice,
+    data: CowBuffer,
     compression: Compression,
     descriptor: &Descriptor,
 ) -> ParquetResult<CompressedPage> {
@@ -262,7 +329,7 @@ pub(super) fn finish_page(
 
             // move the buffer to `dict_page`
             let page = CompressedDictPage::new(
-                CowBuffer::Borrowed(data),
+                data,
                 compression,
                 uncompressed_page_size,
                 dict_header.num_values.try_into()?,
@@ -289,7 +356,7 @@ pub(super) fn finish_page(
 
             Ok(CompressedPage::Data(CompressedDataPage::new_read(
                 DataPageHeader::V1(header),
-                CowBuffer::Borrowed(data),
+                data,
                 compression,
                 uncompressed_page_size,
                 descriptor.clone(),
@@ -313,7 +380,7 @@ pub(super) fn finish_page(
 
             Ok(CompressedPage::Data(CompressedDataPage::new_read(
                 DataPageHeader::V2(header),
-                CowBuffer::Borrowed(data),
+                data,
                 compression,
                 uncompressed_page_size,
                 descriptor.clone(),
@@ -6,6 +6,7 @@ use polars_parquet_format::thrift::protocol::TCompactInputStreamProtocol;
 use polars_utils::mmap::MemSlice;
 
 use super::reader::{PageMetaData, finish_page};
+use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
@@ -30,6 +31,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     max_header_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + 'a> {
     let page_metadata: PageMetaData = column_metadata.into();
+    ensure_not_encrypted(&page_metadata)?;
     Ok(_get_page_stream(
         reader,
         page_metadata.num_values,
@@ -47,6 +49,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     scratch: Vec<u8>,
     max_page_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + '_> {
+    ensure_not_encrypted(&page_metadata)?;
     let column_start = page_metadata.column_start;
     reader.seek(SeekFrom::Start(column_start)).await?;
     Ok(_get_page_stream(
@@ -59,6 +62,15 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     ))
 }
 
+fn ensure_not_encrypted(page_metadata: &PageMetaData) -> ParquetResult<()> {
+    if page_metadata.decryption.is_some() {
+        return Err(ParquetError::not_supported(
+            "streaming the pages of an encrypted column chunk",
+        ));
+    }
+    Ok(())
+}
+
 fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
     total_num_values: i64,
@@ -97,7 +109,7 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
 
             yield finish_page(
                 page_header,
-                MemSlice::from_vec(std::mem::take(&mut scratch)),
+                CowBuffer::Borrowed(MemSlice::from_vec(std::mem::take(&mut scratch))),
                 compression,
                 &descriptor,
             )?;
@@ -3,7 +3,7 @@ use std::io::SeekFrom;
 use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
+use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 use super::metadata::{deserialize_metadata, metadata_len};
 use crate::parquet::HEADER_SIZE;
 use crate::parquet::error::{ParquetError, ParquetResult};
@@ -49,6 +49,9 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
         .await?;
 
     // check this is indeed a parquet file
+    if buffer[default_end_len - 4..] == PARQUET_ENCRYPTED_MAGIC {
+        return Err(ParquetError::decryption_required());
+    }
     if buffer[default_end_len - 4..] != PARQUET_MAGIC {
         return Err(ParquetError::oos("Invalid Parquet file. Corrupt footer"));
     }
@@ -24,11 +24,12 @@ impl JsonSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(
         path: &Path,
-        _options: JsonWriterOptions,
+        options: JsonWriterOptions,
         _schema: &Schema,
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<FilesSink> {
-        let writer = BatchedWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?);
+        let writer = BatchedWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
+            .with_options(options);
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
         let morsels_per_sink = morsels_per_sink();
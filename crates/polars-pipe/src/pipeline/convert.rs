@@ -138,12 +138,16 @@ where
                 SinkTypeIR::File(FileSinkType {
                     target,
                     file_type,
-                    sink_options: _,
+                    sink_options,
                     cloud_options,
                 }) => {
                     let SinkTarget::Path(path) = target else {
                         polars_bail!(InvalidOperation: "in-memory sinks are not supported for the old streaming engine");
                     };
+                    polars_ensure!(
+                        !sink_options.is_rotating(),
+                        InvalidOperation: "file rotation is not supported for the old streaming engine"
+                    );
                     let path = path.as_ref().as_path();
                     match &file_type {
                         #[cfg(feature = "parquet")]
@@ -183,6 +187,9 @@ where
                 SinkTypeIR::Partition { .. } => {
                     polars_bail!(InvalidOperation: "partitioning sink not supported in old streaming engine")
                 },
+                SinkTypeIR::Callback { .. } => {
+                    polars_bail!(InvalidOperation: "callback sink not supported in old streaming engine")
+                },
             }
         },
         Join {
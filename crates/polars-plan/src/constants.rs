@@ -6,6 +6,8 @@ pub static MAP_LIST_NAME: &str = "map_list";
 pub static CSE_REPLACED: &str = "__POLARS_CSER_";
 pub static POLARS_TMP_PREFIX: &str = "_POLARS_";
 pub static POLARS_PLACEHOLDER: &str = "_POLARS_<>";
+/// Prefix of the columns that hold the values of an external frame, see `external`.
+pub static POLARS_EXTERNAL_PREFIX: &str = "__POLARS_EXTERNAL_";
 pub const LEN: &str = "len";
 const LITERAL_NAME: &str = "literal";
 pub const UNLIMITED_CACHE: u32 = u32::MAX;
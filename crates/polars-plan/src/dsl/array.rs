@@ -145,7 +145,12 @@ impl ArrayNameSpace {
     }
 
     #[cfg(feature = "array_to_struct")]
+    /// Convert the sub-arrays to structs with a field per element.
+    ///
+    /// The fields are named by `name_generator`, e.g. `arr_template_struct_name_gen("field_{}")`
+    /// or `arr_names_struct_name_gen`, and default to `field_{idx}`.
     pub fn to_struct(self, name_generator: Option<ArrToStructNameGenerator>) -> PolarsResult<Expr> {
+        let output_name_generator = name_generator.clone();
         Ok(self
             .0
             .map(
@@ -158,10 +163,13 @@ impl ArrayNameSpace {
                     let DataType::Array(inner, width) = dt else {
                         polars_bail!(InvalidOperation: "expected Array type, got: {}", dt)
                     };
+                    let name_generator = output_name_generator
+                        .as_deref()
+                        .unwrap_or(&arr_default_struct_name_gen);
 
                     let fields = (0..*width)
                         .map(|i| {
-                            let name = arr_default_struct_name_gen(i);
+                            let name = name_generator(i);
                             Field::new(name, inner.as_ref().clone())
                         })
                         .collect();
@@ -171,6 +179,36 @@ impl ArrayNameSpace {
             .with_fmt("arr.to_struct"))
     }
 
+    /// Slice every sub-array.
+    ///
+    /// If `offset` and `length` are integer literals (or `length` is a null literal) the output
+    /// is an array of the sliced width. Otherwise the bounds may differ per row and the output is
+    /// a list.
+    pub fn slice(self, offset: Expr, length: Expr) -> Expr {
+        let const_offset = match &offset {
+            Expr::Literal(lv) => lv.to_any_value().and_then(|av| av.extract::<i64>()),
+            _ => None,
+        };
+        let const_length = match &length {
+            Expr::Literal(lv) => match lv.to_any_value() {
+                Some(AnyValue::Null) => Some(None),
+                Some(av) => av.extract::<u64>().map(Some),
+                None => None,
+            },
+            _ => None,
+        };
+        match (const_offset, const_length) {
+            (Some(offset), Some(length)) => {
+                self.0
+                    .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Slice {
+                        offset,
+                        length,
+                    }))
+            },
+            _ => self.to_list().list().slice(offset, length),
+        }
+    }
+
     /// Shift every sub-array.
     pub fn shift(self, n: Expr) -> Expr {
         self.0
@@ -61,4 +61,30 @@ impl BinaryNameSpace {
                 is_little_endian,
             )))
     }
+
+    /// Slice the binary values by byte `offset` and `length`.
+    ///
+    /// A negative `offset` counts from the end of the value and a null `length` slices until the
+    /// end of the value.
+    pub fn slice(self, offset: Expr, length: Expr) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::BinaryExpr(BinaryFunction::Slice),
+            offset,
+            length,
+        )
+    }
+
+    /// Reinterpret the bytes of the binary values as the fixed-width numeric `dtype`.
+    ///
+    /// Values whose size doesn't match the width of `dtype` become null, or raise an error if
+    /// `strict` is set.
+    #[cfg(feature = "binary_encoding")]
+    pub fn reinterpret(self, dtype: DataType, is_little_endian: bool, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Reinterpret {
+                dtype,
+                is_little_endian,
+                strict,
+            }))
+    }
 }
@@ -84,6 +84,7 @@ impl DslBuilder {
             scan_type: Box::new(FileScan::Ipc {
                 options,
                 metadata: None,
+                fingerprints: None,
             }),
             cached_ir: Default::default(),
         }
@@ -95,6 +95,9 @@ pub enum Expr {
         expr: Arc<Expr>,
         dtype: DataType,
         options: CastOptions,
+        /// Drop the user metadata of the input instead of keeping it.
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(default))]
+        drop_metadata: bool,
     },
     Sort {
         expr: Arc<Expr>,
@@ -273,10 +276,12 @@ impl Hash for Expr {
                 expr,
                 dtype,
                 options: strict,
+                drop_metadata,
             } => {
                 expr.hash(state);
                 dtype.hash(state);
-                strict.hash(state)
+                strict.hash(state);
+                drop_metadata.hash(state)
             },
             Expr::Sort { expr, options } => {
                 expr.hash(state);
@@ -0,0 +1,131 @@
+//! Registration of Rust-native expression plugins.
+//!
+//! A plugin is registered once under a name with [`register_expr_plugin`] and can then be
+//! called from the lazy API with [`Expr::plugin_call`]. The plugin is looked up by name when
+//! the query is resolved, so it can be registered after the expression is built.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+
+use super::{Expr, GetOutput};
+use crate::prelude::{FunctionFlags, FunctionOptions, new_column_udf};
+
+/// Resolves the output field of a plugin from the fields of its inputs.
+pub type PluginSchemaFn = Arc<dyn Fn(&[Field]) -> PolarsResult<Field> + Send + Sync>;
+
+/// Computes the output of a plugin from its inputs.
+pub type PluginKernelFn = Arc<dyn Fn(&[Series]) -> PolarsResult<Series> + Send + Sync>;
+
+/// The functions that implement an expression plugin.
+#[derive(Clone)]
+pub struct ExprPluginVTable {
+    pub schema: PluginSchemaFn,
+    pub kernel: PluginKernelFn,
+}
+
+impl ExprPluginVTable {
+    pub fn new<S, K>(schema: S, kernel: K) -> Self
+    where
+        S: Fn(&[Field]) -> PolarsResult<Field> + Send + Sync + 'static,
+        K: Fn(&[Series]) -> PolarsResult<Series> + Send + Sync + 'static,
+    {
+        Self {
+            schema: Arc::new(schema),
+            kernel: Arc::new(kernel),
+        }
+    }
+}
+
+/// How a plugin call behaves, which the optimizer and the engines rely on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PluginCallOptions {
+    /// The kernel computes every output row from the input row at the same position, so it can
+    /// be applied on any slice of the input.
+    pub elementwise: bool,
+    /// The kernel reduces its input to a single value. This can't be combined with
+    /// `elementwise`.
+    pub returns_scalar: bool,
+}
+
+impl PluginCallOptions {
+    fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            !(self.elementwise && self.returns_scalar),
+            InvalidOperation: "a plugin can't be both elementwise and return a scalar"
+        );
+        Ok(())
+    }
+
+    fn function_options(&self, fmt_str: &'static str) -> FunctionOptions {
+        let options = if self.elementwise {
+            FunctionOptions::elementwise()
+        } else if self.returns_scalar {
+            FunctionOptions::aggregation()
+        } else {
+            FunctionOptions::groupwise()
+        };
+        options
+            .with_flags(|f| f | FunctionFlags::ALLOW_EMPTY_INPUTS)
+            .with_fmt_str(fmt_str)
+    }
+}
+
+/// The registered plugins. Names are leaked once so they can be used to format the expression.
+static EXPR_PLUGINS: LazyLock<RwLock<PlHashMap<&'static str, ExprPluginVTable>>> =
+    LazyLock::new(Default::default);
+
+/// Register an expression plugin under `name`, replacing any plugin with the same name.
+pub fn register_expr_plugin(name: &str, vtable: ExprPluginVTable) {
+    let mut plugins = EXPR_PLUGINS.write().unwrap();
+    match plugins.get_mut(name) {
+        Some(existing) => *existing = vtable,
+        None => {
+            let name: &'static str = Box::leak(name.into());
+            plugins.insert(name, vtable);
+        },
+    }
+}
+
+fn get_expr_plugin(name: &str) -> PolarsResult<ExprPluginVTable> {
+    EXPR_PLUGINS.read().unwrap().get(name).cloned().ok_or_else(
+        || polars_err!(InvalidOperation: "expression plugin '{}' is not registered", name),
+    )
+}
+
+impl Expr {
+    /// Call the expression plugin registered under `name` with `args` as inputs.
+    ///
+    /// Fails if the `options` conflict. See [`register_expr_plugin`].
+    pub fn plugin_call(
+        name: &str,
+        args: Vec<Expr>,
+        options: PluginCallOptions,
+    ) -> PolarsResult<Expr> {
+        options.validate()?;
+        let fmt_str = EXPR_PLUGINS
+            .read()
+            .unwrap()
+            .get_key_value(name)
+            .map_or("plugin_call", |(name, _)| *name);
+        let name: PlSmallStr = name.into();
+
+        let kernel_name = name.clone();
+        let function = move |columns: &mut [Column]| {
+            let plugin = get_expr_plugin(&kernel_name)?;
+            let inputs = columns
+                .iter()
+                .map(|c| c.as_materialized_series().clone())
+                .collect::<Vec<_>>();
+            (plugin.kernel)(&inputs).map(|s| Some(s.into_column()))
+        };
+        let output_type =
+            GetOutput::map_fields(move |fields| (get_expr_plugin(&name)?.schema)(fields));
+
+        Ok(Expr::AnonymousFunction {
+            input: args,
+            function: new_column_udf(function),
+            output_type,
+            options: options.function_options(fmt_str),
+        })
+    }
+}
@@ -1,12 +1,13 @@
 use std::hash::Hash;
 use std::sync::Mutex;
 
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
-use polars_io::ipc::IpcScanOptions;
+use polars_io::ipc::{IpcFileFingerprint, IpcScanOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -54,6 +55,10 @@ pub enum FileScan {
         options: IpcScanOptions,
         #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
+        /// Fingerprints of the sources, recorded during IR conversion. `None` for sources that
+        /// are not local files.
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        fingerprints: Option<Arc<[Option<IpcFileFingerprint>]>>,
     },
 
     #[cfg(feature = "python")]
@@ -133,6 +138,10 @@ pub enum MissingColumnsPolicy {
 pub struct CastColumnsPolicy {
     /// Allow casting when target dtype is lossless supertype
     pub integer_upcast: bool,
+    /// Allow casting to any integer dtype, handling out-of-range values according to these
+    /// options. `None` only allows the lossless upcasts enabled by `integer_upcast`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub integer_downcast: Option<CastOptions>,
 
     /// Allow Float32 -> Float64
     pub float_upcast: bool,
@@ -156,6 +165,7 @@ impl CastColumnsPolicy {
     /// Configuration variant that defaults to raising on mismatch.
     pub const ERROR_ON_MISMATCH: Self = Self {
         integer_upcast: false,
+        integer_downcast: None,
         float_upcast: false,
         float_downcast: false,
         datetime_nanoseconds_downcast: false,
@@ -199,12 +209,116 @@ pub struct UnifiedScanArgs {
 
     pub projection: Option<Arc<[PlSmallStr]>>,
     pub row_index: Option<RowIndex>,
+    /// If set, the `row_index` column is a `UInt64` starting at this offset instead, so that it
+    /// can count past `IdxSize::MAX`. See `LazyFrame::with_row_index_u64`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub row_index_u64_offset: Option<u64>,
     /// Slice applied before predicates
     pub pre_slice: Option<Slice>,
 
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub include_file_paths: Option<PlSmallStr>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub file_metadata_columns: FileMetadataColumns,
+}
+
+impl UnifiedScanArgs {
+    /// Data type of the `row_index` column.
+    pub fn row_index_dtype(&self) -> DataType {
+        if self.row_index_u64_offset.is_some() {
+            DataType::UInt64
+        } else {
+            IDX_DTYPE
+        }
+    }
+
+    /// Offset of the `row_index` column.
+    #[allow(clippy::useless_conversion)]
+    pub fn row_index_offset(&self) -> u64 {
+        self.row_index_u64_offset
+            .or_else(|| self.row_index.as_ref().map(|ri| u64::from(ri.offset)))
+            .unwrap_or(0)
+    }
+}
+
+/// Metadata of the source file to attach to every row as constant columns.
+///
+/// Every field holds the name of the output column, or `None` if it should not be included.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileMetadataColumns {
+    /// Path of the file, as a `Categorical`.
+    pub path: Option<PlSmallStr>,
+    /// Size of the file in bytes, as `UInt64`.
+    pub size: Option<PlSmallStr>,
+    /// Last modification time of the file, as a UTC `Datetime` in milliseconds.
+    pub mtime: Option<PlSmallStr>,
+    /// Index of the Parquet row group the row was read from. Null for other file types.
+    pub row_group: Option<PlSmallStr>,
+}
+
+impl FileMetadataColumns {
+    pub fn is_empty(&self) -> bool {
+        self.iter_names().next().is_none()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter_names().any(|x| x == name)
+    }
+
+    pub fn iter_names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        [&self.path, &self.size, &self.mtime, &self.row_group]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Names and data types of the selected columns, in output order.
+    pub fn iter_fields(&self) -> impl Iterator<Item = (&PlSmallStr, DataType)> {
+        [
+            (&self.path, Self::path_dtype()),
+            (&self.size, DataType::UInt64),
+            (&self.mtime, Self::mtime_dtype()),
+            (&self.row_group, IDX_DTYPE),
+        ]
+        .into_iter()
+        .filter_map(|(name, dtype)| Some((name.as_ref()?, dtype)))
+    }
+
+    /// Deselect the columns for which `f` returns `false`.
+    pub fn retain(&mut self, mut f: impl FnMut(&PlSmallStr) -> bool) {
+        for name in [
+            &mut self.path,
+            &mut self.size,
+            &mut self.mtime,
+            &mut self.row_group,
+        ] {
+            name.take_if(|x| !f(x));
+        }
+    }
+
+    pub fn path_dtype() -> DataType {
+        #[cfg(feature = "dtype-categorical")]
+        {
+            DataType::Categorical(None, Default::default())
+        }
+        #[cfg(not(feature = "dtype-categorical"))]
+        {
+            DataType::String
+        }
+    }
+
+    pub fn mtime_dtype() -> DataType {
+        #[cfg(feature = "dtype-datetime")]
+        {
+            DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        }
+        #[cfg(not(feature = "dtype-datetime"))]
+        {
+            DataType::Int64
+        }
+    }
 }
 
 /// Manual impls of Eq/Hash, as some fields are `Arc<T>` where T does not have Eq/Hash. For these
@@ -288,7 +402,13 @@ mod _file_scan_eq_hash {
                 },
 
                 #[cfg(feature = "ipc")]
-                FileScan::Ipc { options, metadata } => FileScanEqHashWrap::Ipc {
+                // The fingerprints are derived from the sources and are recomputed on every
+                // conversion, so they would prevent equal scans from being recognized.
+                FileScan::Ipc {
+                    options,
+                    metadata,
+                    fingerprints: _,
+                } => FileScanEqHashWrap::Ipc {
                     options,
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
@@ -475,6 +595,10 @@ impl CastColumnsPolicy {
         //
 
         if target_dtype.is_integer() && incoming_dtype.is_integer() {
+            if self.integer_downcast.is_some() {
+                return Ok(true);
+            }
+
             if !self.integer_upcast {
                 return mismatch_err(
                     "hint: pass cast_options=pl.ScanCastOptions(integer_cast='upcast')",
@@ -126,6 +126,7 @@ impl fmt::Debug for Expr {
                 expr,
                 dtype,
                 options,
+                drop_metadata: _,
             } => {
                 if options.is_strict() {
                     write!(f, "{expr:?}.strict_cast({dtype:?})")
@@ -3,3 +3,17 @@ use super::*;
 pub(super) fn abs(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::abs(s.as_materialized_series()).map(Column::from)
 }
+
+fn into_scalar_column(s: &Column, value: AnyValue<'static>) -> Column {
+    Scalar::new(s.dtype().clone(), value).into_column(s.name().clone())
+}
+
+pub(super) fn max_abs(s: &Column) -> PolarsResult<Column> {
+    let value = polars_ops::prelude::max_abs(s.as_materialized_series())?;
+    Ok(into_scalar_column(s, value))
+}
+
+pub(super) fn min_abs(s: &Column) -> PolarsResult<Column> {
+    let value = polars_ops::prelude::min_abs(s.as_materialized_series())?;
+    Ok(into_scalar_column(s, value))
+}
@@ -1,3 +1,4 @@
+use polars_core::utils::slice_offsets;
 use polars_ops::chunked_array::array::*;
 
 use super::*;
@@ -34,6 +35,10 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    Slice {
+        offset: i64,
+        length: Option<u64>,
+    },
     Explode {
         skip_empty: bool,
     },
@@ -74,6 +79,14 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            Slice { offset, length } => mapper.try_map_dtype(|dt| {
+                let DataType::Array(inner, width) = dt else {
+                    polars_bail!(InvalidOperation: "expected Array type, got: {}", dt)
+                };
+                let length = length.map_or(*width, |length| length as usize);
+                let (_, new_width) = slice_offsets(*offset, length, *width);
+                Ok(DataType::Array(inner.clone(), new_width))
+            }),
             Explode { .. } => mapper.try_map_to_array_inner_dtype(),
         }
     }
@@ -104,7 +117,8 @@ impl ArrayFunction {
             | A::Concat
             | A::Get(_)
             | A::Join(_)
-            | A::Shift => FunctionOptions::elementwise(),
+            | A::Shift
+            | A::Slice { .. } => FunctionOptions::elementwise(),
             A::Explode { .. } => FunctionOptions::row_separable(),
         }
     }
@@ -148,6 +162,7 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            Slice { .. } => "slice",
             Explode { .. } => "explode",
         };
         write!(f, "arr.{name}")
@@ -184,6 +199,7 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            Slice { offset, length } => map!(slice, offset, length),
             Explode { skip_empty } => map_as_slice!(explode, skip_empty),
         }
     }
@@ -330,6 +346,11 @@ pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     ca.array_shift(n.as_materialized_series()).map(Column::from)
 }
 
+pub(super) fn slice(s: &Column, offset: i64, length: Option<u64>) -> PolarsResult<Column> {
+    let length = length.map(|length| length as usize);
+    Ok(s.array()?.array_slice(offset, length)?.into_column())
+}
+
 fn explode(c: &[Column], skip_empty: bool) -> PolarsResult<Column> {
     c[0].explode(skip_empty)
 }
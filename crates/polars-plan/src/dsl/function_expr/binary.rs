@@ -22,6 +22,13 @@ pub enum BinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataType, bool),
+    Slice,
+    #[cfg(feature = "binary_encoding")]
+    Reinterpret {
+        dtype: DataType,
+        is_little_endian: bool,
+        strict: bool,
+    },
 }
 
 impl BinaryFunction {
@@ -37,6 +44,9 @@ impl BinaryFunction {
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, _) => mapper.with_dtype(dtype.clone()),
+            Slice => mapper.with_same_dtype(),
+            #[cfg(feature = "binary_encoding")]
+            Reinterpret { dtype, .. } => mapper.with_dtype(dtype.clone()),
         }
     }
 
@@ -46,13 +56,14 @@ impl BinaryFunction {
             B::Contains | B::StartsWith | B::EndsWith => {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
             },
-            B::Size => FunctionOptions::elementwise(),
+            B::Size | B::Slice => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
             B::HexDecode(_)
             | B::HexEncode
             | B::Base64Decode(_)
             | B::Base64Encode
-            | B::FromBuffer(_, _) => FunctionOptions::elementwise(),
+            | B::FromBuffer(_, _)
+            | B::Reinterpret { .. } => FunctionOptions::elementwise(),
         }
     }
 }
@@ -75,6 +86,9 @@ impl Display for BinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            Slice => "slice",
+            #[cfg(feature = "binary_encoding")]
+            Reinterpret { .. } => "reinterpret",
         };
         write!(f, "bin.{s}")
     }
@@ -104,6 +118,13 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, is_little_endian) => map!(from_buffer, &dtype, is_little_endian),
+            Slice => map_as_slice!(slice),
+            #[cfg(feature = "binary_encoding")]
+            Reinterpret {
+                dtype,
+                is_little_endian,
+                strict,
+            } => map!(reinterpret, &dtype, is_little_endian, strict),
         }
     }
 }
@@ -177,6 +198,23 @@ pub(super) fn from_buffer(
         .map(|val| val.into())
 }
 
+pub(super) fn slice(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    Ok(ca.bin_slice(&s[1], &s[2])?.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn reinterpret(
+    s: &Column,
+    dtype: &DataType,
+    is_little_endian: bool,
+    strict: bool,
+) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.reinterpret(dtype, is_little_endian, strict)
+        .map(|val| val.into())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
@@ -19,3 +19,7 @@ pub(super) fn clip(s: &[Column], has_min: bool, has_max: bool) -> PolarsResult<C
     }
     .map(Column::from)
 }
+
+pub(super) fn winsorize(s: &Column, lower: f64, upper: f64) -> PolarsResult<Column> {
+    polars_ops::series::winsorize(s.as_materialized_series(), lower, upper).map(Column::from)
+}
@@ -25,6 +25,19 @@ pub(super) fn cum_max(s: &Column, reverse: bool) -> PolarsResult<Column> {
     polars_ops::prelude::cum_max(s.as_materialized_series(), reverse).map(Column::from)
 }
 
+pub(super) fn cum_sum_reset(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::cum_sum_reset(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+pub(super) fn cum_trapz(s: &[Column]) -> PolarsResult<Column> {
+    use polars_ops::series::SeriesCumTrapz;
+    let x = s.get(1).map(|x| x.as_materialized_series());
+    s[0].as_materialized_series()
+        .cumulative_trapz(x)
+        .map(Column::from)
+}
+
 pub(super) mod dtypes {
     use DataType::*;
     use polars_core::utils::materialize_dyn_int;
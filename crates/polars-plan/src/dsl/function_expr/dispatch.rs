@@ -6,6 +6,45 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(s.reverse())
 }
 
+/// Replaces the values of the column where the mask is `true` by the values, in order.
+pub(super) fn scatter_by_mask(s: &mut [Column]) -> PolarsResult<Column> {
+    let [input, mask, values] = s else {
+        unreachable!()
+    };
+    polars_ensure!(
+        mask.len() == input.len(),
+        ShapeMismatch: "'scatter_by_mask' expected a mask of length {}, got {}",
+        input.len(), mask.len()
+    );
+    let mask = mask.bool()?.fill_null_with_values(false)?;
+    let values = values.strict_cast(input.dtype())?;
+
+    let expanded = if values.len() == 1 {
+        values.new_from_index(0, input.len())
+    } else {
+        let n_trues = mask.num_trues();
+        polars_ensure!(
+            values.len() == n_trues,
+            ShapeMismatch: "'scatter_by_mask' expected 1 or {} values, one per true value in the mask, got {}",
+            n_trues, values.len()
+        );
+        let mut next: IdxSize = 0;
+        let idx: IdxCa = mask
+            .into_no_null_iter()
+            .map(|m| {
+                m.then(|| {
+                    next += 1;
+                    next - 1
+                })
+            })
+            .collect();
+        values.take(&idx)?
+    };
+    Ok(expanded
+        .zip_with(&mask, input)?
+        .with_name(input.name().clone()))
+}
+
 #[cfg(feature = "approx_unique")]
 pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
     s.approx_n_unique()
@@ -46,6 +85,23 @@ pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
     polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
 }
 
+#[cfg(feature = "is_close")]
+pub(super) fn is_close(
+    s: &[Column],
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<Column> {
+    polars_ops::prelude::is_close(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        rel_tol,
+        abs_tol,
+        nans_equal,
+    )
+    .map(|ca| ca.into_column())
+}
+
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
     Ok(s.to_physical_repr())
 }
@@ -86,6 +142,12 @@ pub(super) fn unique_counts(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::unique_counts(s.as_materialized_series()).map(Column::from)
 }
 
+#[cfg(feature = "unique_counts")]
+pub(super) fn unique_with_counts(s: &Column) -> PolarsResult<Column> {
+    let out = polars_ops::prelude::unique_with_counts(s.as_materialized_series())?;
+    Ok(out.implode()?.into_column())
+}
+
 #[cfg(feature = "dtype-array")]
 pub(super) fn reshape(c: &Column, dimensions: &[ReshapeDimension]) -> PolarsResult<Column> {
     c.reshape_array(dimensions)
@@ -154,6 +216,81 @@ pub(super) fn kurtosis(s: &Column, fisher: bool, bias: bool) -> PolarsResult<Col
         .map(|opt_v| Column::new(s.name().clone(), &[opt_v]))
 }
 
+#[cfg(feature = "cov")]
+pub(super) fn autocorr(s: &Column, lag: usize) -> PolarsResult<Column> {
+    use polars_ops::series::SeriesAutocorr;
+    // @scalar-opt
+    s.as_materialized_series()
+        .autocorr(lag)
+        .map(|v| Column::new(s.name().clone(), &[v]))
+}
+
+#[cfg(feature = "finance")]
+pub(super) fn garman_klass_volatility(s: &[Column], window: usize) -> PolarsResult<Column> {
+    polars_ops::series::garman_klass_volatility(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+        s[3].as_materialized_series(),
+        window,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "signal")]
+pub(super) fn savgol_filter(
+    s: &Column,
+    window_size: usize,
+    poly_order: usize,
+) -> PolarsResult<Column> {
+    use polars_ops::series::SeriesSignal;
+    s.as_materialized_series()
+        .savgol_filter(window_size, poly_order)
+        .map(Column::from)
+}
+
+pub(super) fn arg_sort_by(
+    s: &[Column],
+    mut descending: Vec<bool>,
+    mut nulls_last: Vec<bool>,
+    limit: Option<IdxSize>,
+    maintain_order: bool,
+) -> PolarsResult<Column> {
+    use polars_core::POOL;
+    use polars_core::chunked_array::ops::sort::_broadcast_bools;
+    use polars_core::chunked_array::ops::sort::arg_bottom_k::_arg_bottom_k;
+
+    let name = s[0].name().clone();
+    let len = s[0].len();
+    for c in &s[1..] {
+        polars_ensure!(
+            c.len() == len,
+            ShapeMismatch: "`arg_sort_by` expected all keys to have length {}, got {} for '{}'",
+            len, c.len(), c.name()
+        );
+    }
+    _broadcast_bools(s.len(), &mut descending);
+    _broadcast_bools(s.len(), &mut nulls_last);
+    polars_ensure!(
+        descending.len() == s.len() && nulls_last.len() == s.len(),
+        InvalidOperation: "`descending` and `nulls_last` must have a single value or one per key"
+    );
+
+    let k = limit.map_or(len, |limit| len.min(limit as usize));
+    if k == 0 {
+        return Ok(IdxCa::from_vec(name, vec![]).into_column());
+    }
+    let mut sort_options = SortMultipleOptions {
+        descending,
+        nulls_last,
+        multithreaded: len >= 10000 && POOL.current_num_threads() > 1,
+        maintain_order,
+        limit: None,
+    };
+    let idx = _arg_bottom_k(k, s, &mut sort_options)?.into_inner();
+    Ok(idx.with_name(name).into_column())
+}
+
 pub(super) fn arg_unique(s: &Column) -> PolarsResult<Column> {
     // @scalar-opt
     s.as_materialized_series()
@@ -166,6 +303,17 @@ pub(super) fn rank(s: &Column, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.as_materialized_series().rank(options, seed).into_column())
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn rank_by(
+    s: &[Column],
+    options: RankOptions,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    s[0].as_materialized_series()
+        .rank_by(&s[1..], options, seed)
+        .map(Column::from)
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Column],
@@ -51,7 +51,7 @@ pub enum ListFunction {
     Unique(bool),
     NUnique,
     #[cfg(feature = "list_sets")]
-    SetOperation(SetOperation),
+    SetOperation(SetOperation, ListSetOptions),
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
@@ -118,7 +118,12 @@ impl ListFunction {
             Unique(_) => mapper.with_same_dtype(),
             Length => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
-            SetOperation(_) => mapper.with_same_dtype(),
+            SetOperation(..) => mapper.map_dtype(|dtype| match dtype {
+                // The width of the result varies per row.
+                #[cfg(feature = "dtype-array")]
+                DataType::Array(inner, _) => DataType::List(inner.clone()),
+                dtype => dtype.clone(),
+            }),
             #[cfg(feature = "list_any_all")]
             Any => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_any_all")]
@@ -145,7 +150,7 @@ impl ListFunction {
             #[cfg(feature = "list_gather")]
             L::GatherEvery => FunctionOptions::elementwise(),
             #[cfg(feature = "list_sets")]
-            L::SetOperation(_) => FunctionOptions::elementwise()
+            L::SetOperation(..) => FunctionOptions::elementwise()
                 .with_casting_rules(CastingRules::Supertype(SuperTypeOptions {
                     flags: SuperTypeFlags::default() | SuperTypeFlags::ALLOW_IMPLODE_LIST,
                 }))
@@ -245,7 +250,7 @@ impl Display for ListFunction {
             },
             NUnique => "n_unique",
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => return write!(f, "list.{s}"),
+            SetOperation(s, _) => return write!(f, "list.{s}"),
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
@@ -307,7 +312,7 @@ impl From<ListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Reverse => map!(reverse),
             Unique(is_stable) => map!(unique, is_stable),
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => map_as_slice!(set_operation, s),
+            SetOperation(s, options) => map_as_slice!(set_operation, s, options),
             #[cfg(feature = "list_any_all")]
             Any => map!(lst_any),
             #[cfg(feature = "list_any_all")]
@@ -728,31 +733,46 @@ pub(super) fn unique(s: &Column, is_stable: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "list_sets")]
-pub(super) fn set_operation(s: &[Column], set_type: SetOperation) -> PolarsResult<Column> {
+pub(super) fn set_operation(
+    s: &[Column],
+    set_type: SetOperation,
+    options: ListSetOptions,
+) -> PolarsResult<Column> {
     let s0 = &s[0];
     let s1 = &s[1];
 
     if s0.is_empty() || s1.is_empty() {
-        return match set_type {
+        let out = match set_type {
             SetOperation::Intersection => {
                 if s0.is_empty() {
-                    Ok(s0.clone())
+                    s0.clone()
                 } else {
-                    Ok(s1.clone().with_name(s0.name().clone()))
+                    s1.clone().with_name(s0.name().clone())
                 }
             },
-            SetOperation::Difference => Ok(s0.clone()),
+            SetOperation::Difference => s0.clone(),
             SetOperation::Union | SetOperation::SymmetricDifference => {
                 if s0.is_empty() {
-                    Ok(s1.clone().with_name(s0.name().clone()))
+                    s1.clone().with_name(s0.name().clone())
                 } else {
-                    Ok(s0.clone())
+                    s0.clone()
                 }
             },
         };
+        return to_list(&out);
     }
 
-    list_set_operation(s0.list()?, s1.list()?, set_type).map(|ca| ca.into_column())
+    // Arrays are handled as lists, the output is a list as the widths vary.
+    let (s0, s1) = (to_list(s0)?, to_list(s1)?);
+    list_set_operation(s0.list()?, s1.list()?, set_type, options).map(|ca| ca.into_column())
+}
+
+fn to_list(c: &Column) -> PolarsResult<Column> {
+    match c.dtype() {
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => c.cast(&DataType::List(inner.clone())),
+        _ => Ok(c.clone()),
+    }
 }
 
 #[cfg(feature = "list_any_all")]
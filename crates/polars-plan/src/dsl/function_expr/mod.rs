@@ -61,6 +61,8 @@ mod row_hash;
 pub(super) mod schema;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "session_id")]
+mod session_id;
 mod shift_and_fill;
 mod shrink_type;
 #[cfg(feature = "sign")]
@@ -69,6 +71,8 @@ mod sign;
 mod strings;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
+#[cfg(feature = "approx_quantile")]
+mod tdigest;
 #[cfg(feature = "temporal")]
 mod temporal;
 #[cfg(feature = "trigonometry")]
@@ -114,6 +118,10 @@ pub(super) use self::rolling_by::RollingFunctionBy;
 pub use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
 pub use self::struct_::StructFunction;
+#[cfg(feature = "dtype-struct")]
+pub(crate) use self::struct_::{map_field_names, map_field_names_dtype};
+#[cfg(feature = "approx_quantile")]
+pub use self::tdigest::{TDigestFunction, update_tdigest};
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -137,6 +145,8 @@ pub enum FunctionExpr {
     TemporalExpr(TemporalFunction),
     #[cfg(feature = "bitwise")]
     Bitwise(BitwiseFunction),
+    #[cfg(feature = "approx_quantile")]
+    TDigest(TDigestFunction),
 
     // Other expressions
     Boolean(BooleanFunction),
@@ -144,6 +154,10 @@ pub enum FunctionExpr {
     Business(BusinessFunction),
     #[cfg(feature = "abs")]
     Abs,
+    #[cfg(feature = "abs")]
+    MaxAbs,
+    #[cfg(feature = "abs")]
+    MinAbs,
     Negate,
     #[cfg(feature = "hist")]
     Hist {
@@ -188,6 +202,19 @@ pub enum FunctionExpr {
     Skew(bool),
     #[cfg(feature = "moment")]
     Kurtosis(bool, bool),
+    #[cfg(feature = "cov")]
+    Autocorr {
+        lag: usize,
+    },
+    #[cfg(feature = "finance")]
+    GarmanKlassVolatility {
+        window: usize,
+    },
+    #[cfg(feature = "signal")]
+    SavgolFilter {
+        window_size: usize,
+        poly_order: usize,
+    },
     #[cfg(feature = "dtype-array")]
     Reshape(Vec<ReshapeDimension>),
     #[cfg(feature = "repeat_by")]
@@ -198,12 +225,28 @@ pub enum FunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    RankBy {
+        options: RankOptions,
+        seed: Option<u64>,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
         has_max: bool,
     },
+    #[cfg(feature = "round_series")]
+    Winsorize {
+        lower: f64,
+        upper: f64,
+    },
+    #[cfg(feature = "is_close")]
+    IsClose {
+        abs_tol: f64,
+        rel_tol: f64,
+        nans_equal: bool,
+    },
     #[cfg(feature = "dtype-struct")]
     AsStruct,
     #[cfg(feature = "top_k")]
@@ -214,6 +257,12 @@ pub enum FunctionExpr {
     TopKBy {
         descending: Vec<bool>,
     },
+    ArgSortBy {
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+        limit: Option<IdxSize>,
+        maintain_order: bool,
+    },
     #[cfg(feature = "cum_agg")]
     CumCount {
         reverse: bool,
@@ -234,7 +283,17 @@ pub enum FunctionExpr {
     CumMax {
         reverse: bool,
     },
+    #[cfg(feature = "cum_agg")]
+    CumSumReset,
+    #[cfg(feature = "cum_agg")]
+    CumTrapz,
+    #[cfg(feature = "session_id")]
+    SessionId {
+        gap: Duration,
+        sort: bool,
+    },
     Reverse,
+    ScatterByMask,
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
         sort: bool,
@@ -244,6 +303,8 @@ pub enum FunctionExpr {
     },
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
+    #[cfg(feature = "unique_counts")]
+    UniqueWithCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
     Coalesce,
@@ -312,9 +373,13 @@ pub enum FunctionExpr {
         include_breaks: bool,
     },
     #[cfg(feature = "rle")]
-    RLE,
+    RLE {
+        lengths_dtype: DataType,
+    },
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "rle")]
+    RLEDecode,
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
@@ -393,6 +458,8 @@ impl Hash for FunctionExpr {
             TemporalExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
             Bitwise(f) => f.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            TDigest(f) => f.hash(state),
 
             // Other expressions
             Boolean(f) => f.hash(state),
@@ -440,13 +507,14 @@ impl Hash for FunctionExpr {
             | DropNans
             | DropNulls
             | Reverse
+            | ScatterByMask
             | ArgUnique
             | Shift
             | ShiftAndFill => {},
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
-            Abs => {},
+            Abs | MaxAbs | MinAbs => {},
             Negate => {},
             NullCount => {},
             #[cfg(feature = "arg_where")]
@@ -470,6 +538,18 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "moment")]
             Skew(a) => a.hash(state),
+            #[cfg(feature = "cov")]
+            Autocorr { lag } => lag.hash(state),
+            #[cfg(feature = "finance")]
+            GarmanKlassVolatility { window } => window.hash(state),
+            #[cfg(feature = "signal")]
+            SavgolFilter {
+                window_size,
+                poly_order,
+            } => {
+                window_size.hash(state);
+                poly_order.hash(state);
+            },
             #[cfg(feature = "moment")]
             Kurtosis(a, b) => {
                 a.hash(state);
@@ -477,7 +557,7 @@ impl Hash for FunctionExpr {
             },
             Repeat => {},
             #[cfg(feature = "rank")]
-            Rank { options, seed } => {
+            Rank { options, seed } | RankBy { options, seed } => {
                 options.hash(state);
                 seed.hash(state);
             },
@@ -486,6 +566,21 @@ impl Hash for FunctionExpr {
                 has_min.hash(state);
                 has_max.hash(state);
             },
+            #[cfg(feature = "round_series")]
+            Winsorize { lower, upper } => {
+                lower.to_bits().hash(state);
+                upper.to_bits().hash(state);
+            },
+            #[cfg(feature = "is_close")]
+            IsClose {
+                abs_tol,
+                rel_tol,
+                nans_equal,
+            } => {
+                abs_tol.to_bits().hash(state);
+                rel_tol.to_bits().hash(state);
+                nans_equal.hash(state);
+            },
             #[cfg(feature = "top_k")]
             TopK { descending } => descending.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -498,6 +593,13 @@ impl Hash for FunctionExpr {
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
+            CumSumReset | CumTrapz => {},
+            #[cfg(feature = "session_id")]
+            SessionId { gap, sort } => {
+                gap.hash(state);
+                sort.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -512,6 +614,8 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
+            #[cfg(feature = "unique_counts")]
+            UniqueWithCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
             Coalesce => {},
@@ -581,9 +685,11 @@ impl Hash for FunctionExpr {
                 include_breaks.hash(state);
             },
             #[cfg(feature = "rle")]
-            RLE => {},
+            RLE { lengths_dtype } => lengths_dtype.hash(state),
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "rle")]
+            RLEDecode => {},
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             #[cfg(feature = "ewma")]
@@ -615,6 +721,17 @@ impl Hash for FunctionExpr {
             ExtendConstant => {},
             #[cfg(feature = "top_k")]
             TopKBy { descending } => descending.hash(state),
+            ArgSortBy {
+                descending,
+                nulls_last,
+                limit,
+                maintain_order,
+            } => {
+                descending.hash(state);
+                nulls_last.hash(state);
+                limit.hash(state);
+                maintain_order.hash(state);
+            },
         }
     }
 }
@@ -638,6 +755,8 @@ impl Display for FunctionExpr {
             TemporalExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => return write!(f, "bitwise_{func}"),
+            #[cfg(feature = "approx_quantile")]
+            TDigest(func) => return write!(f, "{func}"),
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
@@ -645,6 +764,10 @@ impl Display for FunctionExpr {
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
+            #[cfg(feature = "abs")]
+            MaxAbs => "max_abs",
+            #[cfg(feature = "abs")]
+            MinAbs => "min_abs",
             Negate => "negate",
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
@@ -678,10 +801,18 @@ impl Display for FunctionExpr {
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
+            #[cfg(feature = "cov")]
+            Autocorr { .. } => "autocorr",
+            #[cfg(feature = "finance")]
+            GarmanKlassVolatility { .. } => "garman_klass_volatility",
+            #[cfg(feature = "signal")]
+            SavgolFilter { .. } => "savgol_filter",
             ArgUnique => "arg_unique",
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            RankBy { .. } => "rank_by",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -689,6 +820,10 @@ impl Display for FunctionExpr {
                 (true, false) => "clip_min",
                 _ => unreachable!(),
             },
+            #[cfg(feature = "round_series")]
+            Winsorize { .. } => "winsorize",
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => "is_close",
             #[cfg(feature = "dtype-struct")]
             AsStruct => "as_struct",
             #[cfg(feature = "top_k")]
@@ -701,6 +836,7 @@ impl Display for FunctionExpr {
             },
             #[cfg(feature = "top_k")]
             TopKBy { .. } => "top_k_by",
+            ArgSortBy { .. } => "arg_sort_by",
             Shift => "shift",
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
@@ -712,11 +848,20 @@ impl Display for FunctionExpr {
             CumMin { .. } => "cum_min",
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => "cum_max",
+            #[cfg(feature = "cum_agg")]
+            CumSumReset => "cum_sum_reset",
+            #[cfg(feature = "cum_agg")]
+            CumTrapz => "cum_trapz",
+            #[cfg(feature = "session_id")]
+            SessionId { .. } => "session_id",
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => "value_counts",
             #[cfg(feature = "unique_counts")]
             UniqueCounts => "unique_counts",
+            #[cfg(feature = "unique_counts")]
+            UniqueWithCounts => "unique_with_counts",
             Reverse => "reverse",
+            ScatterByMask => "scatter_by_mask",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
             Coalesce => "coalesce",
@@ -772,9 +917,11 @@ impl Display for FunctionExpr {
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
             #[cfg(feature = "rle")]
-            RLE => "rle",
+            RLE { .. } => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "rle")]
+            RLEDecode => "rle_decode",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
@@ -910,6 +1057,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TemporalExpr(func) => func.into(),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => func.into(),
+            #[cfg(feature = "approx_quantile")]
+            TDigest(func) => func.into(),
 
             // Other expressions
             Boolean(func) => func.into(),
@@ -917,6 +1066,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Business(func) => func.into(),
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
+            #[cfg(feature = "abs")]
+            MaxAbs => map!(abs::max_abs),
+            #[cfg(feature = "abs")]
+            MinAbs => map!(abs::min_abs),
             Negate => map!(dispatch::negate),
             NullCount => {
                 let f = |s: &mut [Column]| {
@@ -1011,6 +1164,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy {
+                        options,
+                        ddof,
+                        is_corr,
+                    } => map_as_slice!(
+                        rolling_by::rolling_corr_cov_by,
+                        options.clone(),
+                        ddof,
+                        is_corr
+                    ),
                 }
             },
             #[cfg(feature = "hist")]
@@ -1035,16 +1199,37 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Clip { has_min, has_max } => {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
+            #[cfg(feature = "round_series")]
+            Winsorize { lower, upper } => map!(clip::winsorize, lower, upper),
+            #[cfg(feature = "is_close")]
+            IsClose {
+                rel_tol,
+                abs_tol,
+                nans_equal,
+            } => map_as_slice!(dispatch::is_close, rel_tol, abs_tol, nans_equal),
             #[cfg(feature = "mode")]
             Mode => map!(dispatch::mode),
             #[cfg(feature = "moment")]
             Skew(bias) => map!(dispatch::skew, bias),
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
+            #[cfg(feature = "cov")]
+            Autocorr { lag } => map!(dispatch::autocorr, lag),
+            #[cfg(feature = "finance")]
+            GarmanKlassVolatility { window } => {
+                map_as_slice!(dispatch::garman_klass_volatility, window)
+            },
+            #[cfg(feature = "signal")]
+            SavgolFilter {
+                window_size,
+                poly_order,
+            } => map!(dispatch::savgol_filter, window_size, poly_order),
             ArgUnique => map!(dispatch::arg_unique),
             Repeat => map_as_slice!(repeat::repeat),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
+            #[cfg(feature = "rank")]
+            RankBy { options, seed } => map_as_slice!(dispatch::rank_by, options, seed),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
@@ -1055,6 +1240,18 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             },
             #[cfg(feature = "top_k")]
             TopKBy { descending } => map_as_slice!(top_k_by, descending.clone()),
+            ArgSortBy {
+                descending,
+                nulls_last,
+                limit,
+                maintain_order,
+            } => map_as_slice!(
+                dispatch::arg_sort_by,
+                descending.clone(),
+                nulls_last.clone(),
+                limit,
+                maintain_order
+            ),
             Shift => map_as_slice!(shift_and_fill::shift),
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => map!(cum::cum_count, reverse),
@@ -1066,6 +1263,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             CumMin { reverse } => map!(cum::cum_min, reverse),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => map!(cum::cum_max, reverse),
+            #[cfg(feature = "cum_agg")]
+            CumSumReset => map_as_slice!(cum::cum_sum_reset),
+            #[cfg(feature = "cum_agg")]
+            CumTrapz => map_as_slice!(cum::cum_trapz),
+            #[cfg(feature = "session_id")]
+            SessionId { gap, sort } => map!(session_id::session_id, gap, sort),
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -1081,7 +1284,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => map!(dispatch::unique_counts),
+            #[cfg(feature = "unique_counts")]
+            UniqueWithCounts => map!(dispatch::unique_with_counts),
             Reverse => map!(dispatch::reverse),
+            ScatterByMask => map_as_slice!(dispatch::scatter_by_mask),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
             Coalesce => map_as_slice!(fill_null::coalesce),
@@ -1159,9 +1365,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 include_breaks
             ),
             #[cfg(feature = "rle")]
-            RLE => map!(rle),
+            RLE { lengths_dtype } => map!(rle, &lengths_dtype),
             #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
+            #[cfg(feature = "rle")]
+            RLEDecode => map!(rle_decode),
             ToPhysical => map!(dispatch::to_physical),
             #[cfg(feature = "random")]
             Random { method, seed } => {
@@ -1172,8 +1380,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                         is_fraction,
                         with_replacement,
                         shuffle,
+                        seed_per_group,
                     } => {
-                        if is_fraction {
+                        if seed_per_group {
+                            map_as_slice!(
+                                random::sample_per_group,
+                                is_fraction,
+                                with_replacement,
+                                shuffle,
+                                seed
+                            )
+                        } else if is_fraction {
                             map_as_slice!(random::sample_frac, with_replacement, shuffle, seed)
                         } else {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
@@ -1244,6 +1461,8 @@ impl FunctionExpr {
             F::TemporalExpr(e) => e.function_options(),
             #[cfg(feature = "bitwise")]
             F::Bitwise(e) => e.function_options(),
+            #[cfg(feature = "approx_quantile")]
+            F::TDigest(e) => e.function_options(),
             F::Boolean(e) => e.function_options(),
             #[cfg(feature = "business")]
             F::Business(e) => e.function_options(),
@@ -1252,6 +1471,8 @@ impl FunctionExpr {
             F::Range(e) => e.function_options(),
             #[cfg(feature = "abs")]
             F::Abs => FunctionOptions::elementwise(),
+            #[cfg(feature = "abs")]
+            F::MaxAbs | F::MinAbs => FunctionOptions::aggregation(),
             F::Negate => FunctionOptions::elementwise(),
             #[cfg(feature = "hist")]
             F::Hist { .. } => FunctionOptions::groupwise(),
@@ -1294,18 +1515,30 @@ impl FunctionExpr {
             F::Skew(_) => FunctionOptions::aggregation(),
             #[cfg(feature = "moment")]
             F::Kurtosis(_, _) => FunctionOptions::aggregation(),
+            #[cfg(feature = "cov")]
+            F::Autocorr { .. } => FunctionOptions::aggregation(),
+            #[cfg(feature = "finance")]
+            F::GarmanKlassVolatility { .. } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "signal")]
+            F::SavgolFilter { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "dtype-array")]
             F::Reshape(_) => FunctionOptions::groupwise(),
             #[cfg(feature = "repeat_by")]
             F::RepeatBy => FunctionOptions::elementwise(),
             F::ArgUnique => FunctionOptions::groupwise(),
             #[cfg(feature = "rank")]
-            F::Rank { .. } => FunctionOptions::groupwise(),
+            F::Rank { .. } | F::RankBy { .. } => FunctionOptions::groupwise(),
             F::Repeat => {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
             #[cfg(feature = "round_series")]
             F::Clip { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "round_series")]
+            F::Winsorize { .. } => FunctionOptions::groupwise(),
+            #[cfg(feature = "is_close")]
+            F::IsClose { .. } => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
             #[cfg(feature = "dtype-struct")]
             F::AsStruct => FunctionOptions::elementwise().with_flags(|f| {
                 f | FunctionFlags::PASS_NAME_TO_APPLY | FunctionFlags::INPUT_WILDCARD_EXPANSION
@@ -1314,19 +1547,27 @@ impl FunctionExpr {
             F::TopK { .. } => FunctionOptions::groupwise(),
             #[cfg(feature = "top_k")]
             F::TopKBy { .. } => FunctionOptions::groupwise(),
+            F::ArgSortBy { .. } => FunctionOptions::groupwise(),
             #[cfg(feature = "cum_agg")]
             F::CumCount { .. }
             | F::CumSum { .. }
             | F::CumProd { .. }
             | F::CumMin { .. }
-            | F::CumMax { .. } => FunctionOptions::length_preserving(),
+            | F::CumMax { .. }
+            | F::CumSumReset
+            | F::CumTrapz => FunctionOptions::length_preserving(),
+            #[cfg(feature = "session_id")]
+            F::SessionId { .. } => FunctionOptions::length_preserving(),
             F::Reverse => FunctionOptions::length_preserving(),
+            F::ScatterByMask => FunctionOptions::groupwise(),
             #[cfg(feature = "dtype-struct")]
             F::ValueCounts { .. } => {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY)
             },
             #[cfg(feature = "unique_counts")]
             F::UniqueCounts => FunctionOptions::groupwise(),
+            #[cfg(feature = "unique_counts")]
+            F::UniqueWithCounts => FunctionOptions::aggregation(),
             #[cfg(feature = "approx_unique")]
             F::ApproxNUnique => FunctionOptions::aggregation(),
             F::Coalesce => FunctionOptions::elementwise()
@@ -1368,7 +1609,7 @@ impl FunctionExpr {
             F::Cut { .. } | F::QCut { .. } => FunctionOptions::length_preserving()
                 .with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY),
             #[cfg(feature = "rle")]
-            F::RLE => FunctionOptions::groupwise(),
+            F::RLE { .. } | F::RLEDecode => FunctionOptions::groupwise(),
             #[cfg(feature = "rle")]
             F::RLEID => FunctionOptions::length_preserving(),
             F::ToPhysical => FunctionOptions::elementwise(),
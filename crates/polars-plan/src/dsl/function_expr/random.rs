@@ -1,4 +1,6 @@
+use polars_core::hashing::columns_to_hashes;
 use polars_core::prelude::DataType::Float64;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
@@ -15,6 +17,9 @@ pub enum RandomMethod {
         is_fraction: bool,
         with_replacement: bool,
         shuffle: bool,
+        /// Derive the seed of every group from its key, see [`Expr::sample_per_group`].
+        #[cfg_attr(feature = "serde", serde(default))]
+        seed_per_group: bool,
     },
 }
 
@@ -28,6 +33,35 @@ pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
     Ok(s.shuffle(seed))
 }
 
+/// Sample a group with a seed that is derived from the key of the group, whose columns are passed
+/// after the sample size.
+pub(super) fn sample_per_group(
+    s: &[Column],
+    is_fraction: bool,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    // Hash the first row of the key like `DataFrame::sample_stratified`, so the seed doesn't
+    // depend on the order of the groups.
+    let keys = s[2..].iter().map(|k| k.head(Some(1))).collect::<Vec<_>>();
+    let mut hashes = vec![];
+    if keys.first().is_some_and(|k| !k.is_empty()) {
+        columns_to_hashes(
+            &keys,
+            Some(PlSeedableRandomStateQuality::seed_from_u64(0)),
+            &mut hashes,
+        )?;
+    }
+    let hash = hashes.first().copied().unwrap_or(0);
+    let seed = seed.map(|seed| seed ^ hash);
+    if is_fraction {
+        sample_frac(&s[..2], with_replacement, shuffle, seed)
+    } else {
+        sample_n(&s[..2], with_replacement, shuffle, seed)
+    }
+}
+
 pub(super) fn sample_frac(
     s: &[Column],
     with_replacement: bool,
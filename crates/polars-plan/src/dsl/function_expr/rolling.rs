@@ -1,12 +1,6 @@
-#[cfg(feature = "cov")]
-use std::ops::BitAnd;
-
-use polars_core::utils::Container;
 use polars_time::chunkedarray::*;
 
 use super::*;
-#[cfg(feature = "cov")]
-use crate::dsl::pow::pow;
 
 #[derive(Clone, PartialEq, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -131,25 +125,6 @@ pub(super) fn rolling_kurtosis(
     polars_ops::series::rolling_kurtosis(s, options).map(Column::from)
 }
 
-#[cfg(feature = "cov")]
-fn det_count_x_y(window_size: usize, len: usize, dtype: &DataType) -> Series {
-    match dtype {
-        DataType::Float64 => {
-            let values = (0..len)
-                .map(|v| std::cmp::min(window_size, v + 1) as f64)
-                .collect::<Vec<_>>();
-            Series::new(PlSmallStr::EMPTY, values)
-        },
-        DataType::Float32 => {
-            let values = (0..len)
-                .map(|v| std::cmp::min(window_size, v + 1) as f32)
-                .collect::<Vec<_>>();
-            Series::new(PlSmallStr::EMPTY, values)
-        },
-        _ => unreachable!(),
-    }
-}
-
 #[cfg(feature = "cov")]
 pub(super) fn rolling_corr_cov(
     s: &[Column],
@@ -157,73 +132,12 @@ pub(super) fn rolling_corr_cov(
     cov_options: RollingCovOptions,
     is_corr: bool,
 ) -> PolarsResult<Column> {
-    let mut x = s[0].as_materialized_series().rechunk();
-    let mut y = s[1].as_materialized_series().rechunk();
-
-    if !x.dtype().is_float() {
-        x = x.cast(&DataType::Float64)?;
-    }
-    if !y.dtype().is_float() {
-        y = y.cast(&DataType::Float64)?;
-    }
-    let dtype = x.dtype().clone();
-
-    let mean_x_y = (&x * &y)?.rolling_mean(rolling_options.clone())?;
-    let rolling_options_count = RollingOptionsFixedWindow {
-        window_size: rolling_options.window_size,
-        min_periods: 0,
-        ..Default::default()
-    };
-
-    let count_x_y = if (x.null_count() + y.null_count()) > 0 {
-        // mask out nulls on both sides before compute mean/var
-        let valids = x.is_not_null().bitand(y.is_not_null());
-        let valids_arr = valids.downcast_as_array();
-        let valids_bitmap = valids_arr.values();
-
-        unsafe {
-            let xarr = &mut x.chunks_mut()[0];
-            *xarr = xarr.with_validity(Some(valids_bitmap.clone()));
-            let yarr = &mut y.chunks_mut()[0];
-            *yarr = yarr.with_validity(Some(valids_bitmap.clone()));
-            x.compute_len();
-            y.compute_len();
-        }
-        valids
-            .cast(&dtype)
-            .unwrap()
-            .rolling_sum(rolling_options_count)?
-    } else {
-        det_count_x_y(rolling_options.window_size, x.len(), &dtype)
-    };
-
-    let mean_x = x.rolling_mean(rolling_options.clone())?;
-    let mean_y = y.rolling_mean(rolling_options.clone())?;
-    let ddof = Series::new(
-        PlSmallStr::EMPTY,
-        &[AnyValue::from(cov_options.ddof).cast(&dtype)],
-    );
-
-    let numerator = ((mean_x_y - (mean_x * mean_y).unwrap()).unwrap()
-        * (count_x_y.clone() / (count_x_y - ddof).unwrap()).unwrap())
-    .unwrap();
-
-    if is_corr {
-        let var_x = x.rolling_var(rolling_options.clone())?;
-        let var_y = y.rolling_var(rolling_options.clone())?;
-
-        let base = (var_x * var_y).unwrap();
-        let sc = Scalar::new(
-            base.dtype().clone(),
-            AnyValue::Float64(0.5).cast(&dtype).into_static(),
-        );
-        let denominator = pow(&mut [base.into_column(), sc.into_column("".into())])
-            .unwrap()
-            .unwrap()
-            .take_materialized_series();
-
-        Ok((numerator / denominator)?.into_column())
-    } else {
-        Ok(numerator.into_column())
-    }
+    polars_time::chunkedarray::rolling_cov_corr(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        &rolling_options,
+        cov_options.ddof,
+        is_corr,
+    )
+    .map(Column::from)
 }
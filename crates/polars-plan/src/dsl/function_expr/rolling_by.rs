@@ -13,6 +13,13 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    #[cfg(feature = "cov")]
+    CorrCovBy {
+        options: RollingOptionsDynamicWindow,
+        ddof: u8,
+        // Whether is Corr or Cov
+        is_corr: bool,
+    },
 }
 
 impl Display for RollingFunctionBy {
@@ -27,6 +34,14 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            #[cfg(feature = "cov")]
+            CorrCovBy { is_corr, .. } => {
+                if *is_corr {
+                    "rolling_corr_by"
+                } else {
+                    "rolling_cov_by"
+                }
+            },
         };
 
         write!(f, "{name}")
@@ -108,3 +123,22 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+#[cfg(feature = "cov")]
+pub(super) fn rolling_corr_cov_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    rolling_cov_corr_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+        options,
+        ddof,
+        is_corr,
+    )
+    .map(Column::from)
+}
@@ -28,6 +28,8 @@ impl FunctionExpr {
             TemporalExpr(fun) => fun.get_field(mapper),
             #[cfg(feature = "bitwise")]
             Bitwise(fun) => fun.get_field(mapper),
+            #[cfg(feature = "approx_quantile")]
+            TDigest(fun) => fun.get_field(mapper),
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
@@ -35,6 +37,8 @@ impl FunctionExpr {
             Business(func) => func.get_field(mapper),
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
+            #[cfg(feature = "abs")]
+            MaxAbs | MinAbs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
@@ -79,6 +83,8 @@ impl FunctionExpr {
                     MinBy(_) | MaxBy(_) => mapper.with_same_dtype(),
                     MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
                     SumBy(_) => mapper.sum_dtype(),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy { .. } => mapper.map_to_float_dtype(),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -86,16 +92,26 @@ impl FunctionExpr {
             DropNulls => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "round_series")]
+            Winsorize { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "mode")]
             Mode => mapper.with_same_dtype(),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "cov")]
+            Autocorr { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "finance")]
+            GarmanKlassVolatility { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "signal")]
+            SavgolFilter { .. } => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
             Repeat => mapper.with_same_dtype(),
             #[cfg(feature = "rank")]
-            Rank { options, .. } => mapper.with_dtype(match options.method {
+            Rank { options, .. } | RankBy { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
@@ -108,6 +124,7 @@ impl FunctionExpr {
             TopK { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "top_k")]
             TopKBy { .. } => mapper.with_same_dtype(),
+            ArgSortBy { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort: _,
@@ -127,7 +144,14 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => mapper.with_dtype(IDX_DTYPE),
-            Shift | Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "unique_counts")]
+            UniqueWithCounts => mapper.map_dtype(|dtype| {
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new(PlSmallStr::from_static("value"), dtype.clone()),
+                    Field::new(PlSmallStr::from_static("count"), IDX_DTYPE),
+                ])))
+            }),
+            Shift | Reverse | ScatterByMask => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "cum_agg")]
@@ -138,6 +162,12 @@ impl FunctionExpr {
             CumMin { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "cum_agg")]
+            CumSumReset => mapper.map_dtype(cum::dtypes::cum_sum),
+            #[cfg(feature = "cum_agg")]
+            CumTrapz => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "session_id")]
+            SessionId { .. } => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "hist")]
@@ -308,14 +338,21 @@ impl FunctionExpr {
                 mapper.with_dtype(struct_dt)
             },
             #[cfg(feature = "rle")]
-            RLE => mapper.map_dtype(|dt| {
+            RLE { lengths_dtype } => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("len"), IDX_DTYPE),
+                    Field::new(PlSmallStr::from_static("len"), lengths_dtype.clone()),
                     Field::new(PlSmallStr::from_static("value"), dt.clone()),
                 ])
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "rle")]
+            RLEDecode => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) if fields.len() == 2 => Ok(fields[1].dtype().clone()),
+                dt => polars_bail!(
+                    InvalidOperation: "rle_decode expects a struct with the fields 'len' and 'value', got {}", dt
+                ),
+            }),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
@@ -0,0 +1,13 @@
+use super::*;
+
+pub(super) fn session_id(s: &Column, gap: Duration, sort: bool) -> PolarsResult<Column> {
+    let time_zone = match s.dtype() {
+        DataType::Datetime(_, Some(time_zone)) => Some(time_zone),
+        _ => None,
+    };
+    polars_ensure!(!gap.negative(), InvalidOperation: "gap cannot be negative");
+    ensure_is_constant_duration(gap, time_zone, "gap")?;
+    // `gap` is a constant duration so we can safely use `duration_ns()`.
+    let gap = gap.duration_ns();
+    polars_ops::prelude::session_id(s.as_materialized_series(), gap, sort).map(Column::from)
+}
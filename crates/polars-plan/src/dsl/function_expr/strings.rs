@@ -52,6 +52,8 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_to_integer")]
     ToInteger(bool),
+    #[cfg(feature = "string_to_integer")]
+    ToIntegerWith(ToIntegerOptions),
     LenBytes,
     LenChars,
     Lowercase,
@@ -157,6 +159,18 @@ impl StringFunction {
             ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => mapper.with_dtype(DataType::Int64),
+            #[cfg(feature = "string_to_integer")]
+            ToIntegerWith(options) => {
+                #[cfg(feature = "dtype-struct")]
+                if options.with_errors {
+                    return mapper.with_dtype(DataType::Struct(vec![
+                        Field::new(PlSmallStr::from_static("value"), DataType::Int64),
+                        Field::new(PlSmallStr::from_static("error"), DataType::String),
+                    ]));
+                }
+                let _ = options;
+                mapper.with_dtype(DataType::Int64)
+            },
             #[cfg(feature = "regex")]
             Find { .. } => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "extract_jsonpath")]
@@ -236,6 +250,8 @@ impl StringFunction {
             S::ExtractGroups { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_to_integer")]
             S::ToInteger { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_to_integer")]
+            S::ToIntegerWith(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::Find { .. } => FunctionOptions::elementwise().with_supertyping(Default::default()),
             #[cfg(feature = "extract_jsonpath")]
@@ -312,7 +328,7 @@ impl Display for StringFunction {
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
             #[cfg(feature = "string_to_integer")]
-            ToInteger { .. } => "to_integer",
+            ToInteger { .. } | ToIntegerWith(_) => "to_integer",
             #[cfg(feature = "regex")]
             Find { .. } => "find",
             Head => "head",
@@ -463,6 +479,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             StripSuffix => map_as_slice!(strings::strip_suffix),
             #[cfg(feature = "string_to_integer")]
             ToInteger(strict) => map_as_slice!(strings::to_integer, strict),
+            #[cfg(feature = "string_to_integer")]
+            ToIntegerWith(options) => map!(strings::to_integer_with, options),
             Slice => map_as_slice!(strings::str_slice),
             Head => map_as_slice!(strings::str_head),
             Tail => map_as_slice!(strings::str_tail),
@@ -1100,6 +1118,29 @@ pub(super) fn to_integer(s: &[Column], strict: bool) -> PolarsResult<Column> {
         .map(|ok| ok.into_column())
 }
 
+#[cfg(feature = "string_to_integer")]
+pub(super) fn to_integer_with(s: &Column, options: ToIntegerOptions) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    let (values, errors) = polars_ops::chunked_array::strings::to_integer_with(ca, &options)?;
+    if !options.with_errors {
+        return Ok(values.into_column());
+    }
+    #[cfg(feature = "dtype-struct")]
+    {
+        let values = values
+            .with_name(PlSmallStr::from_static("value"))
+            .into_series();
+        let errors = errors.into_series();
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), [values, errors].iter())?;
+        Ok(out.into_column())
+    }
+    #[cfg(not(feature = "dtype-struct"))]
+    {
+        let _ = errors;
+        polars_bail!(InvalidOperation: "`to_integer` with errors requires the 'dtype-struct' feature")
+    }
+}
+
 fn _ensure_lengths(s: &[Column]) -> bool {
     // Calculate the post-broadcast length and ensure everything is consistent.
     let len = s
@@ -11,8 +11,14 @@ pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(PlSmallStr),
     RenameFields(Arc<[PlSmallStr]>),
-    PrefixFields(PlSmallStr),
-    SuffixFields(PlSmallStr),
+    PrefixFields {
+        prefix: PlSmallStr,
+        recursive: bool,
+    },
+    SuffixFields {
+        suffix: PlSmallStr,
+        recursive: bool,
+    },
     #[cfg(feature = "json")]
     JsonEncode,
     WithFields,
@@ -66,30 +72,20 @@ impl StructFunction {
                         .collect(),
                 ),
             }),
-            PrefixFields(prefix) => mapper.try_map_dtype(|dt| match dt {
-                DataType::Struct(fields) => {
-                    let fields = fields
-                        .iter()
-                        .map(|fld| {
-                            let name = fld.name();
-                            Field::new(format_pl_smallstr!("{prefix}{name}"), fld.dtype().clone())
-                        })
-                        .collect();
-                    Ok(DataType::Struct(fields))
-                },
+            PrefixFields { prefix, recursive } => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(_) => Ok(map_field_names_dtype(
+                    dt,
+                    &|name| format_pl_smallstr!("{prefix}{name}"),
+                    *recursive,
+                )),
                 _ => polars_bail!(op = "prefix_fields", got = dt, expected = "Struct"),
             }),
-            SuffixFields(suffix) => mapper.try_map_dtype(|dt| match dt {
-                DataType::Struct(fields) => {
-                    let fields = fields
-                        .iter()
-                        .map(|fld| {
-                            let name = fld.name();
-                            Field::new(format_pl_smallstr!("{name}{suffix}"), fld.dtype().clone())
-                        })
-                        .collect();
-                    Ok(DataType::Struct(fields))
-                },
+            SuffixFields { suffix, recursive } => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(_) => Ok(map_field_names_dtype(
+                    dt,
+                    &|name| format_pl_smallstr!("{name}{suffix}"),
+                    *recursive,
+                )),
                 _ => polars_bail!(op = "suffix_fields", got = dt, expected = "Struct"),
             }),
             #[cfg(feature = "json")]
@@ -131,7 +127,7 @@ impl StructFunction {
             S::FieldByIndex(_) | S::FieldByName(_) => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
-            S::RenameFields(_) | S::PrefixFields(_) | S::SuffixFields(_) => {
+            S::RenameFields(_) | S::PrefixFields { .. } | S::SuffixFields { .. } => {
                 FunctionOptions::elementwise()
             },
             #[cfg(feature = "json")]
@@ -153,8 +149,8 @@ impl Display for StructFunction {
             FieldByIndex(index) => write!(f, "struct.field_by_index({index})"),
             FieldByName(name) => write!(f, "struct.field_by_name({name})"),
             RenameFields(names) => write!(f, "struct.rename_fields({names:?})"),
-            PrefixFields(_) => write!(f, "name.prefix_fields"),
-            SuffixFields(_) => write!(f, "name.suffixFields"),
+            PrefixFields { .. } => write!(f, "name.prefix_fields"),
+            SuffixFields { .. } => write!(f, "name.suffix_fields"),
             #[cfg(feature = "json")]
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
@@ -170,8 +166,8 @@ impl From<StructFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             FieldByIndex(_) => panic!("should be replaced"),
             FieldByName(name) => map!(get_by_name, &name),
             RenameFields(names) => map!(rename_fields, names.clone()),
-            PrefixFields(prefix) => map!(prefix_fields, prefix.as_str()),
-            SuffixFields(suffix) => map!(suffix_fields, suffix.as_str()),
+            PrefixFields { prefix, recursive } => map!(prefix_fields, prefix.as_str(), recursive),
+            SuffixFields { suffix, recursive } => map!(suffix_fields, suffix.as_str(), recursive),
             #[cfg(feature = "json")]
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
@@ -202,38 +198,65 @@ pub(super) fn rename_fields(s: &Column, names: Arc<[PlSmallStr]>) -> PolarsResul
     Ok(out.into_column())
 }
 
-pub(super) fn prefix_fields(s: &Column, prefix: &str) -> PolarsResult<Column> {
+pub(super) fn prefix_fields(s: &Column, prefix: &str, recursive: bool) -> PolarsResult<Column> {
     let ca = s.struct_()?;
-    let fields = ca
-        .fields_as_series()
-        .iter()
-        .map(|s| {
-            let mut s = s.clone();
-            let name = s.name();
-            s.rename(format_pl_smallstr!("{prefix}{name}"));
-            s
-        })
-        .collect::<Vec<_>>();
-    let mut out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
-    out.zip_outer_validity(ca);
-    Ok(out.into_column())
+    map_field_names(ca, &|name| format_pl_smallstr!("{prefix}{name}"), recursive)
+        .map(|ca| ca.into_column())
 }
 
-pub(super) fn suffix_fields(s: &Column, suffix: &str) -> PolarsResult<Column> {
+pub(super) fn suffix_fields(s: &Column, suffix: &str, recursive: bool) -> PolarsResult<Column> {
     let ca = s.struct_()?;
+    map_field_names(ca, &|name| format_pl_smallstr!("{name}{suffix}"), recursive)
+        .map(|ca| ca.into_column())
+}
+
+/// Rename the fields of a struct dtype with `f`, and those of nested structs if `recursive`.
+pub(crate) fn map_field_names_dtype(
+    dtype: &DataType,
+    f: &dyn Fn(&str) -> PlSmallStr,
+    recursive: bool,
+) -> DataType {
+    match dtype {
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|fld| {
+                    let dtype = if recursive {
+                        map_field_names_dtype(fld.dtype(), f, recursive)
+                    } else {
+                        fld.dtype().clone()
+                    };
+                    Field::new(f(fld.name()), dtype)
+                })
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// Rename the fields of a struct with `f`, and those of nested structs if `recursive`.
+pub(crate) fn map_field_names(
+    ca: &StructChunked,
+    f: &dyn Fn(&str) -> PlSmallStr,
+    recursive: bool,
+) -> PolarsResult<StructChunked> {
     let fields = ca
         .fields_as_series()
-        .iter()
+        .into_iter()
         .map(|s| {
-            let mut s = s.clone();
-            let name = s.name();
-            s.rename(format_pl_smallstr!("{name}{suffix}"));
-            s
+            let mut s = match s.dtype() {
+                DataType::Struct(_) if recursive => {
+                    map_field_names(s.struct_()?, f, recursive)?.into_series()
+                },
+                _ => s,
+            };
+            s.rename(f(s.name()));
+            Ok(s)
         })
-        .collect::<Vec<_>>();
+        .collect::<PolarsResult<Vec<_>>>()?;
     let mut out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
     out.zip_outer_validity(ca);
-    Ok(out.into_column())
+    Ok(out)
 }
 
 #[cfg(feature = "json")]
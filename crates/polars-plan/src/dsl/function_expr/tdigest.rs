@@ -0,0 +1,168 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use polars_compute::tdigest::{MAX_COMPRESSION, TDigest};
+use polars_core::prelude::*;
+
+use super::{ColumnsUdf, SpecialEq};
+use crate::dsl::{FieldsMapper, FunctionOptions};
+use crate::map;
+
+/// Aggregations backed by a t-digest, see [`polars_compute::tdigest`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TDigestFunction {
+    /// Approximate quantile of numeric values, or of serialized digests.
+    Quantile { quantile: f64, compression: f64 },
+    /// The serialized digest of numeric values.
+    State { compression: f64 },
+    /// Merge serialized digests into a single serialized digest.
+    Merge,
+}
+
+impl Hash for TDigestFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Quantile {
+                quantile,
+                compression,
+            } => {
+                quantile.to_bits().hash(state);
+                compression.to_bits().hash(state);
+            },
+            Self::State { compression } => compression.to_bits().hash(state),
+            Self::Merge => {},
+        }
+    }
+}
+
+impl fmt::Display for TDigestFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Quantile { .. } => "approx_quantile",
+            Self::State { .. } => "approx_quantile_state",
+            Self::Merge => "merge_tdigest",
+        };
+        f.write_str(s)
+    }
+}
+
+impl From<TDigestFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: TDigestFunction) -> Self {
+        use TDigestFunction as T;
+
+        match func {
+            T::Quantile {
+                quantile,
+                compression,
+            } => map!(approx_quantile, quantile, compression),
+            T::State { compression } => map!(approx_quantile_state, compression),
+            T::Merge => map!(merge_tdigest),
+        }
+    }
+}
+
+impl TDigestFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.try_map_dtype(|dtype| {
+            let is_valid = match self {
+                Self::Quantile { .. } => dtype.is_primitive_numeric() || dtype == &DataType::Binary,
+                Self::State { .. } => dtype.is_primitive_numeric(),
+                Self::Merge => dtype == &DataType::Binary,
+            };
+            if !is_valid {
+                polars_bail!(InvalidOperation: "dtype {} not supported in '{}' operation", dtype, self);
+            }
+
+            Ok(self.output_dtype())
+        })
+    }
+
+    pub fn output_dtype(&self) -> DataType {
+        match self {
+            Self::Quantile { .. } => DataType::Float64,
+            Self::State { .. } | Self::Merge => DataType::Binary,
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::aggregation()
+    }
+
+    /// Returns a new empty digest for this function.
+    ///
+    /// For [`TDigestFunction::Merge`] the compression of the first merged digest is used.
+    pub fn new_digest(&self) -> PolarsResult<TDigest> {
+        match self {
+            Self::Quantile {
+                quantile,
+                compression,
+            } => {
+                polars_ensure!(
+                    (0.0..=1.0).contains(quantile),
+                    ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+                );
+                new_digest(*compression)
+            },
+            Self::State { compression } => new_digest(*compression),
+            Self::Merge => Ok(TDigest::default()),
+        }
+    }
+
+    /// Finalize a digest into the output value of this function.
+    pub fn finish(&self, digest: &mut TDigest) -> AnyValue<'static> {
+        match self {
+            Self::Quantile { quantile, .. } => digest.quantile(*quantile).into(),
+            Self::State { .. } | Self::Merge => AnyValue::BinaryOwned(digest.to_bytes()),
+        }
+    }
+}
+
+fn new_digest(compression: f64) -> PolarsResult<TDigest> {
+    polars_ensure!(
+        compression > 0.0 && compression <= MAX_COMPRESSION,
+        ComputeError: "t-digest compression should be in (0, {}], got {}", MAX_COMPRESSION, compression
+    );
+    Ok(TDigest::new(compression))
+}
+
+/// Add the numeric values or serialized digests in `c` to `digest`.
+pub fn update_tdigest(digest: &mut TDigest, c: &Column) -> PolarsResult<()> {
+    if c.dtype() == &DataType::Binary {
+        for bytes in c.binary()?.into_iter().flatten() {
+            digest.merge(&TDigest::from_bytes(bytes)?);
+        }
+    } else {
+        let c = c.cast(&DataType::Float64)?;
+        digest.extend(c.f64()?.into_iter().flatten());
+    }
+    Ok(())
+}
+
+fn tdigest_reduce(c: &Column, func: TDigestFunction) -> PolarsResult<Column> {
+    let mut digest = func.new_digest()?;
+    update_tdigest(&mut digest, c)?;
+    let out = Scalar::new(func.output_dtype(), func.finish(&mut digest));
+    Ok(out.into_column(c.name().clone()))
+}
+
+fn approx_quantile(c: &Column, quantile: f64, compression: f64) -> PolarsResult<Column> {
+    tdigest_reduce(
+        c,
+        TDigestFunction::Quantile {
+            quantile,
+            compression,
+        },
+    )
+}
+
+fn approx_quantile_state(c: &Column, compression: f64) -> PolarsResult<Column> {
+    tdigest_reduce(c, TDigestFunction::State { compression })
+}
+
+fn merge_tdigest(c: &Column) -> PolarsResult<Column> {
+    tdigest_reduce(c, TDigestFunction::Merge)
+}
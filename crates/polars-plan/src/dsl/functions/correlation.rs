@@ -60,3 +60,43 @@ pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
 pub fn rolling_cov(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
     dispatch_corr_cov(x, y, options, false)
 }
+
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+fn dispatch_corr_cov_by(
+    x: Expr,
+    y: Expr,
+    by: Expr,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> Expr {
+    Expr::Function {
+        input: vec![x, y, by],
+        function: FunctionExpr::RollingExprBy(RollingFunctionBy::CorrCovBy {
+            options,
+            ddof,
+            is_corr,
+        }),
+        options: Default::default(),
+    }
+}
+
+/// Compute the rolling pearson correlation between two columns over temporal windows based on
+/// the `by` column.
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+pub fn rolling_corr_by(x: Expr, y: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+    dispatch_corr_cov_by(x, y, by, options, 1, true)
+}
+
+/// Compute the rolling covariance between two columns over temporal windows based on the `by`
+/// column.
+#[cfg(all(feature = "rolling_window_by", feature = "cov"))]
+pub fn rolling_cov_by(
+    x: Expr,
+    y: Expr,
+    by: Expr,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+) -> Expr {
+    dispatch_corr_cov_by(x, y, by, options, ddof, false)
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+/// Compute the rolling Garman-Klass volatility estimate over `window` rows from the open, high,
+/// low and close price columns.
+pub fn garman_klass_volatility(
+    open: Expr,
+    high: Expr,
+    low: Expr,
+    close: Expr,
+    window: usize,
+) -> Expr {
+    open.map_n_ary(
+        FunctionExpr::GarmanKlassVolatility { window },
+        [high, low, close],
+    )
+}
@@ -5,9 +5,25 @@ use super::*;
 /// That means that the first `Series` will be used to determine the ordering
 /// until duplicates are found. Once duplicates are found, the next `Series` will
 /// be used and so on.
+///
+/// With a `limit` in `sort_options` only the first `limit` indices are returned, which are found
+/// without sorting all rows.
 #[cfg(feature = "range")]
 pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -> Expr {
-    let e = &by.as_ref()[0];
+    let by = by.as_ref();
+    if sort_options.limit.is_some() {
+        return by[0].clone().map_n_ary(
+            FunctionExpr::ArgSortBy {
+                descending: sort_options.descending,
+                nulls_last: sort_options.nulls_last,
+                limit: sort_options.limit,
+                maintain_order: sort_options.maintain_order,
+            },
+            by[1..].iter().cloned(),
+        );
+    }
+
+    let e = &by[0];
     let name = expr_output_name(e).unwrap();
     int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE)
         .sort_by(by, sort_options)
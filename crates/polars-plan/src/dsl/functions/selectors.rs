@@ -1,4 +1,7 @@
+use polars_utils::format_pl_smallstr;
+
 use super::*;
+use crate::constants::POLARS_EXTERNAL_PREFIX;
 
 /// Create a Column Expression based on a column name.
 ///
@@ -35,6 +38,19 @@ where
     }
 }
 
+/// Reference `column` of the frame that is registered as `frame` with `LazyFrame::with_external`.
+///
+/// The external frame has a single row, so the reference is a scalar that is broadcast to the
+/// frame it is used in, also per group in an aggregation. This is useful for e.g. dividing by a
+/// total that is computed from another table. The output is named `column`.
+pub fn external(frame: &str, column: &str) -> Expr {
+    Expr::Column(format_pl_smallstr!(
+        "{POLARS_EXTERNAL_PREFIX}{frame}.{column}"
+    ))
+    .first()
+    .alias(column)
+}
+
 /// Selects all columns. Shorthand for `col("*")`.
 pub fn all() -> Expr {
     Expr::Wildcard
@@ -62,5 +62,6 @@ pub fn cast(expr: Expr, dtype: DataType) -> Expr {
         expr: Arc::new(expr),
         dtype,
         options: CastOptions::NonStrict,
+        drop_metadata: false,
     }
 }
@@ -266,35 +266,44 @@ impl ListNameSpace {
         )
     }
 
+    /// Apply a set operation between both list arrays.
+    ///
+    /// `Array` inputs are treated as lists, the output is a `List` as its width varies per row.
+    /// A single list, e.g. a literal, is broadcast against every row of the other operand.
     #[cfg(feature = "list_sets")]
-    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+    pub fn set_operation<E: Into<Expr>>(
+        self,
+        other: E,
+        set_operation: SetOperation,
+        options: ListSetOptions,
+    ) -> Expr {
         self.0.map_binary(
-            FunctionExpr::ListExpr(ListFunction::SetOperation(set_operation)),
-            other,
+            FunctionExpr::ListExpr(ListFunction::SetOperation(set_operation, options)),
+            other.into(),
         )
     }
 
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Union)
+        self.set_operation(other, SetOperation::Union, Default::default())
     }
 
     /// Return the SET DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Difference)
+        self.set_operation(other, SetOperation::Difference, Default::default())
     }
 
     /// Return the SET INTERSECTION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Intersection)
+        self.set_operation(other, SetOperation::Intersection, Default::default())
     }
 
     /// Return the SET SYMMETRIC DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::SymmetricDifference)
+        self.set_operation(other, SetOperation::SymmetricDifference, Default::default())
     }
 }
@@ -1,3 +1,5 @@
+use polars_core::prelude::*;
+
 use super::{Expr, ExtraColumnsPolicy, MissingColumnsPolicy};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,4 +30,59 @@ pub struct MatchToSchemaPerColumn {
 
     pub integer_cast: UpcastOrForbid,
     pub float_cast: UpcastOrForbid,
+    /// Cast to the target dtype even if the cast is not an allowed upcast, values that don't fit
+    /// become null.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lossy_cast: bool,
+}
+
+impl MatchToSchemaPerColumn {
+    /// Check the struct field policies on all structs nested in `incoming_dtype`, regardless of
+    /// whether the other dtypes can be cast.
+    pub(crate) fn check_struct_fields(
+        &self,
+        column_name: &str,
+        target_dtype: &DataType,
+        incoming_dtype: &DataType,
+    ) -> PolarsResult<()> {
+        match (target_dtype, incoming_dtype) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(target_fields), DataType::Struct(incoming_fields)) => {
+                for target_field in target_fields {
+                    match incoming_fields
+                        .iter()
+                        .find(|fld| fld.name() == target_field.name())
+                    {
+                        Some(fld) => self.check_struct_fields(
+                            column_name,
+                            target_field.dtype(),
+                            fld.dtype(),
+                        )?,
+                        None => polars_ensure!(
+                            self.missing_struct_fields == MissingColumnsPolicy::Insert,
+                            SchemaMismatch: "missing struct field '{}' in column '{}'",
+                            target_field.name(), column_name
+                        ),
+                    }
+                }
+                for fld in incoming_fields {
+                    polars_ensure!(
+                        self.extra_struct_fields == ExtraColumnsPolicy::Ignore
+                            || target_fields.iter().any(|t| t.name() == fld.name()),
+                        SchemaMismatch: "extra struct field '{}' in column '{}'",
+                        fld.name(), column_name
+                    );
+                }
+                Ok(())
+            },
+            (DataType::List(target_inner), DataType::List(incoming_inner)) => {
+                self.check_struct_fields(column_name, target_inner, incoming_inner)
+            },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(target_inner, _), DataType::Array(incoming_inner, _)) => {
+                self.check_struct_fields(column_name, target_inner, incoming_inner)
+            },
+            _ => Ok(()),
+        }
+    }
 }
@@ -20,6 +20,7 @@ pub use builder_dsl::*;
 #[cfg(feature = "temporal")]
 pub mod dt;
 mod expr;
+mod expr_plugin;
 mod format;
 mod from;
 pub mod function_expr;
@@ -35,6 +36,7 @@ pub mod python_dsl;
 #[cfg(feature = "random")]
 mod random;
 mod scan_sources;
+pub mod sel;
 mod selector;
 mod statistics;
 #[cfg(feature = "strings")]
@@ -51,6 +53,7 @@ pub use arity::*;
 #[cfg(feature = "dtype-array")]
 pub use array::*;
 pub use expr::*;
+pub use expr_plugin::*;
 pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
@@ -311,6 +314,9 @@ impl Expr {
 
     /// Get unique values of this expression, while maintaining order.
     /// This requires more work than [`Expr::unique`].
+    ///
+    /// In a group-by the values of every group are in the order of first appearance, which is
+    /// the same order as [`Expr::unique_counts`].
     pub fn unique_stable(self) -> Self {
         self.map_unary(FunctionExpr::Unique(true))
     }
@@ -396,6 +402,7 @@ impl Expr {
             expr: Arc::new(self),
             dtype,
             options: CastOptions::Strict,
+            drop_metadata: false,
         }
     }
 
@@ -405,6 +412,7 @@ impl Expr {
             expr: Arc::new(self),
             dtype,
             options: CastOptions::NonStrict,
+            drop_metadata: false,
         }
     }
 
@@ -414,6 +422,19 @@ impl Expr {
             expr: Arc::new(self),
             dtype,
             options: cast_options,
+            drop_metadata: false,
+        }
+    }
+
+    /// Cast expression to another data type, without keeping the user metadata of the input.
+    ///
+    /// Other casts keep the metadata, such as the unit or description, of their input.
+    pub fn cast_dropping_metadata(self, dtype: DataType, cast_options: CastOptions) -> Self {
+        Expr::Cast {
+            expr: Arc::new(self),
+            dtype,
+            options: cast_options,
+            drop_metadata: true,
         }
     }
 
@@ -530,6 +551,29 @@ impl Expr {
         self.map_unary(FunctionExpr::Reverse)
     }
 
+    /// Replace the values where `mask` is `true` by `value`.
+    ///
+    /// `value` is only evaluated on the rows where `mask` is `true`, as the columns it refers to
+    /// are filtered by `mask` first, and [`len`] counts the `true` values of the mask. It has to
+    /// produce a single value, or one value per `true` in the mask. In a window (`over`), the
+    /// mask and the values are computed per group.
+    ///
+    /// [`len`]: crate::dsl::len
+    pub fn scatter_by_mask(self, mask: Expr, value: Expr) -> Self {
+        let value = value.map_expr(|e| match e {
+            Expr::Column(_)
+            | Expr::Columns(_)
+            | Expr::DtypeColumn(_)
+            | Expr::IndexColumn(_)
+            | Expr::Nth(_)
+            | Expr::Wildcard
+            | Expr::Selector(_) => e.filter(mask.clone()),
+            Expr::Len => mask.clone().sum(),
+            e => e,
+        });
+        self.map_ternary(FunctionExpr::ScatterByMask, mask, value)
+    }
+
     /// Apply a function/closure once the logical plan get executed.
     ///
     /// This function is very similar to [`Expr::apply`], but differs in how it handles aggregations.
@@ -678,6 +722,24 @@ impl Expr {
         self.map_unary(FunctionExpr::CumSum { reverse })
     }
 
+    /// Get an array with the cumulative sum computed at every element, starting over at every
+    /// element where `mask` is `true`.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum_reset(self, mask: Expr) -> Self {
+        self.map_binary(FunctionExpr::CumSumReset, mask)
+    }
+
+    /// Get the running integral of this expression over `x` with the trapezoidal rule.
+    ///
+    /// Without `x` the points are spaced one unit apart.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_trapz(self, x: Option<Expr>) -> Self {
+        match x {
+            Some(x) => self.map_binary(FunctionExpr::CumTrapz, x),
+            None => self.map_unary(FunctionExpr::CumTrapz),
+        }
+    }
+
     /// Get an array with the cumulative product computed at every element.
     #[cfg(feature = "cum_agg")]
     pub fn cum_prod(self, reverse: bool) -> Self {
@@ -777,6 +839,16 @@ impl Expr {
         )
     }
 
+    /// Winsorize the values: clip the values below the `lower` quantile and above the `upper`
+    /// quantile to those quantiles.
+    ///
+    /// The quantiles are computed with [`QuantileMethod::Nearest`], so the boundaries are values
+    /// of the column and the dtype is preserved.
+    #[cfg(feature = "round_series")]
+    pub fn winsorize(self, lower: f64, upper: f64) -> Self {
+        self.map_unary(FunctionExpr::Winsorize { lower, upper })
+    }
+
     /// Clip underlying values to a set boundary.
     #[cfg(feature = "round_series")]
     pub fn clip_min(self, min: Expr) -> Self {
@@ -795,6 +867,18 @@ impl Expr {
         self.map_unary(FunctionExpr::Abs)
     }
 
+    /// Get the value with the largest absolute value, keeping its sign.
+    #[cfg(feature = "abs")]
+    pub fn max_abs(self) -> Self {
+        self.map_unary(FunctionExpr::MaxAbs)
+    }
+
+    /// Get the value with the smallest absolute value, keeping its sign.
+    #[cfg(feature = "abs")]
+    pub fn min_abs(self) -> Self {
+        self.map_unary(FunctionExpr::MinAbs)
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a group_by + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
@@ -951,6 +1035,31 @@ impl Expr {
         )
     }
 
+    /// Check whether the values are equal to `other` within a tolerance.
+    ///
+    /// Two values `a` and `b` are close if `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`.
+    /// NaN values are only close to each other if `nans_equal` is set. Integers are compared
+    /// without a cast to floats and decimals are compared exactly. Nulls propagate like in
+    /// [`Expr::eq`].
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_close")]
+    pub fn is_close<E: Into<Expr>>(
+        self,
+        other: E,
+        rel_tol: f64,
+        abs_tol: f64,
+        nans_equal: bool,
+    ) -> Self {
+        self.map_binary(
+            FunctionExpr::IsClose {
+                rel_tol,
+                abs_tol,
+                nans_equal,
+            },
+            other.into(),
+        )
+    }
+
     /// Get a mask of unique values.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_unique")]
@@ -964,6 +1073,33 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get the approximate quantile using a t-digest with the given `compression`.
+    ///
+    /// Applied to a `Binary` column of digests created by [`Expr::approx_quantile_state`], the
+    /// digests are merged first.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64, compression: f64) -> Self {
+        self.map_unary(FunctionExpr::TDigest(TDigestFunction::Quantile {
+            quantile,
+            compression,
+        }))
+    }
+
+    /// Get the t-digest of the values serialized as `Binary`, so it can be persisted and
+    /// merged later with [`Expr::merge_tdigest`] or [`Expr::approx_quantile`].
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile_state(self, compression: f64) -> Self {
+        self.map_unary(FunctionExpr::TDigest(TDigestFunction::State {
+            compression,
+        }))
+    }
+
+    /// Merge a `Binary` column of serialized t-digests into a single serialized t-digest.
+    #[cfg(feature = "approx_quantile")]
+    pub fn merge_tdigest(self) -> Self {
+        self.map_unary(FunctionExpr::TDigest(TDigestFunction::Merge))
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1356,6 +1492,33 @@ impl Expr {
         .with_fmt("rolling_map_float")
     }
 
+    #[cfg(all(feature = "rolling_window", feature = "dtype-struct"))]
+    /// Apply a custom function over a rolling/ moving window of the rows of a struct.
+    /// The fields of the struct are passed to `f` as the columns of a [`DataFrame`], so the
+    /// function can combine several columns, e.g. `as_struct(vec![col("x"), col("y")])`.
+    /// See [`DataFrameOps::rolling_map`].
+    #[allow(clippy::type_complexity)]
+    pub fn rolling_map_frame(
+        self,
+        f: Arc<dyn Fn(&DataFrame) -> PolarsResult<AnyValue<'static>> + Send + Sync>,
+        window_size: usize,
+        min_periods: usize,
+        output_type: DataType,
+    ) -> Expr {
+        let dtype = output_type.clone();
+        self.apply(
+            move |c: Column| {
+                let ca = c.struct_()?;
+                let name = ca.name().clone();
+                let df = ca.clone().unnest();
+                df.rolling_map(window_size, min_periods, f.as_ref(), &dtype)
+                    .map(|s| Some(s.with_name(name).into_column()))
+            },
+            GetOutput::from_type(output_type),
+        )
+        .with_fmt("rolling_map_frame")
+    }
+
     #[cfg(feature = "peaks")]
     pub fn peak_min(self) -> Expr {
         self.map_unary(FunctionExpr::PeakMin)
@@ -1372,6 +1535,20 @@ impl Expr {
         self.map_unary(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Assign ranks to data within the groups formed by the unique combinations of `by`.
+    ///
+    /// This is equivalent to `rank(..).over(by)`, but sorts the keys instead of grouping them.
+    pub fn rank_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        options: RankOptions,
+        seed: Option<u64>,
+    ) -> Expr {
+        let by = by.as_ref().iter().map(|e| e.clone().into());
+        self.map_n_ary(FunctionExpr::RankBy { options, seed }, by)
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
@@ -1455,7 +1632,16 @@ impl Expr {
     #[cfg(feature = "rle")]
     /// Get the lengths of runs of identical values.
     pub fn rle(self) -> Expr {
-        self.map_unary(FunctionExpr::RLE)
+        self.rle_with(IDX_DTYPE)
+    }
+
+    #[cfg(feature = "rle")]
+    /// Get the lengths of runs of identical values, with lengths of the given integer dtype.
+    ///
+    /// The lengths are counted as [`IdxSize`] and then cast, so this errors if a run doesn't fit
+    /// in `lengths_dtype`, e.g. a run of 300 values with `UInt8` lengths.
+    pub fn rle_with(self, lengths_dtype: DataType) -> Expr {
+        self.map_unary(FunctionExpr::RLE { lengths_dtype })
     }
 
     #[cfg(feature = "rle")]
@@ -1464,6 +1650,12 @@ impl Expr {
         self.map_unary(FunctionExpr::RLEID)
     }
 
+    #[cfg(feature = "rle")]
+    /// Decode the output of [`Expr::rle`] back into the repeated values.
+    pub fn rle_decode(self) -> Expr {
+        self.map_unary(FunctionExpr::RLEDecode)
+    }
+
     #[cfg(feature = "diff")]
     /// Calculate the n-th discrete difference between values.
     pub fn diff(self, n: Expr, null_behavior: NullBehavior) -> Expr {
@@ -1502,6 +1694,25 @@ impl Expr {
         self.map_unary(FunctionExpr::Kurtosis(fisher, bias))
     }
 
+    #[cfg(feature = "cov")]
+    /// Compute the Pearson autocorrelation at the given `lag`.
+    ///
+    /// This is the correlation of the values with the values `lag` positions earlier.
+    pub fn autocorr(self, lag: usize) -> Expr {
+        self.map_unary(FunctionExpr::Autocorr { lag })
+    }
+
+    #[cfg(feature = "signal")]
+    /// Smooth with a Savitzky-Golay filter of the given window size and polynomial order.
+    ///
+    /// The values are all null if there are fewer than `window_size` of them.
+    pub fn savgol_filter(self, window_size: usize, poly_order: usize) -> Expr {
+        self.map_unary(FunctionExpr::SavgolFilter {
+            window_size,
+            poly_order,
+        })
+    }
+
     /// Get maximal value that could be hold by this dtype.
     pub fn upper_bound(self) -> Expr {
         self.map_unary(FunctionExpr::UpperBound)
@@ -1533,6 +1744,16 @@ impl Expr {
         self.map_binary(FunctionExpr::EwmMeanBy { half_life }, times)
     }
 
+    #[cfg(feature = "session_id")]
+    /// Assign a session index to every event, treating this expression as the event times.
+    ///
+    /// A new session starts whenever the time since the previous event exceeds `gap`. Use within
+    /// `over` to number the sessions per partition. Unsorted times are sorted first if `sort` is
+    /// set and raise an error otherwise.
+    pub fn session_id(self, gap: Duration, sort: bool) -> Self {
+        self.map_unary(FunctionExpr::SessionId { gap, sort })
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving standard deviation.
     pub fn ewm_std(self, options: EWMOptions) -> Self {
@@ -1595,6 +1816,17 @@ impl Expr {
         self.map_unary(FunctionExpr::UniqueCounts)
     }
 
+    #[cfg(feature = "unique_counts")]
+    /// Returns the unique values together with their counts as a `List(Struct{value, count})`,
+    /// in the order of first appearance.
+    ///
+    /// This is an aggregation, in a group-by it returns one list per group. Unlike combining
+    /// [`Expr::unique_stable`] with [`Expr::unique_counts`] the values and counts are computed in
+    /// a single pass.
+    pub fn unique_with_counts(self) -> Self {
+        self.map_unary(FunctionExpr::UniqueWithCounts)
+    }
+
     #[cfg(feature = "log")]
     /// Compute the logarithm to a given base.
     pub fn log(self, base: f64) -> Self {
@@ -3,6 +3,8 @@ use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::*;
+#[cfg(feature = "dtype-struct")]
+use crate::dsl::function_expr::{map_field_names, map_field_names_dtype};
 
 /// Specialized expressions for modifying the name of existing expressions.
 pub struct ExprNameNameSpace(pub(crate) Expr);
@@ -61,52 +63,76 @@ impl ExprNameNameSpace {
         self.map(move |name| Ok(PlSmallStr::from_string(name.to_uppercase())))
     }
 
+    /// Rename the fields of a struct column with `function`.
+    ///
+    /// The new field names are part of the schema, so they can be resolved by `struct.field` and
+    /// `unnest`. See [`ExprNameNameSpace::map_fields_recursive`] to rename nested fields as well.
     #[cfg(feature = "dtype-struct")]
     pub fn map_fields(self, function: FieldsNameMapper) -> Expr {
+        self.map_fields_impl(function, false)
+    }
+
+    /// Rename the fields of a struct column and of all structs nested in it with `function`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn map_fields_recursive(self, function: FieldsNameMapper) -> Expr {
+        self.map_fields_impl(function, true)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn map_fields_impl(self, function: FieldsNameMapper, recursive: bool) -> Expr {
         let f = function.clone();
         self.0.map(
             move |s| {
-                let s = s.struct_()?;
-                let fields = s
-                    .fields_as_series()
-                    .iter()
-                    .map(|fd| {
-                        let mut fd = fd.clone();
-                        fd.rename(function(fd.name()));
-                        fd
-                    })
-                    .collect::<Vec<_>>();
-                let mut out = StructChunked::from_series(s.name().clone(), s.len(), fields.iter())?;
-                out.zip_outer_validity(s);
+                let out = map_field_names(s.struct_()?, &*function, recursive)?;
                 Ok(Some(out.into_column()))
             },
             GetOutput::map_dtype(move |dt| match dt {
-                DataType::Struct(fds) => {
-                    let fields = fds
-                        .iter()
-                        .map(|fd| Field::new(f(fd.name()), fd.dtype().clone()))
-                        .collect();
-                    Ok(DataType::Struct(fields))
-                },
-                _ => panic!("Only struct dtype is supported for `map_fields`."),
+                DataType::Struct(_) => Ok(map_field_names_dtype(dt, &*f, recursive)),
+                _ => polars_bail!(op = "map_fields", got = dt, expected = "Struct"),
             }),
         )
     }
 
+    /// Add a prefix to the field names of a struct column, see [`ExprNameNameSpace::map_fields`].
     #[cfg(feature = "dtype-struct")]
     pub fn prefix_fields(self, prefix: &str) -> Expr {
+        self.prefix_fields_impl(prefix, false)
+    }
+
+    /// Add a prefix to the field names of a struct column and of all structs nested in it.
+    #[cfg(feature = "dtype-struct")]
+    pub fn prefix_fields_recursive(self, prefix: &str) -> Expr {
+        self.prefix_fields_impl(prefix, true)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn prefix_fields_impl(self, prefix: &str, recursive: bool) -> Expr {
         self.0
-            .map_unary(FunctionExpr::StructExpr(StructFunction::PrefixFields(
-                PlSmallStr::from_str(prefix),
-            )))
+            .map_unary(FunctionExpr::StructExpr(StructFunction::PrefixFields {
+                prefix: PlSmallStr::from_str(prefix),
+                recursive,
+            }))
     }
 
+    /// Add a suffix to the field names of a struct column, see [`ExprNameNameSpace::map_fields`].
     #[cfg(feature = "dtype-struct")]
     pub fn suffix_fields(self, suffix: &str) -> Expr {
+        self.suffix_fields_impl(suffix, false)
+    }
+
+    /// Add a suffix to the field names of a struct column and of all structs nested in it.
+    #[cfg(feature = "dtype-struct")]
+    pub fn suffix_fields_recursive(self, suffix: &str) -> Expr {
+        self.suffix_fields_impl(suffix, true)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    fn suffix_fields_impl(self, suffix: &str, recursive: bool) -> Expr {
         self.0
-            .map_unary(FunctionExpr::StructExpr(StructFunction::SuffixFields(
-                PlSmallStr::from_str(suffix),
-            )))
+            .map_unary(FunctionExpr::StructExpr(StructFunction::SuffixFields {
+                suffix: PlSmallStr::from_str(suffix),
+                recursive,
+            }))
     }
 }
 
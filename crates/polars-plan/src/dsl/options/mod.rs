@@ -183,6 +183,20 @@ pub enum WindowMapping {
     Join,
 }
 
+/// The result of an aggregation over a group without non-null values, see [`Expr::sum_with`].
+///
+/// [`Expr::sum_with`]: crate::dsl::Expr::sum_with
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum EmptyGroupPolicy {
+    /// The identity of the aggregation, e.g. `0` for `sum` and `false` for `any`.
+    #[default]
+    Identity,
+    /// Null, like `min` and `max`.
+    Null,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NestedType {
@@ -252,6 +266,9 @@ pub struct UnionOptions {
     pub flattened_by_opt: bool,
     pub rechunk: bool,
     pub maintain_order: bool,
+    // The user asked not to maintain the order, so the streaming engine may interleave the
+    // inputs. Unlike `maintain_order` this is not changed by the optimizer.
+    pub interleave: bool,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -374,6 +391,12 @@ pub struct UnionArgs {
     // If it is a union from a scan over multiple files.
     pub from_partitioned_ds: bool,
     pub maintain_order: bool,
+    /// Add a `UInt32` column named `source_index` holding the index of the input each row came
+    /// from.
+    ///
+    /// Only supported for vertical concatenation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub include_source_index: bool,
 }
 
 impl Default for UnionArgs {
@@ -385,6 +408,7 @@ impl Default for UnionArgs {
             diagonal: false,
             from_partitioned_ds: false,
             maintain_order: true,
+            include_source_index: false,
         }
     }
 }
@@ -399,6 +423,7 @@ impl From<UnionArgs> for UnionOptions {
             flattened_by_opt: false,
             rechunk: args.rechunk,
             maintain_order: args.maintain_order,
+            interleave: !args.maintain_order,
         }
     }
 }
@@ -1,9 +1,10 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use polars_core::error::PolarsResult;
+use polars_core::error::{PolarsResult, polars_err};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::DataType;
 use polars_core::scalar::Scalar;
@@ -30,6 +31,25 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// Start a new file once this many rows have been written to the current one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_rows_per_file: Option<IdxSize>,
+
+    /// Start a new file once roughly this many bytes have been written to the current one.
+    ///
+    /// This is measured on the in-memory size of the data handed to the writer, so encoding and
+    /// compression will make the files on disk differ from this.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_bytes_per_file: Option<u64>,
+
+    /// The file name of the rotated files, e.g. `out_{:05}.csv`.
+    ///
+    /// The `{}` or `{:0N}` placeholder is replaced by the file counter, zero-padded to `N` digits.
+    /// Files are created in the directory of the sink path. If not given, the counter is inserted
+    /// before the extension of the sink path.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub file_name_template: Option<PlSmallStr>,
 }
 
 impl Default for SinkOptions {
@@ -38,10 +58,62 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+            file_name_template: None,
         }
     }
 }
 
+impl SinkOptions {
+    /// Whether the output should be split over multiple files.
+    pub fn is_rotating(&self) -> bool {
+        self.max_rows_per_file.is_some() || self.max_bytes_per_file.is_some()
+    }
+
+    /// Get the path of the `file_idx`-th rotated file for a sink writing to `path`.
+    pub fn rotated_file_path(&self, path: &Path, file_idx: usize) -> PolarsResult<PathBuf> {
+        let file_name = match &self.file_name_template {
+            Some(template) => format_file_name_template(template, file_idx)?,
+            None => {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                match path.extension() {
+                    Some(ext) => format!("{stem}_{file_idx:05}.{}", ext.to_string_lossy()),
+                    None => format!("{stem}_{file_idx:05}"),
+                }
+            },
+        };
+        Ok(path.with_file_name(file_name))
+    }
+}
+
+/// Replace the single `{}` or `{:0N}` placeholder in `template` with `file_idx`.
+fn format_file_name_template(template: &str, file_idx: usize) -> PolarsResult<String> {
+    let invalid = || {
+        polars_err!(
+            InvalidOperation:
+            "file name template must contain exactly one '{{}}' or '{{:0N}}' placeholder, got '{}'",
+            template
+        )
+    };
+
+    let start = template.find('{').ok_or_else(invalid)?;
+    let end = start + template[start..].find('}').ok_or_else(invalid)?;
+    let (prefix, suffix) = (&template[..start], &template[end + 1..]);
+    if prefix.contains(['{', '}']) || suffix.contains(['{', '}']) {
+        return Err(invalid());
+    }
+
+    let width = match &template[start + 1..end] {
+        "" => 0,
+        spec => spec
+            .strip_prefix(":0")
+            .and_then(|w| w.parse::<usize>().ok())
+            .ok_or_else(invalid)?,
+    };
+    Ok(format!("{prefix}{file_idx:0width$}{suffix}"))
+}
+
 type DynSinkTarget = SpecialEq<Arc<std::sync::Mutex<Option<Box<dyn DynWriteable>>>>>;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -185,6 +257,7 @@ pub enum SinkTypeIR {
     Memory,
     File(FileSinkType),
     Partition(PartitionSinkTypeIR),
+    Callback(CallbackSinkType),
 }
 
 #[cfg_attr(feature = "python", pyo3::pyclass)]
@@ -326,6 +399,84 @@ impl PartitionTargetCallback {
     }
 }
 
+/// Called with every batch of a callback sink. Returning `true` stops the query.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SinkBatchCallback {
+    Rust(SpecialEq<Arc<dyn Fn(DataFrame) -> PolarsResult<bool> + Send + Sync>>),
+}
+
+impl SinkBatchCallback {
+    pub fn call(&self, df: DataFrame) -> PolarsResult<bool> {
+        match self {
+            Self::Rust(f) => f(df),
+        }
+    }
+}
+
+impl std::hash::Hash for SinkBatchCallback {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Rust(f) => Arc::as_ptr(f).hash(state),
+        }
+    }
+}
+
+impl std::hash::Hash for CallbackSinkType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.function.hash(state);
+        self.maintain_order.hash(state);
+        self.chunk_size.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SinkBatchCallback {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {self:?}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SinkBatchCallback {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize SinkBatchCallback"))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for SinkBatchCallback {
+    fn schema_name() -> String {
+        "SinkBatchCallback".to_owned()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "SinkBatchCallback"))
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// Sink that passes the output of the query in batches to a function.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallbackSinkType {
+    pub function: SinkBatchCallback,
+    pub maintain_order: bool,
+    /// Number of rows per batch. If `None`, the batches follow the morsels of the engine.
+    pub chunk_size: Option<NonZeroUsize>,
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for SinkFinishCallback {
     fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
@@ -485,6 +636,7 @@ pub enum SinkType {
     Memory,
     File(FileSinkType),
     Partition(PartitionSinkType),
+    Callback(CallbackSinkType),
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -524,6 +676,7 @@ impl SinkTypeIR {
             Self::Memory => {},
             Self::File(f) => f.hash(state),
             Self::Partition(f) => f.traverse_and_hash(expr_arena, state),
+            Self::Callback(f) => f.hash(state),
         }
     }
 }
@@ -583,3 +736,59 @@ pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_file_name_template() {
+        assert_eq!(
+            format_file_name_template("out_{}.csv", 7).unwrap(),
+            "out_7.csv"
+        );
+        assert_eq!(
+            format_file_name_template("out_{:05}.csv", 7).unwrap(),
+            "out_00007.csv"
+        );
+        assert_eq!(format_file_name_template("{:02}", 123).unwrap(), "123");
+
+        for template in [
+            "out.csv",
+            "out_{.csv",
+            "out_}.csv",
+            "out_{:5}.csv",
+            "out_{:0x}.csv",
+            "out_{}_{}.csv",
+            "out_{}}.csv",
+        ] {
+            assert!(
+                format_file_name_template(template, 0).is_err(),
+                "{template}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotated_file_path() {
+        let path = Path::new("dir/out.parquet");
+        let mut options = SinkOptions::default();
+        assert_eq!(
+            options.rotated_file_path(path, 3).unwrap(),
+            Path::new("dir/out_00003.parquet")
+        );
+        assert_eq!(
+            options.rotated_file_path(Path::new("dir/out"), 3).unwrap(),
+            Path::new("dir/out_00003")
+        );
+
+        options.file_name_template = Some("part-{:03}.parquet".into());
+        assert_eq!(
+            options.rotated_file_path(path, 3).unwrap(),
+            Path::new("dir/part-003.parquet")
+        );
+
+        options.file_name_template = Some("part.parquet".into());
+        assert!(options.rotated_file_path(path, 3).is_err());
+    }
+}
@@ -48,9 +48,78 @@ use super::*;
 // - changing a name, type, or meaning of a field or an enum variant
 // - changing a default value of a field or a default enum variant
 // - restricting the range of allowed values a field can have
-pub static DSL_VERSION: (u16, u16) = (6, 0);
+pub static DSL_VERSION: (u16, u16) = (12, 2);
 static DSL_MAGIC_BYTES: &[u8] = b"DSL_VERSION";
 
+/// The JSON format of a [`DslPlan`], with the [`DSL_VERSION`] it was written with.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct VersionedJson<P> {
+    dsl_version: (u16, u16),
+    plan: P,
+}
+
+#[cfg(feature = "serde")]
+fn check_dsl_version(major: u16, minor: u16) -> PolarsResult<()> {
+    const MAJOR: u16 = DSL_VERSION.0;
+    const MINOR: u16 = DSL_VERSION.1;
+
+    if polars_core::config::verbose() {
+        eprintln!(
+            "incoming DSL_VERSION: {major}.{minor}, deserializer DSL_VERSION: {MAJOR}.{MINOR}"
+        );
+    }
+
+    if major != MAJOR {
+        polars_bail!(ComputeError:
+            "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is not compatible with this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}",
+            "error: can't deserialize DSL with a different major version"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn dsl_deserialization_error(major: u16, minor: u16, e: impl fmt::Display) -> PolarsError {
+    const MAJOR: u16 = DSL_VERSION.0;
+    const MINOR: u16 = DSL_VERSION.1;
+
+    // The DSL serialization is forward compatible if there are no unknown fields
+    if minor > MINOR {
+        // Convey that the failure might also be due to broken forward compatibility
+        polars_err!(ComputeError:
+            "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is higher than this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}\nerror: {e}",
+            "either the input is malformed, or the plan requires functionality not supported in this Polars version"
+        )
+    } else {
+        polars_err!(ComputeError:
+            "deserialization failed\n\nerror: {e}",
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+fn check_unknown_fields(major: u16, minor: u16, unknown_fields: Vec<String>) -> PolarsResult<()> {
+    const MAJOR: u16 = DSL_VERSION.0;
+    const MINOR: u16 = DSL_VERSION.1;
+
+    if !unknown_fields.is_empty() {
+        if minor > MINOR {
+            polars_bail!(ComputeError:
+                "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is higher than this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}\nencountered unknown fields: {:?}",
+                "the plan requires functionality not supported in this Polars version",
+                unknown_fields,
+            )
+        } else {
+            polars_bail!(ComputeError:
+                "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} should be supported in this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\nencountered unknown fields: {:?}",
+                unknown_fields,
+            )
+        }
+    }
+    Ok(())
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum DslPlan {
@@ -181,6 +250,9 @@ pub enum DslPlan {
         input_left: Arc<DslPlan>,
         input_right: Arc<DslPlan>,
         key: PlSmallStr,
+        /// Whether the nulls of both keys are placed last instead of first.
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(default))]
+        nulls_last: bool,
     },
     IR {
         // Keep the original Dsl around as we need that for serialization.
@@ -220,7 +292,7 @@ impl Clone for DslPlan {
             Self::Sink { input, payload } => Self::Sink { input: input.clone(), payload: payload.clone() },
             Self::SinkMultiple { inputs } => Self::SinkMultiple { inputs: inputs.clone() },
             #[cfg(feature = "merge_sorted")]
-            Self::MergeSorted { input_left, input_right, key } => Self::MergeSorted { input_left: input_left.clone(), input_right: input_right.clone(), key: key.clone() },
+            Self::MergeSorted { input_left, input_right, key, nulls_last } => Self::MergeSorted { input_left: input_left.clone(), input_right: input_right.clone(), key: key.clone(), nulls_last: *nulls_last },
             Self::IR {node, dsl, version} => Self::IR {node: *node, dsl: dsl.clone(), version: *version},
         }
     }
@@ -300,53 +372,103 @@ impl DslPlan {
                 .unwrap(),
         );
 
-        const MAJOR: u16 = DSL_VERSION.0;
-        const MINOR: u16 = DSL_VERSION.1;
+        check_dsl_version(major, minor)?;
+        let (dsl, unknown_fields) = pl_serialize::SerializeOptions::default()
+            .deserialize_from_reader_with_unknown_fields(reader)
+            .map_err(|e| dsl_deserialization_error(major, minor, e))?;
+        check_unknown_fields(major, minor, unknown_fields)?;
 
-        if polars_core::config::verbose() {
-            eprintln!(
-                "incoming DSL_VERSION: {major}.{minor}, deserializer DSL_VERSION: {MAJOR}.{MINOR}"
-            );
-        }
+        Ok(dsl)
+    }
 
-        if major != MAJOR {
-            polars_bail!(ComputeError:
-                "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is not compatible with this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}",
-                "error: can't deserialize DSL with a different major version"
-            );
-        }
+    /// Serialize this plan into a JSON string.
+    ///
+    /// Scans keep referring to their sources by path, so the plan can be restored with
+    /// [`DslPlan::from_json`] by any process that has access to the same files. Like the binary
+    /// format, the JSON carries the [`DSL_VERSION`] it was written with.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> PolarsResult<String> {
+        let versioned = VersionedJson {
+            dsl_version: DSL_VERSION,
+            plan: self,
+        };
+        serde_json::to_string(&versioned).map_err(polars_core::error::to_compute_err)
+    }
 
-        let (dsl, unknown_fields) = pl_serialize::SerializeOptions::default().deserialize_from_reader_with_unknown_fields(reader).map_err(|e| {
-            // The DSL serialization is forward compatible if there are no unknown fields
-            if minor > MINOR {
-                // Convey that the failure might also be due to broken forward compatibility
-                polars_err!(ComputeError:
-                    "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is higher than this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}\nerror: {e}",
-                    "either the input is malformed, or the plan requires functionality not supported in this Polars version"
-                )
-            } else {
-                polars_err!(ComputeError:
-                    "deserialization failed\n\nerror: {e}",
-                )
-            }
-        })?;
-
-        if !unknown_fields.is_empty() {
-            if minor > MINOR {
-                polars_bail!(ComputeError:
-                    "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is higher than this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\n{}\nencountered unknown fields: {:?}",
-                    "the plan requires functionality not supported in this Polars version",
-                    unknown_fields,
-                )
-            } else {
-                polars_bail!(ComputeError:
-                    "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} should be supported in this Polars version which uses DSL_VERSION: {MAJOR}.{MINOR}\nencountered unknown fields: {:?}",
-                    unknown_fields,
-                )
-            }
+    /// Deserialize a plan from a JSON string created by [`DslPlan::to_json`].
+    ///
+    /// Fails on the same version mismatches as [`DslPlan::deserialize_versioned`].
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        #[derive(Deserialize)]
+        struct Version {
+            dsl_version: (u16, u16),
         }
 
-        Ok(dsl)
+        // Check the version before the plan, as a plan of another version may not parse.
+        let Version {
+            dsl_version: (major, minor),
+        } = serde_json::from_str(json)
+            .map_err(|e| polars_err!(ComputeError: "failed to read incoming DSL_VERSION: {e}"))?;
+        check_dsl_version(major, minor)?;
+
+        let mut unknown_fields = vec![];
+        let versioned: VersionedJson<DslPlan> =
+            serde_ignored::deserialize(&mut serde_json::Deserializer::from_str(json), |path| {
+                unknown_fields.push(path.to_string())
+            })
+            .map_err(|e| dsl_deserialization_error(major, minor, e))?;
+        check_unknown_fields(major, minor, unknown_fields)?;
+
+        Ok(versioned.plan)
+    }
+
+    /// Push mutable references to the direct inputs of this node to `scratch`.
+    ///
+    /// Shared inputs are cloned first, so changes don't leak into other plans.
+    pub fn inputs_mut<'a>(&'a mut self, scratch: &mut Vec<&'a mut DslPlan>) {
+        use DslPlan::*;
+        match self {
+            Select { input, .. }
+            | GroupBy { input, .. }
+            | Filter { input, .. }
+            | Distinct { input, .. }
+            | Sort { input, .. }
+            | Slice { input, .. }
+            | HStack { input, .. }
+            | MatchToSchema { input, .. }
+            | MapFunction { input, .. }
+            | Sink { input, .. }
+            | Cache { input, .. } => scratch.push(Arc::make_mut(input)),
+            Union { inputs, .. } | HConcat { inputs, .. } | SinkMultiple { inputs } => {
+                scratch.extend(inputs)
+            },
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                scratch.push(Arc::make_mut(input_left));
+                scratch.push(Arc::make_mut(input_right));
+            },
+            ExtContext { input, contexts } => {
+                scratch.push(Arc::make_mut(input));
+                scratch.extend(contexts);
+            },
+            IR { dsl, .. } => scratch.push(Arc::make_mut(dsl)),
+            Scan { .. } | DataFrameScan { .. } => (),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => (),
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                scratch.push(Arc::make_mut(input_left));
+                scratch.push(Arc::make_mut(input_right));
+            },
+        }
     }
 
     #[cfg(feature = "dsl-schema")]
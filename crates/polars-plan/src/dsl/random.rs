@@ -21,6 +21,7 @@ impl Expr {
                     is_fraction: false,
                     with_replacement,
                     shuffle,
+                    seed_per_group: false,
                 },
                 seed,
             },
@@ -41,10 +42,53 @@ impl Expr {
                     is_fraction: true,
                     with_replacement,
                     shuffle,
+                    seed_per_group: false,
                 },
                 seed,
             },
             frac,
         )
     }
+
+    /// Sample every group in a `group_by().agg()` context.
+    ///
+    /// `by` are the keys of the group-by. Without replacement, [`StratifiedSpec::N`] is capped at
+    /// the size of the group. The seed of every group is `seed` combined with the hash of its key,
+    /// as in [`DataFrame::sample_stratified`], so the sample of a group doesn't depend on the order
+    /// of the groups. All columns sampled with this expression are still taken at the same rows.
+    pub fn sample_per_group<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        spec: StratifiedSpec,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        let (is_fraction, n) = match spec {
+            StratifiedSpec::N(n) => {
+                let n = lit(n as IdxSize);
+                let n = if with_replacement {
+                    n
+                } else {
+                    let len = self.clone().len();
+                    when(len.clone().lt(n.clone())).then(len).otherwise(n)
+                };
+                (false, n)
+            },
+            StratifiedSpec::Fraction(frac) => (true, lit(frac)),
+        };
+        let by = by.as_ref().iter().map(|e| e.clone().into());
+        self.map_n_ary(
+            FunctionExpr::Random {
+                method: RandomMethod::Sample {
+                    is_fraction,
+                    with_replacement,
+                    shuffle,
+                    seed_per_group: true,
+                },
+                seed,
+            },
+            std::iter::once(n).chain(by),
+        )
+    }
 }
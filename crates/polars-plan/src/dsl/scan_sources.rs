@@ -9,7 +9,9 @@ use polars_io::cloud::CloudOptions;
 use polars_io::file_cache::FileCacheEntry;
 #[cfg(feature = "cloud")]
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder};
-use polars_io::{expand_paths, expand_paths_hive, expanded_from_single_directory};
+use polars_io::{
+    HiveListingFilter, expand_paths, expand_paths_hive, expanded_from_single_directory,
+};
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -175,11 +177,14 @@ impl ScanSources {
 
     /// This will update `scan_args.hive_options.enabled` to `true` if the existing value is `None`
     /// and the paths are expanded from a single directory. Otherwise the existing value is maintained.
+    ///
+    /// If a `listing_filter` is given, only the matching hive partitions of directories are listed.
     #[cfg(any(feature = "ipc", feature = "parquet"))]
     pub fn expand_paths_with_hive_update(
         &self,
         scan_args: &mut UnifiedScanArgs,
         #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+        listing_filter: Option<&HiveListingFilter>,
     ) -> PolarsResult<Self> {
         match self {
             Self::Paths(paths) => {
@@ -188,8 +193,15 @@ impl ScanSources {
                     scan_args.glob,
                     cloud_options,
                     scan_args.hive_options.enabled.unwrap_or(false),
+                    listing_filter,
                 )?;
 
+                if expanded_paths.is_empty() && listing_filter.is_some() {
+                    // No partition matched, list everything so that the schema can still be
+                    // resolved. The filter on the scan then produces an empty result.
+                    return self.expand_paths_with_hive_update(scan_args, cloud_options, None);
+                }
+
                 if scan_args.hive_options.enabled.is_none()
                     && expanded_from_single_directory(paths, expanded_paths.as_ref())
                 {
@@ -305,6 +317,27 @@ impl ScanSourceRef<'_> {
         }
     }
 
+    /// Get the size in bytes and the modification time in milliseconds since the Unix epoch.
+    ///
+    /// Both are `None` for cloud paths, the modification time is also `None` for buffers.
+    pub fn size_and_mtime(&self) -> PolarsResult<(Option<u64>, Option<i64>)> {
+        let metadata = match self {
+            Self::Path(path) if polars_io::is_cloud_url(path) => return Ok((None, None)),
+            Self::Path(path) => std::fs::metadata(path)
+                .map_err(|err| polars_utils::io::_limit_path_len_io_err(path, err))?,
+            Self::File(file) => file.metadata()?,
+            Self::Buffer(buffer) => return Ok((Some(buffer.len() as u64), None)),
+        };
+
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|x| x.duration_since(std::time::UNIX_EPOCH).ok())
+            .and_then(|x| i64::try_from(x.as_millis()).ok());
+
+        Ok((Some(metadata.len()), mtime))
+    }
+
     // @TODO: I would like to remove this function eventually.
     pub fn into_owned(&self) -> PolarsResult<ScanSource> {
         Ok(match self {
@@ -325,6 +358,24 @@ impl ScanSourceRef<'_> {
         self.to_memslice_possibly_async(false, None, 0)
     }
 
+    #[cfg(feature = "cloud")]
+    fn open_path_async<F: Fn(Arc<FileCacheEntry>) -> PolarsResult<std::fs::File>>(
+        path: &Path,
+        assume: F,
+        run_async: bool,
+    ) -> PolarsResult<File> {
+        // This isn't filled if we modified the DSL (e.g. in cloud)
+        let entry = path
+            .to_str()
+            .filter(|_| run_async)
+            .and_then(|path_str| polars_io::file_cache::FILE_CACHE.get_entry(path_str));
+
+        match entry {
+            Some(entry) => assume(entry),
+            None => polars_utils::open_file(path),
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "cloud")]
     fn to_memslice_async<F: Fn(Arc<FileCacheEntry>) -> PolarsResult<std::fs::File>>(
@@ -334,22 +385,7 @@ impl ScanSourceRef<'_> {
     ) -> PolarsResult<MemSlice> {
         match self {
             ScanSourceRef::Path(path) => {
-                let path_str = path.to_str();
-                let file = if run_async && path_str.is_some() {
-                    feature_gated!("cloud", {
-                        // This isn't filled if we modified the DSL (e.g. in cloud)
-                        let entry = polars_io::file_cache::FILE_CACHE.get_entry(path_str.unwrap());
-
-                        if let Some(entry) = entry {
-                            assume(entry)?
-                        } else {
-                            polars_utils::open_file(path)?
-                        }
-                    })
-                } else {
-                    polars_utils::open_file(path)?
-                };
-
+                let file = Self::open_path_async(path, assume, run_async)?;
                 MemSlice::from_file(&file)
             },
             ScanSourceRef::File(file) => MemSlice::from_file(file),
@@ -357,6 +393,20 @@ impl ScanSourceRef<'_> {
         }
     }
 
+    /// Open the file backing this source, or `None` if it is an in-memory buffer.
+    #[cfg(feature = "cloud")]
+    pub fn to_file_async_check_latest(&self, run_async: bool) -> PolarsResult<Option<File>> {
+        match self {
+            ScanSourceRef::Path(path) => Ok(Some(Self::open_path_async(
+                path,
+                |entry| entry.try_open_check_latest(),
+                run_async,
+            )?)),
+            ScanSourceRef::File(file) => Ok(Some(file.try_clone()?)),
+            ScanSourceRef::Buffer(_) => Ok(None),
+        }
+    }
+
     #[cfg(feature = "cloud")]
     pub fn to_memslice_async_assume_latest(&self, run_async: bool) -> PolarsResult<MemSlice> {
         self.to_memslice_async(|entry| entry.try_open_assume_latest(), run_async)
@@ -389,6 +439,16 @@ impl ScanSourceRef<'_> {
         self.to_memslice_async(run_async)
     }
 
+    /// Open the file backing this source, or `None` if it is an in-memory buffer.
+    #[cfg(not(feature = "cloud"))]
+    pub fn to_file_async_check_latest(&self, _run_async: bool) -> PolarsResult<Option<File>> {
+        match self {
+            ScanSourceRef::Path(path) => Ok(Some(polars_utils::open_file(path)?)),
+            ScanSourceRef::File(file) => Ok(Some(file.try_clone()?)),
+            ScanSourceRef::Buffer(_) => Ok(None),
+        }
+    }
+
     pub fn to_memslice_possibly_async(
         &self,
         run_async: bool,
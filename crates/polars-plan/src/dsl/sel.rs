@@ -0,0 +1,100 @@
+//! Selectors pick columns by dtype, name or position.
+//!
+//! They are resolved against the schema when the plan is built, so the selected columns show up
+//! in `explain()`. Selectors can be combined with set operations, see [`Selector`], and used
+//! wherever expressions (with [`Selector::as_expr`]) or selectors are accepted.
+use super::*;
+
+/// Select all columns.
+pub fn all() -> Selector {
+    Selector::new(Expr::Wildcard)
+}
+
+/// Select the columns with the given names.
+pub fn by_name<I, S>(names: I) -> Selector
+where
+    I: IntoIterator<Item = S>,
+    S: Into<PlSmallStr>,
+{
+    Selector::new(cols(names))
+}
+
+/// Select the columns of any of the given dtypes.
+pub fn by_dtype<DT: AsRef<[DataType]>>(dtypes: DT) -> Selector {
+    Selector::new(dtype_cols(dtypes))
+}
+
+/// Select the integer columns.
+pub fn integer() -> Selector {
+    use DataType::*;
+    by_dtype([
+        Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64,
+    ])
+}
+
+/// Select the float columns.
+pub fn float() -> Selector {
+    by_dtype([DataType::Float32, DataType::Float64])
+}
+
+/// Select the decimal columns, of any precision and scale.
+#[cfg(feature = "dtype-decimal")]
+pub fn decimal() -> Selector {
+    by_dtype([DataType::Decimal(None, None)])
+}
+
+/// Select the integer, float and decimal columns.
+pub fn numeric() -> Selector {
+    let numeric = integer() + float();
+    #[cfg(feature = "dtype-decimal")]
+    let numeric = numeric + decimal();
+    numeric
+}
+
+/// Select the columns at the given positions, negative positions count from the end.
+pub fn by_index<N: AsRef<[i64]>>(indices: N) -> Selector {
+    match indices.as_ref() {
+        // A single index column is only resolved as `nth`.
+        &[i] => Selector::new(nth(i)),
+        _ => Selector::new(index_cols(indices)),
+    }
+}
+
+/// Select the first `n` columns, or all columns if there are fewer. A [`Selector::strict`]
+/// selector errors instead.
+pub fn first(n: usize) -> Selector {
+    by_index((0..n as i64).collect::<Vec<_>>())
+}
+
+/// Select the last `n` columns. Errors if there are fewer than `n` columns.
+pub fn last(n: usize) -> Selector {
+    by_index((-(n as i64)..0).collect::<Vec<_>>())
+}
+
+/// Select the columns whose name matches the regular expression `pattern`.
+///
+/// The pattern is not anchored unless it starts with `^` or ends with `$`.
+#[cfg(feature = "regex")]
+pub fn matches(pattern: &str) -> Selector {
+    let prefix = if pattern.starts_with('^') { "" } else { "^.*" };
+    let suffix = if pattern.ends_with('$') { "" } else { ".*$" };
+    Selector::new(col(format!("{prefix}{pattern}{suffix}")))
+}
+
+/// Select the columns whose name starts with `prefix`.
+#[cfg(feature = "regex")]
+pub fn starts_with(prefix: &str) -> Selector {
+    matches(&format!("^{}", regex::escape(prefix)))
+}
+
+/// Select the columns whose name ends with `suffix`.
+#[cfg(feature = "regex")]
+pub fn ends_with(suffix: &str) -> Selector {
+    matches(&format!("{}$", regex::escape(suffix)))
+}
+
+/// Select the columns whose name contains `substring`.
+#[cfg(feature = "regex")]
+pub fn contains(substring: &str) -> Selector {
+    matches(&regex::escape(substring))
+}
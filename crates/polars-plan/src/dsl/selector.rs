@@ -13,6 +13,8 @@ pub enum Selector {
     Sub(Box<Selector>, Box<Selector>),
     ExclusiveOr(Box<Selector>, Box<Selector>),
     Intersect(Box<Selector>, Box<Selector>),
+    /// Errors if the inner selector matches no columns or a column position out of range.
+    Strict(Box<Selector>),
     Root(Box<Expr>),
 }
 
@@ -20,6 +22,41 @@ impl Selector {
     pub fn new(e: Expr) -> Self {
         Self::Root(Box::new(e))
     }
+
+    /// Select the columns matched by either selector.
+    pub fn union(self, other: Selector) -> Self {
+        self + other
+    }
+
+    /// Select the columns matched by both selectors.
+    pub fn intersect(self, other: Selector) -> Self {
+        self & other
+    }
+
+    /// Select the columns matched by this selector but not by `other`.
+    pub fn difference(self, other: Selector) -> Self {
+        self - other
+    }
+
+    /// Select the columns matched by exactly one of the selectors.
+    pub fn exclusive_or(self, other: Selector) -> Self {
+        self ^ other
+    }
+
+    /// Select all columns not matched by this selector.
+    pub fn complement(self) -> Self {
+        Selector::new(Expr::Wildcard) - self
+    }
+
+    /// Raise an error when the plan is built if this selector matches no columns, or selects a
+    /// column position that is out of range.
+    pub fn strict(self) -> Self {
+        Selector::Strict(Box::new(self))
+    }
+
+    pub fn as_expr(self) -> Expr {
+        Expr::Selector(self)
+    }
 }
 
 impl Add for Selector {
@@ -62,6 +62,41 @@ impl Expr {
         AggExpr::Sum(Arc::new(self)).into()
     }
 
+    /// Reduce groups to the sum of all the values, with the given result for groups without
+    /// non-null values.
+    pub fn sum_with(self, policy: EmptyGroupPolicy) -> Self {
+        let agg = self.clone().sum();
+        self.with_empty_group_policy(agg, policy)
+    }
+
+    /// Reduce groups to the product of all the values, with the given result for groups without
+    /// non-null values.
+    pub fn product_with(self, policy: EmptyGroupPolicy) -> Self {
+        let agg = self.clone().product();
+        self.with_empty_group_policy(agg, policy)
+    }
+
+    /// [`Expr::any`] with the given result for groups without non-null values.
+    pub fn any_with(self, ignore_nulls: bool, policy: EmptyGroupPolicy) -> Self {
+        let agg = self.clone().any(ignore_nulls);
+        self.with_empty_group_policy(agg, policy)
+    }
+
+    /// [`Expr::all`] with the given result for groups without non-null values.
+    pub fn all_with(self, ignore_nulls: bool, policy: EmptyGroupPolicy) -> Self {
+        let agg = self.clone().all(ignore_nulls);
+        self.with_empty_group_policy(agg, policy)
+    }
+
+    /// Applies the policy with plain expressions rather than in the kernels, so that the
+    /// in-memory, partitioned and streaming group-by agree by construction.
+    fn with_empty_group_policy(self, agg: Expr, policy: EmptyGroupPolicy) -> Self {
+        match policy {
+            EmptyGroupPolicy::Identity => agg,
+            EmptyGroupPolicy::Null => when(self.count().gt(lit(0))).then(agg).otherwise(lit(NULL)),
+        }
+    }
+
     /// Compute the histogram of a dataset.
     #[cfg(feature = "hist")]
     pub fn hist(
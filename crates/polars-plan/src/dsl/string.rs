@@ -440,6 +440,16 @@ impl StringNameSpace {
         self.0.map_binary(StringFunction::ToInteger(strict), base)
     }
 
+    #[cfg(feature = "string_to_integer")]
+    /// Parse strings into integers, see [`ToIntegerOptions`].
+    ///
+    /// Unlike [`StringNameSpace::to_integer`], the base can be detected per value from its
+    /// prefix. With `with_errors`, the result is a struct of the `value` and the `error` that
+    /// explains why a value couldn't be parsed, so failures can be counted or inspected.
+    pub fn to_integer_with(self, options: ToIntegerOptions) -> Expr {
+        self.0.map_unary(StringFunction::ToIntegerWith(options))
+    }
+
     /// Return the length of each string as the number of bytes.
     ///
     /// When working with non-ASCII text, the length in bytes is not the same
@@ -26,8 +26,13 @@ impl Hash for AExpr {
             AExpr::Agg(agg) => agg.hash(state),
             AExpr::SortBy { sort_options, .. } => sort_options.hash(state),
             AExpr::Cast {
-                options: strict, ..
-            } => strict.hash(state),
+                options: strict,
+                drop_metadata,
+                ..
+            } => {
+                strict.hash(state);
+                drop_metadata.hash(state)
+            },
             AExpr::Window { options, .. } => options.hash(state),
             AExpr::BinaryExpr { op, .. } => op.hash(state),
             _ => {},
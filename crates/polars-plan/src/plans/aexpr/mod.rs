@@ -158,6 +158,8 @@ pub enum AExpr {
         expr: Node,
         dtype: DataType,
         options: CastOptions,
+        /// Drop the user metadata of the input instead of keeping it.
+        drop_metadata: bool,
     },
     Sort {
         expr: Node,
@@ -262,6 +262,7 @@ impl ExprPushdownGroup {
                         expr,
                         dtype: _,
                         options: CastOptions::Strict,
+                        drop_metadata: _,
                     } => !matches!(expr_arena.get(*expr), AExpr::Literal(_)),
 
                     _ => false,
@@ -4,6 +4,11 @@ use polars_core::prelude::*;
 
 pub trait DataFrameUdf: Send + Sync {
     fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame>;
+
+    /// Whether this function may be called concurrently, e.g. on multiple groups at once.
+    fn allows_parallel(&self) -> bool {
+        false
+    }
 }
 
 impl<F> DataFrameUdf for F
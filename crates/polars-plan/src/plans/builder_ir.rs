@@ -283,6 +283,9 @@ impl<'a> IRBuilder<'a> {
             if let Some(options) = options.rolling.as_ref() {
                 let name = &options.index_column;
                 let dtype = current_schema.get(name).unwrap();
+                if options.include_window_id {
+                    schema.with_column("_window_id".into(), DataType::UInt64);
+                }
                 schema.with_column(name.clone(), dtype.clone());
             } else if let Some(options) = options.dynamic.as_ref() {
                 let name = &options.index_column;
@@ -291,6 +294,9 @@ impl<'a> IRBuilder<'a> {
                     schema.with_column("_lower_boundary".into(), dtype.clone());
                     schema.with_column("_upper_boundary".into(), dtype.clone());
                 }
+                if options.include_window_id {
+                    schema.with_column("_window_id".into(), DataType::UInt64);
+                }
                 schema.with_column(name.clone(), dtype.clone());
             }
         }
@@ -43,6 +43,30 @@ pub(super) fn convert_st_union(
     Ok(())
 }
 
+/// Add a `UInt32` column named `source_index` holding the position of the input to every input of
+/// a union.
+pub(super) fn add_union_source_index(
+    inputs: &mut [Node],
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let name = PlSmallStr::from_static("source_index");
+    for (i, input) in inputs.iter_mut().enumerate() {
+        let input_schema = lp_arena.get(*input).schema(lp_arena);
+        polars_ensure!(
+            !input_schema.contains(&name),
+            Duplicate: "source index column '{}' already exists in a concatenated frame", name
+        );
+
+        let expr = to_expr_irs(vec![lit(i as u32).alias(name.clone())], expr_arena)?;
+        let lp = IRBuilder::new(*input, expr_arena, lp_arena)
+            .with_columns(expr, Default::default())
+            .build();
+        *input = lp_arena.add(lp);
+    }
+    Ok(())
+}
+
 fn nodes_to_schemas(inputs: &[Node], lp_arena: &mut Arena<IR>) -> Vec<SchemaRef> {
     inputs
         .iter()
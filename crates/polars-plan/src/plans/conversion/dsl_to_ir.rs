@@ -80,10 +80,11 @@ pub fn to_alp(
         opt_flags,
         nodes_scratch: &mut unitvec![],
         pushdown_maintain_errors: optimizer::pushdown_maintain_errors(),
+        scan_predicate: None,
     };
 
     match to_alp_impl(lp, &mut ctxt) {
-        Ok(out) => Ok(out),
+        Ok(out) => drop_external_columns(out, lp_arena, expr_arena),
         Err(err) => {
             if opt_flags.contains(OptFlags::EAGER) {
                 // If we dispatched to the lazy engine from the eager API, we don't want to resolve
@@ -113,6 +114,51 @@ pub fn to_alp(
     }
 }
 
+/// Removes the columns of the frames registered with `LazyFrame::with_external` from the
+/// output of the plan.
+fn drop_external_columns(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    match lp_arena.get(root) {
+        // Sinks write their input, so the columns are dropped from the input.
+        IR::Sink { input, .. } => {
+            let input = drop_external_columns(*input, lp_arena, expr_arena)?;
+            if let IR::Sink { input: old, .. } = lp_arena.get_mut(root) {
+                *old = input;
+            }
+            return Ok(root);
+        },
+        IR::SinkMultiple { inputs } => {
+            let inputs = inputs
+                .clone()
+                .into_iter()
+                .map(|input| drop_external_columns(input, lp_arena, expr_arena))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            if let IR::SinkMultiple { inputs: old } = lp_arena.get_mut(root) {
+                *old = inputs;
+            }
+            return Ok(root);
+        },
+        _ => {},
+    }
+
+    let schema = lp_arena.get(root).schema(lp_arena).into_owned();
+    if !schema.iter_names().any(|name| is_external_column(name)) {
+        return Ok(root);
+    }
+    let names = schema
+        .iter_names()
+        .filter(|name| !is_external_column(name))
+        .cloned()
+        .collect::<Vec<_>>();
+    let ir = IRBuilder::new(root, expr_arena, lp_arena)
+        .project_simple(names)?
+        .build();
+    Ok(lp_arena.add(ir))
+}
+
 pub(super) struct DslConversionContext<'a> {
     pub(super) expr_arena: &'a mut Arena<AExpr>,
     pub(super) lp_arena: &'a mut Arena<IR>,
@@ -120,6 +166,9 @@ pub(super) struct DslConversionContext<'a> {
     pub(super) opt_flags: &'a mut OptFlags,
     pub(super) nodes_scratch: &'a mut UnitVec<Node>,
     pub(super) pushdown_maintain_errors: bool,
+    /// Predicate of a filter directly on top of the scan that is being converted. Used to only
+    /// list the matching hive partitions.
+    pub(super) scan_predicate: Option<Expr>,
 }
 
 pub(super) fn run_conversion(
@@ -150,11 +199,28 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             scan_type,
             cached_ir,
         } => {
+            let listing_filter = ctxt.scan_predicate.take().and_then(|predicate| {
+                let hive_schema = unified_scan_args_box.hive_options.schema.as_deref()?;
+                if unified_scan_args_box.hive_options.enabled == Some(false)
+                    || !ctxt.opt_flags.predicate_pushdown()
+                {
+                    return None;
+                }
+                hive::hive_listing_filter(
+                    &predicate,
+                    hive_schema,
+                    unified_scan_args_box.hive_options.try_parse_dates,
+                )
+            });
+
             // Note that the first metadata can still end up being `None` later if the files were
             // filtered from predicate pushdown.
             let mut cached_ir = cached_ir.lock().unwrap();
 
-            if cached_ir.is_none() {
+            // If only the matching hive partitions are listed, the result can't be cached for
+            // other queries on this scan.
+            #[cfg_attr(not(feature = "ipc"), allow(unused_mut))]
+            let mut ir = if cached_ir.is_none() || listing_filter.is_some() {
                 let cloud_options = unified_scan_args_box.cloud_options.clone();
                 let cloud_options = cloud_options.as_ref();
 
@@ -176,30 +242,35 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                 }
 
-                let sources =
-                    match &*scan_type {
-                        #[cfg(feature = "parquet")]
-                        FileScan::Parquet { .. } => sources
-                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
-                        #[cfg(feature = "ipc")]
-                        FileScan::Ipc { .. } => sources
-                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
-                        #[cfg(feature = "csv")]
-                        FileScan::Csv { .. } => {
-                            sources.expand_paths(unified_scan_args, cloud_options)?
-                        },
-                        #[cfg(feature = "json")]
-                        FileScan::NDJson { .. } => {
-                            sources.expand_paths(unified_scan_args, cloud_options)?
-                        },
-                        #[cfg(feature = "python")]
-                        FileScan::PythonDataset { .. } => {
-                            // There are a lot of places that short-circuit if the paths is empty,
-                            // so we just give a dummy path here.
-                            ScanSources::Paths(Arc::from(["dummy".into()]))
-                        },
-                        FileScan::Anonymous { .. } => sources,
-                    };
+                let sources = match &*scan_type {
+                    #[cfg(feature = "parquet")]
+                    FileScan::Parquet { .. } => sources.expand_paths_with_hive_update(
+                        unified_scan_args,
+                        cloud_options,
+                        listing_filter.as_ref(),
+                    )?,
+                    #[cfg(feature = "ipc")]
+                    FileScan::Ipc { .. } => sources.expand_paths_with_hive_update(
+                        unified_scan_args,
+                        cloud_options,
+                        listing_filter.as_ref(),
+                    )?,
+                    #[cfg(feature = "csv")]
+                    FileScan::Csv { .. } => {
+                        sources.expand_paths(unified_scan_args, cloud_options)?
+                    },
+                    #[cfg(feature = "json")]
+                    FileScan::NDJson { .. } => {
+                        sources.expand_paths(unified_scan_args, cloud_options)?
+                    },
+                    #[cfg(feature = "python")]
+                    FileScan::PythonDataset { .. } => {
+                        // There are a lot of places that short-circuit if the paths is empty,
+                        // so we just give a dummy path here.
+                        ScanSources::Paths(Arc::from(["dummy".into()]))
+                    },
+                    FileScan::Anonymous { .. } => sources,
+                };
 
                 let mut file_info = match &mut *scan_type {
                     #[cfg(feature = "parquet")]
@@ -219,6 +290,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                                 &sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options.encryption.as_ref(),
                             )
                             .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
@@ -246,6 +318,13 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             unified_scan_args.missing_columns_policy = MissingColumnsPolicy::Insert;
                         }
 
+                        // The CSV reader parses straight into the dtypes of the schema overrides,
+                        // so there is no integer cast that these options could apply to.
+                        polars_ensure!(
+                            unified_scan_args.cast_columns_policy.integer_downcast.is_none(),
+                            InvalidOperation: "integer cast options are not supported for CSV scans"
+                        );
+
                         scans::csv_file_info(
                             &sources,
                             unified_scan_args.row_index.as_ref(),
@@ -341,6 +420,19 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     )?;
                 }
 
+                for (name, dtype) in unified_scan_args.file_metadata_columns.iter_fields() {
+                    let schema = Arc::make_mut(&mut file_info.schema);
+
+                    if schema.contains(name) {
+                        polars_bail!(
+                            Duplicate: r#"column name for file metadata "{}" conflicts with column name from file"#,
+                            name
+                        );
+                    }
+
+                    schema.insert_at_index(schema.len(), name.clone(), dtype)?;
+                }
+
                 unified_scan_args.projection = if file_info.reader_schema.is_some() {
                     maybe_init_projection_excluding_hive(
                         file_info.reader_schema.as_ref().unwrap(),
@@ -351,9 +443,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 };
 
                 if let Some(row_index) = &unified_scan_args.row_index {
+                    let dtype = unified_scan_args.row_index_dtype();
                     let schema = Arc::make_mut(&mut file_info.schema);
                     *schema = schema
-                        .new_inserting_at_index(0, row_index.name.clone(), IDX_DTYPE)
+                        .new_inserting_at_index(0, row_index.name.clone(), dtype)
                         .unwrap();
                 }
 
@@ -379,10 +472,31 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                 };
 
-                cached_ir.replace(ir);
+                if listing_filter.is_some() {
+                    ir
+                } else {
+                    cached_ir.insert(ir).clone()
+                }
+            } else {
+                cached_ir.clone().unwrap()
+            };
+
+            // The fingerprints are taken on every conversion, a cached IR may be reused for a
+            // query that runs after the files were legitimately rewritten.
+            #[cfg(feature = "ipc")]
+            if let IR::Scan {
+                sources, scan_type, ..
+            } = &mut ir
+            {
+                if let FileScan::Ipc { fingerprints, .. } = scan_type.as_mut() {
+                    *fingerprints = Some(
+                        scans::ipc_fingerprints(sources)
+                            .map_err(|e| e.context(failed_here!(ipc scan)))?,
+                    );
+                }
             }
 
-            cached_ir.clone().unwrap()
+            ir
         },
         #[cfg(feature = "python")]
         DslPlan::PythonScan { mut options } => {
@@ -418,6 +532,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     .map_err(|e| e.context(failed_here!(vertical concat)))?;
             }
 
+            if args.include_source_index {
+                convert_utils::add_union_source_index(&mut inputs, ctxt.lp_arena, ctxt.expr_arena)
+                    .map_err(|e| e.context(failed_here!(vertical concat)))?;
+            }
+
             let first = *inputs.first().ok_or_else(
                 || polars_err!(InvalidOperation: "expected at least one input in 'union'/'concat'"),
             )?;
@@ -449,6 +568,9 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
         },
         DslPlan::Filter { input, predicate } => {
+            if matches!(input.as_ref(), DslPlan::Scan { .. }) {
+                ctxt.scan_predicate = Some(predicate.clone());
+            }
             let mut input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(filter)))?;
             let predicate = expand_filter(predicate, input, ctxt.lp_arena, ctxt.opt_flags)
@@ -782,6 +904,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
 
                         let policy = CastColumnsPolicy {
                             integer_upcast: per_column.integer_cast == UpcastOrForbid::Upcast,
+                            integer_downcast: None,
                             float_upcast: per_column.float_cast == UpcastOrForbid::Upcast,
                             float_downcast: false,
                             datetime_nanoseconds_downcast: false,
@@ -791,8 +914,13 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             extra_struct_fields: per_column.extra_struct_fields,
                         };
 
-                        let should_cast =
-                            policy.should_cast_column(column, to_dtype, from_dtype)?;
+                        let should_cast = if per_column.lossy_cast {
+                            // The struct field policies also hold for lossy casts.
+                            per_column.check_struct_fields(column, to_dtype, from_dtype)?;
+                            true
+                        } else {
+                            policy.should_cast_column(column, to_dtype, from_dtype)?
+                        };
 
                         let mut expr = Expr::Column(PlSmallStr::from_str(column));
                         if should_cast {
@@ -800,6 +928,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                                 expr: Arc::new(expr),
                                 dtype: to_dtype.clone(),
                                 options: CastOptions::NonStrict,
+                                drop_metadata: false,
                             };
                         }
 
@@ -810,7 +939,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
 
             // Report the error for missing columns
-            if let Some(lst) = found_missing_columns.first() {
+            if !found_missing_columns.is_empty() {
                 use std::fmt::Write;
                 let mut formatted = String::new();
                 write!(&mut formatted, "\"{}\"", found_missing_columns[0]).unwrap();
@@ -818,7 +947,6 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     write!(&mut formatted, ", \"{c}\"").unwrap();
                 }
 
-                write!(&mut formatted, "\"{lst}\"").unwrap();
                 polars_bail!(SchemaMismatch: "missing columns in `match_to_schema`: {formatted}");
             }
 
@@ -1149,6 +1277,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             let payload = match payload {
                 SinkType::Memory => SinkTypeIR::Memory,
                 SinkType::File(f) => SinkTypeIR::File(f),
+                SinkType::Callback(f) => SinkTypeIR::Callback(f),
                 SinkType::Partition(f) => SinkTypeIR::Partition(PartitionSinkTypeIR {
                     base_path: f.base_path,
                     file_path_cb: f.file_path_cb,
@@ -1224,6 +1353,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             input_left,
             input_right,
             key,
+            nulls_last,
         } => {
             let input_left = to_alp_impl(owned(input_left), ctxt)
                 .map_err(|e| e.context(failed_here!(merge_sorted)))?;
@@ -1234,6 +1364,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 input_left,
                 input_right,
                 key,
+                nulls_last,
             }
         },
         DslPlan::IR { node, dsl, version } => {
@@ -1382,6 +1513,9 @@ fn resolve_group_by(
             let dtype = current_schema.try_get(name.as_str())?;
             keys.push(col(name.clone()));
             pop_keys = true;
+            if options.include_window_id {
+                schema.with_column("_window_id".into(), DataType::UInt64);
+            }
             schema.with_column(name.clone(), dtype.clone());
         } else if let Some(options) = _options.dynamic.as_ref() {
             let name = options.index_column.clone();
@@ -1392,6 +1526,9 @@ fn resolve_group_by(
                 schema.with_column("_lower_boundary".into(), dtype.clone());
                 schema.with_column("_upper_boundary".into(), dtype.clone());
             }
+            if options.include_window_id {
+                schema.with_column("_window_id".into(), DataType::UInt64);
+            }
             schema.with_column(name.clone(), dtype.clone());
         }
     }
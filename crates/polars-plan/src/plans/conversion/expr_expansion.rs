@@ -1,6 +1,7 @@
 //! this contains code used for rewriting projections, expanding wildcards, regex selection etc.
 
 use super::*;
+use crate::constants::POLARS_EXTERNAL_PREFIX;
 
 pub(crate) fn prepare_projection(
     exprs: Vec<Expr>,
@@ -77,9 +78,13 @@ fn replace_wildcard(
 }
 
 fn replace_nth(expr: Expr, schema: &Schema) -> Expr {
+    let names = schema
+        .iter_names()
+        .filter(|name| !is_external_column(name))
+        .collect::<Vec<_>>();
     expr.map_expr(|e| {
         if let Expr::Nth(i) = e {
-            match i.negative_to_usize(schema.len()) {
+            match i.negative_to_usize(names.len()) {
                 None => {
                     let name = match i {
                         0 => "first",
@@ -88,10 +93,7 @@ fn replace_nth(expr: Expr, schema: &Schema) -> Expr {
                     };
                     Expr::Column(PlSmallStr::from_static(name))
                 },
-                Some(idx) => {
-                    let (name, _dtype) = schema.get_at_index(idx).unwrap();
-                    Expr::Column(name.clone())
-                },
+                Some(idx) => Expr::Column(names[idx].clone()),
             }
         } else {
             e
@@ -131,6 +133,12 @@ pub(crate) fn is_regex_projection(name: &str) -> bool {
     name.starts_with('^') && name.ends_with('$')
 }
 
+/// Whether `name` holds the values of a frame registered with `LazyFrame::with_external`. These
+/// columns are only reachable through `external`, never through wildcards or selectors.
+pub(crate) fn is_external_column(name: &str) -> bool {
+    name.starts_with(POLARS_EXTERNAL_PREFIX)
+}
+
 #[cfg(feature = "regex")]
 /// This function searches for a regex expression in `col("..")` and expands the columns
 /// that are selected by that regex in `result`. The regex should start with `^` and end with `$`.
@@ -258,6 +266,9 @@ fn dtypes_match(d1: &DataType, d2: &DataType) -> bool {
                         _ => false,
                     })
         },
+        // An unparametrized decimal matches decimals of any precision and scale...
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(None, None), DataType::Decimal(_, _)) => true,
         // ...but otherwise require exact match
         _ => d1 == d2,
     }
@@ -459,6 +470,7 @@ fn expand_indices(
     schema: &Schema,
     indices: &[i64],
     exclude: &PlHashSet<PlSmallStr>,
+    strict: bool,
 ) -> PolarsResult<()> {
     let n_fields = schema.len() as i64;
     for idx in indices {
@@ -476,6 +488,8 @@ fn expand_indices(
                 let new_expr = rewrite_special_aliases(new_expr)?;
                 result.push(new_expr);
             }
+        } else if strict {
+            polars_bail!(ComputeError: "invalid column index {}", idx)
         }
     }
     Ok(())
@@ -491,6 +505,14 @@ fn prepare_excluded(
 ) -> PolarsResult<PlHashSet<PlSmallStr>> {
     let mut exclude = PlHashSet::new();
 
+    // the columns of external frames are never expanded
+    exclude.extend(
+        schema
+            .iter_names()
+            .filter(|name| is_external_column(name))
+            .cloned(),
+    );
+
     // explicit exclude branch
     if has_exclude {
         for e in expr {
@@ -587,6 +609,8 @@ struct ExpansionFlags {
     has_wildcard: bool,
     has_selector: bool,
     has_exclude: bool,
+    /// Out-of-range column indices raise instead of being skipped, see [`Selector::Strict`].
+    strict_indices: bool,
     #[cfg(feature = "dtype-struct")]
     expands_fields: bool,
     #[cfg(feature = "dtype-struct")]
@@ -652,6 +676,7 @@ fn find_flags(expr: &Expr) -> PolarsResult<ExpansionFlags> {
         has_wildcard,
         has_selector,
         has_exclude,
+        strict_indices: false,
         #[cfg(feature = "dtype-struct")]
         has_struct_field_by_index,
         #[cfg(feature = "dtype-struct")]
@@ -746,7 +771,14 @@ fn replace_and_add_to_results(
                 },
                 Expr::IndexColumn(indices) => {
                     let exclude = prepare_excluded(&expr, schema, keys, flags.has_exclude)?;
-                    expand_indices(&expr, result, schema, indices, &exclude)?
+                    expand_indices(
+                        &expr,
+                        result,
+                        schema,
+                        indices,
+                        &exclude,
+                        flags.strict_indices,
+                    )?
                 },
                 #[cfg(feature = "dtype-struct")]
                 Expr::Function { function, .. } => {
@@ -822,10 +854,12 @@ fn replace_selector_inner(
     scratch: &mut Vec<Expr>,
     schema: &Schema,
     keys: &[Expr],
+    strict: bool,
 ) -> PolarsResult<()> {
     match s {
         Selector::Root(expr) => {
-            let local_flags = find_flags(&expr)?;
+            let mut local_flags = find_flags(&expr)?;
+            local_flags.strict_indices = strict;
             replace_and_add_to_results(
                 *expr,
                 local_flags,
@@ -838,31 +872,41 @@ fn replace_selector_inner(
         },
         Selector::Add(lhs, rhs) => {
             let mut tmp_members: PlIndexSet<Expr> = Default::default();
-            replace_selector_inner(*lhs, members, scratch, schema, keys)?;
-            replace_selector_inner(*rhs, &mut tmp_members, scratch, schema, keys)?;
+            replace_selector_inner(*lhs, members, scratch, schema, keys, strict)?;
+            replace_selector_inner(*rhs, &mut tmp_members, scratch, schema, keys, strict)?;
             members.extend(tmp_members)
         },
         Selector::ExclusiveOr(lhs, rhs) => {
             let mut tmp_members = Default::default();
-            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys)?;
-            replace_selector_inner(*rhs, members, scratch, schema, keys)?;
+            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys, strict)?;
+            replace_selector_inner(*rhs, members, scratch, schema, keys, strict)?;
 
             *members = tmp_members.symmetric_difference(members).cloned().collect();
         },
         Selector::Intersect(lhs, rhs) => {
             let mut tmp_members = Default::default();
-            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys)?;
-            replace_selector_inner(*rhs, members, scratch, schema, keys)?;
+            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys, strict)?;
+            replace_selector_inner(*rhs, members, scratch, schema, keys, strict)?;
 
             *members = tmp_members.intersection(members).cloned().collect();
         },
         Selector::Sub(lhs, rhs) => {
             let mut tmp_members = Default::default();
-            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys)?;
-            replace_selector_inner(*rhs, members, scratch, schema, keys)?;
+            replace_selector_inner(*lhs, &mut tmp_members, scratch, schema, keys, strict)?;
+            replace_selector_inner(*rhs, members, scratch, schema, keys, strict)?;
 
             *members = tmp_members.difference(members).cloned().collect();
         },
+        Selector::Strict(inner) => {
+            let mut tmp_members = Default::default();
+            let msg = format!("{inner:?}");
+            replace_selector_inner(*inner, &mut tmp_members, scratch, schema, keys, true)?;
+            polars_ensure!(
+                !tmp_members.is_empty(),
+                ColumnNotFound: "selector matched no columns: {}", msg
+            );
+            members.extend(tmp_members)
+        },
     }
     Ok(())
 }
@@ -926,7 +970,7 @@ pub(super) fn expand_selector(
     keys: &[Expr],
 ) -> PolarsResult<Arc<[PlSmallStr]>> {
     let mut members = PlIndexSet::new();
-    replace_selector_inner(s, &mut members, &mut vec![], schema, keys)?;
+    replace_selector_inner(s, &mut members, &mut vec![], schema, keys, false)?;
 
     if members.len() <= 1 {
         members
@@ -155,10 +155,12 @@ pub(super) fn to_aexpr_impl(
             expr,
             dtype,
             options,
+            drop_metadata,
         } => AExpr::Cast {
             expr: to_aexpr_impl(owned(expr), arena, state)?,
             dtype,
             options,
+            drop_metadata,
         },
         Expr::Gather {
             expr,
@@ -29,12 +29,14 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             expr,
             dtype,
             options: strict,
+            drop_metadata,
         } => {
             let exp = node_to_expr(expr, expr_arena);
             Expr::Cast {
                 expr: Arc::new(exp),
                 dtype,
                 options: strict,
+                drop_metadata,
             }
         },
         AExpr::Sort { expr, options } => {
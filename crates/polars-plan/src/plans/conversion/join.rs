@@ -55,6 +55,17 @@ pub fn resolve_join(
         to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(join right)))
     })?;
 
+    if let JoinCoalesce::Columns(_) = &options.args.coalesce {
+        return resolve_join_coalesce_columns(
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ctxt,
+        );
+    }
+
     let schema_left = ctxt.lp_arena.get(input_left).schema(ctxt.lp_arena);
     let schema_right = ctxt.lp_arena.get(input_right).schema(ctxt.lp_arena);
 
@@ -258,11 +269,13 @@ pub fn resolve_join(
                 expr: lnode.node(),
                 dtype: dtype.clone(),
                 options: CastOptions::Overflowing,
+                drop_metadata: false,
             });
             let casted_r = ctxt.expr_arena.add(AExpr::Cast {
                 expr: rnode.node(),
                 dtype,
                 options: CastOptions::Overflowing,
+                drop_metadata: false,
             });
 
             if key_cols_coalesced {
@@ -373,6 +386,89 @@ pub fn resolve_join(
     }
 }
 
+/// Resolves a join that only coalesces the keys listed in [`JoinCoalesce::Columns`].
+///
+/// The join keeps all key columns, after which the listed keys are coalesced with their right
+/// counterpart and the right key columns are dropped. Only keys that are plain columns on both
+/// sides can be coalesced, as the values of expression keys are not part of the join output.
+fn resolve_join_coalesce_columns(
+    input_left: Node,
+    input_right: Node,
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+    mut options: Arc<JoinOptions>,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<(Node, Node)> {
+    if let JoinCoalesce::Columns(columns) = &options.args.coalesce {
+        for (l, r) in left_on.iter().zip(&right_on) {
+            if matches!((l, r), (Expr::Column(_), Expr::Column(_))) {
+                continue;
+            }
+            for name in [l, r].into_iter().filter_map(|e| expr_output_name(e).ok()) {
+                polars_ensure!(
+                    !columns.contains(&name),
+                    InvalidOperation: "cannot coalesce join key '{}': only keys that are plain columns can be coalesced", name
+                );
+            }
+        }
+    }
+    let (names_left, names_right): (Vec<_>, Vec<_>) = left_on
+        .iter()
+        .zip(&right_on)
+        .filter_map(|(l, r)| match (l, r) {
+            (Expr::Column(l), Expr::Column(r)) => Some((l.clone(), r.clone())),
+            _ => None,
+        })
+        .unzip();
+    let (keys_left, keys_right) = options.args.coalesced_keys(&names_left, &names_right)?;
+
+    let schema_left = ctxt
+        .lp_arena
+        .get(input_left)
+        .schema(ctxt.lp_arena)
+        .into_owned();
+    let suffix = options.args.suffix().clone();
+    Arc::make_mut(&mut options).args.coalesce = JoinCoalesce::KeepColumns;
+    let (node, join_node) = resolve_join(
+        Either::Right(input_left),
+        Either::Right(input_right),
+        left_on,
+        right_on,
+        vec![],
+        options,
+        ctxt,
+    )?;
+
+    let mut exprs = Vec::with_capacity(keys_left.len());
+    let mut dropped = PlHashSet::with_capacity(keys_right.len());
+    for (l, r) in keys_left.into_iter().zip(keys_right) {
+        let r = if schema_left.contains(&r) {
+            _join_suffix_name(&r, &suffix)
+        } else {
+            r
+        };
+        let e = coalesce(&[col(l.clone()), col(r.clone())]).alias(l);
+        exprs.push(to_expr_ir(e, ctxt.expr_arena)?);
+        dropped.insert(r);
+    }
+    ctxt.conversion_optimizer
+        .fill_scratch(&exprs, ctxt.expr_arena);
+    ctxt.conversion_optimizer
+        .optimize_exprs(ctxt.expr_arena, ctxt.lp_arena, node)
+        .map_err(|e| e.context("'join' failed".into()))?;
+
+    let builder = IRBuilder::new(node, ctxt.expr_arena, ctxt.lp_arena)
+        .with_columns(exprs, ProjectionOptions::default());
+    let names = builder
+        .schema()
+        .iter_names()
+        .filter(|name| !dropped.contains(*name))
+        .cloned()
+        .collect_vec();
+    let ir = builder.project_simple(names)?.build();
+    Ok((ctxt.lp_arena.add(ir), join_node))
+}
+
 #[cfg(feature = "iejoin")]
 impl From<InequalityOperator> for Operator {
     fn from(value: InequalityOperator) -> Self {
@@ -490,6 +586,7 @@ fn ensure_lossless_binary_comparisons(
             expr: old_expr,
             dtype,
             options: CastOptions::Overflowing,
+            drop_metadata: false,
         };
         expr_arena.replace(expr, new_aexpr);
     }
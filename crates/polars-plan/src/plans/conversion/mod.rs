@@ -35,7 +35,9 @@ mod join;
 pub(crate) mod type_check;
 pub(crate) mod type_coercion;
 
-pub(crate) use expr_expansion::{expand_selectors, is_regex_projection, prepare_projection};
+pub(crate) use expr_expansion::{
+    expand_selectors, is_external_column, is_regex_projection, prepare_projection,
+};
 pub(crate) use stack_opt::ConversionOptimizer;
 
 use crate::constants::get_len_name;
@@ -277,6 +279,7 @@ impl IR {
                 let payload = match payload {
                     SinkTypeIR::Memory => SinkType::Memory,
                     SinkTypeIR::File(f) => SinkType::File(f),
+                    SinkTypeIR::Callback(f) => SinkType::Callback(f),
                     SinkTypeIR::Partition(f) => SinkType::Partition(PartitionSinkType {
                         base_path: f.base_path,
                         file_path_cb: f.file_path_cb,
@@ -329,6 +332,7 @@ impl IR {
                 input_left,
                 input_right,
                 key,
+                nulls_last,
             } => {
                 let input_left = Arc::new(convert_to_lp(input_left, lp_arena));
                 let input_right = Arc::new(convert_to_lp(input_right, lp_arena));
@@ -337,6 +341,7 @@ impl IR {
                     input_left,
                     input_right,
                     key,
+                    nulls_last,
                 }
             },
             IR::Invalid => unreachable!(),
@@ -33,6 +33,7 @@ pub(super) fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
@@ -42,8 +43,9 @@ pub(super) fn parquet_file_info(
             feature_gated!("cloud", {
                 let uri = first_path.to_string_lossy();
                 get_runtime().block_in_place_on(async {
-                    let mut reader =
-                        ParquetObjectStore::from_uri(&uri, cloud_options, None).await?;
+                    let mut reader = ParquetObjectStore::from_uri(&uri, cloud_options, None)
+                        .await?
+                        .with_decryption(decryption.cloned());
 
                     PolarsResult::Ok((
                         reader.schema().await?,
@@ -57,7 +59,8 @@ pub(super) fn parquet_file_info(
                 .first()
                 .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 source"))?;
             let memslice = first_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_decryption(decryption.cloned());
             (
                 reader.schema()?,
                 Some(reader.num_rows()?),
@@ -129,6 +132,26 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+/// Fingerprint every local IPC file, so that the scan can detect files that change before or
+/// while they are read.
+#[cfg(feature = "ipc")]
+pub(super) fn ipc_fingerprints(
+    sources: &ScanSources,
+) -> PolarsResult<Arc<[Option<polars_io::ipc::IpcFileFingerprint>]>> {
+    use polars_io::ipc::IpcFileFingerprint;
+
+    sources
+        .iter()
+        .map(|source| match source {
+            ScanSourceRef::Path(path) if !is_cloud_url(path) => {
+                IpcFileFingerprint::from_file(&polars_utils::open_file(path)?).map(Some)
+            },
+            ScanSourceRef::Path(_) | ScanSourceRef::Buffer(_) => Ok(None),
+            ScanSourceRef::File(file) => IpcFileFingerprint::from_file(file).map(Some),
+        })
+        .collect()
+}
+
 #[cfg(feature = "csv")]
 pub fn isolated_csv_file_info(
     source: ScanSourceRef,
@@ -65,6 +65,7 @@ fn process_struct_numeric_arithmetic(
                     expr: node_right,
                     dtype: DataType::Struct(vec![first.clone()]),
                     options: CastOptions::NonStrict,
+                    drop_metadata: false,
                 });
                 Ok(Some(AExpr::BinaryExpr {
                     left: node_left,
@@ -81,6 +82,7 @@ fn process_struct_numeric_arithmetic(
                     expr: node_left,
                     dtype: DataType::Struct(vec![first.clone()]),
                     options: CastOptions::NonStrict,
+                    drop_metadata: false,
                 });
 
                 Ok(Some(AExpr::BinaryExpr {
@@ -263,6 +265,7 @@ pub(super) fn process_binary(
             expr: node_left,
             dtype: st.clone(),
             options: CastOptions::NonStrict,
+            drop_metadata: false,
         })
     } else {
         node_left
@@ -272,6 +275,7 @@ pub(super) fn process_binary(
             expr: node_right,
             dtype: st,
             options: CastOptions::NonStrict,
+            drop_metadata: false,
         })
     } else {
         node_right
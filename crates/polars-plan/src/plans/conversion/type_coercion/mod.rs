@@ -100,6 +100,7 @@ impl OptimizationRule for TypeCoercionRule {
                     expr: input_expr,
                     dtype,
                     options,
+                    drop_metadata,
                 } = ae.clone()
                 else {
                     unreachable!()
@@ -117,6 +118,7 @@ impl OptimizationRule for TypeCoercionRule {
 
                         let v = CastColumnsPolicy {
                             integer_upcast: true,
+                            integer_downcast: None,
                             float_upcast: true,
                             float_downcast: true,
                             datetime_nanoseconds_downcast: true,
@@ -151,6 +153,7 @@ impl OptimizationRule for TypeCoercionRule {
                                         expr: input_expr,
                                         dtype,
                                         options,
+                                        drop_metadata,
                                     },
                                 );
                             },
@@ -189,6 +192,7 @@ impl OptimizationRule for TypeCoercionRule {
                         expr: truthy_node,
                         dtype: st.clone(),
                         options: CastOptions::Strict,
+                        drop_metadata: false,
                     })
                 } else {
                     truthy_node
@@ -199,6 +203,7 @@ impl OptimizationRule for TypeCoercionRule {
                         expr: falsy_node,
                         dtype: st,
                         options: CastOptions::Strict,
+                        drop_metadata: false,
                     })
                 } else {
                     falsy_node
@@ -364,6 +369,7 @@ impl OptimizationRule for TypeCoercionRule {
                         expr: left_node,
                         dtype: super_type.clone(),
                         options: CastOptions::NonStrict,
+                        drop_metadata: false,
                     })
                 } else {
                     left_node
@@ -374,6 +380,7 @@ impl OptimizationRule for TypeCoercionRule {
                         expr: fill_value_node,
                         dtype: super_type.clone(),
                         options: CastOptions::NonStrict,
+                        drop_metadata: false,
                     })
                 } else {
                     fill_value_node
@@ -995,6 +1002,7 @@ fn cast_expr_ir(
         expr: e.node(),
         dtype: to_dtype.clone(),
         options: CastOptions::Strict,
+        drop_metadata: false,
     }));
     e.set_dtype(to_dtype.clone());
 
@@ -1051,15 +1059,18 @@ fn inline_implode(expr: Node, expr_arena: &mut Arena<AExpr>) -> PolarsResult<Opt
             expr,
             dtype,
             options,
+            drop_metadata,
         } => {
             let dtype = dtype.clone();
             let options = *options;
+            let drop_metadata = *drop_metadata;
             inline_implode(*expr, expr_arena)?.map(|expr| {
                 let expr = expr_arena.add(expr);
                 AExpr::Cast {
                     expr,
                     dtype: DataType::List(Box::new(dtype)),
                     options,
+                    drop_metadata,
                 }
             })
         },
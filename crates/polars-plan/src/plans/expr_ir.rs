@@ -301,6 +301,56 @@ impl AsRef<ExprIR> for ExprIR {
     }
 }
 
+/// Hash-consing of expressions.
+///
+/// Assigns the same canonical ID to structurally identical (sub)expressions, also across the
+/// different expressions added to it, and counts how often every ID occurs.
+#[cfg(feature = "cse")]
+#[derive(Default)]
+pub struct ExprHashCons {
+    merger: NaiveExprMerger,
+    counts: Vec<u32>,
+}
+
+#[cfg(feature = "cse")]
+impl ExprHashCons {
+    /// Add an expression and all its subexpressions. Returns the canonical ID of the expression.
+    pub fn add(&mut self, expr: &ExprIR, expr_arena: &Arena<AExpr>) -> u32 {
+        self.merger.add_expr(expr.node(), expr_arena);
+        for (node, _) in expr_arena.iter(expr.node()) {
+            let id = self.merger.get_uniq_id(node).unwrap() as usize;
+            if id >= self.counts.len() {
+                self.counts.resize(id + 1, 0);
+            }
+            self.counts[id] += 1;
+        }
+        self.merger.get_uniq_id(expr.node()).unwrap()
+    }
+
+    /// The canonical ID of an added (sub)expression.
+    pub fn id(&self, node: Node) -> Option<u32> {
+        self.merger.get_uniq_id(node)
+    }
+
+    /// The number of occurrences of the expressions with canonical ID `id`.
+    pub fn count(&self, id: u32) -> u32 {
+        self.counts.get(id as usize).copied().unwrap_or(0)
+    }
+
+    /// Whether any subexpression other than a column, literal or `len` occurs more than once.
+    pub fn has_duplicate_subexprs(&self, expr_arena: &Arena<AExpr>) -> bool {
+        self.counts.iter().enumerate().any(|(id, count)| {
+            *count > 1 && {
+                let node = self.merger.get_node(id as u32).unwrap();
+                !matches!(
+                    expr_arena.get(node),
+                    AExpr::Column(_) | AExpr::Literal(_) | AExpr::Len
+                )
+            }
+        })
+    }
+}
+
 /// A Node that is restricted to `AExpr::Column`
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -1,3 +1,6 @@
+#[cfg(feature = "ipc")]
+use std::io::{Read, Seek, SeekFrom};
+
 #[cfg(feature = "ipc")]
 use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
 #[cfg(any(
@@ -12,9 +15,11 @@ use polars_io::SerReader;
 #[cfg(any(feature = "parquet", feature = "json"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetReader;
+use polars_io::parquet::read::{FileDecryptionProperties, ParquetReader};
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
+#[cfg(feature = "ipc")]
+use polars_utils::mmap::MemSlice;
 
 use super::*;
 
@@ -46,13 +51,18 @@ pub fn count_rows(
             #[cfg(feature = "csv")]
             FileScan::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScan::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            FileScan::Parquet { options, .. } => {
+                count_rows_parquet(sources, cloud_options, options.encryption.as_ref())
+            },
             #[cfg(feature = "ipc")]
-            FileScan::Ipc { options, metadata } => count_rows_ipc(
+            FileScan::Ipc {
+                options, metadata, ..
+            } => count_rows_ipc(
                 sources,
                 #[cfg(feature = "cloud")]
                 cloud_options,
                 metadata.as_deref(),
+                options.lock_file,
             ),
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => count_rows_ndjson(sources, cloud_options),
@@ -109,6 +119,7 @@ fn count_all_rows_csv(
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -120,13 +131,16 @@ pub(super) fn count_rows_parquet(
             get_runtime().block_on(count_rows_cloud_parquet(
                 sources.as_paths().unwrap(),
                 cloud_options,
+                decryption,
             ))
         })
     } else {
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_decryption(decryption.cloned())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -136,13 +150,16 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     paths: &[std::path::PathBuf],
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<usize> {
     use polars_io::prelude::ParquetObjectStore;
 
     let collection = paths.iter().map(|path| {
         with_concurrency_budget(1, || async {
             let mut reader =
-                ParquetObjectStore::from_uri(&path.to_string_lossy(), cloud_options, None).await?;
+                ParquetObjectStore::from_uri(&path.to_string_lossy(), cloud_options, None)
+                    .await?
+                    .with_decryption(decryption.cloned());
             reader.num_rows().await
         })
     });
@@ -156,6 +173,7 @@ pub(super) fn count_rows_ipc(
     sources: &ScanSources,
     #[cfg(feature = "cloud")] cloud_options: Option<&CloudOptions>,
     metadata: Option<&arrow::io::ipc::read::FileMetadata>,
+    lock_file: bool,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -174,7 +192,20 @@ pub(super) fn count_rows_ipc(
         sources
             .iter()
             .map(|source| {
-                let memslice = source.to_memslice()?;
+                let memslice = match source.to_file_async_check_latest(false)? {
+                    // Like the IPC reader, map the file under a shared lock or read it into
+                    // memory if it can't be locked.
+                    Some(mut file) if lock_file => match MemSlice::from_file_locked(&file)? {
+                        Some(memslice) => memslice,
+                        None => {
+                            let mut buf = Vec::new();
+                            file.seek(SeekFrom::Start(0))?;
+                            file.read_to_end(&mut buf)?;
+                            MemSlice::from_vec(buf)
+                        },
+                    },
+                    _ => source.to_memslice()?,
+                };
                 count_rows_ipc_sync(&mut std::io::Cursor::new(memslice)).map(|v| v as usize)
             })
             .sum::<PolarsResult<usize>>()
@@ -28,6 +28,10 @@ pub enum DslFunction {
         name: PlSmallStr,
         offset: Option<IdxSize>,
     },
+    RowIndexU64 {
+        name: PlSmallStr,
+        offset: u64,
+    },
     // This is both in DSL and IR because we want to be able to serialize it.
     #[cfg(feature = "python")]
     OpaquePython(OpaquePythonUdf),
@@ -44,7 +48,10 @@ pub enum DslFunction {
         new: Arc<[PlSmallStr]>,
         strict: bool,
     },
-    Unnest(Vec<Selector>),
+    Unnest {
+        columns: Vec<Selector>,
+        options: UnnestOptions,
+    },
     Stats(StatsFunction),
     /// FillValue
     FillNan(Expr),
@@ -126,10 +133,18 @@ impl DslFunction {
                 offset,
                 schema: Default::default(),
             },
-            DslFunction::Unnest(selectors) => {
-                let columns = expand_selectors(selectors, input_schema, &[])?;
+            DslFunction::RowIndexU64 { name, offset } => FunctionIR::RowIndexU64 {
+                name,
+                offset,
+                schema: Default::default(),
+            },
+            DslFunction::Unnest { columns, options } => {
+                let columns = expand_selectors(columns, input_schema, &[])?;
                 validate_columns_in_input(columns.as_ref(), input_schema, "unnest")?;
-                FunctionIR::Unnest { columns }
+                let function = FunctionIR::Unnest { columns, options };
+                // Resolve the output schema so that colliding names error here.
+                function.schema(&Arc::new(input_schema.clone()))?;
+                function
             },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
@@ -34,6 +34,12 @@ pub enum FunctionIR {
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
+    RowIndexU64 {
+        name: PlSmallStr,
+        offset: u64,
+        #[cfg_attr(feature = "ir_serde", serde(skip))]
+        schema: CachedSchema,
+    },
     #[cfg(feature = "python")]
     OpaquePython(OpaquePythonUdf),
 
@@ -46,6 +52,7 @@ pub enum FunctionIR {
 
     Unnest {
         columns: Arc<[PlSmallStr]>,
+        options: UnnestOptions,
     },
     Rechunk,
     Explode {
@@ -99,6 +106,7 @@ impl PartialEq for FunctionIR {
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            (RowIndexU64 { name: l, .. }, RowIndexU64 { name: r, .. }) => l == r,
             _ => false,
         }
     }
@@ -123,7 +131,10 @@ impl Hash for FunctionIR {
                 alias.hash(state);
             },
             FunctionIR::Pipeline { .. } => {},
-            FunctionIR::Unnest { columns } => columns.hash(state),
+            FunctionIR::Unnest { columns, options } => {
+                columns.hash(state);
+                options.hash(state);
+            },
             FunctionIR::Rechunk => {},
             FunctionIR::Explode { columns, schema: _ } => columns.hash(state),
             #[cfg(feature = "pivot")]
@@ -136,6 +147,14 @@ impl Hash for FunctionIR {
                 name.hash(state);
                 offset.hash(state);
             },
+            FunctionIR::RowIndexU64 {
+                name,
+                schema: _,
+                offset,
+            } => {
+                name.hash(state);
+                offset.hash(state);
+            },
         }
     }
 }
@@ -152,7 +171,7 @@ impl FunctionIR {
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
-            RowIndex { .. } => false,
+            RowIndex { .. } | RowIndexU64 { .. } => false,
         }
     }
 
@@ -176,7 +195,7 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Rechunk | Unnest { .. } | Explode { .. } => true,
-            RowIndex { .. } | FastCount { .. } => false,
+            RowIndex { .. } | RowIndexU64 { .. } | FastCount { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -187,10 +206,12 @@ impl FunctionIR {
             Opaque { projection_pd, .. } => *projection_pd,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { projection_pd, .. }) => *projection_pd,
-            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            Rechunk | FastCount { .. } | Explode { .. } => true,
+            // Which fields get renamed depends on the other columns, so those can't be pruned.
+            Unnest { options, .. } => options.collision != CollisionPolicy::RenameWithPrefix,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            RowIndex { .. } => true,
+            RowIndex { .. } | RowIndexU64 { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -198,7 +219,7 @@ impl FunctionIR {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[PlSmallStr]> {
         use FunctionIR::*;
         match self {
-            Unnest { columns } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             _ => Cow::Borrowed(&[]),
         }
@@ -225,8 +246,14 @@ impl FunctionIR {
                 df.as_single_chunk_par();
                 Ok(df)
             },
-            Unnest { columns: _columns } => {
-                feature_gated!("dtype-struct", df.unnest(_columns.iter().cloned()))
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
+                feature_gated!(
+                    "dtype-struct",
+                    df.unnest_with_options(_columns.iter().cloned().collect::<Vec<_>>(), _options)
+                )
             },
             Pipeline { function, .. } => {
                 // we use a global string cache here as streaming chunks all have different rev maps
@@ -249,6 +276,7 @@ impl FunctionIR {
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
+            RowIndexU64 { name, offset, .. } => df.with_row_index_u64(name.clone(), Some(*offset)),
         }
     }
 
@@ -277,7 +305,7 @@ impl Display for FunctionIR {
         use FunctionIR::*;
         match self {
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
-            Unnest { columns } => {
+            Unnest { columns, .. } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
@@ -15,7 +15,7 @@ impl FunctionIR {
                 let mut guard = schema.lock().unwrap();
                 *guard = None;
             },
-            RowIndex { schema, .. } | Explode { schema, .. } => {
+            RowIndex { schema, .. } | RowIndexU64 { schema, .. } | Explode { schema, .. } => {
                 let mut guard = schema.lock().unwrap();
                 *guard = None;
             },
@@ -49,33 +49,38 @@ impl FunctionIR {
                 Ok(Cow::Owned(Arc::new(schema)))
             },
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
-                    for (name, dtype) in input_schema.iter() {
-                        if _columns.iter().any(|item| item == name) {
-                            match dtype {
-                                DataType::Struct(flds) => {
-                                    for fld in flds {
-                                        new_schema
-                                            .with_column(fld.name().clone(), fld.dtype().clone());
-                                    }
-                                },
-                                DataType::Unknown(_) => {
+                    let columns = input_schema
+                        .iter()
+                        .map(|(name, dtype)| {
+                            let fields = if _columns.iter().any(|item| item == name) {
+                                match dtype {
+                                    DataType::Struct(flds) => Some(
+                                        flds.iter()
+                                            .map(|fld| (fld.name().clone(), fld.dtype().clone()))
+                                            .collect(),
+                                    ),
                                     // pass through unknown
-                                },
-                                _ => {
-                                    polars_bail!(
-                                        SchemaMismatch: "expected struct dtype, got: `{}`", dtype
-                                    );
-                                },
-                            }
-                        } else {
-                            new_schema.with_column(name.clone(), dtype.clone());
-                        }
-                    }
+                                    DataType::Unknown(_) => Some(vec![]),
+                                    _ => {
+                                        polars_bail!(
+                                            SchemaMismatch: "expected struct dtype, got: `{}`", dtype
+                                        );
+                                    },
+                                }
+                            } else {
+                                None
+                            };
+                            Ok((name.clone(), dtype.clone(), fields))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
 
+                    let new_schema = _options.resolve(columns)?.into_iter().collect::<Schema>();
                     Ok(Cow::Owned(Arc::new(new_schema)))
                 }
                 #[cfg(not(feature = "dtype-struct"))]
@@ -87,6 +92,13 @@ impl FunctionIR {
                 schema,
                 input_schema,
                 name.clone(),
+                IDX_DTYPE,
+            ))),
+            RowIndexU64 { schema, name, .. } => Ok(Cow::Owned(row_index_schema(
+                schema,
+                input_schema,
+                name.clone(),
+                DataType::UInt64,
             ))),
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             #[cfg(feature = "pivot")]
@@ -99,13 +111,14 @@ fn row_index_schema(
     cached_schema: &CachedSchema,
     input_schema: &SchemaRef,
     name: PlSmallStr,
+    dtype: DataType,
 ) -> SchemaRef {
     let mut guard = cached_schema.lock().unwrap();
     if let Some(schema) = &*guard {
         return schema.clone();
     }
     let mut schema = (**input_schema).clone();
-    schema.insert_at_index(0, name, IDX_DTYPE).unwrap();
+    schema.insert_at_index(0, name, dtype).unwrap();
     let schema_ref = Arc::new(schema);
     *guard = Some(schema_ref.clone());
     schema_ref
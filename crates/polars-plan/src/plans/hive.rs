@@ -1,10 +1,16 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::HiveListingFilter;
 use polars_io::prelude::schema_inference::{finish_infer_field_schema, infer_field_schema};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::dsl::{AggExpr, Expr, Operator};
+#[cfg(feature = "is_in")]
+use crate::dsl::{BooleanFunction, FunctionExpr};
+use crate::plans::LiteralValue;
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct HivePartitionsDf(DataFrame);
@@ -217,6 +223,258 @@ pub fn hive_partitions_from_paths(
     )?)))
 }
 
+#[derive(Default)]
+struct KeyConstraint {
+    values: Option<Vec<AnyValue<'static>>>,
+    low: Option<i128>,
+    high: Option<i128>,
+}
+
+impl KeyConstraint {
+    fn intersect_values(&mut self, values: Vec<AnyValue<'static>>) {
+        self.values = Some(match self.values.take() {
+            None => values,
+            Some(existing) => existing
+                .into_iter()
+                .filter(|v| values.iter().any(|w| v.total_cmp(w).is_eq()))
+                .collect(),
+        });
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_none() && self.low.is_none() && self.high.is_none()
+    }
+}
+
+/// Filter on the directories of one partition key, applied to the parsed partition value.
+struct PartitionDirFilter {
+    name: PlSmallStr,
+    /// The dtype the partition values are parsed as.
+    dtype: DataType,
+    constraint: KeyConstraint,
+}
+
+impl PartitionDirFilter {
+    /// Whether the directory `dir_name` may contain rows matching the constraint.
+    ///
+    /// Directories that aren't partitions of this key, and values that can't be parsed or compared
+    /// to the constraint, are accepted. The predicate on the scan still removes their rows.
+    fn accepts(&self, dir_name: &str) -> bool {
+        let Some((name, value)) = parse_hive_string(dir_name) else {
+            return true;
+        };
+        if name != self.name.as_str() {
+            return true;
+        }
+        let Some(value) = parse_partition_value(value, &self.dtype) else {
+            return true;
+        };
+        if value.is_null() {
+            return true;
+        }
+
+        if let Some(v) = value
+            .is_integer()
+            .then(|| value.extract::<i128>())
+            .flatten()
+        {
+            let KeyConstraint { low, high, .. } = self.constraint;
+            if low.is_some_and(|low| v < low) || high.is_some_and(|high| v > high) {
+                return false;
+            }
+        }
+
+        self.constraint.values.as_ref().is_none_or(|values| {
+            values
+                .iter()
+                .any(|allowed| partition_value_eq(&value, allowed).unwrap_or(true))
+        })
+    }
+}
+
+/// Parse the percent-encoded partition `value` of a directory name the same way as
+/// [`hive_partitions_from_paths`] does. Returns `None` if it can't be parsed as `dtype`.
+fn parse_partition_value(value: &str, dtype: &DataType) -> Option<AnyValue<'static>> {
+    let value = percent_encoding::percent_decode(value.as_bytes())
+        .decode_utf8()
+        .ok()?;
+    if value.is_empty() || value == "__HIVE_DEFAULT_PARTITION__" {
+        return Some(AnyValue::Null);
+    }
+
+    let schema = Schema::from_iter([Field::new(PlSmallStr::EMPTY, dtype.clone())]);
+    let mut buffers = polars_io::csv::read::buffer::init_buffers(
+        &[0],
+        1,
+        &schema,
+        None,
+        polars_io::prelude::CsvEncoding::Utf8,
+        false,
+    )
+    .ok()?;
+    let mut buffer = buffers.pop()?;
+    buffer.add(value.as_bytes(), false, false, false).ok()?;
+    let series = buffer.into_series().ok()?;
+    series.get(0).ok().map(|av| av.into_static())
+}
+
+/// Compare a parsed partition value to a literal. Returns `None` if they can't be compared.
+fn partition_value_eq(value: &AnyValue, literal: &AnyValue) -> Option<bool> {
+    if value.is_integer() && literal.is_integer() {
+        return Some(value.extract::<i128>()? == literal.extract::<i128>()?);
+    }
+    (value.dtype() == literal.dtype()).then(|| value.total_cmp(literal).is_eq())
+}
+
+fn literal_values(expr: &Expr) -> Option<Vec<AnyValue<'static>>> {
+    let lv = match expr {
+        Expr::Literal(lv) => lv,
+        Expr::Agg(AggExpr::Implode(e)) => match e.as_ref() {
+            Expr::Literal(lv @ LiteralValue::Series(_)) => lv,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let series = match lv {
+        LiteralValue::Series(s) => (**s).clone(),
+        lv => match lv.to_any_value()? {
+            AnyValue::List(s) => s,
+            av => return Some(vec![av.into_static()]),
+        },
+    };
+    Some(
+        series
+            .iter()
+            .filter(|av| !av.is_null())
+            .map(|av| av.into_static())
+            .collect(),
+    )
+}
+
+fn collect_key_constraints(
+    predicate: &Expr,
+    hive_schema: &Schema,
+    constraints: &mut PlHashMap<PlSmallStr, KeyConstraint>,
+) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            collect_key_constraints(left, hive_schema, constraints);
+            collect_key_constraints(right, hive_schema, constraints);
+        },
+        Expr::BinaryExpr { left, op, right } => {
+            let (name, value, op) = match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(name), value) => (name, value, *op),
+                (value, Expr::Column(name)) => (name, value, op.swap_operands()),
+                _ => return,
+            };
+            if !hive_schema.contains(name) {
+                return;
+            }
+            let Some(mut values) = literal_values(value) else {
+                return;
+            };
+            if values.len() != 1 {
+                return;
+            }
+            let value = values.pop().unwrap();
+            let constraint = constraints.entry(name.clone()).or_default();
+
+            match op {
+                Operator::Eq => constraint.intersect_values(vec![value]),
+                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                    let Some(v) = value
+                        .is_integer()
+                        .then(|| value.extract::<i128>())
+                        .flatten()
+                    else {
+                        return;
+                    };
+                    match op {
+                        Operator::Lt => constraint.high = Some(v - 1),
+                        Operator::LtEq => constraint.high = Some(v),
+                        Operator::Gt => constraint.low = Some(v + 1),
+                        _ => constraint.low = Some(v),
+                    }
+                },
+                _ => {},
+            }
+        },
+        #[cfg(feature = "is_in")]
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsIn { .. }),
+            ..
+        } => {
+            let [Expr::Column(name), other] = input.as_slice() else {
+                return;
+            };
+            if !hive_schema.contains(name) {
+                return;
+            }
+            if let Some(values) = literal_values(other) {
+                constraints
+                    .entry(name.clone())
+                    .or_default()
+                    .intersect_values(values);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Derive a [`HiveListingFilter`] from the equality, `is_in` and integer range predicates on the
+/// partition keys of `hive_schema`.
+///
+/// Only the conjunctions of `predicate` are considered, so the listed partitions are a superset of
+/// the partitions matching the predicate. The predicate itself still has to be applied. The
+/// directory names are parsed as the partition dtypes before they are compared, so e.g.
+/// `month=01` matches `col("month") == 1`.
+pub fn hive_listing_filter(
+    predicate: &Expr,
+    hive_schema: &Schema,
+    try_parse_dates: bool,
+) -> Option<HiveListingFilter> {
+    let mut constraints = PlHashMap::new();
+    collect_key_constraints(predicate, hive_schema, &mut constraints);
+
+    let mut filters = hive_schema
+        .iter()
+        .map(|(name, dtype)| {
+            let dtype = if !try_parse_dates && dtype.is_temporal() {
+                DataType::String
+            } else {
+                dtype.clone()
+            };
+            PartitionDirFilter {
+                name: name.clone(),
+                dtype,
+                constraint: constraints.remove(name).unwrap_or_default(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The directory levels after the last constrained key are all listed.
+    while filters
+        .last()
+        .is_some_and(|filter| filter.constraint.is_empty())
+    {
+        filters.pop();
+    }
+    if filters.is_empty() {
+        return None;
+    }
+
+    Some(HiveListingFilter::new(
+        filters.len(),
+        move |level, dir_name| filters[level].accepts(dir_name),
+    ))
+}
+
 /// Determine the path separator for identifying Hive partitions.
 fn separator(url: &Path) -> &[char] {
     if cfg!(target_family = "windows") {
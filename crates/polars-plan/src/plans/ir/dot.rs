@@ -266,7 +266,12 @@ impl<'a> IRDotDisplay<'a> {
                     }
 
                     if let Some(row_index) = unified_scan_args.row_index.as_ref() {
-                        write!(f, "\nrow index: {} (+{})", row_index.name, row_index.offset)?;
+                        write!(
+                            f,
+                            "\nrow index: {} (+{})",
+                            row_index.name,
+                            unified_scan_args.row_index_offset()
+                        )?;
                     }
 
                     Ok(())
@@ -316,6 +321,7 @@ impl<'a> IRDotDisplay<'a> {
                         SinkTypeIR::Memory => "SINK (MEMORY)",
                         SinkTypeIR::File { .. } => "SINK (FILE)",
                         SinkTypeIR::Partition { .. } => "SINK (PARTITION)",
+                        SinkTypeIR::Callback { .. } => "SINK (CALLBACK)",
                     })
                 })?;
             },
@@ -341,6 +347,7 @@ impl<'a> IRDotDisplay<'a> {
                 input_left,
                 input_right,
                 key,
+                ..
             } => {
                 self.with_root(*input_left)._format(f, Some(id), last)?;
                 self.with_root(*input_right)._format(f, Some(id), last)?;
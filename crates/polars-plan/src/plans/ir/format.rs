@@ -1,7 +1,6 @@
 use std::fmt::{self, Display, Formatter};
 
 use polars_core::schema::Schema;
-use polars_io::RowIndex;
 use polars_utils::format_list_truncated;
 use polars_utils::slice_enum::Slice;
 use recursive::recursive;
@@ -72,7 +71,7 @@ fn write_scan(
     total_columns: usize,
     predicate: &Option<ExprIRDisplay<'_>>,
     pre_slice: Option<Slice>,
-    row_index: Option<&RowIndex>,
+    row_index: Option<(&PlSmallStr, u64)>,
     scan_mem_id: Option<usize>,
 ) -> fmt::Result {
     write!(
@@ -102,10 +101,10 @@ fn write_scan(
     if let Some(pre_slice) = pre_slice {
         write!(f, "\n{:indent$}SLICE: {pre_slice:?}", "")?;
     }
-    if let Some(row_index) = row_index {
-        write!(f, "\n{:indent$}ROW_INDEX: {}", "", row_index.name)?;
-        if row_index.offset != 0 {
-            write!(f, " (offset: {})", row_index.offset)?;
+    if let Some((name, offset)) = row_index {
+        write!(f, "\n{:indent$}ROW_INDEX: {name}", "")?;
+        if offset != 0 {
+            write!(f, " (offset: {offset})")?;
         }
     }
     Ok(())
@@ -269,6 +268,7 @@ impl<'a> IRDisplay<'a> {
                 input_left,
                 input_right,
                 key: _,
+                nulls_last: _,
             } => {
                 write_ir_non_recursive(f, ir_node, self.lp.expr_arena, schema, indent)?;
                 write!(f, ":")?;
@@ -496,6 +496,7 @@ impl Display for ExprIRDisplay<'_> {
                 expr,
                 dtype,
                 options,
+                drop_metadata: _,
             } => {
                 self.with_root(expr).fmt(f)?;
                 if options.is_strict() {
@@ -729,7 +730,10 @@ pub fn write_ir_non_recursive(
                 file_info.schema.len(),
                 &predicate,
                 unified_scan_args.pre_slice.clone(),
-                unified_scan_args.row_index.as_ref(),
+                unified_scan_args
+                    .row_index
+                    .as_ref()
+                    .map(|ri| (&ri.name, unified_scan_args.row_index_offset())),
                 Some(scan_mem_id.to_usize()),
             )
         },
@@ -893,6 +897,7 @@ pub fn write_ir_non_recursive(
                 SinkTypeIR::Memory => "SINK (memory)",
                 SinkTypeIR::File { .. } => "SINK (file)",
                 SinkTypeIR::Partition { .. } => "SINK (partition)",
+                SinkTypeIR::Callback { .. } => "SINK (callback)",
             };
             write!(f, "{:indent$}{name}", "")
         },
@@ -902,6 +907,7 @@ pub fn write_ir_non_recursive(
             input_left: _,
             input_right: _,
             key,
+            nulls_last: _,
         } => write!(f, "{:indent$}MERGE SORTED ON '{key}'", ""),
         IR::Invalid => write!(f, "{:indent$}INVALID", ""),
     }
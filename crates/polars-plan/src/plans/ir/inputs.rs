@@ -153,10 +153,12 @@ impl IR {
                 input_left: _,
                 input_right: _,
                 key,
+                nulls_last,
             } => MergeSorted {
                 input_left: inputs[0],
                 input_right: inputs[1],
                 key: key.clone(),
+                nulls_last: *nulls_last,
             },
             Invalid => unreachable!(),
         }
@@ -13,6 +13,7 @@ use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unique_id::UniqueId;
 use polars_utils::unitvec;
+pub use schema::field_metadata;
 #[cfg(feature = "ir_serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
@@ -169,6 +170,7 @@ pub enum IR {
         input_left: Node,
         input_right: Node,
         key: PlSmallStr,
+        nulls_last: bool,
     },
     #[default]
     Invalid,
@@ -1,3 +1,5 @@
+use arrow::datatypes::Metadata;
+use either::Either;
 use recursive::recursive;
 
 use super::*;
@@ -39,6 +41,7 @@ impl IR {
                 SinkTypeIR::Memory => "sink (memory)",
                 SinkTypeIR::File { .. } => "sink (file)",
                 SinkTypeIR::Partition { .. } => "sink (partition)",
+                SinkTypeIR::Callback { .. } => "sink (callback)",
             },
             SinkMultiple { .. } => "sink multiple",
             SimpleProjection { .. } => "simple_projection",
@@ -176,3 +179,105 @@ impl IR {
         schema
     }
 }
+
+/// The user metadata, such as units or descriptions, of the output columns of `node`.
+///
+/// The metadata comes from the Arrow schema of file scans and from the columns of in-memory
+/// frames. It follows the columns through the nodes that keep their values: filters, slices,
+/// sorts, caches, projections of a column that may rename or cast it, and joins, where the left
+/// side wins. Columns that are computed in any other way have no metadata.
+#[recursive]
+pub fn field_metadata(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<PlSmallStr, Arc<Metadata>> {
+    use IR::*;
+
+    // The column that `node` passes through unchanged, apart from a cast.
+    fn passthrough_column(node: Node, expr_arena: &Arena<AExpr>) -> Option<&PlSmallStr> {
+        match expr_arena.get(node) {
+            AExpr::Column(name) => Some(name),
+            AExpr::Alias(input, _) => passthrough_column(*input, expr_arena),
+            AExpr::Cast {
+                expr: input,
+                drop_metadata: false,
+                ..
+            } => passthrough_column(*input, expr_arena),
+            _ => None,
+        }
+    }
+
+    let ir = lp_arena.get(node);
+    let mut out = match ir {
+        Scan { file_info, .. } => match &file_info.reader_schema {
+            Some(Either::Left(reader_schema)) => reader_schema
+                .iter_values()
+                .filter_map(|field| {
+                    let field = Field::from(field);
+                    Some((field.name, field.metadata?))
+                })
+                .collect(),
+            _ => PlHashMap::new(),
+        },
+        DataFrameScan { df, .. } => df
+            .get_columns()
+            .iter()
+            .filter_map(|c| Some((c.name().clone(), c.field().metadata.clone()?)))
+            .collect(),
+        Filter { input, .. }
+        | Slice { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. }
+        | SimpleProjection { input, .. }
+        | Sink { input, .. } => field_metadata(*input, lp_arena, expr_arena),
+        Select { input, expr, .. } => {
+            let input_md = field_metadata(*input, lp_arena, expr_arena);
+            expr.iter()
+                .filter_map(|e| {
+                    let md = input_md.get(passthrough_column(e.node(), expr_arena)?)?;
+                    Some((e.output_name().clone(), md.clone()))
+                })
+                .collect()
+        },
+        HStack { input, exprs, .. } => {
+            let mut md = field_metadata(*input, lp_arena, expr_arena);
+            let new = exprs
+                .iter()
+                .filter_map(|e| {
+                    let column_md = md.get(passthrough_column(e.node(), expr_arena)?)?;
+                    Some((e.output_name().clone(), column_md.clone()))
+                })
+                .collect::<Vec<_>>();
+            for e in exprs {
+                md.remove(e.output_name());
+            }
+            md.extend(new);
+            md
+        },
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let mut md = field_metadata(*input_left, lp_arena, expr_arena);
+            let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+            for (name, column_md) in field_metadata(*input_right, lp_arena, expr_arena) {
+                let name = if left_schema.contains(&name) {
+                    _join_suffix_name(&name, options.args.suffix())
+                } else {
+                    name
+                };
+                md.entry(name).or_insert(column_md);
+            }
+            md
+        },
+        _ => PlHashMap::new(),
+    };
+
+    let schema = ir.schema(lp_arena);
+    out.retain(|name, _| schema.contains(name));
+    out
+}
@@ -370,6 +370,7 @@ impl<'a> TreeFmtNode<'a> {
                                 SinkTypeIR::Memory => "SINK (memory)",
                                 SinkTypeIR::File { .. } => "SINK (file)",
                                 SinkTypeIR::Partition { .. } => "SINK (partition)",
+                                SinkTypeIR::Callback { .. } => "SINK (callback)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
@@ -400,6 +401,7 @@ impl<'a> TreeFmtNode<'a> {
                         input_left,
                         input_right,
                         key,
+                        ..
                     } => ND(
                         wh(h, &format!("MERGE SORTED ON '{key}")),
                         [self.lp_node(Some("LEFT PLAN:".to_string()), *input_left)]
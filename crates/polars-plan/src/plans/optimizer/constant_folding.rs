@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+
+use super::OptimizationRule;
+use crate::constants::get_literal_name;
+use crate::prelude::*;
+
+/// Evaluates expressions whose inputs are all literals at plan time.
+///
+/// Binary expressions on literals are already folded by the [`SimplifyExprRule`] and casts of
+/// literals by the type coercion. This folds elementwise functions on scalar literals and
+/// collapses repeated casts to the same dtype.
+///
+/// [`SimplifyExprRule`]: super::SimplifyExprRule
+pub struct ConstantFolding {}
+
+impl OptimizationRule for ConstantFolding {
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        _lp_arena: &Arena<IR>,
+        _lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        let out = match expr_arena.get(expr_node) {
+            // col.cast(dtype).cast(dtype) => col.cast(dtype)
+            AExpr::Cast {
+                expr,
+                dtype,
+                drop_metadata,
+                ..
+            } => match expr_arena.get(*expr) {
+                inner @ AExpr::Cast {
+                    dtype: inner_dtype,
+                    drop_metadata: inner_drop_metadata,
+                    ..
+                } if inner_dtype == dtype && (*inner_drop_metadata || !*drop_metadata) => {
+                    Some(inner.clone())
+                },
+                _ => None,
+            },
+            AExpr::Function {
+                input,
+                function,
+                options,
+            } if options.is_elementwise() && is_foldable(function) => {
+                fold_function(expr_node, input, function, expr_arena)
+            },
+            _ => None,
+        };
+        Ok(out)
+    }
+}
+
+/// Whether the function can be evaluated at plan time.
+fn is_foldable(function: &FunctionExpr) -> bool {
+    match function {
+        // Don't load user code during optimization.
+        #[cfg(feature = "ffi_plugin")]
+        FunctionExpr::FfiPlugin { .. } => false,
+        _ => true,
+    }
+}
+
+fn fold_function(
+    expr_node: Node,
+    input: &[ExprIR],
+    function: &FunctionExpr,
+    expr_arena: &Arena<AExpr>,
+) -> Option<AExpr> {
+    if input.is_empty() {
+        return None;
+    }
+    // The inputs are literals, so the output dtype doesn't depend on a schema. It is unknown if
+    // it follows a dynamic literal, whose dtype is only resolved by the context it is used in.
+    let dtype = expr_arena
+        .get(expr_node)
+        .to_dtype(&Schema::default(), Context::Default, expr_arena)
+        .ok()?;
+    if !dtype.is_known() {
+        return None;
+    }
+    let mut columns = input
+        .iter()
+        .map(|e| match expr_arena.get(e.node()) {
+            AExpr::Literal(LiteralValue::Scalar(sc)) => {
+                Some(sc.clone().into_column(get_literal_name().clone()))
+            },
+            AExpr::Literal(
+                lv @ LiteralValue::Dyn(
+                    DynLiteralValue::Int(_) | DynLiteralValue::Float(_) | DynLiteralValue::Str(_),
+                ),
+            ) => {
+                let av = lv.to_any_value()?.into_static();
+                Some(Scalar::new(av.dtype(), av).into_column(get_literal_name().clone()))
+            },
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let udf: SpecialEq<Arc<dyn ColumnsUdf>> = function.clone().into();
+    // Errors are raised when the query runs, not during optimization.
+    let out = udf.call_udf(&mut columns).ok()??;
+    if out.len() != 1 || out.dtype() != &dtype {
+        return None;
+    }
+    let value = out.get(0).ok()?.into_static();
+    Some(AExpr::Literal(Scalar::new(dtype, value).into()))
+}
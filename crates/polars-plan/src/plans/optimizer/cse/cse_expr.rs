@@ -164,6 +164,7 @@ impl NaiveExprMerger {
     pub fn add_expr(&mut self, node: Node, arena: &Arena<AExpr>) {
         let node = AexprNode::new(node);
         node.visit(self, arena).unwrap();
+        self.arg_stack.clear();
     }
 
     pub fn get_uniq_id(&self, node: Node) -> Option<u32> {
@@ -198,6 +199,8 @@ impl Visitor for NaiveExprMerger {
             identifier.combine(&arg);
         }
         identifier = identifier.add_ae_node(node, arena);
+        // Pass the identifier to the parent, so the hash covers the whole subexpression.
+        self.arg_stack.push(Some(identifier.clone()));
         let uniq_id = *self.identifier_to_uniq_id.entry(
             identifier,
             || {
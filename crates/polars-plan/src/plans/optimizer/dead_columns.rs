@@ -0,0 +1,143 @@
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+use polars_utils::arena::{Arena, Node};
+
+use crate::plans::{AExpr, ExprIR, IR, IRBuilder, OutputName, aexpr_to_leaf_names_iter};
+
+/// The columns of a node's output that are referenced downstream.
+enum Live {
+    /// All columns except these.
+    AllExcept(PlHashSet<PlSmallStr>),
+    Only(PlHashSet<PlSmallStr>),
+}
+
+impl Live {
+    fn all() -> Self {
+        Self::AllExcept(PlHashSet::new())
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::AllExcept(dead) => !dead.contains(name),
+            Self::Only(live) => live.contains(name),
+        }
+    }
+
+    fn insert(&mut self, name: PlSmallStr) {
+        match self {
+            Self::AllExcept(dead) => {
+                dead.remove(&name);
+            },
+            Self::Only(live) => {
+                live.insert(name);
+            },
+        }
+    }
+
+    fn remove(&mut self, name: &PlSmallStr) {
+        match self {
+            Self::AllExcept(dead) => {
+                dead.insert(name.clone());
+            },
+            Self::Only(live) => {
+                live.remove(name);
+            },
+        }
+    }
+
+    fn extend_with_leaf_names(&mut self, e: &ExprIR, expr_arena: &Arena<AExpr>) {
+        for name in aexpr_to_leaf_names_iter(e.node(), expr_arena) {
+            self.insert(name);
+        }
+    }
+}
+
+/// Dead column elimination.
+///
+/// Removes the expressions of `with_columns` whose output is never referenced downstream, e.g.
+/// because a later `with_columns` overwrites it or a `select` doesn't use it. The plan is walked
+/// from the root down so that every node knows which of its output columns are referenced.
+///
+/// Dead expressions that add a new column, or change the dtype of an existing one, are replaced
+/// by a null literal of the same dtype instead, so that the schemas of the nodes above stay valid.
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut stack = vec![(root, Live::all())];
+
+    while let Some((node, mut live)) = stack.pop() {
+        match lp_arena.get(node) {
+            IR::HStack {
+                input,
+                exprs,
+                schema,
+                options,
+            } => {
+                let (input, options) = (*input, *options);
+                if exprs.iter().all(|e| live.contains(e.output_name())) {
+                    for e in exprs {
+                        live.remove(e.output_name());
+                    }
+                    for e in exprs {
+                        live.extend_with_leaf_names(e, expr_arena);
+                    }
+                    stack.push((input, live));
+                    continue;
+                }
+
+                let schema = schema.clone();
+                let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                let exprs = exprs
+                    .clone()
+                    .into_iter()
+                    .filter_map(|e| {
+                        let name = e.output_name();
+                        if live.contains(name) {
+                            Some(e)
+                        } else if input_schema.get(name) == schema.get(name) {
+                            None
+                        } else {
+                            let dtype = schema.get(name).unwrap().clone();
+                            let lit = expr_arena.add(AExpr::Literal(Scalar::null(dtype).into()));
+                            Some(ExprIR::new(lit, OutputName::Alias(name.clone())))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                for e in &exprs {
+                    live.remove(e.output_name());
+                }
+                for e in &exprs {
+                    live.extend_with_leaf_names(e, expr_arena);
+                }
+
+                if exprs.is_empty() {
+                    let ir = lp_arena.take(input);
+                    lp_arena.replace(node, ir);
+                    // The input now lives at this node.
+                    stack.push((node, live));
+                } else {
+                    let ir = IRBuilder::new(input, expr_arena, lp_arena)
+                        .with_columns(exprs, options)
+                        .build();
+                    lp_arena.replace(node, ir);
+                    stack.push((input, live));
+                }
+            },
+            IR::Filter { input, predicate } => {
+                live.extend_with_leaf_names(predicate, expr_arena);
+                stack.push((*input, live));
+            },
+            IR::Select { input, expr, .. } => {
+                let mut input_live = Live::Only(PlHashSet::new());
+                for e in expr {
+                    input_live.extend_with_leaf_names(e, expr_arena);
+                }
+                stack.push((*input, input_live));
+            },
+            ir => {
+                for input in ir.get_inputs_vec() {
+                    stack.push((input, Live::all()));
+                }
+            },
+        }
+    }
+}
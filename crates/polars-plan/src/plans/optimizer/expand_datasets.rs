@@ -124,10 +124,12 @@ impl OptimizationRule for ExpandDatasets {
                                 glob: _,
                                 projection: _projection @ None,
                                 row_index: _row_index @ None,
+                                row_index_u64_offset: _row_index_u64_offset @ None,
                                 pre_slice: _pre_slice @ None,
                                 cast_columns_policy,
                                 missing_columns_policy,
                                 include_file_paths: _include_file_paths @ None,
+                                file_metadata_columns: _,
                             } = *resolved_unified_scan_args
                             else {
                                 panic!(
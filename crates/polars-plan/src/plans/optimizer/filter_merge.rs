@@ -0,0 +1,50 @@
+use polars_core::error::PolarsResult;
+use polars_utils::arena::{Arena, Node};
+
+use super::OptimizationRule;
+use crate::dsl::Operator;
+use crate::plans::{AExpr, ExprIR, ExprPushdownGroup, IR};
+
+/// Collapses `Filter(Filter(x, p1), p2)` into `Filter(x, p1 & p2)`.
+///
+/// The outer predicate is then evaluated on all rows of `x`, so this is only done if it is
+/// elementwise and can't raise an error on the rows that `p1` removes. Predicate pushdown combines
+/// such predicates as well, so this only has an effect if it is disabled.
+pub struct FilterMerge {}
+
+impl OptimizationRule for FilterMerge {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        let IR::Filter { input, predicate } = lp_arena.get(node) else {
+            return Ok(None);
+        };
+        let IR::Filter {
+            input: inner_input,
+            predicate: inner_predicate,
+        } = lp_arena.get(*input)
+        else {
+            return Ok(None);
+        };
+        if ExprPushdownGroup::Pushable
+            .update_with_expr_rec(expr_arena.get(predicate.node()), expr_arena, None)
+            .blocks_pushdown(true)
+        {
+            return Ok(None);
+        }
+
+        let input = *inner_input;
+        let and = expr_arena.add(AExpr::BinaryExpr {
+            left: inner_predicate.node(),
+            op: Operator::And,
+            right: predicate.node(),
+        });
+        Ok(Some(IR::Filter {
+            input,
+            predicate: ExprIR::from_node(and, expr_arena),
+        }))
+    }
+}
@@ -0,0 +1,163 @@
+//! Optimization that puts the smaller input of an inner join on the build side.
+//!
+//! The right input of a join is the build side, i.e. the side that is hashed. If the left input
+//! is estimated to be smaller, the inputs are swapped and a projection restores the column
+//! order. Row counts are estimated from in-memory frames and scan statistics, or taken from the
+//! hints set with `JoinBuilder::row_hints`.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptions};
+use crate::plans::schema::det_join_schema;
+use crate::plans::{ArenaLpIter, ExprIR};
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let joins = (&*lp_arena)
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Join { .. }).then_some(node))
+        .collect::<Vec<_>>();
+
+    for node in joins {
+        let IR::Join {
+            input_left,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options,
+        } = lp_arena.get(node)
+        else {
+            unreachable!()
+        };
+
+        if !can_swap(
+            lp_arena,
+            expr_arena,
+            *input_left,
+            *input_right,
+            left_on,
+            right_on,
+            options,
+        ) {
+            continue;
+        }
+        let rows_left = hint(options.rows_left).or_else(|| estimate_rows(*input_left, lp_arena));
+        let rows_right = hint(options.rows_right).or_else(|| estimate_rows(*input_right, lp_arena));
+        let (Some(rows_left), Some(rows_right)) = (rows_left, rows_right) else {
+            continue;
+        };
+        if rows_left >= rows_right {
+            continue;
+        }
+
+        let mut swapped_options = options.as_ref().clone();
+        swapped_options.args.validation = swapped_options.args.validation.swap(true);
+        std::mem::swap(
+            &mut swapped_options.rows_left,
+            &mut swapped_options.rows_right,
+        );
+        let Ok(swapped_schema) = det_join_schema(
+            &lp_arena.get(*input_right).schema(lp_arena),
+            &lp_arena.get(*input_left).schema(lp_arena),
+            right_on,
+            left_on,
+            &swapped_options,
+            expr_arena,
+        ) else {
+            continue;
+        };
+
+        let swapped = IR::Join {
+            input_left: *input_right,
+            input_right: *input_left,
+            schema: swapped_schema,
+            left_on: right_on.clone(),
+            right_on: left_on.clone(),
+            options: Arc::new(swapped_options),
+        };
+        let columns = schema.clone();
+        let swapped = lp_arena.add(swapped);
+        lp_arena.replace(
+            node,
+            IR::SimpleProjection {
+                input: swapped,
+                columns,
+            },
+        );
+    }
+}
+
+/// Whether swapping the inputs only changes the column order of the output.
+///
+/// This holds for unordered inner joins on columns with the same names that are coalesced,
+/// and that don't have other overlapping column names.
+fn can_swap(
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    input_left: Node,
+    input_right: Node,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    options: &JoinOptions,
+) -> bool {
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Inner)
+        || !matches!(args.maintain_order, MaintainOrderJoin::None)
+        || !args.should_coalesce()
+        || args.slice.is_some()
+        || options.options.is_some()
+    {
+        return false;
+    }
+
+    let schema_left = lp_arena.get(input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(input_right).schema(lp_arena);
+    let mut keys = PlHashSet::with_capacity(left_on.len());
+    for (l, r) in left_on.iter().zip(right_on) {
+        match (expr_arena.get(l.node()), expr_arena.get(r.node())) {
+            (AExpr::Column(l), AExpr::Column(r))
+                if l == r && schema_left.get(l) == schema_right.get(r) =>
+            {
+                keys.insert(l.clone());
+            },
+            _ => return false,
+        }
+    }
+    schema_left
+        .iter_names()
+        .all(|name| keys.contains(name) || !schema_right.contains(name))
+}
+
+fn hint(rows: (Option<usize>, usize)) -> Option<usize> {
+    rows.0.or((rows.1 != usize::MAX).then_some(rows.1))
+}
+
+/// An upper bound of the number of rows of `node`, if it can be estimated.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>) -> Option<usize> {
+    use IR::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => Some(df.height()),
+        Scan { file_info, .. } => hint(file_info.row_estimation),
+        Slice { input, len, .. } => {
+            let len = *len as usize;
+            Some(estimate_rows(*input, lp_arena).map_or(len, |n| n.min(len)))
+        },
+        Union { inputs, .. } => inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena))
+            .try_fold(0usize, |acc, n| Some(acc.saturating_add(n?))),
+        Filter { input, .. }
+        | Select { input, .. }
+        | HStack { input, .. }
+        | SimpleProjection { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. }
+        | GroupBy { input, .. } => estimate_rows(*input, lp_arena),
+        _ => None,
+    }
+}
@@ -9,12 +9,16 @@ mod cluster_with_columns;
 mod collapse_and_project;
 mod collapse_joins;
 mod collect_members;
+mod constant_folding;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
+mod dead_columns;
+mod filter_merge;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
@@ -27,6 +31,7 @@ mod slice_pushdown_lp;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
+pub use constant_folding::ConstantFolding;
 #[cfg(feature = "cse")]
 pub use cse::NaiveExprMerger;
 use delay_rechunk::DelayRechunk;
@@ -39,6 +44,7 @@ pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
 
+use self::filter_merge::FilterMerge;
 use self::flatten_union::FlattenUnionRule;
 use self::set_order::set_order_flags;
 pub use crate::frame::{AllowedOptimizations, OptFlags};
@@ -188,6 +194,8 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
             let mut count_star_opt = CountStar::new();
             count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top)?;
         }
+
+        dead_columns::optimize(lp_top, lp_arena, expr_arena);
     }
 
     if opt_flags.predicate_pushdown() {
@@ -240,11 +248,15 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     // is completed.
     if opt_flags.simplify_expr() {
         rules.push(Box::new(SimplifyBooleanRule {}));
+        rules.push(Box::new(ConstantFolding {}));
     }
 
     if !opt_flags.eager() {
         rules.push(Box::new(FlattenUnionRule {}));
     }
+    // Predicate pushdown already combines the predicates it can move, this merges the filters that
+    // are left when it is disabled.
+    rules.push(Box::new(FilterMerge {}));
 
     // Note: ExpandDatasets must run after slice and predicate pushdown.
     rules.push(Box::new(expand_datasets::ExpandDatasets {}) as Box<dyn OptimizationRule>);
@@ -255,6 +267,10 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
+    if opt_flags.contains(OptFlags::ROW_ESTIMATE) {
+        join_reorder::optimize(lp_top, lp_arena, expr_arena)
+    }
+
     if _cse_plan_changed
         && get_members_opt!()
             .is_some_and(|members| members.has_joins_or_unions && members.has_cache)
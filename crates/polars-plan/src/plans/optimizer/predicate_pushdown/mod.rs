@@ -368,6 +368,12 @@ impl PredicatePushDown<'_> {
                 if let Some(col) = unified_scan_args.include_file_paths.as_deref() {
                     blocked_names.push(col);
                 }
+                blocked_names.extend(
+                    unified_scan_args
+                        .file_metadata_columns
+                        .iter_names()
+                        .map(|x| x.as_str()),
+                );
 
                 match &*scan_type {
                     #[cfg(feature = "parquet")]
@@ -554,12 +560,13 @@ impl PredicatePushDown<'_> {
                                 expr_arena,
                             ))
                         },
-                        FunctionIR::Unnest { columns } => {
+                        FunctionIR::Unnest { columns, options } => {
                             let exclude = columns.iter().cloned().collect::<PlHashSet<_>>();
 
+                            // Renamed or overwritten columns refer to different data in the input.
                             let local_predicates =
                                 transfer_to_local_by_name(expr_arena, &mut acc_predicates, |x| {
-                                    exclude.contains(x)
+                                    options.renames() || exclude.contains(x)
                                 });
 
                             let lp = self.pushdown_and_continue(
@@ -93,6 +93,7 @@ fn get_scan_columns(
     expr_arena: &Arena<AExpr>,
     row_index: Option<&RowIndex>,
     file_path_col: Option<&str>,
+    file_metadata_cols: &FileMetadataColumns,
 ) -> Option<Arc<[PlSmallStr]>> {
     if !acc_projections.is_empty() {
         Some(
@@ -113,6 +114,10 @@ fn get_scan_columns(
                         }
                     }
 
+                    if file_metadata_cols.contains(name) {
+                        return None;
+                    }
+
                     Some(name.clone())
                 })
                 .collect::<Arc<[_]>>(),
@@ -410,8 +415,13 @@ impl ProjectionPushDown {
                     ctx.process_count_star_at_scan(&options.schema, expr_arena);
                 }
 
-                options.with_columns =
-                    get_scan_columns(&ctx.acc_projections, expr_arena, None, None);
+                options.with_columns = get_scan_columns(
+                    &ctx.acc_projections,
+                    expr_arena,
+                    None,
+                    None,
+                    &Default::default(),
+                );
 
                 options.output_schema = if options.with_columns.is_none() {
                     None
@@ -497,6 +507,7 @@ impl ProjectionPushDown {
                         expr_arena,
                         unified_scan_args.row_index.as_ref(),
                         unified_scan_args.include_file_paths.as_deref(),
+                        &unified_scan_args.file_metadata_columns,
                     );
 
                     output_schema = if unified_scan_args.projection.is_some() {
@@ -514,6 +525,13 @@ impl ProjectionPushDown {
                             }
                         }
 
+                        for name in unified_scan_args.file_metadata_columns.iter_names() {
+                            if let Some(i) = schema.index_of(name) {
+                                let (name, dtype) = schema.shift_remove_index(i).unwrap();
+                                schema.insert_at_index(schema.len(), name, dtype)?;
+                            }
+                        }
+
                         Some(Arc::new(schema))
                     } else {
                         None
@@ -535,6 +553,7 @@ impl ProjectionPushDown {
                         file_schema.shift_remove(name);
                         file_info.schema = Arc::new(file_schema);
                         unified_scan_args.row_index = None;
+                        unified_scan_args.row_index_u64_offset = None;
                     }
                 };
 
@@ -552,6 +571,25 @@ impl ProjectionPushDown {
                     }
                 };
 
+                // Same for file metadata columns that are not projected.
+                if let Some(schema) = output_schema.as_ref() {
+                    let mut file_schema = None;
+                    unified_scan_args.file_metadata_columns.retain(|name| {
+                        let keep = schema.contains(name);
+                        if !keep {
+                            file_schema
+                                .get_or_insert_with(|| {
+                                    Arc::unwrap_or_clone(file_info.schema.clone())
+                                })
+                                .shift_remove(name);
+                        }
+                        keep
+                    });
+                    if let Some(file_schema) = file_schema {
+                        file_info.schema = Arc::new(file_schema);
+                    }
+                }
+
                 let lp = Scan {
                     sources,
                     file_info,
@@ -745,6 +783,7 @@ impl ProjectionPushDown {
                 input_left,
                 input_right,
                 key,
+                nulls_last,
             } => {
                 if ctx.has_pushed_down() {
                     // make sure that the filter column is projected
@@ -758,6 +797,7 @@ impl ProjectionPushDown {
                     input_left,
                     input_right,
                     key,
+                    nulls_last,
                 })
             },
             Invalid => unreachable!(),
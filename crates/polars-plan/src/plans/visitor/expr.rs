@@ -53,7 +53,7 @@ impl TreeWalker for Expr {
             BinaryExpr { left, op, right } => {
                 BinaryExpr { left: am(left, &mut f)? , op, right: am(right, f)?}
             },
-            Cast { expr, dtype, options: strict } => Cast { expr: am(expr, f)?, dtype, options: strict },
+            Cast { expr, dtype, options: strict, drop_metadata } => Cast { expr: am(expr, f)?, dtype, options: strict, drop_metadata },
             Sort { expr, options } => Sort { expr: am(expr, f)?, options },
             Gather { expr, idx, returns_scalar } => Gather { expr: am(expr, &mut f)?, idx: am(idx, f)?, returns_scalar },
             SortBy { expr, by, sort_options } => SortBy { expr: am(expr, &mut f)?, by: by.into_iter().map(f).collect::<Result<_, _>>()?, sort_options },
@@ -171,14 +171,16 @@ impl AExpr {
                 Cast {
                     options: strict_l,
                     dtype: dtl,
+                    drop_metadata: dml,
                     ..
                 },
                 Cast {
                     options: strict_r,
                     dtype: dtr,
+                    drop_metadata: dmr,
                     ..
                 },
-            ) => strict_l == strict_r && dtl == dtr,
+            ) => strict_l == strict_r && dtl == dtr && dml == dmr,
             (Sort { options: l, .. }, Sort { options: r, .. }) => l == r,
             (Gather { .. }, Gather { .. })
             | (Filter { .. }, Filter { .. })
@@ -196,8 +196,10 @@ impl Hash for HashableEqLP<'_> {
                 input_left: _,
                 input_right: _,
                 key,
+                nulls_last,
             } => {
                 key.hash(state);
+                nulls_last.hash(state);
             },
             IR::Invalid => unreachable!(),
         }
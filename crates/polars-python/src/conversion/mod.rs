@@ -1352,6 +1352,7 @@ impl<'py> FromPyObject<'py> for Wrap<CastColumnsPolicy> {
 
         return Ok(Wrap(CastColumnsPolicy {
             integer_upcast,
+            integer_downcast: None,
             float_upcast,
             float_downcast,
             datetime_nanoseconds_downcast,
@@ -117,11 +117,7 @@ impl PyDataFrame {
     }
 
     pub fn rechunk(&self, py: Python) -> PyResult<Self> {
-        py.enter_polars_df(|| {
-            let mut df = self.df.clone();
-            df.as_single_chunk_par();
-            Ok(df)
-        })
+        py.enter_polars_df(|| Ok(self.df.rechunk_parallel()))
     }
 
     /// Format `DataFrame` as String
@@ -631,6 +631,7 @@ impl PyExpr {
             period: Duration::try_parse(period).map_err(PyPolarsErr::from)?,
             offset: Duration::try_parse(offset).map_err(PyPolarsErr::from)?,
             closed_window: closed.0,
+            ..Default::default()
         };
 
         Ok(self.inner.clone().rolling(options).into())
@@ -45,7 +45,7 @@ impl PyExpr {
         self.inner.clone().name().to_uppercase().into()
     }
 
-    fn name_map_fields(&self, name_mapper: PyObject) -> Self {
+    fn name_map_fields(&self, name_mapper: PyObject, recursive: bool) -> Self {
         let name_mapper = Arc::new(move |name: &str| {
             Python::with_gil(|py| {
                 let out = name_mapper.call1(py, (name,)).unwrap();
@@ -54,14 +54,29 @@ impl PyExpr {
             })
         }) as FieldsNameMapper;
 
-        self.inner.clone().name().map_fields(name_mapper).into()
+        let name = self.inner.clone().name();
+        if recursive {
+            name.map_fields_recursive(name_mapper).into()
+        } else {
+            name.map_fields(name_mapper).into()
+        }
     }
 
-    fn name_prefix_fields(&self, prefix: &str) -> Self {
-        self.inner.clone().name().prefix_fields(prefix).into()
+    fn name_prefix_fields(&self, prefix: &str, recursive: bool) -> Self {
+        let name = self.inner.clone().name();
+        if recursive {
+            name.prefix_fields_recursive(prefix).into()
+        } else {
+            name.prefix_fields(prefix).into()
+        }
     }
 
-    fn name_suffix_fields(&self, suffix: &str) -> Self {
-        self.inner.clone().name().suffix_fields(suffix).into()
+    fn name_suffix_fields(&self, suffix: &str, recursive: bool) -> Self {
+        let name = self.inner.clone().name();
+        if recursive {
+            name.suffix_fields_recursive(suffix).into()
+        } else {
+            name.suffix_fields(suffix).into()
+        }
     }
 }
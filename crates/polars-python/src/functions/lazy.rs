@@ -126,9 +126,10 @@ pub fn collect_all(
     optflags: PyOptFlags,
     py: Python<'_>,
 ) -> PyResult<Vec<PyDataFrame>> {
-    let plans = lfs_to_plans(lfs);
-    let dfs =
-        py.enter_polars(|| LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner))?;
+    let lfs = lfs.into_iter().map(|lf| lf.ldf).collect();
+    let dfs = py.enter_polars(|| {
+        LazyFrame::collect_all_frames_with_engine(lfs, engine.0, optflags.inner)
+    })?;
     Ok(dfs.into_iter().map(Into::into).collect())
 }
 
@@ -147,9 +148,9 @@ pub fn collect_all_with_callback(
     lambda: PyObject,
     py: Python<'_>,
 ) {
-    let plans = lfs.into_iter().map(|lf| lf.ldf.logical_plan).collect();
+    let lfs = lfs.into_iter().map(|lf| lf.ldf).collect();
     let result = py
-        .enter_polars(|| LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner))
+        .enter_polars(|| LazyFrame::collect_all_frames_with_engine(lfs, engine.0, optflags.inner))
         .map(|dfs| {
             dfs.into_iter()
                 .map(Into::into)
@@ -340,6 +340,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            encryption: None,
         };
 
         let sources = sources.0;
@@ -395,6 +396,7 @@ impl PyLazyFrame {
             glob,
             projection: None,
             row_index,
+            row_index_u64_offset: None,
             pre_slice: n_rows.map(|len| Slice::Positive { offset: 0, len }),
             cast_columns_policy: cast_options.0,
             missing_columns_policy: if allow_missing_columns {
@@ -403,6 +405,7 @@ impl PyLazyFrame {
                 MissingColumnsPolicy::Raise
             },
             include_file_paths: include_file_paths.map(|x| x.into()),
+            file_metadata_columns: Default::default(),
         };
 
         let lf: LazyFrame = DslBuilder::scan_parquet(sources, options, unified_scan_args)
@@ -455,9 +458,12 @@ impl PyLazyFrame {
             cache,
             rechunk,
             row_index,
+            row_index_u64_offset: None,
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            file_metadata_columns: Default::default(),
+            lock_file: false,
         };
 
         let sources = sources.0;
@@ -720,7 +726,7 @@ impl PyLazyFrame {
         py: Python<'_>,
         lambda_post_opt: Option<PyObject>,
     ) -> PyResult<(PyDataFrame, PyDataFrame)> {
-        let (df, time_df) = py.enter_polars(|| {
+        let (df, info) = py.enter_polars(|| {
             let ldf = self.ldf.clone();
             if let Some(lambda) = lambda_post_opt {
                 ldf._profile_post_opt(|root, lp_arena, expr_arena, duration_since_start| {
@@ -730,7 +736,7 @@ impl PyLazyFrame {
                 ldf.profile()
             }
         })?;
-        Ok((df.into(), time_df.into()))
+        Ok((df.into(), info.timings.into()))
     }
 
     #[pyo3(signature = (engine, lambda_post_opt=None))]
@@ -813,6 +819,7 @@ impl PyLazyFrame {
             data_page_size,
             key_value_metadata: metadata.0,
             field_overwrites: field_overwrites.into_iter().map(|f| f.0).collect(),
+            sorting_columns: Vec::new(),
         };
 
         let cloud_options = match target.base_path() {
@@ -957,6 +964,7 @@ impl PyLazyFrame {
             datetime_format,
             float_scientific,
             float_precision,
+            column_formats: Default::default(),
             separator,
             quote_char,
             null: null_value,
@@ -1026,7 +1034,7 @@ impl PyLazyFrame {
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions::default();
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -1128,6 +1136,7 @@ impl PyLazyFrame {
                 period: Duration::try_parse(period).map_err(PyPolarsErr::from)?,
                 offset: Duration::try_parse(offset).map_err(PyPolarsErr::from)?,
                 closed_window,
+                ..Default::default()
             },
         );
 
@@ -1409,6 +1418,7 @@ impl PyLazyFrame {
                 extra_struct_fields: extra_struct_fields[i],
                 integer_cast: integer_cast[i],
                 float_cast: float_cast[i],
+                lossy_cast: false,
             })
             .collect();
 
@@ -1645,11 +1655,12 @@ impl PyLazyFrame {
     }
 
     #[cfg(feature = "merge_sorted")]
-    fn merge_sorted(&self, other: Self, key: &str) -> PyResult<Self> {
+    #[pyo3(signature = (other, key, nulls_last=false))]
+    fn merge_sorted(&self, other: Self, key: &str, nulls_last: bool) -> PyResult<Self> {
         let out = self
             .ldf
             .clone()
-            .merge_sorted(other.ldf, key)
+            .merge_sorted_with_nulls_last(other.ldf, key, nulls_last)
             .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 use polars::prelude::sync_on_close::SyncOnCloseType;
 use polars::prelude::{PartitionVariant, SinkFinishCallback, SinkOptions, SortColumn, SpecialEq};
 use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::python_function::{PythonFunction, PythonObject};
 use pyo3::exceptions::PyValueError;
 use pyo3::pybacked::PyBackedStr;
@@ -186,9 +187,9 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
-        if parsed.len() != 3 {
+        if !(3..=6).contains(&parsed.len()) {
             return Err(PyValueError::new_err(
-                "`sink_options` must be a dictionary with the exactly 3 field.",
+                "`sink_options` must be a dictionary with between 3 and 6 fields.",
             ));
         }
 
@@ -208,10 +209,27 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             .ok_or_else(|| PyValueError::new_err("`sink_options` must contain `mkdir` field"))?;
         let mkdir = mkdir.extract::<bool>()?;
 
+        let max_rows_per_file = PyDictMethods::get_item(&parsed, "max_rows_per_file")?
+            .map(|v| v.extract::<Option<IdxSize>>())
+            .transpose()?
+            .flatten();
+        let max_bytes_per_file = PyDictMethods::get_item(&parsed, "max_bytes_per_file")?
+            .map(|v| v.extract::<Option<u64>>())
+            .transpose()?
+            .flatten();
+        let file_name_template = PyDictMethods::get_item(&parsed, "file_name_template")?
+            .map(|v| v.extract::<Option<Wrap<PlSmallStr>>>())
+            .transpose()?
+            .flatten()
+            .map(|v| v.0);
+
         Ok(Wrap(SinkOptions {
             sync_on_close,
             maintain_order,
             mkdir,
+            max_rows_per_file,
+            max_bytes_per_file,
+            file_name_template,
         }))
     }
 }
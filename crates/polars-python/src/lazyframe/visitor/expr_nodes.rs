@@ -1,6 +1,7 @@
 #[cfg(feature = "iejoin")]
 use polars::prelude::InequalityOperator;
 use polars::series::ops::NullBehavior;
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::series::IsSorted;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
@@ -287,6 +288,7 @@ pub struct Cast {
     // 0: strict
     // 1: non-strict
     // 2: overflow
+    // 3: saturating
     #[pyo3(get)]
     options: u8,
 }
@@ -446,6 +448,11 @@ impl PyRollingGroupOptions {
     fn closed_window(&self) -> &str {
         self.inner.closed_window.into()
     }
+
+    #[getter]
+    fn include_window_id(&self) -> bool {
+        self.inner.include_window_id
+    }
 }
 
 #[pyclass(name = "DynamicGroupOptions")]
@@ -485,6 +492,11 @@ impl PyDynamicGroupOptions {
         self.inner.include_boundaries
     }
 
+    #[getter]
+    fn include_window_id(&self) -> bool {
+        self.inner.include_window_id
+    }
+
     #[getter]
     fn closed_window(&self) -> &str {
         self.inner.closed_window.into()
@@ -584,10 +596,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             expr,
             dtype,
             options,
+            drop_metadata: _,
         } => Cast {
             expr: expr.0,
             dtype: Wrap(dtype.clone()).into_py_any(py)?,
-            options: *options as u8,
+            options: match options {
+                CastOptions::Strict => 0,
+                CastOptions::NonStrict => 1,
+                CastOptions::Overflowing => 2,
+                CastOptions::Saturating => 3,
+            },
         }
         .into_py_any(py),
         AExpr::Sort { expr, options } => Sort {
@@ -790,6 +808,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ToInteger(strict) => {
                         (PyStringFunction::ToInteger, strict).into_py_any(py)
                     },
+                    StringFunction::ToIntegerWith(_) => {
+                        return Err(PyNotImplementedError::new_err("to_integer_with"));
+                    },
                     StringFunction::LenBytes => (PyStringFunction::LenBytes,).into_py_any(py),
                     StringFunction::LenChars => (PyStringFunction::LenChars,).into_py_any(py),
                     StringFunction::Lowercase => (PyStringFunction::Lowercase,).into_py_any(py),
@@ -1037,6 +1058,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     BooleanFunction::Not => (PyBooleanFunction::Not,).into_py_any(py),
                 },
                 FunctionExpr::Abs => ("abs",).into_py_any(py),
+                FunctionExpr::MaxAbs => ("max_abs",).into_py_any(py),
+                FunctionExpr::MinAbs => ("min_abs",).into_py_any(py),
                 #[cfg(feature = "hist")]
                 FunctionExpr::Hist {
                     bin_count,
@@ -1120,6 +1143,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"));
                     },
+                    RollingFunctionBy::CorrCovBy { is_corr, .. } => {
+                        let name = if *is_corr { "corr" } else { "cov" };
+                        return Err(PyNotImplementedError::new_err(format!("rolling {name} by")));
+                    },
                 },
                 FunctionExpr::ShiftAndFill => ("shift_and_fill",).into_py_any(py),
                 FunctionExpr::Shift => ("shift",).into_py_any(py),
@@ -1128,6 +1155,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Mode => ("mode",).into_py_any(py),
                 FunctionExpr::Skew(bias) => ("skew", bias).into_py_any(py),
                 FunctionExpr::Kurtosis(fisher, bias) => ("kurtosis", fisher, bias).into_py_any(py),
+                FunctionExpr::Autocorr { lag } => ("autocorr", lag).into_py_any(py),
+                FunctionExpr::GarmanKlassVolatility { window } => {
+                    ("garman_klass_volatility", window).into_py_any(py)
+                },
+                FunctionExpr::SavgolFilter {
+                    window_size,
+                    poly_order,
+                } => ("savgol_filter", window_size, poly_order).into_py_any(py),
                 FunctionExpr::Reshape(_) => return Err(PyNotImplementedError::new_err("reshape")),
                 #[cfg(feature = "repeat_by")]
                 FunctionExpr::RepeatBy => ("repeat_by",).into_py_any(py),
@@ -1137,9 +1172,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     options: _,
                     seed: _,
                 } => return Err(PyNotImplementedError::new_err("rank")),
+                FunctionExpr::RankBy {
+                    options: _,
+                    seed: _,
+                } => return Err(PyNotImplementedError::new_err("rank_by")),
                 FunctionExpr::Clip { has_min, has_max } => {
                     ("clip", has_min, has_max).into_py_any(py)
                 },
+                FunctionExpr::Winsorize { lower, upper } => {
+                    ("winsorize", lower, upper).into_py_any(py)
+                },
+                FunctionExpr::IsClose {
+                    rel_tol,
+                    abs_tol,
+                    nans_equal,
+                } => ("is_close", rel_tol, abs_tol, nans_equal).into_py_any(py),
                 FunctionExpr::AsStruct => ("as_struct",).into_py_any(py),
                 #[cfg(feature = "top_k")]
                 FunctionExpr::TopK { descending } => ("top_k", descending).into_py_any(py),
@@ -1148,7 +1195,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::CumProd { reverse } => ("cum_prod", reverse).into_py_any(py),
                 FunctionExpr::CumMin { reverse } => ("cum_min", reverse).into_py_any(py),
                 FunctionExpr::CumMax { reverse } => ("cum_max", reverse).into_py_any(py),
+                FunctionExpr::CumSumReset => ("cum_sum_reset",).into_py_any(py),
+                FunctionExpr::CumTrapz => ("cum_trapz",).into_py_any(py),
+                FunctionExpr::SessionId { gap: _, sort: _ } => {
+                    return Err(PyNotImplementedError::new_err("session_id"));
+                },
                 FunctionExpr::Reverse => ("reverse",).into_py_any(py),
+                FunctionExpr::ScatterByMask => ("scatter_by_mask",).into_py_any(py),
                 FunctionExpr::ValueCounts {
                     sort,
                     parallel,
@@ -1156,7 +1209,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     normalize,
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 FunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
+                FunctionExpr::UniqueWithCounts => ("unique_with_counts",).into_py_any(py),
                 FunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                #[cfg(feature = "approx_quantile")]
+                FunctionExpr::TDigest(_) => {
+                    return Err(PyNotImplementedError::new_err("tdigest"));
+                },
                 FunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 FunctionExpr::ShrinkType => ("shrink_dtype",).into_py_any(py),
                 FunctionExpr::Diff(null_behaviour) => (
@@ -1209,9 +1267,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 #[cfg(feature = "cutqcut")]
                 FunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
                 #[cfg(feature = "rle")]
-                FunctionExpr::RLE => ("rle",).into_py_any(py),
+                FunctionExpr::RLE { lengths_dtype } => {
+                    ("rle", Wrap(lengths_dtype.clone())).into_py_any(py)
+                },
                 #[cfg(feature = "rle")]
                 FunctionExpr::RLEID => ("rle_id",).into_py_any(py),
+                #[cfg(feature = "rle")]
+                FunctionExpr::RLEDecode => ("rle_decode",).into_py_any(py),
                 FunctionExpr::ToPhysical => ("to_physical",).into_py_any(py),
                 FunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
@@ -1265,6 +1327,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 #[cfg(feature = "top_k")]
                 FunctionExpr::TopKBy { descending } => ("top_k_by", descending).into_py_any(py),
+                FunctionExpr::ArgSortBy {
+                    descending,
+                    nulls_last,
+                    limit,
+                    maintain_order,
+                } => ("arg_sort_by", descending, nulls_last, limit, maintain_order).into_py_any(py),
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"));
                 },
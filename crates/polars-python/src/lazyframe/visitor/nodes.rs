@@ -109,11 +109,11 @@ impl PyFileOptions {
         self.inner.cache
     }
     #[getter]
-    fn row_index(&self) -> Option<(&str, IdxSize)> {
+    fn row_index(&self) -> Option<(&str, u64)> {
         self.inner
             .row_index
             .as_ref()
-            .map(|n| (n.name.as_str(), n.offset))
+            .map(|n| (n.name.as_str(), self.inner.row_index_offset()))
     }
     #[getter]
     fn rechunk(&self, _py: Python<'_>) -> bool {
@@ -238,6 +238,8 @@ pub struct MergeSorted {
     input_right: usize,
     #[pyo3(get)]
     key: String,
+    #[pyo3(get)]
+    nulls_last: bool,
 }
 
 #[pyclass]
@@ -568,7 +570,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     original: _,
                 } => return Err(PyNotImplementedError::new_err("pipeline mapfunction")),
-                FunctionIR::Unnest { columns } => (
+                FunctionIR::Unnest { columns, .. } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
                 )
@@ -597,6 +599,11 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     offset,
                 } => ("row_index", name.to_string(), offset.unwrap_or(0)).into_py_any(py)?,
+                FunctionIR::RowIndexU64 {
+                    name,
+                    schema: _,
+                    offset,
+                } => ("row_index_u64", name.to_string(), offset).into_py_any(py)?,
                 FunctionIR::FastCount {
                     sources,
                     scan_type,
@@ -664,10 +671,12 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             input_left,
             input_right,
             key,
+            nulls_last,
         } => MergeSorted {
             input_left: input_left.0,
             input_right: input_right.0,
             key: key.to_string(),
+            nulls_last: *nulls_last,
         }
         .into_py_any(py),
         IR::Invalid => Err(PyNotImplementedError::new_err("Invalid")),
@@ -14,6 +14,7 @@ impl PySeries {
         name: &str,
         chunks: Vec<(Py_uintptr_t, Py_uintptr_t)>,
     ) -> PyResult<Self> {
+        let mut field = None;
         let chunks = chunks
             .into_iter()
             .map(|(schema_ptr, array_ptr)| {
@@ -24,12 +25,23 @@ impl PySeries {
                 let array = std::ptr::read_unaligned(array_ptr);
                 let schema = &*schema_ptr;
 
-                let field = arrow::ffi::import_field_from_c(schema).unwrap();
-                arrow::ffi::import_array_from_c(array, field.dtype).unwrap()
+                let chunk_field = arrow::ffi::import_field_from_c(schema).unwrap();
+                let array =
+                    arrow::ffi::import_array_from_c(array, chunk_field.dtype.clone()).unwrap();
+                field.get_or_insert(chunk_field);
+                array
             })
             .collect::<Vec<_>>();
 
-        let s = Series::try_new(name.into(), chunks).map_err(PyPolarsErr::from)?;
+        // Use the field of the first chunk, its metadata restores categoricals and enums.
+        let s = match field {
+            Some(mut field) => {
+                field.name = name.into();
+                Series::try_from((&field, chunks))
+            },
+            None => Series::try_new(name.into(), chunks),
+        }
+        .map_err(PyPolarsErr::from)?;
         Ok(s.into())
     }
 
@@ -50,15 +62,14 @@ unsafe fn export_chunk(
 ) -> PolarsResult<()> {
     polars_ensure!(s.chunks().len() == 1, InvalidOperation: "expect a single chunk");
 
-    let c_array = arrow::ffi::export_array_to_c(s.chunks()[0].clone());
+    // Export the logical array, the physical chunk doesn't match the field of e.g. categoricals.
+    let c_array = arrow::ffi::export_array_to_c(s.to_arrow(0, CompatLevel::newest()));
     let out_ptr = out_ptr as *mut arrow::ffi::ArrowArray;
     *out_ptr = c_array;
 
-    let field = ArrowField::new(
-        s.name().clone(),
-        s.dtype().to_arrow(CompatLevel::newest()),
-        true,
-    );
+    let field = s
+        .dtype()
+        .to_arrow_field(s.name().clone(), CompatLevel::newest());
     let c_schema = arrow::ffi::export_field_to_c(&field);
 
     let out_schema_ptr = out_schema_ptr as *mut arrow::ffi::ArrowSchema;
@@ -1,16 +1,27 @@
-use core::fmt::{Debug, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use indexmap::map::MutableKeys;
 use polars_error::{PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err};
-use polars_utils::aliases::{InitHashMaps, PlIndexMap};
+use polars_utils::aliases::{InitHashMaps, PlHashMap, PlIndexMap};
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
+/// The user metadata of a field, such as its unit or description.
+pub type FieldMetadata = BTreeMap<PlSmallStr, PlSmallStr>;
+
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct Schema<D> {
     fields: PlIndexMap<PlSmallStr, D>,
+    /// The user metadata of the fields that have any. Like the metadata of a `Field`, it is not
+    /// compared, hashed or serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    metadata: PlHashMap<PlSmallStr, Arc<FieldMetadata>>,
 }
 
 impl<D: Eq> Eq for Schema<D> {}
@@ -18,7 +29,10 @@ impl<D: Eq> Eq for Schema<D> {}
 impl<D> Schema<D> {
     pub fn with_capacity(capacity: usize) -> Self {
         let fields = PlIndexMap::with_capacity(capacity);
-        Self { fields }
+        Self {
+            fields,
+            metadata: PlHashMap::new(),
+        }
     }
 
     /// Reserve `additional` memory spaces in the schema.
@@ -50,6 +64,10 @@ impl<D> Schema<D> {
         // its original position
         self.fields.swap_indices(old_index, new_index);
 
+        if let Some(md) = self.metadata.remove(&old_name) {
+            let new_name = self.fields.get_index(old_index).unwrap().0.clone();
+            self.metadata.insert(new_name, md);
+        }
         Some(old_name)
     }
 
@@ -163,6 +181,7 @@ impl<D> Schema<D> {
     /// is replaced by the last field, which takes its position. For a slower, but order-preserving, method, use
     /// [`shift_remove`][Self::shift_remove].
     pub fn remove(&mut self, name: &str) -> Option<D> {
+        self.metadata.remove(name);
         self.fields.swap_remove(name)
     }
 
@@ -173,6 +192,7 @@ impl<D> Schema<D> {
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
     pub fn shift_remove(&mut self, name: &str) -> Option<D> {
+        self.metadata.remove(name);
         self.fields.shift_remove(name)
     }
 
@@ -183,7 +203,28 @@ impl<D> Schema<D> {
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
     pub fn shift_remove_index(&mut self, index: usize) -> Option<(PlSmallStr, D)> {
-        self.fields.shift_remove_index(index)
+        let (name, dtype) = self.fields.shift_remove_index(index)?;
+        self.metadata.remove(&name);
+        Some((name, dtype))
+    }
+
+    /// The user metadata of the field named `name`, if it has any.
+    pub fn field_metadata(&self, name: &str) -> Option<&Arc<FieldMetadata>> {
+        self.metadata.get(name)
+    }
+
+    /// Set the user metadata of the field named `name`.
+    ///
+    /// Operations that build a new schema, such as projections, do not carry the metadata over.
+    pub fn set_field_metadata(&mut self, name: &str, metadata: Option<Arc<FieldMetadata>>) {
+        match metadata {
+            Some(md) if self.fields.contains_key(name) => {
+                self.metadata.insert(name.into(), md);
+            },
+            _ => {
+                self.metadata.remove(name);
+            },
+        }
     }
 
     /// Whether the schema contains a field named `name`.
@@ -323,6 +364,26 @@ impl<D> Schema<D> {
         Ok(i)
     }
 
+    /// Returns a new schema with `prefix` prepended to every field name.
+    pub fn with_prefix(&self, prefix: &str) -> Self
+    where
+        D: Clone,
+    {
+        self.iter()
+            .map(|(name, dtype)| (format_pl_smallstr!("{prefix}{name}"), dtype.clone()))
+            .collect()
+    }
+
+    /// Returns a new schema with `suffix` appended to every field name.
+    pub fn with_suffix(&self, suffix: &str) -> Self
+    where
+        D: Clone,
+    {
+        self.iter()
+            .map(|(name, dtype)| (format_pl_smallstr!("{name}{suffix}"), dtype.clone()))
+            .collect()
+    }
+
     /// Compare the fields between two schema returning the additional columns that each schema has.
     pub fn field_compare<'a, 'b>(
         &'a self,
@@ -435,7 +496,7 @@ where
             })
             .collect::<PolarsResult<PlIndexMap<_, _>>>()?;
 
-        Ok(Self { fields })
+        Ok(Self::from(fields))
     }
 
     /// Returns a new [`Schema`] with a subset of all fields whose `predicate`
@@ -454,7 +515,7 @@ where
             })
             .collect();
 
-        Self { fields }
+        Self::from(fields)
     }
 
     pub fn from_iter_check_duplicates<I, F>(iter: I) -> PolarsResult<Self>
@@ -483,6 +544,75 @@ where
     }
 }
 
+impl<D> Schema<D>
+where
+    D: Clone + PartialEq,
+{
+    /// Compute the changes needed to go from this schema to `other`.
+    ///
+    /// Fields are matched by name, so a reordering of columns is not considered a change.
+    pub fn diff(&self, other: &Self) -> SchemaDiff<D> {
+        let added = other
+            .iter()
+            .filter(|(name, _)| !self.contains(name))
+            .map(|(name, dtype)| (name.clone(), dtype.clone()))
+            .collect();
+        let mut removed = Vec::new();
+        let mut changed = Vec::new();
+        for (name, dtype) in self.iter() {
+            match other.get(name) {
+                None => removed.push((name.clone(), dtype.clone())),
+                Some(other_dtype) if other_dtype != dtype => {
+                    changed.push((name.clone(), dtype.clone(), other_dtype.clone()))
+                },
+                Some(_) => {},
+            }
+        }
+
+        SchemaDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+}
+
+/// The differences between two schemas, see [`Schema::diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaDiff<D> {
+    /// Fields only present in the new schema.
+    pub added: Vec<(PlSmallStr, D)>,
+    /// Fields only present in the old schema.
+    pub removed: Vec<(PlSmallStr, D)>,
+    /// Fields present in both schemas with a different dtype, as `(name, old, new)`.
+    pub changed: Vec<(PlSmallStr, D, D)>,
+}
+
+impl<D> SchemaDiff<D> {
+    /// Returns `true` if both schemas contain the same fields with the same dtypes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<D: Display> Display for SchemaDiff<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no schema changes");
+        }
+        for (name, dtype) in &self.removed {
+            writeln!(f, "- {name}: {dtype}")?;
+        }
+        for (name, dtype) in &self.added {
+            writeln!(f, "+ {name}: {dtype}")?;
+        }
+        for (name, old, new) in &self.changed {
+            writeln!(f, "~ {name}: {old} -> {new}")?;
+        }
+        Ok(())
+    }
+}
+
 pub fn ensure_matching_schema_names<D>(lhs: &Schema<D>, rhs: &Schema<D>) -> PolarsResult<()> {
     let lhs_names = lhs.iter_names();
     let rhs_names = rhs.iter_names();
@@ -529,7 +659,10 @@ impl<D: PartialEq> PartialEq for Schema<D> {
 
 impl<D> From<PlIndexMap<PlSmallStr, D>> for Schema<D> {
     fn from(fields: PlIndexMap<PlSmallStr, D>) -> Self {
-        Self { fields }
+        Self {
+            fields,
+            metadata: PlHashMap::new(),
+        }
     }
 }
 
@@ -539,7 +672,7 @@ where
 {
     fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
         let fields = PlIndexMap::from_iter(iter.into_iter().map(|x| x.into()));
-        Self { fields }
+        Self::from(fields)
     }
 }
 
@@ -1,8 +1,10 @@
+use futures::future::Either;
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_utils::aliases::PlHashSet;
+use polars_utils::cancel::CancelToken;
 use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::async_executor;
@@ -243,10 +245,20 @@ fn run_subgraph(
             async_executor::track_task_wait_statistics(true);
         }
         let ret = polars_io::pl_async::get_runtime().block_on(async move {
-            for handle in join_handles {
-                handle.await?;
+            let tasks = std::pin::pin!(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            });
+            let cancelled = std::pin::pin!(wait_for_cancellation(state));
+
+            // On cancellation we return early, the task scope then cancels all
+            // tasks which drops their state (readers, spill files, etc.).
+            match futures::future::select(tasks, cancelled).await {
+                Either::Left((ret, _)) => ret,
+                Either::Right((ret, _)) => ret,
             }
-            PolarsResult::Ok(())
         });
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             async_executor::track_task_wait_statistics(false);
@@ -257,16 +269,34 @@ fn run_subgraph(
     Ok(())
 }
 
+/// Resolves with an error once the query is cancelled or its deadline passes.
+async fn wait_for_cancellation(state: &StreamingExecutionState) -> PolarsResult<()> {
+    let token = state.in_memory_exec_state.cancel_token();
+    match token.deadline() {
+        None => token.cancelled().await,
+        Some(deadline) => {
+            let expired = std::pin::pin!(tokio::time::sleep_until(deadline.into()));
+            futures::future::select(std::pin::pin!(token.cancelled()), expired).await;
+        },
+    }
+    state.in_memory_exec_state.should_stop()
+}
+
 pub fn execute_graph(
     graph: &mut Graph,
+    cancel_token: CancelToken,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
-    // Get the number of threads from the rayon thread-pool as that respects our config.
+    // Get the number of threads from the rayon thread-pool as that respects our config. The
+    // executor threads are shared by all queries, while the pipelines of a query are bounded
+    // by its compute pool.
+    async_executor::set_num_threads(POOL.global_num_threads());
     let num_pipelines = POOL.current_num_threads();
-    async_executor::set_num_threads(num_pipelines);
 
+    let mut in_memory_exec_state = ExecutionState::default();
+    in_memory_exec_state.set_cancel_token(cancel_token);
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state,
     };
 
     // Ensure everything is properly connected.
@@ -285,6 +315,7 @@ pub fn execute_graph(
         if polars_core::config::verbose() {
             eprintln!("polars-stream: updating graph state");
         }
+        state.in_memory_exec_state.should_stop()?;
         graph.update_all_states(&state)?;
         let (nodes, pipes) = find_runnable_subgraph(graph);
         if polars_core::config::verbose() {
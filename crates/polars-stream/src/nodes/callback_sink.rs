@@ -0,0 +1,144 @@
+use std::num::NonZeroUsize;
+
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::dsl::SinkBatchCallback;
+
+use super::compute_node_prelude::*;
+
+/// A sink that passes the morsels it receives in batches to a user function.
+///
+/// The function is allowed to block (e.g. to apply backpressure), and can
+/// return `true` to signal that it is not interested in any more batches.
+pub struct CallbackSinkNode {
+    function: SinkBatchCallback,
+    maintain_order: bool,
+    chunk_size: Option<NonZeroUsize>,
+
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+    is_done: bool,
+}
+
+impl CallbackSinkNode {
+    pub fn new(
+        function: SinkBatchCallback,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> Self {
+        Self {
+            function,
+            maintain_order,
+            chunk_size,
+            buffer: Vec::new(),
+            buffered_rows: 0,
+            is_done: false,
+        }
+    }
+
+    /// Take batches of exactly `chunk_size` rows from the buffer, or all buffered
+    /// rows if `flush` is set.
+    fn take_batches(&mut self, flush: bool) -> Vec<DataFrame> {
+        let Some(chunk_size) = self.chunk_size else {
+            self.buffered_rows = 0;
+            return std::mem::take(&mut self.buffer);
+        };
+        let chunk_size = chunk_size.get();
+        if self.buffered_rows < chunk_size && !(flush && self.buffered_rows > 0) {
+            return Vec::new();
+        }
+
+        let mut df = accumulate_dataframes_vertical_unchecked(self.buffer.drain(..));
+        let mut batches = Vec::with_capacity(df.height() / chunk_size + 1);
+        while df.height() >= chunk_size {
+            let (batch, rest) = df.split_at(chunk_size as i64);
+            batches.push(batch);
+            df = rest;
+        }
+        if flush && df.height() > 0 {
+            batches.push(df);
+        } else if df.height() > 0 {
+            self.buffer.push(df);
+        }
+        self.buffered_rows = self.buffer.first().map_or(0, |df| df.height());
+        batches
+    }
+}
+
+impl ComputeNode for CallbackSinkNode {
+    fn name(&self) -> &str {
+        "callback-sink"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        if self.is_done {
+            recv[0] = PortState::Done;
+        } else if recv[0] != PortState::Done {
+            recv[0] = PortState::Ready;
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.is_empty());
+        let mut recv = recv_ports[0]
+            .take()
+            .unwrap()
+            .serial_with_maintain_order(self.maintain_order);
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let df = morsel.into_df();
+                if df.height() == 0 {
+                    continue;
+                }
+                self.buffered_rows += df.height();
+                self.buffer.push(df);
+
+                for batch in self.take_batches(false) {
+                    // The function may block, so we run it outside of the async executor.
+                    let function = self.function.clone();
+                    let stop = polars_io::pl_async::get_runtime()
+                        .spawn_blocking(move || function.call(batch))
+                        .await
+                        .map_err(
+                            |err| polars_err!(ComputeError: "batch callback failed: {err}"),
+                        )??;
+
+                    if stop {
+                        self.is_done = true;
+                        self.buffer.clear();
+                        return Ok(());
+                    }
+                }
+            }
+
+            Ok(())
+        }));
+    }
+
+    fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if !self.is_done {
+            for batch in self.take_batches(true) {
+                if self.function.call(batch)? {
+                    break;
+                }
+            }
+        }
+        Ok(None)
+    }
+}
@@ -7,7 +7,7 @@ use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::write::BatchedWriter;
+use polars_io::parquet::write::{BatchedWriter, ensure_sorted_as_declared};
 use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
@@ -134,6 +134,20 @@ impl SinkNode for ParquetSinkNode {
             self.metrics.clone(),
         ));
 
+        // The declared sorting column of every input column, if any.
+        let sorting_columns = Arc::new(
+            self.input_schema
+                .iter_names()
+                .map(|name| {
+                    write_options
+                        .sorting_columns
+                        .iter()
+                        .find(|c| c.name == name)
+                        .cloned()
+                })
+                .collect::<Vec<_>>(),
+        );
+
         // Encode task.
         //
         // Task encodes the columns into their corresponding Parquet encoding.
@@ -144,12 +158,17 @@ impl SinkNode for ParquetSinkNode {
                 .map(|(mut dist_rx, mut lin_tx)| {
                     let parquet_schema = self.parquet_schema.clone();
                     let column_options = self.column_options.clone();
+                    let sorting_columns = sorting_columns.clone();
 
                     spawn(TaskPriority::High, async move {
                         while let Ok((rg_idx, col_idx, column)) = dist_rx.recv().await {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let column_options = &column_options[col_idx];
 
+                            if let Some(sorting_column) = &sorting_columns[col_idx] {
+                                ensure_sorted_as_declared(&column, sorting_column)?;
+                            }
+
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
 
@@ -263,6 +282,7 @@ impl SinkNode for ParquetSinkNode {
 
             let writer = BufWriter::new(&mut *file);
             let key_value_metadata = write_options.key_value_metadata;
+            let sorting_columns = write_options.sorting_columns;
             let write_options = WriteOptions {
                 statistics: write_options.statistics,
                 compression: write_options.compression.into(),
@@ -281,7 +301,8 @@ impl SinkNode for ParquetSinkNode {
                 write_options,
                 false,
                 key_value_metadata,
-            );
+            )
+            .with_sorting_columns(&sorting_columns)?;
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
             while let Ok(current_row_group) = io_rx.recv().await {
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -7,8 +7,9 @@ use futures::stream::FuturesUnordered;
 use polars_core::config;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
-use polars_error::PolarsResult;
-use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_io::is_cloud_url;
+use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions, SinkTarget};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -18,19 +19,39 @@ use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink, start_sink_on_target};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode};
 use crate::nodes::{JoinHandle, Morsel, TaskPriority};
 
+/// How the files written by a [`MaxSizePartitionSinkNode`] are named.
+#[derive(Clone)]
+enum FileNaming {
+    /// The files of a `partition_by_max_size` sink, relative to `base_path`.
+    Partition {
+        base_path: Arc<PathBuf>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        ext: PlSmallStr,
+    },
+    /// The rotated files of a single file sink writing to `path`.
+    ///
+    /// Local files are first written under a temporary name and renamed once they are complete,
+    /// so readers never observe partially written files. At least one file is always written, so
+    /// an empty result still gets e.g. a CSV header.
+    Rotated {
+        path: Arc<PathBuf>,
+        sink_options: SinkOptions,
+    },
+}
+
 pub struct MaxSizePartitionSinkNode {
     input_schema: SchemaRef,
-    max_size: IdxSize,
+    max_rows: Option<IdxSize>,
+    /// Measured on the estimated in-memory size of the rows given to the writer.
+    max_bytes: Option<u64>,
 
-    base_path: Arc<PathBuf>,
-    file_path_cb: Option<PartitionTargetCallback>,
+    file_naming: FileNaming,
     create_new: CreateNewSinkFn,
-    ext: PlSmallStr,
 
     sink_options: SinkOptions,
 
@@ -64,6 +85,72 @@ impl MaxSizePartitionSinkNode {
         finish_callback: Option<SinkFinishCallback>,
     ) -> Self {
         assert!(max_size > 0);
+        let file_naming = FileNaming::Partition {
+            base_path,
+            file_path_cb,
+            ext,
+        };
+        Self::new_impl(
+            input_schema,
+            Some(max_size),
+            None,
+            file_naming,
+            create_new,
+            sink_options,
+            per_partition_sort_by,
+            finish_callback,
+        )
+    }
+
+    /// Create a sink that splits the output of a file sink over multiple files once the current
+    /// file reaches `max_rows_per_file` rows or `max_bytes_per_file` bytes.
+    ///
+    /// Every file is written by its own underlying sink, so each file gets its own CSV header or
+    /// Parquet / IPC footer.
+    pub fn new_rotating(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        create_new: CreateNewSinkFn,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        let SinkTarget::Path(path) = target else {
+            polars_bail!(InvalidOperation: "file rotation requires the sink target to be a path");
+        };
+        polars_ensure!(
+            sink_options.max_rows_per_file != Some(0) && sink_options.max_bytes_per_file != Some(0),
+            InvalidOperation: "`max_rows_per_file` and `max_bytes_per_file` must be larger than 0"
+        );
+        // Validate the template before any data gets written.
+        sink_options.rotated_file_path(&path, 0)?;
+
+        let file_naming = FileNaming::Rotated {
+            path,
+            sink_options: sink_options.clone(),
+        };
+        Ok(Self::new_impl(
+            input_schema,
+            sink_options.max_rows_per_file,
+            sink_options.max_bytes_per_file,
+            file_naming,
+            create_new,
+            sink_options,
+            None,
+            None,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_impl(
+        input_schema: SchemaRef,
+        max_rows: Option<IdxSize>,
+        max_bytes: Option<u64>,
+        file_naming: FileNaming,
+        create_new: CreateNewSinkFn,
+        sink_options: SinkOptions,
+
+        per_partition_sort_by: Option<PerPartitionSortBy>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> Self {
         let num_retire_tasks =
             std::env::var("POLARS_MAX_SIZE_SINK_RETIRE_TASKS").map_or(DEFAULT_RETIRE_TASKS, |v| {
                 v.parse::<usize>()
@@ -73,11 +160,10 @@ impl MaxSizePartitionSinkNode {
 
         Self {
             input_schema,
-            max_size,
-            base_path,
-            file_path_cb,
+            max_rows,
+            max_bytes,
+            file_naming,
             create_new,
-            ext,
             sink_options,
             num_retire_tasks,
             per_partition_sort_by,
@@ -97,9 +183,153 @@ fn default_file_path_cb(
     Ok(PathBuf::from(format!("{file_idx}.{ext}")))
 }
 
+/// The hidden name a file is written under until it is complete.
+fn temporary_file_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{file_name}.tmp"))
+}
+
+/// An output file that is written under a temporary name if it is local.
+///
+/// The temporary file is removed again on drop unless it has been persisted, so a failing query
+/// does not leave partially written files behind.
+struct TemporaryFile {
+    tmp_path: Option<PathBuf>,
+    path: PathBuf,
+}
+
+impl TemporaryFile {
+    fn new(path: PathBuf) -> Self {
+        let tmp_path = (!is_cloud_url(&path)).then(|| temporary_file_path(&path));
+        Self { tmp_path, path }
+    }
+
+    /// The path the file is written to.
+    fn write_path(&self) -> &Path {
+        self.tmp_path.as_deref().unwrap_or(&self.path)
+    }
+
+    /// Moves the complete file to its final name.
+    fn persist(mut self) -> std::io::Result<()> {
+        if let Some(tmp_path) = &self.tmp_path {
+            std::fs::rename(tmp_path, &self.path)?;
+            self.tmp_path = None;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TemporaryFile {
+    fn drop(&mut self) {
+        if let Some(tmp_path) = self.tmp_path.take() {
+            let _ = std::fs::remove_file(tmp_path);
+        }
+    }
+}
+
+struct CurrentSink {
+    sender: SinkSender,
+    join_handles: FuturesUnordered<AbortOnDropHandle<PolarsResult<()>>>,
+    node: Box<dyn SinkNode + Send + Sync>,
+    file: Option<TemporaryFile>,
+    num_rows: IdxSize,
+    num_bytes: u64,
+}
+
+struct RetiredSink {
+    join_handles: FuturesUnordered<AbortOnDropHandle<PolarsResult<()>>>,
+    node: Box<dyn SinkNode + Send + Sync>,
+    file: Option<TemporaryFile>,
+}
+
+impl CurrentSink {
+    fn retire(self) -> RetiredSink {
+        drop(self.sender);
+        RetiredSink {
+            join_handles: self.join_handles,
+            node: self.node,
+            file: self.file,
+        }
+    }
+}
+
+impl FileNaming {
+    /// Start the sink for the `file_idx`-th file.
+    ///
+    /// Returns `None` if the sink stopped before accepting any input.
+    async fn open_sink(
+        &self,
+        file_idx: usize,
+        create_new: &CreateNewSinkFn,
+        input_schema: SchemaRef,
+        verbose: bool,
+        state: &StreamingExecutionState,
+        per_partition_sort_by: Option<&PerPartitionSortBy>,
+    ) -> PolarsResult<Option<CurrentSink>> {
+        let (result, file) = match self {
+            Self::Partition {
+                base_path,
+                file_path_cb,
+                ext,
+            } => {
+                let result = open_new_sink(
+                    base_path.as_path(),
+                    file_path_cb.as_ref(),
+                    default_file_path_cb,
+                    file_idx,
+                    file_idx,
+                    0,
+                    None,
+                    create_new,
+                    input_schema,
+                    "max-size",
+                    ext.as_str(),
+                    verbose,
+                    state,
+                    per_partition_sort_by,
+                )
+                .await?;
+                (result, None)
+            },
+            Self::Rotated { path, sink_options } => {
+                let file = TemporaryFile::new(sink_options.rotated_file_path(path, file_idx)?);
+                if verbose {
+                    eprintln!(
+                        "[partition[max-size]]: Start on new file '{}'",
+                        file.path.display(),
+                    );
+                }
+
+                let target = SinkTarget::Path(Arc::new(file.write_path().to_path_buf()));
+                let result = start_sink_on_target(
+                    target,
+                    create_new,
+                    input_schema,
+                    state,
+                    per_partition_sort_by,
+                )
+                .await?;
+                (result, Some(file))
+            },
+        };
+
+        Ok(result.map(|(join_handles, sender, node)| CurrentSink {
+            sender,
+            join_handles,
+            node,
+            file,
+            num_rows: 0,
+            num_bytes: 0,
+        }))
+    }
+}
+
 impl SinkNode for MaxSizePartitionSinkNode {
     fn name(&self) -> &str {
-        "partition-max-size-sink"
+        match self.file_naming {
+            FileNaming::Partition { .. } => "partition-max-size-sink",
+            FileNaming::Rotated { .. } => "rotating-sink",
+        }
     }
 
     fn is_sink_input_parallel(&self) -> bool {
@@ -126,24 +356,16 @@ impl SinkNode for MaxSizePartitionSinkNode {
         // Takes the morsels coming in and passes them to underlying sink.
         let state = state.clone();
         let input_schema = self.input_schema.clone();
-        let max_size = self.max_size;
-        let base_path = self.base_path.clone();
-        let file_path_cb = self.file_path_cb.clone();
+        let max_rows = self.max_rows;
+        let max_bytes = self.max_bytes;
+        let file_naming = self.file_naming.clone();
         let create_new = self.create_new.clone();
-        let ext = self.ext.clone();
         let per_partition_sort_by = self.per_partition_sort_by.clone();
         let retire_error = has_error_occurred.clone();
         join_handles.push(spawn(TaskPriority::High, async move {
-            struct CurrentSink {
-                sender: SinkSender,
-                join_handles: FuturesUnordered<AbortOnDropHandle<PolarsResult<()>>>,
-                num_remaining: IdxSize,
-                node: Box<dyn SinkNode + Send + Sync>,
-            }
-
             let verbose = config::verbose();
             let mut file_idx = 0;
-            let mut current_sink_opt = None;
+            let mut current_sink_opt: Option<CurrentSink> = None;
 
             while let Ok((outcome, recv_port)) = recv_port_recv.recv().await {
                 let mut recv_port = recv_port.serial();
@@ -156,76 +378,85 @@ impl SinkNode for MaxSizePartitionSinkNode {
                         let current_sink = match current_sink_opt.as_mut() {
                             Some(c) => c,
                             None => {
-                                let result = open_new_sink(
-                                    base_path.as_path(),
-                                    file_path_cb.as_ref(),
-                                    default_file_path_cb,
-                                    file_idx,
-                                    file_idx,
-                                    0,
-                                    None,
-                                    &create_new,
-                                    input_schema.clone(),
-                                    "max-size",
-                                    ext.as_str(),
-                                    verbose,
-                                    &state,
-                                    per_partition_sort_by.as_ref(),
-                                )
-                                .await?;
+                                let result = file_naming
+                                    .open_sink(
+                                        file_idx,
+                                        &create_new,
+                                        input_schema.clone(),
+                                        verbose,
+                                        &state,
+                                        per_partition_sort_by.as_ref(),
+                                    )
+                                    .await?;
                                 file_idx += 1;
-                                let Some((join_handles, sender, node)) = result else {
+                                let Some(current_sink) = result else {
                                     return Ok(());
                                 };
-
-                                current_sink_opt.insert(CurrentSink {
-                                    sender,
-                                    num_remaining: max_size,
-                                    join_handles,
-                                    node,
-                                })
+                                current_sink_opt.insert(current_sink)
                             },
                         };
 
-                        // If we can send the whole morsel into sink, do that.
-                        if morsel.df().height() < current_sink.num_remaining as usize {
-                            current_sink.num_remaining -= morsel.df().height() as IdxSize;
+                        // Determine how many rows of this morsel still fit into the current file.
+                        let height = morsel.df().height();
+                        let mut num_rows = height;
+                        if let Some(max_rows) = max_rows {
+                            num_rows = num_rows
+                                .min(max_rows.saturating_sub(current_sink.num_rows) as usize);
+                        }
+                        if let Some(max_bytes) = max_bytes {
+                            let row_size = (morsel.df().estimated_size() as u64)
+                                .div_ceil(height as u64)
+                                .max(1);
+                            let mut num_fitting = (max_bytes.saturating_sub(current_sink.num_bytes)
+                                / row_size)
+                                as usize;
+                            // Always write at least one row to a file, even if that row by itself
+                            // exceeds the limit.
+                            if current_sink.num_rows == 0 {
+                                num_fitting = num_fitting.max(1);
+                            }
+                            num_rows = num_rows.min(num_fitting);
+                            current_sink.num_bytes += num_rows as u64 * row_size;
+                        }
+                        current_sink.num_rows += num_rows as IdxSize;
 
+                        let is_full = max_rows.is_some_and(|m| current_sink.num_rows >= m)
+                            || max_bytes.is_some_and(|m| current_sink.num_bytes >= m);
+
+                        // If we can send the whole morsel into sink, do that.
+                        if num_rows == height {
                             // This sends the consume token along so that we don't start buffering here
                             // too much. The sinks are very specific about how they handle consume
                             // tokens and we want to keep that behavior.
-                            let result = match &mut current_sink.sender {
-                                SinkSender::Connector(s) => s.send(morsel).await.ok(),
-                                SinkSender::Distributor(s) => s.send(morsel).await.ok(),
-                            };
-
-                            if result.is_none() {
+                            if current_sink.sender.send(morsel).await.is_err() {
                                 break 'morsel_loop;
                             }
+                            if is_full {
+                                let current_sink = current_sink_opt.take().unwrap();
+                                if retire_tx.send(current_sink.retire()).await.is_err() {
+                                    return Ok(());
+                                }
+                            }
                             break;
                         }
 
                         // Else, we need to split up the morsel into what can be sent and what needs to
                         // be passed to the current sink and what needs to be passed to the next sink.
                         let (df, seq, source_token, consume_token) = morsel.into_inner();
+                        let (final_sink_df, df) = df.split_at(num_rows as i64);
 
-                        let (final_sink_df, df) = df.split_at(current_sink.num_remaining as i64);
-                        let final_sink_morsel =
-                            Morsel::new(final_sink_df, seq, source_token.clone());
-
-                        if current_sink.sender.send(final_sink_morsel).await.is_err() {
-                            return Ok(());
-                        };
+                        if num_rows > 0 {
+                            let final_sink_morsel =
+                                Morsel::new(final_sink_df, seq, source_token.clone());
+                            if current_sink.sender.send(final_sink_morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
 
                         let current_sink = current_sink_opt.take().unwrap();
-                        drop(current_sink.sender);
-                        if retire_tx
-                            .send((current_sink.join_handles, current_sink.node))
-                            .await
-                            .is_err()
-                        {
+                        if retire_tx.send(current_sink.retire()).await.is_err() {
                             return Ok(());
-                        };
+                        }
 
                         // We consciously keep the consume token for the last sub-morsel sent.
                         morsel = Morsel::new(df, seq, source_token);
@@ -237,13 +468,22 @@ impl SinkNode for MaxSizePartitionSinkNode {
                 outcome.stopped();
             }
 
+            // A rotating sink writes at least one file, like the file sink it replaces.
+            if file_idx == 0 && matches!(file_naming, FileNaming::Rotated { .. }) {
+                current_sink_opt = file_naming
+                    .open_sink(
+                        file_idx,
+                        &create_new,
+                        input_schema.clone(),
+                        verbose,
+                        &state,
+                        per_partition_sort_by.as_ref(),
+                    )
+                    .await?;
+            }
+
             if let Some(current_sink) = current_sink_opt.take() {
-                drop(current_sink.sender);
-                if retire_tx
-                    .send((current_sink.join_handles, current_sink.node))
-                    .await
-                    .is_err()
-                {
+                if retire_tx.send(current_sink.retire()).await.is_err() {
                     return Ok(());
                 };
             }
@@ -263,7 +503,13 @@ impl SinkNode for MaxSizePartitionSinkNode {
             spawn(TaskPriority::High, async move {
                 let mut partition_metrics = Vec::new();
 
-                while let Ok((mut join_handles, node)) = retire_rx.recv().await {
+                while let Ok(retired) = retire_rx.recv().await {
+                    let RetiredSink {
+                        mut join_handles,
+                        node,
+                        file,
+                    } = retired;
+
                     while let Some(ret) = join_handles.next().await {
                         ret.inspect_err(|_| {
                             has_error_occurred.store(true, Ordering::Relaxed);
@@ -273,6 +519,9 @@ impl SinkNode for MaxSizePartitionSinkNode {
                         partition_metrics.push(metrics);
                     }
                     node.finish()?;
+                    if let Some(file) = file {
+                        file.persist()?;
+                    }
                 }
 
                 {
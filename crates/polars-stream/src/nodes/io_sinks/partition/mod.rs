@@ -59,10 +59,11 @@ pub fn get_create_new_fn(
             Ok(sink)
         }) as _,
         #[cfg(feature = "json")]
-        FileType::Json(_ndjson_writer_options) => Arc::new(move |_input_schema, target| {
+        FileType::Json(ndjson_writer_options) => Arc::new(move |_input_schema, target| {
             let sink = Box::new(super::json::NDJsonSinkNode::new(
                 target,
                 sink_options.clone(),
+                *ndjson_writer_options,
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
@@ -211,6 +212,32 @@ async fn open_new_sink(
         }
     }
 
+    start_sink_on_target(
+        target,
+        create_new_sink,
+        sink_input_schema,
+        state,
+        per_partition_sort_by,
+    )
+    .await
+}
+
+/// Create a new sink for `target` and spawn its tasks.
+///
+/// Returns `None` if the sink stopped before accepting any input.
+async fn start_sink_on_target(
+    target: SinkTarget,
+    create_new_sink: &CreateNewSinkFn,
+    sink_input_schema: SchemaRef,
+    state: &StreamingExecutionState,
+    per_partition_sort_by: Option<&PerPartitionSortBy>,
+) -> PolarsResult<
+    Option<(
+        FuturesUnordered<AbortOnDropHandle<PolarsResult<()>>>,
+        SinkSender,
+        Box<dyn SinkNode + Send + Sync>,
+    )>,
+> {
     let mut node = (create_new_sink)(sink_input_schema.clone(), target)?;
     let mut join_handles = Vec::new();
     let (sink_input, mut sender) = if node.is_sink_input_parallel() {
@@ -126,6 +126,7 @@ impl FileReader for BatchFnReader {
             pre_slice: None,
             predicate: None,
             cast_columns_policy: _,
+            row_group_index: _,
             num_pipelines: _,
             callbacks:
                 FileReaderCallbacks {
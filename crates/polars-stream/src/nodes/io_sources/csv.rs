@@ -140,6 +140,7 @@ impl FileReader for CsvFileReader {
             pre_slice,
             predicate: None,
             cast_columns_policy: _,
+            row_group_index: _,
             num_pipelines,
             callbacks:
                 FileReaderCallbacks {
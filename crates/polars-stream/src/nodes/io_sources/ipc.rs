@@ -1,5 +1,6 @@
 use std::cmp::Reverse;
-use std::io::Cursor;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -11,9 +12,10 @@ use polars_core::schema::{Schema, SchemaExt};
 use polars_core::utils::arrow::io::ipc::read::{
     FileMetadata, ProjectionInfo, get_row_count_from_blocks, prepare_projection, read_file_metadata,
 };
-use polars_error::{ErrString, PolarsError, PolarsResult, polars_err};
+use polars_error::{ErrString, PolarsError, PolarsResult, polars_ensure, polars_err};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
+use polars_io::ipc::{IpcFileFingerprint, IpcScanOptions};
 use polars_plan::dsl::{ScanSource, ScanSourceRef};
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
@@ -38,6 +40,7 @@ pub mod builder {
     use arrow::io::ipc::read::FileMetadata;
     use polars_core::config;
     use polars_io::cloud::CloudOptions;
+    use polars_io::ipc::{IpcFileFingerprint, IpcScanOptions};
     use polars_plan::dsl::ScanSource;
 
     use super::IpcFileReader;
@@ -47,8 +50,10 @@ pub mod builder {
 
     #[derive(Debug)]
     pub struct IpcReaderBuilder {
+        pub options: IpcScanOptions,
         #[expect(unused)]
         pub first_metadata: Option<Arc<FileMetadata>>,
+        pub fingerprints: Option<Arc<[Option<IpcFileFingerprint>]>>,
     }
 
     #[cfg(feature = "ipc")]
@@ -67,7 +72,7 @@ pub mod builder {
             &self,
             source: ScanSource,
             cloud_options: Option<Arc<CloudOptions>>,
-            #[expect(unused)] scan_source_idx: usize,
+            scan_source_idx: usize,
         ) -> Box<dyn FileReader> {
             let scan_source = source;
             let verbose = config::verbose();
@@ -82,10 +87,17 @@ pub mod builder {
             // };
             let metadata = None;
 
+            let fingerprint = self
+                .fingerprints
+                .as_ref()
+                .and_then(|fingerprints| fingerprints[scan_source_idx]);
+
             let reader = IpcFileReader {
                 scan_source,
                 cloud_options,
+                options: self.options.clone(),
                 metadata,
+                fingerprint,
                 verbose,
                 init_data: None,
             };
@@ -104,7 +116,10 @@ consider compiling with polars-bigidx feature (polars-u64-idx package on python)
 struct IpcFileReader {
     scan_source: ScanSource,
     cloud_options: Option<Arc<CloudOptions>>,
+    options: IpcScanOptions,
     metadata: Option<Arc<FileMetadata>>,
+    // Recorded when the query was planned, `None` if the source is not a local file.
+    fingerprint: Option<IpcFileFingerprint>,
     verbose: bool,
 
     init_data: Option<InitializedState>,
@@ -113,11 +128,33 @@ struct IpcFileReader {
 #[derive(Clone)]
 struct InitializedState {
     memslice: MemSlice,
+    // Set if `memslice` is memory-mapped from a file.
+    mapped_file: Option<Arc<MappedFileCheck>>,
     file_metadata: Arc<FileMetadata>,
     // Lazily initialized - getting this involves iterating record batches.
     n_rows_in_file: Option<IdxSize>,
 }
 
+/// A memory-mapped file with the fingerprint it had when the query was planned.
+struct MappedFileCheck {
+    file: File,
+    expected: IpcFileFingerprint,
+}
+
+impl MappedFileCheck {
+    /// Check that the file is unchanged before reading from its map `memslice`.
+    fn validate(&self, memslice: &[u8]) -> PolarsResult<()> {
+        // Check the length first, reading the footer of a truncated file is what we try to avoid.
+        let len = self.file.metadata()?.len();
+        polars_ensure!(
+            len == self.expected.len,
+            ComputeError: "IPC file was modified during the query: its length changed from {} to {} bytes",
+            self.expected.len, len
+        );
+        IpcFileFingerprint::from_bytes(memslice).ensure_eq(&self.expected)
+    }
+}
+
 /// Move `slice` forward by `n` and return the slice until then.
 fn slice_take(slice: &mut Range<usize>, n: usize) -> Range<usize> {
     let offset = slice.start;
@@ -161,10 +198,48 @@ impl FileReader for IpcFileReader {
             )?;
         }
 
-        let memslice = self
-            .scan_source
-            .as_scan_source_ref()
-            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+        let scan_source = self.scan_source.as_scan_source_ref();
+        let file = scan_source.to_file_async_check_latest(self.scan_source.run_async())?;
+
+        let (memslice, mapped_file) = match file {
+            None => (scan_source.to_memslice()?, None),
+            Some(file) => {
+                let memslice = if self.options.lock_file {
+                    MemSlice::from_file_locked(&file)?
+                } else {
+                    Some(MemSlice::from_file(&file)?)
+                };
+
+                match memslice {
+                    Some(memslice) => {
+                        let mapped_file = self
+                            .fingerprint
+                            .map(|expected| Arc::new(MappedFileCheck { file, expected }));
+                        (memslice, mapped_file)
+                    },
+                    None => {
+                        if self.verbose {
+                            eprintln!(
+                                "[IpcFileReader]: could not lock file, reading it into memory instead"
+                            );
+                        }
+
+                        let mut file = file;
+                        let mut buf = Vec::new();
+                        file.seek(SeekFrom::Start(0))?;
+                        file.read_to_end(&mut buf)?;
+                        if let Some(expected) = &self.fingerprint {
+                            IpcFileFingerprint::from_bytes(&buf).ensure_eq(expected)?;
+                        }
+                        (MemSlice::from_vec(buf), None)
+                    },
+                }
+            },
+        };
+
+        if let Some(mapped_file) = &mapped_file {
+            mapped_file.validate(&memslice)?;
+        }
 
         let file_metadata = if let Some(v) = self.metadata.clone() {
             v
@@ -176,6 +251,7 @@ impl FileReader for IpcFileReader {
 
         self.init_data = Some(InitializedState {
             memslice,
+            mapped_file,
             file_metadata,
             n_rows_in_file: None,
         });
@@ -191,6 +267,7 @@ impl FileReader for IpcFileReader {
 
         let InitializedState {
             memslice,
+            mapped_file,
             file_metadata,
             n_rows_in_file: _,
         } = self.init_data.clone().unwrap();
@@ -201,6 +278,7 @@ impl FileReader for IpcFileReader {
             pre_slice: pre_slice_arg,
             predicate: None,
             cast_columns_policy: _,
+            row_group_index: _,
             num_pipelines,
             callbacks:
                 FileReaderCallbacks {
@@ -369,6 +447,7 @@ impl FileReader for IpcFileReader {
             .zip(batch_rxs)
             .map(|(mut send, mut rx)| {
                 let memslice = memslice.clone();
+                let mapped_file = mapped_file.clone();
                 let metadata = metadata.clone();
                 let row_index = row_index.clone();
                 let projection_info = projection_info.clone();
@@ -401,6 +480,10 @@ impl FileReader for IpcFileReader {
                         } else {
                             use polars_core::utils::arrow::io::ipc;
 
+                            if let Some(mapped_file) = &mapped_file {
+                                mapped_file.validate(&memslice)?;
+                            }
+
                             let mut reader = ipc::read::FileReader::new_with_projection_info(
                                 Cursor::new(memslice.as_ref()),
                                 metadata.as_ref().clone(),
@@ -459,6 +542,7 @@ impl FileReader for IpcFileReader {
             .collect::<Vec<_>>();
 
         let memslice = memslice.clone();
+        let mapped_file = mapped_file.clone();
         let metadata = metadata.clone();
         let slice = slice.clone();
         let row_index = row_index.clone();
@@ -493,6 +577,10 @@ impl FileReader for IpcFileReader {
             );
 
             if slice.start > 0 {
+                if let Some(mapped_file) = &mapped_file {
+                    mapped_file.validate(&memslice)?;
+                }
+
                 // Skip over all blocks that the slice would skip anyway.
                 let new_offset = reader.skip_blocks_till_limit(slice.start as u64)?;
 
@@ -519,6 +607,10 @@ impl FileReader for IpcFileReader {
                 while !slice.is_empty() {
                     let mut is_batch_complete = false;
 
+                    if let Some(mapped_file) = &mapped_file {
+                        mapped_file.validate(&memslice)?;
+                    }
+
                     match reader.next_record_batch() {
                         None if batch.num_rows == 0 => break,
 
@@ -619,11 +711,16 @@ impl IpcFileReader {
     fn _n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
         let InitializedState {
             memslice,
+            mapped_file,
             file_metadata,
             n_rows_in_file,
         } = self.init_data.as_mut().unwrap();
 
         if n_rows_in_file.is_none() {
+            if let Some(mapped_file) = mapped_file {
+                mapped_file.validate(memslice)?;
+            }
+
             let n_rows: i64 = get_row_count_from_blocks(
                 &mut std::io::Cursor::new(memslice.as_ref()),
                 &file_metadata.blocks,
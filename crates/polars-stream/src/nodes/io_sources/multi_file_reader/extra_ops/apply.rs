@@ -3,13 +3,14 @@ use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::ScalarColumn;
-use polars_core::prelude::{AnyValue, Column, DataType, IntoColumn};
+use polars_core::prelude::{AnyValue, Column, DataType, IDX_DTYPE, IntoColumn, NamedFrom};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
+use polars_core::series::Series;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::ScanSource;
+use polars_plan::dsl::{FileMetadataColumns, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::IdxSize;
 use polars_utils::slice_enum::Slice;
@@ -39,9 +40,10 @@ pub enum ApplyExtraOps {
     Initialized {
         // Note: These fields are ordered according to when they (should be) applied.
         row_index: Option<RowIndex>,
+        row_index_u64_offset: Option<u64>,
         pre_slice: Option<Slice>,
         cast_columns: Option<CastColumns>,
-        /// This will have include_file_paths, hive columns, missing columns.
+        /// This will have include_file_paths, file metadata, hive columns, missing columns.
         extra_columns: Vec<ScalarColumn>,
         predicate: Option<ScanIOPredicate>,
         reorder: ReorderColumns,
@@ -68,10 +70,12 @@ impl ApplyExtraOps {
                 extra_ops:
                     ExtraOperations {
                         row_index,
+                        row_index_u64_offset,
                         pre_slice,
                         cast_columns_policy,
                         missing_columns_policy,
                         include_file_paths,
+                        file_metadata_columns,
                         predicate,
                     },
                 scan_source,
@@ -128,10 +132,53 @@ impl ApplyExtraOps {
                     ))
                 }
 
+                if !file_metadata_columns.is_empty() {
+                    let FileMetadataColumns {
+                        path,
+                        size,
+                        mtime,
+                        row_group,
+                    } = &file_metadata_columns;
+                    let scan_source = scan_source.as_scan_source_ref();
+                    let (size_value, mtime_value) = if size.is_some() || mtime.is_some() {
+                        scan_source.size_and_mtime()?
+                    } else {
+                        (None, None)
+                    };
+
+                    if let Some(name) = path {
+                        let s = Series::new(name.clone(), [scan_source.to_include_path_name()])
+                            .cast(&FileMetadataColumns::path_dtype())?;
+                        extra_columns.push(ScalarColumn::from_single_value_series(s, 1));
+                    }
+
+                    if let Some(name) = size {
+                        let s = Series::new(name.clone(), [size_value]);
+                        extra_columns.push(ScalarColumn::from_single_value_series(s, 1));
+                    }
+
+                    if let Some(name) = mtime {
+                        let s = Series::new(name.clone(), [mtime_value])
+                            .cast(&FileMetadataColumns::mtime_dtype())?;
+                        extra_columns.push(ScalarColumn::from_single_value_series(s, 1));
+                    }
+
+                    // Attached by readers that have row groups.
+                    if let Some(name) = row_group.as_ref().filter(|x| !incoming_schema.contains(x))
+                    {
+                        extra_columns.push(ScalarColumn::new(
+                            name.clone(),
+                            Scalar::null(IDX_DTYPE),
+                            1,
+                        ));
+                    }
+                }
+
                 debug_assert_eq!(extra_columns.len(), n_expected_extra_columns);
 
                 let mut slf = Self::Initialized {
                     row_index,
+                    row_index_u64_offset,
                     pre_slice,
                     cast_columns,
                     extra_columns,
@@ -173,6 +220,7 @@ impl ApplyExtraOps {
                 let slf = match slf {
                     Initialized {
                         row_index: None,
+                        row_index_u64_offset: _,
                         pre_slice: None,
                         cast_columns: None,
                         extra_columns,
@@ -199,6 +247,7 @@ impl ApplyExtraOps {
     ) -> PolarsResult<()> {
         let Self::Initialized {
             row_index,
+            row_index_u64_offset,
             pre_slice,
             cast_columns,
             extra_columns,
@@ -218,13 +267,24 @@ impl ApplyExtraOps {
         };
 
         if let Some(ri) = row_index {
-            unsafe {
-                df.with_column_unchecked(Column::new_row_index(
+            let column = if let Some(offset) = row_index_u64_offset {
+                #[allow(clippy::useless_conversion)]
+                let current_row_position = u64::from(current_row_position);
+
+                Column::new_row_index_u64(
+                    ri.name.clone(),
+                    offset.saturating_add(current_row_position),
+                    df.height(),
+                )?
+            } else {
+                Column::new_row_index(
                     ri.name.clone(),
                     ri.offset.saturating_add(current_row_position),
                     df.height(),
-                )?)
+                )?
             };
+
+            unsafe { df.with_column_unchecked(column) };
         }
 
         if let Some(pre_slice) = pre_slice.clone() {
@@ -16,6 +16,7 @@ pub struct CastColumns {
 struct ColumnCast {
     index: usize,
     dtype: DataType,
+    options: CastOptions,
 }
 
 impl CastColumns {
@@ -50,9 +51,17 @@ impl CastColumns {
             let target_dtype = get_target_dtype(name);
 
             if PolicyWrap(policy).should_cast_column(name, target_dtype, incoming_dtype)? {
+                let options = match policy.integer_downcast {
+                    Some(options) if target_dtype.is_integer() && incoming_dtype.is_integer() => {
+                        options
+                    },
+                    _ => CastOptions::Strict,
+                };
+
                 casting_list.push(ColumnCast {
                     index: i,
                     dtype: target_dtype.clone(),
+                    options,
                 })
             }
         }
@@ -72,9 +81,14 @@ impl CastColumns {
 
         let columns = unsafe { df.get_columns_mut() };
 
-        for ColumnCast { index, dtype } in &self.casting_list {
+        for ColumnCast {
+            index,
+            dtype,
+            options,
+        } in &self.casting_list
+        {
             *columns.get_mut(*index).unwrap() =
-                columns[*index].cast_with_options(dtype, CastOptions::Strict)?;
+                columns[*index].cast_with_options(dtype, *options)?;
         }
 
         Ok(())
@@ -8,7 +8,9 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail};
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy};
+use polars_plan::dsl::{
+    CastColumnsPolicy, ExtraColumnsPolicy, FileMetadataColumns, MissingColumnsPolicy,
+};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
@@ -21,10 +23,13 @@ use polars_utils::slice_enum::Slice;
 pub struct ExtraOperations {
     // Note: These fields are ordered according to when they (should be) applied.
     pub row_index: Option<RowIndex>,
+    /// Generate `row_index` as `UInt64` starting at this offset instead.
+    pub row_index_u64_offset: Option<u64>,
     pub pre_slice: Option<Slice>,
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub include_file_paths: Option<PlSmallStr>,
+    pub file_metadata_columns: FileMetadataColumns,
     pub predicate: Option<ScanIOPredicate>,
 }
 
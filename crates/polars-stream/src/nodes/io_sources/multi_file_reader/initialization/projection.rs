@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::schema::{Schema, SchemaRef};
+use polars_plan::dsl::FileMetadataColumns;
 use polars_plan::plans::hive::HivePartitionsDf;
 
 /// Returns the schema containing columns to project from the file.
@@ -22,6 +23,7 @@ pub fn resolve_projections(
     // TODO: One day update IR conversion to avoid attaching these to the file schema :')
     row_index_name: Option<&str>,
     include_file_paths: Option<&str>,
+    file_metadata_columns: &FileMetadataColumns,
 ) -> (SchemaRef, SchemaRef) {
     if let Some(hive_parts) = hive_parts.as_mut() {
         let projected_hive_parts: HivePartitionsDf = hive_parts
@@ -48,7 +50,8 @@ pub fn resolve_projections(
             let in_hive = hive_schema.is_some_and(|x| x.contains(name));
             let is_row_index_col = row_index_name.is_some_and(|x| name == x);
             let is_file_path_col = include_file_paths.is_some_and(|x| name == x);
-            (in_final && !(in_hive || is_file_path_col || is_row_index_col))
+            let is_file_metadata_col = file_metadata_columns.contains(name);
+            (in_final && !(in_hive || is_file_path_col || is_file_metadata_col || is_row_index_col))
                 .then(|| (name.clone(), dtype.clone()))
         })
         .collect();
@@ -65,5 +68,11 @@ pub fn resolve_projections(
         Arc::make_mut(&mut full_file_schema).shift_remove(include_file_paths.unwrap());
     }
 
+    for name in file_metadata_columns.iter_names() {
+        if full_file_schema.contains(name) {
+            Arc::make_mut(&mut full_file_schema).shift_remove(name);
+        }
+    }
+
     (projected_file_schema.into(), full_file_schema)
 }
@@ -13,6 +13,7 @@ use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
 pub struct ResolvedSliceInfo {
     pub scan_source_idx: usize,
     pub row_index: Option<RowIndex>,
+    pub row_index_u64_offset: Option<u64>,
     /// This should always be positive slice.
     pub pre_slice: Option<Slice>,
     /// If we resolved a negative slice we keep the initialized readers here (with a limit). For
@@ -30,6 +31,7 @@ pub async fn resolve_to_positive_slice(
         None => Ok(ResolvedSliceInfo {
             scan_source_idx: 0,
             row_index: config.row_index.clone(),
+            row_index_u64_offset: config.row_index_u64_offset,
             pre_slice: None,
             initialized_readers: None,
         }),
@@ -37,6 +39,7 @@ pub async fn resolve_to_positive_slice(
         pre_slice @ Some(Slice::Positive { .. }) => Ok(ResolvedSliceInfo {
             scan_source_idx: 0,
             row_index: config.row_index.clone(),
+            row_index_u64_offset: config.row_index_u64_offset,
             pre_slice,
             initialized_readers: None,
         }),
@@ -72,6 +75,7 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
         return Ok(ResolvedSliceInfo {
             scan_source_idx: config.sources.len(),
             row_index: config.row_index.clone(),
+            row_index_u64_offset: config.row_index_u64_offset,
             pre_slice: Some(Slice::Positive { offset: 0, len: 0 }),
             initialized_readers: None,
         });
@@ -168,32 +172,43 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
         return Ok(ResolvedSliceInfo {
             scan_source_idx: config.sources.len(),
             row_index: config.row_index.clone(),
+            row_index_u64_offset: config.row_index_u64_offset,
             pre_slice: Some(Slice::Positive { offset: 0, len: 0 }),
             initialized_readers: None,
         });
     }
 
     let mut row_index = config.row_index.clone();
+    let mut row_index_u64_offset = config.row_index_u64_offset;
 
     if let Some(row_index) = row_index.as_mut() {
         if verbose {
             eprintln!("resolve_negative_slice(): continuing scan to resolve row index");
         }
 
-        let mut n_rows_skipped_from_start: IdxSize = 0;
+        // Counted as u64, as a `UInt64` row index can be offset past `IdxSize::MAX`.
+        let mut n_rows_skipped_from_start: u64 = 0;
 
         // Fully traverse to the beginning to update the row index offset.
         while let Some(mut reader) = readers_init_iter.next().await.transpose()? {
-            let n_rows = reader.n_rows_in_file().await?;
+            #[allow(clippy::useless_conversion)]
+            let n_rows = u64::from(reader.n_rows_in_file().await?);
             n_rows_skipped_from_start = n_rows_skipped_from_start.saturating_add(n_rows);
         }
 
-        row_index.offset = row_index.offset.saturating_add(n_rows_skipped_from_start);
+        row_index.offset = row_index
+            .offset
+            .saturating_add(IdxSize::try_from(n_rows_skipped_from_start).unwrap_or(IdxSize::MAX));
+
+        if let Some(offset) = row_index_u64_offset.as_mut() {
+            *offset = offset.saturating_add(n_rows_skipped_from_start);
+        }
     }
 
     Ok(ResolvedSliceInfo {
         scan_source_idx,
         row_index,
+        row_index_u64_offset,
         pre_slice: Some(resolved_slice),
         initialized_readers,
     })
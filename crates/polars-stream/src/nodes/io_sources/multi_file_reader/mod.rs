@@ -15,7 +15,9 @@ use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::{RowIndex, pl_async};
-use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSources};
+use polars_plan::dsl::{
+    CastColumnsPolicy, ExtraColumnsPolicy, FileMetadataColumns, MissingColumnsPolicy, ScanSources,
+};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -45,11 +47,15 @@ pub struct MultiFileReaderConfig {
     pub full_file_schema: SchemaRef,
 
     pub row_index: Option<RowIndex>,
+    /// Generate `row_index` as `UInt64` starting at this offset instead. This is never delegated
+    /// to the readers, as they number rows using `IdxSize`.
+    pub row_index_u64_offset: Option<u64>,
     pub pre_slice: Option<Slice>,
     pub predicate: Option<ScanIOPredicate>,
 
     pub hive_parts: Option<Arc<HivePartitionsDf>>,
     pub include_file_paths: Option<PlSmallStr>,
+    pub file_metadata_columns: FileMetadataColumns,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub extra_columns_policy: ExtraColumnsPolicy,
     pub cast_columns_policy: CastColumnsPolicy,
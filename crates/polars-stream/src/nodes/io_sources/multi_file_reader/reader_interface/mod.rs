@@ -12,6 +12,7 @@ use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::CastColumnsPolicy;
 use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
 use crate::async_executor::JoinHandle;
@@ -119,6 +120,11 @@ pub struct BeginReadArgs {
     /// This can be ignored by the reader, as the policy is also applied in post.
     pub cast_columns_policy: CastColumnsPolicy,
 
+    /// Name of a column to attach holding the index of the row group that rows were read from.
+    ///
+    /// Readers without row groups ignore this, the column is then filled with nulls in post.
+    pub row_group_index: Option<PlSmallStr>,
+
     pub num_pipelines: usize,
     pub callbacks: FileReaderCallbacks,
     // TODO
@@ -136,6 +142,7 @@ impl Default for BeginReadArgs {
             predicate: None,
             // TODO: Use less restrictive default
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            row_group_index: None,
             num_pipelines: 1,
             callbacks: FileReaderCallbacks::default(),
         }
@@ -7,7 +7,7 @@ use futures::stream::BoxStream;
 use polars_core::prelude::{AnyValue, DataType};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_err};
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -56,6 +56,7 @@ impl MultiScanTaskInitializer {
         let ResolvedSliceInfo {
             scan_source_idx,
             row_index,
+            row_index_u64_offset,
             pre_slice,
             initialized_readers,
         } = match self.config.pre_slice {
@@ -64,7 +65,8 @@ impl MultiScanTaskInitializer {
                 if self.config.sources.len() == 1
                     && reader_capabilities.contains(ReaderCapabilities::NEGATIVE_PRE_SLICE)
                     && (self.config.row_index.is_none()
-                        || reader_capabilities.contains(ReaderCapabilities::ROW_INDEX)) =>
+                        || (reader_capabilities.contains(ReaderCapabilities::ROW_INDEX)
+                            && self.config.row_index_u64_offset.is_none())) =>
             {
                 if verbose {
                     eprintln!("[MultiScanTaskInitializer]: Single file negative slice");
@@ -73,6 +75,7 @@ impl MultiScanTaskInitializer {
                 ResolvedSliceInfo {
                     scan_source_idx: 0,
                     row_index: self.config.row_index.clone(),
+                    row_index_u64_offset: self.config.row_index_u64_offset,
                     pre_slice: self.config.pre_slice.clone(),
                     initialized_readers: None,
                 }
@@ -93,13 +96,16 @@ impl MultiScanTaskInitializer {
         let cast_columns_policy = self.config.cast_columns_policy.clone();
         let missing_columns_policy = self.config.missing_columns_policy;
         let include_file_paths = self.config.include_file_paths.clone();
+        let file_metadata_columns = self.config.file_metadata_columns.clone();
 
         let extra_ops = ExtraOperations {
             row_index,
+            row_index_u64_offset,
             pre_slice,
             cast_columns_policy,
             missing_columns_policy,
             include_file_paths,
+            file_metadata_columns,
             predicate,
         };
 
@@ -322,6 +328,10 @@ impl ReaderStarter {
             current_row_position = IdxSize::MAX;
         }
 
+        // Rows before the current file, for the `UInt64` row index which can count past
+        // `IdxSize::MAX`.
+        let mut current_row_position_u64: u64 = 0;
+
         let wait_group = WaitGroup::default();
 
         loop {
@@ -381,9 +391,11 @@ impl ReaderStarter {
                         panic!();
                     }
 
-                    current_row_position = current_row_position.saturating_add(
-                        reader.row_position_after_slice(pre_slice_this_file).await?,
-                    );
+                    let n = reader.row_position_after_slice(pre_slice_this_file).await?;
+                    current_row_position = current_row_position.saturating_add(n);
+                    #[allow(clippy::useless_conversion)]
+                    let n = u64::from(n);
+                    current_row_position_u64 = current_row_position_u64.saturating_add(n);
                 }
 
                 continue;
@@ -394,8 +406,22 @@ impl ReaderStarter {
                 ri
             });
 
+            let row_index_u64_offset_this_file = extra_ops
+                .row_index_u64_offset
+                .map(|offset| {
+                    offset.checked_add(current_row_position_u64).ok_or_else(|| {
+                        polars_err!(
+                            ComputeError:
+                            "row index with offset {} overflows at {} rows",
+                            offset, current_row_position_u64
+                        )
+                    })
+                })
+                .transpose()?;
+
             let extra_ops_this_file = ExtraOperations {
                 row_index: row_index_this_file,
+                row_index_u64_offset: row_index_u64_offset_this_file,
                 pre_slice: pre_slice_this_file.clone(),
                 // Other operations don't need updating per file
                 ..extra_ops.clone()
@@ -434,7 +460,9 @@ impl ReaderStarter {
 
             let mut extra_ops_post = extra_ops_this_file;
 
-            let row_index = if reader_capabilities.contains(ReaderCapabilities::ROW_INDEX) {
+            let row_index = if reader_capabilities.contains(ReaderCapabilities::ROW_INDEX)
+                && extra_ops_post.row_index_u64_offset.is_none()
+            {
                 extra_ops_post.row_index.take()
             } else {
                 None
@@ -479,6 +507,7 @@ impl ReaderStarter {
                 pre_slice,
                 predicate,
                 cast_columns_policy: extra_ops_post.cast_columns_policy.clone(),
+                row_group_index: extra_ops_post.file_metadata_columns.row_group.clone(),
                 num_pipelines,
                 callbacks,
             };
@@ -518,6 +547,9 @@ impl ReaderStarter {
             if let Some(mut rx) = row_position_on_end_rx {
                 if let Ok(n) = rx.recv().await {
                     current_row_position = current_row_position.saturating_add(n);
+                    #[allow(clippy::useless_conversion)]
+                    let n = u64::from(n);
+                    current_row_position_u64 = current_row_position_u64.saturating_add(n);
                 }
             }
 
@@ -696,6 +728,17 @@ async fn start_reader_impl(
             ri.offset = ri.offset.saturating_add(offset_by);
         }
 
+        if let Some(offset) = extra_ops.row_index_u64_offset.as_mut() {
+            let offset_by = pre_slice_to_reader.as_ref().map_or(0, |x| {
+                let Slice::Positive { offset, .. } = x else {
+                    unreachable!()
+                };
+                *offset as u64
+            });
+
+            *offset = offset.saturating_add(offset_by);
+        }
+
         ApplyExtraOps::Uninitialized {
             final_output_schema,
             projected_file_schema,
@@ -74,6 +74,7 @@ impl FileReader for NDJsonFileReader {
 
             predicate: None,
             cast_columns_policy: _,
+            row_group_index: _,
         } = args
         else {
             panic!("unsupported args: {:?}", &args)
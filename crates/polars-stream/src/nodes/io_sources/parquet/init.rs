@@ -368,6 +368,7 @@ impl ParquetReadImpl {
     pub(super) fn init_row_group_decoder(&self) -> RowGroupDecoder {
         let projected_arrow_schema = self.projected_arrow_schema.clone();
         let row_index = self.row_index.clone();
+        let row_group_index = self.row_group_index.clone();
         let min_values_per_thread = self.config.min_values_per_thread;
 
         let mut use_prefiltered = matches!(self.options.parallel, ParallelStrategy::Prefiltered);
@@ -413,6 +414,7 @@ impl ParquetReadImpl {
             num_pipelines: self.config.num_pipelines,
             projected_arrow_schema,
             row_index,
+            row_group_index,
             predicate: self.predicate.clone(),
             use_prefiltered,
             predicate_arrow_field_indices,
@@ -14,6 +14,7 @@ use polars_parquet::read::schema::infer_schema_with_options;
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
 use super::multi_file_reader::extra_ops::cast_columns::CastColumns;
@@ -98,10 +99,14 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
-                metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
-            )?)
+            Arc::new(
+                polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+                    metadata_bytes.as_ref(),
+                    metadata_bytes.ends_with(&polars_parquet::parquet::PARQUET_ENCRYPTED_MAGIC),
+                    metadata_bytes.len() * 2 + 1024,
+                    self.config.encryption.as_ref(),
+                )?,
+            )
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
@@ -135,6 +140,7 @@ impl FileReader for ParquetFileReader {
             pre_slice: pre_slice_arg,
             mut predicate,
             cast_columns_policy,
+            row_group_index,
             num_pipelines,
             callbacks:
                 FileReaderCallbacks {
@@ -264,6 +270,7 @@ impl FileReader for ParquetFileReader {
             projected_arrow_schema,
             memory_prefetch_func,
             row_index,
+            row_group_index,
         }
         .run();
 
@@ -335,6 +342,7 @@ struct ParquetReadImpl {
     projected_arrow_schema: Arc<ArrowSchema>,
     memory_prefetch_func: fn(&[u8]) -> (),
     row_index: Option<RowIndex>,
+    row_group_index: Option<PlSmallStr>,
 }
 
 #[derive(Debug)]
@@ -18,6 +18,7 @@ use crate::utils::task_handles_ext;
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
 pub(super) struct RowGroupData {
     pub(super) fetched_bytes: FetchedBytes,
+    pub(super) row_group_idx: usize,
     pub(super) row_offset: usize,
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
@@ -151,6 +152,7 @@ impl RowGroupDataFetcher {
 
                 PolarsResult::Ok(RowGroupData {
                     fetched_bytes,
+                    row_group_idx: idx,
                     row_offset: current_row_offset,
                     slice,
                     // @TODO: Remove clone
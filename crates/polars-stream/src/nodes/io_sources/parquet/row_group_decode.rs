@@ -3,8 +3,10 @@ use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    ArrowField, ArrowSchema, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn,
+    AnyValue, ArrowField, ArrowSchema, BooleanChunked, ChunkFilter, Column, DataType, IDX_DTYPE,
+    IntoColumn,
 };
+use polars_core::scalar::Scalar;
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
@@ -27,6 +29,8 @@ pub(super) struct RowGroupDecoder {
     pub(super) num_pipelines: usize,
     pub(super) projected_arrow_schema: Arc<ArrowSchema>,
     pub(super) row_index: Option<RowIndex>,
+    /// Name of the column holding the index of the row group, attached after filtering.
+    pub(super) row_group_index: Option<PlSmallStr>,
     pub(super) predicate: Option<ScanIOPredicate>,
     pub(super) use_prefiltered: Option<PrefilterMaskSetting>,
     /// Indices into `projected_arrow_schema. This must be sorted.
@@ -47,14 +51,31 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
-        if self.use_prefiltered.is_some()
+        let row_group_idx = row_group_data.row_group_idx;
+
+        let mut df = if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
             && !self.predicate_arrow_field_indices.is_empty()
         {
-            self.row_group_data_to_df_prefiltered(row_group_data).await
+            self.row_group_data_to_df_prefiltered(row_group_data)
+                .await?
         } else {
-            self.row_group_data_to_df_impl(row_group_data).await
+            self.row_group_data_to_df_impl(row_group_data).await?
+        };
+
+        if let Some(name) = self.row_group_index.clone() {
+            let idx = IdxSize::try_from(row_group_idx).unwrap_or(IdxSize::MAX);
+            let height = df.height();
+            unsafe {
+                df.with_column_unchecked(Column::new_scalar(
+                    name,
+                    Scalar::new(IDX_DTYPE, AnyValue::from(idx)),
+                    height,
+                ))
+            };
         }
+
+        Ok(df)
     }
 
     async fn row_group_data_to_df_impl(
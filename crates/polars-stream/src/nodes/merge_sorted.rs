@@ -17,7 +17,8 @@ pub struct MergeSortedNode {
 
     seq: MorselSeq,
 
-    starting_nulls: bool,
+    /// Whether the nulls of both keys are placed last instead of first.
+    nulls_last: bool,
 
     // Not yet merged buffers.
     left_unmerged: VecDeque<DataFrame>,
@@ -25,7 +26,7 @@ pub struct MergeSortedNode {
 }
 
 impl MergeSortedNode {
-    pub fn new(schema: Arc<Schema>, key: PlSmallStr) -> Self {
+    pub fn new(schema: Arc<Schema>, key: PlSmallStr, nulls_last: bool) -> Self {
         assert!(schema.contains(key.as_str()));
         let key_column_idx = schema.index_of(key.as_str()).unwrap();
 
@@ -34,7 +35,7 @@ impl MergeSortedNode {
 
             seq: MorselSeq::default(),
 
-            starting_nulls: false,
+            nulls_last,
 
             left_unmerged: VecDeque::new(),
             right_unmerged: VecDeque::new(),
@@ -51,8 +52,7 @@ fn find_mergeable(
 
     key_column_idx: usize,
 
-    is_first: bool,
-    starting_nulls: &mut bool,
+    nulls_last: bool,
 ) -> PolarsResult<Option<(DataFrame, DataFrame)>> {
     fn first_non_empty(vd: &mut VecDeque<DataFrame>) -> Option<DataFrame> {
         let mut df = vd.pop_front()?;
@@ -87,15 +87,6 @@ fn find_mergeable(
 
         let has_nulls = left_null_count > 0 || right_null_count > 0;
 
-        // If we are on the first morsel we need to decide whether we have
-        // nulls first or not.
-        if is_first
-            && has_nulls
-            && (left_key.head(Some(1)).has_nulls() || right_key.head(Some(1)).has_nulls())
-        {
-            *starting_nulls = true;
-        }
-
         // For both left and right, find row index of the minimum of the maxima
         // of the left and right key columns. We can safely merge until this
         // point.
@@ -110,7 +101,7 @@ fn find_mergeable(
         assert!(!right_key_last.is_empty());
 
         if has_nulls {
-            if *starting_nulls {
+            if !nulls_last {
                 // If there are starting nulls do those first, then repeat
                 // without the nulls.
                 left_cutoff = left_null_count;
@@ -237,7 +228,7 @@ impl ComputeNode for MergeSortedNode {
         let send = send_ports[0].take().unwrap().parallel();
 
         let seq = &mut self.seq;
-        let starting_nulls = &mut self.starting_nulls;
+        let nulls_last = self.nulls_last;
         let key_column_idx = self.key_column_idx;
         let left_unmerged = &mut self.left_unmerged;
         let right_unmerged = &mut self.right_unmerged;
@@ -325,8 +316,7 @@ impl ComputeNode for MergeSortedNode {
                             left_unmerged,
                             right_unmerged,
                             key_column_idx,
-                            seq.to_u64() == 0,
-                            starting_nulls,
+                            nulls_last,
                         )? {
                             let left_mergeable =
                                 Morsel::new(left_mergeable, *seq, source_token.clone());
@@ -382,13 +372,9 @@ impl ComputeNode for MergeSortedNode {
 
                     // Clear out buffers until we cannot anymore. This helps allows us to go to the
                     // parallel case faster.
-                    while let Some((left_mergeable, right_mergeable)) = find_mergeable(
-                        left_unmerged,
-                        right_unmerged,
-                        key_column_idx,
-                        seq.to_u64() == 0,
-                        starting_nulls,
-                    )? {
+                    while let Some((left_mergeable, right_mergeable)) =
+                        find_mergeable(left_unmerged, right_unmerged, key_column_idx, nulls_last)?
+                    {
                         let left_mergeable =
                             Morsel::new(left_mergeable, *seq, source_token.clone());
                         *seq = seq.successor();
@@ -470,7 +456,9 @@ impl ComputeNode for MergeSortedNode {
                             let left_s = left[key_column_idx].as_materialized_series();
                             let right_s = right[key_column_idx].as_materialized_series();
 
-                            let merged = _merge_sorted_dfs(&left, &right, left_s, right_s, false)?;
+                            let merged = _merge_sorted_dfs(
+                                &left, &right, left_s, right_s, false, false, nulls_last,
+                            )?;
 
                             if ideal_morsel_size > 1 && merged.height() > ideal_morsel_size {
                                 // The merged dataframe will have at most doubled in size from the
@@ -1,3 +1,4 @@
+pub mod callback_sink;
 pub mod filter;
 pub mod group_by;
 pub mod in_memory_map;
@@ -17,6 +18,7 @@ pub mod reduce;
 pub mod select;
 pub mod simple_projection;
 pub mod streaming_slice;
+pub mod unordered_union;
 pub mod with_row_index;
 pub mod zip;
 
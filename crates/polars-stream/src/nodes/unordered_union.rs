@@ -0,0 +1,103 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use futures::StreamExt;
+use futures::stream::{self, BoxStream};
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+
+/// A node that passes through the data from all inputs concurrently, morsels
+/// of different inputs are interleaved in arbitrary order.
+pub struct UnorderedUnionNode {
+    seq: AtomicU64,
+}
+
+impl UnorderedUnionNode {
+    pub fn new() -> Self {
+        Self {
+            seq: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ComputeNode for UnorderedUnionNode {
+    fn name(&self) -> &str {
+        "unordered-union"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(send.len() == 1);
+
+        if send[0] == PortState::Done || recv.iter().all(|r| *r == PortState::Done) {
+            recv.fill(PortState::Done);
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+
+        // All inputs that are not done yet run at the same time, we're ready
+        // as soon as any of them is.
+        let any_ready = recv.contains(&PortState::Ready);
+        for r in recv.iter_mut() {
+            if *r != PortState::Done {
+                *r = send[0];
+            }
+        }
+        send[0] = if any_ready {
+            PortState::Ready
+        } else {
+            PortState::Blocked
+        };
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1);
+        let senders = send_ports[0].take().unwrap().parallel();
+
+        // Group the receivers of all inputs by pipeline.
+        let mut receivers: Vec<Vec<Receiver<Morsel>>> = senders.iter().map(|_| vec![]).collect();
+        for recv_port in recv_ports.iter_mut().filter_map(Option::take) {
+            for (pipeline, recv) in receivers.iter_mut().zip(recv_port.parallel()) {
+                pipeline.push(recv);
+            }
+        }
+
+        for (pipeline, mut send) in receivers.into_iter().zip(senders) {
+            let slf = &*self;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut morsels = stream::select_all(pipeline.into_iter().map(into_stream));
+                while let Some(mut morsel) = morsels.next().await {
+                    // The sequence ids of different inputs overlap, give every
+                    // morsel a fresh one. They're increasing on each pipeline
+                    // and unique over all of them.
+                    let seq = slf.seq.fetch_add(1, Ordering::Relaxed);
+                    morsel.set_seq(MorselSeq::new(seq));
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+}
+
+fn into_stream(recv: Receiver<Morsel>) -> BoxStream<'static, Morsel> {
+    stream::unfold(recv, |mut recv| async move {
+        let morsel = recv.recv().await.ok()?;
+        Some((morsel, recv))
+    })
+    .boxed()
+}
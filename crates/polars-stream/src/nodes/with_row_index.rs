@@ -9,14 +9,18 @@ use crate::async_primitives::wait_group::WaitGroup;
 
 pub struct WithRowIndexNode {
     name: PlSmallStr,
-    offset: IdxSize,
+    /// The index of the next row, `None` once the last index was `u64::MAX`.
+    offset: Option<u64>,
+    /// Either `IDX_DTYPE` or `UInt64`.
+    dtype: DataType,
 }
 
 impl WithRowIndexNode {
-    pub fn new(name: PlSmallStr, offset: Option<IdxSize>) -> Self {
+    pub fn new(name: PlSmallStr, offset: u64, dtype: DataType) -> Self {
         Self {
             name,
-            offset: offset.unwrap_or(0),
+            offset: Some(offset),
+            dtype,
         }
     }
 }
@@ -53,15 +57,22 @@ impl ComputeNode for WithRowIndexNode {
             distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
 
         let name = self.name.clone();
+        let is_u64 = self.dtype == DataType::UInt64;
 
         // To figure out the correct offsets we need to be serial.
         join_handles.push(scope.spawn_task(TaskPriority::High, async move {
             while let Ok(morsel) = receiver.recv().await {
-                let offset = self.offset;
-                self.offset = self
-                    .offset
-                    .checked_add(morsel.df().len().try_into().unwrap())
-                    .unwrap();
+                let len = morsel.df().len() as u64;
+                let offset = self.offset.unwrap_or(u64::MAX);
+                if len > 0 {
+                    let last = self
+                        .offset
+                        .and_then(|offset| offset.checked_add(len - 1))
+                        .ok_or_else(
+                            || polars_err!(ComputeError: "row index overflows with offset {}", offset),
+                        )?;
+                    self.offset = last.checked_add(1);
+                }
                 if distributor.send((morsel, offset)).await.is_err() {
                     break;
                 }
@@ -76,8 +87,19 @@ impl ComputeNode for WithRowIndexNode {
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let wait_group = WaitGroup::default();
                 while let Ok((morsel, offset)) = recv.recv().await {
-                    let mut morsel =
-                        morsel.try_map(|df| df.with_row_index(name.clone(), Some(offset)))?;
+                    let mut morsel = morsel.try_map(|df| {
+                        if is_u64 {
+                            df.with_row_index_u64(name.clone(), Some(offset))
+                        } else {
+                            let offset = IdxSize::try_from(offset).map_err(|_| {
+                                polars_err!(
+                                    ComputeError:
+                                    "row index offset {} does not fit in {}", offset, IDX_DTYPE
+                                )
+                            })?;
+                            df.with_row_index(name.clone(), Some(offset))
+                        }
+                    })?;
                     morsel.set_consume_token(wait_group.token());
                     if send.send(morsel).await.is_err() {
                         break;
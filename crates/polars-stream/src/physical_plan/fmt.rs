@@ -199,8 +199,9 @@ fn visualize_plan_rec(
             input,
             name,
             offset,
+            dtype: _,
         } => (
-            format!("with-row-index\\nname: {name}\\noffset: {offset:?}"),
+            format!("with-row-index\\nname: {name}\\noffset: {offset}"),
             from_ref(input),
         ),
         PhysNodeKind::InputIndependentSelect { selectors } => (
@@ -245,6 +246,7 @@ fn visualize_plan_rec(
             from_ref(input),
         ),
         PhysNodeKind::InMemorySink { input } => ("in-memory-sink".to_string(), from_ref(input)),
+        PhysNodeKind::CallbackSink { input, .. } => ("callback-sink".to_string(), from_ref(input)),
         PhysNodeKind::FileSink {
             input, file_type, ..
         } => match file_type {
@@ -313,6 +315,9 @@ fn visualize_plan_rec(
             from_ref(input),
         ),
         PhysNodeKind::OrderedUnion { inputs } => ("ordered-union".to_string(), inputs.as_slice()),
+        PhysNodeKind::UnorderedUnion { inputs } => {
+            ("unordered-union".to_string(), inputs.as_slice())
+        },
         PhysNodeKind::Zip {
             inputs,
             null_extend,
@@ -332,10 +337,12 @@ fn visualize_plan_rec(
             projected_file_schema,
             output_schema,
             row_index,
+            row_index_u64_offset,
             pre_slice,
             predicate,
             hive_parts,
             include_file_paths,
+            file_metadata_columns,
             cast_columns_policy: _,
             missing_columns_policy: _,
             extra_columns_policy: _,
@@ -359,13 +366,25 @@ fn visualize_plan_rec(
             .unwrap();
 
             if let Some(ri) = row_index {
-                write!(f, "\nrow index: name: {}, offset: {:?}", ri.name, ri.offset).unwrap();
+                if let Some(offset) = row_index_u64_offset {
+                    write!(f, "\nrow index (u64): name: {}, offset: {offset}", ri.name).unwrap();
+                } else {
+                    write!(f, "\nrow index: name: {}, offset: {:?}", ri.name, ri.offset).unwrap();
+                }
             }
 
             if let Some(col_name) = include_file_paths {
                 write!(f, "\nfile path column: {col_name}").unwrap();
             }
 
+            if !file_metadata_columns.is_empty() {
+                let names = file_metadata_columns
+                    .iter_names()
+                    .map(|x| x.as_str())
+                    .collect::<Vec<_>>();
+                write!(f, "\nfile metadata columns: {}", names.join(", ")).unwrap();
+            }
+
             if let Some(pre_slice) = pre_slice {
                 write!(f, "\nslice: offset: ").unwrap();
 
@@ -480,6 +499,7 @@ fn visualize_plan_rec(
             input_left,
             input_right,
             key,
+            nulls_last: _,
         } => {
             let mut out = "merge-sorted".to_string();
             let mut f = EscapeLabel(&mut out);
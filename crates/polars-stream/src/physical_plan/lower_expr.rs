@@ -4,6 +4,7 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{DataType, Field, InitHashMaps, PlHashMap, PlHashSet};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
+use polars_expr::reduce::is_reduction_function;
 use polars_expr::state::ExecutionState;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_ops::frame::{JoinArgs, JoinType};
@@ -146,6 +147,7 @@ pub fn is_input_independent_rec(
             expr: inner,
             dtype: _,
             options: _,
+            drop_metadata: _,
         }
         | AExpr::Sort {
             expr: inner,
@@ -295,6 +297,7 @@ pub fn is_length_preserving_rec(
             expr: inner,
             dtype: _,
             options: _,
+            drop_metadata: _,
         }
         | AExpr::Sort {
             expr: inner,
@@ -639,6 +642,38 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(tmp_name)));
             },
 
+            AExpr::Function {
+                input: ref inner_exprs,
+                ref function,
+                options,
+            } if is_reduction_function(function) => {
+                // The function has a mergeable state, so this lowers to a reduction.
+                let function = function.clone();
+                let inner = &inner_exprs[0];
+                let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[inner.node()], ctx)?;
+                let trans_inner = ExprIR::new(
+                    trans_exprs[0],
+                    OutputName::Alias(inner.output_name().clone()),
+                );
+
+                let out_name = unique_column_name();
+                let trans_agg_expr = ctx.expr_arena.add(AExpr::Function {
+                    input: vec![trans_inner],
+                    function,
+                    options,
+                });
+                let expr_ir = ExprIR::new(trans_agg_expr, OutputName::Alias(out_name.clone()));
+                let output_schema =
+                    schema_for_select(trans_input, std::slice::from_ref(&expr_ir), ctx)?;
+                let kind = PhysNodeKind::Reduce {
+                    input: trans_input,
+                    exprs: vec![expr_ir],
+                };
+                let reduce_node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+                input_streams.insert(PhysStream::first(reduce_node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
             #[cfg(feature = "is_in")]
             AExpr::Function {
                 input: ref inner_exprs,
@@ -767,6 +802,7 @@ fn lower_exprs_with_ctx(
                 expr: inner,
                 dtype,
                 options,
+                drop_metadata,
             } => {
                 let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[inner], ctx)?;
                 input_streams.insert(trans_input);
@@ -774,6 +810,7 @@ fn lower_exprs_with_ctx(
                     expr: trans_exprs[0],
                     dtype,
                     options,
+                    drop_metadata,
                 }));
             },
             AExpr::Sort {
@@ -4,6 +4,7 @@ use parking_lot::Mutex;
 use polars_core::prelude::{InitHashMaps, PlIndexMap};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_err};
+use polars_expr::reduce::is_reduction_function;
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
@@ -180,19 +181,59 @@ fn try_lower_elementwise_scalar_agg_expr(
             Some(expr_arena.add(new_node))
         },
 
+        AExpr::Function {
+            input,
+            function,
+            options,
+        } if is_reduction_function(function) => {
+            let (inner, function, options) = (input[0].node(), function.clone(), *options);
+            if is_input_independent(inner, expr_arena, expr_cache)
+                || !is_elementwise_rec_cached(inner, expr_arena, expr_cache)
+            {
+                return None;
+            }
+
+            let input_id = expr_merger.get_uniq_id(inner).unwrap();
+            let input_col = uniq_input_exprs
+                .entry(input_id)
+                .or_insert_with(unique_column_name)
+                .clone();
+            let input_col_node = expr_arena.add(AExpr::Column(input_col.clone()));
+            let trans_agg_node = expr_arena.add(AExpr::Function {
+                input: vec![ExprIR::new(
+                    input_col_node,
+                    OutputName::ColumnLhs(input_col),
+                )],
+                function,
+                options,
+            });
+
+            let agg_expr = if let Some(name) = outer_name {
+                ExprIR::new(trans_agg_node, OutputName::Alias(name))
+            } else {
+                ExprIR::new(trans_agg_node, OutputName::Alias(unique_column_name()))
+            };
+            let result_node = expr_arena.add(AExpr::Column(agg_expr.output_name().clone()));
+            agg_exprs.push(agg_expr);
+            Some(result_node)
+        },
+
         AExpr::Function { .. } | AExpr::AnonymousFunction { .. } => None,
 
         AExpr::Cast {
             expr,
             dtype,
             options,
+            drop_metadata,
         } => {
-            let (expr, dtype, options) = (*expr, dtype.clone(), *options);
+            let (expr, dtype, options, drop_metadata) =
+                (*expr, dtype.clone(), *options, *drop_metadata);
             let expr = lower_rec!(expr)?;
             Some(expr_arena.add(AExpr::Cast {
                 expr,
                 dtype,
                 options,
+                drop_metadata,
             }))
         },
 
@@ -3,13 +3,14 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use polars_core::config;
 use polars_core::frame::{DataFrame, UniqueKeepStrategy};
-use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
+use polars_core::prelude::{DataType, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::dsl::{
-    ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, SinkTypeIR,
+    CallbackSinkType, ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR,
+    PartitionVariantIR, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
@@ -249,6 +250,23 @@ pub fn lower_ir(
                 let phys_input = lower_ir!(*input)?;
                 PhysNodeKind::InMemorySink { input: phys_input }
             },
+            SinkTypeIR::Callback(CallbackSinkType {
+                function,
+                maintain_order,
+                chunk_size,
+            }) => {
+                let function = function.clone();
+                let maintain_order = *maintain_order;
+                let chunk_size = *chunk_size;
+
+                let phys_input = lower_ir!(*input)?;
+                PhysNodeKind::CallbackSink {
+                    input: phys_input,
+                    function,
+                    maintain_order,
+                    chunk_size,
+                }
+            },
             SinkTypeIR::File(FileSinkType {
                 target,
                 sink_options,
@@ -361,10 +379,12 @@ pub fn lower_ir(
             input_left,
             input_right,
             key,
+            nulls_last,
         } => {
             let input_left = *input_left;
             let input_right = *input_right;
             let key = key.clone();
+            let nulls_last = *nulls_last;
 
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
@@ -373,6 +393,7 @@ pub fn lower_ir(
                 input_left: phys_left,
                 input_right: phys_right,
                 key,
+                nulls_last,
             }
         },
 
@@ -385,10 +406,22 @@ pub fn lower_ir(
                     name,
                     offset,
                     schema: _,
+                } => PhysNodeKind::WithRowIndex {
+                    input: phys_input,
+                    name,
+                    offset: offset.map_or(0, u64::from),
+                    dtype: IDX_DTYPE,
+                },
+
+                FunctionIR::RowIndexU64 {
+                    name,
+                    offset,
+                    schema: _,
                 } => PhysNodeKind::WithRowIndex {
                     input: phys_input,
                     name,
                     offset,
+                    dtype: DataType::UInt64,
                 },
 
                 function if function.is_streamable() => {
@@ -442,9 +475,14 @@ pub fn lower_ir(
                 .map(|input| lower_ir!(input))
                 .collect::<Result<_, _>>()?;
 
+            let kind = if options.interleave {
+                PhysNodeKind::UnorderedUnion { inputs }
+            } else {
+                PhysNodeKind::OrderedUnion { inputs }
+            };
             let node = phys_sm.insert(PhysNode {
                 output_schema,
-                kind: PhysNodeKind::OrderedUnion { inputs },
+                kind,
             });
             let mut stream = PhysStream::first(node);
             if let Some((offset, length)) = options.slice {
@@ -514,10 +552,13 @@ pub fn lower_ir(
 
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc {
-                        options: polars_io::ipc::IpcScanOptions {},
+                        options,
                         metadata: first_metadata,
+                        fingerprints,
                     } => Arc::new(crate::nodes::io_sources::ipc::builder::IpcReaderBuilder {
+                        options: options.clone(),
                         first_metadata: first_metadata.clone(),
+                        fingerprints: fingerprints.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
                     #[cfg(feature = "csv")]
@@ -565,6 +606,8 @@ pub fn lower_ir(
 
                     let cloud_options = cloud_options.clone().map(Arc::new);
                     let file_schema = file_info.schema.clone();
+                    let row_index_u64_offset = unified_scan_args.row_index_u64_offset;
+                    let row_index_dtype = unified_scan_args.row_index_dtype();
 
                     let (projected_file_schema, file_schema) =
                         multi_file_reader::initialization::projection::resolve_projections(
@@ -579,6 +622,7 @@ pub fn lower_ir(
                                 .include_file_paths
                                 .as_ref()
                                 .map(|x| x.as_str()),
+                            &unified_scan_args.file_metadata_columns,
                         );
                     let has_projection = unified_scan_args.projection.is_some();
 
@@ -596,6 +640,7 @@ pub fn lower_ir(
                         projected_file_schema,
                         output_schema: output_schema.clone(),
                         row_index: None,
+                        row_index_u64_offset: None,
                         pre_slice: None,
                         predicate: None,
                         hive_parts,
@@ -603,12 +648,14 @@ pub fn lower_ir(
                         missing_columns_policy: unified_scan_args.missing_columns_policy,
                         extra_columns_policy,
                         include_file_paths: unified_scan_args.include_file_paths,
+                        file_metadata_columns: unified_scan_args.file_metadata_columns,
                         file_schema,
                     };
 
                     let PhysNodeKind::MultiScan {
                         output_schema: multi_scan_output_schema,
                         row_index: row_index_to_multiscan,
+                        row_index_u64_offset: row_index_u64_offset_to_multiscan,
                         pre_slice: pre_slice_to_multiscan,
                         predicate: predicate_to_multiscan,
                         ..
@@ -635,6 +682,7 @@ pub fn lower_ir(
                         || matches!(pre_slice, Some(Slice::Negative { .. }))
                     {
                         *row_index_to_multiscan = row_index_post.take();
+                        *row_index_u64_offset_to_multiscan = row_index_u64_offset;
                     }
 
                     // TODO
@@ -681,8 +729,10 @@ pub fn lower_ir(
                         row_index.offset = row_index.offset.saturating_add(
                             IdxSize::try_from(positive_offset).unwrap_or(IdxSize::MAX),
                         );
+                        let u64_offset = row_index_u64_offset
+                            .map(|offset| offset.saturating_add(positive_offset as u64));
 
-                        Some(row_index)
+                        Some((row_index, u64_offset))
                     })();
 
                     let mut stream = {
@@ -694,10 +744,12 @@ pub fn lower_ir(
                     };
 
                     if let Some(ri) = row_index_post {
+                        #[allow(clippy::useless_conversion)]
                         let node = PhysNodeKind::WithRowIndex {
                             input: stream,
                             name: ri.name,
-                            offset: Some(ri.offset),
+                            offset: row_index_u64_offset.unwrap_or(u64::from(ri.offset)),
+                            dtype: row_index_dtype.clone(),
                         };
 
                         let node_key = phys_sm.insert(PhysNode {
@@ -728,11 +780,13 @@ pub fn lower_ir(
                         stream = build_slice_stream(stream, offset, length, phys_sm);
                     }
 
-                    if let Some(ri) = row_index_post_after_slice {
+                    if let Some((ri, u64_offset)) = row_index_post_after_slice {
+                        #[allow(clippy::useless_conversion)]
                         let node = PhysNodeKind::WithRowIndex {
                             input: stream,
                             name: ri.name,
-                            offset: Some(ri.offset),
+                            offset: u64_offset.unwrap_or(u64::from(ri.offset)),
+                            dtype: row_index_dtype,
                         };
 
                         let node_key = phys_sm.insert(PhysNode {
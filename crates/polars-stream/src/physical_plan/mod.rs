@@ -1,16 +1,18 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions};
+use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, SortMultipleOptions};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::{
-    CastColumnsPolicy, JoinTypeOptionsIR, MissingColumnsPolicy, PartitionTargetCallback,
-    PartitionVariantIR, ScanSources, SinkFinishCallback, SinkOptions, SinkTarget, SortColumnIR,
+    CastColumnsPolicy, FileMetadataColumns, JoinTypeOptionsIR, MissingColumnsPolicy,
+    PartitionTargetCallback, PartitionVariantIR, ScanSources, SinkBatchCallback,
+    SinkFinishCallback, SinkOptions, SinkTarget, SortColumnIR,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_plan::plans::{AExpr, DataFrameUdf, IR};
@@ -100,7 +102,9 @@ pub enum PhysNodeKind {
     WithRowIndex {
         input: PhysStream,
         name: PlSmallStr,
-        offset: Option<IdxSize>,
+        offset: u64,
+        /// Either `IDX_DTYPE` or `UInt64`.
+        dtype: DataType,
     },
 
     InputIndependentSelect {
@@ -138,6 +142,13 @@ pub enum PhysNodeKind {
         input: PhysStream,
     },
 
+    CallbackSink {
+        input: PhysStream,
+        function: SinkBatchCallback,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    },
+
     FileSink {
         target: SinkTarget,
         sink_options: SinkOptions,
@@ -189,6 +200,10 @@ pub enum PhysNodeKind {
         inputs: Vec<PhysStream>,
     },
 
+    UnorderedUnion {
+        inputs: Vec<PhysStream>,
+    },
+
     Zip {
         inputs: Vec<PhysStream>,
         /// If true shorter inputs are extended with nulls to the longest input,
@@ -214,11 +229,14 @@ pub enum PhysNodeKind {
         output_schema: SchemaRef,
 
         row_index: Option<RowIndex>,
+        /// Generate `row_index` as `UInt64` starting at this offset instead.
+        row_index_u64_offset: Option<u64>,
         pre_slice: Option<Slice>,
         predicate: Option<ExprIR>,
 
         hive_parts: Option<HivePartitionsDf>,
         include_file_paths: Option<PlSmallStr>,
+        file_metadata_columns: FileMetadataColumns,
         cast_columns_policy: CastColumnsPolicy,
         missing_columns_policy: MissingColumnsPolicy,
         extra_columns_policy: ExtraColumnsPolicy,
@@ -280,6 +298,7 @@ pub enum PhysNodeKind {
         input_right: PhysStream,
 
         key: PlSmallStr,
+        nulls_last: bool,
     },
 }
 
@@ -314,6 +333,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::Filter { input, .. }
             | PhysNodeKind::SimpleProjection { input, .. }
             | PhysNodeKind::InMemorySink { input }
+            | PhysNodeKind::CallbackSink { input, .. }
             | PhysNodeKind::FileSink { input, .. }
             | PhysNodeKind::PartitionSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
@@ -363,7 +383,9 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
-            PhysNodeKind::OrderedUnion { inputs } | PhysNodeKind::Zip { inputs, .. } => {
+            PhysNodeKind::OrderedUnion { inputs }
+            | PhysNodeKind::UnorderedUnion { inputs }
+            | PhysNodeKind::Zip { inputs, .. } => {
                 for input in inputs {
                     rec!(input.node);
                     visit(input);
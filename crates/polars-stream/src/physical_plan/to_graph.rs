@@ -178,10 +178,11 @@ fn to_graph_rec<'a>(
             input,
             name,
             offset,
+            dtype,
         } => {
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::with_row_index::WithRowIndexNode::new(name.clone(), *offset),
+                nodes::with_row_index::WithRowIndexNode::new(name.clone(), *offset, dtype.clone()),
                 [(input_key, input.port)],
             )
         },
@@ -241,6 +242,23 @@ fn to_graph_rec<'a>(
             )
         },
 
+        CallbackSink {
+            input,
+            function,
+            maintain_order,
+            chunk_size,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::callback_sink::CallbackSinkNode::new(
+                    function.clone(),
+                    *maintain_order,
+                    *chunk_size,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         FileSink {
             target,
             sink_options,
@@ -252,59 +270,80 @@ fn to_graph_rec<'a>(
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
 
-            match file_type {
-                #[cfg(feature = "ipc")]
-                FileType::Ipc(ipc_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::ipc::IpcSinkNode::new(
-                        input_schema,
-                        target.clone(),
-                        sink_options,
-                        *ipc_writer_options,
-                        cloud_options.clone(),
-                    )),
-                    [(input_key, input.port)],
-                ),
-                #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
-                        target.clone(),
-                        sink_options,
-                        cloud_options.clone(),
-                    )),
-                    [(input_key, input.port)],
-                ),
-                #[cfg(feature = "parquet")]
-                FileType::Parquet(parquet_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::parquet::ParquetSinkNode::new(
-                        input_schema,
-                        target.clone(),
-                        sink_options,
-                        parquet_writer_options,
-                        cloud_options.clone(),
-                        false,
-                    )?),
-                    [(input_key, input.port)],
-                ),
-                #[cfg(feature = "csv")]
-                FileType::Csv(csv_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::csv::CsvSinkNode::new(
-                        target.clone(),
-                        input_schema,
-                        sink_options,
-                        csv_writer_options.clone(),
-                        cloud_options.clone(),
-                    )),
+            if sink_options.is_rotating() {
+                let create_new = nodes::io_sinks::partition::get_create_new_fn(
+                    file_type.clone(),
+                    sink_options.clone(),
+                    cloud_options.clone(),
+                    false,
+                );
+                ctx.graph.add_node(
+                    SinkComputeNode::from(
+                        nodes::io_sinks::partition::max_size::MaxSizePartitionSinkNode::new_rotating(
+                            input_schema,
+                            target.clone(),
+                            create_new,
+                            sink_options,
+                        )?,
+                    ),
                     [(input_key, input.port)],
-                ),
-                #[cfg(not(any(
-                    feature = "csv",
-                    feature = "parquet",
-                    feature = "json",
-                    feature = "ipc"
-                )))]
-                _ => {
-                    panic!("activate source feature")
-                },
+                )
+            } else {
+                match file_type {
+                    #[cfg(feature = "ipc")]
+                    FileType::Ipc(ipc_writer_options) => ctx.graph.add_node(
+                        SinkComputeNode::from(nodes::io_sinks::ipc::IpcSinkNode::new(
+                            input_schema,
+                            target.clone(),
+                            sink_options,
+                            *ipc_writer_options,
+                            cloud_options.clone(),
+                        )),
+                        [(input_key, input.port)],
+                    ),
+                    #[cfg(feature = "json")]
+                    FileType::Json(ndjson_writer_options) => ctx.graph.add_node(
+                        SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
+                            target.clone(),
+                            sink_options,
+                            *ndjson_writer_options,
+                            cloud_options.clone(),
+                        )),
+                        [(input_key, input.port)],
+                    ),
+                    #[cfg(feature = "parquet")]
+                    FileType::Parquet(parquet_writer_options) => ctx.graph.add_node(
+                        SinkComputeNode::from(nodes::io_sinks::parquet::ParquetSinkNode::new(
+                            input_schema,
+                            target.clone(),
+                            sink_options,
+                            parquet_writer_options,
+                            cloud_options.clone(),
+                            false,
+                        )?),
+                        [(input_key, input.port)],
+                    ),
+                    #[cfg(feature = "csv")]
+                    FileType::Csv(csv_writer_options) => ctx.graph.add_node(
+                        SinkComputeNode::from(nodes::io_sinks::csv::CsvSinkNode::new(
+                            target.clone(),
+                            input_schema,
+                            sink_options,
+                            csv_writer_options.clone(),
+                            cloud_options.clone(),
+                        )),
+                        [(input_key, input.port)],
+                    ),
+                    #[cfg(not(any(
+                        feature = "csv",
+                        feature = "parquet",
+                        feature = "json",
+                        feature = "ipc"
+                    )))]
+                    _ => {
+                        panic!("activate source feature")
+                    },
+                }
             }
         },
 
@@ -476,6 +515,17 @@ fn to_graph_rec<'a>(
                 .add_node(nodes::ordered_union::OrderedUnionNode::new(), input_keys)
         },
 
+        UnorderedUnion { inputs } => {
+            let input_keys = inputs
+                .iter()
+                .map(|i| PolarsResult::Ok((to_graph_rec(i.node, ctx)?, i.port)))
+                .try_collect_vec()?;
+            ctx.graph.add_node(
+                nodes::unordered_union::UnorderedUnionNode::new(),
+                input_keys,
+            )
+        },
+
         Zip {
             inputs,
             null_extend,
@@ -509,6 +559,7 @@ fn to_graph_rec<'a>(
             projected_file_schema,
             output_schema,
             row_index,
+            row_index_u64_offset,
             pre_slice,
             predicate,
             hive_parts,
@@ -516,6 +567,7 @@ fn to_graph_rec<'a>(
             extra_columns_policy,
             cast_columns_policy,
             include_file_paths,
+            file_metadata_columns,
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
@@ -546,9 +598,11 @@ fn to_graph_rec<'a>(
             let full_file_schema = file_schema.clone();
 
             let row_index = row_index.clone();
+            let row_index_u64_offset = *row_index_u64_offset;
             let pre_slice = pre_slice.clone();
             let hive_parts = hive_parts.map(Arc::new);
             let include_file_paths = include_file_paths.clone();
+            let file_metadata_columns = file_metadata_columns.clone();
             let missing_columns_policy = *missing_columns_policy;
             let extra_columns_policy = *extra_columns_policy;
             let cast_columns_policy = cast_columns_policy.clone();
@@ -565,10 +619,12 @@ fn to_graph_rec<'a>(
                         projected_file_schema,
                         full_file_schema,
                         row_index,
+                        row_index_u64_offset,
                         pre_slice,
                         predicate,
                         hive_parts,
                         include_file_paths,
+                        file_metadata_columns,
                         missing_columns_policy,
                         extra_columns_policy,
                         cast_columns_policy,
@@ -811,6 +867,7 @@ fn to_graph_rec<'a>(
             input_left,
             input_right,
             key,
+            nulls_last,
         } => {
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
             let right_input_key = to_graph_rec(input_right.node, ctx)?;
@@ -818,7 +875,7 @@ fn to_graph_rec<'a>(
             let input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
 
             ctx.graph.add_node(
-                nodes::merge_sorted::MergeSortedNode::new(input_schema, key.clone()),
+                nodes::merge_sorted::MergeSortedNode::new(input_schema, key.clone(), *nulls_last),
                 [
                     (left_input_key, input_left.port),
                     (right_input_key, input_right.port),
@@ -985,10 +1042,12 @@ fn to_graph_rec<'a>(
             let projected_file_schema = output_schema.clone();
             let full_file_schema = output_schema.clone();
             let row_index = None;
+            let row_index_u64_offset = None;
             let pre_slice = None;
             let predicate = None;
             let hive_parts = None;
             let include_file_paths = None;
+            let file_metadata_columns = Default::default();
             let missing_columns_policy = MissingColumnsPolicy::Raise;
             let extra_columns_policy = ExtraColumnsPolicy::Ignore;
             let cast_columns_policy = CastColumnsPolicy::ERROR_ON_MISMATCH;
@@ -1004,10 +1063,12 @@ fn to_graph_rec<'a>(
                         projected_file_schema,
                         full_file_schema,
                         row_index,
+                        row_index_u64_offset,
                         pre_slice,
                         predicate,
                         hive_parts,
                         include_file_paths,
+                        file_metadata_columns,
                         missing_columns_policy,
                         extra_columns_policy,
                         cast_columns_policy,
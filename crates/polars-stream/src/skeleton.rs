@@ -8,6 +8,7 @@ use polars_plan::plans::{Context, IR, IRPlan};
 use polars_plan::prelude::AExpr;
 use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::{Arena, Node};
+use polars_utils::cancel::CancelToken;
 use slotmap::{SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
@@ -29,7 +30,20 @@ pub fn run_query(
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<QueryResult> {
-    StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
+    run_query_cancellable(node, ir_arena, expr_arena, CancelToken::new())
+}
+
+/// Executes the IR with the streaming engine, see [`run_query`].
+///
+/// Setting `cancel_token` stops the query at the next morsel boundary, with a
+/// "query interrupted" error.
+pub fn run_query_cancellable(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    cancel_token: CancelToken,
+) -> PolarsResult<QueryResult> {
+    StreamingQuery::build(node, ir_arena, expr_arena)?.execute_cancellable(cancel_token)
 }
 
 /// Visualizes the physical plan as a dot graph.
@@ -108,6 +122,10 @@ impl StreamingQuery {
     }
 
     pub fn execute(self) -> PolarsResult<QueryResult> {
+        self.execute_cancellable(CancelToken::new())
+    }
+
+    pub fn execute_cancellable(self, cancel_token: CancelToken) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
             mut graph,
@@ -117,7 +135,7 @@ impl StreamingQuery {
         } = self;
 
         crate::async_executor::clear_task_wait_statistics();
-        let mut results = crate::execute::execute_graph(&mut graph)?;
+        let mut results = crate::execute::execute_graph(&mut graph, cancel_token)?;
 
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             let mut stats = crate::async_executor::get_task_wait_statistics();
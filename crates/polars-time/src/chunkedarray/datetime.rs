@@ -16,15 +16,7 @@ fn cast_and_apply<
 ) -> ChunkedArray<T> {
     let dtype = ca.dtype().to_arrow(CompatLevel::newest());
     let chunks = ca.downcast_iter().map(|arr| {
-        let arr = cast(
-            arr,
-            &dtype,
-            CastOptionsImpl {
-                wrapped: true,
-                partial: false,
-            },
-        )
-        .unwrap();
+        let arr = cast(arr, &dtype, CastOptionsImpl::unchecked()).unwrap();
         func(&*arr).unwrap()
     });
     ChunkedArray::from_chunk_iter(ca.name().clone(), chunks)
@@ -0,0 +1,170 @@
+#[cfg(all(feature = "rolling_window_by", feature = "timezones"))]
+use chrono_tz::Tz;
+use polars_compute::rolling::cov;
+#[cfg(feature = "rolling_window_by")]
+use polars_ops::series::SeriesMethods;
+
+use super::*;
+
+fn to_f64(s: &Series) -> PolarsResult<Float64Chunked> {
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.rechunk().into_owned())
+}
+
+/// The result is computed in `f64`, cast back to `Float32` if `x` is `Float32`.
+fn finish(x: &Series, out: Float64Chunked) -> PolarsResult<Series> {
+    let out = out.with_name(x.name().clone()).into_series();
+    match x.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+/// Rolling covariance of `x` and `y`, or their Pearson correlation if `is_corr` is set, over
+/// windows of a fixed number of rows.
+///
+/// Rows where either value is null are skipped.
+#[cfg(feature = "rolling_window")]
+pub fn rolling_cov_corr(
+    x: &Series,
+    y: &Series,
+    options: &RollingOptionsFixedWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "rolling covariance requires columns of equal length, got {} and {}", x.len(), y.len()
+    );
+    let (x_ca, y_ca) = (to_f64(x)?, to_f64(y)?);
+    let out = cov::rolling_cov_corr_fixed(
+        x_ca.downcast_as_array(),
+        y_ca.downcast_as_array(),
+        options.window_size,
+        options.min_periods,
+        options.center,
+        ddof,
+        is_corr,
+    );
+    finish(x, Float64Chunked::with_chunk(PlSmallStr::EMPTY, out))
+}
+
+/// Rolling covariance of `x` and `y`, or their Pearson correlation if `is_corr` is set, over
+/// the temporal windows defined by `by`.
+///
+/// Rows where either value is null are skipped, `by` must not contain nulls.
+#[cfg(feature = "rolling_window_by")]
+pub fn rolling_cov_corr_by(
+    x: &Series,
+    y: &Series,
+    by: &Series,
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        x.len() == y.len() && x.len() == by.len(),
+        ShapeMismatch: "`by` column in `rolling_*_by` must be the same length as values column"
+    );
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "'Expr.rolling_*_by(...)' not yet supported for `by` with null values");
+    let (x_ca, y_ca) = (to_f64(x)?, to_f64(y)?);
+    if x_ca.is_empty() {
+        return finish(x, x_ca);
+    }
+    let (by, tz) = rolling_by_to_datetime(by, &options)?;
+    let by = by.rechunk();
+    let by_is_sorted = by.is_sorted(SortOptions {
+        descending: false,
+        ..Default::default()
+    })?;
+    let by = by.datetime().unwrap();
+    let tu = by.time_unit();
+
+    let sorting_indices = (!by_is_sorted).then(|| by.arg_sort(Default::default()));
+    let (x_ca, y_ca, by) = match &sorting_indices {
+        // SAFETY: the sorting indices are in bounds.
+        Some(idx) => unsafe {
+            (
+                x_ca.take_unchecked(idx),
+                y_ca.take_unchecked(idx),
+                by.physical().take_unchecked(idx),
+            )
+        },
+        None => (x_ca, y_ca, by.physical().clone()),
+    };
+    let by_values = by.cont_slice().unwrap();
+    let windows = match tz.as_ref() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(
+            options.window_size,
+            by_values,
+            options.closed_window,
+            tu,
+            tz.parse::<Tz>().ok(),
+        ),
+        _ => group_by_values_iter(
+            options.window_size,
+            by_values,
+            options.closed_window,
+            tu,
+            None,
+        ),
+    }?
+    .map(|window| window.map(|(start, len)| (start as usize, (start + len) as usize)))
+    .collect::<PolarsResult<Vec<_>>>()?;
+
+    let out = cov::rolling_cov_corr(
+        x_ca.rechunk().downcast_as_array(),
+        y_ca.rechunk().downcast_as_array(),
+        windows,
+        options.min_periods,
+        ddof,
+        is_corr,
+    );
+    let mut out = Float64Chunked::with_chunk(PlSmallStr::EMPTY, out);
+    if let Some(idx) = sorting_indices {
+        // Scatter the results back to the original order.
+        let inverse = idx.arg_sort(Default::default());
+        out = unsafe { out.take_unchecked(&inverse) };
+    }
+    finish(x, out)
+}
+
+#[cfg(all(test, feature = "rolling_window_by"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_cov_corr_by_unsorted() -> PolarsResult<()> {
+        let x = Series::new("x".into(), [1.0, 2.0, 4.0, 3.0, 8.0]);
+        let y = Series::new("y".into(), [2.0, 1.0, 5.0, 0.0, 7.0]);
+        let by = Series::new("by".into(), [0i64, 1, 2, 3, 4]);
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::parse("3i"),
+            min_periods: 2,
+            closed_window: ClosedWindow::Right,
+            fn_params: None,
+        };
+        let sorted = rolling_cov_corr_by(&x, &y, &by, options.clone(), 1, false)?;
+
+        // Windows only depend on `by`, so shuffling all inputs shuffles the output.
+        let idx = IdxCa::from_vec("".into(), vec![3, 0, 4, 2, 1]);
+        let shuffled = rolling_cov_corr_by(
+            &x.take(&idx)?,
+            &y.take(&idx)?,
+            &by.take(&idx)?,
+            options,
+            1,
+            false,
+        )?;
+        assert!(shuffled.equals_missing(&sorted.take(&idx)?));
+
+        let out = sorted.f64()?;
+        assert_eq!(out.get(0), None);
+        // cov([1, 2], [2, 1]) and cov([2, 4, 3], [1, 5, 0]).
+        assert!((out.get(1).unwrap() + 0.5).abs() < 1e-12);
+        assert!((out.get(3).unwrap() - 2.0).abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -58,6 +58,38 @@ where
     Series::try_from((ca.name().clone(), arr))
 }
 
+/// Validate the window of a `rolling_*_by` operation and cast `by` to a `Datetime` without
+/// time zone. Returns the time zone of `by`, if any.
+#[cfg(feature = "rolling_window_by")]
+pub(super) fn rolling_by_to_datetime<'a>(
+    by: &'a Series,
+    options: &RollingOptionsDynamicWindow,
+) -> PolarsResult<(Series, &'a Option<TimeZone>)> {
+    ensure_duration_matches_dtype(options.window_size, by.dtype(), "window_size")?;
+    polars_ensure!(!options.window_size.is_zero() && !options.window_size.negative, InvalidOperation: "`window_size` must be strictly positive");
+    let (by, tz) = match by.dtype() {
+        DataType::Datetime(tu, tz) => (by.cast(&DataType::Datetime(*tu, None))?, tz),
+        DataType::Date => (
+            by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            &None,
+        ),
+        DataType::Int64 => (
+            by.cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?,
+            &None,
+        ),
+        DataType::Int32 | DataType::UInt64 | DataType::UInt32 => (
+            by.cast(&DataType::Int64)?
+                .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?,
+            &None,
+        ),
+        dt => polars_bail!(InvalidOperation:
+            "in `rolling_*_by` operation, `by` argument of dtype `{}` is not supported (expected `{}`)",
+            dt,
+            "Date/Datetime/Int64/Int32/UInt64/UInt32"),
+    };
+    Ok((by, tz))
+}
+
 #[cfg(feature = "rolling_window_by")]
 #[allow(clippy::type_complexity)]
 fn rolling_agg_by<T>(
@@ -84,28 +116,7 @@ where
     }
     polars_ensure!(by.null_count() == 0 && ca.null_count() == 0, InvalidOperation: "'Expr.rolling_*_by(...)' not yet supported for series with null values, consider using 'DataFrame.rolling' or 'Expr.rolling'");
     polars_ensure!(ca.len() == by.len(), InvalidOperation: "`by` column in `rolling_*_by` must be the same length as values column");
-    ensure_duration_matches_dtype(options.window_size, by.dtype(), "window_size")?;
-    polars_ensure!(!options.window_size.is_zero() && !options.window_size.negative, InvalidOperation: "`window_size` must be strictly positive");
-    let (by, tz) = match by.dtype() {
-        DataType::Datetime(tu, tz) => (by.cast(&DataType::Datetime(*tu, None))?, tz),
-        DataType::Date => (
-            by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
-            &None,
-        ),
-        DataType::Int64 => (
-            by.cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?,
-            &None,
-        ),
-        DataType::Int32 | DataType::UInt64 | DataType::UInt32 => (
-            by.cast(&DataType::Int64)?
-                .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?,
-            &None,
-        ),
-        dt => polars_bail!(InvalidOperation:
-            "in `rolling_*_by` operation, `by` argument of dtype `{}` is not supported (expected `{}`)",
-            dt,
-            "Date/Datetime/Int64/Int32/UInt64/UInt32"),
-    };
+    let (by, tz) = rolling_by_to_datetime(by, &options)?;
     let ca = ca.rechunk();
     let by = by.rechunk();
     let by_is_sorted = by.is_sorted(SortOptions {
@@ -1,8 +1,12 @@
+#[cfg(feature = "cov")]
+mod corr_cov;
 mod dispatch;
 #[cfg(feature = "rolling_window_by")]
 mod rolling_kernels;
 
 use arrow::array::{ArrayRef, PrimitiveArray};
+#[cfg(feature = "cov")]
+pub use corr_cov::*;
 pub use dispatch::*;
 use polars_compute::rolling;
 use polars_compute::rolling::RollingFnParams;
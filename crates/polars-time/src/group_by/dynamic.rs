@@ -34,6 +34,12 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// Add a `_window_id` column that numbers the windows of every group, starting at 0.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub include_window_id: bool,
+    /// Combine multiple `group_by` keys into a single struct column, named after the first key.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keys_as_struct: bool,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +53,8 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            include_window_id: false,
+            keys_as_struct: false,
         }
     }
 }
@@ -61,6 +69,17 @@ pub struct RollingGroupOptions {
     pub period: Duration,
     pub offset: Duration,
     pub closed_window: ClosedWindow,
+    /// Add a `_window_id` column that numbers the windows of every group, starting at 0. Every
+    /// row is its own window, so this is the position of the row within its group.
+    ///
+    /// Only used by a rolling group-by, not by the rolling window expression.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub include_window_id: bool,
+    /// Combine multiple `group_by` keys into a single struct column, named after the first key.
+    ///
+    /// Only used by a rolling group-by, not by the rolling window expression.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keys_as_struct: bool,
 }
 
 impl Default for RollingGroupOptions {
@@ -70,6 +89,8 @@ impl Default for RollingGroupOptions {
             period: Duration::new(1),
             offset: Duration::new(1),
             closed_window: ClosedWindow::Left,
+            include_window_id: false,
+            keys_as_struct: false,
         }
     }
 }
@@ -81,6 +102,7 @@ fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
 
 const LB_NAME: &str = "_lower_boundary";
 const UP_NAME: &str = "_upper_boundary";
+const WINDOW_ID_NAME: &str = "_window_id";
 
 pub trait PolarsTemporalGroupby {
     fn rolling(
@@ -189,6 +211,8 @@ impl Wrap<&DataFrame> {
     }
 
     /// Returns: time_keys, keys, groupsproxy.
+    ///
+    /// The keys are the boundaries and the window ids, if requested.
     fn group_by_dynamic(
         &self,
         group_by: Option<GroupsSlice>,
@@ -309,6 +333,7 @@ impl Wrap<&DataFrame> {
                 _ => unreachable!(),
             };
 
+        let mut window_ids = None;
         let groups = if group_by.is_none() {
             let vals = dt.downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
@@ -323,6 +348,9 @@ impl Wrap<&DataFrame> {
                 options.start_by,
             );
             update_bounds(lower, upper);
+            if options.include_window_id {
+                window_ids = Some((0..groups.len() as u64).collect_vec());
+            }
             PolarsResult::Ok(GroupsType::Slice {
                 groups,
                 rolling: false,
@@ -362,6 +390,9 @@ impl Wrap<&DataFrame> {
             });
 
             let res = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
+            if options.include_window_id {
+                window_ids = Some(res.iter().flat_map(|g| 0..g.0.len() as u64).collect_vec());
+            }
             let groups = res.iter().map(|g| &g.0).collect_vec();
             let lower = res.iter().map(|g| &g.1).collect_vec();
             let upper = res.iter().map(|g| &g.2).collect_vec();
@@ -414,6 +445,14 @@ impl Wrap<&DataFrame> {
             bounds.push(lower.into_datetime(tu, tz.clone()).into_column());
             bounds.push(upper.into_datetime(tu, tz.clone()).into_column());
         }
+        if let Some(window_ids) = window_ids {
+            let mut window_ids =
+                UInt64Chunked::from_vec(PlSmallStr::from_static(WINDOW_ID_NAME), window_ids);
+            if group_by.is_none() {
+                window_ids.set_sorted_flag(IsSorted::Ascending);
+            }
+            bounds.push(window_ids.into_column());
+        }
 
         dt.into_datetime(tu, None)
             .into_column()
@@ -543,6 +582,7 @@ mod test {
                         period: Duration::parse("2d"),
                         offset: Duration::parse("-2d"),
                         closed_window: ClosedWindow::Right,
+                        ..Default::default()
                     },
                 )
                 .unwrap();
@@ -590,6 +630,7 @@ mod test {
                     period: Duration::parse("2d"),
                     offset: Duration::parse("-2d"),
                     closed_window: ClosedWindow::Right,
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -0,0 +1,193 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use atomic_waker::AtomicWaker;
+
+/// Shared flag to stop a running query.
+///
+/// Clones share the flag. Tasks can await [`CancelToken::cancelled`] instead of polling the flag,
+/// they are woken when the token is cancelled.
+///
+/// A token can also carry a deadline, after which the query should stop as well.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<Inner>,
+    deadline: Option<Instant>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// One slot per pending [`Cancelled`] future, removed again when the future is dropped.
+    waiters: Mutex<Vec<Arc<AtomicWaker>>>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the token and wake all tasks waiting on it.
+    pub fn cancel(&self) {
+        if !self.inner.cancelled.swap(true, Ordering::AcqRel) {
+            let waiters = std::mem::take(&mut *self.inner.waiters.lock().unwrap());
+            for waiter in waiters {
+                waiter.wake();
+            }
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Set a deadline on the token, the earlier one is kept if it already has one.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(match self.deadline {
+            Some(current) => current.min(deadline),
+            None => deadline,
+        });
+        self
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Whether the deadline of the token has passed.
+    pub fn is_expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Future that resolves once the token is cancelled.
+    ///
+    /// This does not resolve when the deadline passes, use [`CancelToken::deadline`] to wait for
+    /// that as well.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            waiter: None,
+        }
+    }
+}
+
+/// Future returned by [`CancelToken::cancelled`].
+pub struct Cancelled<'a> {
+    token: &'a CancelToken,
+    waiter: Option<Arc<AtomicWaker>>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        match &this.waiter {
+            Some(waiter) => waiter.register(cx.waker()),
+            None => {
+                let waiter = Arc::new(AtomicWaker::new());
+                waiter.register(cx.waker());
+                let mut waiters = this.token.inner.waiters.lock().unwrap();
+                // `cancel` sets the flag before it takes the waiters, so checking again under the
+                // lock ensures we either see the flag or get woken.
+                if this.token.is_cancelled() {
+                    return Poll::Ready(());
+                }
+                waiters.push(waiter.clone());
+                this.waiter = Some(waiter);
+            },
+        }
+
+        // The flag may have been set before the new waker was registered.
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(waiter) = self.waiter.take() {
+            let mut waiters = self.token.inner.waiters.lock().unwrap();
+            waiters.retain(|w| !Arc::ptr_eq(w, &waiter));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::task::{Wake, Waker};
+
+    use super::*;
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_cancel_wakes_waiters() {
+        let token = CancelToken::new();
+        let counter = Arc::new(CountWakes(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut fut = std::pin::pin!(token.cancelled());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+
+        token.clone().cancel();
+        assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+        assert!(fut.as_mut().poll(&mut cx).is_ready());
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_cancelled_keeps_one_waker() {
+        let token = CancelToken::new();
+        let counters = [0, 1].map(|_| Arc::new(CountWakes(AtomicUsize::new(0))));
+
+        {
+            let mut fut = std::pin::pin!(token.cancelled());
+            for counter in &counters {
+                let waker = Waker::from(counter.clone());
+                assert!(
+                    fut.as_mut()
+                        .poll(&mut Context::from_waker(&waker))
+                        .is_pending()
+                );
+            }
+            assert_eq!(token.inner.waiters.lock().unwrap().len(), 1);
+        }
+        // Dropping the future deregisters it.
+        assert!(token.inner.waiters.lock().unwrap().is_empty());
+
+        let mut fut = std::pin::pin!(token.cancelled());
+        for counter in &counters {
+            let waker = Waker::from(counter.clone());
+            assert!(
+                fut.as_mut()
+                    .poll(&mut Context::from_waker(&waker))
+                    .is_pending()
+            );
+        }
+        token.cancel();
+        // Only the latest waker is woken.
+        assert_eq!(counters[0].0.load(Ordering::Relaxed), 0);
+        assert_eq!(counters[1].0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -10,6 +10,7 @@ pub mod algebraic_ops;
 pub mod arena;
 pub mod binary_search;
 pub mod cache;
+pub mod cancel;
 pub mod cardinality_sketch;
 pub mod cell;
 pub mod chunks;
@@ -26,6 +27,7 @@ pub mod idx_vec;
 pub mod mem;
 pub mod min_max;
 pub mod pl_str;
+pub mod pool;
 pub mod priority;
 pub mod regex_cache;
 pub mod select;
@@ -121,6 +121,36 @@ mod private {
             Ok(Self::from_mmap(Arc::new(mmap)))
         }
 
+        /// Memory-map `file` while holding a shared advisory lock on it.
+        ///
+        /// The lock belongs to the open file of `file`, so it is held until both `file` and the
+        /// last clone of the returned `MemSlice` are dropped. Returns
+        /// `None` if the lock could not be taken, e.g. because a writer holds an exclusive lock or
+        /// the file system doesn't support locking.
+        ///
+        /// Only supported on Unix, other platforms return an error.
+        pub fn from_file_locked(file: &File) -> PolarsResult<Option<Self>> {
+            #[cfg(target_family = "unix")]
+            {
+                let file = file.try_clone()?;
+                if !super::try_lock_shared(&file) {
+                    return Ok(None);
+                }
+                let mmap = MMapSemaphore::new_from_file(&file)?;
+                let slice = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(mmap.as_ref()) };
+                Ok(Some(Self {
+                    slice,
+                    inner: MemSliceInner::Arc(Arc::new(super::LockedMMap { mmap, file })),
+                }))
+            }
+
+            #[cfg(not(target_family = "unix"))]
+            {
+                _ = file;
+                polars_error::polars_bail!(InvalidOperation: "locking memory-mapped files is only supported on Unix")
+            }
+        }
+
         /// Construct a `MemSlice` that simply wraps around a `&[u8]`.
         #[inline]
         pub const fn from_static(slice: &'static [u8]) -> Self {
@@ -387,6 +417,24 @@ impl AsRef<[u8]> for MMapSemaphore {
     }
 }
 
+/// A memory map that holds a shared advisory lock on the mapped file.
+///
+/// The lock is released when the file is closed, which happens after the map is unmapped.
+#[cfg(target_family = "unix")]
+#[derive(Debug)]
+#[allow(unused)]
+struct LockedMMap {
+    mmap: MMapSemaphore,
+    file: File,
+}
+
+/// Try to take a shared `flock` on `file` without blocking.
+#[cfg(target_family = "unix")]
+fn try_lock_shared(file: &File) -> bool {
+    use std::os::fd::AsRawFd;
+    unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_SH | libc::LOCK_NB) == 0 }
+}
+
 pub fn ensure_not_mapped(
     #[cfg_attr(not(target_family = "unix"), allow(unused))] file_md: &std::fs::Metadata,
 ) -> PolarsResult<()> {
@@ -486,4 +534,30 @@ mod tests {
             assert_eq!(out.as_ptr(), slice[3..5].as_ptr());
         }
     }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_mem_slice_from_file_locked() {
+        use std::os::fd::AsRawFd;
+
+        use super::MemSlice;
+
+        let path = "../../examples/datasets/foods1.csv";
+        let file = std::fs::File::open(path).unwrap();
+        let mem_slice = MemSlice::from_file_locked(&file).unwrap().unwrap();
+        assert_eq!(&*mem_slice, std::fs::read(path).unwrap().as_slice());
+
+        // A shared lock is held, so no exclusive lock can be taken until it is dropped.
+        let other = std::fs::File::open(path).unwrap();
+        let lock_exclusive =
+            || unsafe { libc::flock(other.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) == 0 };
+        assert!(!lock_exclusive());
+        drop(mem_slice);
+        drop(file);
+        assert!(lock_exclusive());
+
+        // Now that `other` holds an exclusive lock, the file is not mapped.
+        let file = std::fs::File::open(path).unwrap();
+        assert!(MemSlice::from_file_locked(&file).unwrap().is_none());
+    }
 }
@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::sync::{Arc, Weak};
+
+use polars_error::{PolarsResult, polars_err};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// The compute pool the work on a thread belongs to.
+enum CurrentPool {
+    Global,
+    /// The thread [entered](ComputePool::enter) a pool.
+    Entered(Arc<ThreadPool>),
+    /// The thread is a worker of a [`ComputePool`], so rayon's free functions dispatch to it.
+    Worker,
+}
+
+thread_local! {
+    static CURRENT_POOL: RefCell<CurrentPool> = const { RefCell::new(CurrentPool::Global) };
+    /// Handle to the pool on the worker threads of a [`ComputePool`], only upgraded by
+    /// [`PolarsPool::current_pool`] and [`PolarsPool::current_thread_has_pending_tasks`].
+    static WORKER_POOL: RefCell<Option<Weak<ThreadPool>>> = const { RefCell::new(None) };
+}
+
+/// The thread pool polars runs its parallel work on.
+///
+/// This dispatches to the [`ComputePool`] of the current query if there is one, and to the
+/// global pool otherwise. It dereferences to the global pool, so the methods of rayon's
+/// [`ThreadPool`] that aren't wrapped here run on the global pool.
+pub struct PolarsPool {
+    global: Arc<ThreadPool>,
+}
+
+impl PolarsPool {
+    pub fn new(global: ThreadPool) -> Self {
+        Self {
+            global: Arc::new(global),
+        }
+    }
+
+    /// The pool work submitted from this thread runs on.
+    pub fn current_pool(&self) -> Arc<ThreadPool> {
+        match self.dispatch_pool() {
+            Some(pool) => pool.into_arc(),
+            None => WORKER_POOL
+                .with_borrow(|pool| pool.as_ref().and_then(Weak::upgrade))
+                .unwrap_or_else(|| self.global.clone()),
+        }
+    }
+
+    /// The pool to submit work to: an entered [`ComputePool`] or the global pool. This is `None`
+    /// on the workers of a [`ComputePool`], where rayon's free functions dispatch to it.
+    #[inline]
+    fn dispatch_pool(&self) -> Option<DispatchPool<'_>> {
+        CURRENT_POOL.with_borrow(|current| match current {
+            CurrentPool::Global => Some(DispatchPool::Global(&self.global)),
+            CurrentPool::Entered(pool) => Some(DispatchPool::Entered(pool.clone())),
+            CurrentPool::Worker => None,
+        })
+    }
+
+    /// The number of threads of the global pool, regardless of the current [`ComputePool`].
+    pub fn global_num_threads(&self) -> usize {
+        self.global.current_num_threads()
+    }
+
+    pub fn current_num_threads(&self) -> usize {
+        self.dispatch_pool()
+            .map_or_else(rayon::current_num_threads, |pool| {
+                pool.current_num_threads()
+            })
+    }
+
+    pub fn current_thread_index(&self) -> Option<usize> {
+        self.dispatch_pool()
+            .map_or_else(rayon::current_thread_index, |pool| {
+                pool.current_thread_index()
+            })
+    }
+
+    pub fn current_thread_has_pending_tasks(&self) -> Option<bool> {
+        // rayon has no free function for this one.
+        match self.dispatch_pool() {
+            Some(pool) => pool.current_thread_has_pending_tasks(),
+            None => self.current_pool().current_thread_has_pending_tasks(),
+        }
+    }
+
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        match self.dispatch_pool() {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    pub fn join<A, B, RA, RB>(&self, oper_a: A, oper_b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        match self.dispatch_pool() {
+            Some(pool) => pool.join(oper_a, oper_b),
+            None => rayon::join(oper_a, oper_b),
+        }
+    }
+
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&rayon::Scope<'scope>) -> R + Send,
+        R: Send,
+    {
+        match self.dispatch_pool() {
+            Some(pool) => pool.scope(op),
+            None => rayon::scope(op),
+        }
+    }
+
+    pub fn spawn<F>(&self, func: F)
+    where
+        F: 'static + FnOnce() + Send,
+    {
+        match self.dispatch_pool() {
+            Some(pool) => pool.spawn(func),
+            None => rayon::spawn(func),
+        }
+    }
+
+    pub fn spawn_fifo<F>(&self, func: F)
+    where
+        F: 'static + FnOnce() + Send,
+    {
+        match self.dispatch_pool() {
+            Some(pool) => pool.spawn_fifo(func),
+            None => rayon::spawn_fifo(func),
+        }
+    }
+}
+
+impl Deref for PolarsPool {
+    type Target = ThreadPool;
+
+    fn deref(&self) -> &ThreadPool {
+        &self.global
+    }
+}
+
+/// The pool [`PolarsPool`] dispatches to. The entered pool is cloned out of the thread local, so
+/// that the work submitted to it can enter other pools.
+enum DispatchPool<'a> {
+    Global(&'a Arc<ThreadPool>),
+    Entered(Arc<ThreadPool>),
+}
+
+impl DispatchPool<'_> {
+    fn into_arc(self) -> Arc<ThreadPool> {
+        match self {
+            Self::Global(pool) => pool.clone(),
+            Self::Entered(pool) => pool,
+        }
+    }
+}
+
+impl Deref for DispatchPool<'_> {
+    type Target = ThreadPool;
+
+    fn deref(&self) -> &ThreadPool {
+        match self {
+            Self::Global(pool) => pool,
+            Self::Entered(pool) => pool,
+        }
+    }
+}
+
+/// A dedicated thread pool for a query.
+///
+/// All parallel work started while the pool is [entered](ComputePool::enter), including work
+/// nested in user functions that use the polars pool, runs on this pool instead of on the
+/// global pool.
+#[derive(Clone)]
+pub struct ComputePool {
+    pool: Arc<ThreadPool>,
+}
+
+impl ComputePool {
+    pub fn new(n_threads: usize) -> PolarsResult<Self> {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .thread_name(|i| format!("polars-compute-{i}"))
+            .build()
+            .map_err(|e| polars_err!(ComputeError: "could not spawn threads: {}", e))?;
+        let pool = Arc::new(pool);
+
+        // The threads only hold a weak reference, so the pool shuts down once the last handle
+        // is dropped.
+        let weak = Arc::downgrade(&pool);
+        pool.broadcast(|_| {
+            CURRENT_POOL.set(CurrentPool::Worker);
+            WORKER_POOL.set(Some(weak.clone()));
+        });
+
+        Ok(Self { pool })
+    }
+
+    pub fn num_threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Run `op` on the calling thread, with the parallel work it starts running on this pool.
+    pub fn enter<R>(&self, op: impl FnOnce() -> R) -> R {
+        struct Restore(Option<CurrentPool>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_POOL.set(self.0.take().unwrap());
+            }
+        }
+
+        let previous = CURRENT_POOL.replace(CurrentPool::Entered(self.pool.clone()));
+        let _restore = Restore(Some(previous));
+        op()
+    }
+}
@@ -1,7 +1,6 @@
 use std::mem::MaybeUninit;
 
 use num_traits::FromPrimitive;
-use rayon::ThreadPool;
 use rayon::prelude::*;
 
 use crate::IdxSize;
@@ -21,7 +20,11 @@ use crate::total_ord::TotalOrd;
 /// # Safety
 /// The caller must ensure that the right indexes for `&[(_, IdxSize)]` are integers ranging from `0..idx.len`
 #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
-pub unsafe fn perfect_sort(pool: &ThreadPool, idx: &[(IdxSize, IdxSize)], out: &mut Vec<IdxSize>) {
+pub unsafe fn perfect_sort(
+    pool: &crate::pool::PolarsPool,
+    idx: &[(IdxSize, IdxSize)],
+    out: &mut Vec<IdxSize>,
+) {
     let chunk_size = std::cmp::max(
         idx.len() / pool.current_num_threads(),
         pool.current_num_threads(),
@@ -202,8 +202,12 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read Parquet files written with modular encryption
+//!     - `disk_cache` - Cache query results as Parquet files with `LazyFrame::cache_to_disk`
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `compat-arrow-rs` - Convert a [`Series`] from and to an array of the
+//!       [arrow](https://crates.io/crates/arrow) crate without copying.
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -236,6 +240,7 @@
 //!     - `is_first_distinct` - Check if element is first unique value.
 //!     - `is_last_distinct` - Check if element is last unique value.
 //!     - `is_between` - Check if this expression is between the given lower and upper bounds.
+//!     - `is_close` - Check if the values of two expressions are equal within a tolerance.
 //!     - `checked_arithmetic` - checked arithmetic/ returning [`None`] on invalid operations.
 //!     - `dot_product` - Dot/inner product on [`Series`] and [`Expr`].
 //!     - `concat_str` - Concat string data in linear time.
@@ -251,6 +256,9 @@
 //!     - `rank` - Ranking algorithms.
 //!     - `moment` - Kurtosis and skew statistics
 //!     - `ewma` - Exponential moving average windows
+//!     - `session_id` - Assign session ids to events based on the gap between them.
+//!     - `finance` - Volatility estimators for OHLC price data.
+//!     - `signal` - Signal processing filters such as Savitzky-Golay smoothing.
 //!     - `abs` - Get absolute values of [`Series`].
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
@@ -1,3 +1,4 @@
+pub use polars_core::error::PolarsResultExt;
 pub use polars_core::prelude::*;
 pub use polars_core::utils::NoNull;
 #[cfg(feature = "polars-io")]
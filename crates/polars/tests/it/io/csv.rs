@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
 
@@ -135,6 +136,80 @@ fn write_dates() {
     assert_eq!("2000-12-01T00:01:39.049-0500\n", csv);
 }
 
+#[test]
+fn write_float_formats() {
+    let mut df = df![
+        "a" => [1.234567f64, -0.000123456, 98765.4321],
+        "b" => [0.5f64, 1234.5678, -2.0],
+    ]
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_float_precision(Some(3))
+        .with_column_formats(BTreeMap::from([(
+            "b".into(),
+            ValueFormat::Float {
+                precision: Some(2),
+                scientific: Some(true),
+            },
+        )]))
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(
+        "a,b\n1.235,5.00e-1\n-0.000,1.23e3\n98765.432,-2.00e0\n",
+        csv
+    );
+
+    // The values parse back to the values rounded at the chosen precision.
+    let out = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(buf))
+        .finish()
+        .unwrap();
+    let expected = df![
+        "a" => [1.235f64, -0.0, 98765.432],
+        "b" => [0.5f64, 1230.0, -2.0],
+    ]
+    .unwrap();
+    assert!(out.equals(&expected), "{out:?}");
+
+    // A float format cannot be applied to a non-float column.
+    let mut df = create_df();
+    CsvWriter::new(&mut buf)
+        .with_column_formats(BTreeMap::from([(
+            "days".into(),
+            ValueFormat::Float {
+                precision: Some(2),
+                scientific: None,
+            },
+        )]))
+        .finish(&mut df)
+        .expect_err("float format on integer column should err");
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn write_temporal_column_formats() {
+    let mut df = df![
+        "a" => [chrono::NaiveDate::from_ymd_opt(2024, 2, 2).unwrap()],
+        "b" => [chrono::NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()],
+    ]
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_date_format(Some("%Y%m%d".into()))
+        .with_column_formats(BTreeMap::from([(
+            "b".into(),
+            ValueFormat::Temporal("%d/%m/%Y".into()),
+        )]))
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("a,b\n20240202,03/02/2024\n", csv);
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -151,3 +151,31 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn write_and_read_ipc_field_metadata() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let md = Arc::new(std::collections::BTreeMap::from([(
+        PlSmallStr::from_static("unit"),
+        PlSmallStr::from_static("celsius"),
+    )]));
+    let temp = Series::new("temp".into(), [22.1, 19.9, 7.]).with_metadata(Some(md.clone()));
+    let mut df = DataFrame::new(vec![temp.into()]).unwrap();
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    let temp = df_read.column("temp").unwrap().as_materialized_series();
+    assert_eq!(temp.metadata(), Some(md.clone()));
+
+    // Renames and casts keep the metadata.
+    let temp = temp
+        .clone()
+        .with_name("temperature".into())
+        .cast(&DataType::Float32)
+        .unwrap();
+    assert_eq!(temp.metadata(), Some(md));
+}
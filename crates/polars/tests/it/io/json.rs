@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
 
@@ -112,6 +113,118 @@ fn read_unordered_json() {
     assert_eq!((12, 4), df.shape());
 }
 
+#[test]
+fn write_ndjson_float_formats() {
+    let mut df = df![
+        "a" => [Some(1.234567f64), None, Some(98765.4321)],
+        "b" => [0.5f64, 1234.5678, -2.0],
+    ]
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_float_precision(Some(3))
+        .with_column_formats(BTreeMap::from([(
+            "b".into(),
+            ValueFormat::Float {
+                precision: Some(2),
+                scientific: Some(true),
+            },
+        )]))
+        .finish(&mut df)
+        .unwrap();
+    let json = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(
+        r#"{"a":1.235,"b":5.00e-1}
+{"a":null,"b":1.23e3}
+{"a":98765.432,"b":-2.00e0}
+"#,
+        json
+    );
+
+    // The values parse back to the values rounded at the chosen precision.
+    let out = JsonReader::new(Cursor::new(buf))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()
+        .unwrap();
+    let expected = df![
+        "a" => [Some(1.235f64), None, Some(98765.432)],
+        "b" => [0.5f64, 1230.0, -2.0],
+    ]
+    .unwrap();
+    assert!(out.equals_missing(&expected), "{out:?}");
+}
+
+#[test]
+fn write_ndjson_whole_floats() {
+    let mut df = df![
+        "a" => [1.0f64, -2.0, 1e20],
+    ]
+    .unwrap();
+
+    for (precision, scientific, expected) in [
+        (None, None, ["1.0", "-2.0", "1e20"]),
+        (
+            None,
+            Some(false),
+            ["1.0", "-2.0", "100000000000000000000.0"],
+        ),
+        (None, Some(true), ["1e0", "-2e0", "1e20"]),
+        (Some(0), None, ["1.0", "-2.0", "100000000000000000000.0"]),
+        (Some(0), Some(true), ["1e0", "-2e0", "1e20"]),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::JsonLines)
+            .with_float_precision(precision)
+            .with_float_scientific(scientific)
+            .finish(&mut df)
+            .unwrap();
+        let json = std::str::from_utf8(&buf).unwrap();
+        let expected = expected.map(|v| format!("{{\"a\":{v}}}\n")).concat();
+        assert_eq!(json, expected);
+
+        // Whole values are still read back as floats.
+        let out = JsonReader::new(Cursor::new(buf))
+            .with_json_format(JsonFormat::JsonLines)
+            .finish()
+            .unwrap();
+        assert!(out.equals(&df), "{out:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn write_ndjson_temporal_column_formats() {
+    let mut df = df![
+        "a" => [chrono::NaiveDate::from_ymd_opt(2024, 2, 2).unwrap()],
+        "b" => [chrono::NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()],
+    ]
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_column_formats(BTreeMap::from([(
+            "b".into(),
+            ValueFormat::Temporal("%d/%m/%Y".into()),
+        )]))
+        .finish(&mut df)
+        .unwrap();
+    let json = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("{\"a\":\"2024-02-02\",\"b\":\"03/02/2024\"}\n", json);
+
+    buf.clear();
+    JsonWriter::new(&mut buf)
+        .with_column_formats(BTreeMap::from([(
+            "b".into(),
+            ValueFormat::Temporal("%<invalid format>".into()),
+        )]))
+        .finish(&mut df)
+        .expect_err("invalid temporal format should err");
+}
+
 #[test]
 fn read_ndjson_with_trailing_newline() {
     let data = r#"{"Column1":"Value1"}
@@ -206,3 +206,132 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_write_and_read_sorting_columns() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let mut ts = Series::new("ts".into(), [1i64, 2, 3, 4]);
+    ts.set_sorted_flag(IsSorted::Ascending);
+    let mut df = DataFrame::new(vec![
+        Column::new("l".into(), vec![Series::new("".into(), [1i64]); 4]),
+        Column::new("a".into(), [3i64, 1, 2, 5]),
+        ts.into(),
+        Column::new("b".into(), [4i64, 3, 2, 1]),
+    ])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .with_sorting_columns(vec![ParquetSortingColumn {
+            name: "b".into(),
+            descending: true,
+            nulls_last: true,
+        }])
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    let metadata = reader.get_metadata()?.clone();
+    assert_eq!(metadata.row_groups.len(), 2);
+    for rg in &metadata.row_groups {
+        let sorting_columns = rg.sorting_columns().unwrap();
+        // The list column occupies leaf 0.
+        assert_eq!(
+            sorting_columns
+                .iter()
+                .map(|s| (s.column_idx, s.descending))
+                .collect::<Vec<_>>(),
+            [(3, true), (2, false)]
+        );
+    }
+
+    let read_df = reader.finish()?;
+    assert!(df.equals(&read_df));
+    assert_eq!(read_df.column("ts")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(read_df.column("b")?.is_sorted_flag(), IsSorted::Descending);
+    assert_eq!(read_df.column("a")?.is_sorted_flag(), IsSorted::Not);
+
+    let mut buf = Cursor::new(Vec::new());
+    let result = ParquetWriter::new(&mut buf)
+        .with_sorting_columns(vec![ParquetSortingColumn {
+            name: "l".into(),
+            descending: false,
+            nulls_last: true,
+        }])
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    // Declared sorting columns are checked against the data.
+    let mut buf = Cursor::new(Vec::new());
+    let result = ParquetWriter::new(&mut buf)
+        .with_sorting_columns(vec![ParquetSortingColumn {
+            name: "a".into(),
+            descending: false,
+            nulls_last: true,
+        }])
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::InvalidOperation(_))));
+
+    let mut df = df!("c" => [None, Some(1i64), Some(2)])?;
+    for (nulls_last, is_ok) in [(false, true), (true, false)] {
+        let mut buf = Cursor::new(Vec::new());
+        let result = ParquetWriter::new(&mut buf)
+            .with_sorting_columns(vec![ParquetSortingColumn {
+                name: "c".into(),
+                descending: false,
+                nulls_last,
+            }])
+            .finish(&mut df);
+        assert_eq!(result.is_ok(), is_ok);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write_and_read_field_metadata() -> PolarsResult<()> {
+    let md = |unit: &'static str| {
+        Arc::new(std::collections::BTreeMap::from([(
+            PlSmallStr::from_static("unit"),
+            PlSmallStr::from_static(unit),
+        )]))
+    };
+    let temp = Series::new("temp".into(), [22.1, 19.9, 7.]).with_metadata(Some(md("celsius")));
+    let mut df = DataFrame::new(vec![Column::new("station".into(), [1, 2, 3]), temp.into()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    let temp = read_df.column("temp")?.as_materialized_series();
+    assert_eq!(temp.metadata(), Some(md("celsius")));
+    assert_eq!(
+        read_df
+            .column("station")?
+            .as_materialized_series()
+            .metadata(),
+        None
+    );
+
+    // The left frame wins for the columns that exist on both sides.
+    let temp_right = Series::new("temp".into(), [1., 2., 3.]).with_metadata(Some(md("fahrenheit")));
+    let right = DataFrame::new(vec![
+        Column::new("station".into(), [3, 2, 1]),
+        temp_right.into(),
+    ])?;
+    let joined = read_df.join(
+        &right,
+        ["station"],
+        ["station"],
+        JoinType::Inner.into(),
+        None,
+    )?;
+    let metadata = |name| {
+        joined
+            .column(name)
+            .unwrap()
+            .as_materialized_series()
+            .metadata()
+    };
+    assert_eq!(metadata("temp"), Some(md("celsius")));
+    assert_eq!(metadata("temp_right"), Some(md("fahrenheit")));
+    Ok(())
+}
@@ -56,3 +56,94 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_coalesce_columns() -> PolarsResult<()> {
+    let left = df! {
+        "id" => [1, 2],
+        "day" => [10, 11],
+        "x" => ["a", "b"],
+    }?;
+    let right = df! {
+        "id" => [2, 3],
+        "day" => [11, 12],
+        "y" => ["c", "d"],
+    }?;
+    let join = |how: JoinType, coalesce: JoinCoalesce| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .on([col("id"), col("day")])
+            .how(how)
+            .coalesce(coalesce)
+            .finish()
+            .sort(["id"], Default::default())
+    };
+
+    let mut lf = join(JoinType::Full, JoinCoalesce::Columns(vec!["id".into()]));
+    let names = lf
+        .collect_schema()?
+        .iter_names()
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["id", "day", "x", "day_right", "y"]);
+
+    for (how, ids, days_right) in [
+        (JoinType::Inner, vec![2], vec![Some(11)]),
+        (JoinType::Left, vec![1, 2], vec![None, Some(11)]),
+        (JoinType::Right, vec![2, 3], vec![Some(11), Some(12)]),
+        (
+            JoinType::Full,
+            vec![1, 2, 3],
+            vec![None, Some(11), Some(12)],
+        ),
+    ] {
+        let out = join(how.clone(), JoinCoalesce::Columns(vec!["id".into()])).collect()?;
+        // The coalesced key has no missing values, the other key is kept on both sides.
+        assert_eq!(
+            Vec::from(out.column("id")?.i32()?),
+            ids.into_iter().map(Some).collect::<Vec<_>>(),
+            "{how}"
+        );
+        assert_eq!(
+            Vec::from(out.column("day_right")?.i32()?),
+            days_right,
+            "{how}"
+        );
+        assert!(out.column("id_right").is_err(), "{how}");
+
+        // The eager join coalesces the same columns.
+        let eager = left.join(
+            &right,
+            ["id", "day"],
+            ["id", "day"],
+            JoinArgs::new(how.clone()).with_coalesce(JoinCoalesce::Columns(vec!["id".into()])),
+            None,
+        )?;
+        assert!(
+            eager.sort(["id"], Default::default())?.equals_missing(&out),
+            "{how}"
+        );
+    }
+
+    let err = join(JoinType::Full, JoinCoalesce::Columns(vec!["x".into()])).collect();
+    assert!(err.is_err());
+
+    // Expression keys are not part of the output, so they can't be coalesced.
+    let err = left
+        .clone()
+        .lazy()
+        .join_builder()
+        .with(right.clone().lazy())
+        .left_on([col("id"), col("day") + lit(1)])
+        .right_on([col("id"), col("day") + lit(1)])
+        .how(JoinType::Inner)
+        .coalesce(JoinCoalesce::Columns(vec!["day".into()]))
+        .finish()
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("plain columns"), "{err}");
+    Ok(())
+}
@@ -61,3 +61,134 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_group_by_dynamic_window_id() -> PolarsResult<()> {
+    let df = df![
+        "t" => [0i64, 1, 2, 3, 4, 0, 5],
+        "g" => ["a", "a", "a", "a", "a", "b", "b"],
+        "v" => [1, 2, 3, 4, 5, 6, 7],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [col("g")],
+            DynamicGroupOptions {
+                every: Duration::parse("2i"),
+                period: Duration::parse("2i"),
+                offset: Duration::parse("0i"),
+                include_boundaries: true,
+                include_window_id: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    let expected = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "_lower_boundary" => [0i64, 2, 4, 0, 4],
+        "_upper_boundary" => [2i64, 4, 6, 2, 6],
+        "_window_id" => [0u64, 1, 2, 0, 1],
+        "t" => [0i64, 2, 4, 0, 4],
+        "v" => [3, 7, 5, 6, 7],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dynamic_group_by", feature = "dtype-struct"))]
+fn test_group_by_dynamic_keys_as_struct() -> PolarsResult<()> {
+    let df = df![
+        "t" => [0i64, 1, 2, 0, 1],
+        "g" => ["a", "a", "a", "b", "b"],
+        "h" => [1, 1, 1, 2, 2],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [col("g"), col("h")],
+            DynamicGroupOptions {
+                every: Duration::parse("2i"),
+                period: Duration::parse("2i"),
+                offset: Duration::parse("0i"),
+                include_window_id: true,
+                keys_as_struct: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["g", "_window_id", "t", "v"]);
+    let keys = out.column("g")?.struct_()?.clone().unnest();
+    let expected = df![
+        "g" => ["a", "a", "b"],
+        "h" => [1, 1, 2],
+    ]?;
+    assert!(keys.equals(&expected));
+    assert_eq!(
+        Vec::from(out.column("_window_id")?.u64()?),
+        [0, 1, 0].map(Some)
+    );
+    assert_eq!(Vec::from(out.column("v")?.i32()?), [3, 3, 9].map(Some));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dynamic_group_by", feature = "dtype-struct"))]
+fn test_rolling_window_id() -> PolarsResult<()> {
+    let df = df![
+        "t" => [0i64, 1, 2, 0, 1],
+        "g" => ["a", "a", "a", "b", "b"],
+        "h" => [1, 1, 1, 2, 2],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+    let rolling = |keys_as_struct: bool| {
+        df.clone()
+            .lazy()
+            .rolling(
+                col("t"),
+                [col("g"), col("h")],
+                RollingGroupOptions {
+                    period: Duration::parse("2i"),
+                    offset: Duration::parse("-2i"),
+                    closed_window: ClosedWindow::Right,
+                    include_window_id: true,
+                    keys_as_struct,
+                    ..Default::default()
+                },
+            )
+            .agg([col("v").sum()])
+    };
+
+    // Every row is its own window, so the id is the position of the row within its group.
+    let mut lf = rolling(false);
+    let schema = lf.collect_schema()?;
+    assert_eq!(schema.get("_window_id"), Some(&DataType::UInt64));
+    let out = lf.collect()?;
+    let expected = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "h" => [1, 1, 1, 2, 2],
+        "_window_id" => [0u64, 1, 2, 0, 1],
+        "t" => [0i64, 1, 2, 0, 1],
+        "v" => [1, 3, 5, 4, 9],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = rolling(true).collect()?;
+    assert_eq!(out.get_column_names(), &["g", "_window_id", "t", "v"]);
+    assert!(matches!(out.column("g")?.dtype(), DataType::Struct(_)));
+    assert!(
+        out.column("_window_id")?
+            .equals(expected.column("_window_id")?)
+    );
+    Ok(())
+}
@@ -270,3 +270,75 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "list_sets", feature = "dtype-array"))]
+fn test_list_set_operation_exprs() -> PolarsResult<()> {
+    let list_dtype = DataType::List(Box::new(DataType::Int32));
+    let a: ListChunked = [[1i32, 2, 2], [3, 4, 3]]
+        .iter()
+        .map(|row| Some(Series::new(PlSmallStr::EMPTY, row)))
+        .collect();
+    let a = a
+        .into_series()
+        .with_name("a".into())
+        .cast(&DataType::Array(Box::new(DataType::Int32), 3))?;
+    let b: ListChunked = [vec![2i32, 5], vec![4]]
+        .iter()
+        .map(|row| Some(Series::new(PlSmallStr::EMPTY, row)))
+        .collect();
+    let b = b.into_series().with_name("b".into());
+    let df = DataFrame::new(vec![a.into_column(), b.into_column()])?;
+
+    let rhs = lit(Scalar::new(
+        list_dtype.clone(),
+        AnyValue::List(Series::new(PlSmallStr::EMPTY, [3i32, 1])),
+    ));
+    let lf = df.lazy().select([
+        col("a").list().union(col("b")).alias("union"),
+        col("a")
+            .list()
+            .set_intersection(col("b"))
+            .alias("intersection"),
+        col("a")
+            .list()
+            .set_difference(rhs.clone())
+            .alias("difference"),
+        col("a")
+            .list()
+            .set_operation(
+                rhs,
+                SetOperation::SymmetricDifference,
+                ListSetOptions {
+                    maintain_order: false,
+                    ..Default::default()
+                },
+            )
+            .alias("symmetric_difference"),
+    ]);
+
+    // The array input is handled as a list and the output is a list.
+    let schema = lf.clone().collect_schema()?;
+    assert!(schema.iter_values().all(|dtype| dtype == &list_dtype));
+
+    let out = lf.collect()?;
+    let row = |name: &str, i: usize| -> PolarsResult<Vec<Option<i32>>> {
+        let s = out.column(name)?.list()?.get_as_series(i).unwrap();
+        Ok(s.i32()?.into_iter().collect())
+    };
+    assert_eq!(row("union", 0)?, [Some(1), Some(2), Some(5)]);
+    assert_eq!(row("union", 1)?, [Some(3), Some(4)]);
+    assert_eq!(row("intersection", 0)?, [Some(2)]);
+    assert_eq!(row("intersection", 1)?, [Some(4)]);
+    // The literal is broadcast to every row.
+    assert_eq!(row("difference", 0)?, [Some(2)]);
+    assert_eq!(row("difference", 1)?, [Some(4)]);
+
+    let mut symmetric_difference = row("symmetric_difference", 0)?;
+    symmetric_difference.sort();
+    assert_eq!(symmetric_difference, [Some(2), Some(3)]);
+    let mut symmetric_difference = row("symmetric_difference", 1)?;
+    symmetric_difference.sort();
+    assert_eq!(symmetric_difference, [Some(1), Some(4)]);
+    Ok(())
+}
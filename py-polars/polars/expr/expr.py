@@ -3375,6 +3375,9 @@ class Expr:
         maintain_order
             Maintain order of data. This requires more work.
 
+            In a group by, the values of every group are then in the order of their
+            first appearance, which is the order of :meth:`unique_counts`.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 1, 2]})
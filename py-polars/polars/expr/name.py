@@ -286,7 +286,9 @@ class ExprNameNameSpace:
         """
         return self._from_pyexpr(self._pyexpr.name_to_uppercase())
 
-    def map_fields(self, function: Callable[[str], str]) -> Expr:
+    def map_fields(
+        self, function: Callable[[str], str], *, recursive: bool = False
+    ) -> Expr:
         """
         Rename fields of a struct by mapping a function over the field name(s).
 
@@ -298,6 +300,8 @@ class ExprNameNameSpace:
         ----------
         function
             Function that maps a field name to a new name.
+        recursive
+            Also rename the fields of nested structs.
 
         See Also
         --------
@@ -310,9 +314,9 @@ class ExprNameNameSpace:
         >>> df.select(pl.col("x").name.map_fields(lambda x: x.upper())).schema
         Schema({'x': Struct({'A': Int64, 'B': Int64})})
         """
-        return self._from_pyexpr(self._pyexpr.name_map_fields(function))
+        return self._from_pyexpr(self._pyexpr.name_map_fields(function, recursive))
 
-    def prefix_fields(self, prefix: str) -> Expr:
+    def prefix_fields(self, prefix: str, *, recursive: bool = False) -> Expr:
         """
         Add a prefix to all field names of a struct.
 
@@ -324,6 +328,8 @@ class ExprNameNameSpace:
         ----------
         prefix
             Prefix to add to the field name.
+        recursive
+            Also prefix the fields of nested structs.
 
         See Also
         --------
@@ -336,9 +342,9 @@ class ExprNameNameSpace:
         >>> df.select(pl.col("x").name.prefix_fields("prefix_")).schema
         Schema({'x': Struct({'prefix_a': Int64, 'prefix_b': Int64})})
         """
-        return self._from_pyexpr(self._pyexpr.name_prefix_fields(prefix))
+        return self._from_pyexpr(self._pyexpr.name_prefix_fields(prefix, recursive))
 
-    def suffix_fields(self, suffix: str) -> Expr:
+    def suffix_fields(self, suffix: str, *, recursive: bool = False) -> Expr:
         """
         Add a suffix to all field names of a struct.
 
@@ -350,6 +356,8 @@ class ExprNameNameSpace:
         ----------
         suffix
             Suffix to add to the field name.
+        recursive
+            Also suffix the fields of nested structs.
 
         See Also
         --------
@@ -362,4 +370,4 @@ class ExprNameNameSpace:
         >>> df.select(pl.col("x").name.suffix_fields("_suffix")).schema
         Schema({'x': Struct({'a_suffix': Int64, 'b_suffix': Int64})})
         """
-        return self._from_pyexpr(self._pyexpr.name_suffix_fields(suffix))
+        return self._from_pyexpr(self._pyexpr.name_suffix_fields(suffix, recursive))
@@ -127,3 +127,21 @@ def test_groupby_h2oai_q10(groupby_data: pl.DataFrame) -> None:
         )
         .collect()
     )
+
+
+def test_groupby_sorted_keys_many_chunks() -> None:
+    # Keys that span many chunks (e.g. many Parquet row groups) should not be rechunked.
+    n_chunks = 512
+    chunk_size = 10_000
+    df = pl.concat(
+        [
+            pl.DataFrame(
+                {"key": pl.int_range(i * chunk_size, (i + 1) * chunk_size, eager=True)}
+            ).with_columns(value=pl.col("key") % 7, key=pl.col("key") // 100)
+            for i in range(n_chunks)
+        ],
+        rechunk=False,
+    ).with_columns(pl.col("key").set_sorted())
+
+    out = df.group_by("key").agg(pl.sum("value"))
+    assert out.height == n_chunks * chunk_size // 100
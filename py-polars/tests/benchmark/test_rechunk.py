@@ -0,0 +1,35 @@
+"""Benchmark tests for rechunking wide DataFrames."""
+
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+N_COLUMNS = 500
+N_ROWS = 20_000
+N_CHUNKS = 8
+
+
+@pytest.fixture(scope="module")
+def wide_chunked_df() -> pl.DataFrame:
+    rng = np.random.default_rng(0)
+    chunk = pl.DataFrame(
+        {f"c{i}": rng.standard_normal(N_ROWS // N_CHUNKS) for i in range(N_COLUMNS)}
+    )
+    df = pl.concat([chunk] * N_CHUNKS, rechunk=False)
+    assert df.n_chunks() == N_CHUNKS
+    return df
+
+
+def test_rechunk_wide(wide_chunked_df: pl.DataFrame) -> None:
+    assert wide_chunked_df.rechunk().n_chunks() == 1
+
+
+def test_rechunk_wide_sequential(wide_chunked_df: pl.DataFrame) -> None:
+    # Baseline for `test_rechunk_wide`, rechunking one column at a time.
+    out = pl.DataFrame([s.rechunk() for s in wide_chunked_df.iter_columns()])
+    assert out.n_chunks() == 1
@@ -90,6 +90,28 @@ def test_prefix_suffix_fields() -> None:
     )
 
 
+def test_fields_recursive() -> None:
+    lf = pl.LazyFrame({"x": {"a": 1, "y": {"b": 2}}})
+
+    out = lf.select(pl.col("x").name.prefix_fields("p_", recursive=True))
+    assert out.collect_schema() == OrderedDict(
+        [("x", pl.Struct({"p_a": pl.Int64, "p_y": pl.Struct({"p_b": pl.Int64})}))]
+    )
+    assert out.unnest("x").select(pl.col("p_y").struct.field("p_b")).collect()[
+        "p_b"
+    ].to_list() == [2]
+
+    out = lf.select(pl.col("x").name.suffix_fields("_s"))
+    assert out.collect_schema() == OrderedDict(
+        [("x", pl.Struct({"a_s": pl.Int64, "y_s": pl.Struct({"b": pl.Int64})}))]
+    )
+
+    out = lf.select(pl.col("x").name.map_fields(str.upper, recursive=True))
+    assert out.collect().schema == OrderedDict(
+        [("x", pl.Struct({"A": pl.Int64, "Y": pl.Struct({"B": pl.Int64})}))]
+    )
+
+
 def test_struct_alias_prune_15401() -> None:
     df = pl.DataFrame({"a": []}, schema={"a": pl.Struct({"b": pl.Int8})})
     assert df.select(pl.col("a").alias("c").struct.field("b")).columns == ["b"]
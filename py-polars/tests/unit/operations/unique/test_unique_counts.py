@@ -1,7 +1,7 @@
 from datetime import datetime
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_unique_counts() -> None:
@@ -41,3 +41,29 @@ def test_unique_counts_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([3], dtype=pl.UInt32)
     assert_series_equal(s.unique_counts(), expected)
+
+
+def test_unique_counts_aligned_with_unique_in_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 1, 2, 2],
+            "v": [3, 1, 3, 3, None, 5],
+        }
+    )
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(
+            pl.col("v").unique(maintain_order=True).alias("u"),
+            pl.col("v").unique_counts().alias("c"),
+        )
+        .explode("u", "c")
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 2],
+            "u": [3, 1, 3, None, 5],
+            "c": [2, 1, 1, 1, 1],
+        },
+        schema_overrides={"c": pl.UInt32},
+    )
+    assert_frame_equal(out, expected)